@@ -1,4 +1,15 @@
-use clap::Parser;
+use clap::{
+    Parser,
+    ValueEnum,
+};
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LeaseBackend {
+    /// Leader lease stored on the external Redis nodes
+    Redis,
+    /// Leader lease replicated by the Raft log embedded into the producers
+    Raft,
+}
 
 #[derive(Parser, Debug)]
 #[command(name = "chaos-test", about = "Chaos test for HA leader lock failover")]
@@ -15,10 +26,14 @@ pub struct Cli {
     #[arg(long, default_value = "3")]
     pub nodes: usize,
 
-    /// Number of Redis nodes
+    /// Number of Redis nodes (ignored by the Raft backend)
     #[arg(long, default_value = "3")]
     pub redis_nodes: usize,
 
+    /// Leader lease backend
+    #[arg(long, value_enum, default_value = "redis")]
+    pub lease_backend: LeaseBackend,
+
     /// Block production interval
     #[arg(long, default_value = "100ms")]
     pub block_time: humantime::Duration,
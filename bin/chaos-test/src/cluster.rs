@@ -4,11 +4,15 @@ use std::time::{
 };
 
 use crate::{
+    cli::LeaseBackend,
     proxy::{
         ProxyMode,
         TcpProxy,
     },
-    redis_server::RedisTestServer,
+    redis_server::{
+        RedisTestServer,
+        bind_unused_port,
+    },
 };
 use fuel_core::{
     chain_config::ConsensusConfig,
@@ -21,7 +25,11 @@ use fuel_core::{
     service::{
         Config,
         FuelService,
-        config::RedisLeaderLockConfig,
+        config::{
+            RaftLeaderLockConfig,
+            RaftPeer,
+            RedisLeaderLockConfig,
+        },
     },
     state::{
         historical_rocksdb::StateRewindPolicy,
@@ -49,8 +57,11 @@ pub struct NodeHandle {
 }
 
 pub struct Cluster {
+    pub backend: LeaseBackend,
+    /// Empty for the Raft backend
     pub redis_servers: Vec<RedisTestServer>,
-    /// proxies[node_idx][redis_idx]
+    /// proxies[node_idx][redis_idx] for the Redis backend,
+    /// proxies[node_idx][peer_idx] for the Raft backend
     pub proxies: Vec<Vec<TcpProxy>>,
     pub nodes: Vec<Option<NodeHandle>>,
     node_configs: Vec<Config>,
@@ -67,29 +78,43 @@ impl Cluster {
         redis_count: usize,
         block_time: Duration,
         seed: u64,
+        backend: LeaseBackend,
     ) -> Self {
         let mut rng = StdRng::seed_from_u64(seed);
 
-        // 1. Start Redis servers
-        info!("Starting {redis_count} Redis servers...");
-        let mut redis_servers = Vec::with_capacity(redis_count);
-        for i in 0..redis_count {
-            let server = RedisTestServer::spawn();
-            info!("  Redis {i} listening on port {}", server.port());
-            redis_servers.push(server);
-        }
-
-        // 2. Create proxy grid: proxies[node_idx][redis_idx]
-        info!("Creating {}x{} proxy grid...", node_count, redis_count);
+        // 1. Start Redis servers, or reserve the Raft ports of the nodes
+        let mut redis_servers = Vec::new();
+        let mut raft_ports = Vec::new();
+        let targets: Vec<u16> = match backend {
+            LeaseBackend::Redis => {
+                info!("Starting {redis_count} Redis servers...");
+                for i in 0..redis_count {
+                    let server = RedisTestServer::spawn();
+                    info!("  Redis {i} listening on port {}", server.port());
+                    redis_servers.push(server);
+                }
+                redis_servers.iter().map(|server| server.port()).collect()
+            }
+            LeaseBackend::Raft => {
+                raft_ports = (0..node_count).map(|_| bind_unused_port()).collect();
+                raft_ports.clone()
+            }
+        };
+        let target_name = match backend {
+            LeaseBackend::Redis => "Redis",
+            LeaseBackend::Raft => "node",
+        };
+
+        // 2. Create proxy grid: proxies[node_idx][target_idx]
+        info!("Creating {}x{} proxy grid...", node_count, targets.len());
         let mut proxies = Vec::with_capacity(node_count);
         for node_idx in 0..node_count {
-            let mut node_proxies = Vec::with_capacity(redis_count);
-            for (redis_idx, server) in redis_servers.iter().enumerate().take(redis_count)
-            {
-                let target = format!("127.0.0.1:{}", server.port());
+            let mut node_proxies = Vec::with_capacity(targets.len());
+            for (target_idx, port) in targets.iter().enumerate() {
+                let target = format!("127.0.0.1:{port}");
                 let proxy = TcpProxy::start(target).await;
                 info!(
-                    "  Proxy node {node_idx} -> Redis {redis_idx}: port {}",
+                    "  Proxy node {node_idx} -> {target_name} {target_idx}: port {}",
                     proxy.listen_port()
                 );
                 node_proxies.push(proxy);
@@ -126,21 +151,6 @@ impl Cluster {
             });
             info!("  Node {node_idx} data dir: {}", tmp_dir.path().display());
 
-            let redis_urls: Vec<String> =
-                node_proxies.iter().map(|p| p.listen_url()).collect();
-
-            let leader_lock_config = RedisLeaderLockConfig {
-                redis_urls,
-                lease_key: format!("chaos-test:leader:{seed}"),
-                lease_ttl: Duration::from_secs(2),
-                node_timeout: Duration::from_millis(50),
-                retry_delay: Duration::from_millis(100),
-                max_retry_delay_offset: Duration::from_millis(25),
-                max_attempts: 2,
-                stream_max_len: 1000,
-                quorum_disruption_budget: 0,
-            };
-
             let mut node_config = make_config(
                 format!("Node-{node_idx}"),
                 base_config.clone(),
@@ -148,7 +158,46 @@ impl Cluster {
             );
             node_config.debug = true;
             node_config.block_production = Trigger::Interval { block_time };
-            node_config.leader_lock = Some(leader_lock_config);
+            match backend {
+                LeaseBackend::Redis => {
+                    node_config.leader_lock = Some(RedisLeaderLockConfig {
+                        redis_urls: node_proxies.iter().map(|p| p.listen_url()).collect(),
+                        lease_key: format!("chaos-test:leader:{seed}"),
+                        lease_ttl: Duration::from_secs(2),
+                        node_timeout: Duration::from_millis(50),
+                        retry_delay: Duration::from_millis(100),
+                        max_retry_delay_offset: Duration::from_millis(25),
+                        max_attempts: 2,
+                        stream_max_len: 1000,
+                        quorum_disruption_budget: 0,
+                    });
+                }
+                LeaseBackend::Raft => {
+                    // Node `node_idx` reaches peer `peer_idx` only through
+                    // proxies[node_idx][peer_idx], so the links can be faulted
+                    // in each direction separately.
+                    let peers = node_proxies
+                        .iter()
+                        .enumerate()
+                        .filter(|(peer_idx, _)| *peer_idx != node_idx)
+                        .map(|(peer_idx, proxy)| RaftPeer {
+                            id: raft_node_id(peer_idx),
+                            addr: ([127, 0, 0, 1], proxy.listen_port()).into(),
+                        })
+                        .collect();
+                    node_config.raft_leader_lock = Some(RaftLeaderLockConfig {
+                        node_id: raft_node_id(node_idx),
+                        listen_addr: ([127, 0, 0, 1], raft_ports[node_idx]).into(),
+                        peers,
+                        data_dir: tmp_dir.path().join("raft"),
+                        shared_secret: Secret::new("chaos-test".to_string()),
+                        election_timeout: Duration::from_secs(1),
+                        heartbeat_interval: Duration::from_millis(200),
+                        rpc_timeout: Duration::from_millis(300),
+                        stream_max_len: 1000,
+                    });
+                }
+            }
             node_config.consensus_signer = SignMode::Key(Secret::new(secret.into()));
             node_config.p2p.as_mut().unwrap().bootstrap_nodes =
                 bootstrap_listeners.clone();
@@ -180,6 +229,7 @@ impl Cluster {
         std::mem::forget(bootstrap);
 
         Self {
+            backend,
             redis_servers,
             proxies,
             nodes,
@@ -222,14 +272,56 @@ impl Cluster {
             .count()
     }
 
-    /// Returns true if at least one live node can reach a quorum of Redis.
-    pub fn any_node_has_redis_quorum(&self) -> bool {
-        let quorum = self.redis_servers.len() / 2 + 1;
+    /// Count how many nodes (including itself) a given node can exchange
+    /// Raft messages with. Both nodes must be alive, and the proxies must be
+    /// healthy in both directions.
+    pub fn reachable_raft_count(&self, node_idx: usize, cleanly: bool) -> usize {
+        let link_ok = |proxy: &TcpProxy| {
+            if cleanly {
+                proxy.is_unfaulted()
+            } else {
+                proxy.is_healthy()
+            }
+        };
+        if !self.is_node_alive(node_idx) {
+            return 0;
+        }
+        (0..self.node_count)
+            .filter(|peer_idx| {
+                *peer_idx == node_idx
+                    || (self.is_node_alive(*peer_idx)
+                        && link_ok(&self.proxies[node_idx][*peer_idx])
+                        && link_ok(&self.proxies[*peer_idx][node_idx]))
+            })
+            .count()
+    }
+
+    /// Returns true if at least one live node can reach a quorum of the lease
+    /// backend: the Redis nodes, or the Raft peers.
+    pub fn any_node_has_lease_quorum(&self) -> bool {
         (0..self.node_count).any(|node_idx| {
-            self.is_node_alive(node_idx) && self.reachable_redis_count(node_idx) >= quorum
+            self.is_node_alive(node_idx) && self.has_lease_quorum(node_idx, false)
         })
     }
 
+    fn has_lease_quorum(&self, node_idx: usize, cleanly: bool) -> bool {
+        match self.backend {
+            LeaseBackend::Redis => {
+                let quorum = self.redis_servers.len() / 2 + 1;
+                let reachable = if cleanly {
+                    self.cleanly_reachable_redis_count(node_idx)
+                } else {
+                    self.reachable_redis_count(node_idx)
+                };
+                reachable >= quorum
+            }
+            LeaseBackend::Raft => {
+                let quorum = self.node_count / 2 + 1;
+                self.reachable_raft_count(node_idx, cleanly) >= quorum
+            }
+        }
+    }
+
     pub async fn stop_node(&mut self, idx: usize) {
         if let Some(handle) = self.nodes[idx].take() {
             info!("Stopping node {idx}");
@@ -268,17 +360,16 @@ impl Cluster {
     }
 
     /// Returns true if at least one node is alive, has been alive longer
-    /// than `grace_period`, and can cleanly reach a Redis quorum (no
+    /// than `grace_period`, and can cleanly reach a lease quorum (no
     /// latency/drop faults on the path). Only such a node could reasonably
     /// be expected to produce blocks.
     pub fn any_node_can_produce(&self, grace_period: Duration) -> bool {
-        let quorum = self.redis_servers.len() / 2 + 1;
         let now = Instant::now();
         (0..self.node_count).any(|idx| {
             self.is_node_alive(idx)
                 && self.node_alive_since[idx]
                     .is_some_and(|t| now.duration_since(t) >= grace_period)
-                && self.cleanly_reachable_redis_count(idx) >= quorum
+                && self.has_lease_quorum(idx, true)
         })
     }
 
@@ -339,6 +430,10 @@ async fn start_node_with_db(data_dir: &TempDir, config: &Config) -> NodeHandle {
     NodeHandle { service }
 }
 
+fn raft_node_id(node_idx: usize) -> u64 {
+    node_idx as u64 + 1
+}
+
 fn update_signing_key(config: &mut Config, key: Address) {
     let snapshot_reader = &config.snapshot_reader;
     let mut chain_config = snapshot_reader.chain_config().clone();
@@ -349,6 +444,9 @@ fn update_signing_key(config: &mut Config, key: Address) {
         ConsensusConfig::PoAV2(poa) => {
             poa.set_genesis_signing_key(key);
         }
+        ConsensusConfig::Bft(_) => {
+            // The validators of the network are defined by the chain config.
+        }
     }
     config.snapshot_reader = snapshot_reader.clone().with_chain_config(chain_config);
 }
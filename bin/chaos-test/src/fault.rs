@@ -17,6 +17,7 @@ use tracing::{
 };
 
 use crate::{
+    cli::LeaseBackend,
     cluster::Cluster,
     proxy::ProxyMode,
     timeline::{
@@ -51,6 +52,29 @@ pub enum FaultAction {
     RestoreAllProxies,
     KillNode(usize),
     RestartNode(usize),
+    /// Cuts the Raft link between two nodes in both directions
+    PartitionNodes {
+        node_idx: usize,
+        peer_idx: usize,
+    },
+    /// Cuts the Raft links from one node to its peers (one direction)
+    PartitionNodeOutbound(usize),
+    /// Cuts all Raft links of one node in both directions
+    IsolateNode(usize),
+    AddPeerLatency {
+        node_idx: usize,
+        peer_idx: usize,
+        ms: u64,
+    },
+    DropPeerMidOperation {
+        node_idx: usize,
+        peer_idx: usize,
+        after_bytes: usize,
+    },
+    RestorePeerLink {
+        node_idx: usize,
+        peer_idx: usize,
+    },
 }
 
 impl fmt::Display for FaultAction {
@@ -98,6 +122,33 @@ impl fmt::Display for FaultAction {
             FaultAction::RestoreAllProxies => write!(f, "Restore all proxies"),
             FaultAction::KillNode(idx) => write!(f, "Kill node {idx}"),
             FaultAction::RestartNode(idx) => write!(f, "Restart node {idx}"),
+            FaultAction::PartitionNodes { node_idx, peer_idx } => {
+                write!(f, "Partition node {node_idx} from node {peer_idx}")
+            }
+            FaultAction::PartitionNodeOutbound(idx) => {
+                write!(f, "Partition outbound Raft links of node {idx}")
+            }
+            FaultAction::IsolateNode(idx) => write!(f, "Isolate node {idx}"),
+            FaultAction::AddPeerLatency {
+                node_idx,
+                peer_idx,
+                ms,
+            } => {
+                write!(f, "Add {ms}ms latency: node {node_idx} -> node {peer_idx}")
+            }
+            FaultAction::DropPeerMidOperation {
+                node_idx,
+                peer_idx,
+                after_bytes,
+            } => {
+                write!(
+                    f,
+                    "Drop after {after_bytes}B: node {node_idx} -> node {peer_idx}"
+                )
+            }
+            FaultAction::RestorePeerLink { node_idx, peer_idx } => {
+                write!(f, "Restore link: node {node_idx} <-> node {peer_idx}")
+            }
         }
     }
 }
@@ -109,6 +160,7 @@ struct PendingRecovery {
 
 pub struct FaultScheduler {
     rng: StdRng,
+    backend: LeaseBackend,
     fault_interval: Duration,
     node_count: usize,
     redis_count: usize,
@@ -118,12 +170,14 @@ pub struct FaultScheduler {
 impl FaultScheduler {
     pub fn new(
         seed: u64,
+        backend: LeaseBackend,
         fault_interval: Duration,
         node_count: usize,
         redis_count: usize,
     ) -> Self {
         Self {
             rng: StdRng::seed_from_u64(seed),
+            backend,
             fault_interval,
             node_count,
             redis_count,
@@ -189,6 +243,10 @@ impl FaultScheduler {
     }
 
     fn pick_fault(&mut self, cluster: &Cluster) -> Option<FaultAction> {
+        if self.backend == LeaseBackend::Raft {
+            return self.pick_raft_fault(cluster);
+        }
+
         // Weighted random selection
         let roll: f64 = self.rng.r#gen();
 
@@ -216,6 +274,72 @@ impl FaultScheduler {
         }
     }
 
+    fn pick_raft_fault(&mut self, cluster: &Cluster) -> Option<FaultAction> {
+        // Weighted random selection
+        let roll: f64 = self.rng.r#gen();
+
+        if roll < 0.30 {
+            // Network partition between nodes (30%)
+            self.pick_node_partition_fault(cluster)
+        } else if roll < 0.50 {
+            // Latency on a Raft link (20%)
+            let (node_idx, peer_idx) = self.pick_peer_link(cluster)?;
+            let ms = self.rng.gen_range(50..500);
+            Some(FaultAction::AddPeerLatency {
+                node_idx,
+                peer_idx,
+                ms,
+            })
+        } else if roll < 0.65 {
+            // Mid-operation drop on a Raft link (15%)
+            let (node_idx, peer_idx) = self.pick_peer_link(cluster)?;
+            let after_bytes = self.rng.gen_range(10..500);
+            Some(FaultAction::DropPeerMidOperation {
+                node_idx,
+                peer_idx,
+                after_bytes,
+            })
+        } else if roll < 0.85 {
+            // Node kill/restart (20%)
+            self.pick_node_fault(cluster)
+        } else if roll < 0.95 {
+            // Restore single link (10%)
+            let node_idx = self.rng.gen_range(0..self.node_count);
+            let peer_idx = self.rng.gen_range(0..self.node_count);
+            Some(FaultAction::RestorePeerLink { node_idx, peer_idx })
+        } else {
+            // Restore all (5%)
+            Some(FaultAction::RestoreAllProxies)
+        }
+    }
+
+    fn pick_peer_link(&mut self, cluster: &Cluster) -> Option<(usize, usize)> {
+        let node_idx = self.rng.gen_range(0..self.node_count);
+        let peer_idx = self.rng.gen_range(0..self.node_count);
+        if node_idx == peer_idx || !cluster.is_node_alive(node_idx) {
+            return None;
+        }
+        Some((node_idx, peer_idx))
+    }
+
+    fn pick_node_partition_fault(&mut self, cluster: &Cluster) -> Option<FaultAction> {
+        let roll: f64 = self.rng.r#gen();
+        if roll < 0.3 {
+            let node_idx = self.rng.gen_range(0..self.node_count);
+            cluster
+                .is_node_alive(node_idx)
+                .then_some(FaultAction::IsolateNode(node_idx))
+        } else if roll < 0.5 {
+            let node_idx = self.rng.gen_range(0..self.node_count);
+            cluster
+                .is_node_alive(node_idx)
+                .then_some(FaultAction::PartitionNodeOutbound(node_idx))
+        } else {
+            let (node_idx, peer_idx) = self.pick_peer_link(cluster)?;
+            Some(FaultAction::PartitionNodes { node_idx, peer_idx })
+        }
+    }
+
     fn pick_partition_fault(&mut self, cluster: &Cluster) -> Option<FaultAction> {
         if self.rng.gen_bool(0.3) {
             // Partition all nodes from one Redis
@@ -286,10 +410,15 @@ impl FaultScheduler {
         let node_idx = self.rng.gen_range(0..self.node_count);
 
         if cluster.is_node_alive(node_idx) {
-            // Don't kill the last alive node
+            // Don't kill the last alive node. With the Raft backend, the nodes
+            // themselves are the quorum, so keep the majority alive.
             let alive_nodes = cluster.alive_node_count();
-            if alive_nodes <= 1 {
-                warn!("Skipping node kill: only 1 node alive");
+            let min_alive = match self.backend {
+                LeaseBackend::Redis => 1,
+                LeaseBackend::Raft => self.node_count / 2 + 1,
+            };
+            if alive_nodes <= min_alive {
+                warn!("Skipping node kill: only {alive_nodes} nodes alive");
                 return None;
             }
             Some(FaultAction::KillNode(node_idx))
@@ -321,7 +450,10 @@ impl FaultScheduler {
             FaultAction::KillRedis(_)
             | FaultAction::KillNode(_)
             | FaultAction::PartitionNodeFromRedis { .. }
-            | FaultAction::PartitionAllFromRedis(_) => {
+            | FaultAction::PartitionAllFromRedis(_)
+            | FaultAction::PartitionNodes { .. }
+            | FaultAction::PartitionNodeOutbound(_)
+            | FaultAction::IsolateNode(_) => {
                 let recovery_delay = Duration::from_secs(self.rng.gen_range(3..=10));
                 let recovery_action = match &action {
                     FaultAction::KillRedis(idx) => FaultAction::RestartRedis(*idx),
@@ -333,8 +465,14 @@ impl FaultScheduler {
                         node_idx: *node_idx,
                         redis_idx: *redis_idx,
                     },
-                    FaultAction::PartitionAllFromRedis(_) => {
-                        FaultAction::RestoreAllProxies
+                    FaultAction::PartitionAllFromRedis(_)
+                    | FaultAction::PartitionNodeOutbound(_)
+                    | FaultAction::IsolateNode(_) => FaultAction::RestoreAllProxies,
+                    FaultAction::PartitionNodes { node_idx, peer_idx } => {
+                        FaultAction::RestorePeerLink {
+                            node_idx: *node_idx,
+                            peer_idx: *peer_idx,
+                        }
                     }
                     _ => unreachable!(),
                 };
@@ -349,12 +487,12 @@ impl FaultScheduler {
         let mut cluster_guard = cluster.lock().await;
         self.apply_action(&mut cluster_guard, &action).await;
 
-        // Safety check: if no live node can reach a Redis quorum after
+        // Safety check: if no live node can reach a lease quorum after
         // applying this fault, undo it immediately. This prevents the
         // scheduler from creating scenarios where block production is
         // impossible (which would cause expected but uninformative stalls).
-        if !cluster_guard.any_node_has_redis_quorum() {
-            warn!("Fault '{action}' broke Redis quorum for all nodes — reverting");
+        if !cluster_guard.any_node_has_lease_quorum() {
+            warn!("Fault '{action}' broke lease quorum for all nodes — reverting");
             self.undo_action(&mut cluster_guard, &action).await;
             self.pending_recoveries.pop_back(); // remove scheduled recovery
         }
@@ -416,9 +554,59 @@ impl FaultScheduler {
             FaultAction::RestartNode(idx) => {
                 cluster.restart_node(*idx).await;
             }
+            FaultAction::PartitionNodes { node_idx, peer_idx } => {
+                self.set_link_mode(cluster, *node_idx, *peer_idx, ProxyMode::DropAll);
+            }
+            FaultAction::PartitionNodeOutbound(node_idx) => {
+                for peer_idx in 0..self.node_count {
+                    cluster.set_proxy_mode(*node_idx, peer_idx, ProxyMode::DropAll);
+                }
+            }
+            FaultAction::IsolateNode(node_idx) => {
+                for peer_idx in 0..self.node_count {
+                    self.set_link_mode(cluster, *node_idx, peer_idx, ProxyMode::DropAll);
+                }
+            }
+            FaultAction::AddPeerLatency {
+                node_idx,
+                peer_idx,
+                ms,
+            } => {
+                cluster.set_proxy_mode(
+                    *node_idx,
+                    *peer_idx,
+                    ProxyMode::Latency(Duration::from_millis(*ms)),
+                );
+            }
+            FaultAction::DropPeerMidOperation {
+                node_idx,
+                peer_idx,
+                after_bytes,
+            } => {
+                cluster.set_proxy_mode(
+                    *node_idx,
+                    *peer_idx,
+                    ProxyMode::CloseAfterBytes(*after_bytes),
+                );
+            }
+            FaultAction::RestorePeerLink { node_idx, peer_idx } => {
+                self.set_link_mode(cluster, *node_idx, *peer_idx, ProxyMode::Normal);
+            }
         }
     }
 
+    /// Sets the mode of the Raft link between two nodes in both directions.
+    fn set_link_mode(
+        &self,
+        cluster: &Cluster,
+        node_idx: usize,
+        peer_idx: usize,
+        mode: ProxyMode,
+    ) {
+        cluster.set_proxy_mode(node_idx, peer_idx, mode.clone());
+        cluster.set_proxy_mode(peer_idx, node_idx, mode);
+    }
+
     /// Undo a fault action (best-effort inverse).
     async fn undo_action(&self, cluster: &mut Cluster, action: &FaultAction) {
         match action {
@@ -451,6 +639,27 @@ impl FaultScheduler {
             FaultAction::KillNode(idx) => {
                 cluster.restart_node(*idx).await;
             }
+            FaultAction::PartitionNodes { node_idx, peer_idx } => {
+                self.set_link_mode(cluster, *node_idx, *peer_idx, ProxyMode::Normal);
+            }
+            FaultAction::PartitionNodeOutbound(node_idx) => {
+                for peer_idx in 0..self.node_count {
+                    cluster.set_proxy_mode(*node_idx, peer_idx, ProxyMode::Normal);
+                }
+            }
+            FaultAction::IsolateNode(node_idx) => {
+                for peer_idx in 0..self.node_count {
+                    self.set_link_mode(cluster, *node_idx, peer_idx, ProxyMode::Normal);
+                }
+            }
+            FaultAction::AddPeerLatency {
+                node_idx, peer_idx, ..
+            }
+            | FaultAction::DropPeerMidOperation {
+                node_idx, peer_idx, ..
+            } => {
+                cluster.set_proxy_mode(*node_idx, *peer_idx, ProxyMode::Normal);
+            }
             // Recovery actions don't need undoing
             _ => {}
         }
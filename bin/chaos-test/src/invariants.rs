@@ -21,6 +21,7 @@ use tracing::{
 };
 
 use crate::{
+    cli::LeaseBackend,
     cluster::Cluster,
     timeline::{
         Timeline,
@@ -280,6 +281,10 @@ pub fn spawn_block_readers(
 /// where height and epoch are numeric strings, and block_bytes is raw postcard.
 /// We read entries as `(String, Vec<(Vec<u8>, Vec<u8>)>)` to handle binary values.
 async fn dump_redis_streams(cluster: &Cluster, seed: u64) {
+    if cluster.backend == LeaseBackend::Raft {
+        info!("=== Block stream is replicated by Raft, no Redis stream to dump ===");
+        return;
+    }
     let stream_key = format!("chaos-test:leader:{seed}:block:stream");
     info!("=== REDIS STREAM DUMP (key: {stream_key}) ===");
 
@@ -47,18 +47,26 @@ async fn main() -> anyhow::Result<()> {
     info!("  Seed: {seed}");
     info!("  Duration: {:?}", *cli.duration);
     info!("  Nodes: {}", cli.nodes);
+    info!("  Lease backend: {:?}", cli.lease_backend);
     info!("  Redis nodes: {}", cli.redis_nodes);
     info!("  Block time: {:?}", *cli.block_time);
     info!("  Fault interval: {:?}", *cli.fault_interval);
 
     let timeline = Timeline::new(seed);
     timeline.record(TimelineEventKind::Info(format!(
-        "Test started with seed={seed}, nodes={}, redis={}, block_time={:?}, fault_interval={:?}",
-        cli.nodes, cli.redis_nodes, *cli.block_time, *cli.fault_interval
+        "Test started with seed={seed}, backend={:?}, nodes={}, redis={}, block_time={:?}, fault_interval={:?}",
+        cli.lease_backend, cli.nodes, cli.redis_nodes, *cli.block_time, *cli.fault_interval
     )));
 
     // Create cluster
-    let cluster = Cluster::new(cli.nodes, cli.redis_nodes, *cli.block_time, seed).await;
+    let cluster = Cluster::new(
+        cli.nodes,
+        cli.redis_nodes,
+        *cli.block_time,
+        seed,
+        cli.lease_backend,
+    )
+    .await;
     let cluster = Arc::new(Mutex::new(cluster));
 
     // Wait for initial leader election
@@ -145,10 +153,16 @@ async fn main() -> anyhow::Result<()> {
     let fault_timeline = timeline.clone();
     let node_count = cli.nodes;
     let redis_count = cli.redis_nodes;
+    let lease_backend = cli.lease_backend;
     let fault_interval = *cli.fault_interval;
     let fault_handle = tokio::spawn(async move {
-        let scheduler =
-            FaultScheduler::new(seed, fault_interval, node_count, redis_count);
+        let scheduler = FaultScheduler::new(
+            seed,
+            lease_backend,
+            fault_interval,
+            node_count,
+            redis_count,
+        );
         scheduler
             .run(fault_cluster, fault_timeline, fault_stop_rx)
             .await;
@@ -8,7 +8,11 @@ use crate::{
         run::{
             consensus::PoATriggerArgs,
             graphql::GraphQLArgs,
-            tx_pool::TxPoolArgs,
+            tx_pool::{
+                ReplaceableInputArg,
+                SelectionPolicyArg,
+                TxPoolArgs,
+            },
             tx_status_manager::TxStatusManagerArgs,
         },
     },
@@ -23,6 +27,7 @@ use fuel_core::{
     },
     fuel_core_graphql_api::{
         Costs,
+        RateLimitConfig,
         ServiceConfig as GraphQLConfig,
     },
     producer::Config as ProducerConfig,
@@ -41,7 +46,12 @@ use fuel_core::{
         BlackList,
         Config as TxPoolConfig,
         HeavyWorkConfig,
+        JournalConfig,
+        LanesConfig,
         PoolLimits,
+        ReplaceableInput,
+        ReplacementPolicy,
+        SelectionPolicy,
         ServiceChannelLimits,
     },
     types::{
@@ -124,6 +134,8 @@ mod preconfirmation_signature_service;
 mod profiling;
 #[cfg(feature = "relayer")]
 mod relayer;
+#[cfg(feature = "remote-signer")]
+mod remote_signer;
 mod tx_pool;
 mod tx_status_manager;
 
@@ -212,6 +224,24 @@ pub struct Command {
     #[arg(long = "expensive-subscriptions", env)]
     pub expensive_subscriptions: bool,
 
+    /// Indexes `Log` and `LogData` receipts by the contract id and the log id
+    /// and enables the `logs` GraphQL query and subscription.
+    /// Only blocks processed while the flag is enabled are indexed.
+    #[arg(long = "logs-indexation", env)]
+    pub logs_indexation: bool,
+
+    /// Indexes the gas price, the gas used and the tips paid in each block
+    /// and enables the `feeHistory` GraphQL query.
+    /// Only blocks processed while the flag is enabled are indexed.
+    #[arg(long = "fee-history-indexation", env)]
+    pub fee_history_indexation: bool,
+
+    /// Enables the GraphQL API to manage the p2p peers at runtime:
+    /// add and remove reserved peers, ban, unban and disconnect peers,
+    /// and inspect their reputation.
+    #[arg(long = "peer-admin-api", env)]
+    pub peer_admin_api: bool,
+
     /// Enable logging of backtraces from vm errors
     #[arg(long = "vm-backtrace", env)]
     #[deprecated]
@@ -253,6 +283,11 @@ pub struct Command {
     #[cfg(feature = "aws-kms")]
     pub consensus_aws_kms: Option<String>,
 
+    /// The cli arguments of the remote signing service used for signing blocks.
+    #[clap(flatten)]
+    #[cfg(feature = "remote-signer")]
+    pub remote_signer_args: remote_signer::RemoteSignerArgs,
+
     /// If given, the node will produce and store da-compressed blocks
     /// with the given retention time.
     #[arg(long = "da-compression", env)]
@@ -362,6 +397,9 @@ impl Command {
             historical_execution,
             allow_syscall,
             expensive_subscriptions,
+            logs_indexation,
+            fee_history_indexation,
+            peer_admin_api,
             utxo_validation,
             native_executor_version,
             #[cfg(feature = "parallel-executor")]
@@ -370,6 +408,8 @@ impl Command {
             consensus_key,
             #[cfg(feature = "aws-kms")]
             consensus_aws_kms,
+            #[cfg(feature = "remote-signer")]
+            remote_signer_args,
             da_compression,
             da_compression_starting_height,
             poa_trigger,
@@ -467,6 +507,7 @@ impl Command {
         let rpc_config = rpc_args.map(|args| args.into_config());
 
         let leader_lock = poa_trigger.leader_lock()?;
+        let raft_leader_lock = poa_trigger.raft_leader_lock()?;
         let trigger: Trigger = poa_trigger.into();
 
         if trigger != Trigger::Never {
@@ -498,6 +539,22 @@ impl Command {
             };
         }
 
+        #[cfg(feature = "remote-signer")]
+        if let Some(config) = remote_signer_args.into_config()? {
+            if matches!(consensus_signer, SignMode::Unavailable) {
+                // Request the public key, and ensure that the signing service
+                // is accessible and knows the key.
+                let signer =
+                    fuel_core_types::signer::remote::RemoteSigner::connect(config)
+                        .await?;
+                consensus_signer = SignMode::Remote(signer);
+            } else {
+                anyhow::bail!(
+                    "Only one of AWS KMS and the remote signer can be used for signing blocks"
+                );
+            }
+        }
+
         if matches!(consensus_signer, SignMode::Unavailable) {
             if let Some(consensus_key) = consensus_key {
                 let key = SecretKey::from_str(&consensus_key)
@@ -610,7 +667,11 @@ impl Command {
             tx_max_number,
             tx_max_total_bytes,
             tx_max_total_gas,
+            tx_max_number_per_owner,
+            tx_max_total_gas_per_owner,
+            tx_max_total_bytes_per_owner,
             tx_max_chain_count,
+            tx_max_bundle_size,
             tx_blacklist_addresses,
             tx_blacklist_coins,
             tx_blacklist_messages,
@@ -623,6 +684,16 @@ impl Command {
             tx_max_pending_write_requests,
             tx_pending_pool_ttl,
             tx_pending_pool_size_percentage,
+            tx_max_maturity_lookahead,
+            tx_future_pool_size_percentage,
+            tx_journal_path,
+            tx_journal_rotation_interval,
+            tx_selection_policy,
+            tx_priority_addresses,
+            tx_priority_lane_gas_percentage,
+            tx_rbf_min_tip_bump_percentage,
+            tx_rbf_max_evicted_transactions,
+            tx_rbf_non_replaceable_inputs,
         } = tx_pool;
 
         let TxStatusManagerArgs {
@@ -643,6 +714,12 @@ impl Command {
             max_bytes_size: tx_max_total_bytes,
         };
 
+        let per_owner_limits = PoolLimits {
+            max_txs: tx_max_number_per_owner.unwrap_or(tx_max_number),
+            max_gas: tx_max_total_gas_per_owner.unwrap_or(tx_max_total_gas),
+            max_bytes_size: tx_max_total_bytes_per_owner.unwrap_or(tx_max_total_bytes),
+        };
+
         let pool_heavy_work_config = HeavyWorkConfig {
             number_threads_to_verify_transactions:
                 tx_number_threads_to_verify_transactions,
@@ -651,6 +728,36 @@ impl Command {
             size_of_p2p_sync_queue: tx_size_of_p2p_sync_queue,
         };
 
+        let journal = tx_journal_path.map(|path| JournalConfig {
+            path,
+            rotation_interval: tx_journal_rotation_interval.into(),
+        });
+
+        let selection_policy = match tx_selection_policy {
+            SelectionPolicyArg::RatioTipGas => SelectionPolicy::RatioTipGas,
+            SelectionPolicyArg::Fifo => SelectionPolicy::Fifo,
+            SelectionPolicyArg::Lanes => SelectionPolicy::Lanes(LanesConfig {
+                priority_addresses: tx_priority_addresses.into_iter().collect(),
+                reserved_gas_percentage: tx_priority_lane_gas_percentage,
+            }),
+        };
+
+        let replacement_policy = ReplacementPolicy {
+            min_tip_bump_percentage: tx_rbf_min_tip_bump_percentage,
+            max_evicted_transactions: tx_rbf_max_evicted_transactions,
+            non_replaceable_inputs: tx_rbf_non_replaceable_inputs
+                .into_iter()
+                .map(|input| match input {
+                    ReplaceableInputArg::Coin => ReplaceableInput::Coin,
+                    ReplaceableInputArg::Message => ReplaceableInput::Message,
+                    ReplaceableInputArg::ContractCreation => {
+                        ReplaceableInput::ContractCreation
+                    }
+                    ReplaceableInputArg::Blob => ReplaceableInput::Blob,
+                })
+                .collect(),
+        };
+
         let service_channel_limits = ServiceChannelLimits {
             max_pending_read_pool_requests: tx_max_pending_read_requests,
             max_pending_write_pool_requests: tx_max_pending_write_requests,
@@ -698,6 +805,7 @@ impl Command {
                     .max_queries_resolver_recursive_depth,
                 max_queries_directives: graphql.max_queries_directives,
                 max_concurrent_queries: graphql.graphql_max_concurrent_queries,
+                max_websocket_connections: graphql.graphql_max_websocket_connections,
                 request_body_bytes_limit: graphql.graphql_request_body_bytes_limit,
                 api_request_timeout: graphql.api_request_timeout.into(),
                 assemble_tx_dry_run_limit: graphql.assemble_tx_dry_run_limit,
@@ -712,6 +820,7 @@ impl Command {
                     assemble_tx: graphql.costs.assemble_tx,
                     dry_run: graphql.costs.dry_run,
                     storage_read_replay: graphql.costs.storage_read_replay,
+                    transaction_trace: graphql.costs.transaction_trace,
                     submit: graphql.costs.submit,
                     submit_and_await: graphql.costs.submit_and_await,
                     status_change: graphql.costs.status_change,
@@ -728,18 +837,29 @@ impl Command {
                         .costs
                         .state_transition_bytecode_read,
                     da_compressed_block_read: graphql.costs.da_compressed_block_read,
+                    consensus_status: graphql.costs.consensus_status,
                 },
                 required_fuel_block_height_tolerance: graphql
                     .required_fuel_block_height_tolerance,
                 required_fuel_block_height_timeout: graphql
                     .required_fuel_block_height_timeout
                     .into(),
+                rate_limit: RateLimitConfig {
+                    window: graphql.rate_limit_window.into(),
+                    ip_complexity_per_window: graphql.ip_complexity_per_window,
+                    api_key_complexity_per_window: graphql.api_key_complexity_per_window,
+                    max_tracked_clients: graphql.rate_limit_max_tracked_clients,
+                },
+                api_keys_path: graphql.api_keys_path,
             },
             combined_db_config,
             snapshot_reader,
             debug,
             historical_execution,
             expensive_subscriptions,
+            logs_indexation,
+            fee_history_indexation,
+            peer_admin_api,
             native_executor_version,
             continue_on_error,
             allow_syscall,
@@ -748,6 +868,7 @@ impl Command {
             executor_number_of_cores,
             block_production: trigger,
             leader_lock,
+            raft_leader_lock,
             predefined_blocks_path,
             txpool: TxPoolConfig {
                 max_txs_chain_count: tx_max_chain_count,
@@ -757,12 +878,20 @@ impl Command {
                 allow_syscall,
                 black_list,
                 pool_limits,
+                per_owner_limits,
                 heavy_work: pool_heavy_work_config,
                 service_channel_limits,
                 pending_pool_tx_ttl: tx_pending_pool_ttl.into(),
                 max_pending_pool_size_percentage: tx_pending_pool_size_percentage,
+                max_maturity_lookahead: tx_max_maturity_lookahead,
+                max_future_pool_size_percentage: tx_future_pool_size_percentage,
                 metrics: metrics.is_enabled(Module::TxPool),
+                journal,
+                selection_policy,
+                max_bundle_size: tx_max_bundle_size,
+                replacement_policy,
             },
+            txpool_admission_filter: Default::default(),
             block_producer: ProducerConfig {
                 coinbase_recipient,
                 metrics: metrics.is_enabled(Module::Producer),
@@ -778,6 +907,8 @@ impl Command {
             sync: sync_args.into(),
             #[cfg(feature = "p2p")]
             pre_confirmation_signature_service: preconfirmation_signature_service_config,
+            #[cfg(feature = "p2p")]
+            bft_timeouts: Default::default(),
             #[cfg(feature = "shared-sequencer")]
             shared_sequencer: shared_sequencer_args.try_into()?,
             consensus_signer,
@@ -7,10 +7,17 @@ use clap::{
     ValueEnum,
 };
 use fuel_core::service::config::{
+    RaftLeaderLockConfig,
+    RaftPeer,
     RedisLeaderLockConfig,
     Trigger as PoATrigger,
 };
+use fuel_core_types::secrecy::Secret;
 use humantime::Duration;
+use std::{
+    net::SocketAddr,
+    path::PathBuf,
+};
 
 #[derive(Debug, Clone, clap::Args)]
 pub struct PoATriggerArgs {
@@ -21,7 +28,11 @@ pub struct PoATriggerArgs {
     #[clap(flatten)]
     open: Open,
     #[clap(flatten)]
+    hybrid: Hybrid,
+    #[clap(flatten)]
     leader_lock: LeaderLock,
+    #[clap(flatten)]
+    raft_leader_lock: RaftLeaderLock,
 }
 
 impl PoATriggerArgs {
@@ -58,6 +69,52 @@ impl PoATriggerArgs {
             Ok(None)
         }
     }
+
+    pub fn raft_leader_lock(&self) -> anyhow::Result<Option<RaftLeaderLockConfig>> {
+        let RaftLeaderLock {
+            enabled,
+            node_id,
+            listen_addr,
+            peers,
+            data_dir,
+            secret,
+            election_timeout,
+            heartbeat_interval,
+            rpc_timeout,
+            stream_max_len,
+        } = self.raft_leader_lock.clone();
+        if enabled {
+            Ok(Some(RaftLeaderLockConfig {
+                node_id: node_id
+                    .ok_or(anyhow!("`node_id` is required when `enabled` is true"))?,
+                listen_addr: listen_addr
+                    .ok_or(anyhow!("`listen_addr` is required when `enabled` is true"))?,
+                peers,
+                data_dir: data_dir
+                    .ok_or(anyhow!("`data_dir` is required when `enabled` is true"))?,
+                shared_secret: Secret::new(
+                    secret
+                        .ok_or(anyhow!("`secret` is required when `enabled` is true"))?,
+                ),
+                election_timeout: election_timeout.into(),
+                heartbeat_interval: heartbeat_interval.into(),
+                rpc_timeout: rpc_timeout.into(),
+                stream_max_len,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+fn parse_raft_peer(value: &str) -> anyhow::Result<RaftPeer> {
+    let (id, addr) = value.split_once('=').ok_or(anyhow!(
+        "Expected the Raft peer in the `<id>=<address>` format"
+    ))?;
+    Ok(RaftPeer {
+        id: id.parse()?,
+        addr: addr.parse()?,
+    })
 }
 
 // Convert from arg struct to PoATrigger enum
@@ -68,6 +125,19 @@ impl From<PoATriggerArgs> for PoATrigger {
                 open: Open { period: Some(p) },
                 ..
             } => PoATrigger::Open { period: p.into() },
+            PoATriggerArgs {
+                hybrid:
+                    Hybrid {
+                        hybrid_period: Some(p),
+                        fill_threshold,
+                        max_idle_time,
+                    },
+                ..
+            } => PoATrigger::Hybrid {
+                block_time: p.into(),
+                fill_threshold_percent: fill_threshold,
+                max_idle_time: max_idle_time.map(Into::into),
+            },
             PoATriggerArgs {
                 interval:
                     Interval {
@@ -87,7 +157,7 @@ impl From<PoATriggerArgs> for PoATrigger {
 
 #[derive(Debug, Clone, clap::Args)]
 #[clap(
-    group = ArgGroup::new("instant-mode").args(&["instant"]).conflicts_with_all(&["interval-mode", "open-mode"]),
+    group = ArgGroup::new("instant-mode").args(&["instant"]).conflicts_with_all(&["interval-mode", "open-mode", "hybrid-mode"]),
 )]
 struct Instant {
     /// Use instant block production mode.
@@ -105,7 +175,7 @@ enum Boolean {
 
 #[derive(Debug, Clone, clap::Args)]
 #[clap(
-    group = ArgGroup::new("interval-mode").args(&["block_time"]).conflicts_with_all(&["instant-mode", "open-mode"]),
+    group = ArgGroup::new("interval-mode").args(&["block_time"]).conflicts_with_all(&["instant-mode", "open-mode", "hybrid-mode"]),
 )]
 struct Interval {
     /// Interval trigger option.
@@ -117,7 +187,7 @@ struct Interval {
 
 #[derive(Debug, Clone, clap::Args)]
 #[clap(
-    group = ArgGroup::new("open-mode").args(&["period"]).conflicts_with_all(&["instant-mode", "interval-mode"]),
+    group = ArgGroup::new("open-mode").args(&["period"]).conflicts_with_all(&["instant-mode", "interval-mode", "hybrid-mode"]),
 )]
 struct Open {
     /// Opens the block production immediately and keeps it open for the specified period.
@@ -127,6 +197,33 @@ struct Open {
     pub period: Option<Duration>,
 }
 
+#[derive(Debug, Clone, clap::Args)]
+#[clap(
+    group = ArgGroup::new("hybrid-mode").args(&["hybrid_period"]).conflicts_with_all(&["instant-mode", "interval-mode", "open-mode"]),
+)]
+struct Hybrid {
+    /// Hybrid trigger option.
+    /// Produces a block as soon as the pending transactions fill the block up to
+    /// the fill threshold, otherwise produces a block at the end of the period.
+    /// Cannot be combined with other poa flags.
+    #[clap(long = "poa-hybrid-period", env)]
+    pub hybrid_period: Option<Duration>,
+    /// The percentage of the block gas or transactions limit that the pending
+    /// transactions should use to trigger the block production before the end of the period.
+    #[clap(
+        long = "poa-hybrid-fill-threshold",
+        env,
+        default_value_t = 50,
+        value_parser = clap::value_parser!(u8).range(1..=100),
+        requires = "hybrid_period"
+    )]
+    pub fill_threshold: u8,
+    /// The maximum time without blocks when the txpool is empty.
+    /// If not set, empty blocks are produced at the end of each period.
+    #[clap(long = "poa-hybrid-max-idle-time", env, requires = "hybrid_period")]
+    pub max_idle_time: Option<Duration>,
+}
+
 #[derive(Debug, Clone, clap::Args)]
 struct LeaderLock {
     /// Enable distributed leader lock for PoA producers.
@@ -176,11 +273,66 @@ struct LeaderLock {
     quorum_disruption_budget: u32,
 }
 
+#[derive(Debug, Clone, clap::Args)]
+struct RaftLeaderLock {
+    /// Enable the leader lock replicated by the embedded Raft log between PoA producers.
+    /// Cannot be combined with the Redis leader lock.
+    #[clap(
+        id = "raft_enabled",
+        long = "poa-raft-leader-lock",
+        env,
+        default_value_t = false,
+        requires_all = ["node_id", "listen_addr", "data_dir", "raft_secret"],
+        conflicts_with = "enabled"
+    )]
+    enabled: bool,
+    /// The identifier of this producer in the Raft cluster. Must be unique.
+    #[clap(long = "poa-raft-node-id", env)]
+    node_id: Option<u64>,
+    /// The address where the Raft peers connect to this producer.
+    #[clap(long = "poa-raft-listen-addr", env)]
+    listen_addr: Option<SocketAddr>,
+    /// Other producers of the Raft cluster in the `<id>=<address>` format.
+    /// Multiple values can be provided by repeating the arg.
+    #[clap(
+        long = "poa-raft-peer",
+        env,
+        value_delimiter = ',',
+        value_parser = parse_raft_peer
+    )]
+    peers: Vec<RaftPeer>,
+    /// The directory for the Raft log.
+    #[clap(long = "poa-raft-data-dir", env)]
+    data_dir: Option<PathBuf>,
+    /// The secret shared by the producers of the Raft cluster. Peers authenticate
+    /// each other with it. Prefer passing it via the ENV var.
+    #[clap(id = "raft_secret", long = "poa-raft-secret", env = "POA_RAFT_SECRET")]
+    secret: Option<String>,
+    /// The time without the leader after which a producer starts the election.
+    #[clap(long = "poa-raft-election-timeout", env, default_value = "1s")]
+    election_timeout: Duration,
+    /// The interval between the heartbeats of the leader.
+    #[clap(long = "poa-raft-heartbeat-interval", env, default_value = "200ms")]
+    heartbeat_interval: Duration,
+    /// Timeout per Raft request to a peer.
+    #[clap(long = "poa-raft-rpc-timeout", env, default_value = "500ms")]
+    rpc_timeout: Duration,
+    /// Maximum number of blocks retained in the replicated block stream.
+    #[clap(
+        id = "raft_stream_max_len",
+        long = "poa-raft-stream-max-len",
+        env,
+        default_value_t = 1000
+    )]
+    stream_max_len: u32,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use clap::Parser;
     use fuel_core::service::config::Trigger;
+    use fuel_core_types::secrecy::ExposeSecret;
     use std::time::Duration as StdDuration;
     use test_case::test_case;
 
@@ -194,6 +346,12 @@ mod tests {
     #[test_case(&["", "--poa-instant=false"] => Ok(Trigger::Never); "never trigger if instant is explicitly disabled")]
     #[test_case(&["", "--poa-interval-period=1s"] => Ok(Trigger::Interval { block_time: StdDuration::from_secs(1)}); "uses interval mode if set")]
     #[test_case(&["", "--poa-open-period=1s"] => Ok(Trigger::Open { period: StdDuration::from_secs(1)}); "uses open mode if set")]
+    #[test_case(&["", "--poa-hybrid-period=1s"] => Ok(Trigger::Hybrid { block_time: StdDuration::from_secs(1), fill_threshold_percent: 50, max_idle_time: None }); "uses hybrid mode if set")]
+    #[test_case(&["", "--poa-hybrid-period=1s", "--poa-hybrid-fill-threshold=80", "--poa-hybrid-max-idle-time=10s"] => Ok(Trigger::Hybrid { block_time: StdDuration::from_secs(1), fill_threshold_percent: 80, max_idle_time: Some(StdDuration::from_secs(10)) }); "uses hybrid mode with custom threshold and idle time")]
+    #[test_case(&["", "--poa-hybrid-period=1s", "--poa-hybrid-fill-threshold=0"] => Err(()); "can't set zero fill threshold")]
+    #[test_case(&["", "--poa-hybrid-period=1s", "--poa-hybrid-fill-threshold=101"] => Err(()); "can't set fill threshold above hundred percent")]
+    #[test_case(&["", "--poa-hybrid-max-idle-time=10s"] => Err(()); "can't set max idle time without hybrid period")]
+    #[test_case(&["", "--poa-hybrid-period=1s", "--poa-interval-period=1s"] => Err(()); "can't set hybrid and interval at the same time")]
     #[test_case(&["", "--poa-instant=true", "--poa-interval-period=1s"] => Err(()); "can't set interval and instant at the same time")]
     #[test_case(&["", "--poa-open-period=1s", "--poa-interval-period=1s"] => Err(()); "can't set open and interval at the same time")]
     fn parse(args: &[&str]) -> Result<Trigger, ()> {
@@ -267,4 +425,77 @@ mod tests {
         let leader_lock = command.trigger.clone().leader_lock().unwrap().unwrap();
         assert_eq!(leader_lock.quorum_disruption_budget, 2);
     }
+
+    #[test]
+    fn raft_leader_lock__defaults_to_none() {
+        let command = Command::try_parse_from([""]).unwrap();
+        assert!(
+            command
+                .trigger
+                .clone()
+                .raft_leader_lock()
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn raft_leader_lock__when_all_args_set_then_some() {
+        let command = Command::try_parse_from([
+            "",
+            "--poa-raft-leader-lock",
+            "--poa-raft-node-id",
+            "1",
+            "--poa-raft-listen-addr",
+            "127.0.0.1:4101",
+            "--poa-raft-peer",
+            "2=127.0.0.1:4102",
+            "--poa-raft-peer",
+            "3=127.0.0.1:4103",
+            "--poa-raft-data-dir",
+            "/tmp/raft",
+            "--poa-raft-secret",
+            "secret",
+        ])
+        .unwrap();
+        let raft_leader_lock =
+            command.trigger.clone().raft_leader_lock().unwrap().unwrap();
+        assert_eq!(raft_leader_lock.node_id, 1);
+        assert_eq!(
+            raft_leader_lock.listen_addr,
+            "127.0.0.1:4101".parse().unwrap()
+        );
+        assert_eq!(
+            raft_leader_lock.peers,
+            vec![
+                RaftPeer {
+                    id: 2,
+                    addr: "127.0.0.1:4102".parse().unwrap()
+                },
+                RaftPeer {
+                    id: 3,
+                    addr: "127.0.0.1:4103".parse().unwrap()
+                },
+            ]
+        );
+        assert_eq!(raft_leader_lock.data_dir, PathBuf::from("/tmp/raft"));
+        assert_eq!(raft_leader_lock.shared_secret.expose_secret(), "secret");
+        assert_eq!(raft_leader_lock.election_timeout, StdDuration::from_secs(1));
+        assert_eq!(
+            raft_leader_lock.heartbeat_interval,
+            StdDuration::from_millis(200)
+        );
+        assert_eq!(raft_leader_lock.rpc_timeout, StdDuration::from_millis(500));
+        assert_eq!(raft_leader_lock.stream_max_len, 1000);
+    }
+
+    #[test_case(&["", "--poa-raft-leader-lock"]; "without node id and listen address")]
+    #[test_case(&["", "--poa-raft-leader-lock", "--poa-raft-node-id", "1", "--poa-raft-listen-addr", "127.0.0.1:4101", "--poa-raft-secret", "secret"]; "without data dir")]
+    #[test_case(&["", "--poa-raft-leader-lock", "--poa-raft-node-id", "1", "--poa-raft-listen-addr", "127.0.0.1:4101", "--poa-raft-data-dir", "/tmp/raft"]; "without secret")]
+    #[test_case(&["", "--poa-raft-leader-lock", "--poa-raft-node-id", "1", "--poa-raft-listen-addr", "127.0.0.1:4101", "--poa-raft-data-dir", "/tmp/raft", "--poa-raft-secret", "secret", "--poa-raft-peer", "127.0.0.1:4102"]; "with peer without id")]
+    #[test_case(&["", "--poa-raft-leader-lock", "--poa-raft-node-id", "1", "--poa-raft-listen-addr", "127.0.0.1:4101", "--poa-leader-lock", "--poa-leader-lock-redis-url", "redis://127.0.0.1:6379/", "--poa-leader-lock-key", "poa:leader:lock", "--poa-leader-lock-ttl", "2s"]; "with redis leader lock")]
+    fn raft_leader_lock__when_args_are_invalid_then_parse_error(args: &[&str]) {
+        let result = Command::try_parse_from(args);
+        assert!(result.is_err());
+    }
 }
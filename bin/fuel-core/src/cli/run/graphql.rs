@@ -1,6 +1,9 @@
 //! Clap configuration related to GraphQL service.
 
-use std::net;
+use std::{
+    net,
+    path::PathBuf,
+};
 
 use fuel_core::fuel_core_graphql_api::DEFAULT_QUERY_COSTS;
 
@@ -54,6 +57,14 @@ pub struct GraphQLArgs {
     #[clap(long = "graphql-max-concurrent-queries", default_value = "1024", env)]
     pub graphql_max_concurrent_queries: usize,
 
+    /// The max number of simultaneously open WebSocket connections.
+    #[clap(
+        long = "graphql-max-websocket-connections",
+        default_value = "1024",
+        env
+    )]
+    pub graphql_max_websocket_connections: usize,
+
     /// The max body limit of the GraphQL query.
     #[clap(
         long = "graphql-request-body-bytes-limit",
@@ -103,6 +114,39 @@ pub struct GraphQLArgs {
     )]
     pub required_fuel_block_height_timeout: humantime::Duration,
 
+    /// The time window after which the complexity budgets of all clients are reset.
+    #[clap(long = "graphql-rate-limit-window", default_value = "60s", env)]
+    pub rate_limit_window: humantime::Duration,
+
+    /// The total complexity of queries that a client identified by the IP address
+    /// can execute during the rate limit window. Unlimited if not set.
+    #[clap(long = "graphql-ip-complexity-per-window", env)]
+    pub ip_complexity_per_window: Option<usize>,
+
+    /// The total complexity of queries that a client identified by the
+    /// `x-api-key` header can execute during the rate limit window.
+    /// Only the keys from `--graphql-api-keys-path` identify clients, requests with
+    /// other keys are limited by their IP. Unlimited if not set.
+    #[clap(long = "graphql-api-key-complexity-per-window", env)]
+    pub api_key_complexity_per_window: Option<usize>,
+
+    /// The maximum number of clients whose budgets are tracked during the rate limit
+    /// window. Queries of new clients are rejected until the end of the window
+    /// once the limit is reached.
+    #[clap(
+        long = "graphql-rate-limit-max-tracked-clients",
+        default_value = "100000",
+        env
+    )]
+    pub rate_limit_max_tracked_clients: usize,
+
+    /// The path to the JSON file with API keys and their roles. Roles grant access
+    /// to privileged operations (`block_production`, `debugger`, `unchecked_dry_run`,
+    /// `peer_admin`) to the requests with the corresponding `x-api-key` header.
+    /// The file is reloaded on changes.
+    #[clap(long = "graphql-api-keys-path", env)]
+    pub api_keys_path: Option<PathBuf>,
+
     #[clap(flatten)]
     pub costs: QueryCosts,
 }
@@ -165,6 +209,14 @@ pub struct QueryCosts {
     )]
     pub storage_read_replay: usize,
 
+    /// Query costs for generating the call tree of a transaction.
+    #[clap(
+        long = "query-cost-transaction-trace",
+        default_value = DEFAULT_QUERY_COSTS.transaction_trace.to_string(),
+        env
+    )]
+    pub transaction_trace: usize,
+
     /// Query costs for submitting a transaction.
     #[clap(
         long = "query-cost-submit",
@@ -276,4 +328,12 @@ pub struct QueryCosts {
         env
     )]
     pub da_compressed_block_read: usize,
+
+    /// Query costs for reading the status of the leader lease.
+    #[clap(
+        long = "query-cost-consensus-status",
+        default_value = DEFAULT_QUERY_COSTS.consensus_status.to_string(),
+        env
+    )]
+    pub consensus_status: usize,
 }
@@ -1,6 +1,7 @@
 use anyhow::anyhow;
 use clap::{
     Args,
+    ValueEnum,
     builder::ArgPredicate::IsPresent,
 };
 use fuel_core::{
@@ -10,6 +11,7 @@ use fuel_core::{
             Config,
             MAX_RESPONSE_SIZE,
             NotInitialized,
+            TxGossipMode,
             convert_to_libp2p_keypair,
         },
         gossipsub_config::default_gossipsub_builder,
@@ -220,6 +222,30 @@ pub struct P2PArgs {
     /// Size of the cache for the P2P req/res protocol.
     #[clap(long = "p2p-cache-size", default_value = "1000", env)]
     pub cache_size: Option<NonZeroUsize>,
+
+    /// The way the node gossips transactions. With `announce`, only the ids of transactions
+    /// are gossiped, and peers fetch the transactions they don't know yet.
+    #[clap(long = "tx-gossip-mode", value_enum, default_value = "full", env)]
+    pub tx_gossip_mode: TxGossipModeArg,
+
+    /// Max number of announced transactions fetched from peers at the same time.
+    #[clap(long = "max-pending-tx-fetches", default_value = "256", env)]
+    pub max_pending_tx_fetches: usize,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum TxGossipModeArg {
+    Full,
+    Announce,
+}
+
+impl From<TxGossipModeArg> for TxGossipMode {
+    fn from(value: TxGossipModeArg) -> Self {
+        match value {
+            TxGossipModeArg::Full => TxGossipMode::Full,
+            TxGossipModeArg::Announce => TxGossipMode::Announce,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Args)]
@@ -362,7 +388,11 @@ impl P2PArgs {
             state: NotInitialized,
             subscribe_to_pre_confirmations: self.subscribe_to_pre_confirmations,
             subscribe_to_transactions: self.subscribe_to_transactions,
+            // Enabled by the node when the chain uses BFT consensus.
+            subscribe_to_bft_consensus: false,
             cache_size: self.cache_size,
+            tx_gossip_mode: self.tx_gossip_mode.into(),
+            max_pending_tx_fetches: self.max_pending_tx_fetches,
         };
         Ok(Some(config))
     }
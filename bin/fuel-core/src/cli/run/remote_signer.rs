@@ -0,0 +1,120 @@
+use anyhow::Context;
+use clap::Args;
+use fuel_core_types::signer::remote::RemoteSignerConfig;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Args)]
+pub struct RemoteSignerArgs {
+    /// Use a remote signing service for signing blocks and pre-confirmation delegate keys.
+    /// Takes the base URL of the service, e.g. `https://signer.internal:8443`.
+    /// The public key is requested from the service on startup.
+    #[clap(
+        long = "consensus-remote-signer-url",
+        env,
+        conflicts_with = "consensus_key",
+        requires = "consensus_remote_signer_key_id"
+    )]
+    pub consensus_remote_signer_url: Option<String>,
+
+    /// The identifier of the signing key in the remote signing service.
+    #[clap(long = "consensus-remote-signer-key-id", env)]
+    pub consensus_remote_signer_key_id: Option<String>,
+
+    /// The path to the PEM encoded CA certificates used to verify the remote signing service.
+    /// If not set, the system root certificates are used.
+    #[clap(long = "consensus-remote-signer-ca-cert", env)]
+    pub consensus_remote_signer_ca_cert: Option<PathBuf>,
+
+    /// The path to the PEM encoded client certificate used to authenticate to the remote signing service.
+    #[clap(
+        long = "consensus-remote-signer-client-cert",
+        env,
+        requires = "consensus_remote_signer_client_key"
+    )]
+    pub consensus_remote_signer_client_cert: Option<PathBuf>,
+
+    /// The path to the PEM encoded private key of the client certificate.
+    #[clap(
+        long = "consensus-remote-signer-client-key",
+        env,
+        requires = "consensus_remote_signer_client_cert"
+    )]
+    pub consensus_remote_signer_client_key: Option<PathBuf>,
+
+    /// The timeout of a single request to the remote signing service.
+    #[clap(long = "consensus-remote-signer-timeout", default_value = "5s", env)]
+    pub consensus_remote_signer_timeout: humantime::Duration,
+
+    /// The timeout of establishing the connection to the remote signing service.
+    #[clap(
+        long = "consensus-remote-signer-connect-timeout",
+        default_value = "2s",
+        env
+    )]
+    pub consensus_remote_signer_connect_timeout: humantime::Duration,
+
+    /// The number of retries of a request to the remote signing service
+    /// that failed because of the network, a timeout or a server error.
+    #[clap(long = "consensus-remote-signer-max-retries", default_value = "3", env)]
+    pub consensus_remote_signer_max_retries: u32,
+
+    /// The delay before the first retry, doubled after each retry.
+    #[clap(
+        long = "consensus-remote-signer-retry-backoff",
+        default_value = "100ms",
+        env
+    )]
+    pub consensus_remote_signer_retry_backoff: humantime::Duration,
+}
+
+impl RemoteSignerArgs {
+    /// Returns the configuration of the remote signer if the URL is set.
+    /// Reads the certificates and the key from the files.
+    pub fn into_config(self) -> anyhow::Result<Option<RemoteSignerConfig>> {
+        let Some(url) = self.consensus_remote_signer_url else {
+            return Ok(None);
+        };
+        let key_id = self
+            .consensus_remote_signer_key_id
+            .context("The remote signer key id is required")?;
+
+        let ca_cert_pem = self
+            .consensus_remote_signer_ca_cert
+            .map(|path| {
+                std::fs::read(&path).with_context(|| {
+                    format!("Failed to read the remote signer CA certificate {path:?}")
+                })
+            })
+            .transpose()?;
+        let client_identity_pem = match (
+            self.consensus_remote_signer_client_cert,
+            self.consensus_remote_signer_client_key,
+        ) {
+            (Some(cert_path), Some(key_path)) => {
+                let mut identity = std::fs::read(&cert_path).with_context(|| {
+                    format!("Failed to read the remote signer client certificate {cert_path:?}")
+                })?;
+                identity.push(b'\n');
+                identity.extend(std::fs::read(&key_path).with_context(|| {
+                    format!("Failed to read the remote signer client key {key_path:?}")
+                })?);
+                Some(identity)
+            }
+            (None, None) => None,
+            _ => anyhow::bail!(
+                "Both the client certificate and the client key of the remote signer are required"
+            ),
+        };
+
+        Ok(Some(RemoteSignerConfig {
+            url,
+            key_id,
+            ca_cert_pem,
+            client_identity_pem,
+            request_timeout: self.consensus_remote_signer_timeout.into(),
+            connect_timeout: self.consensus_remote_signer_connect_timeout.into(),
+            max_retries: self.consensus_remote_signer_max_retries,
+            retry_backoff: self.consensus_remote_signer_retry_backoff.into(),
+        }))
+    }
+}
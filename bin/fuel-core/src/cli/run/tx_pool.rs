@@ -8,6 +8,7 @@ use fuel_core_types::{
     },
     fuel_types::Nonce,
 };
+use std::path::PathBuf;
 
 #[derive(Debug, Clone, clap::Args)]
 pub struct TxPoolArgs {
@@ -31,11 +32,32 @@ pub struct TxPoolArgs {
     #[clap(long = "tx-max-total-bytes", default_value = "131072000", env)]
     pub tx_max_total_bytes: usize,
 
+    /// The max number of transactions of one owner that the `TxPool` can simultaneously store.
+    /// The owner of a transaction is the owner of any coin or the recipient of any message
+    /// it spends; for predicates, it is the predicate root.
+    /// By default, it is equal to `tx-max-number`.
+    #[clap(long = "tx-max-number-per-owner", env)]
+    pub tx_max_number_per_owner: Option<usize>,
+
+    /// The max number of gas of one owner that the `TxPool` can simultaneously store.
+    /// By default, it is equal to `tx-max-total-gas`.
+    #[clap(long = "tx-max-total-gas-per-owner", env)]
+    pub tx_max_total_gas_per_owner: Option<u64>,
+
+    /// The max number of bytes of one owner that the `TxPool` can simultaneously store.
+    /// By default, it is equal to `tx-max-total-bytes`.
+    #[clap(long = "tx-max-total-bytes-per-owner", env)]
+    pub tx_max_total_bytes_per_owner: Option<usize>,
+
     /// The flag name is `tx-max-depth` for backward compatibility but the behavior is:
     /// The max number of tx in a chain of dependent transactions that supported by the `TxPool`.
     #[clap(long = "tx-max-depth", default_value = "32", env)]
     pub tx_max_chain_count: usize,
 
+    /// The max number of transactions in one bundle submitted to the `TxPool`.
+    #[clap(long = "tx-max-bundle-size", default_value = "16", env)]
+    pub tx_max_bundle_size: usize,
+
     /// The list of banned addresses ignored by the `TxPool`.
     #[clap(long = "tx-blacklist-addresses", value_delimiter = ',', env)]
     pub tx_blacklist_addresses: Vec<Address>,
@@ -89,6 +111,79 @@ pub struct TxPoolArgs {
     /// The max percentage of the `TxPool` that can be used by the `PendingPool`.
     #[clap(long = "tx-pending-pool-size-percentage", default_value = "50", env)]
     pub tx_pending_pool_size_percentage: u16,
+
+    /// The max number of blocks ahead of the next block for the maturity of the transaction.
+    /// Transactions maturing within this window are held by the `TxPool` until they mature.
+    #[clap(long = "tx-max-maturity-lookahead", default_value = "3600", env)]
+    pub tx_max_maturity_lookahead: u32,
+
+    /// The max percentage of the `TxPool` that can be used by the transactions waiting
+    /// for their maturity.
+    #[clap(long = "tx-future-pool-size-percentage", default_value = "10", env)]
+    pub tx_future_pool_size_percentage: u16,
+
+    /// The path to the journal of the `TxPool`. When set, transactions of the `TxPool`
+    /// are persisted into the journal and replayed after the restart of the node.
+    /// Replayed transactions are verified again, and the ones older than `tx-pool-ttl` are dropped.
+    #[clap(long = "tx-journal-path", env)]
+    pub tx_journal_path: Option<PathBuf>,
+
+    /// The interval for dropping the transactions that left the `TxPool` from the journal.
+    /// Transactions are appended to the journal as soon as the `TxPool` accepts them.
+    #[clap(long = "tx-journal-rotation-interval", default_value = "1m", env)]
+    pub tx_journal_rotation_interval: humantime::Duration,
+
+    /// The policy used to select transactions from the `TxPool` for the block production.
+    /// `ratio-tip-gas` selects transactions with the highest tip per gas first,
+    /// `fifo` selects transactions strictly in the order of their arrival,
+    /// `lanes` reserves a part of the block gas for transactions from `tx-priority-addresses`.
+    #[clap(long = "tx-selection-policy", default_value = "ratio-tip-gas", env)]
+    pub tx_selection_policy: SelectionPolicyArg,
+
+    /// The list of addresses whose transactions are selected into the priority lane.
+    /// Used only by the `lanes` selection policy.
+    #[clap(long = "tx-priority-addresses", value_delimiter = ',', env)]
+    pub tx_priority_addresses: Vec<Address>,
+
+    /// The percentage of the block gas reserved for the priority lane.
+    /// Used only by the `lanes` selection policy.
+    #[clap(
+        long = "tx-priority-lane-gas-percentage",
+        default_value = "20",
+        value_parser = clap::value_parser!(u8).range(0..=100),
+        env
+    )]
+    pub tx_priority_lane_gas_percentage: u8,
+
+    /// The minimum percentage by which the tip per gas of a transaction must exceed
+    /// the tip per gas of the collided transactions to replace them in the `TxPool`.
+    #[clap(long = "tx-rbf-min-tip-bump-percentage", default_value = "0", env)]
+    pub tx_rbf_min_tip_bump_percentage: u16,
+
+    /// The maximum number of transactions, including dependents and the bundles
+    /// removed along with them, that one replacement is allowed to evict from the `TxPool`.
+    #[clap(long = "tx-rbf-max-evicted-transactions", default_value = "100", env)]
+    pub tx_rbf_max_evicted_transactions: usize,
+
+    /// The list of input kinds for which the replacement is disabled.
+    /// A transaction colliding on such an input is rejected regardless of its tip.
+    #[clap(long = "tx-rbf-non-replaceable-inputs", value_delimiter = ',', env)]
+    pub tx_rbf_non_replaceable_inputs: Vec<ReplaceableInputArg>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum SelectionPolicyArg {
+    RatioTipGas,
+    Fifo,
+    Lanes,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum ReplaceableInputArg {
+    Coin,
+    Message,
+    ContractCreation,
+    Blob,
 }
 
 #[cfg(test)]
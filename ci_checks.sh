@@ -34,6 +34,7 @@ OVERRIDE_CHAIN_CONFIGS=true cargo test --test integration_tests local_node &&
 cargo nextest run --workspace &&
 FUEL_ALWAYS_USE_WASM=true cargo test run --all-features --workspace &&
 cargo nextest run -p fuel-core --no-default-features &&
+cargo nextest run -p fuel-core-parallel-executor --features wasm-executor &&
 cargo nextest run -p fuel-core-client --no-default-features &&
 cargo nextest run -p fuel-core-chain-config --no-default-features &&
 cargo nextest run --manifest-path version-compatibility/Cargo.toml --workspace
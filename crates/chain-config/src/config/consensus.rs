@@ -1,4 +1,7 @@
-use alloc::collections::BTreeMap;
+use alloc::{
+    collections::BTreeMap,
+    vec::Vec,
+};
 use fuel_core_types::{
     fuel_tx::Input,
     fuel_types::{
@@ -18,6 +21,7 @@ use fuel_core_chain_config::default_consensus_dev_key;
 pub enum ConsensusConfig {
     PoA { signing_key: Address },
     PoAV2(PoAV2),
+    Bft(BftConfig),
 }
 
 impl ConsensusConfig {
@@ -80,6 +84,34 @@ impl PoAV2 {
     }
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct BftConfig {
+    validators: Vec<Address>,
+}
+
+impl BftConfig {
+    pub fn new(validators: Vec<Address>) -> Self {
+        BftConfig { validators }
+    }
+
+    /// Returns the addresses of the signing keys of the validators.
+    pub fn validators(&self) -> &[Address] {
+        &self.validators
+    }
+
+    /// Returns the number of validators required to commit a block.
+    /// The quorum is more than two-thirds of the validators, so the network
+    /// tolerates less than one-third of faulty validators.
+    pub fn quorum(&self) -> usize {
+        self.validators
+            .len()
+            .saturating_mul(2)
+            .checked_div(3)
+            .unwrap_or_default()
+            .saturating_add(1)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(non_snake_case)]
@@ -119,4 +151,17 @@ mod tests {
             signing_key_after_30
         );
     }
+
+    #[test]
+    fn quorum__is_more_than_two_thirds_of_validators() {
+        let config =
+            |n: u8| BftConfig::new((0..n).map(|i| Address::from([i; 32])).collect());
+
+        assert_eq!(config(1).quorum(), 1);
+        assert_eq!(config(3).quorum(), 3);
+        assert_eq!(config(4).quorum(), 3);
+        assert_eq!(config(6).quorum(), 5);
+        assert_eq!(config(7).quorum(), 5);
+        assert_eq!(config(10).quorum(), 7);
+    }
 }
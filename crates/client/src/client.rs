@@ -23,13 +23,18 @@ use crate::{
             relayed_tx::RelayedTransactionStatusArgs,
             tx::{
                 DryRunArg,
+                SubmitArg,
                 TxWithEstimatedPredicatesArg,
+                TxsWithEstimatedPredicatesArg,
             },
         },
         types::{
             RelayedTransactionStatus,
             asset::AssetDetail,
-            gas_price::LatestGasPrice,
+            gas_price::{
+                FeeHistory,
+                LatestGasPrice,
+            },
             message::MessageStatus,
             primitives::{
                 Address,
@@ -82,8 +87,10 @@ use fuel_core_types::{
         canonical::Serialize,
     },
     services::executor::{
+        StateOverrides,
         StorageReadReplayEvent,
         TransactionExecutionStatus,
+        TransactionTrace,
     },
 };
 #[cfg(feature = "subscriptions")]
@@ -129,13 +136,17 @@ use schema::{
         ContractByIdArgs,
     },
     da_compressed::DaCompressedBlockByHeightArgs,
-    gas_price::BlockHorizonArgs,
+    gas_price::{
+        BlockHorizonArgs,
+        FeeHistoryArgs,
+    },
     storage_read_replay::{
         StorageReadReplay,
         StorageReadReplayArgs,
     },
     tx::{
         AssembleTxArg,
+        TransactionsByContractConnectionArgs,
         TransactionsByOwnerConnectionArgs,
         TxArg,
         TxIdArgs,
@@ -498,6 +509,22 @@ impl FuelClient {
         self
     }
 
+    /// Sends the `api_key` with each request to get access to the privileged
+    /// operations allowed by the roles of the key.
+    pub fn with_api_key(&mut self, api_key: impl Into<String>) -> &mut Self {
+        self.transport.set_api_key(Some(api_key.into()));
+        self
+    }
+
+    /// Multiplexes all subscriptions of the client over one WebSocket
+    /// connection using the `graphql-transport-ws` protocol, instead of
+    /// opening an SSE stream for each subscription.
+    #[cfg(feature = "subscriptions")]
+    pub fn use_websocket_subscriptions(&mut self) -> &mut Self {
+        self.transport.use_websocket_subscriptions();
+        self
+    }
+
     pub fn use_manual_consistency_policy(
         &mut self,
         height: Option<BlockHeight>,
@@ -695,6 +722,24 @@ impl FuelClient {
         self.query(query).await.map(|r| r.estimate_gas_price)
     }
 
+    /// Returns the gas prices, the gas used ratios and the tips at the
+    /// `reward_percentiles` of the last `block_count` blocks up to the
+    /// `newest_block` (the latest block by default).
+    pub async fn fee_history(
+        &self,
+        block_count: u32,
+        newest_block: Option<u32>,
+        reward_percentiles: &[f64],
+    ) -> io::Result<FeeHistory> {
+        let args = FeeHistoryArgs {
+            block_count: block_count.into(),
+            newest_block: newest_block.map(Into::into),
+            reward_percentiles: Some(reward_percentiles.to_vec()),
+        };
+        let query = schema::gas_price::QueryFeeHistory::build(args);
+        self.query(query).await.map(|r| r.fee_history.into())
+    }
+
     #[cfg(feature = "std")]
     pub async fn connected_peers_info(
         &self,
@@ -705,6 +750,114 @@ impl FuelClient {
             .map(|r| r.node_info.peers.into_iter().map(Into::into).collect())
     }
 
+    /// Returns the reputation and heartbeat data of all connected peers.
+    /// Requires the peer administration API to be enabled on the node.
+    #[cfg(feature = "std")]
+    pub async fn peer_reputations(
+        &self,
+    ) -> io::Result<Vec<fuel_core_types::services::p2p::PeerReputation>> {
+        let query = schema::peers::QueryPeerReputations::build(());
+        self.query(query)
+            .await
+            .map(|r| r.peer_reputations.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the peers banned with the [`Self::ban_peer`].
+    #[cfg(feature = "std")]
+    pub async fn banned_peers(
+        &self,
+    ) -> io::Result<Vec<fuel_core_types::services::p2p::BannedPeer>> {
+        let query = schema::peers::QueryBannedPeers::build(());
+        self.query(query)
+            .await
+            .map(|r| r.banned_peers.into_iter().map(Into::into).collect())
+    }
+
+    /// Adds the reserved peer by its multiaddress, that must contain the peer id.
+    /// Returns the id of the added peer.
+    #[cfg(feature = "std")]
+    pub async fn add_reserved_peer(
+        &self,
+        address: &str,
+    ) -> io::Result<fuel_core_types::services::p2p::PeerId> {
+        let query = schema::peers::AddReservedPeerMutation::build(
+            schema::peers::AddReservedPeerArgs {
+                address: address.to_string(),
+            },
+        );
+        let peer_id = self.query(query).await?.add_reserved_peer;
+        peer_id
+            .parse::<fuel_core_types::services::p2p::PeerId>()
+            .map_err(io::Error::other)
+    }
+
+    /// Removes the reserved peer. Returns `false` if the peer was not reserved.
+    #[cfg(feature = "std")]
+    pub async fn remove_reserved_peer(
+        &self,
+        peer_id: &fuel_core_types::services::p2p::PeerId,
+    ) -> io::Result<bool> {
+        let query =
+            schema::peers::RemoveReservedPeerMutation::build(schema::peers::PeerIdArgs {
+                peer_id: peer_id.to_string(),
+            });
+        self.query(query).await.map(|r| r.remove_reserved_peer)
+    }
+
+    /// Bans the peer for the `duration` and closes all connections with it.
+    #[cfg(feature = "std")]
+    pub async fn ban_peer(
+        &self,
+        peer_id: &fuel_core_types::services::p2p::PeerId,
+        duration: std::time::Duration,
+    ) -> io::Result<()> {
+        let query = schema::peers::BanPeerMutation::build(schema::peers::BanPeerArgs {
+            peer_id: peer_id.to_string(),
+            duration_seconds: duration.as_secs().into(),
+        });
+        self.query(query).await?;
+        Ok(())
+    }
+
+    /// Unbans the peer.
+    #[cfg(feature = "std")]
+    pub async fn unban_peer(
+        &self,
+        peer_id: &fuel_core_types::services::p2p::PeerId,
+    ) -> io::Result<()> {
+        let query = schema::peers::UnbanPeerMutation::build(schema::peers::PeerIdArgs {
+            peer_id: peer_id.to_string(),
+        });
+        self.query(query).await?;
+        Ok(())
+    }
+
+    /// Closes all connections with the peer. Returns `false` if the peer was not connected.
+    #[cfg(feature = "std")]
+    pub async fn disconnect_peer(
+        &self,
+        peer_id: &fuel_core_types::services::p2p::PeerId,
+    ) -> io::Result<bool> {
+        let query =
+            schema::peers::DisconnectPeerMutation::build(schema::peers::PeerIdArgs {
+                peer_id: peer_id.to_string(),
+            });
+        self.query(query).await.map(|r| r.disconnect_peer)
+    }
+
+    /// Returns the state of the leader lease of the block producer,
+    /// or `None` if the Redis leader lock is not enabled on the node.
+    #[cfg(feature = "std")]
+    pub async fn consensus_status(
+        &self,
+    ) -> io::Result<Option<fuel_core_types::services::leader_lease::LeaderLeaseStatus>>
+    {
+        let query = schema::consensus::QueryConsensusStatus::build(());
+        self.query(query)
+            .await
+            .map(|r| r.consensus_status.map(Into::into))
+    }
+
     pub async fn chain_info(&self) -> io::Result<types::ChainInfo> {
         let node_version = self.ensure_node_version().await?;
         if is_legacy_node(&node_version) {
@@ -792,7 +945,7 @@ impl FuelClient {
         &self,
         txs: &[Transaction],
     ) -> io::Result<Vec<TransactionExecutionStatus>> {
-        self.dry_run_opt(txs, None, None, None).await
+        self.dry_run_opt(txs, None, None, None, None).await
     }
 
     /// Dry run with options to override the node behavior
@@ -803,6 +956,8 @@ impl FuelClient {
         utxo_validation: Option<bool>,
         gas_price: Option<u64>,
         at_height: Option<BlockHeight>,
+        // Overrides the contracts' state and injects fake coins and messages before the execution
+        state_overrides: Option<StateOverrides>,
     ) -> io::Result<Vec<TransactionExecutionStatus>> {
         let txs = txs
             .iter()
@@ -814,6 +969,7 @@ impl FuelClient {
                 utxo_validation,
                 gas_price: gas_price.map(|gp| gp.into()),
                 block_height: at_height.map(|bh| bh.into()),
+                state_overrides: state_overrides.map(Into::into),
             });
         let tx_statuses = self.query(query).await.map(|r| r.dry_run)?;
         tx_statuses
@@ -830,6 +986,8 @@ impl FuelClient {
         utxo_validation: Option<bool>,
         gas_price: Option<u64>,
         at_height: Option<BlockHeight>,
+        // Overrides the contracts' state and injects fake coins and messages before the execution
+        state_overrides: Option<StateOverrides>,
     ) -> io::Result<(Vec<TransactionExecutionStatus>, Vec<StorageReadReplayEvent>)> {
         let txs = txs
             .iter()
@@ -841,6 +999,7 @@ impl FuelClient {
                 utxo_validation,
                 gas_price: gas_price.map(|gp| gp.into()),
                 block_height: at_height.map(|bh| bh.into()),
+                state_overrides: state_overrides.map(Into::into),
             });
         let result = self
             .query(query)
@@ -877,6 +1036,15 @@ impl FuelClient {
             .collect())
     }
 
+    /// Re-executes the block containing the transaction and returns its call tree.
+    /// Requires the node to run with `--historical-execution`.
+    pub async fn transaction_trace(&self, id: &TxId) -> io::Result<TransactionTrace> {
+        let query =
+            schema::tx::TransactionTraceQuery::build(TxIdArgs { id: (*id).into() });
+        let trace = self.query(query).await?.transaction_trace.try_into()?;
+        Ok(trace)
+    }
+
     /// Assembles the transaction based on the provided requirements.
     /// The return transaction contains:
     /// - Input coins to cover `required_balances`
@@ -962,17 +1130,64 @@ impl FuelClient {
         &self,
         tx: &Transaction,
         estimate_predicates: Option<bool>,
+    ) -> io::Result<types::primitives::TransactionId> {
+        self.submit_with_replacement_opt(tx, estimate_predicates, None)
+            .await
+    }
+
+    /// Submits the transaction. If `replaceable` is `Some(false)`, the `TxPool` of
+    /// the node rejects transactions colliding with it regardless of their tip.
+    pub async fn submit_with_replacement_opt(
+        &self,
+        tx: &Transaction,
+        estimate_predicates: Option<bool>,
+        replaceable: Option<bool>,
     ) -> io::Result<types::primitives::TransactionId> {
         let tx = tx.clone().to_bytes();
-        let query = schema::tx::Submit::build(TxWithEstimatedPredicatesArg {
+        let query = schema::tx::Submit::build(SubmitArg {
             tx: HexString(Bytes(tx)),
             estimate_predicates,
+            replaceable,
         });
 
         let id = self.query(query).await.map(|r| r.submit)?.id.into();
         Ok(id)
     }
 
+    /// Submits the bundle of transactions. The `TxPool` selects the bundle for the block
+    /// all-or-nothing, one transaction after another. A transaction that fails at
+    /// the execution is skipped without reverting the rest of the bundle.
+    pub async fn submit_bundle(
+        &self,
+        txs: &[Transaction],
+    ) -> io::Result<Vec<types::primitives::TransactionId>> {
+        self.submit_bundle_opt(txs, None).await
+    }
+
+    pub async fn submit_bundle_opt(
+        &self,
+        txs: &[Transaction],
+        estimate_predicates: Option<bool>,
+    ) -> io::Result<Vec<types::primitives::TransactionId>> {
+        let txs = txs
+            .iter()
+            .map(|tx| HexString(Bytes(tx.clone().to_bytes())))
+            .collect();
+        let query = schema::tx::SubmitBundle::build(TxsWithEstimatedPredicatesArg {
+            txs,
+            estimate_predicates,
+        });
+
+        let ids = self
+            .query(query)
+            .await?
+            .submit_bundle
+            .into_iter()
+            .map(|tx| tx.id.into())
+            .collect();
+        Ok(ids)
+    }
+
     /// Similar to [`Self::submit_and_await_commit_opt`], but with default options.
     #[cfg(feature = "subscriptions")]
     pub async fn submit_and_await_commit(
@@ -1108,6 +1323,29 @@ impl FuelClient {
         Ok(stream)
     }
 
+    /// Subscribes to the logs matching the `filter` emitted by the newly imported blocks.
+    /// The stream ends after the block at `to_height` is imported.
+    /// Requires the logs indexation to be enabled on the node.
+    #[cfg(feature = "subscriptions")]
+    pub async fn logs_subscription(
+        &self,
+        filter: types::LogFilter,
+    ) -> io::Result<impl Stream<Item = io::Result<types::Log>> + '_> {
+        use schema::logs::LogsSubscriptionArgs;
+        let variables = LogsSubscriptionArgs {
+            filter: filter.into(),
+        };
+
+        let stream = self.subscribe(variables).await?.map(
+            |result: io::Result<schema::logs::LogsSubscription>| {
+                let log: types::Log = result?.logs.try_into()?;
+                Result::<_, io::Error>::Ok(log)
+            },
+        );
+
+        Ok(stream)
+    }
+
     /// Requests all storage balances for the `contract_id`.
     #[cfg(feature = "subscriptions")]
     pub async fn contract_storage_balances(
@@ -1452,6 +1690,63 @@ impl FuelClient {
         Ok(transactions)
     }
 
+    /// Returns a paginated set of transactions that called or created the contract.
+    /// Requires the contract transactions indexation to be enabled on the node.
+    pub async fn transactions_by_contract(
+        &self,
+        contract_id: &ContractId,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+        let contract: schema::ContractId = (*contract_id).into();
+        let args = TransactionsByContractConnectionArgs::from((contract, request));
+        let query = schema::tx::TransactionsByContractQuery::build(args);
+
+        let transactions = self
+            .query(query)
+            .await?
+            .transactions_by_contract
+            .try_into()?;
+        Ok(transactions)
+    }
+
+    /// Returns a paginated set of transactions of the transaction pool, sorted by
+    /// their tip per gas ratio. Transactions waiting for their inputs or
+    /// maturity are included; `executable` filters them in or out.
+    /// Members of a bundle are returned as a part of the bundle's head.
+    pub async fn tx_pool_transactions(
+        &self,
+        owner: Option<&Address>,
+        contract_id: Option<&ContractId>,
+        executable: Option<bool>,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::TxPoolTransaction, String>> {
+        let args = schema::tx::TxPoolTransactionsConnectionArgs::from((
+            owner.map(|owner| (*owner).into()),
+            contract_id.map(|contract_id| (*contract_id).into()),
+            executable,
+            request,
+        ));
+        let query = schema::tx::TxPoolTransactionsQuery::build(args);
+
+        let transactions = self.query(query).await?.tx_pool_transactions.try_into()?;
+        Ok(transactions)
+    }
+
+    /// Returns a paginated set of logs matching the `filter`.
+    /// Requires the logs indexation to be enabled on the node.
+    pub async fn logs(
+        &self,
+        filter: types::LogFilter,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::Log, String>> {
+        use schema::logs::LogsConnectionArgs;
+        let args = LogsConnectionArgs::from((filter.into(), request));
+        let query = schema::logs::LogsQuery::build(args);
+
+        let logs = self.query(query).await?.logs.try_into()?;
+        Ok(logs)
+    }
+
     pub async fn receipts(&self, id: &TxId) -> io::Result<Option<Vec<Receipt>>> {
         let query =
             schema::tx::TransactionStatusQuery::build(TxIdArgs { id: (*id).into() });
@@ -1613,6 +1908,25 @@ impl FuelClient {
         Ok(coins)
     }
 
+    /// Retrieve a page of coins owned by the `owner` at the `block_height`.
+    /// Requires the node to keep the state history for that height.
+    pub async fn coins_at_height(
+        &self,
+        owner: &Address,
+        asset_id: Option<&AssetId>,
+        block_height: BlockHeight,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::Coin, String>> {
+        let owner: schema::Address = (*owner).into();
+        let asset_id = asset_id.map(|id| (*id).into());
+        let mut args = CoinsConnectionArgs::from((owner, asset_id, request));
+        args.block_height = Some((*block_height).into());
+        let query = schema::coins::CoinsQuery::build(args);
+
+        let coins = self.query(query).await?.coins.into();
+        Ok(coins)
+    }
+
     /// Retrieve coins to spend in a transaction
     pub async fn coins_to_spend(
         &self,
@@ -1686,7 +2000,28 @@ impl FuelClient {
             Some(asset_id) => (*asset_id).into(),
             None => schema::AssetId::default(),
         };
-        let query = schema::balance::BalanceQuery::build(BalanceArgs { owner, asset_id });
+        let query = schema::balance::BalanceQuery::build(BalanceArgs {
+            owner,
+            asset_id,
+            block_height: None,
+        });
+        let balance: types::Balance = self.query(query).await?.balance.into();
+        Ok(balance.amount)
+    }
+
+    /// Retrieve the balance of the `owner` at the `block_height`.
+    /// Requires the node to keep the state history for that height.
+    pub async fn balance_at_height(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        block_height: BlockHeight,
+    ) -> io::Result<u128> {
+        let query = schema::balance::BalanceQuery::build(BalanceArgs {
+            owner: (*owner).into(),
+            asset_id: (*asset_id).into(),
+            block_height: Some((*block_height).into()),
+        });
         let balance: types::Balance = self.query(query).await?.balance.into();
         Ok(balance.amount)
     }
@@ -1705,6 +2040,23 @@ impl FuelClient {
         Ok(balances)
     }
 
+    /// Retrieve a page of balances of the `owner` at the `block_height`.
+    /// Requires the node to keep the state history for that height.
+    pub async fn balances_at_height(
+        &self,
+        owner: &Address,
+        block_height: BlockHeight,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::Balance, String>> {
+        let owner: schema::Address = (*owner).into();
+        let mut args = schema::balance::BalancesConnectionArgs::from((owner, request));
+        args.block_height = Some((*block_height).into());
+        let query = schema::balance::BalancesQuery::build(args);
+
+        let balances = self.query(query).await?.balances.into();
+        Ok(balances)
+    }
+
     pub async fn contract_balances(
         &self,
         contract: &ContractId,
@@ -31,10 +31,13 @@ pub mod blob;
 pub mod block;
 pub mod chain;
 pub mod coins;
+pub mod consensus;
 pub mod contract;
 pub mod da_compressed;
+pub mod logs;
 pub mod message;
 pub mod node_info;
+pub mod peers;
 pub mod storage_read_replay;
 pub mod upgrades;
 
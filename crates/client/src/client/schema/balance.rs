@@ -5,6 +5,7 @@ use crate::client::{
         Address,
         AssetId,
         PageInfo,
+        U32,
         U64,
         schema,
     },
@@ -14,6 +15,7 @@ use crate::client::{
 pub struct BalanceArgs {
     pub owner: Address,
     pub asset_id: AssetId,
+    pub block_height: Option<U32>,
 }
 
 #[derive(cynic::QueryFragment, Clone, Debug)]
@@ -23,7 +25,7 @@ pub struct BalanceArgs {
     variables = "BalanceArgs"
 )]
 pub struct BalanceQuery {
-    #[arguments(owner: $owner, assetId: $asset_id)]
+    #[arguments(owner: $owner, assetId: $asset_id, blockHeight: $block_height)]
     pub balance: Balance,
 }
 
@@ -47,6 +49,8 @@ pub struct BalancesConnectionArgs {
     /// Retrieve the last n coins in order (backward pagination).
     /// Can't be used at the same time as `first`.
     pub last: Option<i32>,
+    /// The height of the block at which to query the balances
+    pub block_height: Option<U32>,
 }
 
 impl From<(Address, PaginationRequest<String>)> for BalancesConnectionArgs {
@@ -58,6 +62,7 @@ impl From<(Address, PaginationRequest<String>)> for BalancesConnectionArgs {
                 before: None,
                 first: Some(r.1.results),
                 last: None,
+                block_height: None,
             },
             PageDirection::Backward => BalancesConnectionArgs {
                 filter: BalanceFilterInput { owner: r.0 },
@@ -65,6 +70,7 @@ impl From<(Address, PaginationRequest<String>)> for BalancesConnectionArgs {
                 before: r.1.cursor,
                 first: None,
                 last: Some(r.1.results),
+                block_height: None,
             },
         }
     }
@@ -77,7 +83,7 @@ impl From<(Address, PaginationRequest<String>)> for BalancesConnectionArgs {
     variables = "BalancesConnectionArgs"
 )]
 pub struct BalancesQuery {
-    #[arguments(filter: $filter, after: $after, before: $before, first: $first, last: $last)]
+    #[arguments(filter: $filter, after: $after, before: $before, first: $first, last: $last, blockHeight: $block_height)]
     pub balances: BalanceConnection,
 }
 
@@ -113,6 +119,7 @@ mod tests {
         let operation = BalanceQuery::build(BalanceArgs {
             owner: Address::default(),
             asset_id: AssetId::default(),
+            block_height: None,
         });
         insta::assert_snapshot!(operation.query)
     }
@@ -128,6 +135,7 @@ mod tests {
             before: None,
             first: None,
             last: None,
+            block_height: None,
         });
         insta::assert_snapshot!(operation.query)
     }
@@ -159,6 +159,7 @@ pub struct Header {
 pub enum Consensus {
     Genesis(Genesis),
     PoAConsensus(PoAConsensus),
+    BftConsensus(BftConsensus),
     #[cynic(fallback)]
     Unknown,
 }
@@ -179,6 +180,13 @@ pub struct PoAConsensus {
     pub signature: Signature,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BftConsensus {
+    pub round: U32,
+    pub signatures: Vec<Signature>,
+}
+
 impl Block {
     /// Returns the block producer public key, if any.
     pub fn block_producer(&self) -> Option<fuel_crypto::PublicKey> {
@@ -190,6 +198,8 @@ impl Block {
                 let producer_pub_key = signature.recover(&message);
                 producer_pub_key.ok()
             }
+            // The block is committed by the quorum of validators.
+            Consensus::BftConsensus(_) => None,
             Consensus::Unknown => None,
         }
     }
@@ -57,6 +57,8 @@ pub struct CoinsConnectionArgs {
     /// Retrieve the last n coins in order (backward pagination).
     /// Can't be used at the same time as `first`.
     pub last: Option<i32>,
+    /// The height of the block at which to query the coins
+    pub block_height: Option<U32>,
 }
 
 impl From<(Address, Option<AssetId>, PaginationRequest<String>)> for CoinsConnectionArgs {
@@ -71,6 +73,7 @@ impl From<(Address, Option<AssetId>, PaginationRequest<String>)> for CoinsConnec
                 before: None,
                 first: Some(r.2.results),
                 last: None,
+                block_height: None,
             },
             PageDirection::Backward => CoinsConnectionArgs {
                 filter: CoinFilterInput {
@@ -81,6 +84,7 @@ impl From<(Address, Option<AssetId>, PaginationRequest<String>)> for CoinsConnec
                 before: r.2.cursor,
                 first: None,
                 last: Some(r.2.results),
+                block_height: None,
             },
         }
     }
@@ -93,7 +97,7 @@ impl From<(Address, Option<AssetId>, PaginationRequest<String>)> for CoinsConnec
     variables = "CoinsConnectionArgs"
 )]
 pub struct CoinsQuery {
-    #[arguments(filter: $ filter, after: $ after, before: $ before, first: $ first, last: $ last)]
+    #[arguments(filter: $ filter, after: $ after, before: $ before, first: $ first, last: $ last, blockHeight: $ block_height)]
     pub coins: CoinConnection,
 }
 
@@ -254,6 +258,7 @@ mod tests {
             before: None,
             first: None,
             last: None,
+            block_height: None,
         });
         insta::assert_snapshot!(operation.query)
     }
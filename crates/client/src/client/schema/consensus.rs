@@ -0,0 +1,190 @@
+use crate::client::schema::{
+    U32,
+    U64,
+    schema,
+};
+#[cfg(feature = "std")]
+use fuel_core_types::services::leader_lease;
+#[cfg(feature = "std")]
+use std::time::{
+    Duration,
+    SystemTime,
+    UNIX_EPOCH,
+};
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QueryConsensusStatus {
+    pub consensus_status: Option<ConsensusStatus>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ConsensusStatus {
+    pub backend: LeaderLeaseBackend,
+    pub lease_key: Option<String>,
+    pub lease_owner_token: String,
+    pub is_leader: bool,
+    pub epoch_token: Option<U64>,
+    pub holder: Option<String>,
+    pub quorum: U32,
+    pub nodes: Vec<LeaseNodeStatus>,
+    pub last_quorum_round: Option<QuorumRound>,
+    pub local_height: Option<U32>,
+    pub stream_height: Option<U32>,
+    pub stream_lag: Option<U32>,
+    pub transitions: Vec<LeaseTransition>,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum LeaderLeaseBackend {
+    Redis,
+    Raft,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct LeaseNodeStatus {
+    pub address: String,
+    pub reachable: bool,
+    pub holder: Option<String>,
+    pub epoch: Option<U64>,
+    pub stream_height: Option<U32>,
+    pub error: Option<String>,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum QuorumRoundKind {
+    OwnershipCheck,
+    Promotion,
+    Release,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct QuorumRound {
+    pub kind: QuorumRoundKind,
+    pub nodes: U32,
+    pub responded: U32,
+    pub granted: U32,
+    pub quorum_reached: bool,
+    pub time_ms: U64,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum LeaseTransitionKind {
+    Acquired,
+    EpochChanged,
+    Lost,
+    Released,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct LeaseTransition {
+    pub kind: LeaseTransitionKind,
+    pub epoch: Option<U64>,
+    pub time_ms: U64,
+}
+
+#[cfg(feature = "std")]
+fn system_time(time_ms: U64) -> SystemTime {
+    UNIX_EPOCH
+        .checked_add(Duration::from_millis(time_ms.0))
+        .unwrap_or(UNIX_EPOCH)
+}
+
+#[cfg(feature = "std")]
+impl From<ConsensusStatus> for leader_lease::LeaderLeaseStatus {
+    fn from(status: ConsensusStatus) -> Self {
+        Self {
+            backend: match status.backend {
+                LeaderLeaseBackend::Redis => leader_lease::LeaderLeaseBackend::Redis,
+                LeaderLeaseBackend::Raft => leader_lease::LeaderLeaseBackend::Raft,
+            },
+            lease_key: status.lease_key,
+            lease_owner_token: status.lease_owner_token,
+            is_leader: status.is_leader,
+            epoch_token: status.epoch_token.map(Into::into),
+            holder: status.holder,
+            quorum: status.quorum.into(),
+            nodes: status.nodes.into_iter().map(Into::into).collect(),
+            last_quorum_round: status.last_quorum_round.map(Into::into),
+            local_height: status.local_height.map(|h| h.0.into()),
+            stream_height: status.stream_height.map(|h| h.0.into()),
+            stream_lag: status.stream_lag.map(Into::into),
+            transitions: status.transitions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<LeaseNodeStatus> for leader_lease::LeaseNodeStatus {
+    fn from(node: LeaseNodeStatus) -> Self {
+        Self {
+            address: node.address,
+            reachable: node.reachable,
+            holder: node.holder,
+            epoch: node.epoch.map(Into::into),
+            stream_height: node.stream_height.map(|h| h.0.into()),
+            error: node.error,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<QuorumRound> for leader_lease::QuorumRound {
+    fn from(round: QuorumRound) -> Self {
+        Self {
+            kind: match round.kind {
+                QuorumRoundKind::OwnershipCheck => {
+                    leader_lease::QuorumRoundKind::OwnershipCheck
+                }
+                QuorumRoundKind::Promotion => leader_lease::QuorumRoundKind::Promotion,
+                QuorumRoundKind::Release => leader_lease::QuorumRoundKind::Release,
+            },
+            nodes: round.nodes.into(),
+            responded: round.responded.into(),
+            granted: round.granted.into(),
+            quorum_reached: round.quorum_reached,
+            time: system_time(round.time_ms),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<LeaseTransition> for leader_lease::LeaseTransition {
+    fn from(transition: LeaseTransition) -> Self {
+        Self {
+            kind: match transition.kind {
+                LeaseTransitionKind::Acquired => {
+                    leader_lease::LeaseTransitionKind::Acquired
+                }
+                LeaseTransitionKind::EpochChanged => {
+                    leader_lease::LeaseTransitionKind::EpochChanged
+                }
+                LeaseTransitionKind::Lost => leader_lease::LeaseTransitionKind::Lost,
+                LeaseTransitionKind::Released => {
+                    leader_lease::LeaseTransitionKind::Released
+                }
+            },
+            epoch: transition.epoch.map(Into::into),
+            time: system_time(transition.time_ms),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cynic::QueryBuilder;
+
+    #[test]
+    fn consensus_status_query_gql_output() {
+        let operation = QueryConsensusStatus::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+}
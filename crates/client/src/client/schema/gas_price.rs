@@ -47,6 +47,40 @@ pub struct QueryEstimateGasPrice {
     pub estimate_gas_price: EstimateGasPrice,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BlockFees {
+    pub height: U32,
+    pub gas_price: U64,
+    pub gas_used_ratio: f64,
+    pub reward: Vec<U64>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct FeeHistory {
+    pub oldest_block: U32,
+    pub blocks: Vec<BlockFees>,
+}
+
+#[derive(cynic::QueryVariables, Debug, Clone)]
+pub struct FeeHistoryArgs {
+    pub block_count: U32,
+    pub newest_block: Option<U32>,
+    pub reward_percentiles: Option<Vec<f64>>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "FeeHistoryArgs"
+)]
+pub struct QueryFeeHistory {
+    #[arguments(blockCount: $block_count, newestBlock: $newest_block, rewardPercentiles: $reward_percentiles)]
+    pub fee_history: FeeHistory,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -68,4 +102,16 @@ mod tests {
         let operation = QueryEstimateGasPrice::build(args);
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn fee_history_query_gql_output() {
+        use cynic::QueryBuilder;
+        let args = FeeHistoryArgs {
+            block_count: 10.into(),
+            newest_block: Some(100.into()),
+            reward_percentiles: Some(vec![25.0, 75.0]),
+        };
+        let operation = QueryFeeHistory::build(args);
+        insta::assert_snapshot!(operation.query)
+    }
 }
@@ -0,0 +1,146 @@
+use super::{
+    ContractId,
+    PageInfo,
+    TransactionId,
+    tx::transparent_receipt::Receipt,
+};
+use crate::client::{
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    schema::{
+        U16,
+        U32,
+        U64,
+        schema,
+    },
+};
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct LogFilterInput {
+    /// Filter logs emitted by the contract
+    pub contract_id: ContractId,
+    /// Filter logs by the log id(the `rb` register)
+    pub rb: U64,
+    /// Filter logs emitted at or after the block height
+    pub from_height: Option<U32>,
+    /// Filter logs emitted at or before the block height
+    pub to_height: Option<U32>,
+}
+
+#[derive(cynic::QueryVariables, Debug, Clone)]
+pub struct LogsConnectionArgs {
+    /// Filter logs based on a filter
+    pub filter: LogFilterInput,
+    /// Skip until cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n logs in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n logs in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(LogFilterInput, PaginationRequest<String>)> for LogsConnectionArgs {
+    fn from(r: (LogFilterInput, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => LogsConnectionArgs {
+                filter: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results),
+                last: None,
+            },
+            PageDirection::Backward => LogsConnectionArgs {
+                filter: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "LogsConnectionArgs"
+)]
+pub struct LogsQuery {
+    #[arguments(filter: $filter, after: $after, before: $before, first: $first, last: $last)]
+    pub logs: LogConnection,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct LogConnection {
+    pub edges: Vec<LogEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct LogEdge {
+    pub cursor: String,
+    pub node: Log,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct Log {
+    pub contract_id: ContractId,
+    pub rb: U64,
+    pub block_height: U32,
+    pub tx_index: U16,
+    pub receipt_index: U32,
+    pub transaction_id: TransactionId,
+    pub receipt: Receipt,
+}
+
+#[derive(cynic::QueryVariables, Debug, Clone)]
+pub struct LogsSubscriptionArgs {
+    /// Filter logs based on a filter
+    pub filter: LogFilterInput,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "LogsSubscriptionArgs"
+)]
+pub struct LogsSubscription {
+    #[arguments(filter: $filter)]
+    pub logs: Log,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn logs_query_gql_output() {
+        use cynic::QueryBuilder;
+
+        let operation = LogsQuery::build(LogsConnectionArgs {
+            filter: LogFilterInput {
+                contract_id: ContractId::default(),
+                rb: 0u64.into(),
+                from_height: None,
+                to_height: None,
+            },
+            after: None,
+            before: None,
+            first: None,
+            last: None,
+        });
+
+        insta::assert_snapshot!(operation.query)
+    }
+}
@@ -0,0 +1,191 @@
+use crate::client::schema::{
+    U32,
+    U64,
+    schema,
+};
+#[cfg(feature = "std")]
+use fuel_core_types::services::p2p::{
+    HeartbeatData,
+    PeerId,
+};
+#[cfg(feature = "std")]
+use std::{
+    str::FromStr,
+    time::{
+        Duration,
+        UNIX_EPOCH,
+    },
+};
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PeerReputation {
+    pub id: String,
+    pub reserved: bool,
+    pub app_score: f64,
+    pub gossipsub_score: Option<f64>,
+    pub block_height: Option<U32>,
+    pub last_heartbeat_ms: U64,
+    pub average_heartbeat_interval_ms: U64,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QueryPeerReputations {
+    pub peer_reputations: Vec<PeerReputation>,
+}
+
+#[cfg(feature = "std")]
+impl From<PeerReputation> for fuel_core_types::services::p2p::PeerReputation {
+    fn from(reputation: PeerReputation) -> Self {
+        Self {
+            id: PeerId::from_str(reputation.id.as_str()).unwrap_or_default(),
+            reserved: reputation.reserved,
+            app_score: reputation.app_score,
+            gossipsub_score: reputation.gossipsub_score,
+            heartbeat_data: HeartbeatData {
+                block_height: reputation.block_height.map(|h| h.0.into()),
+                last_heartbeat: UNIX_EPOCH
+                    .checked_add(Duration::from_millis(reputation.last_heartbeat_ms.0))
+                    .unwrap_or(UNIX_EPOCH),
+            },
+            average_heartbeat_interval: Duration::from_millis(
+                reputation.average_heartbeat_interval_ms.0,
+            ),
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BannedPeer {
+    pub id: String,
+    pub remaining_ban_duration_ms: U64,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QueryBannedPeers {
+    pub banned_peers: Vec<BannedPeer>,
+}
+
+#[cfg(feature = "std")]
+impl From<BannedPeer> for fuel_core_types::services::p2p::BannedPeer {
+    fn from(peer: BannedPeer) -> Self {
+        Self {
+            id: PeerId::from_str(peer.id.as_str()).unwrap_or_default(),
+            remaining_ban_duration: Duration::from_millis(
+                peer.remaining_ban_duration_ms.0,
+            ),
+        }
+    }
+}
+
+#[derive(cynic::QueryVariables, Debug, Clone)]
+pub struct AddReservedPeerArgs {
+    pub address: String,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "AddReservedPeerArgs",
+    graphql_type = "Mutation"
+)]
+pub struct AddReservedPeerMutation {
+    #[arguments(address: $address)]
+    pub add_reserved_peer: String,
+}
+
+#[derive(cynic::QueryVariables, Debug, Clone)]
+pub struct PeerIdArgs {
+    pub peer_id: String,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "PeerIdArgs",
+    graphql_type = "Mutation"
+)]
+pub struct RemoveReservedPeerMutation {
+    #[arguments(peerId: $peer_id)]
+    pub remove_reserved_peer: bool,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "PeerIdArgs",
+    graphql_type = "Mutation"
+)]
+pub struct UnbanPeerMutation {
+    #[arguments(peerId: $peer_id)]
+    pub unban_peer: bool,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "PeerIdArgs",
+    graphql_type = "Mutation"
+)]
+pub struct DisconnectPeerMutation {
+    #[arguments(peerId: $peer_id)]
+    pub disconnect_peer: bool,
+}
+
+#[derive(cynic::QueryVariables, Debug, Clone)]
+pub struct BanPeerArgs {
+    pub peer_id: String,
+    pub duration_seconds: U64,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "BanPeerArgs",
+    graphql_type = "Mutation"
+)]
+pub struct BanPeerMutation {
+    #[arguments(peerId: $peer_id, durationSeconds: $duration_seconds)]
+    pub ban_peer: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cynic::{
+        MutationBuilder,
+        QueryBuilder,
+    };
+
+    #[test]
+    fn peer_reputations_query_gql_output() {
+        let operation = QueryPeerReputations::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn banned_peers_query_gql_output() {
+        let operation = QueryBannedPeers::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn add_reserved_peer_mutation_gql_output() {
+        let operation = AddReservedPeerMutation::build(AddReservedPeerArgs {
+            address: "/ip4/127.0.0.1/tcp/30333".to_string(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn ban_peer_mutation_gql_output() {
+        let operation = BanPeerMutation::build(BanPeerArgs {
+            peer_id: "peer".to_string(),
+            duration_seconds: U64(60),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+}
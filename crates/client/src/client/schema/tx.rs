@@ -5,9 +5,12 @@ use crate::client::{
     schema::{
         Address,
         AssetId,
+        Bytes,
         ConnectionArgsFields,
+        ContractId,
         ConversionError,
         HexString,
+        Nonce,
         PageInfo,
         Tai64Timestamp,
         TransactionId,
@@ -33,6 +36,7 @@ use fuel_core_types::{
     },
     fuel_vm,
     services::executor::{
+        StateOverrides,
         TransactionExecutionResult,
         TransactionExecutionStatus,
     },
@@ -475,6 +479,55 @@ pub struct TransactionsByOwnerQuery {
     pub transactions_by_owner: TransactionConnection,
 }
 
+#[derive(cynic::QueryVariables, Debug, Clone)]
+pub struct TransactionsByContractConnectionArgs {
+    /// Select transactions that called or created the `contract`
+    pub contract: ContractId,
+    /// Skip until cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n transactions in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n transactions in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(ContractId, PaginationRequest<String>)>
+    for TransactionsByContractConnectionArgs
+{
+    fn from(r: (ContractId, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => TransactionsByContractConnectionArgs {
+                contract: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results),
+                last: None,
+            },
+            PageDirection::Backward => TransactionsByContractConnectionArgs {
+                contract: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TransactionsByContractConnectionArgs"
+)]
+pub struct TransactionsByContractQuery {
+    #[arguments(contract: $contract, after: $after, before: $before, first: $first, last: $last)]
+    pub transactions_by_contract: TransactionConnection,
+}
+
 #[derive(cynic::QueryVariables, Debug, Clone)]
 pub struct StatusChangeSubscriptionArgs {
     pub id: TransactionId,
@@ -507,6 +560,22 @@ pub struct TxWithEstimatedPredicatesArg {
     pub estimate_predicates: Option<bool>,
 }
 
+#[derive(cynic::QueryVariables, Clone)]
+pub struct SubmitArg {
+    pub tx: HexString,
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub estimate_predicates: Option<bool>,
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub replaceable: Option<bool>,
+}
+
+#[derive(cynic::QueryVariables, Clone)]
+pub struct TxsWithEstimatedPredicatesArg {
+    pub txs: Vec<HexString>,
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub estimate_predicates: Option<bool>,
+}
+
 #[derive(cynic::QueryVariables, Clone)]
 pub struct SubmitAndAwaitStatusArg {
     pub tx: HexString,
@@ -579,6 +648,101 @@ pub struct AssembleTxArg {
     pub reserve_gas: Option<U64>,
 }
 
+#[derive(cynic::QueryVariables, Debug, Clone)]
+pub struct TxPoolTransactionsConnectionArgs {
+    /// Select transactions that spend inputs of the owner
+    pub owner: Option<Address>,
+    /// Select transactions that use or create the contract
+    pub contract: Option<ContractId>,
+    /// Select executable(`true`) or not executable(`false`) transactions
+    pub executable: Option<bool>,
+    /// Skip until cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n transactions in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n transactions in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl
+    From<(
+        Option<Address>,
+        Option<ContractId>,
+        Option<bool>,
+        PaginationRequest<String>,
+    )> for TxPoolTransactionsConnectionArgs
+{
+    fn from(
+        r: (
+            Option<Address>,
+            Option<ContractId>,
+            Option<bool>,
+            PaginationRequest<String>,
+        ),
+    ) -> Self {
+        match r.3.direction {
+            PageDirection::Forward => TxPoolTransactionsConnectionArgs {
+                owner: r.0,
+                contract: r.1,
+                executable: r.2,
+                after: r.3.cursor,
+                before: None,
+                first: Some(r.3.results),
+                last: None,
+            },
+            PageDirection::Backward => TxPoolTransactionsConnectionArgs {
+                owner: r.0,
+                contract: r.1,
+                executable: r.2,
+                after: None,
+                before: r.3.cursor,
+                first: None,
+                last: Some(r.3.results),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TxPoolTransactionConnection {
+    pub edges: Vec<TxPoolTransactionEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TxPoolTransactionEdge {
+    pub cursor: String,
+    pub node: TxPoolTransaction,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TxPoolTransaction {
+    pub transaction: OpaqueTransaction,
+    pub bundle: Vec<OpaqueTransaction>,
+    pub tip: U64,
+    pub max_gas: U64,
+    pub insertion_time: Tai64Timestamp,
+    pub dependents_count: U32,
+    pub not_executable_reason: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxPoolTransactionsConnectionArgs"
+)]
+pub struct TxPoolTransactionsQuery {
+    #[arguments(owner: $owner, contract: $contract, executable: $executable, after: $after, before: $before, first: $first, last: $last)]
+    pub tx_pool_transactions: TxPoolTransactionConnection,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -606,12 +770,123 @@ pub struct AssembleTransactionResult {
     pub gas_price: U64,
 }
 
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct StateOverridesInput {
+    pub contracts: Vec<ContractOverrideInput>,
+    pub coins: Vec<FakeCoinInput>,
+    pub messages: Vec<FakeMessageInput>,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractOverrideInput {
+    pub contract_id: ContractId,
+    pub bytecode: Option<HexString>,
+    pub storage_slots: Vec<StorageSlotInput>,
+    pub balances: Vec<ContractBalanceInput>,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct StorageSlotInput {
+    pub key: crate::client::schema::Bytes32,
+    pub value: HexString,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractBalanceInput {
+    pub asset_id: AssetId,
+    pub amount: U64,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct FakeCoinInput {
+    pub utxo_id: UtxoId,
+    pub owner: Address,
+    pub asset_id: AssetId,
+    pub amount: U64,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct FakeMessageInput {
+    pub nonce: Nonce,
+    pub sender: Option<Address>,
+    pub recipient: Address,
+    pub amount: U64,
+    pub data: Option<HexString>,
+    pub da_height: Option<U64>,
+}
+
+impl From<StateOverrides> for StateOverridesInput {
+    fn from(overrides: StateOverrides) -> Self {
+        let contracts = overrides
+            .contracts
+            .into_iter()
+            .map(|contract| ContractOverrideInput {
+                contract_id: contract.contract_id.into(),
+                bytecode: contract.bytecode.map(|code| HexString(Bytes(code))),
+                storage_slots: contract
+                    .storage_slots
+                    .into_iter()
+                    .map(|(key, value)| StorageSlotInput {
+                        key: key.into(),
+                        value: HexString(Bytes(value)),
+                    })
+                    .collect(),
+                balances: contract
+                    .balances
+                    .into_iter()
+                    .map(|(asset_id, amount)| ContractBalanceInput {
+                        asset_id: asset_id.into(),
+                        amount: amount.into(),
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        let coins = overrides
+            .coins
+            .into_iter()
+            .map(|coin| FakeCoinInput {
+                utxo_id: coin.utxo_id.into(),
+                owner: coin.owner.into(),
+                asset_id: coin.asset_id.into(),
+                amount: coin.amount.into(),
+            })
+            .collect();
+
+        let messages = overrides
+            .messages
+            .into_iter()
+            .map(|message| FakeMessageInput {
+                nonce: (*message.nonce()).into(),
+                sender: Some((*message.sender()).into()),
+                recipient: (*message.recipient()).into(),
+                amount: message.amount().into(),
+                data: Some(HexString(Bytes(message.data().clone()))),
+                da_height: Some(message.da_height().0.into()),
+            })
+            .collect();
+
+        Self {
+            contracts,
+            coins,
+            messages,
+        }
+    }
+}
+
 #[derive(cynic::QueryVariables, Clone)]
 pub struct DryRunArg {
     pub txs: Vec<HexString>,
     pub utxo_validation: Option<bool>,
     pub gas_price: Option<U64>,
     pub block_height: Option<U32>,
+    pub state_overrides: Option<StateOverridesInput>,
 }
 
 #[derive(cynic::QueryFragment, Clone, Debug)]
@@ -621,7 +896,7 @@ pub struct DryRunArg {
     variables = "DryRunArg"
 )]
 pub struct DryRun {
-    #[arguments(txs: $txs, utxoValidation: $utxo_validation, gasPrice: $gas_price, blockHeight: $block_height
+    #[arguments(txs: $txs, utxoValidation: $utxo_validation, gasPrice: $gas_price, blockHeight: $block_height, stateOverrides: $state_overrides
     )]
     pub dry_run: Vec<DryRunTransactionExecutionStatus>,
 }
@@ -640,7 +915,7 @@ pub struct DryRunStorageReads {
     variables = "DryRunArg"
 )]
 pub struct DryRunRecordStorageReads {
-    #[arguments(txs: $txs, utxoValidation: $utxo_validation, gasPrice: $gas_price, blockHeight: $block_height
+    #[arguments(txs: $txs, utxoValidation: $utxo_validation, gasPrice: $gas_price, blockHeight: $block_height, stateOverrides: $state_overrides
     )]
     pub dry_run_record_storage_reads: DryRunStorageReads,
 }
@@ -649,13 +924,24 @@ pub struct DryRunRecordStorageReads {
 #[cynic(
     schema_path = "./assets/schema.sdl",
     graphql_type = "Mutation",
-    variables = "TxWithEstimatedPredicatesArg"
+    variables = "SubmitArg"
 )]
 pub struct Submit {
-    #[arguments(tx: $tx, estimatePredicates: $estimate_predicates)]
+    #[arguments(tx: $tx, estimatePredicates: $estimate_predicates, replaceable: $replaceable)]
     pub submit: TransactionIdFragment,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "TxsWithEstimatedPredicatesArg"
+)]
+pub struct SubmitBundle {
+    #[arguments(txs: $txs, estimatePredicates: $estimate_predicates)]
+    pub submit_bundle: Vec<TransactionIdFragment>,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -696,6 +982,85 @@ pub struct AllReceipts {
     pub all_receipts: Vec<Receipt>,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxIdArgs"
+)]
+pub struct TransactionTraceQuery {
+    #[arguments(id: $id)]
+    pub transaction_trace: TransactionTrace,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransactionTrace {
+    pub gas_used: U64,
+    pub receipts: Vec<Receipt>,
+    pub frames: Vec<CallFrameTrace>,
+    pub revert: Option<Receipt>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CallFrameTrace {
+    pub parent: Option<U32>,
+    pub contract_id: ContractId,
+    pub function_selector: U64,
+    pub param2: U64,
+    pub asset_id: AssetId,
+    pub amount: U64,
+    pub gas_forwarded: U64,
+    pub gas_used: U64,
+    pub receipts: Vec<Receipt>,
+    pub revert: Option<Receipt>,
+}
+
+impl TryFrom<TransactionTrace> for fuel_core_types::services::executor::TransactionTrace {
+    type Error = ConversionError;
+
+    fn try_from(trace: TransactionTrace) -> Result<Self, Self::Error> {
+        Ok(Self {
+            gas_used: trace.gas_used.into(),
+            receipts: trace
+                .receipts
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            frames: trace
+                .frames
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            revert: trace.revert.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+impl TryFrom<CallFrameTrace> for fuel_core_types::services::executor::CallFrameTrace {
+    type Error = ConversionError;
+
+    fn try_from(frame: CallFrameTrace) -> Result<Self, Self::Error> {
+        Ok(Self {
+            parent: frame.parent.map(Into::into),
+            contract_id: frame.contract_id.into(),
+            function_selector: frame.function_selector.into(),
+            param2: frame.param2.into(),
+            asset_id: frame.asset_id.into(),
+            amount: frame.amount.into(),
+            gas_forwarded: frame.gas_forwarded.into(),
+            gas_used: frame.gas_used.into(),
+            receipts: frame
+                .receipts
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            revert: frame.revert.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Subscription")]
 pub struct PreconfirmationsSubscription {
@@ -706,7 +1071,6 @@ pub struct PreconfirmationsSubscription {
 #[cfg(test)]
 pub mod tests {
     use super::*;
-    use crate::client::schema::Bytes;
     use fuel_core_types::fuel_types::canonical::Serialize;
 
     #[cfg(not(feature = "test-helpers"))]
@@ -719,6 +1083,16 @@ pub mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[cfg(not(feature = "test-helpers"))]
+    #[test]
+    fn transaction_trace_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TransactionTraceQuery::build(TxIdArgs {
+            id: TransactionId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[cfg(not(feature = "test-helpers"))]
     #[test]
     fn opaque_transaction_by_id_query_gql_output() {
@@ -759,6 +1133,21 @@ pub mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[cfg(not(feature = "test-helpers"))]
+    #[test]
+    fn transactions_by_contract_gql_output() {
+        use cynic::QueryBuilder;
+        let operation =
+            TransactionsByContractQuery::build(TransactionsByContractConnectionArgs {
+                contract: Default::default(),
+                after: None,
+                before: None,
+                first: None,
+                last: None,
+            });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn dry_run_tx_gql_output() {
         use cynic::MutationBuilder;
@@ -768,6 +1157,7 @@ pub mod tests {
             utxo_validation: Some(true),
             gas_price: Some(123u64.into()),
             block_height: Some(456u32.into()),
+            state_overrides: None,
         });
         insta::assert_snapshot!(query.query)
     }
@@ -776,9 +1166,37 @@ pub mod tests {
     fn submit_tx_gql_output() {
         use cynic::MutationBuilder;
         let tx = fuel_tx::Transaction::default_test_tx();
-        let query = Submit::build(TxWithEstimatedPredicatesArg {
+        let query = Submit::build(SubmitArg {
             tx: HexString(Bytes(tx.to_bytes())),
             estimate_predicates: Some(true),
+            replaceable: Some(false),
+        });
+        insta::assert_snapshot!(query.query)
+    }
+
+    #[test]
+    fn tx_pool_transactions_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation =
+            TxPoolTransactionsQuery::build(TxPoolTransactionsConnectionArgs {
+                owner: Some(Address::default()),
+                contract: None,
+                executable: Some(false),
+                after: None,
+                before: None,
+                first: Some(10),
+                last: None,
+            });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn submit_bundle_gql_output() {
+        use cynic::MutationBuilder;
+        let tx = fuel_tx::Transaction::default_test_tx();
+        let query = SubmitBundle::build(TxsWithEstimatedPredicatesArg {
+            txs: vec![HexString(Bytes(tx.to_bytes()))],
+            estimate_predicates: Some(true),
         });
         insta::assert_snapshot!(query.query)
     }
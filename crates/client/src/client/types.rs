@@ -10,9 +10,11 @@ pub mod upgrades;
 pub mod assemble_tx;
 pub mod asset;
 pub mod gas_price;
+pub mod log;
 pub mod merkle_proof;
 pub mod message;
 pub mod node_info;
+pub mod tx_pool;
 
 pub use balance::Balance;
 pub use blob::Blob;
@@ -34,12 +36,17 @@ pub use gas_costs::{
     DependentCost,
     GasCosts,
 };
+pub use log::{
+    Log,
+    LogFilter,
+};
 pub use merkle_proof::MerkleProof;
 pub use message::{
     Message,
     MessageProof,
 };
 pub use node_info::NodeInfo;
+pub use tx_pool::TxPoolTransaction;
 
 use crate::client::{
     schema,
@@ -56,6 +56,7 @@ pub struct Header {
 pub enum Consensus {
     Genesis(Genesis),
     PoAConsensus(PoAConsensus),
+    BftConsensus(BftConsensus),
     Unknown,
 }
 
@@ -73,6 +74,12 @@ pub struct PoAConsensus {
     pub signature: Signature,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BftConsensus {
+    pub round: u32,
+    pub signatures: Vec<Signature>,
+}
+
 // GraphQL Translation
 
 impl TryFrom<schema::block::Header> for Header {
@@ -132,6 +139,9 @@ impl From<schema::block::Consensus> for Consensus {
             schema::block::Consensus::PoAConsensus(poa) => {
                 Consensus::PoAConsensus(poa.into())
             }
+            schema::block::Consensus::BftConsensus(bft) => {
+                Consensus::BftConsensus(bft.into())
+            }
             schema::block::Consensus::Unknown => Consensus::Unknown,
         }
     }
@@ -158,6 +168,22 @@ impl From<schema::block::PoAConsensus> for PoAConsensus {
     }
 }
 
+impl From<schema::block::BftConsensus> for BftConsensus {
+    fn from(value: schema::block::BftConsensus) -> Self {
+        Self {
+            round: value.round.into(),
+            signatures: value
+                .signatures
+                .into_iter()
+                .map(|signature| {
+                    let bytes: [u8; 64] = signature.0.0.into();
+                    Signature::from_bytes(bytes)
+                })
+                .collect(),
+        }
+    }
+}
+
 impl TryFrom<schema::block::Block> for Block {
     type Error = ConversionError;
 
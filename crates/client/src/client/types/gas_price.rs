@@ -28,3 +28,39 @@ impl From<schema::gas_price::EstimateGasPrice> for EstimateGasPrice {
         }
     }
 }
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockFees {
+    pub height: BlockHeight,
+    pub gas_price: u64,
+    /// The ratio of the gas used by the block to the block gas limit.
+    pub gas_used_ratio: f64,
+    /// The tips paid at each of the requested percentiles.
+    pub reward: Vec<u64>,
+}
+
+impl From<schema::gas_price::BlockFees> for BlockFees {
+    fn from(value: schema::gas_price::BlockFees) -> Self {
+        Self {
+            height: BlockHeight::new(value.height.into()),
+            gas_price: value.gas_price.into(),
+            gas_used_ratio: value.gas_used_ratio,
+            reward: value.reward.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeeHistory {
+    pub oldest_block: BlockHeight,
+    pub blocks: Vec<BlockFees>,
+}
+
+impl From<schema::gas_price::FeeHistory> for FeeHistory {
+    fn from(value: schema::gas_price::FeeHistory) -> Self {
+        Self {
+            oldest_block: BlockHeight::new(value.oldest_block.into()),
+            blocks: value.blocks.into_iter().map(Into::into).collect(),
+        }
+    }
+}
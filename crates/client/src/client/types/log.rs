@@ -0,0 +1,82 @@
+use crate::client::{
+    PaginatedResult,
+    schema,
+    schema::ConversionError,
+    types::primitives::{
+        ContractId,
+        TransactionId,
+    },
+};
+use fuel_core_types::{
+    fuel_tx::Receipt,
+    fuel_types::BlockHeight,
+};
+
+/// Selects logs emitted by the `contract_id` with the log id `rb`
+/// within the optional block height range.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LogFilter {
+    pub contract_id: ContractId,
+    pub rb: u64,
+    pub from_height: Option<BlockHeight>,
+    pub to_height: Option<BlockHeight>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Log {
+    pub contract_id: ContractId,
+    pub rb: u64,
+    pub block_height: BlockHeight,
+    pub tx_index: u16,
+    pub receipt_index: u32,
+    pub transaction_id: TransactionId,
+    pub receipt: Receipt,
+}
+
+// GraphQL Translation
+
+impl From<LogFilter> for schema::logs::LogFilterInput {
+    fn from(value: LogFilter) -> Self {
+        Self {
+            contract_id: value.contract_id.into(),
+            rb: value.rb.into(),
+            from_height: value.from_height.map(Into::into),
+            to_height: value.to_height.map(Into::into),
+        }
+    }
+}
+
+impl TryFrom<schema::logs::Log> for Log {
+    type Error = ConversionError;
+
+    fn try_from(value: schema::logs::Log) -> Result<Self, Self::Error> {
+        Ok(Self {
+            contract_id: value.contract_id.into(),
+            rb: value.rb.into(),
+            block_height: value.block_height.into(),
+            tx_index: value.tx_index.into(),
+            receipt_index: value.receipt_index.into(),
+            transaction_id: value.transaction_id.into(),
+            receipt: value.receipt.try_into()?,
+        })
+    }
+}
+
+impl TryFrom<schema::logs::LogConnection> for PaginatedResult<Log, String> {
+    type Error = ConversionError;
+
+    fn try_from(conn: schema::logs::LogConnection) -> Result<Self, Self::Error> {
+        let results = conn
+            .edges
+            .into_iter()
+            .map(|e| e.node.try_into())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results,
+        })
+    }
+}
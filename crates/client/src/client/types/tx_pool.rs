@@ -0,0 +1,84 @@
+use crate::client::{
+    PaginatedResult,
+    schema::{
+        self,
+        ConversionError,
+    },
+};
+use fuel_core_types::{
+    fuel_tx::Transaction,
+    fuel_types::canonical::Deserialize,
+};
+use tai64::Tai64;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxPoolTransaction {
+    pub transaction: Transaction,
+    /// Other transactions of the bundle headed by this transaction,
+    /// in the order of the bundle.
+    pub bundle: Vec<Transaction>,
+    pub tip: u64,
+    pub max_gas: u64,
+    /// The time when the transaction was inserted into the pool.
+    pub insertion_time: Tai64,
+    /// The number of transactions of the pool that depend on this transaction.
+    pub dependents_count: u32,
+    /// The reason why the transaction can't be included in the next block,
+    /// `None` if the transaction is executable.
+    pub not_executable_reason: Option<String>,
+}
+
+impl TxPoolTransaction {
+    pub fn is_executable(&self) -> bool {
+        self.not_executable_reason.is_none()
+    }
+}
+
+// GraphQL Translation
+
+impl TryFrom<schema::tx::TxPoolTransaction> for TxPoolTransaction {
+    type Error = ConversionError;
+
+    fn try_from(value: schema::tx::TxPoolTransaction) -> Result<Self, Self::Error> {
+        let transaction = Transaction::from_bytes(&value.transaction.raw_payload)
+            .map_err(ConversionError::TransactionFromBytesError)?;
+        let bundle = value
+            .bundle
+            .iter()
+            .map(|tx| Transaction::from_bytes(&tx.raw_payload))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(ConversionError::TransactionFromBytesError)?;
+        Ok(Self {
+            transaction,
+            bundle,
+            tip: value.tip.into(),
+            max_gas: value.max_gas.into(),
+            insertion_time: value.insertion_time.0,
+            dependents_count: value.dependents_count.into(),
+            not_executable_reason: value.not_executable_reason,
+        })
+    }
+}
+
+impl TryFrom<schema::tx::TxPoolTransactionConnection>
+    for PaginatedResult<TxPoolTransaction, String>
+{
+    type Error = ConversionError;
+
+    fn try_from(
+        conn: schema::tx::TxPoolTransactionConnection,
+    ) -> Result<Self, Self::Error> {
+        let results = conn
+            .edges
+            .into_iter()
+            .map(|e| e.node.try_into())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results,
+        })
+    }
+}
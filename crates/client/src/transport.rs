@@ -37,6 +37,12 @@ use std::{
     },
 };
 
+#[cfg(feature = "subscriptions")]
+mod websocket;
+
+/// The header used to authenticate the client with the API key.
+const API_KEY_HEADER: &str = "x-api-key";
+
 #[cfg(feature = "subscriptions")]
 type SseConnector = hyper_rustls::HttpsConnector<hyper::client::HttpConnector>;
 
@@ -45,6 +51,8 @@ pub struct FailoverTransport {
     client: reqwest::Client,
     urls: Box<[Url]>,
     default_url_index: AtomicUsize,
+    /// The API key sent with each request in the `x-api-key` header.
+    api_key: Option<String>,
     #[cfg(feature = "subscriptions")]
     cookie: Arc<reqwest::cookie::Jar>,
     /// Lazily initialized hyper client shared by all subscriptions (and all
@@ -54,6 +62,11 @@ pub struct FailoverTransport {
     /// per call.
     #[cfg(feature = "subscriptions")]
     sse_client: Arc<std::sync::OnceLock<hyper::Client<SseConnector>>>,
+    /// The WebSocket transport for each URL. If set, subscriptions are
+    /// multiplexed over one WebSocket connection instead of opening
+    /// an SSE stream per subscription.
+    #[cfg(feature = "subscriptions")]
+    websockets: Option<Arc<[websocket::WebSocketTransport]>>,
 }
 
 impl Clone for FailoverTransport {
@@ -64,10 +77,13 @@ impl Clone for FailoverTransport {
             default_url_index: AtomicUsize::new(
                 self.default_url_index.load(Ordering::Relaxed),
             ),
+            api_key: self.api_key.clone(),
             #[cfg(feature = "subscriptions")]
             cookie: self.cookie.clone(),
             #[cfg(feature = "subscriptions")]
             sse_client: self.sse_client.clone(),
+            #[cfg(feature = "subscriptions")]
+            websockets: self.websockets.clone(),
         }
     }
 }
@@ -84,8 +100,10 @@ impl FailoverTransport {
                 urls: urls.into_boxed_slice(),
                 client,
                 default_url_index: AtomicUsize::new(0),
+                api_key: None,
                 cookie,
                 sse_client: Default::default(),
+                websockets: None,
             })
         }
 
@@ -96,6 +114,7 @@ impl FailoverTransport {
                 client,
                 urls: urls.into_boxed_slice(),
                 default_url_index: AtomicUsize::new(0),
+                api_key: None,
             })
         }
     }
@@ -105,6 +124,28 @@ impl FailoverTransport {
         &self.urls[default_index]
     }
 
+    pub fn set_api_key(&mut self, api_key: Option<String>) {
+        self.api_key = api_key;
+        #[cfg(feature = "subscriptions")]
+        if self.websockets.is_some() {
+            self.use_websocket_subscriptions();
+        }
+    }
+
+    /// Serves all subscriptions over the `graphql-transport-ws` WebSocket
+    /// protocol, multiplexing them over one connection per URL.
+    #[cfg(feature = "subscriptions")]
+    pub fn use_websocket_subscriptions(&mut self) {
+        let websockets = self
+            .urls
+            .iter()
+            .map(|url| {
+                websocket::WebSocketTransport::new(url.clone(), self.api_key.clone())
+            })
+            .collect();
+        self.websockets = Some(websockets);
+    }
+
     pub async fn query<ResponseData, Vars>(
         &self,
         q: Operation<ResponseData, Vars>,
@@ -177,10 +218,20 @@ impl FailoverTransport {
                 .ok_or_else(|| io::Error::other("Invalid URL count"))?;
             let url = self.urls[url_index].clone();
             let query = ResponseData::build(variables.clone());
-            match self
-                .internal_subscribe(query, url, required_block_height)
-                .await
-            {
+            let result = match &self.websockets {
+                Some(websockets) => {
+                    let operation = FuelOperation::new(query, required_block_height);
+                    websockets[url_index]
+                        .subscribe(operation)
+                        .await
+                        .map(futures::future::Either::Left)
+                }
+                None => self
+                    .internal_subscribe(query, url, required_block_height)
+                    .await
+                    .map(futures::future::Either::Right),
+            };
+            match result {
                 Ok(response_data) => {
                     if url_offset != 0 {
                         self.default_url_index.store(url_index, Ordering::Relaxed);
@@ -204,8 +255,11 @@ impl FailoverTransport {
         ResponseData: DeserializeOwned + QueryFragment + Send + 'static,
     {
         let fuel_operation = FuelOperation::new(q, required_block_height);
-        self.client
-            .post(url)
+        let mut request = self.client.post(url);
+        if let Some(api_key) = &self.api_key {
+            request = request.header(API_KEY_HEADER, api_key);
+        }
+        request
             .run_fuel_graphql(fuel_operation)
             .await
             .map_err(io::Error::other)
@@ -254,6 +308,15 @@ impl FailoverTransport {
                 })?;
         }
 
+        if let Some(api_key) = &self.api_key {
+            client_builder =
+                client_builder
+                    .header(API_KEY_HEADER, api_key)
+                    .map_err(|e| {
+                        io::Error::other(format!("Failed to add header to client {e:?}"))
+                    })?;
+        }
+
         if let Some(value) = self.cookie.deref().cookies(&url) {
             let value = value.to_str().map_err(|e| {
                 io::Error::other(format!("Unable convert header value to string {e:?}"))
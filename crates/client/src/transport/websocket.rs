@@ -0,0 +1,341 @@
+//! The [`graphql-transport-ws`](https://github.com/enisdenjo/graphql-ws/blob/master/PROTOCOL.md)
+//! transport that multiplexes all subscriptions of the client over one WebSocket connection.
+
+use super::API_KEY_HEADER;
+use crate::reqwest_ext::{
+    FuelGraphQlResponse,
+    FuelOperation,
+};
+use base64::prelude::{
+    BASE64_STANDARD,
+    Engine as _,
+};
+use cynic::StreamingOperation;
+use futures::{
+    Stream,
+    StreamExt,
+    stream::BoxStream,
+};
+use hyper::service::Service;
+use reqwest::Url;
+use serde::{
+    Serialize,
+    de::DeserializeOwned,
+};
+use soketto::{
+    connection::Sender,
+    handshake::{
+        Client,
+        ServerResponse,
+        client::Header,
+    },
+};
+use std::{
+    collections::HashMap,
+    io,
+    sync::atomic::{
+        AtomicU64,
+        Ordering,
+    },
+};
+use tokio::sync::{
+    Mutex,
+    mpsc,
+};
+use tokio_util::compat::{
+    Compat,
+    TokioAsyncReadCompatExt,
+};
+
+const PROTOCOL: &str = "graphql-transport-ws";
+const ENDPOINT: &str = "/v1/graphql-ws";
+
+type Socket = Compat<hyper_rustls::MaybeHttpsStream<tokio::net::TcpStream>>;
+type Responses = mpsc::UnboundedSender<io::Result<serde_json::Value>>;
+
+enum Command {
+    Subscribe {
+        id: String,
+        payload: serde_json::Value,
+        responses: Responses,
+    },
+    Complete {
+        id: String,
+    },
+}
+
+#[derive(serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ServerMessage {
+    ConnectionAck,
+    Next {
+        id: String,
+        payload: serde_json::Value,
+    },
+    Error {
+        id: String,
+        payload: serde_json::Value,
+    },
+    Complete {
+        id: String,
+    },
+    Ping,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Opens the connection lazily on the first subscription and reopens it
+/// if the previous connection was closed.
+#[derive(Debug)]
+pub struct WebSocketTransport {
+    url: Url,
+    api_key: Option<String>,
+    connection: Mutex<Option<mpsc::UnboundedSender<Command>>>,
+    next_id: AtomicU64,
+}
+
+impl WebSocketTransport {
+    pub fn new(url: Url, api_key: Option<String>) -> Self {
+        Self {
+            url,
+            api_key,
+            connection: Mutex::new(None),
+            next_id: AtomicU64::new(0),
+        }
+    }
+
+    pub async fn subscribe<ResponseData, Vars>(
+        &self,
+        operation: FuelOperation<StreamingOperation<ResponseData, Vars>>,
+    ) -> io::Result<
+        impl Stream<Item = io::Result<FuelGraphQlResponse<ResponseData>>>
+        + use<ResponseData, Vars>,
+    >
+    where
+        Vars: Serialize,
+        ResponseData: DeserializeOwned + 'static + Send,
+    {
+        let payload = serde_json::to_value(&operation)?;
+        let commands = self.connection().await?;
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed).to_string();
+        let (responses, receiver) = mpsc::unbounded_channel();
+        commands
+            .send(Command::Subscribe {
+                id: id.clone(),
+                payload,
+                responses,
+            })
+            .map_err(|_| connection_closed())?;
+
+        // Stops the subscription on the node when the stream is dropped.
+        let guard = CompleteOnDrop { id, commands };
+        let stream = futures::stream::unfold(
+            (receiver, guard),
+            |(mut receiver, guard)| async move {
+                let response = receiver.recv().await?.and_then(|value| {
+                    serde_json::from_value(value)
+                        .map_err(|e| io::Error::other(format!("Json error: {e:?}")))
+                });
+                Some((response, (receiver, guard)))
+            },
+        );
+        Ok(stream)
+    }
+
+    async fn connection(&self) -> io::Result<mpsc::UnboundedSender<Command>> {
+        let mut connection = self.connection.lock().await;
+        if let Some(commands) = connection.as_ref().filter(|c| !c.is_closed()) {
+            return Ok(commands.clone())
+        }
+
+        let commands = connect(&self.url, self.api_key.as_deref()).await?;
+        *connection = Some(commands.clone());
+        Ok(commands)
+    }
+}
+
+struct CompleteOnDrop {
+    id: String,
+    commands: mpsc::UnboundedSender<Command>,
+}
+
+impl Drop for CompleteOnDrop {
+    fn drop(&mut self) {
+        let id = core::mem::take(&mut self.id);
+        let _ = self.commands.send(Command::Complete { id });
+    }
+}
+
+fn connection_closed() -> io::Error {
+    io::Error::other("The WebSocket connection is closed")
+}
+
+async fn connect(
+    url: &Url,
+    api_key: Option<&str>,
+) -> io::Result<mpsc::UnboundedSender<Command>> {
+    let mut url = url.clone();
+    url.set_path(ENDPOINT);
+    let host = url
+        .host_str()
+        .ok_or_else(|| io::Error::other("The URL doesn't have a host"))?;
+    let host = match url.port() {
+        Some(port) => format!("{host}:{port}"),
+        None => host.to_string(),
+    };
+    let uri = url.as_str().parse().map_err(io::Error::other)?;
+
+    let mut connector = hyper_rustls::HttpsConnectorBuilder::new()
+        .with_webpki_roots()
+        .https_or_http()
+        .enable_http1()
+        .build();
+    futures::future::poll_fn(|cx| connector.poll_ready(cx))
+        .await
+        .map_err(io::Error::other)?;
+    let socket = connector.call(uri).await.map_err(io::Error::other)?;
+
+    let authorization = url.password().map(|password| {
+        let credentials = format!("{}:{}", url.username(), password);
+        format!("Basic {}", BASE64_STANDARD.encode(credentials))
+    });
+    let headers: Vec<_> = api_key
+        .map(|api_key| Header {
+            name: API_KEY_HEADER,
+            value: api_key.as_bytes(),
+        })
+        .into_iter()
+        .chain(authorization.as_deref().map(|authorization| Header {
+            name: "Authorization",
+            value: authorization.as_bytes(),
+        }))
+        .collect();
+    let mut client = Client::new(socket.compat(), &host, ENDPOINT);
+    client.add_protocol(PROTOCOL).set_headers(&headers);
+    match client.handshake().await.map_err(io::Error::other)? {
+        ServerResponse::Accepted { .. } => {}
+        ServerResponse::Redirect { status_code, .. }
+        | ServerResponse::Rejected { status_code } => {
+            return Err(io::Error::other(format!(
+                "The WebSocket handshake was rejected with status {status_code}"
+            )))
+        }
+    }
+
+    let (mut sender, receiver) = client.into_builder().finish();
+    let mut incoming = futures::stream::unfold(receiver, |mut receiver| async move {
+        let mut message = Vec::new();
+        match receiver.receive_data(&mut message).await {
+            Ok(_) => Some((serde_json::from_slice::<ServerMessage>(&message), receiver)),
+            Err(e) => {
+                tracing::debug!("The WebSocket connection is closed: {e:?}");
+                None
+            }
+        }
+    })
+    .boxed();
+
+    send(
+        &mut sender,
+        serde_json::json!({ "type": "connection_init", "payload": {} }),
+    )
+    .await?;
+    match incoming.next().await {
+        Some(Ok(ServerMessage::ConnectionAck)) => {}
+        _ => {
+            return Err(io::Error::other(
+                "The node didn't acknowledge the connection",
+            ))
+        }
+    }
+
+    let (commands, commands_receiver) = mpsc::unbounded_channel();
+    tokio::spawn(run(sender, incoming, commands_receiver));
+    Ok(commands)
+}
+
+async fn send(sender: &mut Sender<Socket>, message: serde_json::Value) -> io::Result<()> {
+    sender
+        .send_text_owned(message.to_string())
+        .await
+        .map_err(io::Error::other)?;
+    sender.flush().await.map_err(io::Error::other)
+}
+
+/// Routes the messages of the connection to the subscriptions until
+/// the connection is closed or all clients are dropped.
+async fn run(
+    mut sender: Sender<Socket>,
+    mut incoming: BoxStream<'static, serde_json::Result<ServerMessage>>,
+    mut commands: mpsc::UnboundedReceiver<Command>,
+) {
+    let mut subscriptions: HashMap<String, Responses> = HashMap::new();
+
+    loop {
+        let result = tokio::select! {
+            command = commands.recv() => {
+                let Some(command) = command else {
+                    break
+                };
+                match command {
+                    Command::Subscribe { id, payload, responses } => {
+                        subscriptions.insert(id.clone(), responses);
+                        let message = serde_json::json!({
+                            "id": id,
+                            "type": "subscribe",
+                            "payload": payload,
+                        });
+                        send(&mut sender, message).await
+                    }
+                    Command::Complete { id } => {
+                        if subscriptions.remove(&id).is_none() {
+                            continue
+                        }
+                        let message = serde_json::json!({ "id": id, "type": "complete" });
+                        send(&mut sender, message).await
+                    }
+                }
+            }
+            message = incoming.next() => {
+                match message {
+                    Some(Ok(ServerMessage::Next { id, payload })) => {
+                        if let Some(responses) = subscriptions.get(&id) {
+                            let _ = responses.send(Ok(payload));
+                        }
+                        Ok(())
+                    }
+                    Some(Ok(ServerMessage::Error { id, payload })) => {
+                        if let Some(responses) = subscriptions.remove(&id) {
+                            let error = io::Error::other(format!("Graphql error: {payload}"));
+                            let _ = responses.send(Err(error));
+                        }
+                        Ok(())
+                    }
+                    Some(Ok(ServerMessage::Complete { id })) => {
+                        subscriptions.remove(&id);
+                        Ok(())
+                    }
+                    Some(Ok(ServerMessage::Ping)) => {
+                        send(&mut sender, serde_json::json!({ "type": "pong" })).await
+                    }
+                    Some(Ok(ServerMessage::ConnectionAck | ServerMessage::Unknown)) => Ok(()),
+                    Some(Err(e)) => {
+                        tracing::warn!("Failed to decode the WebSocket message: {e:?}");
+                        Ok(())
+                    }
+                    None => break,
+                }
+            }
+        };
+
+        if let Err(e) = result {
+            tracing::debug!("Failed to send the WebSocket message: {e:?}");
+            break
+        }
+    }
+
+    for (_, responses) in subscriptions {
+        let _ = responses.send(Err(connection_closed()));
+    }
+    let _ = sender.close().await;
+}
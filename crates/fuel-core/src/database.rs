@@ -15,7 +15,6 @@ use crate::{
     state::{
         ColumnType,
         IterableKeyValueView,
-        KeyValueView,
         data_source::{
             DataSource,
             DataSourceType,
@@ -44,6 +43,7 @@ use fuel_core_storage::{
         changes_iterator::ChangesIterator,
     },
     not_found,
+    structured_storage::StructuredStorage,
     tables::FuelBlocks,
     transactional::{
         AtomicView,
@@ -135,10 +135,8 @@ where
 
 pub type Database<Description = OnChain, Stage = RegularStage<Description>> =
     GenericDatabase<DataSource<Description, Stage>, Empty>;
-pub type OnChainKeyValueView = KeyValueView<ColumnType<OnChain>, HeightType<OnChain>>;
 pub type OnChainIterableKeyValueView =
     IterableKeyValueView<ColumnType<OnChain>, HeightType<OnChain>>;
-pub type OffChainKeyValueView = KeyValueView<ColumnType<OffChain>, HeightType<OffChain>>;
 pub type OffChainIterableKeyValueView =
     IterableKeyValueView<ColumnType<OffChain>, HeightType<OffChain>>;
 pub type RelayerIterableKeyValueView =
@@ -389,7 +387,8 @@ where
     Description: DatabaseDescription,
 {
     type Height = Description::Height;
-    type ViewAtHeight = KeyValueView<ColumnType<Description>, Description::Height>;
+    type ViewAtHeight =
+        IterableKeyValueView<ColumnType<Description>, Description::Height>;
 
     fn latest_height(&self) -> Option<Self::Height> {
         *self.inner_storage().stage.height.lock()
@@ -400,14 +399,10 @@ where
 
         match *lock {
             None => {
-                return self
-                    .latest_view_with_height(None)
-                    .map(|view| view.into_key_value_view())
+                return self.latest_view_with_height(None);
             }
             Some(current_height) if &current_height == height => {
-                return self
-                    .latest_view_with_height(Some(current_height))
-                    .map(|view| view.into_key_value_view())
+                return self.latest_view_with_height(Some(current_height));
             }
             _ => {}
         };
@@ -517,12 +512,19 @@ where
     Description::Height: Debug + PartialOrd + DatabaseHeight,
     for<'a> StorageTransaction<&'a &'a mut Database<Description>>:
         StorageMutate<MetadataTable<Description>, Error = StorageError>,
+    for<'a> StructuredStorage<&'a ChangesIterator<'a, Description::Column>>:
+        StorageInspect<MetadataTable<Description>, Error = StorageError>,
     Changes: Into<StorageChanges>,
 {
     // Gets the all new heights from the `changes`
     let mut changes = changes.into();
     let iterator = ChangesIterator::<Description::Column>::new(&changes);
     let new_heights = heights_lookup(&iterator)?;
+    // The `changes` may update the metadata, e.g., to revoke the indexation availability.
+    let changed_metadata = StructuredStorage::new(&iterator)
+        .storage::<MetadataTable<Description>>()
+        .get(&())?
+        .map(Cow::into_owned);
 
     // Changes for each block should be committed separately.
     // If we have more than one height, it means we are mixing commits
@@ -581,9 +583,12 @@ where
             ConflictPolicy::Overwrite,
             Default::default(),
         );
-        let maybe_current_metadata = transaction
-            .storage_as_mut::<MetadataTable<Description>>()
-            .get(&())?;
+        let maybe_current_metadata = match changed_metadata {
+            Some(metadata) => Some(Cow::Owned(metadata)),
+            None => transaction
+                .storage_as_mut::<MetadataTable<Description>>()
+                .get(&())?,
+        };
         let metadata = update_metadata::<Description>(maybe_current_metadata, new_height);
         transaction
             .storage_as_mut::<MetadataTable<Description>>()
@@ -841,10 +846,16 @@ mod tests {
         use crate::{
             database::{
                 DatabaseHeight,
-                database_description::off_chain::OffChain,
+                database_description::{
+                    IndexationKind,
+                    off_chain::OffChain,
+                },
             },
             fuel_core_graphql_api::storage::messages::OwnedMessageKey,
-            graphql_api::storage::messages::OwnedMessageIds,
+            graphql_api::{
+                ports::worker::OffChainDatabaseTransaction,
+                storage::messages::OwnedMessageIds,
+            },
         };
         use fuel_core_storage::transactional::WriteTransaction;
 
@@ -990,6 +1001,38 @@ mod tests {
                 .to_string()
             );
         }
+
+        #[test]
+        fn database_keeps_indexation_revoked_by_the_commit() {
+            let mut database = Database::<OffChain>::default();
+            assert!(database.indexation_available(IndexationKind::Logs).unwrap());
+
+            // Given
+            let height = 1.into();
+            let mut transaction = database.write_transaction();
+            transaction
+                .storage_as_mut::<FuelBlockIdsToHeights>()
+                .insert(&Default::default(), &height)
+                .unwrap();
+            OffChainDatabaseTransaction::revoke_indexation(
+                &mut transaction,
+                IndexationKind::Logs,
+                height,
+            )
+            .unwrap();
+
+            // When
+            transaction.commit().unwrap();
+
+            // Then
+            assert_eq!(database.latest_height(), Some(height));
+            assert!(!database.indexation_available(IndexationKind::Logs).unwrap());
+            assert!(
+                database
+                    .indexation_available(IndexationKind::Balances)
+                    .unwrap()
+            );
+        }
     }
 
     #[cfg(feature = "relayer")]
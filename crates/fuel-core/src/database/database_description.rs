@@ -88,6 +88,9 @@ pub enum IndexationKind {
     Balances,
     CoinsToSpend,
     AssetMetadata,
+    ContractTransactions,
+    Logs,
+    FeeHistory,
 }
 
 impl IndexationKind {
@@ -1,6 +1,8 @@
 use crate::{
     database::OffChainIterableKeyValueView,
     fuel_core_graphql_api::storage::transactions::{
+        ContractTransactionIndexKey,
+        ContractTransactions,
         OwnedTransactionIndexCursor,
         OwnedTransactionIndexKey,
         OwnedTransactions,
@@ -20,7 +22,10 @@ use fuel_core_types::{
         Bytes32,
         TxPointer,
     },
-    fuel_types::Address,
+    fuel_types::{
+        Address,
+        ContractId,
+    },
     services::transaction_status::TransactionExecutionStatus,
 };
 
@@ -63,6 +68,32 @@ impl OffChainIterableKeyValueView {
         })
     }
 
+    /// Iterates over a KV mapping of `[contract id + block height + tx idx] => transaction id`.
+    /// Similar to [`Self::owned_transactions`], but for transactions that called or created
+    /// the contract.
+    pub fn contract_transactions(
+        &self,
+        contract_id: ContractId,
+        start: Option<OwnedTransactionIndexCursor>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = StorageResult<(TxPointer, Bytes32)>> + '_ {
+        let start = start.map(|cursor| {
+            ContractTransactionIndexKey::new(
+                &contract_id,
+                cursor.block_height,
+                cursor.tx_idx,
+            )
+        });
+        self.iter_all_filtered::<ContractTransactions, _>(
+            Some(contract_id),
+            start.as_ref(),
+            direction,
+        )
+        .map(|res| {
+            res.map(|(key, tx_id)| (TxPointer::new(key.block_height, key.tx_idx), tx_id))
+        })
+    }
+
     pub fn get_tx_status(
         &self,
         id: &Bytes32,
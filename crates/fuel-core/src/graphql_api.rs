@@ -1,3 +1,4 @@
+use crate::database::database_description::IndexationKind;
 use async_graphql::Context;
 use fuel_core_storage::{
     Error as StorageError,
@@ -5,17 +6,20 @@ use fuel_core_storage::{
 };
 use std::{
     net::SocketAddr,
+    path::PathBuf,
     sync::OnceLock,
     time::Duration,
 };
 
 pub mod api_service;
+pub mod authorization;
 pub(crate) mod block_height_subscription;
 pub mod database;
 pub(crate) mod extensions;
 pub(crate) mod indexation;
 pub mod ports;
 pub mod storage;
+pub(crate) mod websocket;
 pub mod worker_service;
 
 #[derive(Clone, Debug)]
@@ -26,10 +30,16 @@ pub struct Config {
     pub allow_syscall: bool,
     pub historical_execution: bool,
     pub expensive_subscriptions: bool,
+    pub logs_indexation: bool,
+    pub fee_history_indexation: bool,
+    pub peer_admin_api: bool,
+    /// The node produces blocks, either by itself or as a BFT validator.
+    pub block_production: bool,
     pub max_tx: usize,
     pub max_gas: u64,
     pub max_size: usize,
     pub max_txpool_dependency_chain_length: usize,
+    pub max_bundle_size: usize,
     pub chain_name: String,
 }
 
@@ -45,6 +55,8 @@ pub struct ServiceConfig {
     pub max_queries_resolver_recursive_depth: usize,
     pub max_queries_directives: usize,
     pub max_concurrent_queries: usize,
+    /// The max number of simultaneously open WebSocket connections.
+    pub max_websocket_connections: usize,
     pub request_body_bytes_limit: usize,
     /// Number of blocks that the node can be lagging behind the required fuel block height
     /// before it will be considered out of sync.
@@ -59,6 +71,41 @@ pub struct ServiceConfig {
     pub assemble_tx_estimate_predicates_limit: usize,
     /// Configurable cost parameters to limit graphql queries complexity
     pub costs: Costs,
+    /// Limits the accumulated complexity of queries per client.
+    pub rate_limit: RateLimitConfig,
+    /// The path to the file with API keys and their roles.
+    /// When set, privileged operations are available to the API keys with
+    /// the corresponding role.
+    pub api_keys_path: Option<PathBuf>,
+}
+
+/// The configuration of the per-client rate limiting. Each client has a budget
+/// of the query complexity that it can spend during the time window.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RateLimitConfig {
+    /// The duration of the window after which budgets of all clients are reset.
+    pub window: Duration,
+    /// The budget of clients identified by the IP address.
+    /// `None` means that the IP clients are not limited.
+    pub ip_complexity_per_window: Option<usize>,
+    /// The budget of clients identified by one of the configured API keys.
+    /// `None` means that the API key clients are not limited.
+    pub api_key_complexity_per_window: Option<usize>,
+    /// The maximum number of clients tracked during the window. Queries of new
+    /// clients are rejected until the end of the window once it is reached.
+    pub max_tracked_clients: usize,
+}
+
+impl RateLimitConfig {
+    /// The configuration that doesn't limit any clients.
+    pub fn unlimited() -> Self {
+        Self {
+            window: Duration::from_secs(60),
+            ip_complexity_per_window: None,
+            api_key_complexity_per_window: None,
+            max_tracked_clients: 100_000,
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -70,6 +117,7 @@ pub struct Costs {
     pub assemble_tx: usize,
     pub dry_run: usize,
     pub storage_read_replay: usize,
+    pub transaction_trace: usize,
     pub submit: usize,
     pub submit_and_await: usize,
     pub status_change: usize,
@@ -84,6 +132,7 @@ pub struct Costs {
     pub bytecode_read: usize,
     pub state_transition_bytecode_read: usize,
     pub da_compressed_block_read: usize,
+    pub consensus_status: usize,
 }
 
 #[cfg(feature = "test-helpers")]
@@ -104,6 +153,7 @@ pub const DEFAULT_QUERY_COSTS: Costs = Costs {
     dry_run: 12000,
     assemble_tx: 76_000,
     storage_read_replay: 40001,
+    transaction_trace: 40001,
     submit: 40001,
     submit_and_await: 40001,
     status_change: 40001,
@@ -118,6 +168,7 @@ pub const DEFAULT_QUERY_COSTS: Costs = Costs {
     bytecode_read: 8000,
     state_transition_bytecode_read: 76_000,
     da_compressed_block_read: 4000,
+    consensus_status: 40001,
 };
 
 pub fn query_costs() -> &'static Costs {
@@ -198,3 +249,59 @@ pub fn require_expensive_subscriptions(ctx: &Context<'_>) -> async_graphql::Resu
         ))
     }
 }
+
+pub fn require_peer_admin(ctx: &Context<'_>) -> async_graphql::Result<()> {
+    let config = ctx.data_unchecked::<Config>();
+
+    if config.peer_admin_api
+        || authorization::has_role(ctx, authorization::Role::PeerAdmin)
+    {
+        Ok(())
+    } else if config.config.api_keys_path.is_some() {
+        Err(async_graphql::Error::new(format!(
+            "The API key must have the `{}` role to use this endpoint",
+            authorization::Role::PeerAdmin.as_str()
+        )))
+    } else {
+        Err(async_graphql::Error::new(
+            "`--peer-admin-api` is required for this operation",
+        ))
+    }
+}
+
+pub fn require_logs_indexation(ctx: &Context<'_>) -> async_graphql::Result<()> {
+    let config = ctx.data_unchecked::<Config>();
+    let database = ctx.data_unchecked::<database::ReadDatabase>();
+
+    if !config.logs_indexation {
+        Err(async_graphql::Error::new(
+            "`--logs-indexation` is required for this operation",
+        ))
+    } else if !database.indexation_flags().contains(&IndexationKind::Logs) {
+        Err(async_graphql::Error::new(
+            "Logs indexation is not available for this database",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+pub fn require_fee_history_indexation(ctx: &Context<'_>) -> async_graphql::Result<()> {
+    let config = ctx.data_unchecked::<Config>();
+    let database = ctx.data_unchecked::<database::ReadDatabase>();
+
+    if !config.fee_history_indexation {
+        Err(async_graphql::Error::new(
+            "`--fee-history-indexation` is required for this operation",
+        ))
+    } else if !database
+        .indexation_flags()
+        .contains(&IndexationKind::FeeHistory)
+    {
+        Err(async_graphql::Error::new(
+            "Fee history indexation is not available for this database",
+        ))
+    } else {
+        Ok(())
+    }
+}
@@ -17,12 +17,22 @@ use crate::{
     },
     graphql_api::{
         self,
+        authorization::{
+            API_KEYS_RELOAD_INTERVAL,
+            ApiKeys,
+        },
         extensions::{
             chain_state_info::ChainStateInfoExtension,
             metrics::MetricsExtension,
+            rate_limit::{
+                API_KEY_HEADER,
+                ClientId,
+                RateLimitExtension,
+            },
             required_fuel_block_height::RequiredFuelBlockHeightExtension,
             validation::ValidationExtension,
         },
+        websocket,
     },
     schema::{
         CoreSchema,
@@ -34,6 +44,7 @@ use crate::{
     },
 };
 use async_graphql::{
+    Data,
     Request,
     Response,
     http::GraphiQLSource,
@@ -42,11 +53,15 @@ use axum::{
     Json,
     Router,
     extract::{
+        ConnectInfo,
         DefaultBodyLimit,
         Extension,
+        WebSocketUpgrade,
     },
     http::{
+        HeaderMap,
         HeaderValue,
+        StatusCode,
         header::{
             ACCESS_CONTROL_ALLOW_HEADERS,
             ACCESS_CONTROL_ALLOW_METHODS,
@@ -132,6 +147,7 @@ pub struct ServerParams {
     router: Router,
     listener: TcpListener,
     number_of_threads: usize,
+    api_keys: Option<Arc<ApiKeys>>,
 }
 
 pub struct Task {
@@ -146,6 +162,8 @@ pub struct Task {
     /// `__run_exit_handlers`, surfacing as SIGABRT/SIGSEGV at process
     /// exit.
     processor: Arc<AsyncProcessor>,
+    /// Polls the API keys file, if any, for modifications.
+    api_keys_reloader: Option<tokio::task::JoinHandle<()>>,
 }
 
 const GRAPHQL_SHUTDOWN_TIMEOUT: Duration = Duration::from_millis(500);
@@ -212,6 +230,7 @@ impl RunnableService for GraphqlService {
             router,
             listener,
             number_of_threads,
+            api_keys,
         } = params;
 
         let processor = Arc::new(AsyncProcessor::new(
@@ -225,10 +244,14 @@ impl RunnableService for GraphqlService {
             state: state.clone(),
         };
 
+        // WebSocket connections outlive their upgrade requests, so they are
+        // spawned on the same executor to be tracked and stopped on shutdown.
+        let router = router.layer(Extension(executor.clone()));
+
         let server = axum::Server::from_tcp(listener)
             .unwrap()
             .executor(executor)
-            .serve(router.into_make_service())
+            .serve(router.into_make_service_with_connect_info::<SocketAddr>())
             .with_graceful_shutdown(async move {
                 // Wait for an actual stop signal. Using `while_started` here would
                 // resolve immediately while the state is still `Starting`, racing
@@ -245,9 +268,14 @@ impl RunnableService for GraphqlService {
                 let _ = state.wait_stopping_or_stopped().await;
             });
 
+        let api_keys_reloader = api_keys.map(|api_keys| {
+            tokio::spawn(api_keys.reload_periodically(API_KEYS_RELOAD_INTERVAL))
+        });
+
         Ok(Task {
             server: tokio::spawn(server),
             processor,
+            api_keys_reloader,
         })
     }
 }
@@ -294,6 +322,9 @@ impl RunnableTask for Task {
         // global `Env::Default()` destructor in `__run_exit_handlers`,
         // surfacing as SIGABRT/SIGSEGV at process exit.
         self.processor.drain().await;
+        if let Some(api_keys_reloader) = self.api_keys_reloader {
+            api_keys_reloader.abort();
+        }
         Ok(())
     }
 }
@@ -362,6 +393,8 @@ where
         genesis_block_height,
         on_database,
         off_database,
+        config.logs_indexation,
+        config.fee_history_indexation,
     )?;
     let request_timeout = config.config.api_request_timeout;
     let concurrency_limit = config.config.max_concurrent_queries;
@@ -373,6 +406,16 @@ where
         config.config.required_fuel_block_height_tolerance;
     let required_fuel_block_height_timeout =
         config.config.required_fuel_block_height_timeout;
+    let rate_limit = config.config.rate_limit;
+    let websocket_limits =
+        websocket::Limits::new(config.config.max_websocket_connections, body_limit);
+    let api_keys = config
+        .config
+        .api_keys_path
+        .as_deref()
+        .map(ApiKeys::load)
+        .transpose()?
+        .map(Arc::new);
 
     let schema = schema
         .limit_complexity(config.config.max_queries_complexity)
@@ -385,6 +428,7 @@ where
         .extension(MetricsExtension::new(
             config.config.query_log_threshold_time,
         ))
+        .extension(RateLimitExtension::new(rate_limit))
         .data(config)
         .data(combined_read_database)
         .data(txpool)
@@ -410,6 +454,7 @@ where
 
     let graphql_endpoint = "/v1/graphql";
     let graphql_subscription_endpoint = "/v1/graphql-sub";
+    let graphql_ws_endpoint = "/v1/graphql-ws";
 
     let graphql_playground =
         || render_graphql_playground(graphql_endpoint, graphql_subscription_endpoint);
@@ -426,10 +471,15 @@ where
             graphql_subscription_endpoint,
             post(graphql_subscription_handler).options(ok),
         )
+        .route(
+            graphql_ws_endpoint,
+            get(graphql_ws_handler).layer(Extension(websocket_limits)),
+        )
         .route("/v1/metrics", get(metrics))
         .route("/v1/health", get(health))
         .route("/health", get(health))
         .layer(Extension(schema))
+        .layer(Extension(api_keys.clone()))
         .layer(TraceLayer::new_for_http())
         .layer(TimeoutLayer::new(request_timeout))
         .layer(SetResponseHeaderLayer::<_>::overriding(
@@ -457,6 +507,7 @@ where
             router,
             listener,
             number_of_threads,
+            api_keys,
         },
     ))
 }
@@ -504,11 +555,33 @@ async fn health() -> Json<serde_json::Value> {
     Json(json!({ "up": true }))
 }
 
+/// Attaches the identity of the client and the roles of its API key to the request data.
+fn authorize(
+    data: &mut Data,
+    address: SocketAddr,
+    headers: &HeaderMap,
+    api_keys: Option<&ApiKeys>,
+) {
+    data.insert(ClientId::new(address.ip(), headers, api_keys));
+
+    let api_key = headers
+        .get(API_KEY_HEADER)
+        .and_then(|value| value.to_str().ok());
+    if let (Some(api_keys), Some(api_key)) = (api_keys, api_key) {
+        data.insert(api_keys.roles(api_key));
+    }
+}
+
 async fn graphql_handler(
     schema: Extension<CoreSchema>,
+    Extension(api_keys): Extension<Option<Arc<ApiKeys>>>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
     req: Json<Request>,
 ) -> Json<Response> {
-    let response = schema.execute(req.0).await;
+    let mut req = req.0;
+    authorize(&mut req.data, address, &headers, api_keys.as_deref());
+    let response = schema.execute(req).await;
     let response = unify_response(response);
 
     response.into()
@@ -516,9 +589,14 @@ async fn graphql_handler(
 
 async fn graphql_subscription_handler(
     schema: Extension<CoreSchema>,
+    Extension(api_keys): Extension<Option<Arc<ApiKeys>>>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
     req: Json<Request>,
 ) -> Sse<impl Stream<Item = anyhow::Result<Event, serde_json::Error>>> {
-    let stream = schema.execute_stream(req.0).map(|response| {
+    let mut req = req.0;
+    authorize(&mut req.data, address, &headers, api_keys.as_deref());
+    let stream = schema.execute_stream(req).map(|response| {
         let response = unify_response(response);
         Event::default().json_data(response)
     });
@@ -526,6 +604,46 @@ async fn graphql_subscription_handler(
         .keep_alive(axum::response::sse::KeepAlive::new().text("keep-alive-text"))
 }
 
+async fn graphql_ws_handler(
+    Extension(schema): Extension<CoreSchema>,
+    Extension(api_keys): Extension<Option<Arc<ApiKeys>>>,
+    Extension(executor): Extension<ExecutorWithMetrics>,
+    Extension(limits): Extension<websocket::Limits>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    upgrade: WebSocketUpgrade,
+) -> axum::response::Response {
+    if !websocket::supports_protocol(&headers) {
+        return (
+            StatusCode::BAD_REQUEST,
+            "The client must support the `graphql-transport-ws` protocol",
+        )
+            .into_response()
+    }
+    let Some(permit) = limits.try_acquire_connection() else {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Too many open GraphQL WebSocket connections",
+        )
+            .into_response()
+    };
+
+    // The connection data is shared by all subscriptions of the connection.
+    let mut data = Data::default();
+    authorize(&mut data, address, &headers, api_keys.as_deref());
+
+    upgrade
+        .protocols([websocket::PROTOCOL.sec_websocket_protocol()])
+        .max_message_size(limits.max_message_size)
+        .max_frame_size(limits.max_message_size)
+        .on_upgrade(move |socket| async move {
+            executor.execute(async move {
+                websocket::serve(schema, data, socket).await;
+                drop(permit);
+            });
+        })
+}
+
 async fn ok() -> anyhow::Result<(), ()> {
     Ok(())
 }
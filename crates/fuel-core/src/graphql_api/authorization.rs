@@ -0,0 +1,237 @@
+use anyhow::Context as _;
+use async_graphql::Context;
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::Arc,
+    time::{
+        Duration,
+        SystemTime,
+    },
+};
+
+/// How often the API keys file is checked for modifications.
+pub const API_KEYS_RELOAD_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The role grants access to the privileged operations of the GraphQL API.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    /// Allows to produce blocks manually with `produceBlocks` mutation.
+    BlockProduction,
+    /// Allows to use the debugger mutations, like `startSession` or `execute`.
+    Debugger,
+    /// Allows to call `dryRun` with disabled UTXO validation or state overrides.
+    UncheckedDryRun,
+    /// Allows to manage the p2p peers, like `banPeer` or `addReservedPeer`.
+    PeerAdmin,
+}
+
+impl Role {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::BlockProduction => "block_production",
+            Role::Debugger => "debugger",
+            Role::UncheckedDryRun => "unchecked_dry_run",
+            Role::PeerAdmin => "peer_admin",
+        }
+    }
+}
+
+/// The roles granted to the request by its API key.
+/// The value is set in the request data by the graphql handler.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Roles(HashSet<Role>);
+
+impl Roles {
+    pub fn contains(&self, role: Role) -> bool {
+        self.0.contains(&role)
+    }
+}
+
+struct Snapshot {
+    modified: Option<SystemTime>,
+    keys: HashMap<String, Roles>,
+}
+
+/// API keys and their roles, loaded from the JSON file of the form:
+/// ```json
+/// {
+///   "<api key>": ["block_production", "debugger", "unchecked_dry_run"]
+/// }
+/// ```
+/// The file is polled by [`ApiKeys::reload_periodically`] and reloaded when its
+/// modification time changes, so keys can be added or revoked without
+/// restarting the node. Requests only read the in-memory snapshot.
+pub struct ApiKeys {
+    path: PathBuf,
+    snapshot: parking_lot::RwLock<Snapshot>,
+}
+
+impl ApiKeys {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let snapshot = read_snapshot(path)?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            snapshot: parking_lot::RwLock::new(snapshot),
+        })
+    }
+
+    /// Returns the roles of the `key`. Unknown keys don't have any roles.
+    pub fn roles(&self, key: &str) -> Roles {
+        self.snapshot
+            .read()
+            .keys
+            .get(key)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Returns `true` if the `key` is one of the configured API keys.
+    pub fn contains(&self, key: &str) -> bool {
+        self.snapshot.read().keys.contains_key(key)
+    }
+
+    /// Checks the file for modifications every `period` and reloads it
+    /// on the blocking thread pool, away from the request path.
+    pub async fn reload_periodically(self: Arc<Self>, period: Duration) {
+        let mut interval = tokio::time::interval(period);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        loop {
+            interval.tick().await;
+            let api_keys = self.clone();
+            let result =
+                tokio::task::spawn_blocking(move || api_keys.reload_if_modified()).await;
+            if let Err(e) = result {
+                tracing::error!("Failed to reload GraphQL API keys: {e:?}");
+            }
+        }
+    }
+
+    fn reload_if_modified(&self) {
+        let modified = modified_time(&self.path);
+        if modified == self.snapshot.read().modified {
+            return
+        }
+
+        match read_snapshot(&self.path) {
+            Ok(snapshot) => {
+                tracing::info!("Reloaded GraphQL API keys from {:?}", self.path);
+                *self.snapshot.write() = snapshot;
+            }
+            Err(e) => {
+                // Keep using the previous keys until the file is fixed.
+                tracing::warn!("Failed to reload GraphQL API keys: {e:?}");
+                self.snapshot.write().modified = modified;
+            }
+        }
+    }
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+fn read_snapshot(path: &Path) -> anyhow::Result<Snapshot> {
+    let modified = modified_time(path);
+    let content = std::fs::read(path)
+        .with_context(|| format!("Failed to read the API keys file {path:?}"))?;
+    let keys: HashMap<String, HashSet<Role>> = serde_json::from_slice(&content)
+        .with_context(|| format!("Failed to parse the API keys file {path:?}"))?;
+    let keys = keys
+        .into_iter()
+        .map(|(key, roles)| (key, Roles(roles)))
+        .collect();
+
+    Ok(Snapshot { modified, keys })
+}
+
+/// Returns `true` if the API key of the request has the `role`.
+pub fn has_role(ctx: &Context<'_>, role: Role) -> bool {
+    ctx.data_opt::<Roles>()
+        .is_some_and(|roles| roles.contains(role))
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_keys(file: &tempfile::NamedTempFile, content: &str) {
+        std::fs::write(file.path(), content).unwrap();
+    }
+
+    #[test]
+    fn roles__returns_roles_of_the_key() {
+        // Given
+        let file = tempfile::NamedTempFile::new().unwrap();
+        write_keys(&file, r#"{ "internal": ["debugger", "block_production"] }"#);
+        let api_keys = ApiKeys::load(file.path()).unwrap();
+
+        // When
+        let internal = api_keys.roles("internal");
+        let unknown = api_keys.roles("unknown");
+
+        // Then
+        assert!(internal.contains(Role::Debugger));
+        assert!(internal.contains(Role::BlockProduction));
+        assert!(!internal.contains(Role::UncheckedDryRun));
+        assert_eq!(unknown, Roles::default());
+    }
+
+    #[test]
+    fn reload_if_modified__reloads_modified_file() {
+        // Given
+        let file = tempfile::NamedTempFile::new().unwrap();
+        write_keys(&file, r#"{ "internal": ["debugger"] }"#);
+        let api_keys = ApiKeys::load(file.path()).unwrap();
+        api_keys.snapshot.write().modified = None;
+
+        // When
+        write_keys(&file, r#"{ "internal": ["unchecked_dry_run"] }"#);
+        api_keys.reload_if_modified();
+        let roles = api_keys.roles("internal");
+
+        // Then
+        assert!(!roles.contains(Role::Debugger));
+        assert!(roles.contains(Role::UncheckedDryRun));
+    }
+
+    #[test]
+    fn reload_if_modified__keeps_previous_keys_if_file_is_invalid() {
+        // Given
+        let file = tempfile::NamedTempFile::new().unwrap();
+        write_keys(&file, r#"{ "internal": ["debugger"] }"#);
+        let api_keys = ApiKeys::load(file.path()).unwrap();
+        api_keys.snapshot.write().modified = None;
+
+        // When
+        write_keys(&file, r#"{ "internal": ["unknown_role"] }"#);
+        api_keys.reload_if_modified();
+        let roles = api_keys.roles("internal");
+
+        // Then
+        assert!(roles.contains(Role::Debugger));
+    }
+
+    #[test]
+    fn load__fails_for_invalid_file() {
+        // Given
+        let file = tempfile::NamedTempFile::new().unwrap();
+        write_keys(&file, "not a json");
+
+        // When
+        let result = ApiKeys::load(file.path());
+
+        // Then
+        assert!(result.is_err());
+    }
+}
@@ -8,6 +8,13 @@ use crate::{
             OnChainDatabase,
             OnChainDatabaseAt,
         },
+        storage::{
+            fee_statistics::BlockFeeStatistics,
+            logs::{
+                LogDetails,
+                LogKey,
+            },
+        },
     },
 };
 use fuel_core_services::yield_stream::StreamYieldExt;
@@ -61,6 +68,7 @@ use fuel_core_types::{
         BlobId,
         BlockHeight,
         Nonce,
+        Word,
     },
     fuel_vm::BlobData,
     services::{
@@ -141,11 +149,14 @@ impl Default for IndexationFlags {
 
 impl ReadDatabase {
     /// Creates a new [`ReadDatabase`] with the given on-chain and off-chain database view providers.
+    /// The optional logs and fee history indexation are available only if enabled.
     pub fn new<OnChain, OffChain>(
         batch_size: usize,
         genesis_height: BlockHeight,
         on_chain: OnChain,
         off_chain: OffChain,
+        logs_indexation: bool,
+        fee_history_indexation: bool,
     ) -> Result<Self, StorageError>
     where
         OnChain: HistoricalView<Height = BlockHeight> + 'static,
@@ -174,6 +185,23 @@ impl ReadDatabase {
                         indexation_flags.insert(kind);
                     }
                 }
+                IndexationKind::ContractTransactions => {
+                    if off_chain.contract_transactions_indexation_enabled()? {
+                        indexation_flags.insert(kind);
+                    }
+                }
+                IndexationKind::Logs => {
+                    if logs_indexation && off_chain.logs_indexation_enabled()? {
+                        indexation_flags.insert(kind);
+                    }
+                }
+                IndexationKind::FeeHistory => {
+                    if fee_history_indexation
+                        && off_chain.fee_history_indexation_enabled()?
+                    {
+                        indexation_flags.insert(kind);
+                    }
+                }
             }
         }
         Ok(Self {
@@ -185,6 +213,11 @@ impl ReadDatabase {
         })
     }
 
+    /// Returns the indexation available for the queries.
+    pub fn indexation_flags(&self) -> &IndexationFlags {
+        &self.indexation_flags
+    }
+
     /// Creates a consistent view of the database.
     pub fn view(&self) -> StorageResult<ReadView> {
         // TODO: Use the same height for both views to guarantee consistency.
@@ -479,6 +512,36 @@ impl ReadView {
         )
     }
 
+    pub fn contract_transactions_ids(
+        &self,
+        contract_id: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> impl Stream<Item = StorageResult<(TxPointer, TxId)>> + '_ {
+        futures::stream::iter(self.off_chain.contract_transactions_ids(
+            contract_id,
+            start,
+            direction,
+        ))
+    }
+
+    pub fn logs(
+        &self,
+        contract_id: ContractId,
+        rb: Word,
+        start: Option<LogKey>,
+        direction: IterDirection,
+    ) -> impl Stream<Item = StorageResult<(LogKey, LogDetails)>> + '_ {
+        futures::stream::iter(self.off_chain.logs(contract_id, rb, start, direction))
+    }
+
+    pub fn fee_statistics(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<BlockFeeStatistics>> {
+        self.off_chain.fee_statistics(height)
+    }
+
     pub fn contract_salt(&self, contract_id: &ContractId) -> StorageResult<Salt> {
         self.off_chain.contract_salt(contract_id)
     }
@@ -535,19 +598,39 @@ fn test_indexation_flags() {
     assert!(!indexation.contains(&IndexationKind::Balances));
     assert!(!indexation.contains(&IndexationKind::CoinsToSpend));
     assert!(!indexation.contains(&IndexationKind::AssetMetadata));
+    assert!(!indexation.contains(&IndexationKind::ContractTransactions));
 
     indexation.insert(IndexationKind::Balances);
     assert!(indexation.contains(&IndexationKind::Balances));
     assert!(!indexation.contains(&IndexationKind::CoinsToSpend));
     assert!(!indexation.contains(&IndexationKind::AssetMetadata));
+    assert!(!indexation.contains(&IndexationKind::ContractTransactions));
 
     indexation.insert(IndexationKind::CoinsToSpend);
     assert!(indexation.contains(&IndexationKind::Balances));
     assert!(indexation.contains(&IndexationKind::CoinsToSpend));
     assert!(!indexation.contains(&IndexationKind::AssetMetadata));
+    assert!(!indexation.contains(&IndexationKind::ContractTransactions));
 
     indexation.insert(IndexationKind::AssetMetadata);
     assert!(indexation.contains(&IndexationKind::Balances));
     assert!(indexation.contains(&IndexationKind::CoinsToSpend));
     assert!(indexation.contains(&IndexationKind::AssetMetadata));
+    assert!(!indexation.contains(&IndexationKind::ContractTransactions));
+
+    indexation.insert(IndexationKind::ContractTransactions);
+    assert!(indexation.contains(&IndexationKind::Balances));
+    assert!(indexation.contains(&IndexationKind::CoinsToSpend));
+    assert!(indexation.contains(&IndexationKind::AssetMetadata));
+    assert!(indexation.contains(&IndexationKind::ContractTransactions));
+    assert!(!indexation.contains(&IndexationKind::Logs));
+    assert!(!indexation.contains(&IndexationKind::FeeHistory));
+
+    indexation.insert(IndexationKind::Logs);
+    assert!(indexation.contains(&IndexationKind::Logs));
+    assert!(!indexation.contains(&IndexationKind::FeeHistory));
+
+    indexation.insert(IndexationKind::FeeHistory);
+    assert!(indexation.contains(&IndexationKind::Logs));
+    assert!(indexation.contains(&IndexationKind::FeeHistory));
 }
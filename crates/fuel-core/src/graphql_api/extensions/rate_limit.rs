@@ -0,0 +1,347 @@
+use crate::fuel_core_graphql_api::{
+    RateLimitConfig,
+    authorization::ApiKeys,
+};
+use async_graphql::{
+    ServerError,
+    ValidationResult,
+    Value,
+    extensions::{
+        Extension,
+        ExtensionContext,
+        ExtensionFactory,
+        NextValidation,
+    },
+};
+use axum::http::HeaderMap;
+use fuel_core_metrics::graphql_metrics::graphql_metrics;
+use std::{
+    collections::HashMap,
+    net::IpAddr,
+    sync::Arc,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The header used by clients to identify themselves with the API key.
+pub(crate) const API_KEY_HEADER: &str = "x-api-key";
+/// The error extension with the number of seconds left until the end of the window.
+pub(crate) const RATE_LIMIT_EXCEEDED: &str = "rate_limit_exceeded";
+/// The error code returned when the client has exhausted its budget.
+const RATE_LIMITED_CODE: &str = "RATE_LIMITED";
+
+/// The identity of the client used to track its budget.
+/// The value is set in the request data by the graphql handler.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum ClientId {
+    ApiKey(String),
+    Ip(IpAddr),
+}
+
+impl ClientId {
+    /// Identifies the client by the API key if the headers contain one of the
+    /// configured `api_keys`, otherwise by the IP address. Unknown keys share
+    /// the budget of their IP, so they can't be used to bypass the IP limit.
+    pub(crate) fn new(
+        ip: IpAddr,
+        headers: &HeaderMap,
+        api_keys: Option<&ApiKeys>,
+    ) -> Self {
+        headers
+            .get(API_KEY_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .filter(|value| api_keys.is_some_and(|api_keys| api_keys.contains(value)))
+            .map(|value| ClientId::ApiKey(value.to_string()))
+            .unwrap_or(ClientId::Ip(ip))
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            ClientId::ApiKey(_) => "api_key",
+            ClientId::Ip(_) => "ip",
+        }
+    }
+}
+
+struct Window {
+    started_at: Instant,
+    consumed: HashMap<ClientId, usize>,
+}
+
+/// Tracks the complexity consumed by each client during the fixed time window.
+/// Budgets of all clients are reset at the end of the window.
+pub(crate) struct RateLimiter {
+    config: RateLimitConfig,
+    window: parking_lot::Mutex<Window>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(config: RateLimitConfig) -> Self {
+        Self {
+            config,
+            window: parking_lot::Mutex::new(Window {
+                started_at: Instant::now(),
+                consumed: HashMap::new(),
+            }),
+        }
+    }
+
+    fn budget(&self, client: &ClientId) -> Option<usize> {
+        match client {
+            ClientId::ApiKey(_) => self.config.api_key_complexity_per_window,
+            ClientId::Ip(_) => self.config.ip_complexity_per_window,
+        }
+    }
+
+    /// Charges the `complexity` to the `client` budget.
+    /// Returns the time left until the end of the window if the client doesn't
+    /// have enough budget left or if it is a new client and the maximum number
+    /// of tracked clients is reached. In this case, the budget is not charged.
+    pub(crate) fn try_consume(
+        &self,
+        client: &ClientId,
+        complexity: usize,
+        now: Instant,
+    ) -> Result<(), Duration> {
+        let Some(budget) = self.budget(client) else {
+            return Ok(())
+        };
+
+        let mut window = self.window.lock();
+        let elapsed = now.saturating_duration_since(window.started_at);
+        if elapsed >= self.config.window {
+            window.started_at = now;
+            window.consumed.clear();
+        }
+
+        let retry_after = self
+            .config
+            .window
+            .saturating_sub(now.saturating_duration_since(window.started_at));
+        let consumed = match window.consumed.get(client) {
+            Some(consumed) => *consumed,
+            None if window.consumed.len() >= self.config.max_tracked_clients => {
+                return Err(retry_after)
+            }
+            None => 0,
+        };
+        let total = consumed.saturating_add(complexity);
+        if total > budget {
+            return Err(retry_after)
+        }
+
+        window.consumed.insert(client.clone(), total);
+        graphql_metrics().rate_limit_tracked_clients(window.consumed.len());
+        Ok(())
+    }
+}
+
+/// The extension that rejects queries of clients that exceeded their
+/// complexity budget for the current time window. The client is identified
+/// by the `ClientId` set in the request data by the graphql handler.
+pub(crate) struct RateLimitExtension {
+    limiter: Arc<RateLimiter>,
+}
+
+impl RateLimitExtension {
+    pub fn new(config: RateLimitConfig) -> Self {
+        Self {
+            limiter: Arc::new(RateLimiter::new(config)),
+        }
+    }
+}
+
+impl ExtensionFactory for RateLimitExtension {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(RateLimitInner {
+            limiter: self.limiter.clone(),
+        })
+    }
+}
+
+struct RateLimitInner {
+    limiter: Arc<RateLimiter>,
+}
+
+#[async_trait::async_trait]
+impl Extension for RateLimitInner {
+    async fn validation(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        next: NextValidation<'_>,
+    ) -> Result<ValidationResult, Vec<ServerError>> {
+        let result = next.run(ctx).await?;
+
+        let Some(client) = ctx.data_opt::<ClientId>() else {
+            return Ok(result)
+        };
+
+        match self
+            .limiter
+            .try_consume(client, result.complexity, Instant::now())
+        {
+            Ok(()) => {
+                graphql_metrics().rate_limit_consumed(
+                    client.kind(),
+                    u64::try_from(result.complexity).unwrap_or(u64::MAX),
+                );
+                Ok(result)
+            }
+            Err(retry_after) => {
+                graphql_metrics().rate_limit_rejected(client.kind());
+                Err(vec![error_response(retry_after)])
+            }
+        }
+    }
+}
+
+fn error_response(retry_after: Duration) -> ServerError {
+    let retry_after_secs = retry_after.as_secs().saturating_add(1);
+    let mut error = ServerError::new(
+        format!(
+            "The query complexity budget is exhausted, retry after {retry_after_secs} seconds"
+        ),
+        None,
+    );
+
+    let extensions = error.extensions.get_or_insert_with(Default::default);
+    extensions.set("code", Value::String(RATE_LIMITED_CODE.to_string()));
+    extensions.set(RATE_LIMIT_EXCEEDED, Value::Number(retry_after_secs.into()));
+
+    error
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    const WINDOW: Duration = Duration::from_secs(10);
+
+    fn limiter(ip: Option<usize>, api_key: Option<usize>) -> RateLimiter {
+        RateLimiter::new(RateLimitConfig {
+            window: WINDOW,
+            ip_complexity_per_window: ip,
+            api_key_complexity_per_window: api_key,
+            max_tracked_clients: 1000,
+        })
+    }
+
+    fn ip(byte: u8) -> ClientId {
+        ClientId::Ip(IpAddr::V4(Ipv4Addr::new(127, 0, 0, byte)))
+    }
+
+    #[test]
+    fn try_consume__rejects_query_exceeding_the_budget_without_charging_it() {
+        // Given
+        let limiter = limiter(Some(100), None);
+        let now = Instant::now();
+        limiter.try_consume(&ip(1), 60, now).unwrap();
+
+        // When
+        let result = limiter.try_consume(&ip(1), 50, now + Duration::from_secs(4));
+
+        // Then
+        let retry_after = result.expect_err("The query should exceed the budget");
+        assert!(retry_after <= Duration::from_secs(6));
+        assert!(retry_after > Duration::from_secs(5));
+        limiter
+            .try_consume(&ip(1), 40, now)
+            .expect("The rejected query should not be charged");
+    }
+
+    #[test]
+    fn try_consume__tracks_budgets_per_client() {
+        // Given
+        let limiter = limiter(Some(100), Some(1000));
+        let now = Instant::now();
+        limiter.try_consume(&ip(1), 100, now).unwrap();
+
+        // When
+        let other_ip = limiter.try_consume(&ip(2), 100, now);
+        let api_key = limiter.try_consume(&ClientId::ApiKey("key".into()), 1000, now);
+
+        // Then
+        assert_eq!(other_ip, Ok(()));
+        assert_eq!(api_key, Ok(()));
+        assert!(limiter.try_consume(&ip(1), 1, now).is_err());
+    }
+
+    #[test]
+    fn try_consume__resets_budgets_after_the_window() {
+        // Given
+        let limiter = limiter(Some(100), None);
+        let now = Instant::now();
+        limiter.try_consume(&ip(1), 100, now).unwrap();
+
+        // When
+        let result = limiter.try_consume(&ip(1), 100, now + WINDOW);
+
+        // Then
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn try_consume__rejects_new_clients_when_max_tracked_clients_is_reached() {
+        // Given
+        let limiter = RateLimiter::new(RateLimitConfig {
+            max_tracked_clients: 2,
+            ..limiter(Some(100), None).config
+        });
+        let now = Instant::now();
+        limiter.try_consume(&ip(1), 10, now).unwrap();
+        limiter.try_consume(&ip(2), 10, now).unwrap();
+
+        // When
+        let new_client = limiter.try_consume(&ip(3), 10, now);
+        let tracked_client = limiter.try_consume(&ip(1), 10, now);
+        let after_window = limiter.try_consume(&ip(3), 10, now + WINDOW);
+
+        // Then
+        assert!(new_client.is_err());
+        assert_eq!(tracked_client, Ok(()));
+        assert_eq!(after_window, Ok(()));
+    }
+
+    #[test]
+    fn try_consume__does_not_limit_clients_without_budget() {
+        // Given
+        let limiter = limiter(Some(100), None);
+        let client = ClientId::ApiKey("key".into());
+
+        // When
+        let result = limiter.try_consume(&client, usize::MAX, Instant::now());
+
+        // Then
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn client_id__prefers_known_api_key_over_ip() {
+        // Given
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), r#"{ "key": [] }"#).unwrap();
+        let api_keys = ApiKeys::load(file.path()).unwrap();
+        let header = |key: &str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(API_KEY_HEADER, key.parse().unwrap());
+            headers
+        };
+        let address = IpAddr::V4(Ipv4Addr::LOCALHOST);
+
+        // When
+        let known_key = ClientId::new(address, &header("key"), Some(&api_keys));
+        let unknown_key = ClientId::new(address, &header("unknown"), Some(&api_keys));
+        let without_api_keys = ClientId::new(address, &header("key"), None);
+        let without_key = ClientId::new(address, &HeaderMap::new(), Some(&api_keys));
+
+        // Then
+        assert_eq!(known_key, ClientId::ApiKey("key".into()));
+        assert_eq!(unknown_key, ClientId::Ip(address));
+        assert_eq!(without_api_keys, ClientId::Ip(address));
+        assert_eq!(without_key, ClientId::Ip(address));
+    }
+}
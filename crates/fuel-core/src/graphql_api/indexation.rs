@@ -1,6 +1,9 @@
 pub(crate) mod asset_metadata;
 pub(crate) mod balances;
 pub(crate) mod coins_to_spend;
+pub(crate) mod contract_transactions;
 pub(crate) mod error;
+pub(crate) mod fee_statistics;
+pub(crate) mod logs;
 #[cfg(test)]
 pub(crate) mod test_utils;
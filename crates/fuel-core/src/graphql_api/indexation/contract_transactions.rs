@@ -0,0 +1,202 @@
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        transaction::TransactionExt,
+    },
+    fuel_tx::{
+        Input,
+        Output,
+        UniqueIdentifier,
+    },
+    fuel_types::ChainId,
+};
+
+use fuel_core_storage::StorageAsMut;
+
+use crate::graphql_api::{
+    ports::worker::OffChainDatabaseTransaction,
+    storage::transactions::{
+        ContractTransactionIndexKey,
+        ContractTransactions,
+    },
+};
+
+use super::error::IndexationError;
+
+/// Indexes every transaction of the `block` by the contracts it called or created.
+pub(crate) fn update<T>(
+    block: &Block,
+    block_st_transaction: &mut T,
+    enabled: bool,
+    chain_id: &ChainId,
+) -> Result<(), IndexationError>
+where
+    T: OffChainDatabaseTransaction,
+{
+    if !enabled {
+        return Ok(());
+    }
+
+    let block_height = *block.header().height();
+    for (tx_idx, tx) in block.transactions().iter().enumerate() {
+        let tx_idx = u16::try_from(tx_idx)
+            .map_err(|_| IndexationError::TooManyTransactions { block_height })?;
+
+        let inputs = tx.inputs();
+        let outputs = tx.outputs();
+        let called = inputs.iter().filter_map(|input| match input {
+            Input::Contract(contract) => Some(contract.contract_id),
+            _ => None,
+        });
+        let created = outputs.iter().filter_map(|output| match output {
+            Output::ContractCreated { contract_id, .. } => Some(*contract_id),
+            _ => None,
+        });
+        let mut contracts = called.chain(created).collect::<Vec<_>>();
+
+        if contracts.is_empty() {
+            continue;
+        }
+
+        contracts.sort();
+        contracts.dedup();
+
+        let tx_id = tx.id(chain_id);
+        for contract_id in contracts {
+            block_st_transaction
+                .storage::<ContractTransactions>()
+                .insert(
+                    &ContractTransactionIndexKey::new(&contract_id, block_height, tx_idx),
+                    &tx_id,
+                )?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_core_storage::{
+        StorageAsRef,
+        transactional::WriteTransaction,
+    };
+    use fuel_core_types::{
+        blockchain::block::Block,
+        fuel_tx::{
+            ContractId,
+            Output,
+            Transaction,
+            TransactionBuilder,
+            UniqueIdentifier,
+        },
+        fuel_types::ChainId,
+    };
+
+    use crate::{
+        database::{
+            Database,
+            database_description::off_chain::OffChain,
+        },
+        graphql_api::{
+            indexation::contract_transactions::update,
+            storage::transactions::{
+                ContractTransactionIndexKey,
+                ContractTransactions,
+            },
+        },
+    };
+
+    fn call(contract_id: ContractId) -> Transaction {
+        TransactionBuilder::script(vec![], vec![])
+            .add_input(fuel_core_types::fuel_tx::Input::contract(
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                contract_id,
+            ))
+            .add_output(Output::contract(0, Default::default(), Default::default()))
+            .finalize_as_transaction()
+    }
+
+    fn create(contract_id: ContractId) -> Transaction {
+        TransactionBuilder::create(Default::default(), Default::default(), vec![])
+            .add_output(Output::contract_created(contract_id, Default::default()))
+            .finalize_as_transaction()
+    }
+
+    fn block(transactions: Vec<Transaction>) -> Block {
+        let mut block = Block::default();
+        *block.transactions_mut() = transactions;
+        block.header_mut().set_block_height(1.into());
+        block
+    }
+
+    #[test]
+    fn contract_transactions_index_is_correctly_updated() {
+        let mut db = Database::<OffChain>::default();
+        let mut tx = db.write_transaction();
+        let chain_id = ChainId::default();
+
+        const CONTRACT_TRANSACTIONS_IS_ENABLED: bool = true;
+
+        let called: ContractId = ContractId::from([1u8; 32]);
+        let created: ContractId = ContractId::from([2u8; 32]);
+        let transactions = vec![
+            call(called),
+            TransactionBuilder::script(vec![], vec![]).finalize_as_transaction(),
+            create(created),
+        ];
+        let block = block(transactions.clone());
+
+        update(&block, &mut tx, CONTRACT_TRANSACTIONS_IS_ENABLED, &chain_id)
+            .expect("should process block");
+
+        let called_tx = tx
+            .storage::<ContractTransactions>()
+            .get(&ContractTransactionIndexKey::new(&called, 1.into(), 0))
+            .expect("should correctly query db")
+            .expect("should have the call indexed");
+        assert_eq!(*called_tx, transactions[0].id(&chain_id));
+
+        let created_tx = tx
+            .storage::<ContractTransactions>()
+            .get(&ContractTransactionIndexKey::new(&created, 1.into(), 2))
+            .expect("should correctly query db")
+            .expect("should have the creation indexed");
+        assert_eq!(*created_tx, transactions[2].id(&chain_id));
+
+        let not_related = tx
+            .storage::<ContractTransactions>()
+            .get(&ContractTransactionIndexKey::new(&called, 1.into(), 1))
+            .expect("should correctly query db");
+        assert!(not_related.is_none());
+    }
+
+    #[test]
+    fn contract_transactions_indexation_enabled_flag_is_respected() {
+        let mut db = Database::<OffChain>::default();
+        let mut tx = db.write_transaction();
+        let chain_id = ChainId::default();
+
+        const CONTRACT_TRANSACTIONS_IS_DISABLED: bool = false;
+
+        let called: ContractId = ContractId::from([1u8; 32]);
+        let block = block(vec![call(called)]);
+
+        update(
+            &block,
+            &mut tx,
+            CONTRACT_TRANSACTIONS_IS_DISABLED,
+            &chain_id,
+        )
+        .expect("should process block");
+
+        let indexed = tx
+            .storage::<ContractTransactions>()
+            .get(&ContractTransactionIndexKey::new(&called, 1.into(), 0))
+            .expect("should correctly query db");
+        assert!(indexed.is_none());
+    }
+}
@@ -4,9 +4,13 @@ use fuel_core_types::{
     fuel_tx::{
         Address,
         AssetId,
+        TxId,
         UtxoId,
     },
-    fuel_types::Nonce,
+    fuel_types::{
+        BlockHeight,
+        Nonce,
+    },
 };
 
 #[derive(derive_more::From, derive_more::Display, Debug)]
@@ -112,6 +116,13 @@ pub enum IndexationError {
         receipt
     )]
     UnexpectedReceipt { receipt: String },
+    #[display(
+        "The block at height {} has more than `u16::MAX` transactions",
+        block_height
+    )]
+    TooManyTransactions { block_height: BlockHeight },
+    #[display("The transaction {} has more than `u32::MAX` receipts", tx_id)]
+    TooManyReceipts { tx_id: TxId },
     #[from]
     StorageError(StorageError),
 }
@@ -0,0 +1,213 @@
+use fuel_core_types::{
+    fuel_tx::{
+        Transaction,
+        field::{
+            MintGasPrice,
+            Tip,
+        },
+    },
+    services::block_importer::ImportResult,
+};
+
+use fuel_core_storage::StorageAsMut;
+
+use crate::graphql_api::{
+    ports::worker::OffChainDatabaseTransaction,
+    storage::fee_statistics::{
+        BlockFeeStatistics,
+        FeeStatistics,
+        TipAndGas,
+    },
+};
+
+use super::error::IndexationError;
+
+/// Stores the gas price, the gas used and the tips paid by the transactions
+/// of the block from the `import_result`.
+pub(crate) fn update<T>(
+    import_result: &ImportResult,
+    block_st_transaction: &mut T,
+    enabled: bool,
+) -> Result<(), IndexationError>
+where
+    T: OffChainDatabaseTransaction,
+{
+    if !enabled {
+        return Ok(());
+    }
+
+    let block = &import_result.sealed_block.entity;
+    let mut statistics = BlockFeeStatistics::default();
+    for (tx, status) in block.transactions().iter().zip(&import_result.tx_status) {
+        let tip = match tx {
+            Transaction::Script(tx) => tx.tip(),
+            Transaction::Create(tx) => tx.tip(),
+            Transaction::Upgrade(tx) => tx.tip(),
+            Transaction::Upload(tx) => tx.tip(),
+            Transaction::Blob(tx) => tx.tip(),
+            Transaction::Mint(mint) => {
+                statistics.gas_price = *mint.gas_price();
+                continue;
+            }
+        };
+        let gas = *status.result.total_gas();
+        statistics.gas_used = statistics.gas_used.saturating_add(gas);
+        statistics.tips.push(TipAndGas { tip, gas });
+    }
+    statistics.tips.sort_by_key(|tip| tip.tip);
+
+    block_st_transaction
+        .storage::<FeeStatistics>()
+        .insert(block.header().height(), &statistics)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use fuel_core_storage::{
+        StorageAsRef,
+        transactional::WriteTransaction,
+    };
+    use fuel_core_types::{
+        fuel_tx::{
+            Finalizable,
+            Transaction,
+            TransactionBuilder,
+        },
+        services::{
+            block_importer::ImportResult,
+            executor::{
+                TransactionExecutionResult,
+                TransactionExecutionStatus,
+            },
+        },
+    };
+
+    use crate::{
+        database::{
+            Database,
+            database_description::off_chain::OffChain,
+        },
+        graphql_api::{
+            indexation::fee_statistics::update,
+            storage::fee_statistics::{
+                BlockFeeStatistics,
+                FeeStatistics,
+                TipAndGas,
+            },
+        },
+    };
+
+    fn script(tip: u64) -> Transaction {
+        TransactionBuilder::script(vec![], vec![])
+            .tip(tip)
+            .finalize()
+            .into()
+    }
+
+    fn mint(gas_price: u64) -> Transaction {
+        Transaction::mint(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            gas_price,
+        )
+        .into()
+    }
+
+    fn status(total_gas: u64, success: bool) -> TransactionExecutionStatus {
+        let receipts = Arc::new(vec![]);
+        let result = if success {
+            TransactionExecutionResult::Success {
+                result: None,
+                receipts,
+                total_gas,
+                total_fee: 0,
+            }
+        } else {
+            TransactionExecutionResult::Failed {
+                result: None,
+                receipts,
+                total_gas,
+                total_fee: 0,
+            }
+        };
+        TransactionExecutionStatus {
+            id: Default::default(),
+            result,
+        }
+    }
+
+    fn import_result(
+        transactions: Vec<Transaction>,
+        tx_status: Vec<TransactionExecutionStatus>,
+    ) -> ImportResult {
+        let mut result = ImportResult {
+            sealed_block: Default::default(),
+            tx_status,
+            events: vec![],
+            source: Default::default(),
+        };
+        let block = &mut result.sealed_block.entity;
+        *block.transactions_mut() = transactions;
+        block.header_mut().set_block_height(1.into());
+        result
+    }
+
+    #[test]
+    fn fee_statistics_of_all_executed_transactions_are_indexed() {
+        let mut db = Database::<OffChain>::default();
+        let mut tx = db.write_transaction();
+
+        const FEE_HISTORY_INDEXATION_IS_ENABLED: bool = true;
+
+        let result = import_result(
+            vec![script(10), script(1), mint(7)],
+            vec![status(100, true), status(50, false), status(0, true)],
+        );
+
+        update(&result, &mut tx, FEE_HISTORY_INDEXATION_IS_ENABLED)
+            .expect("should process block");
+
+        let statistics = tx
+            .storage::<FeeStatistics>()
+            .get(&1.into())
+            .expect("should correctly query db")
+            .expect("should index the block");
+        assert_eq!(
+            statistics.into_owned(),
+            BlockFeeStatistics {
+                gas_price: 7,
+                gas_used: 150,
+                tips: vec![
+                    TipAndGas { tip: 1, gas: 50 },
+                    TipAndGas { tip: 10, gas: 100 },
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn fee_history_indexation_enabled_flag_is_respected() {
+        let mut db = Database::<OffChain>::default();
+        let mut tx = db.write_transaction();
+
+        const FEE_HISTORY_INDEXATION_IS_DISABLED: bool = false;
+
+        let result = import_result(vec![script(10)], vec![status(100, true)]);
+
+        update(&result, &mut tx, FEE_HISTORY_INDEXATION_IS_DISABLED)
+            .expect("should process block");
+
+        let statistics = tx
+            .storage::<FeeStatistics>()
+            .get(&1.into())
+            .expect("should correctly query db");
+        assert!(statistics.is_none());
+    }
+}
@@ -0,0 +1,214 @@
+use fuel_core_types::{
+    fuel_tx::Receipt,
+    services::{
+        block_importer::ImportResult,
+        executor::TransactionExecutionResult,
+    },
+};
+
+use fuel_core_storage::StorageAsMut;
+
+use crate::graphql_api::{
+    ports::worker::OffChainDatabaseTransaction,
+    storage::logs::{
+        LogDetails,
+        LogKey,
+        Logs,
+    },
+};
+
+use super::error::IndexationError;
+
+/// Indexes `Log` and `LogData` receipts of the successful transactions from the
+/// `import_result` and returns the indexed logs in the order they were emitted.
+pub(crate) fn update<T>(
+    import_result: &ImportResult,
+    block_st_transaction: &mut T,
+    enabled: bool,
+) -> Result<Vec<(LogKey, LogDetails)>, IndexationError>
+where
+    T: OffChainDatabaseTransaction,
+{
+    if !enabled {
+        return Ok(Vec::new());
+    }
+
+    let block_height = *import_result.sealed_block.entity.header().height();
+    let mut logs = Vec::new();
+    for (tx_idx, status) in import_result.tx_status.iter().enumerate() {
+        let TransactionExecutionResult::Success { receipts, .. } = &status.result else {
+            continue;
+        };
+        let tx_idx = u16::try_from(tx_idx)
+            .map_err(|_| IndexationError::TooManyTransactions { block_height })?;
+
+        for (receipt_idx, receipt) in receipts.iter().enumerate() {
+            let (contract_id, rb) = match receipt {
+                Receipt::Log { id, rb, .. } | Receipt::LogData { id, rb, .. } => {
+                    (*id, *rb)
+                }
+                _ => continue,
+            };
+            let receipt_idx = u32::try_from(receipt_idx)
+                .map_err(|_| IndexationError::TooManyReceipts { tx_id: status.id })?;
+
+            let key = LogKey::new(contract_id, rb, block_height, tx_idx, receipt_idx);
+            let details = LogDetails {
+                tx_id: status.id,
+                receipt: receipt.clone(),
+            };
+            block_st_transaction
+                .storage::<Logs>()
+                .insert(&key, &details)?;
+            logs.push((key, details));
+        }
+    }
+
+    Ok(logs)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use fuel_core_storage::{
+        StorageAsRef,
+        transactional::WriteTransaction,
+    };
+    use fuel_core_types::{
+        fuel_tx::{
+            ContractId,
+            Receipt,
+        },
+        services::{
+            block_importer::ImportResult,
+            executor::{
+                TransactionExecutionResult,
+                TransactionExecutionStatus,
+            },
+        },
+    };
+
+    use crate::{
+        database::{
+            Database,
+            database_description::off_chain::OffChain,
+        },
+        graphql_api::{
+            indexation::logs::update,
+            storage::logs::{
+                LogKey,
+                Logs,
+            },
+        },
+    };
+
+    fn log(contract_id: ContractId, rb: u64) -> Receipt {
+        Receipt::log(contract_id, 0, rb, 0, 0, 0, 0)
+    }
+
+    fn status(receipts: Vec<Receipt>, success: bool) -> TransactionExecutionStatus {
+        let receipts = Arc::new(receipts);
+        let result = if success {
+            TransactionExecutionResult::Success {
+                result: None,
+                receipts,
+                total_gas: 0,
+                total_fee: 0,
+            }
+        } else {
+            TransactionExecutionResult::Failed {
+                result: None,
+                receipts,
+                total_gas: 0,
+                total_fee: 0,
+            }
+        };
+        TransactionExecutionStatus {
+            id: Default::default(),
+            result,
+        }
+    }
+
+    fn import_result(tx_status: Vec<TransactionExecutionStatus>) -> ImportResult {
+        let mut result = ImportResult {
+            sealed_block: Default::default(),
+            tx_status,
+            events: vec![],
+            source: Default::default(),
+        };
+        result
+            .sealed_block
+            .entity
+            .header_mut()
+            .set_block_height(1.into());
+        result
+    }
+
+    #[test]
+    fn logs_of_successful_transactions_are_indexed() {
+        let mut db = Database::<OffChain>::default();
+        let mut tx = db.write_transaction();
+
+        const LOGS_INDEXATION_IS_ENABLED: bool = true;
+
+        let contract_id = ContractId::from([1u8; 32]);
+        let result = import_result(vec![
+            status(
+                vec![log(contract_id, 7), Receipt::ret(contract_id, 0, 0, 0)],
+                true,
+            ),
+            status(vec![log(contract_id, 7)], false),
+            status(
+                vec![Receipt::ret(contract_id, 0, 0, 0), log(contract_id, 8)],
+                true,
+            ),
+        ]);
+
+        let logs = update(&result, &mut tx, LOGS_INDEXATION_IS_ENABLED)
+            .expect("should process block");
+
+        let keys = logs.into_iter().map(|(key, _)| key).collect::<Vec<_>>();
+        assert_eq!(
+            keys,
+            vec![
+                LogKey::new(contract_id, 7, 1.into(), 0, 0),
+                LogKey::new(contract_id, 8, 1.into(), 2, 1),
+            ]
+        );
+        for key in keys {
+            let indexed = tx
+                .storage::<Logs>()
+                .get(&key)
+                .expect("should correctly query db");
+            assert!(indexed.is_some());
+        }
+
+        let reverted = tx
+            .storage::<Logs>()
+            .get(&LogKey::new(contract_id, 7, 1.into(), 1, 0))
+            .expect("should correctly query db");
+        assert!(reverted.is_none());
+    }
+
+    #[test]
+    fn logs_indexation_enabled_flag_is_respected() {
+        let mut db = Database::<OffChain>::default();
+        let mut tx = db.write_transaction();
+
+        const LOGS_INDEXATION_IS_DISABLED: bool = false;
+
+        let contract_id = ContractId::from([1u8; 32]);
+        let result = import_result(vec![status(vec![log(contract_id, 7)], true)]);
+
+        let logs = update(&result, &mut tx, LOGS_INDEXATION_IS_DISABLED)
+            .expect("should process block");
+        assert!(logs.is_empty());
+
+        let indexed = tx
+            .storage::<Logs>()
+            .get(&LogKey::new(contract_id, 7, 1.into(), 0, 0))
+            .expect("should correctly query db");
+        assert!(indexed.is_none());
+    }
+}
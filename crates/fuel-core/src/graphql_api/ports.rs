@@ -1,6 +1,11 @@
 use super::storage::{
     assets::AssetDetails,
     balances::TotalBalanceAmount,
+    fee_statistics::BlockFeeStatistics,
+    logs::{
+        LogDetails,
+        LogKey,
+    },
 };
 use crate::fuel_core_graphql_api::storage::coins::CoinsToSpendIndexKey;
 use async_trait::async_trait;
@@ -25,7 +30,13 @@ use fuel_core_storage::{
     },
 };
 use fuel_core_tx_status_manager::TxStatusMessage;
-use fuel_core_txpool::TxPoolStats;
+use fuel_core_txpool::{
+    TxPoolStats,
+    inspection::{
+        InspectedTransaction,
+        InspectionFilter,
+    },
+};
 use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
@@ -61,15 +72,24 @@ use fuel_core_types::{
         BlockHeight,
         ContractId,
         Nonce,
+        Word,
     },
     fuel_vm::interpreter::Memory,
     services::{
         executor::{
             DryRunResult,
+            StateOverrides,
             StorageReadReplayEvent,
+            TransactionTrace,
         },
         graphql_api::ContractBalance,
-        p2p::PeerInfo,
+        leader_lease::LeaderLeaseStatus,
+        p2p::{
+            BannedPeer,
+            PeerId,
+            PeerInfo,
+            PeerReputation,
+        },
         transaction_status::{
             self,
             TransactionStatus,
@@ -77,7 +97,10 @@ use fuel_core_types::{
     },
     tai64::Tai64,
 };
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::Duration,
+};
 
 pub struct CoinsToSpendIndexIter<'a> {
     pub big_coins_iter: BoxedIter<'a, Result<CoinsToSpendIndexKey, StorageError>>,
@@ -128,6 +151,30 @@ pub trait OffChainDatabase: Send + Sync {
         direction: IterDirection,
     ) -> BoxedIter<'_, StorageResult<(TxPointer, TxId)>>;
 
+    fn contract_transactions_ids(
+        &self,
+        contract_id: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(TxPointer, TxId)>>;
+
+    /// Iterates over the logs emitted by the `contract_id` with the log id `rb`
+    /// in the order of their emission.
+    fn logs(
+        &self,
+        contract_id: ContractId,
+        rb: Word,
+        start: Option<LogKey>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(LogKey, LogDetails)>>;
+
+    /// Returns the fee statistics of the block at the `height`,
+    /// if the block was indexed with the fee history indexation enabled.
+    fn fee_statistics(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<BlockFeeStatistics>>;
+
     fn coins_to_spend_index(
         &self,
         owner: &Address,
@@ -250,9 +297,20 @@ pub trait DatabaseChain {
 pub trait TxPoolPort: Send + Sync {
     async fn transaction(&self, id: TxId) -> anyhow::Result<Option<Transaction>>;
 
-    async fn insert(&self, txs: Transaction) -> anyhow::Result<()>;
+    /// Inserts the transaction into the `TxPool`. If `replaceable` is `false`,
+    /// the `TxPool` rejects transactions colliding with it regardless of their tip.
+    async fn insert(&self, tx: Transaction, replaceable: bool) -> anyhow::Result<()>;
+
+    async fn insert_bundle(&self, txs: Vec<Transaction>) -> anyhow::Result<()>;
 
     fn latest_pool_stats(&self) -> TxPoolStats;
+
+    /// Returns up to `filter.limit` transactions of the pool that match the `filter`,
+    /// in the inspection order starting from `filter.start`.
+    async fn inspect(
+        &self,
+        filter: InspectionFilter,
+    ) -> anyhow::Result<Vec<InspectedTransaction>>;
 }
 
 #[async_trait]
@@ -271,6 +329,7 @@ pub trait TxStatusManager: Send + Sync {
 
 #[async_trait]
 pub trait BlockProducerPort: Send + Sync {
+    #[allow(clippy::too_many_arguments)]
     async fn dry_run_txs(
         &self,
         transactions: Vec<Transaction>,
@@ -279,12 +338,19 @@ pub trait BlockProducerPort: Send + Sync {
         utxo_validation: Option<bool>,
         gas_price: Option<u64>,
         record_storage_reads: bool,
+        state_overrides: Option<StateOverrides>,
     ) -> anyhow::Result<DryRunResult>;
 
     async fn storage_read_replay(
         &self,
         height: BlockHeight,
     ) -> anyhow::Result<Vec<StorageReadReplayEvent>>;
+
+    async fn transaction_trace(
+        &self,
+        height: BlockHeight,
+        tx_id: TxId,
+    ) -> anyhow::Result<TransactionTrace>;
 }
 
 #[async_trait::async_trait]
@@ -294,6 +360,9 @@ pub trait ConsensusModulePort: Send + Sync {
         start_time: Option<Tai64>,
         number_of_blocks: u32,
     ) -> anyhow::Result<()>;
+
+    /// Returns `None` if the leader lease of the block producer is not enabled.
+    async fn leader_lease_status(&self) -> anyhow::Result<Option<LeaderLeaseStatus>>;
 }
 
 /// Trait that specifies queries supported by the database.
@@ -310,6 +379,23 @@ pub trait DatabaseMessageProof: Send + Sync {
 #[async_trait::async_trait]
 pub trait P2pPort: Send + Sync {
     async fn all_peer_info(&self) -> anyhow::Result<Vec<PeerInfo>>;
+
+    /// Adds the reserved peer by its multiaddress, that must contain the peer id.
+    async fn add_reserved_peer(&self, address: String) -> anyhow::Result<PeerId>;
+
+    /// Returns `false` if the peer was not reserved.
+    async fn remove_reserved_peer(&self, peer_id: PeerId) -> anyhow::Result<bool>;
+
+    async fn ban_peer(&self, peer_id: PeerId, duration: Duration) -> anyhow::Result<()>;
+
+    async fn unban_peer(&self, peer_id: PeerId) -> anyhow::Result<()>;
+
+    /// Returns `false` if the peer was not connected.
+    async fn disconnect_peer(&self, peer_id: PeerId) -> anyhow::Result<bool>;
+
+    async fn peer_reputations(&self) -> anyhow::Result<Vec<PeerReputation>>;
+
+    async fn banned_peers(&self) -> anyhow::Result<Vec<BannedPeer>>;
 }
 
 /// Trait for defining how to estimate gas price for future blocks
@@ -330,6 +416,7 @@ pub trait MemoryPool {
 pub mod worker {
     use super::super::storage::blocks::FuelBlockIdsToHeights;
     use crate::{
+        database::database_description::IndexationKind,
         fuel_core_graphql_api::storage::{
             coins::OwnedCoins,
             contracts::ContractsInfo,
@@ -345,12 +432,15 @@ pub mod worker {
                 MessageBalances,
             },
             coins::CoinsToSpendIndex,
+            fee_statistics::FeeStatistics,
+            logs::Logs,
             old::{
                 OldFuelBlockConsensus,
                 OldFuelBlocks,
                 OldTransactions,
             },
             relayed_transactions::RelayedTransactionStatuses,
+            transactions::ContractTransactions,
         },
     };
     use derive_more::Display;
@@ -399,6 +489,15 @@ pub mod worker {
 
         /// Checks if AssetMetadata indexation functionality is available.
         fn asset_metadata_indexation_enabled(&self) -> StorageResult<bool>;
+
+        /// Checks if ContractTransactions indexation functionality is available.
+        fn contract_transactions_indexation_enabled(&self) -> StorageResult<bool>;
+
+        /// Checks if Logs indexation functionality is available.
+        fn logs_indexation_enabled(&self) -> StorageResult<bool>;
+
+        /// Checks if FeeHistory indexation functionality is available.
+        fn fee_history_indexation_enabled(&self) -> StorageResult<bool>;
     }
 
     /// Represents either the Genesis Block or a block at a specific height
@@ -424,6 +523,9 @@ pub mod worker {
         + StorageMutate<MessageBalances, Error = StorageError>
         + StorageMutate<CoinsToSpendIndex, Error = StorageError>
         + StorageMutate<AssetsInfo, Error = StorageError>
+        + StorageMutate<ContractTransactions, Error = StorageError>
+        + StorageMutate<Logs, Error = StorageError>
+        + StorageMutate<FeeStatistics, Error = StorageError>
     {
         fn record_tx_id_owner(
             &mut self,
@@ -446,6 +548,15 @@ pub mod worker {
         /// Gets the total number of transactions on the chain from metadata.
         fn get_tx_count(&self) -> StorageResult<u64>;
 
+        /// Marks the indexation as unavailable in the metadata of the database.
+        /// The indexation stays unavailable because the blocks processed
+        /// without it are never indexed.
+        fn revoke_indexation(
+            &mut self,
+            kind: IndexationKind,
+            block_height: BlockHeight,
+        ) -> StorageResult<()>;
+
         /// Commits the underlying changes into the database.
         fn commit(self) -> StorageResult<()>;
     }
@@ -490,7 +601,9 @@ pub trait ChainStateProvider: Send + Sync {
     fn current_stf_version(&self) -> StateTransitionBytecodeVersion;
 }
 
-pub trait OnChainDatabaseAt: Send + Sync {
+pub trait OnChainDatabaseAt:
+    Send + Sync + StorageInspect<Coins, Error = StorageError>
+{
     fn contract_slot_values(
         &self,
         contract_id: ContractId,
@@ -504,4 +617,26 @@ pub trait OnChainDatabaseAt: Send + Sync {
     ) -> BoxedIter<'_, StorageResult<ContractBalance>>;
 }
 
-pub trait OffChainDatabaseAt: Send + Sync {}
+pub trait OffChainDatabaseAt: Send + Sync {
+    fn balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        base_asset_id: &AssetId,
+    ) -> StorageResult<TotalBalanceAmount>;
+
+    fn balances<'a>(
+        &'a self,
+        owner: &Address,
+        start: Option<AssetId>,
+        base_asset_id: &'a AssetId,
+        direction: IterDirection,
+    ) -> BoxedIter<'a, StorageResult<(AssetId, TotalBalanceAmount)>>;
+
+    fn owned_coins_ids(
+        &self,
+        owner: &Address,
+        start_coin: Option<UtxoId>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<UtxoId>>;
+}
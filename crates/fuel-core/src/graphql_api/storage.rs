@@ -1,4 +1,14 @@
 use crate::{
+    database::{
+        database_description::{
+            DatabaseDescription,
+            DatabaseMetadata,
+            IndexationKind,
+            indexation_availability,
+            off_chain::OffChain,
+        },
+        metadata::MetadataTable,
+    },
     fuel_core_graphql_api::storage::{
         blocks::FuelBlockIdsToHeights,
         coins::OwnedCoins,
@@ -41,6 +51,8 @@ pub mod balances;
 pub mod blocks;
 pub mod coins;
 pub mod contracts;
+pub mod fee_statistics;
+pub mod logs;
 pub mod messages;
 pub mod old;
 pub mod statistic;
@@ -103,6 +115,15 @@ pub enum Column {
     AssetsInfo = 25,
     /// Index of the coins that are available to spend.
     CoinsToSpend = 26,
+    /// The column of the table of all transactions that called or created a contract.
+    /// See [`ContractTransactions`](transactions::ContractTransactions)
+    TransactionsByContractBlockIdx = 27,
+    /// Log receipts emitted by contracts, indexed by the contract and the log id.
+    /// See [`Logs`](logs::Logs)
+    Logs = 28,
+    /// Fee statistics of each block, used to build the fee history.
+    /// See [`FeeStatistics`](fee_statistics::FeeStatistics)
+    FeeStatistics = 29,
 }
 
 impl Column {
@@ -131,7 +152,8 @@ where
     S: KeyValueInspect<Column = Column> + Modifiable,
     StorageTransaction<S>: StorageMutate<OwnedMessageIds, Error = StorageError>
         + StorageMutate<OwnedCoins, Error = StorageError>
-        + StorageMutate<FuelBlockIdsToHeights, Error = StorageError>,
+        + StorageMutate<FuelBlockIdsToHeights, Error = StorageError>
+        + StorageMutate<MetadataTable<OffChain>, Error = StorageError>,
 {
     fn record_tx_id_owner(
         &mut self,
@@ -172,6 +194,32 @@ where
         Ok(tx_count)
     }
 
+    fn revoke_indexation(
+        &mut self,
+        kind: IndexationKind,
+        block_height: BlockHeight,
+    ) -> StorageResult<()> {
+        let metadata = self
+            .storage::<MetadataTable<OffChain>>()
+            .get(&())?
+            .map(|metadata| metadata.into_owned());
+        if let Some(DatabaseMetadata::V1 { .. }) = metadata {
+            // The indexation is not available for the `V1` metadata.
+            return Ok(())
+        }
+        let mut availability = indexation_availability::<OffChain>(metadata);
+        if availability.remove(&kind) {
+            let metadata = DatabaseMetadata::V2 {
+                version: OffChain::version(),
+                height: block_height,
+                indexation_availability: availability,
+            };
+            self.storage::<MetadataTable<OffChain>>()
+                .insert(&(), &metadata)?;
+        }
+        Ok(())
+    }
+
     fn commit(self) -> StorageResult<()> {
         self.commit()?;
         Ok(())
@@ -0,0 +1,125 @@
+use fuel_core_storage::{
+    Mappable,
+    blueprint::plain::Plain,
+    codec::{
+        postcard::Postcard,
+        primitive::Primitive,
+    },
+    structured_storage::TableWithBlueprint,
+};
+use fuel_core_types::fuel_types::BlockHeight;
+
+/// The table stores the fee statistics of each block, used by the `feeHistory` query.
+pub struct FeeStatistics;
+
+impl Mappable for FeeStatistics {
+    type Key = Self::OwnedKey;
+    type OwnedKey = BlockHeight;
+    type Value = Self::OwnedValue;
+    type OwnedValue = BlockFeeStatistics;
+}
+
+impl TableWithBlueprint for FeeStatistics {
+    type Blueprint = Plain<Primitive<4>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::FeeStatistics
+    }
+}
+
+/// The tip paid by the transaction along with the gas it used.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+)]
+pub struct TipAndGas {
+    pub tip: u64,
+    pub gas: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BlockFeeStatistics {
+    /// The gas price of the block from its `Mint` transaction.
+    pub gas_price: u64,
+    /// The gas used by all transactions of the block.
+    pub gas_used: u64,
+    /// Tips of the transactions in the block, sorted by the tip in ascending order.
+    pub tips: Vec<TipAndGas>,
+}
+
+impl BlockFeeStatistics {
+    /// Returns the tip for each of the `percentiles` in the range `[0; 100]`.
+    /// Tips are weighted by the gas used, so the tip at the 50th percentile
+    /// is the tip paid for the median unit of gas in the block.
+    pub fn tip_percentiles(&self, percentiles: &[f64]) -> Vec<u64> {
+        let total_gas = self
+            .tips
+            .iter()
+            .fold(0u64, |total, tip| total.saturating_add(tip.gas));
+        let highest_tip = self.tips.last().map(|tip| tip.tip).unwrap_or_default();
+
+        percentiles
+            .iter()
+            .map(|percentile| {
+                let threshold = total_gas as f64 * percentile / 100.0;
+                let mut cumulative_gas = 0u64;
+                self.tips
+                    .iter()
+                    .find(|tip| {
+                        cumulative_gas = cumulative_gas.saturating_add(tip.gas);
+                        cumulative_gas as f64 >= threshold
+                    })
+                    .map(|tip| tip.tip)
+                    .unwrap_or(highest_tip)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn statistics() -> BlockFeeStatistics {
+        BlockFeeStatistics {
+            gas_price: 1,
+            gas_used: 100,
+            tips: vec![
+                TipAndGas { tip: 1, gas: 10 },
+                TipAndGas { tip: 5, gas: 60 },
+                TipAndGas { tip: 10, gas: 30 },
+            ],
+        }
+    }
+
+    fuel_core_storage::basic_storage_tests!(
+        FeeStatistics,
+        <FeeStatistics as Mappable>::Key::default(),
+        statistics()
+    );
+
+    #[test]
+    fn tip_percentiles_are_weighted_by_gas() {
+        // Given
+        let statistics = statistics();
+
+        // When
+        let tips =
+            statistics.tip_percentiles(&[0.0, 10.0, 11.0, 50.0, 70.0, 71.0, 100.0]);
+
+        // Then
+        assert_eq!(tips, vec![1, 1, 5, 5, 5, 10, 10]);
+    }
+
+    #[test]
+    fn tip_percentiles_of_empty_block_are_zero() {
+        // Given
+        let statistics = BlockFeeStatistics::default();
+
+        // When
+        let tips = statistics.tip_percentiles(&[0.0, 50.0, 100.0]);
+
+        // Then
+        assert_eq!(tips, vec![0, 0, 0]);
+    }
+}
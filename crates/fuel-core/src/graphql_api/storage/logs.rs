@@ -0,0 +1,194 @@
+use fuel_core_storage::{
+    Mappable,
+    blueprint::plain::Plain,
+    codec::{
+        Decode,
+        Encode,
+        manual::Manual,
+        postcard::Postcard,
+    },
+    structured_storage::TableWithBlueprint,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        ContractId,
+        Receipt,
+        TxId,
+    },
+    fuel_types::{
+        BlockHeight,
+        Word,
+    },
+};
+use std::mem::size_of;
+
+/// The table stores `Log` and `LogData` receipts of successful transactions,
+/// allowing iteration over them by the emitting contract and the log id(`rb` register).
+pub struct Logs;
+
+impl Mappable for Logs {
+    type Key = LogKey;
+    type OwnedKey = Self::Key;
+    type Value = Self::OwnedValue;
+    type OwnedValue = LogDetails;
+}
+
+impl TableWithBlueprint for Logs {
+    type Blueprint = Plain<Manual<LogKey>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::Logs
+    }
+}
+
+const LOG_ID_SIZE: usize = size_of::<Word>();
+const BLOCK_HEIGHT_SIZE: usize = size_of::<BlockHeight>();
+const TX_INDEX_SIZE: usize = size_of::<u16>();
+const RECEIPT_INDEX_SIZE: usize = size_of::<u32>();
+
+/// The size of the prefix used to iterate over logs of the contract with a specific log id.
+pub const LOG_PREFIX_SIZE: usize = ContractId::LEN + LOG_ID_SIZE;
+const BLOCK_HEIGHT_OFFSET: usize = LOG_PREFIX_SIZE;
+const TX_INDEX_OFFSET: usize = BLOCK_HEIGHT_OFFSET + BLOCK_HEIGHT_SIZE;
+const RECEIPT_INDEX_OFFSET: usize = TX_INDEX_OFFSET + TX_INDEX_SIZE;
+const LOG_KEY_SIZE: usize = RECEIPT_INDEX_OFFSET + RECEIPT_INDEX_SIZE;
+
+/// Returns the prefix of the [`Logs`] table for the `contract_id` and `rb`.
+pub fn log_prefix(contract_id: &ContractId, rb: Word) -> [u8; LOG_PREFIX_SIZE] {
+    let mut prefix = [0u8; LOG_PREFIX_SIZE];
+    prefix[..ContractId::LEN].copy_from_slice(contract_id.as_ref());
+    prefix[ContractId::LEN..].copy_from_slice(&rb.to_be_bytes());
+    prefix
+}
+
+/// The key is `contract_id + rb + block_height + tx_idx + receipt_idx`, so logs with
+/// the same contract and log id are sorted by the order in which they were emitted.
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+pub struct LogKey {
+    pub contract_id: ContractId,
+    pub rb: Word,
+    pub block_height: BlockHeight,
+    pub tx_idx: u16,
+    pub receipt_idx: u32,
+}
+
+impl LogKey {
+    pub fn new(
+        contract_id: ContractId,
+        rb: Word,
+        block_height: BlockHeight,
+        tx_idx: u16,
+        receipt_idx: u32,
+    ) -> Self {
+        Self {
+            contract_id,
+            rb,
+            block_height,
+            tx_idx,
+            receipt_idx,
+        }
+    }
+
+    fn to_bytes(&self) -> [u8; LOG_KEY_SIZE] {
+        let mut bytes = [0u8; LOG_KEY_SIZE];
+        bytes[..LOG_PREFIX_SIZE].copy_from_slice(&log_prefix(&self.contract_id, self.rb));
+        bytes[BLOCK_HEIGHT_OFFSET..TX_INDEX_OFFSET]
+            .copy_from_slice(&self.block_height.to_bytes());
+        bytes[TX_INDEX_OFFSET..RECEIPT_INDEX_OFFSET]
+            .copy_from_slice(&self.tx_idx.to_be_bytes());
+        bytes[RECEIPT_INDEX_OFFSET..].copy_from_slice(&self.receipt_idx.to_be_bytes());
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8; LOG_KEY_SIZE]) -> Self {
+        let contract_id: [u8; ContractId::LEN] = bytes[..ContractId::LEN]
+            .try_into()
+            .expect("It's an array of 32 bytes");
+        let rb: [u8; LOG_ID_SIZE] = bytes[ContractId::LEN..BLOCK_HEIGHT_OFFSET]
+            .try_into()
+            .expect("It's an array of 8 bytes");
+        let block_height: [u8; BLOCK_HEIGHT_SIZE] = bytes
+            [BLOCK_HEIGHT_OFFSET..TX_INDEX_OFFSET]
+            .try_into()
+            .expect("It's an array of 4 bytes");
+        let tx_idx: [u8; TX_INDEX_SIZE] = bytes[TX_INDEX_OFFSET..RECEIPT_INDEX_OFFSET]
+            .try_into()
+            .expect("It's an array of 2 bytes");
+        let receipt_idx: [u8; RECEIPT_INDEX_SIZE] = bytes[RECEIPT_INDEX_OFFSET..]
+            .try_into()
+            .expect("It's an array of 4 bytes");
+
+        Self {
+            contract_id: contract_id.into(),
+            rb: Word::from_be_bytes(rb),
+            block_height: u32::from_be_bytes(block_height).into(),
+            tx_idx: u16::from_be_bytes(tx_idx),
+            receipt_idx: u32::from_be_bytes(receipt_idx),
+        }
+    }
+}
+
+impl Encode<LogKey> for Manual<LogKey> {
+    type Encoder<'a> = [u8; LOG_KEY_SIZE];
+
+    fn encode(t: &LogKey) -> Self::Encoder<'_> {
+        t.to_bytes()
+    }
+}
+
+impl Decode<LogKey> for Manual<LogKey> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<LogKey> {
+        let bytes: [u8; LOG_KEY_SIZE] = bytes
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))?;
+        Ok(LogKey::from_bytes(&bytes))
+    }
+}
+
+/// The log receipt along with the id of the transaction that emitted it.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LogDetails {
+    pub tx_id: TxId,
+    pub receipt: Receipt,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn generate_key(rng: &mut impl rand::Rng) -> <Logs as Mappable>::Key {
+        let mut bytes = [0u8; LOG_KEY_SIZE];
+        rng.fill(bytes.as_mut());
+        LogKey::from_bytes(&bytes)
+    }
+
+    fn details() -> LogDetails {
+        LogDetails {
+            tx_id: [3u8; 32].into(),
+            receipt: Receipt::log([2u8; 32].into(), 1, 2, 3, 4, 5, 6),
+        }
+    }
+
+    fuel_core_storage::basic_storage_tests!(
+        Logs,
+        LogKey::new([1u8; 32].into(), 2, 3.into(), 4, 5),
+        details(),
+        details(),
+        generate_key
+    );
+
+    #[test]
+    fn log_key_encoding_roundtrip() {
+        let key = LogKey::new([1u8; 32].into(), 2, 3.into(), 4, 5);
+        let encoded = key.to_bytes();
+
+        assert_eq!(
+            encoded[..LOG_PREFIX_SIZE],
+            log_prefix(&key.contract_id, key.rb)
+        );
+        assert_eq!(LogKey::from_bytes(&encoded), key);
+    }
+}
@@ -21,6 +21,7 @@ use fuel_core_types::{
     fuel_tx::{
         Address,
         Bytes32,
+        ContractId,
     },
     fuel_types::BlockHeight,
     services::transaction_status::TransactionExecutionStatus,
@@ -61,6 +62,37 @@ impl AddTable<OwnedTransactions> for StateConfigBuilder {
     }
 }
 
+/// These tables allow iteration over all transactions that called or created a contract.
+pub struct ContractTransactions;
+
+impl Mappable for ContractTransactions {
+    type Key = ContractTransactionIndexKey;
+    type OwnedKey = Self::Key;
+    type Value = Bytes32;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for ContractTransactions {
+    type Blueprint = Plain<Manual<ContractTransactionIndexKey>, Raw>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::TransactionsByContractBlockIdx
+    }
+}
+
+impl AsTable<ContractTransactions> for StateConfig {
+    fn as_table(&self) -> Vec<TableEntry<ContractTransactions>> {
+        Vec::new() // Do not include these for now
+    }
+}
+
+impl AddTable<ContractTransactions> for StateConfigBuilder {
+    fn add(&mut self, _entries: Vec<TableEntry<ContractTransactions>>) {
+        // Do not include these for now
+    }
+}
+
 /// The table stores the status of each transaction.
 pub struct TransactionStatuses;
 
@@ -96,15 +128,15 @@ const TX_INDEX_SIZE: usize = size_of::<TransactionIndex>();
 const BLOCK_HEIGHT: usize = size_of::<BlockHeight>();
 const INDEX_SIZE: usize = Address::LEN + BLOCK_HEIGHT + TX_INDEX_SIZE;
 
-fn owned_tx_index_key(
-    owner: &Address,
+fn tx_index_key(
+    prefix: &[u8; 32],
     height: BlockHeight,
     tx_idx: TransactionIndex,
 ) -> [u8; INDEX_SIZE] {
     let mut default = [0u8; INDEX_SIZE];
-    // generate prefix to enable sorted indexing of transactions by owner
-    // owner + block_height + tx_idx
-    default[0..Address::LEN].copy_from_slice(owner.as_ref());
+    // generate prefix to enable sorted indexing of transactions by owner or contract
+    // prefix + block_height + tx_idx
+    default[0..Address::LEN].copy_from_slice(prefix.as_ref());
     default[Address::LEN..Address::LEN + BLOCK_HEIGHT]
         .copy_from_slice(height.to_bytes().as_ref());
     default[Address::LEN + BLOCK_HEIGHT..].copy_from_slice(tx_idx.to_be_bytes().as_ref());
@@ -168,7 +200,7 @@ impl Encode<OwnedTransactionIndexKey> for Manual<OwnedTransactionIndexKey> {
     type Encoder<'a> = [u8; INDEX_SIZE];
 
     fn encode(t: &OwnedTransactionIndexKey) -> Self::Encoder<'_> {
-        owned_tx_index_key(&t.owner, t.block_height, t.tx_idx)
+        tx_index_key(&t.owner, t.block_height, t.tx_idx)
     }
 }
 
@@ -179,6 +211,70 @@ impl Decode<OwnedTransactionIndexKey> for Manual<OwnedTransactionIndexKey> {
     }
 }
 
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+pub struct ContractTransactionIndexKey {
+    pub contract_id: ContractId,
+    pub block_height: BlockHeight,
+    pub tx_idx: TransactionIndex,
+}
+
+impl ContractTransactionIndexKey {
+    pub fn new(
+        contract_id: &ContractId,
+        block_height: BlockHeight,
+        tx_idx: TransactionIndex,
+    ) -> Self {
+        Self {
+            contract_id: *contract_id,
+            block_height,
+            tx_idx,
+        }
+    }
+}
+
+impl From<[u8; INDEX_SIZE]> for ContractTransactionIndexKey {
+    fn from(bytes: [u8; INDEX_SIZE]) -> Self {
+        let contract_id: [u8; 32] =
+            bytes[..32].try_into().expect("It's an array of 32 bytes");
+        let mut block_height_bytes: [u8; 4] = Default::default();
+        block_height_bytes.copy_from_slice(&bytes[32..36]);
+        let mut tx_idx_bytes: [u8; 2] = Default::default();
+        tx_idx_bytes.copy_from_slice(&bytes.as_ref()[36..38]);
+
+        Self {
+            contract_id: ContractId::from(contract_id),
+            block_height: u32::from_be_bytes(block_height_bytes).into(),
+            tx_idx: u16::from_be_bytes(tx_idx_bytes),
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for ContractTransactionIndexKey {
+    type Error = TryFromSliceError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; INDEX_SIZE] = bytes.try_into()?;
+        Ok(Self::from(bytes))
+    }
+}
+
+impl Encode<ContractTransactionIndexKey> for Manual<ContractTransactionIndexKey> {
+    type Encoder<'a> = [u8; INDEX_SIZE];
+
+    fn encode(t: &ContractTransactionIndexKey) -> Self::Encoder<'_> {
+        tx_index_key(&t.contract_id, t.block_height, t.tx_idx)
+    }
+}
+
+impl Decode<ContractTransactionIndexKey> for Manual<ContractTransactionIndexKey> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<ContractTransactionIndexKey> {
+        ContractTransactionIndexKey::try_from(bytes)
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))
+    }
+}
+
 #[derive(Clone, Debug, PartialOrd, Eq, PartialEq)]
 pub struct OwnedTransactionIndexCursor {
     pub block_height: BlockHeight,
@@ -235,6 +331,22 @@ mod test {
         generate_key
     );
 
+    fn generate_contract_key(
+        rng: &mut impl rand::Rng,
+    ) -> <ContractTransactions as Mappable>::Key {
+        let mut bytes = [0u8; INDEX_SIZE];
+        rng.fill(bytes.as_mut());
+        bytes.into()
+    }
+
+    fuel_core_storage::basic_storage_tests!(
+        ContractTransactions,
+        [1u8; INDEX_SIZE].into(),
+        <ContractTransactions as Mappable>::Value::default(),
+        <ContractTransactions as Mappable>::Value::default(),
+        generate_contract_key
+    );
+
     fuel_core_storage::basic_storage_tests!(
         TransactionStatuses,
         <TransactionStatuses as Mappable>::Key::default(),
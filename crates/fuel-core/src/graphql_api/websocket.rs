@@ -0,0 +1,180 @@
+//! Serves GraphQL subscriptions over the
+//! [`graphql-transport-ws`](https://github.com/enisdenjo/graphql-ws/blob/master/PROTOCOL.md)
+//! protocol, so clients can multiplex many subscriptions over one connection.
+
+use crate::schema::CoreSchema;
+use async_graphql::{
+    Data,
+    http::{
+        WebSocket,
+        WebSocketProtocols,
+        WsMessage,
+    },
+};
+use axum::{
+    extract::ws::{
+        self,
+        CloseFrame,
+        Message,
+    },
+    http::{
+        HeaderMap,
+        header::SEC_WEBSOCKET_PROTOCOL,
+    },
+};
+use futures::{
+    SinkExt,
+    StreamExt,
+};
+use std::sync::Arc;
+use tokio::sync::{
+    OwnedSemaphorePermit,
+    Semaphore,
+};
+
+pub(crate) const PROTOCOL: WebSocketProtocols = WebSocketProtocols::GraphQLWS;
+
+/// Limits the resources used by the WebSocket connections.
+#[derive(Clone)]
+pub(crate) struct Limits {
+    /// One permit per open connection.
+    connections: Arc<Semaphore>,
+    /// The max size of the message or of the frame received from the client.
+    pub max_message_size: usize,
+}
+
+impl Limits {
+    pub fn new(max_connections: usize, max_message_size: usize) -> Self {
+        Self {
+            connections: Arc::new(Semaphore::new(max_connections)),
+            max_message_size,
+        }
+    }
+
+    /// Reserves the connection slot, if any is left.
+    /// The slot is released when the permit is dropped.
+    pub fn try_acquire_connection(&self) -> Option<OwnedSemaphorePermit> {
+        self.connections.clone().try_acquire_owned().ok()
+    }
+}
+
+/// Checks that the client offers the `graphql-transport-ws` protocol.
+pub(crate) fn supports_protocol(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(SEC_WEBSOCKET_PROTOCOL)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|protocol| protocol.trim() == PROTOCOL.sec_websocket_protocol())
+}
+
+/// Runs the GraphQL protocol over the upgraded connection until
+/// the client or the server closes it. The protocol errors close
+/// the connection with the corresponding close code and reason.
+pub(crate) async fn serve(schema: CoreSchema, data: Data, socket: ws::WebSocket) {
+    let (mut sender, receiver) = socket.split();
+
+    let incoming = receiver
+        .take_while(|message| {
+            let open = match message {
+                Ok(Message::Close(_)) => false,
+                Ok(_) => true,
+                Err(e) => {
+                    tracing::debug!("The GraphQL WebSocket connection is closed: {e:?}");
+                    false
+                }
+            };
+            futures::future::ready(open)
+        })
+        .filter_map(|message| {
+            let data = match message {
+                Ok(Message::Text(text)) => Some(text.into_bytes()),
+                Ok(Message::Binary(data)) => Some(data),
+                _ => None,
+            };
+            futures::future::ready(data)
+        })
+        .boxed();
+    let mut outgoing = WebSocket::new(schema, incoming, PROTOCOL).connection_data(data);
+
+    while let Some(message) = outgoing.next().await {
+        let message = match message {
+            WsMessage::Text(text) => Message::Text(text),
+            WsMessage::Close(code, reason) => {
+                tracing::debug!(
+                    "Closing the GraphQL WebSocket connection: {code} {reason}"
+                );
+                Message::Close(Some(CloseFrame {
+                    code,
+                    reason: reason.into(),
+                }))
+            }
+        };
+        let is_close = matches!(message, Message::Close(_));
+
+        if let Err(e) = sender.send(message).await {
+            tracing::debug!("Failed to send the GraphQL WebSocket message: {e:?}");
+            return
+        }
+        if is_close {
+            return
+        }
+    }
+
+    let _ = sender.close().await;
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn protocol_headers(protocols: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            SEC_WEBSOCKET_PROTOCOL,
+            axum::http::HeaderValue::from_static(protocols),
+        );
+        headers
+    }
+
+    #[test]
+    fn supports_protocol__accepts_graphql_transport_ws_among_others() {
+        // Given
+        let headers = protocol_headers("graphql-ws, graphql-transport-ws");
+
+        // When
+        let supported = supports_protocol(&headers);
+
+        // Then
+        assert!(supported);
+    }
+
+    #[test]
+    fn limits__rejects_connections_above_the_limit() {
+        // Given
+        let limits = Limits::new(1, 1024);
+        let first = limits.try_acquire_connection();
+
+        // When
+        let second = limits.try_acquire_connection();
+        drop(first);
+        let third = limits.try_acquire_connection();
+
+        // Then
+        assert!(second.is_none());
+        assert!(third.is_some());
+    }
+
+    #[test]
+    fn supports_protocol__rejects_unsupported_protocol() {
+        // Given
+        let headers = protocol_headers("graphql-ws");
+
+        // When
+        let supported = supports_protocol(&headers);
+
+        // Then
+        assert!(!supported);
+    }
+}
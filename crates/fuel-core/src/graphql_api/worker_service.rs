@@ -10,6 +10,7 @@ use super::{
     },
 };
 use crate::{
+    database::database_description::IndexationKind,
     fuel_core_graphql_api::{
         ports::{
             self,
@@ -25,6 +26,10 @@ use crate::{
                 owner_coin_id_key,
             },
             contracts::ContractsInfo,
+            logs::{
+                LogDetails,
+                LogKey,
+            },
             messages::{
                 OwnedMessageIds,
                 OwnedMessageKey,
@@ -119,6 +124,8 @@ pub(crate) struct Context<'a, TxStatusManager, BlockImporter, OnChain, OffChain>
     pub(crate) off_chain_database: OffChain,
     pub(crate) continue_on_error: bool,
     pub(crate) block_subscriptions_queue: usize,
+    pub(crate) logs_indexation_enabled: bool,
+    pub(crate) fee_history_indexation_enabled: bool,
     pub(crate) consensus_parameters: &'a ConsensusParameters,
 }
 
@@ -132,6 +139,8 @@ pub struct InitializeTask<TxStatusManager, BlockImporter, OnChain, OffChain> {
     on_chain_database: OnChain,
     off_chain_database: OffChain,
     base_asset_id: AssetId,
+    logs_indexation_enabled: bool,
+    fee_history_indexation_enabled: bool,
     shared_state: SharedState,
 }
 
@@ -139,6 +148,14 @@ pub struct InitializeTask<TxStatusManager, BlockImporter, OnChain, OffChain> {
 pub struct SharedState {
     pub block_height_subscription_handler: block_height_subscription::Handler,
     pub block_subscription: tokio::sync::broadcast::Sender<Arc<Vec<u8>>>,
+    /// Notifies about the logs indexed from the newly imported block.
+    pub log_subscription: tokio::sync::broadcast::Sender<Arc<BlockLogs>>,
+}
+
+/// The logs indexed from the imported block.
+pub struct BlockLogs {
+    pub block_height: BlockHeight,
+    pub logs: Vec<(LogKey, LogDetails)>,
 }
 
 /// The off-chain GraphQL API worker task processes the imported blocks
@@ -152,6 +169,12 @@ pub struct Task<TxStatusManager, D> {
     balances_indexation_enabled: bool,
     coins_to_spend_indexation_enabled: bool,
     asset_metadata_indexation_enabled: bool,
+    contract_transactions_indexation_enabled: bool,
+    logs_indexation_enabled: bool,
+    fee_history_indexation_enabled: bool,
+    /// The indexation disabled by the configuration that is still marked as available.
+    /// It is revoked with the next processed block.
+    revoked_indexation: Vec<IndexationKind>,
     base_asset_id: AssetId,
     shared_state: SharedState,
 }
@@ -174,6 +197,28 @@ where
         // save the associated owner for each transaction in the block
         index_tx_owners_for_block(block, &mut transaction, &self.chain_id)?;
 
+        // save the associated contracts for each transaction in the block
+        indexation::contract_transactions::update(
+            block,
+            &mut transaction,
+            self.contract_transactions_indexation_enabled,
+            &self.chain_id,
+        )?;
+
+        // save the logs emitted by the contracts in the block
+        let logs = indexation::logs::update(
+            &result,
+            &mut transaction,
+            self.logs_indexation_enabled,
+        )?;
+
+        // save the gas price, the gas used and the tips of the block
+        indexation::fee_statistics::update(
+            &result,
+            &mut transaction,
+            self.fee_history_indexation_enabled,
+        )?;
+
         // save the transaction related information
         process_transactions(block.transactions().iter(), &mut transaction)?;
 
@@ -195,7 +240,12 @@ where
             &self.base_asset_id,
         )?;
 
+        for kind in &self.revoked_indexation {
+            transaction.revoke_indexation(*kind, *height)?;
+        }
+
         transaction.commit()?;
+        self.revoked_indexation.clear();
 
         for status in result.tx_status.iter() {
             let tx_id = status.id;
@@ -217,6 +267,13 @@ where
             .shared_state
             .block_subscription
             .send(Arc::new(raw_import_result));
+
+        if self.logs_indexation_enabled {
+            let _ = self.shared_state.log_subscription.send(Arc::new(BlockLogs {
+                block_height: *height,
+                logs,
+            }));
+        }
         // Get all the subscribers that need to be notified that the block height
         // has been reached.
 
@@ -596,10 +653,45 @@ where
         let asset_metadata_indexation_enabled = self
             .off_chain_database
             .asset_metadata_indexation_enabled()?;
+        let contract_transactions_indexation_enabled = self
+            .off_chain_database
+            .contract_transactions_indexation_enabled()?;
+        let logs_indexation_available =
+            self.off_chain_database.logs_indexation_enabled()?;
+        let fee_history_indexation_available =
+            self.off_chain_database.fee_history_indexation_enabled()?;
+
+        // The optional indexation is available only if it was enabled
+        // for all blocks since the creation of the database.
+        let mut revoked_indexation = vec![];
+        if !self.logs_indexation_enabled && logs_indexation_available {
+            revoked_indexation.push(IndexationKind::Logs);
+        }
+        if !self.fee_history_indexation_enabled && fee_history_indexation_available {
+            revoked_indexation.push(IndexationKind::FeeHistory);
+        }
+        if self.logs_indexation_enabled && !logs_indexation_available {
+            tracing::warn!(
+                "Logs indexation is not available for this database and stays disabled"
+            );
+        }
+        if self.fee_history_indexation_enabled && !fee_history_indexation_available {
+            tracing::warn!(
+                "Fee history indexation is not available for this database and stays disabled"
+            );
+        }
+        let logs_indexation_enabled =
+            self.logs_indexation_enabled && logs_indexation_available;
+        let fee_history_indexation_enabled =
+            self.fee_history_indexation_enabled && fee_history_indexation_available;
+
         tracing::info!(
             balances_indexation_enabled,
             coins_to_spend_indexation_enabled,
             asset_metadata_indexation_enabled,
+            contract_transactions_indexation_enabled,
+            logs_indexation_enabled,
+            fee_history_indexation_enabled,
             "Indexation availability status"
         );
         tracing::debug!(
@@ -617,6 +709,7 @@ where
             continue_on_error,
             base_asset_id,
             shared_state,
+            ..
         } = self;
 
         let mut task = Task {
@@ -628,6 +721,10 @@ where
             balances_indexation_enabled,
             coins_to_spend_indexation_enabled,
             asset_metadata_indexation_enabled,
+            contract_transactions_indexation_enabled,
+            logs_indexation_enabled,
+            fee_history_indexation_enabled,
+            revoked_indexation,
             base_asset_id,
             shared_state,
         };
@@ -763,6 +860,8 @@ where
         off_chain_database,
         continue_on_error,
         block_subscriptions_queue,
+        logs_indexation_enabled,
+        fee_history_indexation_enabled,
         consensus_parameters,
     } = context;
 
@@ -772,6 +871,7 @@ where
     let shared_state = SharedState {
         block_height_subscription_handler,
         block_subscription: tokio::sync::broadcast::channel(block_subscriptions_queue).0,
+        log_subscription: tokio::sync::broadcast::channel(block_subscriptions_queue).0,
     };
 
     let service = ServiceRunner::new(InitializeTask {
@@ -783,6 +883,8 @@ where
         chain_id: consensus_parameters.chain_id(),
         continue_on_error,
         base_asset_id: *consensus_parameters.base_asset_id(),
+        logs_indexation_enabled,
+        fee_history_indexation_enabled,
         shared_state,
     });
 
@@ -88,10 +88,15 @@ fn worker_task_with_block_importer_and_db<D: ports::worker::OffChainDatabase>(
         balances_indexation_enabled: true,
         coins_to_spend_indexation_enabled: true,
         asset_metadata_indexation_enabled: true,
+        contract_transactions_indexation_enabled: true,
+        logs_indexation_enabled: true,
+        fee_history_indexation_enabled: true,
+        revoked_indexation: vec![],
         base_asset_id: Default::default(),
         shared_state: SharedState {
             block_height_subscription_handler: Default::default(),
             block_subscription: tokio::sync::broadcast::channel(1).0,
+            log_subscription: tokio::sync::broadcast::channel(1).0,
         },
     }
 }
@@ -18,6 +18,7 @@ use crate::{
     },
 };
 use fuel_core_chain_config::{
+    BftConfig,
     ConsensusConfig,
     StateConfig,
 };
@@ -26,6 +27,11 @@ use fuel_core_p2p::{
         gossipsub::GossipsubMessageHandler,
         request_response::RequestResponseMessageHandler,
     },
+    config::TxGossipMode,
+    gossipsub::messages::{
+        GossipsubBroadcastRequest,
+        GossipsubMessage,
+    },
     network_service::FuelP2PService,
     p2p_service::FuelP2PEvent,
     request_response::messages::{
@@ -44,11 +50,19 @@ use fuel_core_storage::{
 };
 use fuel_core_txpool::error::Error as TxPoolError;
 use fuel_core_types::{
+    blockchain::consensus::bft::{
+        BftMessage,
+        Signed,
+        Vote,
+    },
     fuel_asm::{
         RegId,
         op,
     },
-    fuel_crypto::SecretKey,
+    fuel_crypto::{
+        SecretKey,
+        Signature,
+    },
     fuel_tx::{
         Input,
         Transaction,
@@ -67,15 +81,20 @@ use fuel_core_types::{
 };
 use futures::StreamExt;
 use rand::{
+    Rng,
     SeedableRng,
     rngs::StdRng,
 };
 use std::{
-    collections::HashMap,
+    collections::{
+        HashMap,
+        HashSet,
+    },
     ops::{
         Index,
         IndexMut,
     },
+    sync::Arc,
     time::Duration,
 };
 use tokio::{
@@ -97,6 +116,7 @@ pub struct CustomizeConfig {
     max_functional_peers_connected: Option<u32>,
     max_discovery_peers_connected: Option<u32>,
     subscribe_to_transactions: Option<bool>,
+    tx_gossip_mode: Option<TxGossipMode>,
     #[cfg(feature = "rpc")]
     rpc_config: Option<fuel_core_block_aggregator_api::service::Config>,
 }
@@ -108,6 +128,7 @@ impl CustomizeConfig {
             max_functional_peers_connected: None,
             max_discovery_peers_connected: None,
             subscribe_to_transactions: None,
+            tx_gossip_mode: None,
             #[cfg(feature = "rpc")]
             rpc_config: None,
         }
@@ -132,6 +153,11 @@ impl CustomizeConfig {
         self.subscribe_to_transactions = Some(enabled);
         self
     }
+
+    pub fn tx_gossip_mode(mut self, mode: TxGossipMode) -> Self {
+        self.tx_gossip_mode = Some(mode);
+        self
+    }
 }
 
 #[derive(Clone)]
@@ -193,6 +219,39 @@ pub struct Nodes {
 /// Nodes accessible by their name.
 pub struct NamedNodes(pub HashMap<String, Node>);
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// Behaviour of the validator of the BFT consensus
+pub enum BftBehaviour {
+    /// Runs the full node and follows the protocol.
+    Honest,
+    /// Never joins the network.
+    Crashed,
+    /// Signs conflicting votes for every round it observes.
+    Equivocating,
+}
+
+#[derive(Clone)]
+/// Setup for a validator of the BFT consensus
+pub struct BftValidatorSetup {
+    /// Name of the validator.
+    pub name: String,
+    /// Secret key used to sign the consensus messages.
+    pub secret: SecretKey,
+    /// Behaviour of the validator.
+    pub behaviour: BftBehaviour,
+}
+
+/// A validator that only gossips conflicting votes without running the node.
+pub struct ByzantineValidator {
+    kill: broadcast::Sender<()>,
+}
+
+pub struct BftNodes {
+    pub bootstrap_nodes: Vec<Bootstrap>,
+    pub honest: Vec<Node>,
+    pub byzantine: Vec<ByzantineValidator>,
+}
+
 impl Bootstrap {
     /// Spawn a bootstrap node.
     pub async fn new(node_config: &Config) -> anyhow::Result<Self> {
@@ -264,6 +323,174 @@ impl Bootstrap {
     }
 }
 
+impl ByzantineValidator {
+    /// Spawn a validator that equivocates in every round of the consensus.
+    pub async fn new(node_config: &Config, secret: SecretKey) -> anyhow::Result<Self> {
+        let p2p_config = extract_p2p_config(node_config).await;
+        let chain_id = node_config
+            .snapshot_reader
+            .chain_config()
+            .consensus_parameters
+            .chain_id();
+        let request_response_codec =
+            RequestResponseMessageHandler::new(p2p_config.max_block_size);
+        let gossipsub_codec = GossipsubMessageHandler::new();
+        let (sender, _) =
+            broadcast::channel(p2p_config.reserved_nodes.len().saturating_add(1));
+        let mut validator = FuelP2PService::new(
+            sender,
+            p2p_config,
+            gossipsub_codec,
+            request_response_codec,
+        )
+        .await?;
+        validator.start().await?;
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut attacked_rounds = HashSet::new();
+        let (kill, mut shutdown) = broadcast::channel(1);
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    result = shutdown.recv() => {
+                        assert!(result.is_ok());
+                        break;
+                    }
+                    event = validator.next_event() => {
+                        let Some(FuelP2PEvent::GossipsubMessage {
+                            peer_id,
+                            message_id,
+                            message,
+                            ..
+                        }) = event else {
+                            continue
+                        };
+                        validator.report_message_validation_result(
+                            &message_id,
+                            peer_id,
+                            to_message_acceptance(&GossipsubMessageAcceptance::Accept)
+                        );
+
+                        let GossipsubMessage::BftConsensus(message) = message else {
+                            continue
+                        };
+                        let height = message.height();
+                        let round = message.round();
+                        if !attacked_rounds.insert((height, round)) {
+                            continue
+                        }
+
+                        // Votes for the proposed block, for a block that doesn't
+                        // exist and against all blocks of the round at the same time.
+                        let mut block_ids = vec![Some(rng.r#gen::<[u8; 32]>().into()), None];
+                        if let BftMessage::Proposal(proposal) = &message {
+                            block_ids.push(Some(proposal.entity.block.id()));
+                        }
+                        for block_id in block_ids {
+                            for vote in [
+                                Vote::prevote(height, round, block_id),
+                                Vote::precommit(height, round, block_id),
+                            ] {
+                                let signature = Signature::sign(&secret, &vote.signing_message(&chain_id));
+                                let message = BftMessage::Vote(Signed {
+                                    entity: vote,
+                                    signature,
+                                });
+                                let _ = validator.publish_message(
+                                    GossipsubBroadcastRequest::BftConsensus(Arc::new(message)),
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        Ok(ByzantineValidator { kill })
+    }
+
+    pub fn shutdown(&mut self) {
+        self.kill.send(()).unwrap();
+    }
+}
+
+/// Creates the network of validators of the BFT consensus with the given setups.
+/// All validators, including the faulty ones, are part of the validator set.
+pub async fn make_bft_nodes(
+    validators_setup: impl IntoIterator<Item = BftValidatorSetup>,
+    config: Option<Config>,
+) -> BftNodes {
+    let validators_setup: Vec<_> = validators_setup.into_iter().collect();
+    let mut config = config.unwrap_or_else(Config::local_node);
+
+    let validators = validators_setup
+        .iter()
+        .map(|setup| Input::owner(&setup.secret.public_key()))
+        .collect();
+    let mut chain_config = config.snapshot_reader.chain_config().clone();
+    chain_config.consensus = ConsensusConfig::Bft(BftConfig::new(validators));
+    config.snapshot_reader = config
+        .snapshot_reader
+        .clone()
+        .with_chain_config(chain_config);
+
+    let mut bootstrap_config = make_config(
+        "b:0".to_string(),
+        config.clone(),
+        CustomizeConfig::no_overrides(),
+    );
+    // The bootstrap node forwards the consensus messages between validators.
+    if let Some(p2p) = &mut bootstrap_config.p2p {
+        p2p.subscribe_to_bft_consensus = true;
+    }
+    let bootstrap = Bootstrap::new(&bootstrap_config)
+        .await
+        .expect("Failed to create bootstrap node");
+    let boots = bootstrap.listeners();
+
+    let mut honest = vec![];
+    let mut byzantine = vec![];
+    for (i, setup) in validators_setup.into_iter().enumerate() {
+        let BftValidatorSetup {
+            name,
+            secret,
+            behaviour,
+        } = setup;
+        let node_name = if name.is_empty() {
+            format!("v:{i}")
+        } else {
+            name
+        };
+        let mut node_config =
+            make_config(node_name, config.clone(), CustomizeConfig::no_overrides());
+        node_config.block_production = Trigger::Never;
+        node_config.consensus_signer = SignMode::Key(Secret::new(secret.into()));
+        if let Some(p2p) = &mut node_config.p2p {
+            p2p.reserved_nodes.clone_from(&boots);
+            p2p.subscribe_to_bft_consensus = true;
+        }
+
+        match behaviour {
+            BftBehaviour::Honest => {
+                honest.push(make_node(node_config, Vec::with_capacity(0)).await);
+            }
+            BftBehaviour::Crashed => {}
+            BftBehaviour::Equivocating => {
+                let validator = ByzantineValidator::new(&node_config, secret)
+                    .await
+                    .expect("Failed to create byzantine validator");
+                byzantine.push(validator);
+            }
+        }
+    }
+
+    BftNodes {
+        bootstrap_nodes: vec![bootstrap],
+        honest,
+        byzantine,
+    }
+}
+
 // set of nodes with the given setups.
 #[allow(clippy::arithmetic_side_effects)]
 pub async fn make_nodes(
@@ -495,6 +722,9 @@ fn update_signing_key(config: &mut Config, key: Address) {
         ConsensusConfig::PoAV2(poa) => {
             poa.set_genesis_signing_key(key);
         }
+        ConsensusConfig::Bft(_) => {
+            // The validators of the network are defined by the chain config.
+        }
     }
     config.snapshot_reader = snapshot_reader.clone().with_chain_config(chain_config)
 }
@@ -533,6 +763,10 @@ pub fn make_config(
         {
             p2p.subscribe_to_transactions = subscribe_to_transactions;
         }
+
+        if let Some(tx_gossip_mode) = config_overrides.tx_gossip_mode {
+            p2p.tx_gossip_mode = tx_gossip_mode;
+        }
     }
     node_config
 }
@@ -874,8 +1108,31 @@ impl IndexMut<&str> for NamedNodes {
     }
 }
 
+impl BftValidatorSetup {
+    pub fn new(secret: SecretKey, behaviour: BftBehaviour) -> Self {
+        Self {
+            name: Default::default(),
+            secret,
+            behaviour,
+        }
+    }
+
+    pub fn with_name(self, name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..self
+        }
+    }
+}
+
 impl Drop for Bootstrap {
     fn drop(&mut self) {
         self.shutdown();
     }
 }
+
+impl Drop for ByzantineValidator {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
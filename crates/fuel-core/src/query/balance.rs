@@ -5,7 +5,10 @@ use std::{
 
 use crate::{
     database::database_description::IndexationKind,
-    fuel_core_graphql_api::database::ReadView,
+    fuel_core_graphql_api::database::{
+        ReadView,
+        ReadViewAt,
+    },
     graphql_api::storage::balances::TotalBalanceAmount,
 };
 use asset_query::{
@@ -16,7 +19,10 @@ use asset_query::{
 use fuel_core_services::yield_stream::StreamYieldExt;
 use fuel_core_storage::{
     Result as StorageResult,
-    iter::IterDirection,
+    iter::{
+        IntoBoxedIter,
+        IterDirection,
+    },
 };
 use fuel_core_types::{
     fuel_tx::{
@@ -161,3 +167,58 @@ impl ReadView {
         .yield_each(self.batch_size)
     }
 }
+
+impl ReadViewAt {
+    /// The history of the balances is only available from the balances indexation.
+    fn require_balances_indexation(&self) -> StorageResult<()> {
+        if self.indexation_flags.contains(&IndexationKind::Balances) {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!(
+                "The balances indexation is required to query balances at the past height"
+            )
+            .into())
+        }
+    }
+
+    pub fn balance(
+        &self,
+        owner: Address,
+        asset_id: AssetId,
+        base_asset_id: AssetId,
+    ) -> StorageResult<AddressBalance> {
+        self.require_balances_indexation()?;
+        let amount = self.off_chain.balance(&owner, &asset_id, &base_asset_id)?;
+
+        Ok(AddressBalance {
+            owner,
+            amount,
+            asset_id,
+        })
+    }
+
+    pub fn balances<'a>(
+        &'a self,
+        owner: &'a Address,
+        start: Option<AssetId>,
+        direction: IterDirection,
+        base_asset_id: &'a AssetId,
+    ) -> impl Stream<Item = StorageResult<AddressBalance>> + 'a {
+        let balances = match self.require_balances_indexation() {
+            Ok(()) => self
+                .off_chain
+                .balances(owner, start, base_asset_id, direction),
+            Err(err) => core::iter::once(Err(err)).into_boxed(),
+        };
+
+        stream::iter(balances)
+            .map(move |result| {
+                result.map(|(asset_id, amount)| AddressBalance {
+                    owner: *owner,
+                    asset_id,
+                    amount,
+                })
+            })
+            .yield_each(self.batch_size)
+    }
+}
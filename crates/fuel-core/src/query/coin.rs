@@ -1,4 +1,8 @@
-use crate::fuel_core_graphql_api::database::ReadView;
+use crate::fuel_core_graphql_api::database::{
+    ReadView,
+    ReadViewAt,
+};
+use fuel_core_services::yield_stream::StreamYieldExt;
 use fuel_core_storage::{
     Error as StorageError,
     Result as StorageResult,
@@ -64,3 +68,30 @@ impl ReadView {
             .try_flatten()
     }
 }
+
+impl ReadViewAt {
+    pub fn coin(&self, utxo_id: UtxoId) -> StorageResult<Coin> {
+        let coin = self
+            .on_chain
+            .as_ref()
+            .storage::<Coins>()
+            .get(&utxo_id)?
+            .ok_or(not_found!(Coins))?
+            .into_owned();
+
+        Ok(coin.uncompress(utxo_id))
+    }
+
+    pub fn owned_coins(
+        &self,
+        owner: &Address,
+        start_coin: Option<UtxoId>,
+        direction: IterDirection,
+    ) -> impl Stream<Item = StorageResult<Coin>> + '_ + use<'_> {
+        futures::stream::iter(
+            self.off_chain.owned_coins_ids(owner, start_coin, direction),
+        )
+        .map(|result| result.and_then(|utxo_id| self.coin(utxo_id)))
+        .yield_each(self.batch_size)
+    }
+}
@@ -13,7 +13,10 @@ use fuel_core_types::{
         TxId,
         TxPointer,
     },
-    fuel_types::Address,
+    fuel_types::{
+        Address,
+        ContractId,
+    },
     services::transaction_status::TransactionExecutionStatus,
 };
 use futures::{
@@ -43,8 +46,30 @@ impl ReadView {
         start: Option<TxPointer>,
         direction: IterDirection,
     ) -> impl Stream<Item = StorageResult<(TxPointer, Transaction)>> + '_ {
-        self.owned_transactions_ids(owner, start, direction)
-            .chunks(self.batch_size)
+        self.transactions_by_pointers(
+            self.owned_transactions_ids(owner, start, direction),
+        )
+    }
+
+    pub fn contract_transactions(
+        &self,
+        contract_id: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> impl Stream<Item = StorageResult<(TxPointer, Transaction)>> + '_ {
+        self.transactions_by_pointers(self.contract_transactions_ids(
+            contract_id,
+            start,
+            direction,
+        ))
+    }
+
+    /// Fetches transactions in batches for the stream of indexed transaction ids.
+    fn transactions_by_pointers<'a>(
+        &'a self,
+        ids: impl Stream<Item = StorageResult<(TxPointer, TxId)>> + 'a,
+    ) -> impl Stream<Item = StorageResult<(TxPointer, Transaction)>> + 'a {
+        ids.chunks(self.batch_size)
             .map(|chunk| {
                 use itertools::Itertools;
 
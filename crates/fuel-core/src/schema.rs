@@ -36,12 +36,15 @@ pub mod blob;
 pub mod block;
 pub mod chain;
 pub mod coins;
+pub mod consensus;
 pub mod contract;
 pub mod da_compressed;
 pub mod dap;
 pub mod health;
+pub mod logs;
 pub mod message;
 pub mod node_info;
+pub mod peers;
 pub mod upgrades;
 
 pub mod gas_price;
@@ -66,22 +69,32 @@ pub struct Query(
     contract::ContractQuery,
     contract::ContractBalanceQuery,
     node_info::NodeQuery,
+    peers::PeerAdminQuery,
+    consensus::ConsensusStatusQuery,
     gas_price::LatestGasPriceQuery,
     gas_price::EstimateGasPriceQuery,
+    gas_price::FeeHistoryQuery,
     message::MessageQuery,
     relayed_tx::RelayedTransactionQuery,
     upgrades::UpgradeQuery,
     storage::StorageQuery,
+    logs::LogsQuery,
 );
 
 #[derive(MergedObject, Default)]
-pub struct Mutation(dap::DapMutation, tx::TxMutation, block::BlockMutation);
+pub struct Mutation(
+    dap::DapMutation,
+    tx::TxMutation,
+    block::BlockMutation,
+    peers::PeerAdminMutation,
+);
 
 #[derive(MergedSubscription, Default)]
 pub struct Subscription(
     tx::TxStatusSubscription,
     storage::StorageSubscription,
     block::BlockSubscription,
+    logs::LogsSubscription,
 );
 
 pub type CoreSchema = Schema<Query, Mutation, Subscription>;
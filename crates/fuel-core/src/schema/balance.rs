@@ -1,14 +1,19 @@
 use crate::{
     database::database_description::IndexationKind,
     fuel_core_graphql_api::{
-        api_service::ChainInfoProvider,
+        api_service::{
+            ChainInfoProvider,
+            ReadDatabase,
+        },
         query_costs,
+        require_historical_execution,
     },
     schema::{
         ReadViewProvider,
         scalars::{
             Address,
             AssetId,
+            U32,
             U128,
         },
     },
@@ -24,7 +29,10 @@ use async_graphql::{
     },
 };
 use fuel_core_types::services::graphql_api;
-use futures::StreamExt;
+use futures::{
+    StreamExt,
+    future::Either,
+};
 
 use super::scalars::U64;
 
@@ -67,12 +75,23 @@ impl BalanceQuery {
         ctx: &Context<'_>,
         #[graphql(desc = "address of the owner")] owner: Address,
         #[graphql(desc = "asset_id of the coin")] asset_id: AssetId,
+        #[graphql(desc = "the height of the block at which to query the balance")]
+        block_height: Option<U32>,
     ) -> async_graphql::Result<Balance> {
-        let query = ctx.read_view()?;
         let base_asset_id = *ctx
             .data_unchecked::<ChainInfoProvider>()
             .current_consensus_params()
             .base_asset_id();
+
+        if let Some(block_height) = block_height {
+            require_historical_execution(ctx)?;
+            let read_database: &ReadDatabase = ctx.data_unchecked();
+            let view_at = read_database.view_at(block_height.0.into())?;
+            let balance = view_at.balance(owner.0, asset_id.0, base_asset_id)?.into();
+            return Ok(balance)
+        }
+
+        let query = ctx.read_view()?;
         let balance = query
             .balance(owner.0, asset_id.0, base_asset_id)
             .await?
@@ -103,6 +122,7 @@ impl BalanceQuery {
         (child_complexity as f32 * first.unwrap_or_default() as f32 * 0.66) as usize + \
         (child_complexity as f32 * last.unwrap_or_default() as f32 * 0.66) as usize
     } else { query_costs().balance_query }")]
+    #[allow(clippy::too_many_arguments)]
     async fn balances(
         &self,
         ctx: &Context<'_>,
@@ -111,9 +131,17 @@ impl BalanceQuery {
         after: Option<String>,
         last: Option<i32>,
         before: Option<String>,
+        block_height: Option<U32>,
     ) -> async_graphql::Result<Connection<AssetId, Balance, EmptyFields, EmptyFields>>
     {
         let query = ctx.read_view()?;
+        let view_at = if let Some(block_height) = block_height {
+            require_historical_execution(ctx)?;
+            let read_database: &ReadDatabase = ctx.data_unchecked();
+            Some(read_database.view_at(block_height.0.into())?)
+        } else {
+            None
+        };
         if !query.indexation_flags.contains(&IndexationKind::Balances)
             && (before.is_some() || after.is_some())
         {
@@ -128,11 +156,24 @@ impl BalanceQuery {
             .base_asset_id();
         let owner = filter.owner.into();
         crate::schema::query_pagination(after, before, first, last, |start, direction| {
-            Ok(query
-                .balances(&owner, (*start).map(Into::into), direction, &base_asset_id)
-                .map(|result| {
-                    result.map(|balance| (balance.asset_id.into(), balance.into()))
-                }))
+            let start = (*start).map(Into::into);
+            let balances = match &view_at {
+                Some(view_at) => Either::Left(view_at.balances(
+                    &owner,
+                    start,
+                    direction,
+                    &base_asset_id,
+                )),
+                None => Either::Right(query.balances(
+                    &owner,
+                    start,
+                    direction,
+                    &base_asset_id,
+                )),
+            };
+            Ok(balances.map(|result| {
+                result.map(|balance| (balance.asset_id.into(), balance.into()))
+            }))
         })
         .await
     }
@@ -9,6 +9,10 @@ use crate::{
         Config as GraphQLConfig,
         IntoApiResult,
         api_service::ConsensusModule,
+        authorization::{
+            Role,
+            has_role,
+        },
         database::ReadView,
         query_costs,
         require_expensive_subscriptions,
@@ -70,6 +74,7 @@ pub struct Header(pub(crate) BlockHeader);
 pub enum Consensus {
     Genesis(Genesis),
     PoA(PoAConsensus),
+    Bft(BftConsensus),
 }
 
 type CoreGenesis = fuel_core_types::blockchain::consensus::Genesis;
@@ -94,6 +99,11 @@ pub struct PoAConsensus {
     signature: Signature,
 }
 
+pub struct BftConsensus {
+    round: u32,
+    signatures: Vec<Signature>,
+}
+
 #[derive(Clone, Copy, Debug, Enum, Eq, PartialEq)]
 pub enum BlockVersion {
     V1,
@@ -265,6 +275,19 @@ impl PoAConsensus {
     }
 }
 
+#[Object]
+impl BftConsensus {
+    /// Gets the round of the height in which the block was committed.
+    async fn round(&self) -> U32 {
+        self.round.into()
+    }
+
+    /// Gets the precommit signatures of the validators that committed the block.
+    async fn signatures(&self) -> Vec<Signature> {
+        self.signatures.clone()
+    }
+}
+
 #[derive(Default)]
 pub struct BlockQuery;
 
@@ -396,7 +419,14 @@ impl BlockMutation {
     ) -> async_graphql::Result<U32> {
         let config = ctx.data_unchecked::<GraphQLConfig>().clone();
 
-        if !config.debug {
+        if !config.debug && !has_role(ctx, Role::BlockProduction) {
+            if config.config.api_keys_path.is_some() {
+                return Err(anyhow!(
+                    "The API key must have the `{}` role to use this endpoint",
+                    Role::BlockProduction.as_str()
+                )
+                .into())
+            }
             return Err(anyhow!("`debug` must be enabled to use this endpoint").into())
         }
 
@@ -490,6 +520,10 @@ impl TryFrom<CoreConsensus> for Consensus {
             CoreConsensus::PoA(poa) => Ok(Consensus::PoA(PoAConsensus {
                 signature: poa.signature.into(),
             })),
+            CoreConsensus::Bft(bft) => Ok(Consensus::Bft(BftConsensus {
+                round: bft.round,
+                signatures: bft.signatures.into_iter().map(Into::into).collect(),
+            })),
             _ => Err(format!("Unknown consensus type: {:?}", consensus)),
         }
     }
@@ -1079,6 +1079,7 @@ mod tests {
     use crate::{
         graphql_api::{
             Config,
+            RateLimitConfig,
             ServiceConfig,
             ports::MockChainStateProvider,
         },
@@ -1137,6 +1138,7 @@ mod tests {
                 max_queries_resolver_recursive_depth: 32,
                 max_queries_directives: 16,
                 max_concurrent_queries: 16,
+                max_websocket_connections: 16,
                 request_body_bytes_limit: 1024 * 1024,
                 required_fuel_block_height_tolerance: 0,
                 required_fuel_block_height_timeout: Duration::from_secs(1),
@@ -1145,16 +1147,23 @@ mod tests {
                 assemble_tx_dry_run_limit: 1,
                 assemble_tx_estimate_predicates_limit: 1,
                 costs: Default::default(),
+                rate_limit: RateLimitConfig::unlimited(),
+                api_keys_path: None,
             },
             utxo_validation: false,
             debug: false,
             allow_syscall: false,
             historical_execution: false,
             expensive_subscriptions: false,
+            logs_indexation: false,
+            fee_history_indexation: false,
+            peer_admin_api: false,
+            block_production: false,
             max_tx: 1,
             max_gas: 1,
             max_size: 1,
             max_txpool_dependency_chain_length: 1,
+            max_bundle_size: 1,
             chain_name: "test".into(),
         };
 
@@ -14,10 +14,14 @@ use crate::{
     fuel_core_graphql_api::{
         IntoApiResult,
         query_costs,
+        require_historical_execution,
         storage::coins::CoinsToSpendIndexKey,
     },
     graphql_api::{
-        api_service::ChainInfoProvider,
+        api_service::{
+            ChainInfoProvider,
+            ReadDatabase,
+        },
         database::ReadView,
     },
     query::asset_query::{
@@ -60,6 +64,7 @@ use fuel_core_types::{
         ConsensusParameters,
     },
 };
+use futures::future::Either;
 use itertools::Itertools;
 use tokio_stream::StreamExt;
 
@@ -236,6 +241,7 @@ impl CoinQuery {
         + (query_costs().storage_read + first.unwrap_or_default() as usize) * child_complexity \
         + (query_costs().storage_read + last.unwrap_or_default() as usize) * child_complexity\
     }")]
+    #[allow(clippy::too_many_arguments)]
     async fn coins(
         &self,
         ctx: &Context<'_>,
@@ -244,12 +250,26 @@ impl CoinQuery {
         after: Option<String>,
         last: Option<i32>,
         before: Option<String>,
+        block_height: Option<U32>,
     ) -> async_graphql::Result<Connection<UtxoId, Coin, EmptyFields, EmptyFields>> {
         let query = ctx.read_view()?;
+        let view_at = if let Some(block_height) = block_height {
+            require_historical_execution(ctx)?;
+            let read_database: &ReadDatabase = ctx.data_unchecked();
+            Some(read_database.view_at(block_height.0.into())?)
+        } else {
+            None
+        };
         let owner: fuel_tx::Address = filter.owner.into();
         crate::schema::query_pagination(after, before, first, last, |start, direction| {
-            let coins = query
-                .owned_coins(&owner, (*start).map(Into::into), direction)
+            let start = (*start).map(Into::into);
+            let coins = match &view_at {
+                Some(view_at) => {
+                    Either::Left(view_at.owned_coins(&owner, start, direction))
+                }
+                None => Either::Right(query.owned_coins(&owner, start, direction)),
+            };
+            let coins = coins
                 .filter_map(|result| {
                     if let (Ok(coin), Some(filter_asset_id)) = (&result, &filter.asset_id)
                         && coin.asset_id != filter_asset_id.0
@@ -0,0 +1,282 @@
+use super::scalars::{
+    U32,
+    U64,
+};
+use crate::fuel_core_graphql_api::{
+    Config as GraphQLConfig,
+    api_service::ConsensusModule,
+    authorization::{
+        Role,
+        has_role,
+    },
+    query_costs,
+};
+use async_graphql::{
+    Context,
+    Enum,
+    Object,
+};
+use fuel_core_types::services::leader_lease;
+use std::time::{
+    SystemTime,
+    UNIX_EPOCH,
+};
+
+fn unix_time_ms(time: SystemTime) -> U64 {
+    let time = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    U64(time.try_into().unwrap_or(u64::MAX))
+}
+
+#[derive(Default)]
+pub struct ConsensusStatusQuery;
+
+#[Object]
+impl ConsensusStatusQuery {
+    /// The state of the leader lease of the block producer.
+    /// Returns `null` if neither the Redis nor the Raft leader lock is enabled.
+    #[graphql(complexity = "query_costs().consensus_status + child_complexity")]
+    async fn consensus_status(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<ConsensusStatus>> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+
+        if !config.debug && !has_role(ctx, Role::BlockProduction) {
+            if config.config.api_keys_path.is_some() {
+                return Err(async_graphql::Error::new(format!(
+                    "The API key must have the `{}` role to use this endpoint",
+                    Role::BlockProduction.as_str()
+                )))
+            }
+            return Err(async_graphql::Error::new(
+                "`debug` must be enabled to use this endpoint",
+            ))
+        }
+
+        let consensus_module = ctx.data_unchecked::<ConsensusModule>();
+        let status = consensus_module.leader_lease_status().await?;
+        Ok(status.map(ConsensusStatus))
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum LeaderLeaseBackend {
+    Redis,
+    Raft,
+}
+
+impl From<leader_lease::LeaderLeaseBackend> for LeaderLeaseBackend {
+    fn from(backend: leader_lease::LeaderLeaseBackend) -> Self {
+        match backend {
+            leader_lease::LeaderLeaseBackend::Redis => Self::Redis,
+            leader_lease::LeaderLeaseBackend::Raft => Self::Raft,
+        }
+    }
+}
+
+pub struct ConsensusStatus(leader_lease::LeaderLeaseStatus);
+
+#[Object]
+impl ConsensusStatus {
+    /// The backend of the lease
+    async fn backend(&self) -> LeaderLeaseBackend {
+        self.0.backend.into()
+    }
+
+    /// The key of the lease in Redis. `null` for the Raft lease
+    async fn lease_key(&self) -> Option<&str> {
+        self.0.lease_key.as_deref()
+    }
+
+    /// The token that identifies this node as the lease holder.
+    /// The id of the replica for the Raft lease
+    async fn lease_owner_token(&self) -> &str {
+        &self.0.lease_owner_token
+    }
+
+    /// Is this node the leader after the last lease round
+    async fn is_leader(&self) -> bool {
+        self.0.is_leader
+    }
+
+    /// The epoch token of this node used to fence the writes to the block stream
+    async fn epoch_token(&self) -> Option<U64> {
+        self.0.epoch_token.map(Into::into)
+    }
+
+    /// The token of the lease holder agreed by the quorum of nodes
+    async fn holder(&self) -> Option<&str> {
+        self.0.holder.as_deref()
+    }
+
+    /// The number of nodes required for the quorum
+    async fn quorum(&self) -> U32 {
+        self.0.quorum.into()
+    }
+
+    /// The state of each Redis node, or of each peer of the Raft replica
+    async fn nodes(&self) -> Vec<LeaseNodeStatus> {
+        self.0.nodes.iter().cloned().map(LeaseNodeStatus).collect()
+    }
+
+    /// The last round of lease requests sent to all Redis nodes.
+    /// `null` for the Raft lease
+    async fn last_quorum_round(&self) -> Option<QuorumRound> {
+        self.0.last_quorum_round.clone().map(QuorumRound)
+    }
+
+    /// The height of the latest block committed by this node
+    async fn local_height(&self) -> Option<U32> {
+        self.0.local_height.map(|height| (*height).into())
+    }
+
+    /// The height of the latest block in the replicated block stream
+    async fn stream_height(&self) -> Option<U32> {
+        self.0.stream_height.map(|height| (*height).into())
+    }
+
+    /// The number of blocks in the block stream not yet committed by this node
+    async fn stream_lag(&self) -> Option<U32> {
+        self.0.stream_lag.map(Into::into)
+    }
+
+    /// The recent lease transitions of this node, from the oldest to the newest
+    async fn transitions(&self) -> Vec<LeaseTransition> {
+        self.0
+            .transitions
+            .iter()
+            .cloned()
+            .map(LeaseTransition)
+            .collect()
+    }
+}
+
+pub struct LeaseNodeStatus(leader_lease::LeaseNodeStatus);
+
+#[Object]
+impl LeaseNodeStatus {
+    /// The address of the node
+    async fn address(&self) -> &str {
+        &self.0.address
+    }
+
+    /// Did the node answer the status request. For the Raft peer,
+    /// did it answer the latest request of this replica
+    async fn reachable(&self) -> bool {
+        self.0.reachable
+    }
+
+    /// The token of the lease holder seen by the node
+    async fn holder(&self) -> Option<&str> {
+        self.0.holder.as_deref()
+    }
+
+    /// The latest epoch token issued by the node
+    async fn epoch(&self) -> Option<U64> {
+        self.0.epoch.map(Into::into)
+    }
+
+    /// The height of the latest block in the block stream of the node
+    async fn stream_height(&self) -> Option<U32> {
+        self.0.stream_height.map(|height| (*height).into())
+    }
+
+    /// The reason why the node is unreachable
+    async fn error(&self) -> Option<&str> {
+        self.0.error.as_deref()
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum QuorumRoundKind {
+    OwnershipCheck,
+    Promotion,
+    Release,
+}
+
+impl From<leader_lease::QuorumRoundKind> for QuorumRoundKind {
+    fn from(kind: leader_lease::QuorumRoundKind) -> Self {
+        match kind {
+            leader_lease::QuorumRoundKind::OwnershipCheck => Self::OwnershipCheck,
+            leader_lease::QuorumRoundKind::Promotion => Self::Promotion,
+            leader_lease::QuorumRoundKind::Release => Self::Release,
+        }
+    }
+}
+
+pub struct QuorumRound(leader_lease::QuorumRound);
+
+#[Object]
+impl QuorumRound {
+    /// The kind of the lease requests
+    async fn kind(&self) -> QuorumRoundKind {
+        self.0.kind.into()
+    }
+
+    /// The number of Redis nodes in the round
+    async fn nodes(&self) -> U32 {
+        self.0.nodes.into()
+    }
+
+    /// The number of Redis nodes that answered the request
+    async fn responded(&self) -> U32 {
+        self.0.responded.into()
+    }
+
+    /// The number of Redis nodes that granted the request
+    async fn granted(&self) -> U32 {
+        self.0.granted.into()
+    }
+
+    /// Did the quorum of Redis nodes grant the request
+    async fn quorum_reached(&self) -> bool {
+        self.0.quorum_reached
+    }
+
+    /// The time when the round finished in unix epoch time ms
+    async fn time_ms(&self) -> U64 {
+        unix_time_ms(self.0.time)
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum LeaseTransitionKind {
+    Acquired,
+    EpochChanged,
+    Lost,
+    Released,
+}
+
+impl From<leader_lease::LeaseTransitionKind> for LeaseTransitionKind {
+    fn from(kind: leader_lease::LeaseTransitionKind) -> Self {
+        match kind {
+            leader_lease::LeaseTransitionKind::Acquired => Self::Acquired,
+            leader_lease::LeaseTransitionKind::EpochChanged => Self::EpochChanged,
+            leader_lease::LeaseTransitionKind::Lost => Self::Lost,
+            leader_lease::LeaseTransitionKind::Released => Self::Released,
+        }
+    }
+}
+
+pub struct LeaseTransition(leader_lease::LeaseTransition);
+
+#[Object]
+impl LeaseTransition {
+    /// The kind of the change of the lease held by this node
+    async fn kind(&self) -> LeaseTransitionKind {
+        self.0.kind.into()
+    }
+
+    /// The epoch token of this node after the change
+    async fn epoch(&self) -> Option<U64> {
+        self.0.epoch.map(Into::into)
+    }
+
+    /// The time of the change in unix epoch time ms
+    async fn time_ms(&self) -> U64 {
+        unix_time_ms(self.0.time)
+    }
+}
@@ -4,7 +4,13 @@ use crate::{
         OnChainIterableKeyValueView,
         database_description::on_chain::OnChain,
     },
-    fuel_core_graphql_api::api_service::ChainInfoProvider,
+    fuel_core_graphql_api::{
+        api_service::ChainInfoProvider,
+        authorization::{
+            Role,
+            has_role,
+        },
+    },
     schema::scalars::{
         U32,
         U64,
@@ -80,7 +86,7 @@ pub struct Config {
 pub fn require_debug(ctx: &Context<'_>) -> async_graphql::Result<()> {
     let config = ctx.data_unchecked::<Config>();
 
-    if config.debug_enabled {
+    if config.debug_enabled || has_role(ctx, Role::Debugger) {
         Ok(())
     } else {
         Err(async_graphql::Error::new("The 'debug' feature is disabled"))
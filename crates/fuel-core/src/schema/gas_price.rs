@@ -4,8 +4,12 @@ use super::scalars::{
 };
 use crate::{
     graphql_api::{
-        api_service::GasPriceProvider,
+        api_service::{
+            ChainInfoProvider,
+            GasPriceProvider,
+        },
         query_costs,
+        require_fee_history_indexation,
     },
     schema::ReadViewProvider,
 };
@@ -19,8 +23,14 @@ use fuel_core_types::{
         Transaction,
         field::MintGasPrice,
     },
+    fuel_types::BlockHeight,
 };
 
+/// The maximum number of blocks returned by the `feeHistory` query.
+const MAX_FEE_HISTORY_BLOCK_COUNT: u32 = 1024;
+/// The maximum number of tip percentiles requested by the `feeHistory` query.
+const MAX_REWARD_PERCENTILES: usize = 100;
+
 pub struct LatestGasPrice {
     pub gas_price: U64,
     pub block_height: U32,
@@ -99,6 +109,155 @@ impl EstimateGasPriceQuery {
     }
 }
 
+pub struct BlockFees {
+    height: U32,
+    gas_price: U64,
+    gas_used_ratio: f64,
+    reward: Vec<U64>,
+}
+
+#[Object]
+impl BlockFees {
+    async fn height(&self) -> U32 {
+        self.height
+    }
+
+    async fn gas_price(&self) -> U64 {
+        self.gas_price
+    }
+
+    /// The ratio of the gas used by the block to the block gas limit.
+    async fn gas_used_ratio(&self) -> f64 {
+        self.gas_used_ratio
+    }
+
+    /// The tips paid at each of the requested percentiles, weighted by the gas used.
+    async fn reward(&self) -> &[U64] {
+        &self.reward
+    }
+}
+
+pub struct FeeHistory {
+    oldest_block: U32,
+    blocks: Vec<BlockFees>,
+}
+
+#[Object]
+impl FeeHistory {
+    async fn oldest_block(&self) -> U32 {
+        self.oldest_block
+    }
+
+    /// The fees of the blocks from the oldest block to the newest one.
+    async fn blocks(&self) -> &[BlockFees] {
+        &self.blocks
+    }
+}
+
+#[derive(Default)]
+pub struct FeeHistoryQuery {}
+
+#[Object]
+impl FeeHistoryQuery {
+    #[graphql(complexity = "{\
+        (query_costs().block_header + query_costs().storage_read + child_complexity) \
+        * block_count.0 as usize \
+    }")]
+    async fn fee_history(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The number of blocks in the history, up to 1024")]
+        block_count: U32,
+        #[graphql(desc = "The newest block of the history, the latest block by default")]
+        newest_block: Option<U32>,
+        #[graphql(
+            desc = "Ascending percentiles in the range [0; 100] of tips to return for each block"
+        )]
+        reward_percentiles: Option<Vec<f64>>,
+    ) -> async_graphql::Result<FeeHistory> {
+        require_fee_history_indexation(ctx)?;
+
+        let block_count = block_count.0;
+        if block_count == 0 || block_count > MAX_FEE_HISTORY_BLOCK_COUNT {
+            return Err(async_graphql::Error::new(format!(
+                "`blockCount` must be in the range [1; {MAX_FEE_HISTORY_BLOCK_COUNT}]"
+            )));
+        }
+
+        let percentiles = reward_percentiles.unwrap_or_default();
+        if percentiles.len() > MAX_REWARD_PERCENTILES {
+            return Err(async_graphql::Error::new(format!(
+                "At most {MAX_REWARD_PERCENTILES} `rewardPercentiles` can be requested"
+            )));
+        }
+        let valid_percentiles = percentiles
+            .iter()
+            .all(|percentile| (0.0..=100.0).contains(percentile))
+            && percentiles.is_sorted();
+        if !valid_percentiles {
+            return Err(async_graphql::Error::new(
+                "`rewardPercentiles` must be in the range [0; 100] and sorted in ascending order",
+            ));
+        }
+
+        let query = ctx.read_view()?;
+        let latest_height = query.latest_height()?;
+        let newest_block = newest_block.map(|height| BlockHeight::from(height.0));
+        let newest_block = match newest_block {
+            Some(height) if height > latest_height => {
+                return Err(async_graphql::Error::new(format!(
+                    "`newestBlock` {height} is higher than the latest block {latest_height}"
+                )));
+            }
+            Some(height) => height,
+            None => latest_height,
+        };
+        // The genesis block doesn't have transactions, so it is not indexed.
+        let first_block = query.genesis_height.succ().unwrap_or(query.genesis_height);
+        let oldest_block = BlockHeight::from(
+            (*newest_block).saturating_sub(block_count.saturating_sub(1)),
+        )
+        .max(first_block);
+
+        let chain_info = ctx.data_unchecked::<ChainInfoProvider>();
+        let mut blocks = Vec::new();
+        for height in *oldest_block..=*newest_block {
+            let height = BlockHeight::from(height);
+            let statistics = query.fee_statistics(&height)?.ok_or_else(|| {
+                async_graphql::Error::new(format!(
+                    "The fee statistics of the block {height} are not indexed"
+                ))
+            })?;
+            let block = query.block(&height)?;
+            let params = chain_info.consensus_params_at_version(
+                &block.header().consensus_parameters_version(),
+            )?;
+            let block_gas_limit = params.block_gas_limit();
+            let gas_used_ratio = if block_gas_limit == 0 {
+                0.0
+            } else {
+                statistics.gas_used as f64 / block_gas_limit as f64
+            };
+
+            blocks.push(BlockFees {
+                height: height.into(),
+                gas_price: statistics.gas_price.into(),
+                gas_used_ratio,
+                reward: statistics
+                    .tip_percentiles(&percentiles)
+                    .into_iter()
+                    .map(Into::into)
+                    .collect(),
+            });
+        }
+
+        Ok(FeeHistory {
+            oldest_block: oldest_block.into(),
+            blocks,
+        })
+    }
+}
+
 pub trait EstimateGasPriceExt {
     fn estimate_gas_price(
         &self,
@@ -0,0 +1,273 @@
+use crate::{
+    fuel_core_graphql_api::{
+        query_costs,
+        storage::logs::{
+            LogDetails,
+            LogKey,
+        },
+        worker_service::SharedState,
+    },
+    graphql_api::require_logs_indexation,
+    schema::{
+        ReadViewProvider,
+        scalars::{
+            ContractId,
+            LogCursor,
+            TransactionId,
+            U16,
+            U32,
+            U64,
+        },
+        tx::receipt::Receipt,
+    },
+};
+use async_graphql::{
+    Context,
+    InputObject,
+    Object,
+    Subscription,
+    connection::{
+        Connection,
+        EmptyFields,
+    },
+};
+use fuel_core_storage::iter::IterDirection;
+use fuel_core_types::fuel_types::{
+    self,
+    BlockHeight,
+};
+use futures::{
+    Stream,
+    StreamExt,
+};
+use tokio_stream::wrappers::BroadcastStream;
+
+/// The filter selecting logs emitted by the contract with the specified log id(`rb` register).
+#[derive(InputObject, Clone, Copy)]
+pub struct LogFilterInput {
+    /// The contract that emitted the log.
+    contract_id: ContractId,
+    /// The log id, the value of the `rb` register of the `LOG`/`LOGD` instruction.
+    rb: U64,
+    /// The first block height to include(inclusive).
+    from_height: Option<U32>,
+    /// The last block height to include(inclusive).
+    to_height: Option<U32>,
+}
+
+impl LogFilterInput {
+    fn start_height(&self) -> BlockHeight {
+        self.from_height.map(|h| h.0).unwrap_or_default().into()
+    }
+
+    fn end_height(&self) -> BlockHeight {
+        self.to_height.map(|h| h.0).unwrap_or(u32::MAX).into()
+    }
+
+    fn validate(&self) -> async_graphql::Result<()> {
+        if self.start_height() > self.end_height() {
+            return Err(anyhow::anyhow!(
+                "`fromHeight` {} is greater than `toHeight` {}",
+                self.start_height(),
+                self.end_height()
+            )
+            .into())
+        }
+        Ok(())
+    }
+
+    fn key(&self, block_height: BlockHeight, tx_idx: u16, receipt_idx: u32) -> LogKey {
+        LogKey::new(
+            self.contract_id.into(),
+            self.rb.0,
+            block_height,
+            tx_idx,
+            receipt_idx,
+        )
+    }
+
+    fn matches(&self, key: &LogKey) -> bool {
+        key.contract_id == fuel_types::ContractId::from(self.contract_id)
+            && key.rb == self.rb.0
+            && key.block_height >= self.start_height()
+            && key.block_height <= self.end_height()
+    }
+}
+
+pub struct Log {
+    key: LogKey,
+    details: LogDetails,
+}
+
+#[Object]
+impl Log {
+    /// The contract that emitted the log.
+    async fn contract_id(&self) -> ContractId {
+        self.key.contract_id.into()
+    }
+
+    /// The log id, the value of the `rb` register.
+    async fn rb(&self) -> U64 {
+        self.key.rb.into()
+    }
+
+    /// The height of the block containing the transaction that emitted the log.
+    async fn block_height(&self) -> U32 {
+        self.key.block_height.into()
+    }
+
+    /// The index of the transaction within the block.
+    async fn tx_index(&self) -> U16 {
+        self.key.tx_idx.into()
+    }
+
+    /// The index of the receipt within the transaction receipts.
+    async fn receipt_index(&self) -> U32 {
+        self.key.receipt_idx.into()
+    }
+
+    async fn transaction_id(&self) -> TransactionId {
+        self.details.tx_id.into()
+    }
+
+    /// The `Log` or `LogData` receipt.
+    async fn receipt(&self) -> Receipt {
+        self.details.receipt.clone().into()
+    }
+}
+
+impl From<(LogKey, LogDetails)> for Log {
+    fn from((key, details): (LogKey, LogDetails)) -> Self {
+        Self { key, details }
+    }
+}
+
+#[derive(Default)]
+pub struct LogsQuery;
+
+#[Object]
+impl LogsQuery {
+    /// Returns `Log` and `LogData` receipts emitted by successful transactions
+    /// matching the `filter`, ordered by their position in the chain.
+    /// Requires the logs indexation to be enabled.
+    #[graphql(complexity = "{\
+        query_costs().storage_iterator\
+        + first.unwrap_or_default() as usize * (child_complexity + query_costs().storage_read) \
+        + last.unwrap_or_default() as usize * (child_complexity + query_costs().storage_read) \
+    }")]
+    async fn logs(
+        &self,
+        ctx: &Context<'_>,
+        filter: LogFilterInput,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<Connection<LogCursor, Log, EmptyFields, EmptyFields>> {
+        require_logs_indexation(ctx)?;
+        filter.validate()?;
+        let query = ctx.read_view()?;
+        let from_height = filter.start_height();
+        let to_height = filter.end_height();
+
+        crate::schema::query_pagination(
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<LogCursor>, direction| {
+                let start = start.map(|cursor| {
+                    filter.key(cursor.block_height, cursor.tx_idx, cursor.receipt_idx)
+                });
+                let start = match direction {
+                    IterDirection::Forward => {
+                        let first = filter.key(from_height, 0, 0);
+                        start.map_or(first.clone(), |start| start.max(first))
+                    }
+                    IterDirection::Reverse => {
+                        let last = filter.key(to_height, u16::MAX, u32::MAX);
+                        start.map_or(last.clone(), |start| start.min(last))
+                    }
+                };
+
+                let logs = query
+                    .logs(
+                        filter.contract_id.into(),
+                        filter.rb.0,
+                        Some(start),
+                        direction,
+                    )
+                    .take_while(move |result| {
+                        let in_range = match result {
+                            Ok((key, _)) => {
+                                key.block_height >= from_height
+                                    && key.block_height <= to_height
+                            }
+                            Err(_) => true,
+                        };
+                        futures::future::ready(in_range)
+                    })
+                    .map(|result| {
+                        result.map(|(key, details)| {
+                            let cursor = LogCursor::new(
+                                key.block_height,
+                                key.tx_idx,
+                                key.receipt_idx,
+                            );
+                            (cursor, (key, details).into())
+                        })
+                    });
+                Ok(logs)
+            },
+        )
+        .await
+    }
+}
+
+#[derive(Default)]
+pub struct LogsSubscription;
+
+#[Subscription]
+impl LogsSubscription {
+    /// Streams `Log` and `LogData` receipts matching the `filter` as blocks are imported.
+    /// The stream ends once the block at `toHeight` is processed.
+    /// Requires the logs indexation to be enabled.
+    async fn logs<'a>(
+        &self,
+        ctx: &Context<'a>,
+        filter: LogFilterInput,
+    ) -> async_graphql::Result<
+        impl Stream<Item = async_graphql::Result<Log>> + 'a + use<'a>,
+    > {
+        require_logs_indexation(ctx)?;
+        filter.validate()?;
+        let worker_state: &SharedState = ctx.data_unchecked();
+        let to_height = filter.end_height();
+
+        let receiver = worker_state.log_subscription.subscribe();
+        let stream = BroadcastStream::new(receiver)
+            .take_while(move |result| {
+                let before_end = match result {
+                    Ok(block_logs) => block_logs.block_height <= to_height,
+                    Err(_) => true,
+                };
+                futures::future::ready(before_end)
+            })
+            .map(move |result| {
+                let logs = match result {
+                    Ok(block_logs) => block_logs
+                        .logs
+                        .iter()
+                        .filter(|(key, _)| filter.matches(key))
+                        .cloned()
+                        .map(|log| Ok(log.into()))
+                        .collect(),
+                    Err(err) => vec![Err(err.into())],
+                };
+                futures::stream::iter(logs)
+            })
+            .flatten();
+
+        Ok(stream)
+    }
+}
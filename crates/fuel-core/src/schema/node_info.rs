@@ -200,4 +200,19 @@ impl IndexationFlags {
     async fn asset_metadata(&self) -> bool {
         self.contains(&IndexationKind::AssetMetadata)
     }
+
+    /// Is contract transactions indexation enabled
+    async fn contract_transactions(&self) -> bool {
+        self.contains(&IndexationKind::ContractTransactions)
+    }
+
+    /// Is logs indexation enabled
+    async fn logs(&self) -> bool {
+        self.contains(&IndexationKind::Logs)
+    }
+
+    /// Is fee history indexation enabled
+    async fn fee_history(&self) -> bool {
+        self.contains(&IndexationKind::FeeHistory)
+    }
 }
@@ -0,0 +1,192 @@
+use super::scalars::{
+    U32,
+    U64,
+};
+use crate::fuel_core_graphql_api::{
+    api_service::P2pService,
+    query_costs,
+    require_peer_admin,
+};
+use async_graphql::{
+    Context,
+    Object,
+};
+use fuel_core_types::services::p2p::PeerId;
+use std::{
+    str::FromStr,
+    time::{
+        Duration,
+        UNIX_EPOCH,
+    },
+};
+
+fn parse_peer_id(peer_id: &str) -> async_graphql::Result<PeerId> {
+    PeerId::from_str(peer_id)
+        .map_err(|e| async_graphql::Error::new(format!("Invalid peer id: {e}")))
+}
+
+#[derive(Default)]
+pub struct PeerAdminQuery;
+
+#[Object]
+impl PeerAdminQuery {
+    /// The reputation and heartbeat data of all connected peers.
+    #[graphql(complexity = "query_costs().get_peers + child_complexity")]
+    async fn peer_reputations(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<PeerReputation>> {
+        require_peer_admin(ctx)?;
+        let p2p = ctx.data_unchecked::<P2pService>();
+        let reputations = p2p.peer_reputations().await?;
+        Ok(reputations.into_iter().map(PeerReputation).collect())
+    }
+
+    /// The peers banned with the `banPeer` mutation.
+    #[graphql(complexity = "query_costs().get_peers + child_complexity")]
+    async fn banned_peers(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<BannedPeer>> {
+        require_peer_admin(ctx)?;
+        let p2p = ctx.data_unchecked::<P2pService>();
+        let banned_peers = p2p.banned_peers().await?;
+        Ok(banned_peers.into_iter().map(BannedPeer).collect())
+    }
+}
+
+#[derive(Default)]
+pub struct PeerAdminMutation;
+
+#[Object]
+impl PeerAdminMutation {
+    /// Adds the reserved peer. The node keeps the connection with the reserved peer
+    /// and reconnects to it after disconnection. Returns the id of the peer.
+    async fn add_reserved_peer(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The multiaddress of the peer that contains the peer id")]
+        address: String,
+    ) -> async_graphql::Result<String> {
+        require_peer_admin(ctx)?;
+        let p2p = ctx.data_unchecked::<P2pService>();
+        let peer_id = p2p.add_reserved_peer(address).await?;
+        Ok(peer_id.to_string())
+    }
+
+    /// Removes the reserved peer. The connection with the peer is kept
+    /// as with a non-reserved peer. Returns `false` if the peer was not reserved.
+    async fn remove_reserved_peer(
+        &self,
+        ctx: &Context<'_>,
+        peer_id: String,
+    ) -> async_graphql::Result<bool> {
+        require_peer_admin(ctx)?;
+        let p2p = ctx.data_unchecked::<P2pService>();
+        let removed = p2p.remove_reserved_peer(parse_peer_id(&peer_id)?).await?;
+        Ok(removed)
+    }
+
+    /// Bans the peer for the given duration and closes all connections with it.
+    async fn ban_peer(
+        &self,
+        ctx: &Context<'_>,
+        peer_id: String,
+        #[graphql(desc = "The duration of the ban in seconds")] duration_seconds: U64,
+    ) -> async_graphql::Result<bool> {
+        require_peer_admin(ctx)?;
+        let p2p = ctx.data_unchecked::<P2pService>();
+        let duration = Duration::from_secs(duration_seconds.into());
+        p2p.ban_peer(parse_peer_id(&peer_id)?, duration).await?;
+        Ok(true)
+    }
+
+    /// Unbans the peer banned by the `banPeer` mutation or because of its low reputation.
+    async fn unban_peer(
+        &self,
+        ctx: &Context<'_>,
+        peer_id: String,
+    ) -> async_graphql::Result<bool> {
+        require_peer_admin(ctx)?;
+        let p2p = ctx.data_unchecked::<P2pService>();
+        p2p.unban_peer(parse_peer_id(&peer_id)?).await?;
+        Ok(true)
+    }
+
+    /// Closes all connections with the peer. Returns `false` if the peer was not connected.
+    async fn disconnect_peer(
+        &self,
+        ctx: &Context<'_>,
+        peer_id: String,
+    ) -> async_graphql::Result<bool> {
+        require_peer_admin(ctx)?;
+        let p2p = ctx.data_unchecked::<P2pService>();
+        let disconnected = p2p.disconnect_peer(parse_peer_id(&peer_id)?).await?;
+        Ok(disconnected)
+    }
+}
+
+struct PeerReputation(fuel_core_types::services::p2p::PeerReputation);
+
+#[Object]
+impl PeerReputation {
+    /// The libp2p peer id
+    async fn id(&self) -> String {
+        self.0.id.to_string()
+    }
+
+    /// Is the peer one of the reserved peers
+    async fn reserved(&self) -> bool {
+        self.0.reserved
+    }
+
+    /// The internal fuel p2p reputation of this peer
+    async fn app_score(&self) -> f64 {
+        self.0.app_score
+    }
+
+    /// The gossipsub score of this peer, if it is known
+    async fn gossipsub_score(&self) -> Option<f64> {
+        self.0.gossipsub_score
+    }
+
+    /// The last reported height of the peer
+    async fn block_height(&self) -> Option<U32> {
+        self.0
+            .heartbeat_data
+            .block_height
+            .map(|height| (*height).into())
+    }
+
+    /// The last heartbeat from this peer in unix epoch time ms
+    async fn last_heartbeat_ms(&self) -> U64 {
+        let time = self.0.heartbeat_data.last_heartbeat;
+        let time = time
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        U64(time.try_into().unwrap_or_default())
+    }
+
+    /// The average time between the recent heartbeats of this peer in ms
+    async fn average_heartbeat_interval_ms(&self) -> U64 {
+        let interval = self.0.average_heartbeat_interval.as_millis();
+        U64(interval.try_into().unwrap_or(u64::MAX))
+    }
+}
+
+struct BannedPeer(fuel_core_types::services::p2p::BannedPeer);
+
+#[Object]
+impl BannedPeer {
+    /// The libp2p peer id
+    async fn id(&self) -> String {
+        self.0.id.to_string()
+    }
+
+    /// The time left until the ban expires in ms
+    async fn remaining_ban_duration_ms(&self) -> U64 {
+        let remaining = self.0.remaining_ban_duration.as_millis();
+        U64(remaining.try_into().unwrap_or(u64::MAX))
+    }
+}
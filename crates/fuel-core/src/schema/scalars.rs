@@ -6,6 +6,7 @@ use async_graphql::{
     Value,
     connection::CursorType,
 };
+use fuel_core_txpool::inspection::InspectionCursor;
 use fuel_core_types::{
     fuel_types::{
         self,
@@ -21,6 +22,10 @@ use std::{
         Formatter,
     },
     str::FromStr,
+    time::{
+        Duration,
+        SystemTime,
+    },
 };
 pub use tx_pointer::TxPointer;
 pub use utxo_id::UtxoId;
@@ -160,6 +165,108 @@ impl CursorType for SortedTxCursor {
     }
 }
 
+/// The position of the log in the chain: `block_height#tx_idx#receipt_idx`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LogCursor {
+    pub block_height: BlockHeight,
+    pub tx_idx: u16,
+    pub receipt_idx: u32,
+}
+
+impl LogCursor {
+    pub fn new(block_height: BlockHeight, tx_idx: u16, receipt_idx: u32) -> Self {
+        Self {
+            block_height,
+            tx_idx,
+            receipt_idx,
+        }
+    }
+}
+
+impl CursorType for LogCursor {
+    type Error = String;
+
+    fn decode_cursor(s: &str) -> Result<Self, Self::Error> {
+        let mut parts = s.split('#');
+        let (Some(block_height), Some(tx_idx), Some(receipt_idx), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err("Incorrect format provided".to_string())
+        };
+
+        Ok(Self::new(
+            BlockHeight::from_str(block_height)
+                .map_err(|_| "Failed to decode block_height")?,
+            tx_idx.parse().map_err(|_| "Failed to decode tx_idx")?,
+            receipt_idx
+                .parse()
+                .map_err(|_| "Failed to decode receipt_idx")?,
+        ))
+    }
+
+    fn encode_cursor(&self) -> String {
+        format!("{}#{}#{}", self.block_height, self.tx_idx, self.receipt_idx)
+    }
+}
+
+/// The position of the transaction in the transaction pool:
+/// `tip#max_gas#insertion_secs#insertion_nanos#tx_id`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TxPoolCursor(pub InspectionCursor);
+
+impl CursorType for TxPoolCursor {
+    type Error = String;
+
+    fn decode_cursor(s: &str) -> Result<Self, Self::Error> {
+        let mut parts = s.split('#');
+        let (Some(tip), Some(max_gas), Some(secs), Some(nanos), Some(tx_id), None) = (
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+        ) else {
+            return Err("Incorrect format provided".to_string())
+        };
+        let secs = secs
+            .parse()
+            .map_err(|_| "Failed to decode insertion_secs")?;
+        let nanos: u32 = nanos
+            .parse()
+            .map_err(|_| "Failed to decode insertion_nanos")?;
+        if nanos >= 1_000_000_000 {
+            return Err("Failed to decode insertion_nanos".to_string())
+        }
+        let creation_instant = SystemTime::UNIX_EPOCH
+            .checked_add(Duration::new(secs, nanos))
+            .ok_or("Failed to decode the insertion time")?;
+
+        Ok(Self(InspectionCursor {
+            tip: tip.parse().map_err(|_| "Failed to decode tip")?,
+            max_gas: max_gas.parse().map_err(|_| "Failed to decode max_gas")?,
+            creation_instant,
+            tx_id: Bytes32::decode_cursor(tx_id)?.into(),
+        }))
+    }
+
+    fn encode_cursor(&self) -> String {
+        let since_epoch = self
+            .0
+            .creation_instant
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default();
+        format!(
+            "{}#{}#{}#{}#{}",
+            self.0.tip,
+            self.0.max_gas,
+            since_epoch.as_secs(),
+            since_epoch.subsec_nanos(),
+            Bytes32::from(self.0.tx_id)
+        )
+    }
+}
+
 #[derive(Clone, Debug, derive_more::Into, derive_more::From, PartialEq, Eq)]
 pub struct HexString(pub(crate) Vec<u8>);
 
@@ -361,4 +468,30 @@ mod tests {
         let res = HexString::from_str(hex_data);
         assert!(res.is_err());
     }
+
+    #[test]
+    fn log_cursor_roundtrip() {
+        let cursor = LogCursor::new(10.into(), 2, 3);
+        let encoded = cursor.encode_cursor();
+        assert_eq!(encoded, "0000000a#2#3");
+        assert_eq!(LogCursor::decode_cursor(&encoded), Ok(cursor));
+    }
+
+    #[test]
+    fn tx_pool_cursor_roundtrip() {
+        let cursor = TxPoolCursor(InspectionCursor {
+            tip: 10,
+            max_gas: 20,
+            creation_instant: SystemTime::UNIX_EPOCH + Duration::new(30, 40),
+            tx_id: [1; 32].into(),
+        });
+        let encoded = cursor.encode_cursor();
+        assert_eq!(TxPoolCursor::decode_cursor(&encoded), Ok(cursor));
+    }
+
+    #[test]
+    fn log_cursor_rejects_extra_parts() {
+        let res = LogCursor::decode_cursor("10#2#3#4");
+        assert!(res.is_err());
+    }
 }
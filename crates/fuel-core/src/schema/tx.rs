@@ -1,11 +1,13 @@
 use super::scalars::{
     AssetId,
+    ContractId,
     U16,
     U32,
     U64,
 };
 use crate::{
     coins_query::CoinsQueryError,
+    database::database_description::IndexationKind,
     fuel_core_graphql_api::{
         Config as GraphQLConfig,
         IntoApiResult,
@@ -18,9 +20,14 @@ use crate::{
         query_costs,
     },
     graphql_api::{
+        authorization::{
+            Role,
+            has_role,
+        },
         database::ReadView,
         ports::MemoryPool,
         require_expensive_subscriptions,
+        require_historical_execution,
     },
     query::{
         TxnStatusChangeState,
@@ -37,6 +44,7 @@ use crate::{
             SortedTxCursor,
             TransactionId,
             TxPointer,
+            TxPoolCursor,
         },
         tx::{
             assemble_tx::{
@@ -46,6 +54,7 @@ use crate::{
             types::{
                 AssembleTransactionResult,
                 TransactionStatus,
+                TxPoolTransaction,
                 get_tx_status,
             },
         },
@@ -70,8 +79,13 @@ use fuel_core_storage::{
 };
 use fuel_core_syscall::handlers::log_collector::EcalLogCollector;
 use fuel_core_tx_status_manager::TxStatusMessage;
+use fuel_core_txpool::inspection::InspectionFilter;
 use fuel_core_types::{
     blockchain::transaction::TransactionExt,
+    entities::{
+        coins::coin::Coin,
+        relayer::message::MessageV1,
+    },
     fuel_tx::{
         self,
         Bytes32,
@@ -88,7 +102,11 @@ use fuel_core_types::{
         EstimatePredicates,
     },
     services::{
-        executor::DryRunResult,
+        executor::{
+            ContractOverride,
+            DryRunResult,
+            StateOverrides,
+        },
         transaction_status,
     },
 };
@@ -107,6 +125,7 @@ use types::{
     DryRunTransactionExecutionStatus,
     StorageReadReplayEvent,
     Transaction,
+    TransactionTrace,
 };
 
 mod assemble_tx;
@@ -121,6 +140,7 @@ pub struct TxQuery;
 
 impl TxQuery {
     /// The actual logic of all different dry-run queries.
+    #[allow(clippy::too_many_arguments)]
     async fn dry_run_inner(
         &self,
         ctx: &Context<'_>,
@@ -135,6 +155,8 @@ impl TxQuery {
         block_height: Option<U32>,
         // Record storage reads, so this tx can be used with execution tracer in a local debugger.
         record_storage_reads: bool,
+        // The changes applied on top of the forked state before the execution.
+        state_overrides: Option<schema_types::StateOverridesInput>,
     ) -> async_graphql::Result<DryRunStorageReads> {
         let config = ctx.data_unchecked::<GraphQLConfig>().clone();
         let block_producer = ctx.data_unchecked::<BlockProducer>();
@@ -150,6 +172,24 @@ impl TxQuery {
             .into());
         }
 
+        let unchecked = if utxo_validation == Some(false) {
+            Some("Disabling the UTXO validation")
+        } else if state_overrides.is_some() {
+            Some("Overriding the state")
+        } else {
+            None
+        };
+        if let Some(unchecked) = unchecked
+            && config.config.api_keys_path.is_some()
+            && !has_role(ctx, Role::UncheckedDryRun)
+        {
+            return Err(anyhow::anyhow!(
+                "{unchecked} requires the API key with the `{}` role",
+                Role::UncheckedDryRun.as_str()
+            )
+            .into());
+        }
+
         let mut transactions = txs
             .iter()
             .map(|tx| FuelTx::from_bytes(&tx.0))
@@ -175,6 +215,7 @@ impl TxQuery {
                 utxo_validation,
                 gas_price.map(|x| x.into()),
                 record_storage_reads,
+                state_overrides.map(Into::into),
             )
             .await?;
 
@@ -345,6 +386,124 @@ impl TxQuery {
         .await
     }
 
+    /// Returns transactions that called or created the `contract`.
+    /// Requires the contract transactions indexation to be enabled.
+    #[graphql(complexity = "{\
+        query_costs().storage_iterator\
+        + first.unwrap_or_default() as usize * (child_complexity + query_costs().storage_read) \
+        + last.unwrap_or_default() as usize * (child_complexity + query_costs().storage_read) \
+    }")]
+    async fn transactions_by_contract(
+        &self,
+        ctx: &Context<'_>,
+        contract: ContractId,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<Connection<TxPointer, Transaction, EmptyFields, EmptyFields>>
+    {
+        use futures::stream::StreamExt;
+        let query = ctx.read_view()?;
+        if !query
+            .indexation_flags
+            .contains(&IndexationKind::ContractTransactions)
+        {
+            return Err(anyhow::anyhow!(
+                "Contract transactions indexation is not available"
+            )
+            .into())
+        }
+        let params = ctx
+            .data_unchecked::<ChainInfoProvider>()
+            .current_consensus_params();
+        let contract_id = fuel_types::ContractId::from(contract);
+
+        crate::schema::query_pagination(
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<TxPointer>, direction| {
+                let start = (*start).map(Into::into);
+                let txs = query
+                    .contract_transactions(contract_id, start, direction)
+                    .map(|result| {
+                        result.map(|(cursor, tx)| {
+                            let tx_id = tx.id(&params.chain_id());
+                            (cursor.into(), Transaction::from_tx(tx_id, tx))
+                        })
+                    });
+                Ok(txs)
+            },
+        )
+        .await
+    }
+
+    /// Returns transactions of the transaction pool, including transactions that
+    /// wait for their inputs or maturity, sorted by their tip per gas ratio.
+    /// Members of a bundle are returned as a part of the bundle's head.
+    #[graphql(complexity = "{\
+        (query_costs().tx_get + child_complexity) \
+        * (first.unwrap_or_default() as usize + last.unwrap_or_default() as usize)
+    }")]
+    #[allow(clippy::too_many_arguments)]
+    async fn tx_pool_transactions(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Only transactions that spend inputs of the owner")]
+        owner: Option<Address>,
+        #[graphql(desc = "Only transactions that use or create the contract")]
+        contract: Option<ContractId>,
+        #[graphql(
+            desc = "Only executable(`true`) or not executable(`false`) transactions"
+        )]
+        executable: Option<bool>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<TxPoolCursor, TxPoolTransaction, EmptyFields, EmptyFields>,
+    > {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let count = first
+            .or(last)
+            .and_then(|count| usize::try_from(count).ok())
+            .unwrap_or_default();
+        crate::schema::query_pagination(
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<TxPoolCursor>, direction| {
+                let filter = InspectionFilter {
+                    owner: owner.map(Into::into),
+                    contract: contract.map(Into::into),
+                    executable,
+                    start: start.map(|cursor| cursor.0),
+                    direction,
+                    // The transaction at the cursor and one more transaction
+                    // to know whether there is the next page.
+                    limit: count.saturating_add(2),
+                };
+                let txs =
+                    futures::stream::once(async move {
+                        txpool.inspect(filter).await.map_err(StorageError::from)
+                    })
+                    .map_ok(|txs| {
+                        futures::stream::iter(txs.into_iter().map(|tx| {
+                            Ok((TxPoolCursor(tx.cursor()), TxPoolTransaction(tx)))
+                        }))
+                    })
+                    .try_flatten();
+
+                Ok(txs)
+            },
+        )
+        .await
+    }
+
     /// Assembles the transaction based on the provided requirements.
     /// The return transaction contains:
     /// - Input coins to cover `required_balances`
@@ -493,6 +652,7 @@ impl TxQuery {
                 Some(false),
                 Some(gas_price),
                 false,
+                None,
             )
             .await?
             .transactions
@@ -559,9 +719,20 @@ impl TxQuery {
         // This can be used to run the dry-run on top of a past block.
         // Requires `--historical-execution` flag to be enabled.
         block_height: Option<U32>,
+        // Overrides the contracts' state and injects fake coins and messages
+        // into the forked state before the execution.
+        state_overrides: Option<schema_types::StateOverridesInput>,
     ) -> async_graphql::Result<Vec<DryRunTransactionExecutionStatus>> {
         Ok(self
-            .dry_run_inner(ctx, txs, utxo_validation, gas_price, block_height, false)
+            .dry_run_inner(
+                ctx,
+                txs,
+                utxo_validation,
+                gas_price,
+                block_height,
+                false,
+                state_overrides,
+            )
             .await?
             .tx_statuses)
     }
@@ -583,9 +754,20 @@ impl TxQuery {
         // This can be used to run the dry-run on top of a past block.
         // Requires `--historical-execution` flag to be enabled.
         block_height: Option<U32>,
+        // Overrides the contracts' state and injects fake coins and messages
+        // into the forked state before the execution.
+        state_overrides: Option<schema_types::StateOverridesInput>,
     ) -> async_graphql::Result<DryRunStorageReads> {
-        self.dry_run_inner(ctx, txs, utxo_validation, gas_price, block_height, true)
-            .await
+        self.dry_run_inner(
+            ctx,
+            txs,
+            utxo_validation,
+            gas_price,
+            block_height,
+            true,
+            state_overrides,
+        )
+        .await
     }
 
     /// Get execution trace for an already-executed block.
@@ -612,6 +794,41 @@ impl TxQuery {
             .map(StorageReadReplayEvent::from)
             .collect())
     }
+
+    /// Re-executes the block containing the transaction and returns its call tree.
+    #[graphql(complexity = "query_costs().transaction_trace + child_complexity")]
+    async fn transaction_trace(
+        &self,
+        ctx: &Context<'_>,
+        id: TransactionId,
+    ) -> async_graphql::Result<TransactionTrace> {
+        require_historical_execution(ctx)?;
+
+        let tx_id = id.0;
+        let query = ctx.read_view()?;
+        let block_height = match query.tx_status(&tx_id)? {
+            transaction_status::TransactionExecutionStatus::Success {
+                block_height,
+                ..
+            }
+            | transaction_status::TransactionExecutionStatus::Failed {
+                block_height,
+                ..
+            } => block_height,
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "Transaction {tx_id} is not included in a block"
+                )
+                .into());
+            }
+        };
+
+        let block_producer = ctx.data_unchecked::<BlockProducer>();
+        Ok(block_producer
+            .transaction_trace(block_height, tx_id)
+            .await?
+            .into())
+    }
 }
 
 #[derive(Default)]
@@ -636,13 +853,27 @@ impl TxMutation {
         // This can be used to run the dry-run on top of a past block.
         // Requires `--historical-execution` flag to be enabled.
         block_height: Option<U32>,
+        // Overrides the contracts' state and injects fake coins and messages
+        // into the forked state before the execution.
+        state_overrides: Option<schema_types::StateOverridesInput>,
     ) -> async_graphql::Result<Vec<DryRunTransactionExecutionStatus>> {
-        TxQuery::dry_run(&TxQuery, ctx, txs, utxo_validation, gas_price, block_height)
-            .await
+        TxQuery::dry_run(
+            &TxQuery,
+            ctx,
+            txs,
+            utxo_validation,
+            gas_price,
+            block_height,
+            state_overrides,
+        )
+        .await
     }
 
     /// Submits transaction to the `TxPool`.
     ///
+    /// If `replaceable` is `false`, the `TxPool` rejects transactions colliding with
+    /// the submitted one regardless of their tip. The flag is local to the node.
+    ///
     /// Returns submitted transaction if the transaction is included in the `TxPool` without problems.
     #[graphql(complexity = "query_costs().submit + child_complexity")]
     async fn submit(
@@ -650,6 +881,7 @@ impl TxMutation {
         ctx: &Context<'_>,
         tx: HexString,
         estimate_predicates: Option<bool>,
+        replaceable: Option<bool>,
     ) -> async_graphql::Result<Transaction> {
         let txpool = ctx.data_unchecked::<TxPool>();
         let mut tx = FuelTx::from_bytes(&tx.0)?;
@@ -660,7 +892,7 @@ impl TxMutation {
         }
 
         txpool
-            .insert(tx.clone())
+            .insert(tx.clone(), replaceable.unwrap_or(true))
             .await
             .map_err(|e| anyhow::anyhow!(e))?;
 
@@ -673,6 +905,80 @@ impl TxMutation {
         let tx = Transaction(tx, id);
         Ok(tx)
     }
+
+    /// Submits the bundle of transactions to the `TxPool`.
+    ///
+    /// The `TxPool` accepts or rejects the bundle as a whole: if any transaction
+    /// of the bundle is invalid, the whole bundle is rejected. The transactions of the
+    /// bundle are selected for the block all-or-nothing, one after another in the
+    /// submitted order. The block producer executes them as regular transactions,
+    /// so a transaction that fails at the execution is skipped without reverting the others.
+    ///
+    /// Bundles are neither gossiped to other nodes nor written to the `TxPool` journal,
+    /// so only the nodes that produce blocks accept them, and they are lost on the restart.
+    ///
+    /// Returns submitted transactions if the bundle is included in the `TxPool` without problems.
+    #[graphql(complexity = "{\
+        (query_costs().submit \
+            + usize::from(estimate_predicates.unwrap_or_default()) * query_costs().estimate_predicates \
+            + child_complexity) \
+        * txs.len()\
+    }")]
+    async fn submit_bundle(
+        &self,
+        ctx: &Context<'_>,
+        txs: Vec<HexString>,
+        estimate_predicates: Option<bool>,
+    ) -> async_graphql::Result<Vec<Transaction>> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        if !config.block_production {
+            return Err(anyhow::anyhow!(
+                "Bundles are not gossiped, so only the block producer accepts them"
+            )
+            .into());
+        }
+        if txs.len() > config.max_bundle_size {
+            return Err(anyhow::anyhow!(
+                "The bundle has {} transactions, but the limit is {}",
+                txs.len(),
+                config.max_bundle_size
+            )
+            .into());
+        }
+
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let mut transactions = txs
+            .iter()
+            .map(|tx| FuelTx::from_bytes(&tx.0))
+            .collect::<Result<Vec<FuelTx>, _>>()?;
+
+        if estimate_predicates.unwrap_or(false) {
+            let query = ctx.read_view()?.into_owned();
+            let mut estimated = Vec::with_capacity(transactions.len());
+            for tx in transactions {
+                estimated.push(ctx.estimate_predicates(tx, query.clone()).await?);
+            }
+            transactions = estimated;
+        }
+
+        txpool
+            .insert_bundle(transactions.clone())
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        let chain_id = ctx
+            .data_unchecked::<ChainInfoProvider>()
+            .current_consensus_params()
+            .chain_id();
+
+        Ok(transactions
+            .into_iter()
+            .map(|tx| {
+                let id = tx.id(&chain_id);
+                Transaction(tx, id)
+            })
+            .collect())
+    }
 }
 
 #[derive(Default)]
@@ -744,19 +1050,26 @@ impl TxStatusSubscription {
     }
 
     /// Submits transaction to the `TxPool` and await either success or failure.
+    /// See `submit` for the meaning of `replaceable`.
     #[graphql(complexity = "query_costs().submit_and_await + child_complexity")]
     async fn submit_and_await<'a>(
         &self,
         ctx: &'a Context<'a>,
         tx: HexString,
         estimate_predicates: Option<bool>,
+        replaceable: Option<bool>,
     ) -> async_graphql::Result<
         impl Stream<Item = async_graphql::Result<TransactionStatus>> + 'a + use<'a>,
     > {
         use tokio_stream::StreamExt;
-        let subscription =
-            submit_and_await_status(ctx, tx, estimate_predicates.unwrap_or(false), false)
-                .await?;
+        let subscription = submit_and_await_status(
+            ctx,
+            tx,
+            estimate_predicates.unwrap_or(false),
+            false,
+            replaceable.unwrap_or(true),
+        )
+        .await?;
 
         Ok(subscription
             .skip_while(|event| event.as_ref().map_or(true, |status| !status.is_final()))
@@ -766,6 +1079,7 @@ impl TxStatusSubscription {
     /// Submits the transaction to the `TxPool` and returns a stream of events.
     /// Compared to the `submitAndAwait`, the stream also contains
     /// `SubmittedStatus` and potentially preconfirmation as an intermediate state.
+    /// See `submit` for the meaning of `replaceable`.
     #[graphql(complexity = "query_costs().submit_and_await + child_complexity")]
     async fn submit_and_await_status<'a>(
         &self,
@@ -773,6 +1087,7 @@ impl TxStatusSubscription {
         tx: HexString,
         estimate_predicates: Option<bool>,
         include_preconfirmation: Option<bool>,
+        replaceable: Option<bool>,
     ) -> async_graphql::Result<
         impl Stream<Item = async_graphql::Result<TransactionStatus>> + 'a + use<'a>,
     > {
@@ -781,6 +1096,7 @@ impl TxStatusSubscription {
             tx,
             estimate_predicates.unwrap_or(false),
             include_preconfirmation.unwrap_or(false),
+            replaceable.unwrap_or(true),
         )
         .await
     }
@@ -791,6 +1107,7 @@ async fn submit_and_await_status<'a>(
     tx: HexString,
     estimate_predicates: bool,
     include_preconfirmation: bool,
+    replaceable: bool,
 ) -> async_graphql::Result<
     impl Stream<Item = async_graphql::Result<TransactionStatus>> + 'a,
 > {
@@ -810,7 +1127,7 @@ async fn submit_and_await_status<'a>(
 
     let subscription = tx_status_manager.tx_update_subscribe(tx_id).await?;
 
-    txpool.insert(tx).await?;
+    txpool.insert(tx, replaceable).await?;
 
     Ok(subscription
         .filter_map(move |status| {
@@ -856,6 +1173,7 @@ impl TxnStatusChangeState for StatusChangeState<'_> {
 
 pub mod schema_types {
     use super::*;
+    use crate::schema::scalars;
 
     #[derive(async_graphql::Enum, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
     pub enum Destroy {
@@ -895,6 +1213,68 @@ pub mod schema_types {
         pub account: Account,
         pub change_policy: ChangePolicy,
     }
+
+    /// The changes applied on top of the forked state before the dry run.
+    /// They are visible only to the transactions of the dry run and are never committed.
+    #[derive(async_graphql::InputObject)]
+    pub struct StateOverridesInput {
+        /// Overrides of the contracts' bytecode, storage slots, and balances.
+        #[graphql(default)]
+        pub contracts: Vec<ContractOverrideInput>,
+        /// Fake coins inserted into the state.
+        #[graphql(default)]
+        pub coins: Vec<FakeCoinInput>,
+        /// Fake messages inserted into the state.
+        #[graphql(default)]
+        pub messages: Vec<FakeMessageInput>,
+    }
+
+    #[derive(async_graphql::InputObject)]
+    pub struct ContractOverrideInput {
+        pub contract_id: ContractId,
+        /// Replaces the bytecode of the contract.
+        /// If the contract doesn't exist, it is created with this bytecode.
+        pub bytecode: Option<HexString>,
+        /// Storage slots that are set to the specified values.
+        #[graphql(default)]
+        pub storage_slots: Vec<StorageSlotInput>,
+        /// Balances that are set to the specified amounts.
+        #[graphql(default)]
+        pub balances: Vec<ContractBalanceInput>,
+    }
+
+    #[derive(async_graphql::InputObject)]
+    pub struct StorageSlotInput {
+        pub key: scalars::Bytes32,
+        pub value: HexString,
+    }
+
+    #[derive(async_graphql::InputObject)]
+    pub struct ContractBalanceInput {
+        pub asset_id: AssetId,
+        pub amount: U64,
+    }
+
+    #[derive(async_graphql::InputObject)]
+    pub struct FakeCoinInput {
+        pub utxo_id: scalars::UtxoId,
+        pub owner: Address,
+        pub asset_id: AssetId,
+        pub amount: U64,
+    }
+
+    #[derive(async_graphql::InputObject)]
+    pub struct FakeMessageInput {
+        pub nonce: scalars::Nonce,
+        /// The sender of the message. Zero address by default.
+        pub sender: Option<Address>,
+        pub recipient: Address,
+        pub amount: U64,
+        /// The data of the message. Empty by default.
+        pub data: Option<HexString>,
+        /// The DA height of the message. Zero by default.
+        pub da_height: Option<U64>,
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -969,6 +1349,66 @@ impl From<schema_types::RequiredBalance> for RequiredBalance {
     }
 }
 
+impl From<schema_types::StateOverridesInput> for StateOverrides {
+    fn from(overrides: schema_types::StateOverridesInput) -> Self {
+        let contracts = overrides
+            .contracts
+            .into_iter()
+            .map(|contract| ContractOverride {
+                contract_id: contract.contract_id.into(),
+                bytecode: contract.bytecode.map(Into::into),
+                storage_slots: contract
+                    .storage_slots
+                    .into_iter()
+                    .map(|slot| (slot.key.into(), slot.value.into()))
+                    .collect(),
+                balances: contract
+                    .balances
+                    .into_iter()
+                    .map(|balance| (balance.asset_id.into(), balance.amount.into()))
+                    .collect(),
+            })
+            .collect();
+
+        let coins = overrides
+            .coins
+            .into_iter()
+            .map(|coin| Coin {
+                utxo_id: coin.utxo_id.into(),
+                owner: coin.owner.into(),
+                amount: coin.amount.into(),
+                asset_id: coin.asset_id.into(),
+                tx_pointer: Default::default(),
+            })
+            .collect();
+
+        let messages = overrides
+            .messages
+            .into_iter()
+            .map(|message| {
+                MessageV1 {
+                    sender: message.sender.map(Into::into).unwrap_or_default(),
+                    recipient: message.recipient.into(),
+                    nonce: message.nonce.into(),
+                    amount: message.amount.into(),
+                    data: message.data.map(Into::into).unwrap_or_default(),
+                    da_height: message
+                        .da_height
+                        .map(|height| height.0.into())
+                        .unwrap_or_default(),
+                }
+                .into()
+            })
+            .collect();
+
+        Self {
+            contracts,
+            coins,
+            messages,
+        }
+    }
+}
+
 pub trait ContextExt {
     fn try_find_tx(
         &self,
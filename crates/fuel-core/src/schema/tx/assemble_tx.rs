@@ -140,7 +140,15 @@ impl AssembleArguments<'_> {
         script: Script,
     ) -> anyhow::Result<(Transaction, TransactionExecutionStatus)> {
         self.block_producer
-            .dry_run_txs(vec![script.into()], None, None, Some(false), Some(0), false)
+            .dry_run_txs(
+                vec![script.into()],
+                None,
+                None,
+                Some(false),
+                Some(0),
+                false,
+                None,
+            )
             .await?
             .transactions
             .into_iter()
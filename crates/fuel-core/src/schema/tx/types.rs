@@ -44,6 +44,7 @@ use async_graphql::{
     Union,
 };
 use fuel_core_storage::Error as StorageError;
+use fuel_core_txpool::inspection::InspectedTransaction;
 use fuel_core_types::{
     fuel_tx::{
         self,
@@ -89,7 +90,10 @@ use fuel_core_types::{
             TransactionStatus as TxStatus,
         },
     },
-    tai64::Tai64,
+    tai64::{
+        Tai64,
+        Tai64N,
+    },
 };
 use std::{
     sync::Arc,
@@ -1125,6 +1129,90 @@ impl StorageReadReplayEvent {
     }
 }
 
+/// The call tree of the transaction. The call frames are ordered by the time
+/// they were entered, the `parent` of the frame points to its caller.
+pub struct TransactionTrace(fuel_core_types::services::executor::TransactionTrace);
+
+impl From<fuel_core_types::services::executor::TransactionTrace> for TransactionTrace {
+    fn from(trace: fuel_core_types::services::executor::TransactionTrace) -> Self {
+        Self(trace)
+    }
+}
+
+#[Object]
+impl TransactionTrace {
+    /// The gas used by the script, including the gas used by all call frames.
+    async fn gas_used(&self) -> U64 {
+        self.0.gas_used.into()
+    }
+
+    /// Receipts emitted by the script outside of any call frame.
+    async fn receipts(&self) -> Vec<Receipt> {
+        self.0.receipts.iter().cloned().map(Into::into).collect()
+    }
+
+    async fn frames(&self) -> Vec<CallFrameTrace> {
+        self.0.frames.iter().cloned().map(CallFrameTrace).collect()
+    }
+
+    /// The `Revert` or `Panic` receipt, if the script itself reverted.
+    async fn revert(&self) -> Option<Receipt> {
+        self.0.revert.clone().map(Into::into)
+    }
+}
+
+pub struct CallFrameTrace(fuel_core_types::services::executor::CallFrameTrace);
+
+#[Object]
+impl CallFrameTrace {
+    /// The index of the caller frame in the `frames`.
+    /// Null if the frame was opened by the script.
+    async fn parent(&self) -> Option<U32> {
+        self.0.parent.map(Into::into)
+    }
+
+    async fn contract_id(&self) -> ContractId {
+        self.0.contract_id.into()
+    }
+
+    /// The first parameter of the call, used as the function selector.
+    async fn function_selector(&self) -> U64 {
+        self.0.function_selector.into()
+    }
+
+    /// The second parameter of the call, usually the arguments.
+    async fn param2(&self) -> U64 {
+        self.0.param2.into()
+    }
+
+    async fn asset_id(&self) -> AssetId {
+        self.0.asset_id.into()
+    }
+
+    async fn amount(&self) -> U64 {
+        self.0.amount.into()
+    }
+
+    async fn gas_forwarded(&self) -> U64 {
+        self.0.gas_forwarded.into()
+    }
+
+    /// The gas used inside of the frame, including the gas used by nested frames.
+    async fn gas_used(&self) -> U64 {
+        self.0.gas_used.into()
+    }
+
+    /// Receipts emitted inside of the frame, including the `Call` receipts of nested frames.
+    async fn receipts(&self) -> Vec<Receipt> {
+        self.0.receipts.iter().cloned().map(Into::into).collect()
+    }
+
+    /// The `Revert` or `Panic` receipt, if the execution reverted inside of the frame.
+    async fn revert(&self) -> Option<Receipt> {
+        self.0.revert.clone().map(Into::into)
+    }
+}
+
 #[tracing::instrument(level = "debug", skip(query, tx_status_manager), ret, err)]
 pub(crate) async fn get_tx_status(
     id: &fuel_core_types::fuel_types::Bytes32,
@@ -1188,3 +1276,60 @@ impl AssembleTransactionResult {
         self.gas_price.into()
     }
 }
+
+/// The transaction of the transaction pool.
+pub struct TxPoolTransaction(pub(crate) InspectedTransaction);
+
+#[Object]
+impl TxPoolTransaction {
+    async fn id(&self) -> TransactionId {
+        TransactionId(self.0.tx.id())
+    }
+
+    async fn transaction(&self) -> Transaction {
+        Transaction::from_tx(self.0.tx.id(), self.0.tx.as_ref().into())
+    }
+
+    /// Other transactions of the bundle headed by this transaction, in the order
+    /// of the bundle. Empty if the transaction isn't a head of a bundle.
+    async fn bundle(&self) -> Vec<Transaction> {
+        self.0
+            .bundle
+            .iter()
+            .map(|tx| Transaction::from_tx(tx.id(), tx.as_ref().into()))
+            .collect()
+    }
+
+    /// The tip of the transaction.
+    async fn tip(&self) -> U64 {
+        self.0.tx.tip().into()
+    }
+
+    /// The maximum gas the transaction can consume.
+    async fn max_gas(&self) -> U64 {
+        self.0.tx.max_gas().into()
+    }
+
+    /// The time when the transaction was inserted into the pool.
+    async fn insertion_time(&self) -> Tai64Timestamp {
+        Tai64Timestamp(Tai64N::from(self.0.creation_instant).0)
+    }
+
+    /// The number of transactions of the pool that depend on this transaction.
+    async fn dependents_count(&self) -> U32 {
+        u32::try_from(self.0.dependents).unwrap_or(u32::MAX).into()
+    }
+
+    /// Whether the transaction can be included in the next block.
+    async fn executable(&self) -> bool {
+        self.0.is_executable()
+    }
+
+    /// The reason why the transaction can't be included in the next block.
+    async fn not_executable_reason(&self) -> Option<String> {
+        self.0
+            .not_executable_reason
+            .as_ref()
+            .map(|reason| reason.to_string())
+    }
+}
@@ -292,6 +292,9 @@ impl FuelService {
             ConsensusConfig::PoA { .. } => {
                 // We don't support overriding of the heights for PoA version 1.
             }
+            ConsensusConfig::Bft(_) => {
+                // The BFT blocks are signed by the validators without overrides.
+            }
             ConsensusConfig::PoAV2(poa) => {
                 let on_chain_view = combined_database.on_chain().latest_view()?;
 
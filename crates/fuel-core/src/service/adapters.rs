@@ -107,6 +107,29 @@ impl ChainStateInfoProvider {
     }
 }
 
+/// The admission filter of the `TxPool` installed by the embedder of the node.
+/// When no filter is installed, all transactions are admitted.
+#[derive(Clone, Default)]
+pub struct TxPoolAdmissionFilter {
+    filter: Option<Arc<dyn fuel_core_txpool::ports::AdmissionFilter>>,
+}
+
+impl TxPoolAdmissionFilter {
+    pub fn new(filter: Arc<dyn fuel_core_txpool::ports::AdmissionFilter>) -> Self {
+        Self {
+            filter: Some(filter),
+        }
+    }
+}
+
+impl core::fmt::Debug for TxPoolAdmissionFilter {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TxPoolAdmissionFilter")
+            .field("installed", &self.filter.is_some())
+            .finish()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct StaticGasPrice {
     pub gas_price: u64,
@@ -311,16 +334,24 @@ impl GasPriceEstimate for UniversalGasPriceProvider<u32, u64> {
 #[derive(Clone)]
 pub struct PoAAdapter {
     shared_state: Option<fuel_core_poa::service::SharedState>,
+    leader_lease_status: Option<consensus_module::poa::LeaderLeaseStatusReporter>,
 }
 
 #[derive(Clone)]
 pub struct TxPoolAdapter {
     service: TxPoolSharedState,
+    chain_state_info_provider: ChainStateInfoProvider,
 }
 
 impl TxPoolAdapter {
-    pub fn new(service: TxPoolSharedState) -> Self {
-        Self { service }
+    pub fn new(
+        service: TxPoolSharedState,
+        chain_state_info_provider: ChainStateInfoProvider,
+    ) -> Self {
+        Self {
+            service,
+            chain_state_info_provider,
+        }
     }
 }
 
@@ -413,6 +444,24 @@ pub struct VerifierAdapter {
     pub block_verifier: Arc<Verifier<Database>>,
 }
 
+#[cfg(feature = "p2p")]
+#[derive(Clone)]
+pub struct BftBlockValidatorAdapter {
+    pub verifier: VerifierAdapter,
+    pub executor: ExecutorAdapter,
+}
+
+/// Persists the messages signed by the BFT validator.
+#[cfg(feature = "p2p")]
+#[derive(Clone)]
+pub enum BftSigningStateAdapter {
+    /// The state is stored in the file next to the database.
+    File(std::path::PathBuf),
+    /// The state is kept in memory along with the in-memory database,
+    /// so the node loses both the chain and the state on restart.
+    InMemory(Arc<parking_lot::Mutex<Option<fuel_core_bft::signing_state::SigningState>>>),
+}
+
 #[derive(Clone)]
 pub struct ConsensusAdapter {
     pub block_verifier: Arc<Verifier<Database>>,
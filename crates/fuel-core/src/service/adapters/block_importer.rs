@@ -7,7 +7,10 @@ use crate::{
         BlockImporterAdapter,
         ExecutorAdapter,
         VerifierAdapter,
-        consensus_module::poa::RedisLeaderLeaseAdapter,
+        consensus_module::poa::{
+            RaftLeaderLeaseAdapter,
+            RedisLeaderLeaseAdapter,
+        },
     },
 };
 use fuel_core_importer::{
@@ -65,6 +68,7 @@ use std::sync::Arc;
 #[allow(clippy::large_enum_variant)]
 pub enum BlockReconciliationWriteAdapter {
     Redis(RedisLeaderLeaseAdapter),
+    Raft(RaftLeaderLeaseAdapter),
     Noop(NoopBlockReconciliationWriteAdapter),
 }
 
@@ -122,6 +126,9 @@ impl fuel_core_importer::ports::BlockReconciliationWritePort
             Self::Redis(adapter) => {
                 fuel_core_importer::ports::BlockReconciliationWritePort::publish_produced_block(adapter, block)
             }
+            Self::Raft(adapter) => {
+                fuel_core_importer::ports::BlockReconciliationWritePort::publish_produced_block(adapter, block)
+            }
             Self::Noop(adapter) => {
                 fuel_core_importer::ports::BlockReconciliationWritePort::publish_produced_block(adapter, block)
             }
@@ -27,21 +27,28 @@ use fuel_core_types::{
         primitives::DaBlockHeight,
     },
     fuel_tx::Bytes32,
-    fuel_types::BlockHeight,
+    fuel_types::{
+        BlockHeight,
+        ChainId,
+    },
 };
 use std::sync::Arc;
 
+#[cfg(feature = "p2p")]
+pub mod bft;
 pub mod poa;
 
 impl VerifierAdapter {
     pub fn new(
         genesis_block: &CompressedBlock,
         consensus: ConsensusConfig,
+        chain_id: ChainId,
         database: Database,
     ) -> Self {
         let block_height = *genesis_block.header().height();
         let da_block_height = genesis_block.header().da_height();
-        let config = VerifierConfig::new(consensus, block_height, da_block_height);
+        let config =
+            VerifierConfig::new(consensus, block_height, da_block_height, chain_id);
         Self {
             block_verifier: Arc::new(Verifier::new(config, database)),
         }
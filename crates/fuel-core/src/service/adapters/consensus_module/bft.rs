@@ -0,0 +1,181 @@
+use crate::service::adapters::{
+    BftBlockValidatorAdapter,
+    BftSigningStateAdapter,
+    BlockImporterAdapter,
+    BlockProducerAdapter,
+    FuelBlockSigner,
+    P2PAdapter,
+};
+use fuel_core_bft::{
+    ports::{
+        BftGossipData,
+        BlockImporter,
+        BlockProducer,
+        BlockValidator,
+        P2pPort,
+        Signer,
+        SigningStateStorage,
+    },
+    signing_state::SigningState,
+};
+use fuel_core_importer::ports::{
+    BlockVerifier,
+    Validator,
+};
+use fuel_core_services::stream::BoxStream;
+use fuel_core_types::{
+    blockchain::{
+        SealedBlock,
+        block::Block,
+        consensus::{
+            Consensus,
+            bft::BftMessage,
+        },
+    },
+    fuel_crypto::{
+        Message,
+        Signature,
+    },
+    fuel_types::BlockHeight,
+    services::{
+        block_importer::BlockImportInfo,
+        p2p::{
+            GossipsubMessageAcceptance,
+            GossipsubMessageInfo,
+        },
+    },
+    tai64::Tai64,
+};
+use std::{
+    fs,
+    io::Write,
+    sync::Arc,
+};
+use tokio::time::Instant;
+use tokio_stream::{
+    StreamExt,
+    wrappers::BroadcastStream,
+};
+
+#[async_trait::async_trait]
+impl BlockProducer for BlockProducerAdapter {
+    async fn produce_block(
+        &self,
+        height: BlockHeight,
+        block_time: Tai64,
+        deadline: Instant,
+    ) -> anyhow::Result<Block> {
+        let result = self
+            .block_producer
+            .produce_and_execute_block_txpool(height, block_time, deadline)
+            .await?;
+        Ok(result.into_result().block)
+    }
+}
+
+#[async_trait::async_trait]
+impl BlockValidator for BftBlockValidatorAdapter {
+    async fn validate_block(&self, block: &Block) -> anyhow::Result<()> {
+        // The signatures of the validators are not known yet,
+        // so only the fields of the block are verified.
+        self.verifier
+            .verify_block_fields(&Consensus::Bft(Default::default()), block)?;
+
+        let executor = self.executor.clone();
+        let block = block.clone();
+        tokio_rayon::spawn_fifo(move || executor.validate(&block))
+            .await
+            .map(|_| ())
+            .map_err(Into::into)
+    }
+}
+
+#[async_trait::async_trait]
+impl BlockImporter for BlockImporterAdapter {
+    async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()> {
+        self.block_importer
+            .execute_and_commit(block)
+            .await
+            .map_err(Into::into)
+    }
+
+    fn block_stream(&self) -> BoxStream<BlockImportInfo> {
+        Box::pin(
+            BroadcastStream::new(self.block_importer.subscribe())
+                .filter_map(|result| result.ok())
+                .map(|result| BlockImportInfo::from(result.shared_result)),
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl Signer for FuelBlockSigner {
+    async fn sign_message(&self, message: Message) -> anyhow::Result<Signature> {
+        self.mode.sign_message(message).await
+    }
+}
+
+impl SigningStateStorage for BftSigningStateAdapter {
+    fn load(&self) -> anyhow::Result<Option<SigningState>> {
+        match self {
+            BftSigningStateAdapter::File(path) => match fs::read(path) {
+                Ok(bytes) => Ok(Some(postcard::from_bytes(&bytes)?)),
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+                Err(err) => Err(err.into()),
+            },
+            BftSigningStateAdapter::InMemory(state) => Ok(state.lock().clone()),
+        }
+    }
+
+    fn store(&self, state: &SigningState) -> anyhow::Result<()> {
+        match self {
+            BftSigningStateAdapter::File(path) => {
+                // Writes the new state aside and renames it over the old one,
+                // so the crash in the middle doesn't corrupt the persisted state.
+                let tmp_path = path.with_extension("tmp");
+                {
+                    let mut file = fs::File::create(&tmp_path)?;
+                    file.write_all(&postcard::to_allocvec(state)?)?;
+                    file.sync_all()?;
+                }
+                fs::rename(tmp_path, path)?;
+            }
+            BftSigningStateAdapter::InMemory(stored) => {
+                *stored.lock() = Some(state.clone());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl P2pPort for P2PAdapter {
+    fn broadcast_message(&self, message: BftMessage) -> anyhow::Result<()> {
+        match &self.service {
+            Some(service) => service.broadcast_bft_message(Arc::new(message)),
+            _ => Ok(()),
+        }
+    }
+
+    fn gossiped_messages(&self) -> BoxStream<BftGossipData> {
+        match &self.service {
+            Some(service) => Box::pin(
+                BroadcastStream::new(service.subscribe_bft_messages())
+                    .filter_map(|result| result.ok()),
+            ),
+            _ => Box::pin(fuel_core_services::stream::pending()),
+        }
+    }
+
+    fn notify_message_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        validity: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        match &self.service {
+            Some(service) => {
+                service.notify_gossip_transaction_validity(message_info, validity)
+            }
+            _ => Ok(()),
+        }
+    }
+}
@@ -16,6 +16,7 @@ use fuel_core_importer::ports::{
 use fuel_core_metrics::poa_metrics::poa_metrics;
 use fuel_core_poa::{
     ports::{
+        BlockFill,
         BlockImporter,
         BlockReconciliationReadPort,
         LeaderState,
@@ -44,9 +45,14 @@ use fuel_core_types::{
             UncommittedResult as UncommittedImporterResult,
         },
         executor::UncommittedResult,
+        leader_lease::{
+            LeaderLeaseStatus,
+            QuorumRoundKind,
+        },
     },
     tai64::Tai64,
 };
+use redis_lease_status::LeaseStatusRecorder;
 use std::{
     collections::HashMap,
     path::{
@@ -72,7 +78,12 @@ use tokio_stream::{
 };
 use tracing::error;
 
+pub use raft_leader_lease::RaftLeaderLeaseAdapter;
+pub use redis_lease_status::RedisLeaseStatusReporter;
+
 pub mod pre_confirmation_signature;
+pub mod raft_leader_lease;
+mod redis_lease_status;
 
 const CHECK_LEASE_OWNER_SCRIPT: &str = include_str!(concat!(
     env!("CARGO_MANIFEST_DIR"),
@@ -104,17 +115,41 @@ const READ_LATEST_STREAM_ENTRY_SCRIPT: &str = include_str!(concat!(
     "/redis_leader_lease_adapter_scripts/read_latest_stream_entry.lua"
 ));
 
-struct RedisNode {
+/// The Redis node of the leader lock. Clones share the cached connection,
+/// so all users of the adapter multiplex their requests over it.
+#[derive(Clone)]
+pub(crate) struct RedisNode {
     redis_client: redis::Client,
-    cached_connection: Mutex<Option<redis::aio::MultiplexedConnection>>,
+    cached_connection: std::sync::Arc<Mutex<Option<redis::aio::MultiplexedConnection>>>,
 }
 
-impl Clone for RedisNode {
-    fn clone(&self) -> Self {
-        Self {
-            redis_client: self.redis_client.clone(),
-            cached_connection: Mutex::new(None),
+impl RedisNode {
+    async fn multiplexed_connection(
+        &self,
+        node_timeout: Duration,
+    ) -> anyhow::Result<redis::aio::MultiplexedConnection> {
+        if let Some(connection) = self.cached_connection.lock().await.as_ref().cloned() {
+            return Ok(connection);
         }
+
+        let new_connection = timeout(
+            node_timeout,
+            self.redis_client.get_multiplexed_async_connection(),
+        )
+        .await
+        .map_err(|_| anyhow!("Timed out while connecting to redis leader-lock node"))??;
+        let mut cached_connection = self.cached_connection.lock().await;
+        if let Some(connection) = cached_connection.as_ref().cloned() {
+            return Ok(connection);
+        }
+        *cached_connection = Some(new_connection.clone());
+        Ok(new_connection)
+    }
+
+    async fn clear_cached_connection(&self) {
+        let mut cached_connection = self.cached_connection.lock().await;
+        *cached_connection = None;
+        poa_metrics().connection_reset_total.inc();
     }
 }
 
@@ -135,6 +170,7 @@ pub struct RedisLeaderLeaseAdapter {
     max_retry_delay_offset_millis: u64,
     max_attempts: usize,
     stream_max_len: u32,
+    lease_status: std::sync::Arc<std::sync::Mutex<LeaseStatusRecorder>>,
 }
 
 impl Clone for RedisLeaderLeaseAdapter {
@@ -156,6 +192,7 @@ impl Clone for RedisLeaderLeaseAdapter {
             max_retry_delay_offset_millis: self.max_retry_delay_offset_millis,
             max_attempts: self.max_attempts,
             stream_max_len: self.stream_max_len,
+            lease_status: self.lease_status.clone(),
         }
     }
 }
@@ -166,9 +203,26 @@ pub struct NoopReconciliationAdapter;
 #[allow(clippy::large_enum_variant)]
 pub enum ReconciliationAdapter {
     Redis(RedisLeaderLeaseAdapter),
+    Raft(RaftLeaderLeaseAdapter),
     Noop(NoopReconciliationAdapter),
 }
 
+/// Reports the status of the leader lease of the enabled backend.
+#[derive(Clone)]
+pub enum LeaderLeaseStatusReporter {
+    Redis(RedisLeaseStatusReporter),
+    Raft(RaftLeaderLeaseAdapter),
+}
+
+impl LeaderLeaseStatusReporter {
+    pub async fn status(&self) -> anyhow::Result<LeaderLeaseStatus> {
+        match self {
+            Self::Redis(reporter) => reporter.status().await,
+            Self::Raft(adapter) => adapter.status(),
+        }
+    }
+}
+
 impl RedisLeaderLeaseAdapter {
     fn calculate_quorum(redis_nodes_len: usize, quorum_disruption_budget: u32) -> usize {
         let majority = redis_nodes_len
@@ -197,7 +251,7 @@ impl RedisLeaderLeaseAdapter {
             .map(|redis_url| {
                 redis::Client::open(redis_url).map(|redis_client| RedisNode {
                     redis_client,
-                    cached_connection: Mutex::new(None),
+                    cached_connection: Default::default(),
                 })
             })
             .collect::<Result<Vec<_>, _>>()?;
@@ -237,6 +291,7 @@ impl RedisLeaderLeaseAdapter {
             max_retry_delay_offset_millis,
             max_attempts,
             stream_max_len,
+            lease_status: Default::default(),
         })
     }
 
@@ -250,40 +305,55 @@ impl RedisLeaderLeaseAdapter {
         self
     }
 
+    /// Returns the reporter of the lease state of this adapter and its Redis nodes.
+    pub fn status_reporter(&self) -> RedisLeaseStatusReporter {
+        RedisLeaseStatusReporter {
+            redis_nodes: self.redis_nodes.clone(),
+            quorum: self.quorum,
+            lease_key: self.lease_key.clone(),
+            epoch_key: self.epoch_key.clone(),
+            block_stream_key: self.block_stream_key.clone(),
+            lease_owner_token: self.lease_owner_token.clone(),
+            node_timeout: self.node_timeout,
+            recorder: self.lease_status.clone(),
+        }
+    }
+
+    fn update_lease_status(&self, update: impl FnOnce(&mut LeaseStatusRecorder)) {
+        if let Ok(mut lease_status) = self.lease_status.lock() {
+            update(&mut lease_status);
+        }
+    }
+
+    fn record_quorum_round(
+        &self,
+        kind: QuorumRoundKind,
+        responded: usize,
+        granted: usize,
+    ) {
+        let nodes = self.redis_nodes.len();
+        let quorum = self.quorum;
+        self.update_lease_status(|lease_status| {
+            lease_status.record_quorum_round(kind, nodes, responded, granted, quorum)
+        });
+    }
+
     async fn multiplexed_connection(
         &self,
         redis_node: &RedisNode,
     ) -> anyhow::Result<redis::aio::MultiplexedConnection> {
-        if let Some(connection) =
-            redis_node.cached_connection.lock().await.as_ref().cloned()
-        {
-            return Ok(connection);
-        }
-
-        let new_connection = timeout(
-            self.node_timeout,
-            redis_node.redis_client.get_multiplexed_async_connection(),
-        )
-        .await
-        .map_err(|_| anyhow!("Timed out while connecting to redis leader-lock node"))??;
-        let mut cached_connection = redis_node.cached_connection.lock().await;
-        if let Some(connection) = cached_connection.as_ref().cloned() {
-            return Ok(connection);
-        }
-        *cached_connection = Some(new_connection.clone());
-        Ok(new_connection)
+        redis_node.multiplexed_connection(self.node_timeout).await
     }
 
     async fn clear_cached_connection(&self, redis_node: &RedisNode) {
-        let mut cached_connection = redis_node.cached_connection.lock().await;
-        *cached_connection = None;
-        poa_metrics().connection_reset_total.inc();
+        redis_node.clear_cached_connection().await;
     }
 
-    async fn check_lease_owner_on_node(&self, redis_node: &RedisNode) -> bool {
+    /// Returns `None` if the node didn't answer.
+    async fn check_lease_owner_on_node(&self, redis_node: &RedisNode) -> Option<bool> {
         let mut connection = match self.multiplexed_connection(redis_node).await {
             Ok(connection) => connection,
-            Err(_) => return false,
+            Err(_) => return None,
         };
         let is_owner = timeout(
             self.node_timeout,
@@ -294,26 +364,25 @@ impl RedisLeaderLeaseAdapter {
         )
         .await;
         match is_owner {
-            Ok(Ok(is_owner)) => is_owner == 1,
+            Ok(Ok(is_owner)) => Some(is_owner == 1),
             Err(_) => {
                 self.clear_cached_connection(redis_node).await;
-                false
+                None
             }
             Ok(Err(_)) => {
                 self.clear_cached_connection(redis_node).await;
-                false
+                None
             }
         }
     }
 
+    /// Returns `Ok(None)` if another leader holds the lock on the node,
+    /// and an error if the node didn't answer.
     async fn promote_leader_on_node(
         &self,
         redis_node: &RedisNode,
     ) -> anyhow::Result<Option<u64>> {
-        let mut connection = match self.multiplexed_connection(redis_node).await {
-            Ok(connection) => connection,
-            Err(_) => return Ok(None),
-        };
+        let mut connection = self.multiplexed_connection(redis_node).await?;
         let promoted = timeout(
             self.node_timeout,
             redis::Script::new(PROMOTE_LEADER_SCRIPT)
@@ -331,19 +400,20 @@ impl RedisLeaderLeaseAdapter {
                     return Ok(None);
                 }
                 self.clear_cached_connection(redis_node).await;
-                Ok(None)
+                Err(err.into())
             }
             Err(_) => {
                 self.clear_cached_connection(redis_node).await;
-                Ok(None)
+                Err(anyhow!("Timed out while promoting leader on redis node"))
             }
         }
     }
 
-    async fn release_lease_on_node(&self, redis_node: &RedisNode) -> bool {
+    /// Returns `None` if the node didn't answer.
+    async fn release_lease_on_node(&self, redis_node: &RedisNode) -> Option<bool> {
         let mut connection = match self.multiplexed_connection(redis_node).await {
             Ok(connection) => connection,
-            Err(_) => return false,
+            Err(_) => return None,
         };
         let released = timeout(
             self.node_timeout,
@@ -354,14 +424,14 @@ impl RedisLeaderLeaseAdapter {
         )
         .await;
         match released {
-            Ok(Ok(released)) => released == 1,
+            Ok(Ok(released)) => Some(released == 1),
             Err(_) => {
                 self.clear_cached_connection(redis_node).await;
-                false
+                None
             }
             Ok(Err(_)) => {
                 self.clear_cached_connection(redis_node).await;
-                false
+                None
             }
         }
     }
@@ -407,7 +477,17 @@ impl RedisLeaderLeaseAdapter {
                 .map(|redis_node| self.check_lease_owner_on_node(redis_node)),
         )
         .await;
+        let responded_count = ownership.iter().flatten().count();
+        let ownership = ownership
+            .into_iter()
+            .map(|is_owner| is_owner.unwrap_or(false))
+            .collect::<Vec<_>>();
         let owner_count = ownership.iter().filter(|&&is_owner| is_owner).count();
+        self.record_quorum_round(
+            QuorumRoundKind::OwnershipCheck,
+            responded_count,
+            owner_count,
+        );
         if !self.quorum_reached(owner_count) {
             tracing::info!(
                 owner_count,
@@ -471,11 +551,18 @@ impl RedisLeaderLeaseAdapter {
                     .map(|redis_node| self.promote_leader_on_node(redis_node)),
             )
             .await;
+            let responded_count =
+                promoted_nodes.iter().filter(|token| token.is_ok()).count();
             let promoted_tokens = promoted_nodes
                 .into_iter()
                 .filter_map(|token| token.ok().flatten())
                 .collect::<Vec<_>>();
             let acquired_count = promoted_tokens.len();
+            self.record_quorum_round(
+                QuorumRoundKind::Promotion,
+                responded_count,
+                acquired_count,
+            );
             let elapsed_millis =
                 u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX);
             let validity_millis =
@@ -960,13 +1047,23 @@ impl RedisLeaderLeaseAdapter {
                 .map(|redis_node| self.release_lease_on_node(redis_node)),
         )
         .await;
-        let released_count = releases.into_iter().filter(|released| *released).count();
+        let responded_count = releases.iter().flatten().count();
+        let released_count = releases
+            .into_iter()
+            .filter(|released| *released == Some(true))
+            .count();
+        self.record_quorum_round(
+            QuorumRoundKind::Release,
+            responded_count,
+            released_count,
+        );
         if self.quorum_reached(released_count) {
             let mut current_epoch_token = self
                 .current_epoch_token
                 .lock()
                 .map_err(|_| anyhow!("cannot access epoch token, poisoned lock"))?;
             *current_epoch_token = None;
+            self.update_lease_status(LeaseStatusRecorder::record_release);
             Ok(())
         } else {
             Err(anyhow!("Failed to release lease on quorum"))
@@ -1227,8 +1324,14 @@ enum WriteBlockResult {
 }
 
 impl PoAAdapter {
-    pub fn new(shared_state: Option<SharedState>) -> Self {
-        Self { shared_state }
+    pub fn new(
+        shared_state: Option<SharedState>,
+        leader_lease_status: Option<LeaderLeaseStatusReporter>,
+    ) -> Self {
+        Self {
+            shared_state,
+            leader_lease_status,
+        }
     }
 
     pub async fn manually_produce_blocks(
@@ -1264,7 +1367,16 @@ impl BlockReconciliationReadPort for RedisLeaderLeaseAdapter {
         &self,
         next_height: BlockHeight,
     ) -> anyhow::Result<LeaderState> {
-        if self.can_produce_block().await? {
+        let local_height = u32::from(next_height).checked_sub(1).map(BlockHeight::from);
+        self.update_lease_status(|lease_status| {
+            lease_status.record_local_height(local_height)
+        });
+        let can_produce_block = self.can_produce_block().await?;
+        let epoch_token = self.current_epoch_token_value();
+        self.update_lease_status(|lease_status| {
+            lease_status.record_leadership(can_produce_block, epoch_token)
+        });
+        if can_produce_block {
             poa_metrics().is_leader.set(1);
             if let Ok(epoch) = self.current_epoch_token.lock()
                 && let Some(epoch) = *epoch
@@ -1302,6 +1414,7 @@ impl BlockReconciliationReadPort for ReconciliationAdapter {
     ) -> anyhow::Result<LeaderState> {
         match self {
             Self::Redis(adapter) => adapter.leader_state(next_height).await,
+            Self::Raft(adapter) => adapter.leader_state(next_height).await,
             Self::Noop(adapter) => adapter.leader_state(next_height).await,
         }
     }
@@ -1309,6 +1422,7 @@ impl BlockReconciliationReadPort for ReconciliationAdapter {
     async fn release(&self) -> anyhow::Result<()> {
         match self {
             Self::Redis(adapter) => adapter.release().await,
+            Self::Raft(adapter) => adapter.release().await,
             Self::Noop(adapter) => adapter.release().await,
         }
     }
@@ -1408,6 +1522,13 @@ impl ConsensusModulePort for PoAAdapter {
         self.manually_produce_blocks(start_time, Mode::Blocks { number_of_blocks })
             .await
     }
+
+    async fn leader_lease_status(&self) -> anyhow::Result<Option<LeaderLeaseStatus>> {
+        match &self.leader_lease_status {
+            Some(reporter) => reporter.status().await.map(Some),
+            None => Ok(None),
+        }
+    }
 }
 
 #[cfg(feature = "p2p")]
@@ -1471,6 +1592,21 @@ impl TransactionPool for TxPoolAdapter {
     fn new_txs_watcher(&self) -> watch::Receiver<()> {
         self.service.get_new_executable_txs_notifier()
     }
+
+    fn block_fill(&self) -> BlockFill {
+        let stats = self.service.latest_stats();
+        let consensus_parameters = self
+            .chain_state_info_provider
+            .shared_state
+            .latest_consensus_parameters();
+
+        BlockFill {
+            pending_gas: stats.total_gas,
+            pending_transactions: stats.tx_count,
+            max_gas: consensus_parameters.block_gas_limit(),
+            max_transactions: u64::from(fuel_core_executor::executor::max_tx_count()),
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -1544,7 +1680,10 @@ mod tests {
     use super::*;
     use fuel_core_importer::ports::BlockReconciliationWritePort;
     use fuel_core_poa::ports::BlockReconciliationReadPort;
-    use fuel_core_types::blockchain::consensus::Consensus;
+    use fuel_core_types::{
+        blockchain::consensus::Consensus,
+        services::leader_lease::LeaderLeaseBackend,
+    };
     use std::{
         io::Read as _,
         net::{
@@ -2136,6 +2275,72 @@ mod tests {
         );
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn status_reporter__when_leader_holds_lease_then_reports_holder_and_unreachable_node()
+     {
+        // given
+        let redis_a = RedisTestServer::spawn();
+        let redis_b = RedisTestServer::spawn();
+        let mut redis_c = RedisTestServer::spawn();
+        let lease_key = "poa:test:status-reporter".to_string();
+        let adapter = new_test_adapter(
+            vec![
+                redis_a.redis_url(),
+                redis_b.redis_url(),
+                redis_c.redis_url(),
+            ],
+            lease_key.clone(),
+        );
+        let state = adapter
+            .leader_state(1.into())
+            .await
+            .expect("leader_state should succeed");
+        assert!(matches!(state, LeaderState::ReconciledLeader));
+        redis_c.stop();
+
+        // when
+        let status = adapter
+            .status_reporter()
+            .status()
+            .await
+            .expect("status should be reported");
+
+        // then
+        assert!(status.is_leader);
+        assert_eq!(status.backend, LeaderLeaseBackend::Redis);
+        assert_eq!(status.lease_key, Some(lease_key));
+        assert_eq!(
+            status.holder.as_deref(),
+            Some(adapter.lease_owner_token.as_str())
+        );
+        assert_eq!(status.quorum, 2);
+        let reachable = status.nodes.iter().filter(|node| node.reachable).count();
+        assert_eq!(reachable, 2);
+        let unreachable = status
+            .nodes
+            .iter()
+            .find(|node| !node.reachable)
+            .expect("Stopped node should be unreachable");
+        assert!(unreachable.error.is_some());
+        let round = status
+            .last_quorum_round
+            .expect("Promotion round should be recorded");
+        assert_eq!(round.kind, QuorumRoundKind::Promotion);
+        assert_eq!(round.responded, 3);
+        assert_eq!(round.granted, 3);
+        assert_eq!(status.local_height, None);
+        assert_eq!(status.stream_height, None);
+        let transitions = status
+            .transitions
+            .iter()
+            .map(|transition| transition.kind)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            transitions,
+            vec![fuel_core_types::services::leader_lease::LeaseTransitionKind::Acquired]
+        );
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn leader_state__when_lease_expires_then_another_adapter_becomes_leader() {
         // given
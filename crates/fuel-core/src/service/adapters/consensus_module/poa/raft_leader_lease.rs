@@ -0,0 +1,606 @@
+//! The leader lease replicated by the Raft log embedded into the block producer
+//! replicas. It is an alternative to [`super::RedisLeaderLeaseAdapter`] that
+//! doesn't require the external Redis cluster.
+//!
+//! The elected Raft leader appends the `Promote` command at the start of its
+//! term. Once the command is committed, the leader owns the lease, and the
+//! epoch token is bumped. Every produced block is committed to the replicated
+//! block stream with the epoch token of the producer, so the blocks of
+//! a deposed leader are fenced off by the state machine.
+
+use super::redis_lease_status::LeaseStatusRecorder;
+use crate::service::config::RaftLeaderLockConfig;
+use anyhow::anyhow;
+use fuel_core_importer::ports::BlockReconciliationWritePort;
+use fuel_core_metrics::poa_metrics::poa_metrics;
+use fuel_core_poa::ports::{
+    BlockReconciliationReadPort,
+    LeaderState,
+};
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+    TaskNextAction,
+};
+use fuel_core_types::{
+    blockchain::{
+        SealedBlock,
+        consensus::Consensus,
+    },
+    fuel_types::BlockHeight,
+    secrecy::ExposeSecret,
+    services::leader_lease::{
+        LeaderLeaseBackend,
+        LeaderLeaseStatus,
+        LeaseNodeStatus,
+    },
+};
+use futures::{
+    StreamExt,
+    stream::FuturesUnordered,
+};
+use parking_lot::Mutex;
+use raft::{
+    CoreConfig,
+    LeaseStatus,
+    RaftCore,
+    RaftRequest,
+    VoteRequest,
+};
+use state_machine::{
+    ApplyResult,
+    LeaseCommand,
+    NodeId,
+};
+use std::{
+    collections::{
+        BTreeSet,
+        HashMap,
+    },
+    net::SocketAddr,
+    sync::Arc,
+    time::Duration,
+};
+use storage::RaftStorage;
+use tokio::{
+    net::{
+        TcpListener,
+        TcpStream,
+    },
+    sync::Notify,
+    task::JoinSet,
+    time::{
+        Instant,
+        Interval,
+        MissedTickBehavior,
+    },
+};
+use transport::{
+    ClusterKey,
+    FrameKind,
+    PeerClient,
+};
+
+mod raft;
+mod state_machine;
+mod storage;
+mod transport;
+
+struct Peer {
+    client: PeerClient,
+    /// Wakes up the replication to the peer when the leader appends new entries.
+    replication_trigger: Notify,
+}
+
+struct RaftNode {
+    core: Mutex<RaftCore>,
+    peers: HashMap<NodeId, Peer>,
+    key: ClusterKey,
+    heartbeat_interval: Duration,
+    commit_timeout: Duration,
+    recorder: Mutex<LeaseStatusRecorder>,
+}
+
+impl RaftNode {
+    fn lease_status(&self) -> LeaseStatus {
+        self.core.lock().lease_status(Instant::now())
+    }
+
+    fn trigger_replication(&self) {
+        for peer in self.peers.values() {
+            peer.replication_trigger.notify_one();
+        }
+    }
+
+    fn tick(&self) -> anyhow::Result<Option<VoteRequest>> {
+        let mut core = self.core.lock();
+        let was_leader = core.role() == raft::Role::Leader;
+        let request = core.tick(Instant::now())?;
+        let is_leader = core.role() == raft::Role::Leader;
+        drop(core);
+        if !was_leader && is_leader {
+            self.trigger_replication();
+        }
+        Ok(request)
+    }
+
+    /// Collects the votes of the peers. Continues with the election
+    /// if the pre-vote succeeds.
+    async fn run_election(self: Arc<Self>, mut request: VoteRequest) {
+        'rounds: loop {
+            let raft_request = RaftRequest::Vote(request.clone());
+            let mut calls = self
+                .peers
+                .iter()
+                .map(|(peer_id, peer)| {
+                    let raft_request = &raft_request;
+                    async move { (*peer_id, peer.client.call(raft_request).await) }
+                })
+                .collect::<FuturesUnordered<_>>();
+
+            while let Some((peer_id, result)) = calls.next().await {
+                let response = match result {
+                    Ok(raft::RaftResponse::Vote(response)) => response,
+                    Ok(_) => {
+                        tracing::warn!("Unexpected response to the Raft vote request");
+                        continue;
+                    }
+                    Err(err) => {
+                        tracing::debug!("Raft vote request to {peer_id} failed: {err}");
+                        continue;
+                    }
+                };
+                let mut core = self.core.lock();
+                let next_request = core.handle_vote_response(
+                    peer_id,
+                    &request,
+                    response,
+                    Instant::now(),
+                );
+                let is_leader = core.role() == raft::Role::Leader;
+                drop(core);
+                match next_request {
+                    Ok(Some(next_request)) => {
+                        request = next_request;
+                        continue 'rounds;
+                    }
+                    Ok(None) if is_leader => {
+                        self.trigger_replication();
+                        return;
+                    }
+                    Ok(None) => {}
+                    Err(err) => {
+                        tracing::error!("Failed to handle the Raft vote: {err}");
+                        return;
+                    }
+                }
+            }
+            return;
+        }
+    }
+
+    /// Replicates the log to the `peer_id` while this replica is the leader.
+    /// Sends heartbeats when there is nothing to replicate.
+    async fn replicate_to(self: Arc<Self>, peer_id: NodeId) {
+        let Some(peer) = self.peers.get(&peer_id) else {
+            return;
+        };
+        loop {
+            let next_request = {
+                let core = self.core.lock();
+                core.replication_request(peer_id)
+                    .map(|request| (core.term(), request))
+            };
+            if let Some((term, request)) = next_request {
+                let sent_at = Instant::now();
+                match peer.client.call(&request).await {
+                    Ok(response) => {
+                        let mut core = self.core.lock();
+                        if let Err(err) = core.handle_response(
+                            peer_id,
+                            term,
+                            response,
+                            sent_at,
+                            Instant::now(),
+                        ) {
+                            tracing::error!(
+                                "Failed to handle the Raft replication response: {err}"
+                            );
+                        }
+                        if core.has_pending_replication(peer_id) {
+                            continue;
+                        }
+                    }
+                    Err(err) => {
+                        tracing::debug!("Raft replication to {peer_id} failed: {err}");
+                    }
+                }
+            }
+            let _ = tokio::time::timeout(
+                self.heartbeat_interval,
+                peer.replication_trigger.notified(),
+            )
+            .await;
+        }
+    }
+
+    async fn serve_connection(self: Arc<Self>, mut stream: TcpStream) {
+        if let Err(err) = transport::authenticate_incoming(&mut stream, &self.key).await {
+            tracing::warn!("Rejected the unauthenticated Raft connection: {err}");
+            return;
+        }
+        loop {
+            let request = match transport::read_frame::<RaftRequest, _>(&mut stream).await
+            {
+                Ok((kind, request)) if kind == request.frame_kind() => request,
+                Ok((kind, _)) => {
+                    tracing::warn!("Unexpected Raft request in the {kind:?} frame");
+                    return;
+                }
+                Err(err) => {
+                    tracing::trace!("Raft connection closed: {err}");
+                    return;
+                }
+            };
+            let response = self.core.lock().handle_request(request, Instant::now());
+            let response = match response {
+                Ok(response) => response,
+                Err(err) => {
+                    tracing::error!("Failed to handle the Raft request: {err}");
+                    return;
+                }
+            };
+            if let Err(err) =
+                transport::write_frame(&mut stream, FrameKind::Message, &response).await
+            {
+                tracing::debug!("Failed to respond to the Raft request: {err}");
+                return;
+            }
+        }
+    }
+}
+
+/// The leader lease adapter backed by the embedded Raft log.
+#[derive(Clone)]
+pub struct RaftLeaderLeaseAdapter {
+    node: Arc<RaftNode>,
+    stream_max_len: u32,
+}
+
+impl RaftLeaderLeaseAdapter {
+    /// Combines the state of the replica and its peers with the outcome
+    /// of the latest lease checks of the block producer.
+    pub fn status(&self) -> anyhow::Result<LeaderLeaseStatus> {
+        let (id, status, quorum, stream_height) = {
+            let core = self.node.core.lock();
+            (
+                core.id(),
+                core.lease_status(Instant::now()),
+                core.quorum(),
+                core.state().latest_height(),
+            )
+        };
+        let mut peers = self.node.peers.iter().collect::<Vec<_>>();
+        peers.sort_by_key(|(peer_id, _)| **peer_id);
+        let nodes = peers
+            .into_iter()
+            .map(|(_, peer)| {
+                let (reachable, error) = match peer.client.last_result() {
+                    Some(Ok(())) => (true, None),
+                    Some(Err(err)) => (false, Some(err)),
+                    None => {
+                        (false, Some("No requests were sent to the peer".to_string()))
+                    }
+                };
+                LeaseNodeStatus {
+                    address: peer.client.address().to_string(),
+                    reachable,
+                    holder: None,
+                    epoch: None,
+                    stream_height: None,
+                    error,
+                }
+            })
+            .collect();
+        Ok(self.node.recorder.lock().lease_status(
+            LeaderLeaseBackend::Raft,
+            None,
+            id.to_string(),
+            status.owner.map(|owner| owner.to_string()),
+            quorum,
+            nodes,
+            stream_height.map(BlockHeight::from),
+        ))
+    }
+
+    fn unreconciled_blocks(
+        &self,
+        next_height: BlockHeight,
+    ) -> anyhow::Result<Vec<SealedBlock>> {
+        let next_height = u32::from(next_height);
+        let core = self.node.core.lock();
+        let state = core.state();
+        let Some(latest_height) = state.latest_height() else {
+            return Ok(Vec::new());
+        };
+        if latest_height < next_height {
+            return Ok(Vec::new());
+        }
+        if let Some(earliest_height) = state.earliest_height() {
+            let local_committed = i64::from(next_height.saturating_sub(1));
+            let headroom = i64::from(earliest_height).saturating_sub(local_committed);
+            poa_metrics().stream_trim_headroom.set(headroom);
+        }
+
+        let max_blocks = usize::try_from(self.stream_max_len).unwrap_or(usize::MAX);
+        let blocks = state
+            .blocks_from(next_height)
+            .take(max_blocks)
+            .map(|(_, entry)| postcard::from_bytes::<SealedBlock>(&entry.block))
+            .collect::<Result<Vec<_>, _>>()?;
+        if blocks.is_empty() {
+            return Err(anyhow!(
+                "Backlog unresolved at height {next_height}: \
+                 stream indicates backlog but no entries found at next height"
+            ));
+        }
+        Ok(blocks)
+    }
+}
+
+#[async_trait::async_trait]
+impl BlockReconciliationReadPort for RaftLeaderLeaseAdapter {
+    async fn leader_state(
+        &self,
+        next_height: BlockHeight,
+    ) -> anyhow::Result<LeaderState> {
+        let status = self.node.lease_status();
+        {
+            let mut recorder = self.node.recorder.lock();
+            recorder.record_local_height(
+                u32::from(next_height).checked_sub(1).map(BlockHeight::from),
+            );
+            recorder.record_leadership(
+                status.is_owner,
+                status.is_owner.then_some(status.epoch),
+            );
+        }
+        if !status.is_owner {
+            poa_metrics().is_leader.set(0);
+            return Ok(LeaderState::ReconciledFollower);
+        }
+        poa_metrics().is_leader.set(1);
+        poa_metrics()
+            .leader_epoch
+            .set(i64::try_from(status.epoch).unwrap_or(i64::MAX));
+
+        let reconcile_start = std::time::Instant::now();
+        let unreconciled_blocks = self.unreconciled_blocks(next_height)?;
+        poa_metrics()
+            .reconciliation_duration_s
+            .observe(reconcile_start.elapsed().as_secs_f64());
+        if unreconciled_blocks.is_empty() {
+            Ok(LeaderState::ReconciledLeader)
+        } else {
+            Ok(LeaderState::UnreconciledBlocks(unreconciled_blocks))
+        }
+    }
+
+    async fn release(&self) -> anyhow::Result<()> {
+        self.node.core.lock().release(Instant::now())?;
+        self.node.recorder.lock().record_release();
+        poa_metrics().is_leader.set(0);
+        Ok(())
+    }
+}
+
+impl BlockReconciliationWritePort for RaftLeaderLeaseAdapter {
+    fn publish_produced_block(&self, block: &SealedBlock) -> anyhow::Result<()> {
+        let receiver = {
+            let mut core = self.node.core.lock();
+            let now = Instant::now();
+            let status = core.lease_status(now);
+            if !status.is_owner {
+                if matches!(block.consensus, Consensus::Genesis(_)) {
+                    tracing::debug!(
+                        "Skipping raft block publish for genesis block because the lease is not owned"
+                    );
+                    return Ok(());
+                }
+                return Err(anyhow!(
+                    "Cannot publish block because this replica doesn't own the Raft leader lease"
+                ));
+            }
+            let command = LeaseCommand::Block {
+                epoch: status.epoch,
+                height: u32::from(*block.entity.header().height()),
+                block: postcard::to_allocvec(block)?,
+            };
+            core.propose(command, now)?
+        };
+        self.node.trigger_replication();
+
+        let write_start = std::time::Instant::now();
+        let result = receiver.recv_timeout(self.node.commit_timeout);
+        poa_metrics()
+            .write_block_duration_s
+            .observe(write_start.elapsed().as_secs_f64());
+        match result {
+            Ok(ApplyResult::Written) => {
+                poa_metrics().write_block_success_total.inc();
+                Ok(())
+            }
+            Ok(ApplyResult::HeightExists) => {
+                poa_metrics().write_block_height_exists_total.inc();
+                Err(anyhow!(
+                    "Cannot publish block because the stream already has a block at its height"
+                ))
+            }
+            Ok(ApplyResult::FencingRejected) => {
+                poa_metrics().write_block_fencing_error_total.inc();
+                Err(anyhow!(
+                    "Cannot publish block because another leader took over the lease"
+                ))
+            }
+            Ok(ApplyResult::Promoted { .. }) | Err(_) => {
+                poa_metrics().write_block_error_total.inc();
+                Err(anyhow!("Failed to commit block to the Raft quorum"))
+            }
+        }
+    }
+}
+
+pub struct UninitializedTask {
+    node: Arc<RaftNode>,
+    listen_addr: SocketAddr,
+    stream_max_len: u32,
+}
+
+pub struct Task {
+    node: Arc<RaftNode>,
+    listener: TcpListener,
+    ticker: Interval,
+    tasks: JoinSet<()>,
+}
+
+#[async_trait::async_trait]
+impl RunnableService for UninitializedTask {
+    const NAME: &'static str = "RaftLeaderLease";
+    type SharedData = RaftLeaderLeaseAdapter;
+    type Task = Task;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        RaftLeaderLeaseAdapter {
+            node: self.node.clone(),
+            stream_max_len: self.stream_max_len,
+        }
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        let listener = TcpListener::bind(self.listen_addr).await?;
+        let mut tasks = JoinSet::new();
+        for peer_id in self.node.peers.keys() {
+            tasks.spawn(self.node.clone().replicate_to(*peer_id));
+        }
+        let mut ticker = tokio::time::interval(self.node.heartbeat_interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        Ok(Task {
+            node: self.node,
+            listener,
+            ticker,
+            tasks,
+        })
+    }
+}
+
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> TaskNextAction {
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                TaskNextAction::Stop
+            }
+
+            accepted = self.listener.accept() => {
+                match accepted {
+                    Ok((stream, _)) => {
+                        if let Err(err) = stream.set_nodelay(true) {
+                            tracing::debug!("Failed to configure the Raft connection: {err}");
+                        }
+                        self.tasks.spawn(self.node.clone().serve_connection(stream));
+                        TaskNextAction::Continue
+                    }
+                    Err(err) => TaskNextAction::ErrorContinue(err.into()),
+                }
+            }
+
+            _ = self.ticker.tick() => {
+                match self.node.tick() {
+                    Ok(Some(request)) => {
+                        self.tasks.spawn(self.node.clone().run_election(request));
+                        TaskNextAction::Continue
+                    }
+                    Ok(None) => TaskNextAction::Continue,
+                    Err(err) => TaskNextAction::ErrorContinue(err),
+                }
+            }
+
+            Some(result) = self.tasks.join_next() => {
+                if let Err(err) = result {
+                    tracing::error!("Raft leader lease task failed: {err}");
+                }
+                TaskNextAction::Continue
+            }
+        }
+    }
+
+    async fn shutdown(mut self) -> anyhow::Result<()> {
+        self.tasks.shutdown().await;
+        self.node.core.lock().release(Instant::now())
+    }
+}
+
+pub fn new_service(
+    config: &RaftLeaderLockConfig,
+) -> anyhow::Result<ServiceRunner<UninitializedTask>> {
+    let peer_ids = config
+        .peers
+        .iter()
+        .map(|peer| peer.id)
+        .collect::<BTreeSet<_>>();
+    if peer_ids.len() != config.peers.len() || peer_ids.contains(&config.node_id) {
+        return Err(anyhow!(
+            "The Raft node ids must be unique within the cluster"
+        ));
+    }
+    if config.heartbeat_interval >= config.election_timeout {
+        return Err(anyhow!(
+            "The Raft heartbeat interval must be shorter than the election timeout"
+        ));
+    }
+
+    let storage = RaftStorage::open(&config.data_dir)?;
+    let key = ClusterKey::new(config.shared_secret.expose_secret());
+    let core = RaftCore::new(
+        config.node_id,
+        peer_ids.into_iter().collect(),
+        CoreConfig {
+            election_timeout: config.election_timeout,
+            stream_max_len: config.stream_max_len,
+        },
+        storage,
+        Instant::now(),
+    );
+    let peers = config
+        .peers
+        .iter()
+        .map(|peer| {
+            let peer_state = Peer {
+                client: PeerClient::new(peer.addr, config.rpc_timeout, key.clone()),
+                replication_trigger: Notify::new(),
+            };
+            (peer.id, peer_state)
+        })
+        .collect();
+    let node = RaftNode {
+        core: Mutex::new(core),
+        peers,
+        key,
+        heartbeat_interval: config.heartbeat_interval,
+        commit_timeout: config.election_timeout,
+        recorder: Mutex::new(LeaseStatusRecorder::default()),
+    };
+    Ok(ServiceRunner::new(UninitializedTask {
+        node: Arc::new(node),
+        listen_addr: config.listen_addr,
+        stream_max_len: config.stream_max_len,
+    }))
+}
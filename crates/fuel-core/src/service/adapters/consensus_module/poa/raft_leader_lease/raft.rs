@@ -0,0 +1,1125 @@
+use super::{
+    state_machine::{
+        ApplyResult,
+        LeaseCommand,
+        LeaseStateMachine,
+        NodeId,
+    },
+    storage::{
+        HardState,
+        LogEntry,
+        RaftStorage,
+        Snapshot,
+    },
+};
+use anyhow::anyhow;
+use rand::Rng;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::{
+    collections::{
+        BTreeMap,
+        BTreeSet,
+        HashMap,
+    },
+    sync::mpsc,
+    time::Duration,
+};
+use tokio::time::Instant;
+
+/// The maximum number of log entries sent in a single `AppendEntries` request.
+const MAX_ENTRIES_PER_REQUEST: usize = 64;
+/// The maximum total size of the blocks sent in a single `AppendEntries` request,
+/// unless the request carries only one entry.
+const MAX_BLOCK_BYTES_PER_REQUEST: usize = 4 * 1024 * 1024;
+/// The minimum number of applied log entries before the log is compacted.
+const MIN_ENTRIES_BEFORE_COMPACTION: u64 = 128;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum RaftRequest {
+    Vote(VoteRequest),
+    AppendEntries(AppendEntriesRequest),
+    InstallSnapshot(InstallSnapshotRequest),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum RaftResponse {
+    Vote(VoteResponse),
+    AppendEntries(AppendEntriesResponse),
+    InstallSnapshot(InstallSnapshotResponse),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VoteRequest {
+    /// The pre-vote doesn't change the term of the voters. It checks that
+    /// the candidate could win the election before disrupting the cluster.
+    pub pre_vote: bool,
+    pub term: u64,
+    pub candidate: NodeId,
+    pub last_log_index: u64,
+    pub last_log_term: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VoteResponse {
+    pub term: u64,
+    pub granted: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppendEntriesRequest {
+    pub term: u64,
+    pub leader: NodeId,
+    pub prev_log_index: u64,
+    pub prev_log_term: u64,
+    pub entries: Vec<LogEntry>,
+    pub leader_commit: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppendEntriesResponse {
+    pub term: u64,
+    pub success: bool,
+    /// The index of the last entry matching the leader's log on success.
+    /// Otherwise, the index after which the leader should retry.
+    pub match_index: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstallSnapshotRequest {
+    pub term: u64,
+    pub leader: NodeId,
+    pub snapshot: Snapshot,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstallSnapshotResponse {
+    pub term: u64,
+    pub match_index: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    Follower,
+    PreCandidate,
+    Candidate,
+    Leader,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct CoreConfig {
+    pub election_timeout: Duration,
+    pub stream_max_len: u32,
+}
+
+/// The state of the lease as seen by the replica.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LeaseStatus {
+    /// The epoch token of the current lease owner.
+    pub epoch: u64,
+    /// The current lease owner, according to the applied log.
+    pub owner: Option<NodeId>,
+    /// `true` if this replica owns the lease and may produce blocks.
+    pub is_owner: bool,
+}
+
+struct Progress {
+    next_index: u64,
+    match_index: u64,
+    last_ack: Option<Instant>,
+}
+
+struct PendingProposal {
+    term: u64,
+    sender: mpsc::Sender<ApplyResult>,
+}
+
+/// The Raft consensus of a single replica, without any I/O except the storage.
+/// The caller delivers the messages between replicas and drives the timers.
+pub struct RaftCore {
+    id: NodeId,
+    peers: Vec<NodeId>,
+    config: CoreConfig,
+    storage: RaftStorage,
+    state: LeaseStateMachine,
+    role: Role,
+    leader: Option<NodeId>,
+    commit_index: u64,
+    last_applied: u64,
+    election_deadline: Instant,
+    leader_contact: Option<Instant>,
+    leader_since: Option<Instant>,
+    votes: BTreeSet<NodeId>,
+    progress: HashMap<NodeId, Progress>,
+    pending: BTreeMap<u64, PendingProposal>,
+}
+
+impl RaftCore {
+    pub fn new(
+        id: NodeId,
+        peers: Vec<NodeId>,
+        config: CoreConfig,
+        storage: RaftStorage,
+        now: Instant,
+    ) -> Self {
+        let snapshot = storage.snapshot();
+        let state = snapshot.state.clone();
+        let last_applied = snapshot.last_index;
+        let mut core = Self {
+            id,
+            peers,
+            config,
+            storage,
+            state,
+            role: Role::Follower,
+            leader: None,
+            commit_index: last_applied,
+            last_applied,
+            election_deadline: now,
+            leader_contact: None,
+            leader_since: None,
+            votes: BTreeSet::new(),
+            progress: HashMap::new(),
+            pending: BTreeMap::new(),
+        };
+        core.reset_election_deadline(now);
+        core
+    }
+
+    pub fn id(&self) -> NodeId {
+        self.id
+    }
+
+    pub fn role(&self) -> Role {
+        self.role
+    }
+
+    pub fn term(&self) -> u64 {
+        self.storage.hard_state().term
+    }
+
+    pub fn state(&self) -> &LeaseStateMachine {
+        &self.state
+    }
+
+    pub fn lease_status(&self, now: Instant) -> LeaseStatus {
+        let is_owner = self.role == Role::Leader
+            && self.state.owner() == Some(self.id)
+            && self.state.owner_term() == self.term()
+            && self.has_quorum_contact(now);
+        LeaseStatus {
+            epoch: self.state.epoch(),
+            owner: self.state.owner(),
+            is_owner,
+        }
+    }
+
+    /// Advances the timers. Returns the vote request to send to every peer
+    /// if the replica starts a new election.
+    pub fn tick(&mut self, now: Instant) -> anyhow::Result<Option<VoteRequest>> {
+        if self.role == Role::Leader {
+            let in_grace_period = self
+                .leader_since
+                .and_then(|since| since.checked_add(self.config.election_timeout))
+                .is_some_and(|grace_deadline| now < grace_deadline);
+            if !in_grace_period && !self.has_quorum_contact(now) {
+                tracing::warn!(
+                    term = self.term(),
+                    "Raft leader lost contact with the quorum, stepping down"
+                );
+                self.become_follower(self.term(), now)?;
+            }
+            return Ok(None);
+        }
+
+        if now < self.election_deadline {
+            return Ok(None);
+        }
+        self.start_pre_vote(now)
+    }
+
+    /// Gives up the leadership and stays away from the elections for a while
+    /// to let another replica take over.
+    pub fn release(&mut self, now: Instant) -> anyhow::Result<()> {
+        if self.role != Role::Leader {
+            return Ok(());
+        }
+        self.become_follower(self.term(), now)?;
+        self.election_deadline = now
+            .checked_add(self.config.election_timeout.saturating_mul(2))
+            .unwrap_or(self.election_deadline);
+        Ok(())
+    }
+
+    /// Appends the `command` to the log of the leader. The returned receiver
+    /// gets the result of the command once it is committed and applied.
+    /// The sender is dropped if the command can't be committed by this leader.
+    pub fn propose(
+        &mut self,
+        command: LeaseCommand,
+        now: Instant,
+    ) -> anyhow::Result<mpsc::Receiver<ApplyResult>> {
+        if self.role != Role::Leader {
+            return Err(anyhow!("This replica is not the Raft leader"));
+        }
+        let term = self.term();
+        let index = self.storage.last_index().saturating_add(1);
+        self.storage.append(vec![LogEntry { term, command }])?;
+        let (sender, receiver) = mpsc::channel();
+        self.pending.insert(index, PendingProposal { term, sender });
+        self.advance_commit(now)?;
+        Ok(receiver)
+    }
+
+    pub fn handle_request(
+        &mut self,
+        request: RaftRequest,
+        now: Instant,
+    ) -> anyhow::Result<RaftResponse> {
+        let response = match request {
+            RaftRequest::Vote(request) => {
+                RaftResponse::Vote(self.handle_vote_request(request, now)?)
+            }
+            RaftRequest::AppendEntries(request) => {
+                RaftResponse::AppendEntries(self.handle_append_entries(request, now)?)
+            }
+            RaftRequest::InstallSnapshot(request) => {
+                RaftResponse::InstallSnapshot(self.handle_install_snapshot(request, now)?)
+            }
+        };
+        Ok(response)
+    }
+
+    /// Returns the request replicating the log to the `peer`, if this replica
+    /// is the leader.
+    pub fn replication_request(&self, peer: NodeId) -> Option<RaftRequest> {
+        if self.role != Role::Leader {
+            return None;
+        }
+        let progress = self.progress.get(&peer)?;
+        let snapshot = self.storage.snapshot();
+        if progress.next_index <= snapshot.last_index {
+            return Some(RaftRequest::InstallSnapshot(InstallSnapshotRequest {
+                term: self.term(),
+                leader: self.id,
+                snapshot: snapshot.clone(),
+            }));
+        }
+        let prev_log_index = progress.next_index.saturating_sub(1);
+        let prev_log_term = self.storage.term_at(prev_log_index)?;
+        Some(RaftRequest::AppendEntries(AppendEntriesRequest {
+            term: self.term(),
+            leader: self.id,
+            prev_log_index,
+            prev_log_term,
+            entries: self.storage.entries_from(
+                progress.next_index,
+                MAX_ENTRIES_PER_REQUEST,
+                MAX_BLOCK_BYTES_PER_REQUEST,
+            ),
+            leader_commit: self.commit_index,
+        }))
+    }
+
+    /// Returns `true` if the leader has log entries not yet sent to the `peer`.
+    pub fn has_pending_replication(&self, peer: NodeId) -> bool {
+        self.role == Role::Leader
+            && self
+                .progress
+                .get(&peer)
+                .is_some_and(|progress| progress.next_index <= self.storage.last_index())
+    }
+
+    /// Handles the response of the `peer` to the request sent at `request_term`.
+    ///
+    /// The peer acknowledged the leader no earlier than the request was `sent_at`,
+    /// so the lease of the leader is counted from that instant.
+    pub fn handle_response(
+        &mut self,
+        peer: NodeId,
+        request_term: u64,
+        response: RaftResponse,
+        sent_at: Instant,
+        now: Instant,
+    ) -> anyhow::Result<()> {
+        let (term, match_index, success) = match response {
+            RaftResponse::AppendEntries(response) => {
+                (response.term, response.match_index, response.success)
+            }
+            RaftResponse::InstallSnapshot(response) => {
+                (response.term, response.match_index, true)
+            }
+            RaftResponse::Vote(_) => {
+                return Err(anyhow!("Unexpected vote response to the replication"));
+            }
+        };
+        if term > self.term() {
+            return self.become_follower(term, now);
+        }
+        if self.role != Role::Leader || request_term != self.term() {
+            return Ok(());
+        }
+        let Some(progress) = self.progress.get_mut(&peer) else {
+            return Ok(());
+        };
+        progress.last_ack = progress.last_ack.max(Some(sent_at));
+        if success {
+            progress.match_index = progress.match_index.max(match_index);
+            progress.next_index = progress.match_index.saturating_add(1);
+            self.advance_commit(now)?;
+        } else {
+            progress.next_index = progress
+                .next_index
+                .saturating_sub(1)
+                .min(match_index.saturating_add(1))
+                .max(progress.match_index.saturating_add(1));
+        }
+        Ok(())
+    }
+
+    /// Handles the vote of the `peer` for the `request`. Returns the next
+    /// vote request if the pre-vote succeeded and the election starts.
+    pub fn handle_vote_response(
+        &mut self,
+        peer: NodeId,
+        request: &VoteRequest,
+        response: VoteResponse,
+        now: Instant,
+    ) -> anyhow::Result<Option<VoteRequest>> {
+        if response.term > self.term() && !response.granted {
+            self.become_follower(response.term, now)?;
+            return Ok(None);
+        }
+        let expected = if request.pre_vote {
+            self.role == Role::PreCandidate
+                && request.term == self.term().saturating_add(1)
+        } else {
+            self.role == Role::Candidate && request.term == self.term()
+        };
+        if !expected || !response.granted {
+            return Ok(None);
+        }
+        self.votes.insert(peer);
+        if !self.has_majority(self.votes.len()) {
+            return Ok(None);
+        }
+        if request.pre_vote {
+            self.start_election(now)
+        } else {
+            self.become_leader(now)?;
+            Ok(None)
+        }
+    }
+
+    fn handle_vote_request(
+        &mut self,
+        request: VoteRequest,
+        now: Instant,
+    ) -> anyhow::Result<VoteResponse> {
+        let term = self.term();
+        let rejected = VoteResponse {
+            term,
+            granted: false,
+        };
+        // The replicas don't vote while they hear from the leader, so a replica
+        // rejoining after a partition doesn't depose the healthy leader.
+        if request.term < term || self.has_recent_leader(now) {
+            return Ok(rejected);
+        }
+        let log_is_up_to_date = (request.last_log_term, request.last_log_index)
+            >= (self.storage.last_term(), self.storage.last_index());
+
+        if request.pre_vote {
+            return Ok(VoteResponse {
+                term,
+                granted: request.term > term && log_is_up_to_date,
+            });
+        }
+
+        if request.term > term {
+            self.become_follower(request.term, now)?;
+        }
+        let hard_state = self.storage.hard_state();
+        let can_vote = hard_state
+            .voted_for
+            .is_none_or(|voted_for| voted_for == request.candidate);
+        if !can_vote || !log_is_up_to_date {
+            return Ok(VoteResponse {
+                term: self.term(),
+                granted: false,
+            });
+        }
+        self.storage.set_hard_state(HardState {
+            term: request.term,
+            voted_for: Some(request.candidate),
+        })?;
+        self.reset_election_deadline(now);
+        Ok(VoteResponse {
+            term: self.term(),
+            granted: true,
+        })
+    }
+
+    fn handle_append_entries(
+        &mut self,
+        request: AppendEntriesRequest,
+        now: Instant,
+    ) -> anyhow::Result<AppendEntriesResponse> {
+        if request.term < self.term() {
+            return Ok(AppendEntriesResponse {
+                term: self.term(),
+                success: false,
+                match_index: 0,
+            });
+        }
+        self.follow(request.term, request.leader, now)?;
+
+        let term = self.term();
+        let rejected = |match_index| AppendEntriesResponse {
+            term,
+            success: false,
+            match_index,
+        };
+        let snapshot_last_index = self.storage.snapshot().last_index;
+        if request.prev_log_index > self.storage.last_index() {
+            return Ok(rejected(self.storage.last_index()));
+        }
+        if request.prev_log_index >= snapshot_last_index {
+            match self.storage.term_at(request.prev_log_index) {
+                Some(prev_log_term) if prev_log_term == request.prev_log_term => {}
+                Some(conflicting_term) => {
+                    // Skips the whole conflicting term at once.
+                    let mut index = request.prev_log_index;
+                    while index > snapshot_last_index.saturating_add(1)
+                        && self.storage.term_at(index.saturating_sub(1))
+                            == Some(conflicting_term)
+                    {
+                        index = index.saturating_sub(1);
+                    }
+                    return Ok(rejected(index.saturating_sub(1)));
+                }
+                None => return Ok(rejected(snapshot_last_index)),
+            }
+        }
+
+        let last_new_index = request
+            .prev_log_index
+            .saturating_add(u64::try_from(request.entries.len())?);
+        let mut index = request.prev_log_index;
+        let mut new_entries = Vec::new();
+        for entry in request.entries {
+            index = index.saturating_add(1);
+            if !new_entries.is_empty() {
+                new_entries.push(entry);
+                continue;
+            }
+            if index <= snapshot_last_index {
+                continue;
+            }
+            match self.storage.term_at(index) {
+                Some(existing_term) if existing_term == entry.term => {}
+                Some(_) => {
+                    self.storage.truncate_from(index)?;
+                    new_entries.push(entry);
+                }
+                None => new_entries.push(entry),
+            }
+        }
+        self.storage.append(new_entries)?;
+
+        let commit_index = request.leader_commit.min(last_new_index);
+        if commit_index > self.commit_index {
+            self.commit_index = commit_index;
+            self.apply_committed()?;
+        }
+        Ok(AppendEntriesResponse {
+            term,
+            success: true,
+            match_index: last_new_index,
+        })
+    }
+
+    fn handle_install_snapshot(
+        &mut self,
+        request: InstallSnapshotRequest,
+        now: Instant,
+    ) -> anyhow::Result<InstallSnapshotResponse> {
+        if request.term < self.term() {
+            return Ok(InstallSnapshotResponse {
+                term: self.term(),
+                match_index: 0,
+            });
+        }
+        self.follow(request.term, request.leader, now)?;
+
+        let last_index = request.snapshot.last_index;
+        if last_index > self.commit_index {
+            self.state = request.snapshot.state.clone();
+            self.storage.install_snapshot(request.snapshot)?;
+            self.commit_index = last_index;
+            self.last_applied = last_index;
+        }
+        Ok(InstallSnapshotResponse {
+            term: self.term(),
+            match_index: last_index,
+        })
+    }
+
+    fn start_pre_vote(&mut self, now: Instant) -> anyhow::Result<Option<VoteRequest>> {
+        self.role = Role::PreCandidate;
+        self.leader = None;
+        self.votes = BTreeSet::from([self.id]);
+        self.reset_election_deadline(now);
+        if self.has_majority(self.votes.len()) {
+            return self.start_election(now);
+        }
+        Ok(Some(VoteRequest {
+            pre_vote: true,
+            term: self.term().saturating_add(1),
+            candidate: self.id,
+            last_log_index: self.storage.last_index(),
+            last_log_term: self.storage.last_term(),
+        }))
+    }
+
+    fn start_election(&mut self, now: Instant) -> anyhow::Result<Option<VoteRequest>> {
+        let term = self.term().saturating_add(1);
+        self.storage.set_hard_state(HardState {
+            term,
+            voted_for: Some(self.id),
+        })?;
+        self.role = Role::Candidate;
+        self.votes = BTreeSet::from([self.id]);
+        self.reset_election_deadline(now);
+        tracing::debug!(term, id = self.id, "Raft replica starts the election");
+        if self.has_majority(self.votes.len()) {
+            self.become_leader(now)?;
+            return Ok(None);
+        }
+        Ok(Some(VoteRequest {
+            pre_vote: false,
+            term,
+            candidate: self.id,
+            last_log_index: self.storage.last_index(),
+            last_log_term: self.storage.last_term(),
+        }))
+    }
+
+    fn become_leader(&mut self, now: Instant) -> anyhow::Result<()> {
+        tracing::info!(
+            term = self.term(),
+            id = self.id,
+            "Raft replica became the leader"
+        );
+        self.role = Role::Leader;
+        self.leader = Some(self.id);
+        self.leader_since = Some(now);
+        self.votes.clear();
+        let next_index = self.storage.last_index().saturating_add(1);
+        self.progress = self
+            .peers
+            .iter()
+            .map(|peer| {
+                let progress = Progress {
+                    next_index,
+                    match_index: 0,
+                    last_ack: None,
+                };
+                (*peer, progress)
+            })
+            .collect();
+        // The leader commits the entries of the previous terms by committing
+        // the entry of its own term. The same entry transfers the lease.
+        self.storage.append(vec![LogEntry {
+            term: self.term(),
+            command: LeaseCommand::Promote { owner: self.id },
+        }])?;
+        self.advance_commit(now)
+    }
+
+    fn become_follower(&mut self, term: u64, now: Instant) -> anyhow::Result<()> {
+        if term > self.term() {
+            self.storage.set_hard_state(HardState {
+                term,
+                voted_for: None,
+            })?;
+        }
+        if self.role == Role::Leader {
+            tracing::info!(term, id = self.id, "Raft replica stepped down");
+        }
+        self.role = Role::Follower;
+        self.leader = None;
+        self.leader_since = None;
+        self.votes.clear();
+        self.progress.clear();
+        // The proposals of the previous leadership are never acknowledged.
+        self.pending.clear();
+        self.reset_election_deadline(now);
+        Ok(())
+    }
+
+    fn follow(&mut self, term: u64, leader: NodeId, now: Instant) -> anyhow::Result<()> {
+        if term > self.term() || self.role != Role::Follower {
+            self.become_follower(term, now)?;
+        }
+        self.leader = Some(leader);
+        self.leader_contact = Some(now);
+        self.reset_election_deadline(now);
+        Ok(())
+    }
+
+    fn advance_commit(&mut self, now: Instant) -> anyhow::Result<()> {
+        if self.role != Role::Leader {
+            return Ok(());
+        }
+        let mut match_indexes = self
+            .progress
+            .values()
+            .map(|progress| progress.match_index)
+            .chain(std::iter::once(self.storage.last_index()))
+            .collect::<Vec<_>>();
+        match_indexes.sort_unstable_by(|a, b| b.cmp(a));
+        let Some(quorum_index) = match_indexes.get(self.quorum().saturating_sub(1))
+        else {
+            return Ok(());
+        };
+        // Only the entries of the current term are committed by counting replicas.
+        if *quorum_index > self.commit_index
+            && self.storage.term_at(*quorum_index) == Some(self.term())
+        {
+            self.commit_index = *quorum_index;
+            self.apply_committed()?;
+        }
+        let _ = now;
+        Ok(())
+    }
+
+    fn apply_committed(&mut self) -> anyhow::Result<()> {
+        while self.last_applied < self.commit_index {
+            let index = self.last_applied.saturating_add(1);
+            let entry = self
+                .storage
+                .entry(index)
+                .cloned()
+                .ok_or(anyhow!("The committed Raft entry {index} is missing"))?;
+            let result =
+                self.state
+                    .apply(entry.term, &entry.command, self.config.stream_max_len);
+            self.last_applied = index;
+            if let Some(pending) = self.pending.remove(&index)
+                && pending.term == entry.term
+            {
+                let _ = pending.sender.send(result);
+            }
+        }
+        self.maybe_compact()
+    }
+
+    fn maybe_compact(&mut self) -> anyhow::Result<()> {
+        let threshold =
+            u64::from(self.config.stream_max_len).max(MIN_ENTRIES_BEFORE_COMPACTION);
+        let applied_since_snapshot = self
+            .last_applied
+            .saturating_sub(self.storage.snapshot().last_index);
+        if applied_since_snapshot <= threshold {
+            return Ok(());
+        }
+        let Some(last_term) = self.storage.term_at(self.last_applied) else {
+            return Ok(());
+        };
+        self.storage.compact(Snapshot {
+            last_index: self.last_applied,
+            last_term,
+            state: self.state.clone(),
+        })
+    }
+
+    fn has_recent_leader(&self, now: Instant) -> bool {
+        match self.role {
+            Role::Leader => self.has_quorum_contact(now),
+            _ => {
+                self.leader.is_some()
+                    && self
+                        .leader_contact
+                        .and_then(|contact| {
+                            contact.checked_add(self.config.election_timeout)
+                        })
+                        .is_some_and(|deadline| now < deadline)
+            }
+        }
+    }
+
+    /// Returns `true` if the quorum acknowledged this leader
+    /// within the election timeout.
+    fn has_quorum_contact(&self, now: Instant) -> bool {
+        let acknowledged = self
+            .progress
+            .values()
+            .filter_map(|progress| progress.last_ack)
+            .filter(|ack| {
+                ack.checked_add(self.config.election_timeout)
+                    .is_some_and(|deadline| now < deadline)
+            })
+            .count();
+        self.has_majority(acknowledged.saturating_add(1))
+    }
+
+    /// The number of replicas required for the quorum.
+    pub fn quorum(&self) -> usize {
+        self.peers
+            .len()
+            .saturating_add(1)
+            .checked_div(2)
+            .unwrap_or(0)
+            .saturating_add(1)
+    }
+
+    fn has_majority(&self, votes: usize) -> bool {
+        votes >= self.quorum()
+    }
+
+    fn reset_election_deadline(&mut self, now: Instant) {
+        let election_timeout = self.config.election_timeout;
+        let jitter_millis =
+            u64::try_from(election_timeout.as_millis()).unwrap_or(u64::MAX);
+        let jitter =
+            Duration::from_millis(rand::thread_rng().gen_range(0..=jitter_millis.max(1)));
+        self.election_deadline = now
+            .checked_add(election_timeout.saturating_add(jitter))
+            .unwrap_or(now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ELECTION_TIMEOUT: Duration = Duration::from_millis(100);
+
+    struct Cluster {
+        replicas: BTreeMap<NodeId, RaftCore>,
+        /// The pairs of replicas that can't reach each other.
+        partitions: BTreeSet<(NodeId, NodeId)>,
+        now: Instant,
+    }
+
+    impl Cluster {
+        fn new(size: u64, stream_max_len: u32) -> Self {
+            let now = Instant::now();
+            let ids = (1..=size).collect::<Vec<_>>();
+            let replicas = ids
+                .iter()
+                .map(|id| {
+                    let peers = ids.iter().copied().filter(|peer| peer != id).collect();
+                    let config = CoreConfig {
+                        election_timeout: ELECTION_TIMEOUT,
+                        stream_max_len,
+                    };
+                    let core =
+                        RaftCore::new(*id, peers, config, RaftStorage::in_memory(), now);
+                    (*id, core)
+                })
+                .collect();
+            Self {
+                replicas,
+                partitions: BTreeSet::new(),
+                now,
+            }
+        }
+
+        fn replica(&mut self, id: NodeId) -> &mut RaftCore {
+            self.replicas.get_mut(&id).unwrap()
+        }
+
+        fn isolate(&mut self, id: NodeId) {
+            for peer in self.replicas.keys().copied() {
+                self.partitions.insert((id, peer));
+                self.partitions.insert((peer, id));
+            }
+        }
+
+        fn heal(&mut self) {
+            self.partitions.clear();
+        }
+
+        fn connected(&self, from: NodeId, to: NodeId) -> bool {
+            !self.partitions.contains(&(from, to))
+        }
+
+        fn advance(&mut self, duration: Duration) {
+            self.now = self.now.checked_add(duration).unwrap();
+        }
+
+        /// Forces the `candidate` to run the election and delivers the votes.
+        fn elect(&mut self, candidate: NodeId) {
+            let now = self.now;
+            let mut request = self.replica(candidate).start_pre_vote(now).unwrap();
+            while let Some(vote_request) = request.take() {
+                let peers = self.replica(candidate).peers.clone();
+                for peer in peers {
+                    if !self.connected(candidate, peer) {
+                        continue;
+                    }
+                    let response = self
+                        .replica(peer)
+                        .handle_request(RaftRequest::Vote(vote_request.clone()), now)
+                        .unwrap();
+                    let RaftResponse::Vote(response) = response else {
+                        panic!("Unexpected response");
+                    };
+                    if let Some(next) = self
+                        .replica(candidate)
+                        .handle_vote_response(peer, &vote_request, response, now)
+                        .unwrap()
+                    {
+                        request = Some(next);
+                        break;
+                    }
+                }
+            }
+        }
+
+        /// Delivers one round of the replication from every leader.
+        fn replicate(&mut self) {
+            let now = self.now;
+            let ids = self.replicas.keys().copied().collect::<Vec<_>>();
+            for leader in ids.iter().copied() {
+                let peers = self.replica(leader).peers.clone();
+                for peer in peers {
+                    if !self.connected(leader, peer) {
+                        continue;
+                    }
+                    let Some(request) = self.replica(leader).replication_request(peer)
+                    else {
+                        continue;
+                    };
+                    let term = self.replica(leader).term();
+                    let response =
+                        self.replica(peer).handle_request(request, now).unwrap();
+                    self.replica(leader)
+                        .handle_response(peer, term, response, now, now)
+                        .unwrap();
+                }
+            }
+        }
+
+        fn replicate_rounds(&mut self, rounds: usize) {
+            for _ in 0..rounds {
+                self.replicate();
+            }
+        }
+    }
+
+    fn block(epoch: u64, height: u32) -> LeaseCommand {
+        LeaseCommand::Block {
+            epoch,
+            height,
+            block: height.to_le_bytes().to_vec(),
+        }
+    }
+
+    #[test]
+    fn tick__when_single_replica_then_becomes_lease_owner() {
+        // given
+        let mut cluster = Cluster::new(1, 10);
+        cluster.advance(ELECTION_TIMEOUT.saturating_mul(3));
+        let now = cluster.now;
+
+        // when
+        let request = cluster.replica(1).tick(now).unwrap();
+
+        // then
+        assert_eq!(request, None);
+        let status = cluster.replica(1).lease_status(now);
+        assert!(status.is_owner);
+        assert_eq!(status.epoch, 1);
+    }
+
+    #[test]
+    fn lease_status__when_leader_elected_then_owns_lease_after_promotion_is_committed() {
+        // given
+        let mut cluster = Cluster::new(3, 10);
+        cluster.elect(1);
+        let now = cluster.now;
+        assert_eq!(cluster.replica(1).role(), Role::Leader);
+        assert!(!cluster.replica(1).lease_status(now).is_owner);
+
+        // when
+        cluster.replicate_rounds(2);
+
+        // then
+        assert!(cluster.replica(1).lease_status(now).is_owner);
+        assert_eq!(cluster.replica(2).state().owner(), Some(1));
+        assert_eq!(cluster.replica(3).state().owner(), Some(1));
+        assert!(!cluster.replica(2).lease_status(now).is_owner);
+    }
+
+    #[test]
+    fn lease_status__when_acknowledgements_are_late_then_counts_lease_from_requests() {
+        // given
+        let mut cluster = Cluster::new(3, 10);
+        cluster.elect(1);
+        cluster.replicate_rounds(2);
+        let sent_at = cluster.now;
+        cluster.advance(ELECTION_TIMEOUT);
+        let now = cluster.now;
+
+        // when
+        for peer in [2, 3] {
+            let request = cluster.replica(1).replication_request(peer).unwrap();
+            let term = cluster.replica(1).term();
+            let response = cluster
+                .replica(peer)
+                .handle_request(request, sent_at)
+                .unwrap();
+            cluster
+                .replica(1)
+                .handle_response(peer, term, response, sent_at, now)
+                .unwrap();
+        }
+
+        // then
+        assert!(!cluster.replica(1).lease_status(now).is_owner);
+    }
+
+    #[test]
+    fn propose__when_majority_replicates_block_then_returns_written() {
+        // given
+        let mut cluster = Cluster::new(3, 10);
+        cluster.elect(1);
+        cluster.replicate_rounds(2);
+        cluster.isolate(3);
+        let now = cluster.now;
+        let epoch = cluster.replica(1).lease_status(now).epoch;
+
+        // when
+        let receiver = cluster.replica(1).propose(block(epoch, 1), now).unwrap();
+        cluster.replicate_rounds(2);
+
+        // then
+        assert_eq!(receiver.try_recv(), Ok(ApplyResult::Written));
+        assert_eq!(cluster.replica(2).state().latest_height(), Some(1));
+        assert_eq!(cluster.replica(3).state().latest_height(), None);
+    }
+
+    #[test]
+    fn propose__when_leader_is_deposed_then_its_block_is_never_written() {
+        // given
+        let mut cluster = Cluster::new(3, 10);
+        cluster.elect(1);
+        cluster.replicate_rounds(2);
+        let now = cluster.now;
+        let epoch = cluster.replica(1).lease_status(now).epoch;
+        cluster.isolate(1);
+        let receiver = cluster.replica(1).propose(block(epoch, 1), now).unwrap();
+
+        // when
+        cluster.advance(ELECTION_TIMEOUT.saturating_mul(3));
+        cluster.elect(2);
+        cluster.replicate_rounds(2);
+        cluster.heal();
+        cluster.replicate_rounds(2);
+
+        // then
+        assert!(receiver.try_recv().is_err());
+        assert_eq!(cluster.replica(1).role(), Role::Follower);
+        assert_eq!(cluster.replica(1).state().owner(), Some(2));
+        assert_eq!(cluster.replica(1).state().latest_height(), None);
+        assert_eq!(cluster.replica(1).storage.last_index(), 2);
+    }
+
+    #[test]
+    fn handle_vote_request__when_leader_is_alive_then_rejects_rejoining_candidate() {
+        // given
+        let mut cluster = Cluster::new(3, 10);
+        cluster.elect(1);
+        cluster.replicate_rounds(2);
+        cluster.isolate(3);
+        cluster.advance(ELECTION_TIMEOUT.saturating_mul(3));
+        cluster.replicate_rounds(1);
+        let now = cluster.now;
+        let _ = cluster.replica(3).tick(now).unwrap();
+        let _ = cluster.replica(3).tick(now).unwrap();
+        cluster.heal();
+
+        // when
+        cluster.elect(3);
+
+        // then
+        assert_eq!(cluster.replica(1).role(), Role::Leader);
+        assert_ne!(cluster.replica(3).role(), Role::Leader);
+        assert!(cluster.replica(1).lease_status(now).is_owner);
+    }
+
+    #[test]
+    fn replication_request__when_follower_is_behind_compacted_log_then_installs_snapshot()
+    {
+        // given
+        let mut cluster = Cluster::new(3, 2);
+        cluster.elect(1);
+        cluster.replicate_rounds(2);
+        cluster.isolate(3);
+        let now = cluster.now;
+        let epoch = cluster.replica(1).lease_status(now).epoch;
+        let blocks = u32::try_from(MIN_ENTRIES_BEFORE_COMPACTION).unwrap();
+        for height in 1..=blocks.saturating_add(10) {
+            let _ = cluster
+                .replica(1)
+                .propose(block(epoch, height), now)
+                .unwrap();
+            cluster.replicate();
+        }
+        cluster.replicate_rounds(2);
+        assert!(cluster.replica(1).storage.snapshot().last_index > 0);
+
+        // when
+        cluster.heal();
+        cluster.replicate_rounds(4);
+
+        // then
+        let leader_state = cluster.replica(1).state().clone();
+        let leader_commit_index = cluster.replica(1).commit_index;
+        assert_eq!(cluster.replica(3).state(), &leader_state);
+        assert_eq!(cluster.replica(3).commit_index, leader_commit_index);
+    }
+
+    #[test]
+    fn handle_append_entries__when_follower_has_conflicting_entries_then_replaces_them() {
+        // given
+        let mut cluster = Cluster::new(3, 10);
+        cluster.elect(1);
+        cluster.replicate_rounds(2);
+        let now = cluster.now;
+        let stale_epoch = cluster.replica(1).lease_status(now).epoch;
+        cluster.isolate(1);
+        let _ = cluster
+            .replica(1)
+            .propose(block(stale_epoch, 1), now)
+            .unwrap();
+        cluster.advance(ELECTION_TIMEOUT.saturating_mul(3));
+        cluster.elect(2);
+        cluster.replicate_rounds(2);
+        let now = cluster.now;
+        let epoch = cluster.replica(2).lease_status(now).epoch;
+        let receiver = cluster.replica(2).propose(block(epoch, 1), now).unwrap();
+        cluster.replicate_rounds(2);
+        assert_eq!(receiver.try_recv(), Ok(ApplyResult::Written));
+
+        // when
+        cluster.heal();
+        cluster.replicate_rounds(3);
+
+        // then
+        let leader_last_index = cluster.replica(2).storage.last_index();
+        let entries = cluster
+            .replica(1)
+            .state()
+            .blocks_from(1)
+            .collect::<Vec<_>>();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].1.epoch, epoch);
+        assert_eq!(cluster.replica(1).storage.last_index(), leader_last_index);
+    }
+}
@@ -0,0 +1,210 @@
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::collections::BTreeMap;
+
+/// The identifier of the producer replica in the Raft cluster.
+pub type NodeId = u64;
+
+/// The command replicated through the Raft log.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LeaseCommand {
+    /// Appended by a newly elected leader at the start of its term.
+    /// Bumps the epoch token and transfers the lease to the `owner`.
+    Promote { owner: NodeId },
+    /// The block produced by the lease owner while it held the `epoch` token.
+    Block {
+        epoch: u64,
+        height: u32,
+        block: Vec<u8>,
+    },
+}
+
+/// The outcome of applying a [`LeaseCommand`] to the [`LeaseStateMachine`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ApplyResult {
+    /// The lease was transferred, and the epoch token was bumped.
+    Promoted { epoch: u64 },
+    /// The block was appended to the block stream.
+    Written,
+    /// The block stream already contains a block at this height.
+    HeightExists,
+    /// The block was produced with a stale epoch token.
+    FencingRejected,
+}
+
+/// The block stored in the replicated block stream.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StreamEntry {
+    pub epoch: u64,
+    pub block: Vec<u8>,
+}
+
+/// The state replicated by the Raft log: the lease owner with its epoch token
+/// and the stream of the recently produced blocks.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LeaseStateMachine {
+    epoch: u64,
+    owner: Option<NodeId>,
+    /// The Raft term of the log entry that promoted the current owner.
+    owner_term: u64,
+    blocks: BTreeMap<u32, StreamEntry>,
+}
+
+impl LeaseStateMachine {
+    /// Applies the `command` committed at the Raft `term`.
+    /// The block stream keeps at most `stream_max_len` latest blocks.
+    pub fn apply(
+        &mut self,
+        term: u64,
+        command: &LeaseCommand,
+        stream_max_len: u32,
+    ) -> ApplyResult {
+        match command {
+            LeaseCommand::Promote { owner } => {
+                self.epoch = self.epoch.saturating_add(1);
+                self.owner = Some(*owner);
+                self.owner_term = term;
+                ApplyResult::Promoted { epoch: self.epoch }
+            }
+            LeaseCommand::Block {
+                epoch,
+                height,
+                block,
+            } => {
+                if *epoch != self.epoch {
+                    return ApplyResult::FencingRejected;
+                }
+                if self.blocks.contains_key(height) {
+                    return ApplyResult::HeightExists;
+                }
+                self.blocks.insert(
+                    *height,
+                    StreamEntry {
+                        epoch: *epoch,
+                        block: block.clone(),
+                    },
+                );
+                let max_len = usize::try_from(stream_max_len).unwrap_or(usize::MAX);
+                while self.blocks.len() > max_len {
+                    self.blocks.pop_first();
+                }
+                ApplyResult::Written
+            }
+        }
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    pub fn owner(&self) -> Option<NodeId> {
+        self.owner
+    }
+
+    pub fn owner_term(&self) -> u64 {
+        self.owner_term
+    }
+
+    /// Returns the height of the earliest block in the stream.
+    pub fn earliest_height(&self) -> Option<u32> {
+        self.blocks.first_key_value().map(|(height, _)| *height)
+    }
+
+    /// Returns the height of the latest block in the stream.
+    pub fn latest_height(&self) -> Option<u32> {
+        self.blocks.last_key_value().map(|(height, _)| *height)
+    }
+
+    /// Returns the consecutive blocks of the stream starting at `height`.
+    pub fn blocks_from(&self, height: u32) -> impl Iterator<Item = (u32, &StreamEntry)> {
+        self.blocks
+            .range(height..)
+            .zip(height..)
+            .take_while(|((height, _), expected)| *height == expected)
+            .map(|((height, entry), _)| (*height, entry))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(epoch: u64, height: u32) -> LeaseCommand {
+        LeaseCommand::Block {
+            epoch,
+            height,
+            block: height.to_le_bytes().to_vec(),
+        }
+    }
+
+    #[test]
+    fn apply__when_promoted_then_bumps_epoch_and_transfers_lease() {
+        // given
+        let mut state = LeaseStateMachine::default();
+
+        // when
+        let first = state.apply(1, &LeaseCommand::Promote { owner: 1 }, 10);
+        let second = state.apply(3, &LeaseCommand::Promote { owner: 2 }, 10);
+
+        // then
+        assert_eq!(first, ApplyResult::Promoted { epoch: 1 });
+        assert_eq!(second, ApplyResult::Promoted { epoch: 2 });
+        assert_eq!(state.owner(), Some(2));
+        assert_eq!(state.owner_term(), 3);
+    }
+
+    #[test]
+    fn apply__when_block_has_stale_epoch_then_rejects_it() {
+        // given
+        let mut state = LeaseStateMachine::default();
+        state.apply(1, &LeaseCommand::Promote { owner: 1 }, 10);
+        state.apply(2, &LeaseCommand::Promote { owner: 2 }, 10);
+
+        // when
+        let result = state.apply(2, &block(1, 1), 10);
+
+        // then
+        assert_eq!(result, ApplyResult::FencingRejected);
+        assert_eq!(state.latest_height(), None);
+    }
+
+    #[test]
+    fn apply__when_height_exists_then_rejects_block() {
+        // given
+        let mut state = LeaseStateMachine::default();
+        state.apply(1, &LeaseCommand::Promote { owner: 1 }, 10);
+        assert_eq!(state.apply(1, &block(1, 1), 10), ApplyResult::Written);
+
+        // when
+        let result = state.apply(1, &block(1, 1), 10);
+
+        // then
+        assert_eq!(result, ApplyResult::HeightExists);
+    }
+
+    #[test]
+    fn apply__when_stream_exceeds_max_len_then_trims_oldest_blocks() {
+        // given
+        let mut state = LeaseStateMachine::default();
+        state.apply(1, &LeaseCommand::Promote { owner: 1 }, 3);
+
+        // when
+        for height in 1..=5 {
+            state.apply(1, &block(1, height), 3);
+        }
+
+        // then
+        let heights = state
+            .blocks_from(0)
+            .map(|(height, _)| height)
+            .collect::<Vec<_>>();
+        assert!(heights.is_empty());
+        let heights = state
+            .blocks_from(3)
+            .map(|(height, _)| height)
+            .collect::<Vec<_>>();
+        assert_eq!(heights, vec![3, 4, 5]);
+    }
+}
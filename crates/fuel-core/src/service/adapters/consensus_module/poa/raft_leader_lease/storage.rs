@@ -0,0 +1,478 @@
+use super::state_machine::{
+    LeaseCommand,
+    LeaseStateMachine,
+    NodeId,
+};
+use anyhow::anyhow;
+use serde::{
+    Deserialize,
+    Serialize,
+    de::DeserializeOwned,
+};
+use std::{
+    fs,
+    io::Write,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+const HARD_STATE_FILE: &str = "hard_state";
+const SNAPSHOT_FILE: &str = "snapshot";
+const LOG_FILE: &str = "log";
+
+/// The size of the log file header storing the index of the first entry.
+const LOG_HEADER_SIZE: usize = 8;
+/// The size of the length prefix of each log entry in the log file.
+const FRAME_LENGTH_SIZE: usize = 4;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogEntry {
+    pub term: u64,
+    pub command: LeaseCommand,
+}
+
+/// The compacted prefix of the Raft log.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub last_index: u64,
+    pub last_term: u64,
+    pub state: LeaseStateMachine,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HardState {
+    pub term: u64,
+    pub voted_for: Option<NodeId>,
+}
+
+/// The durable state of the Raft replica: the current term with the vote,
+/// the snapshot of the compacted log prefix and the log entries after it.
+///
+/// When the storage is opened from a directory, every mutation is flushed
+/// to disk before returning, so the replica never forgets a vote or
+/// an acknowledged entry after a restart.
+pub struct RaftStorage {
+    hard_state: HardState,
+    snapshot: Snapshot,
+    /// `entries[i]` is the log entry at `snapshot.last_index + 1 + i`.
+    entries: Vec<LogEntry>,
+    directory: Option<PathBuf>,
+    log_file: Option<fs::File>,
+}
+
+impl RaftStorage {
+    /// Creates the storage that keeps the state only in memory.
+    #[cfg(test)]
+    pub fn in_memory() -> Self {
+        Self {
+            hard_state: HardState::default(),
+            snapshot: Snapshot::default(),
+            entries: Vec::new(),
+            directory: None,
+            log_file: None,
+        }
+    }
+
+    /// Opens the storage persisted in the `directory`, creating it if needed.
+    pub fn open(directory: &Path) -> anyhow::Result<Self> {
+        fs::create_dir_all(directory)?;
+        let hard_state: HardState =
+            read_value(&directory.join(HARD_STATE_FILE))?.unwrap_or_default();
+        let snapshot: Snapshot =
+            read_value(&directory.join(SNAPSHOT_FILE))?.unwrap_or_default();
+        let entries = read_log(&directory.join(LOG_FILE), snapshot.last_index)?;
+
+        let mut storage = Self {
+            hard_state,
+            snapshot,
+            entries,
+            directory: Some(directory.to_path_buf()),
+            log_file: None,
+        };
+        // Drops the entries covered by the snapshot and the torn tail, if any.
+        storage.rewrite_log()?;
+        Ok(storage)
+    }
+
+    pub fn hard_state(&self) -> HardState {
+        self.hard_state
+    }
+
+    pub fn set_hard_state(&mut self, hard_state: HardState) -> anyhow::Result<()> {
+        if self.hard_state == hard_state {
+            return Ok(());
+        }
+        if let Some(directory) = &self.directory {
+            write_atomically(
+                &directory.join(HARD_STATE_FILE),
+                &postcard::to_allocvec(&hard_state)?,
+            )?;
+        }
+        self.hard_state = hard_state;
+        Ok(())
+    }
+
+    pub fn snapshot(&self) -> &Snapshot {
+        &self.snapshot
+    }
+
+    pub fn last_index(&self) -> u64 {
+        self.snapshot
+            .last_index
+            .saturating_add(u64::try_from(self.entries.len()).unwrap_or(u64::MAX))
+    }
+
+    pub fn last_term(&self) -> u64 {
+        self.entries
+            .last()
+            .map(|entry| entry.term)
+            .unwrap_or(self.snapshot.last_term)
+    }
+
+    /// Returns the term of the entry at `index`, if it is known.
+    /// The term of the compacted entries, except the last one, is unknown.
+    pub fn term_at(&self, index: u64) -> Option<u64> {
+        if index == self.snapshot.last_index {
+            return Some(self.snapshot.last_term);
+        }
+        self.entry(index).map(|entry| entry.term)
+    }
+
+    pub fn entry(&self, index: u64) -> Option<&LogEntry> {
+        self.entries.get(self.offset(index)?)
+    }
+
+    /// Returns up to `max_entries` entries starting at `index`. Stops before
+    /// the entry exceeding the `max_block_bytes` in total, except the first one.
+    pub fn entries_from(
+        &self,
+        index: u64,
+        max_entries: usize,
+        max_block_bytes: usize,
+    ) -> Vec<LogEntry> {
+        let Some(offset) = self.offset(index) else {
+            return Vec::new();
+        };
+        let mut block_bytes = 0usize;
+        self.entries
+            .iter()
+            .skip(offset)
+            .take(max_entries)
+            .enumerate()
+            .take_while(|(taken, entry)| {
+                if let LeaseCommand::Block { block, .. } = &entry.command {
+                    block_bytes = block_bytes.saturating_add(block.len());
+                }
+                *taken == 0 || block_bytes <= max_block_bytes
+            })
+            .map(|(_, entry)| entry.clone())
+            .collect()
+    }
+
+    /// Appends the `entries` to the end of the log.
+    pub fn append(&mut self, entries: Vec<LogEntry>) -> anyhow::Result<()> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        if let Some(log_file) = &mut self.log_file {
+            let mut bytes = Vec::new();
+            for entry in &entries {
+                write_frame(&mut bytes, entry)?;
+            }
+            log_file.write_all(&bytes)?;
+            log_file.sync_data()?;
+        }
+        self.entries.extend(entries);
+        Ok(())
+    }
+
+    /// Removes the entries starting at `index` from the log.
+    pub fn truncate_from(&mut self, index: u64) -> anyhow::Result<()> {
+        let Some(offset) = self.offset(index) else {
+            return Ok(());
+        };
+        if offset >= self.entries.len() {
+            return Ok(());
+        }
+        self.entries.truncate(offset);
+        self.rewrite_log()
+    }
+
+    /// Replaces the log prefix up to `snapshot.last_index` with the `snapshot`.
+    /// The snapshot must not be ahead of the log.
+    pub fn compact(&mut self, snapshot: Snapshot) -> anyhow::Result<()> {
+        if snapshot.last_index <= self.snapshot.last_index {
+            return Ok(());
+        }
+        if snapshot.last_index > self.last_index() {
+            return Err(anyhow!(
+                "Cannot compact the Raft log beyond its last index {}",
+                self.last_index()
+            ));
+        }
+        let compacted = snapshot.last_index.saturating_sub(self.snapshot.last_index);
+        let compacted = usize::try_from(compacted)?.min(self.entries.len());
+        self.persist_snapshot(&snapshot)?;
+        self.entries.drain(..compacted);
+        self.snapshot = snapshot;
+        self.rewrite_log()
+    }
+
+    /// Installs the `snapshot` received from the leader. The log entries
+    /// following the snapshot are kept only if the log agrees with it.
+    pub fn install_snapshot(&mut self, snapshot: Snapshot) -> anyhow::Result<()> {
+        if snapshot.last_index <= self.snapshot.last_index {
+            return Ok(());
+        }
+        if self.term_at(snapshot.last_index) == Some(snapshot.last_term) {
+            return self.compact(snapshot);
+        }
+        self.persist_snapshot(&snapshot)?;
+        self.entries.clear();
+        self.snapshot = snapshot;
+        self.rewrite_log()
+    }
+
+    fn offset(&self, index: u64) -> Option<usize> {
+        let offset = index
+            .checked_sub(self.snapshot.last_index)?
+            .checked_sub(1)?;
+        usize::try_from(offset).ok()
+    }
+
+    fn persist_snapshot(&self, snapshot: &Snapshot) -> anyhow::Result<()> {
+        if let Some(directory) = &self.directory {
+            write_atomically(
+                &directory.join(SNAPSHOT_FILE),
+                &postcard::to_allocvec(snapshot)?,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn rewrite_log(&mut self) -> anyhow::Result<()> {
+        let Some(directory) = &self.directory else {
+            return Ok(());
+        };
+        let first_index = self.snapshot.last_index.saturating_add(1);
+        let mut bytes = first_index.to_le_bytes().to_vec();
+        for entry in &self.entries {
+            write_frame(&mut bytes, entry)?;
+        }
+        let path = directory.join(LOG_FILE);
+        // The file is closed before it is replaced.
+        self.log_file = None;
+        write_atomically(&path, &bytes)?;
+        self.log_file = Some(fs::OpenOptions::new().append(true).open(path)?);
+        Ok(())
+    }
+}
+
+fn read_value<T: DeserializeOwned>(path: &Path) -> anyhow::Result<Option<T>> {
+    match fs::read(path) {
+        Ok(bytes) => Ok(Some(postcard::from_bytes(&bytes)?)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Reads the entries of the log file following the `snapshot_last_index`.
+/// The incomplete entry at the end of the file, left by a crash
+/// in the middle of the write, is ignored.
+fn read_log(path: &Path, snapshot_last_index: u64) -> anyhow::Result<Vec<LogEntry>> {
+    let bytes = match fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+    let Some((header, mut frames)) = bytes.split_at_checked(LOG_HEADER_SIZE) else {
+        return Ok(Vec::new());
+    };
+    let first_index = u64::from_le_bytes(header.try_into()?);
+
+    let mut entries = Vec::new();
+    let mut index = first_index;
+    while let Some((length, rest)) = frames.split_at_checked(FRAME_LENGTH_SIZE) {
+        let length = usize::try_from(u32::from_le_bytes(length.try_into()?))?;
+        let Some((frame, rest)) = rest.split_at_checked(length) else {
+            break;
+        };
+        let Ok(entry) = postcard::from_bytes::<LogEntry>(frame) else {
+            break;
+        };
+        if index > snapshot_last_index {
+            entries.push(entry);
+        }
+        index = index.saturating_add(1);
+        frames = rest;
+    }
+
+    if !entries.is_empty() && first_index > snapshot_last_index.saturating_add(1) {
+        return Err(anyhow!(
+            "The Raft log starts at {first_index}, but the snapshot ends at {snapshot_last_index}"
+        ));
+    }
+    Ok(entries)
+}
+
+fn write_frame(bytes: &mut Vec<u8>, entry: &LogEntry) -> anyhow::Result<()> {
+    let frame = postcard::to_allocvec(entry)?;
+    bytes.extend_from_slice(&u32::try_from(frame.len())?.to_le_bytes());
+    bytes.extend_from_slice(&frame);
+    Ok(())
+}
+
+fn write_atomically(path: &Path, bytes: &[u8]) -> anyhow::Result<()> {
+    let tmp_path = path.with_extension("tmp");
+    {
+        let mut file = fs::File::create(&tmp_path)?;
+        file.write_all(bytes)?;
+        file.sync_all()?;
+    }
+    fs::rename(tmp_path, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(term: u64, owner: NodeId) -> LogEntry {
+        LogEntry {
+            term,
+            command: LeaseCommand::Promote { owner },
+        }
+    }
+
+    #[test]
+    fn open__when_entries_were_appended_then_restores_them() {
+        // given
+        let directory = tempfile::tempdir().unwrap();
+        let mut storage = RaftStorage::open(directory.path()).unwrap();
+        storage
+            .set_hard_state(HardState {
+                term: 2,
+                voted_for: Some(1),
+            })
+            .unwrap();
+        storage
+            .append(vec![entry(1, 1), entry(2, 1), entry(2, 2)])
+            .unwrap();
+        drop(storage);
+
+        // when
+        let storage = RaftStorage::open(directory.path()).unwrap();
+
+        // then
+        assert_eq!(
+            storage.hard_state(),
+            HardState {
+                term: 2,
+                voted_for: Some(1)
+            }
+        );
+        assert_eq!(storage.last_index(), 3);
+        assert_eq!(storage.entry(3), Some(&entry(2, 2)));
+    }
+
+    #[test]
+    fn open__when_log_has_torn_tail_then_ignores_it() {
+        // given
+        let directory = tempfile::tempdir().unwrap();
+        let mut storage = RaftStorage::open(directory.path()).unwrap();
+        storage.append(vec![entry(1, 1), entry(1, 2)]).unwrap();
+        drop(storage);
+        let mut log = fs::OpenOptions::new()
+            .append(true)
+            .open(directory.path().join(LOG_FILE))
+            .unwrap();
+        log.write_all(&[200, 0, 0, 0, 1, 2]).unwrap();
+        drop(log);
+
+        // when
+        let storage = RaftStorage::open(directory.path()).unwrap();
+
+        // then
+        assert_eq!(storage.last_index(), 2);
+        assert_eq!(storage.entry(2), Some(&entry(1, 2)));
+    }
+
+    #[test]
+    fn open__when_truncated_and_compacted_then_restores_remaining_suffix() {
+        // given
+        let directory = tempfile::tempdir().unwrap();
+        let mut storage = RaftStorage::open(directory.path()).unwrap();
+        storage
+            .append(vec![entry(1, 1), entry(1, 2), entry(1, 3), entry(1, 4)])
+            .unwrap();
+        storage.truncate_from(4).unwrap();
+        storage.append(vec![entry(2, 5)]).unwrap();
+        storage
+            .compact(Snapshot {
+                last_index: 2,
+                last_term: 1,
+                state: LeaseStateMachine::default(),
+            })
+            .unwrap();
+        drop(storage);
+
+        // when
+        let storage = RaftStorage::open(directory.path()).unwrap();
+
+        // then
+        assert_eq!(storage.snapshot().last_index, 2);
+        assert_eq!(storage.term_at(2), Some(1));
+        assert_eq!(storage.entry(1), None);
+        assert_eq!(storage.entry(3), Some(&entry(1, 3)));
+        assert_eq!(storage.entry(4), Some(&entry(2, 5)));
+        assert_eq!(storage.last_index(), 4);
+    }
+
+    #[test]
+    fn entries_from__when_blocks_exceed_byte_limit_then_stops_before_them() {
+        // given
+        let mut storage = RaftStorage::in_memory();
+        let block = |height: u32| LogEntry {
+            term: 1,
+            command: LeaseCommand::Block {
+                epoch: 1,
+                height,
+                block: vec![0; 10],
+            },
+        };
+        storage
+            .append(vec![block(1), block(2), block(3), block(4)])
+            .unwrap();
+
+        // when
+        let limited = storage.entries_from(1, 10, 25);
+        let single = storage.entries_from(2, 10, 5);
+
+        // then
+        assert_eq!(limited, vec![block(1), block(2)]);
+        assert_eq!(single, vec![block(2)]);
+    }
+
+    #[test]
+    fn install_snapshot__when_log_conflicts_then_discards_it() {
+        // given
+        let mut storage = RaftStorage::in_memory();
+        storage.append(vec![entry(1, 1), entry(1, 2)]).unwrap();
+
+        // when
+        storage
+            .install_snapshot(Snapshot {
+                last_index: 5,
+                last_term: 3,
+                state: LeaseStateMachine::default(),
+            })
+            .unwrap();
+
+        // then
+        assert_eq!(storage.last_index(), 5);
+        assert_eq!(storage.last_term(), 3);
+        assert_eq!(storage.entry(2), None);
+    }
+}
@@ -0,0 +1,346 @@
+use super::raft::{
+    RaftRequest,
+    RaftResponse,
+};
+use anyhow::anyhow;
+use fuel_core_types::fuel_crypto::Hasher;
+use serde::{
+    Serialize,
+    de::DeserializeOwned,
+};
+use std::{
+    net::SocketAddr,
+    time::Duration,
+};
+use tokio::{
+    io::{
+        AsyncRead,
+        AsyncReadExt,
+        AsyncWrite,
+        AsyncWriteExt,
+    },
+    net::TcpStream,
+    sync::Mutex,
+    time::timeout,
+};
+
+/// The maximum size of the snapshot frame. The snapshot with the whole block
+/// stream is the largest message.
+const MAX_SNAPSHOT_FRAME_LEN: u32 = 256 * 1024 * 1024;
+/// The maximum size of any other frame. The `AppendEntries` request carries
+/// a limited batch of blocks, and other messages are tiny.
+const MAX_MESSAGE_FRAME_LEN: u32 = 16 * 1024 * 1024;
+
+/// The size of the random challenge of the handshake.
+const NONCE_LEN: usize = 32;
+/// How long the accepting replica waits for the peer to authenticate.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+const CONNECTING_TAG: &[u8] = b"FUEL_RAFT_CONNECTING";
+const ACCEPTING_TAG: &[u8] = b"FUEL_RAFT_ACCEPTING";
+
+/// The kind of the frame. It is sent before the length of the frame,
+/// so the receiver applies the limit of the kind before reading the frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FrameKind {
+    Message,
+    Snapshot,
+}
+
+impl FrameKind {
+    fn max_len(self) -> u32 {
+        match self {
+            FrameKind::Message => MAX_MESSAGE_FRAME_LEN,
+            FrameKind::Snapshot => MAX_SNAPSHOT_FRAME_LEN,
+        }
+    }
+}
+
+impl RaftRequest {
+    pub fn frame_kind(&self) -> FrameKind {
+        match self {
+            RaftRequest::InstallSnapshot(_) => FrameKind::Snapshot,
+            RaftRequest::Vote(_) | RaftRequest::AppendEntries(_) => FrameKind::Message,
+        }
+    }
+}
+
+/// Reads a frame prefixed with its kind and its little-endian `u32` length.
+///
+/// The buffer grows with the received bytes instead of being allocated
+/// for the announced length up front.
+pub async fn read_frame<T, S>(stream: &mut S) -> anyhow::Result<(FrameKind, T)>
+where
+    T: DeserializeOwned,
+    S: AsyncRead + Unpin,
+{
+    let kind = match stream.read_u8().await? {
+        0 => FrameKind::Message,
+        1 => FrameKind::Snapshot,
+        kind => return Err(anyhow!("Unknown kind {kind} of the Raft frame")),
+    };
+    let len = stream.read_u32_le().await?;
+    if len > kind.max_len() {
+        return Err(anyhow!(
+            "The Raft {kind:?} frame of {len} bytes is too large"
+        ));
+    }
+    let mut bytes = Vec::new();
+    let read = (&mut *stream)
+        .take(u64::from(len))
+        .read_to_end(&mut bytes)
+        .await?;
+    if read != usize::try_from(len)? {
+        return Err(anyhow!(
+            "The Raft connection closed in the middle of the frame"
+        ));
+    }
+    Ok((kind, postcard::from_bytes(&bytes)?))
+}
+
+/// Writes a frame prefixed with its kind and its little-endian `u32` length.
+pub async fn write_frame<T, S>(
+    stream: &mut S,
+    kind: FrameKind,
+    message: &T,
+) -> anyhow::Result<()>
+where
+    T: Serialize,
+    S: AsyncWrite + Unpin,
+{
+    let bytes = postcard::to_allocvec(message)?;
+    let len = u32::try_from(bytes.len())?;
+    if len > kind.max_len() {
+        return Err(anyhow!(
+            "The Raft {kind:?} frame of {len} bytes is too large"
+        ));
+    }
+    let kind = match kind {
+        FrameKind::Message => 0,
+        FrameKind::Snapshot => 1,
+    };
+    stream.write_u8(kind).await?;
+    stream.write_u32_le(len).await?;
+    stream.write_all(&bytes).await?;
+    stream.flush().await?;
+    Ok(())
+}
+
+/// The key derived from the secret shared by the replicas of the cluster.
+#[derive(Clone)]
+pub struct ClusterKey([u8; 32]);
+
+impl ClusterKey {
+    pub fn new(secret: &str) -> Self {
+        Self(*Hasher::hash(secret.as_bytes()))
+    }
+
+    /// Proves the knowledge of the key for the pair of challenges.
+    fn proof(&self, tag: &[u8], first: &[u8], second: &[u8]) -> [u8; 32] {
+        let mut hasher = Hasher::default();
+        hasher.input(self.0);
+        hasher.input(tag);
+        hasher.input(first);
+        hasher.input(second);
+        *hasher.digest()
+    }
+}
+
+/// Authenticates the connection to the peer. Both sides prove that they know
+/// the cluster key by hashing it with the random challenges of each other.
+pub async fn authenticate_outgoing<S>(
+    stream: &mut S,
+    key: &ClusterKey,
+) -> anyhow::Result<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let mut peer_nonce = [0u8; NONCE_LEN];
+    stream.read_exact(&mut peer_nonce).await?;
+    let nonce: [u8; NONCE_LEN] = rand::random();
+    stream.write_all(&nonce).await?;
+    stream
+        .write_all(&key.proof(CONNECTING_TAG, &peer_nonce, &nonce))
+        .await?;
+    stream.flush().await?;
+
+    let mut peer_proof = [0u8; 32];
+    stream.read_exact(&mut peer_proof).await?;
+    if peer_proof != key.proof(ACCEPTING_TAG, &nonce, &peer_nonce) {
+        return Err(anyhow!("The Raft peer doesn't know the cluster secret"));
+    }
+    Ok(())
+}
+
+/// Authenticates the connection accepted from the peer.
+/// See [`authenticate_outgoing`] for the other side of the handshake.
+pub async fn authenticate_incoming<S>(
+    stream: &mut S,
+    key: &ClusterKey,
+) -> anyhow::Result<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    timeout(HANDSHAKE_TIMEOUT, async {
+        let nonce: [u8; NONCE_LEN] = rand::random();
+        stream.write_all(&nonce).await?;
+        stream.flush().await?;
+
+        let mut peer_nonce = [0u8; NONCE_LEN];
+        stream.read_exact(&mut peer_nonce).await?;
+        let mut peer_proof = [0u8; 32];
+        stream.read_exact(&mut peer_proof).await?;
+        if peer_proof != key.proof(CONNECTING_TAG, &nonce, &peer_nonce) {
+            return Err(anyhow!("The Raft peer doesn't know the cluster secret"));
+        }
+
+        stream
+            .write_all(&key.proof(ACCEPTING_TAG, &peer_nonce, &nonce))
+            .await?;
+        stream.flush().await?;
+        Ok(())
+    })
+    .await
+    .unwrap_or_else(|_| Err(anyhow!("The Raft handshake timed out")))
+}
+
+/// The client of a single Raft peer. Keeps the connection open between calls
+/// and reconnects after any failure.
+pub struct PeerClient {
+    address: SocketAddr,
+    rpc_timeout: Duration,
+    key: ClusterKey,
+    connection: Mutex<Option<TcpStream>>,
+    /// The outcome of the latest call, `None` before the first call.
+    last_result: parking_lot::Mutex<Option<Result<(), String>>>,
+}
+
+impl PeerClient {
+    pub fn new(address: SocketAddr, rpc_timeout: Duration, key: ClusterKey) -> Self {
+        Self {
+            address,
+            rpc_timeout,
+            key,
+            connection: Mutex::new(None),
+            last_result: parking_lot::Mutex::new(None),
+        }
+    }
+
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Returns the outcome of the latest call, `None` before the first call.
+    pub fn last_result(&self) -> Option<Result<(), String>> {
+        self.last_result.lock().clone()
+    }
+
+    pub async fn call(&self, request: &RaftRequest) -> anyhow::Result<RaftResponse> {
+        let mut connection = self.connection.lock().await;
+        // The connection is owned by the call, so it is dropped if the call
+        // is cancelled in the middle of the exchange.
+        let cached = connection.take();
+        let result = timeout(self.rpc_timeout, async {
+            let mut stream = match cached {
+                Some(stream) => stream,
+                None => {
+                    let mut stream = TcpStream::connect(self.address).await?;
+                    stream.set_nodelay(true)?;
+                    authenticate_outgoing(&mut stream, &self.key).await?;
+                    stream
+                }
+            };
+            write_frame(&mut stream, request.frame_kind(), request).await?;
+            let (_, response) = read_frame(&mut stream).await?;
+            Ok((stream, response))
+        })
+        .await
+        .unwrap_or_else(|_| {
+            Err(anyhow!(
+                "The Raft request to {} timed out after {:?}",
+                self.address,
+                self.rpc_timeout
+            ))
+        });
+        *self.last_result.lock() =
+            Some(result.as_ref().map(|_| ()).map_err(|err| err.to_string()));
+        let (stream, response) = result?;
+        *connection = Some(stream);
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::duplex;
+
+    #[tokio::test]
+    async fn authenticate__when_peers_share_the_secret_then_succeeds() {
+        // given
+        let (mut connecting, mut accepting) = duplex(1024);
+        let key = ClusterKey::new("secret");
+
+        // when
+        let (outgoing, incoming) = tokio::join!(
+            authenticate_outgoing(&mut connecting, &key),
+            authenticate_incoming(&mut accepting, &key),
+        );
+
+        // then
+        assert!(outgoing.is_ok());
+        assert!(incoming.is_ok());
+    }
+
+    #[tokio::test]
+    async fn authenticate__when_peer_has_another_secret_then_fails() {
+        // given
+        let (mut connecting, mut accepting) = duplex(1024);
+        let connecting_key = ClusterKey::new("another");
+        let accepting_key = ClusterKey::new("secret");
+
+        // when
+        let (outgoing, incoming) = tokio::join!(
+            authenticate_outgoing(&mut connecting, &connecting_key),
+            // The rejected connection is closed.
+            async move { authenticate_incoming(&mut accepting, &accepting_key).await },
+        );
+
+        // then
+        assert!(outgoing.is_err());
+        assert!(incoming.is_err());
+    }
+
+    #[tokio::test]
+    async fn read_frame__when_message_frame_exceeds_its_limit_then_fails() {
+        // given
+        let (mut writer, mut reader) = duplex(1024);
+        writer.write_u8(0).await.unwrap();
+        writer
+            .write_u32_le(MAX_MESSAGE_FRAME_LEN.saturating_add(1))
+            .await
+            .unwrap();
+
+        // when
+        let result = read_frame::<Vec<u8>, _>(&mut reader).await;
+
+        // then
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn read_frame__when_frame_is_written_then_reads_it() {
+        // given
+        let (mut writer, mut reader) = duplex(1024);
+        let message = vec![7u8; 4096];
+
+        // when
+        let (written, read) = tokio::join!(
+            write_frame(&mut writer, FrameKind::Snapshot, &message),
+            read_frame::<Vec<u8>, _>(&mut reader),
+        );
+
+        // then
+        written.unwrap();
+        assert_eq!(read.unwrap(), (FrameKind::Snapshot, message));
+    }
+}
@@ -0,0 +1,359 @@
+use super::{
+    READ_LATEST_STREAM_ENTRY_SCRIPT,
+    RedisNode,
+};
+use anyhow::anyhow;
+use fuel_core_types::{
+    fuel_types::BlockHeight,
+    services::leader_lease::{
+        LeaderLeaseBackend,
+        LeaderLeaseStatus,
+        LeaseNodeStatus,
+        LeaseTransition,
+        LeaseTransitionKind,
+        QuorumRound,
+        QuorumRoundKind,
+    },
+};
+use std::{
+    collections::{
+        HashMap,
+        VecDeque,
+    },
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::{
+        Duration,
+        SystemTime,
+    },
+};
+use tokio::time::timeout;
+
+/// The number of the latest lease transitions kept for the status.
+const MAX_LEASE_TRANSITIONS: usize = 32;
+
+/// Records the outcome of the lease rounds of the leader lease adapter.
+/// Shared between all clones of the adapter.
+#[derive(Default)]
+pub(crate) struct LeaseStatusRecorder {
+    is_leader: bool,
+    epoch_token: Option<u64>,
+    local_height: Option<BlockHeight>,
+    last_quorum_round: Option<QuorumRound>,
+    transitions: VecDeque<LeaseTransition>,
+}
+
+impl LeaseStatusRecorder {
+    pub(crate) fn record_quorum_round(
+        &mut self,
+        kind: QuorumRoundKind,
+        nodes: usize,
+        responded: usize,
+        granted: usize,
+        quorum: usize,
+    ) {
+        self.last_quorum_round = Some(QuorumRound {
+            kind,
+            nodes: u32::try_from(nodes).unwrap_or(u32::MAX),
+            responded: u32::try_from(responded).unwrap_or(u32::MAX),
+            granted: u32::try_from(granted).unwrap_or(u32::MAX),
+            quorum_reached: granted >= quorum,
+            time: SystemTime::now(),
+        });
+    }
+
+    /// Records the result of the lease round that decides whether this node
+    /// can produce the block.
+    pub(crate) fn record_leadership(
+        &mut self,
+        is_leader: bool,
+        epoch_token: Option<u64>,
+    ) {
+        let kind = match (self.is_leader, is_leader) {
+            (false, true) => Some(LeaseTransitionKind::Acquired),
+            (true, false) => Some(LeaseTransitionKind::Lost),
+            (true, true) if self.epoch_token != epoch_token => {
+                Some(LeaseTransitionKind::EpochChanged)
+            }
+            _ => None,
+        };
+        self.is_leader = is_leader;
+        self.epoch_token = epoch_token;
+        if let Some(kind) = kind {
+            self.push_transition(kind);
+        }
+    }
+
+    pub(crate) fn record_release(&mut self) {
+        let was_leader = self.is_leader;
+        self.is_leader = false;
+        self.epoch_token = None;
+        if was_leader {
+            self.push_transition(LeaseTransitionKind::Released);
+        }
+    }
+
+    pub(crate) fn record_local_height(&mut self, local_height: Option<BlockHeight>) {
+        self.local_height = local_height;
+    }
+
+    /// Combines the state of the lease nodes with the recorded outcome
+    /// of the lease rounds.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn lease_status(
+        &self,
+        backend: LeaderLeaseBackend,
+        lease_key: Option<String>,
+        lease_owner_token: String,
+        holder: Option<String>,
+        quorum: usize,
+        nodes: Vec<LeaseNodeStatus>,
+        stream_height: Option<BlockHeight>,
+    ) -> LeaderLeaseStatus {
+        let stream_lag = stream_height.map(|stream_height| {
+            let local_height = self.local_height.map(u32::from).unwrap_or(0);
+            u32::from(stream_height).saturating_sub(local_height)
+        });
+        LeaderLeaseStatus {
+            backend,
+            lease_key,
+            lease_owner_token,
+            is_leader: self.is_leader,
+            epoch_token: self.epoch_token,
+            holder,
+            quorum: u32::try_from(quorum).unwrap_or(u32::MAX),
+            nodes,
+            last_quorum_round: self.last_quorum_round.clone(),
+            local_height: self.local_height,
+            stream_height,
+            stream_lag,
+            transitions: self.transitions.iter().cloned().collect(),
+        }
+    }
+
+    fn push_transition(&mut self, kind: LeaseTransitionKind) {
+        if self.transitions.len() >= MAX_LEASE_TRANSITIONS {
+            self.transitions.pop_front();
+        }
+        self.transitions.push_back(LeaseTransition {
+            kind,
+            epoch: self.epoch_token,
+            time: SystemTime::now(),
+        });
+    }
+}
+
+/// Reports the [`LeaderLeaseStatus`] of the [`super::RedisLeaderLeaseAdapter`].
+/// Reuses the connections of the adapter to the Redis nodes.
+#[derive(Clone)]
+pub struct RedisLeaseStatusReporter {
+    pub(crate) redis_nodes: Vec<RedisNode>,
+    pub(crate) quorum: usize,
+    pub(crate) lease_key: String,
+    pub(crate) epoch_key: String,
+    pub(crate) block_stream_key: String,
+    pub(crate) lease_owner_token: String,
+    pub(crate) node_timeout: Duration,
+    pub(crate) recorder: Arc<Mutex<LeaseStatusRecorder>>,
+}
+
+struct NodeState {
+    holder: Option<String>,
+    epoch: Option<u64>,
+    stream_height: Option<u32>,
+}
+
+impl RedisLeaseStatusReporter {
+    /// Requests the state of all Redis nodes and combines it with the outcome
+    /// of the latest lease rounds of this node.
+    pub async fn status(&self) -> anyhow::Result<LeaderLeaseStatus> {
+        let nodes = futures::future::join_all(
+            self.redis_nodes
+                .iter()
+                .map(|redis_node| self.node_status(redis_node)),
+        )
+        .await;
+
+        let mut holder_votes = HashMap::<&str, usize>::new();
+        for holder in nodes.iter().filter_map(|node| node.holder.as_deref()) {
+            let votes = holder_votes.entry(holder).or_default();
+            *votes = votes.saturating_add(1);
+        }
+        let holder = holder_votes
+            .into_iter()
+            .find(|(_, votes)| *votes >= self.quorum)
+            .map(|(holder, _)| holder.to_string());
+        let stream_height = nodes.iter().filter_map(|node| node.stream_height).max();
+
+        let recorder = self
+            .recorder
+            .lock()
+            .map_err(|_| anyhow!("cannot access lease status, poisoned lock"))?;
+        Ok(recorder.lease_status(
+            LeaderLeaseBackend::Redis,
+            Some(self.lease_key.clone()),
+            self.lease_owner_token.clone(),
+            holder,
+            self.quorum,
+            nodes,
+            stream_height,
+        ))
+    }
+
+    async fn node_status(&self, redis_node: &RedisNode) -> LeaseNodeStatus {
+        let address = redis_node
+            .redis_client
+            .get_connection_info()
+            .addr()
+            .to_string();
+        match self.read_node_state(redis_node).await {
+            Ok(state) => LeaseNodeStatus {
+                address,
+                reachable: true,
+                holder: state.holder,
+                epoch: state.epoch,
+                stream_height: state.stream_height.map(BlockHeight::from),
+                error: None,
+            },
+            Err(err) => LeaseNodeStatus {
+                address,
+                reachable: false,
+                holder: None,
+                epoch: None,
+                stream_height: None,
+                error: Some(err.to_string()),
+            },
+        }
+    }
+
+    async fn read_node_state(&self, redis_node: &RedisNode) -> anyhow::Result<NodeState> {
+        let mut connection = redis_node.multiplexed_connection(self.node_timeout).await?;
+        let reply = timeout(self.node_timeout, async {
+            let (holder, epoch) = redis::pipe()
+                .get(&self.lease_key)
+                .get(&self.epoch_key)
+                .query_async::<(Option<String>, Option<u64>)>(&mut connection)
+                .await?;
+            let latest_entry = redis::Script::new(READ_LATEST_STREAM_ENTRY_SCRIPT)
+                .key(&self.block_stream_key)
+                .invoke_async::<Vec<String>>(&mut connection)
+                .await?;
+            Ok::<_, redis::RedisError>((holder, epoch, latest_entry))
+        })
+        .await;
+        // The connection is shared with the adapter, so the broken one is dropped
+        // for the adapter to reconnect too.
+        let (holder, epoch, latest_entry) = match reply {
+            Ok(Ok(reply)) => reply,
+            Ok(Err(err)) => {
+                redis_node.clear_cached_connection().await;
+                return Err(err.into());
+            }
+            Err(_) => {
+                redis_node.clear_cached_connection().await;
+                return Err(anyhow!(
+                    "Timed out after {:?} while reading the node state",
+                    self.node_timeout
+                ));
+            }
+        };
+        let stream_height = match latest_entry.as_slice() {
+            [height, _stream_id] => Some(
+                height
+                    .parse::<u32>()
+                    .map_err(|e| anyhow!("Invalid latest stream entry height: {e}"))?,
+            ),
+            _ => None,
+        };
+        Ok(NodeState {
+            holder,
+            epoch,
+            stream_height,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transition_kinds(recorder: &LeaseStatusRecorder) -> Vec<LeaseTransitionKind> {
+        recorder
+            .transitions
+            .iter()
+            .map(|transition| transition.kind)
+            .collect()
+    }
+
+    #[test]
+    fn record_leadership__when_leadership_changes_then_records_transitions() {
+        // given
+        let mut recorder = LeaseStatusRecorder::default();
+
+        // when
+        recorder.record_leadership(false, None);
+        recorder.record_leadership(true, Some(1));
+        recorder.record_leadership(true, Some(1));
+        recorder.record_leadership(true, Some(3));
+        recorder.record_leadership(false, None);
+
+        // then
+        assert_eq!(
+            transition_kinds(&recorder),
+            vec![
+                LeaseTransitionKind::Acquired,
+                LeaseTransitionKind::EpochChanged,
+                LeaseTransitionKind::Lost,
+            ]
+        );
+        let epochs = recorder
+            .transitions
+            .iter()
+            .map(|transition| transition.epoch)
+            .collect::<Vec<_>>();
+        assert_eq!(epochs, vec![Some(1), Some(3), None]);
+    }
+
+    #[test]
+    fn record_release__when_not_leader_then_records_nothing() {
+        // given
+        let mut recorder = LeaseStatusRecorder::default();
+        recorder.record_leadership(true, Some(1));
+        recorder.record_release();
+
+        // when
+        recorder.record_release();
+
+        // then
+        assert_eq!(
+            transition_kinds(&recorder),
+            vec![LeaseTransitionKind::Acquired, LeaseTransitionKind::Released]
+        );
+        assert!(!recorder.is_leader);
+        assert_eq!(recorder.epoch_token, None);
+    }
+
+    #[test]
+    fn record_leadership__when_history_is_full_then_drops_oldest_transitions() {
+        // given
+        let mut recorder = LeaseStatusRecorder::default();
+
+        // when
+        for epoch in 1..=u64::try_from(MAX_LEASE_TRANSITIONS).unwrap() {
+            recorder.record_leadership(true, Some(epoch));
+            recorder.record_leadership(false, None);
+        }
+
+        // then
+        assert_eq!(recorder.transitions.len(), MAX_LEASE_TRANSITIONS);
+        assert_eq!(
+            recorder
+                .transitions
+                .back()
+                .map(|transition| transition.kind),
+            Some(LeaseTransitionKind::Lost)
+        );
+    }
+}
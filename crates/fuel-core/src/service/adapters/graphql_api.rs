@@ -47,7 +47,13 @@ use fuel_core_storage::{
     structured_storage::TableWithBlueprint,
 };
 use fuel_core_tx_status_manager::TxStatusMessage;
-use fuel_core_txpool::TxPoolStats;
+use fuel_core_txpool::{
+    TxPoolStats,
+    inspection::{
+        InspectedTransaction,
+        InspectionFilter,
+    },
+};
 use fuel_core_types::{
     blockchain::header::{
         ConsensusParametersVersion,
@@ -65,9 +71,16 @@ use fuel_core_types::{
         block_importer::SharedImportResult,
         executor::{
             DryRunResult,
+            StateOverrides,
             StorageReadReplayEvent,
+            TransactionTrace,
+        },
+        p2p::{
+            BannedPeer,
+            PeerId,
+            PeerInfo,
+            PeerReputation,
         },
-        p2p::PeerInfo,
         transaction_status::TransactionStatus,
     },
     tai64::Tai64,
@@ -75,6 +88,7 @@ use fuel_core_types::{
 use std::{
     ops::Deref,
     sync::Arc,
+    time::Duration,
 };
 
 mod off_chain;
@@ -111,9 +125,16 @@ impl TxPoolPort for TxPoolAdapter {
             .map(|info| info.tx().clone().deref().into()))
     }
 
-    async fn insert(&self, tx: Transaction) -> anyhow::Result<()> {
+    async fn insert(&self, tx: Transaction, replaceable: bool) -> anyhow::Result<()> {
         self.service
-            .insert(tx)
+            .insert_opt(tx, replaceable)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
+    async fn insert_bundle(&self, txs: Vec<Transaction>) -> anyhow::Result<()> {
+        self.service
+            .insert_bundle(txs)
             .await
             .map_err(|e| anyhow::anyhow!(e))
     }
@@ -121,6 +142,16 @@ impl TxPoolPort for TxPoolAdapter {
     fn latest_pool_stats(&self) -> TxPoolStats {
         self.service.latest_stats()
     }
+
+    async fn inspect(
+        &self,
+        filter: InspectionFilter,
+    ) -> anyhow::Result<Vec<InspectedTransaction>> {
+        self.service
+            .inspect(filter)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+    }
 }
 
 impl DatabaseMessageProof for OnChainIterableKeyValueView {
@@ -143,6 +174,7 @@ impl BlockProducerPort for BlockProducerAdapter {
         utxo_validation: Option<bool>,
         gas_price: Option<u64>,
         record_storage_reads: bool,
+        state_overrides: Option<StateOverrides>,
     ) -> anyhow::Result<DryRunResult> {
         self.block_producer
             .dry_run(
@@ -152,6 +184,7 @@ impl BlockProducerPort for BlockProducerAdapter {
                 utxo_validation,
                 gas_price,
                 record_storage_reads,
+                state_overrides,
             )
             .await
     }
@@ -162,6 +195,14 @@ impl BlockProducerPort for BlockProducerAdapter {
     ) -> anyhow::Result<Vec<StorageReadReplayEvent>> {
         self.block_producer.storage_read_replay(height).await
     }
+
+    async fn transaction_trace(
+        &self,
+        height: BlockHeight,
+        tx_id: TxId,
+    ) -> anyhow::Result<TransactionTrace> {
+        self.block_producer.transaction_trace(height, tx_id).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -203,6 +244,145 @@ impl P2pPort for P2PAdapter {
             Ok(vec![])
         }
     }
+
+    async fn add_reserved_peer(&self, address: String) -> anyhow::Result<PeerId> {
+        #[cfg(feature = "p2p")]
+        {
+            let address = address.parse()?;
+            let peer_id = self.p2p_service()?.add_reserved_peer(address).await?;
+            Ok(PeerId::from(peer_id.to_bytes()))
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            let _ = address;
+            Err(anyhow::anyhow!("The P2P service is disabled"))
+        }
+    }
+
+    async fn remove_reserved_peer(&self, peer_id: PeerId) -> anyhow::Result<bool> {
+        #[cfg(feature = "p2p")]
+        {
+            let peer_id = to_libp2p_peer_id(peer_id)?;
+            self.p2p_service()?.remove_reserved_peer(peer_id).await
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            let _ = peer_id;
+            Err(anyhow::anyhow!("The P2P service is disabled"))
+        }
+    }
+
+    async fn ban_peer(&self, peer_id: PeerId, duration: Duration) -> anyhow::Result<()> {
+        #[cfg(feature = "p2p")]
+        {
+            let peer_id = to_libp2p_peer_id(peer_id)?;
+            self.p2p_service()?.ban_peer(peer_id, duration).await
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            let _ = (peer_id, duration);
+            Err(anyhow::anyhow!("The P2P service is disabled"))
+        }
+    }
+
+    async fn unban_peer(&self, peer_id: PeerId) -> anyhow::Result<()> {
+        #[cfg(feature = "p2p")]
+        {
+            let peer_id = to_libp2p_peer_id(peer_id)?;
+            self.p2p_service()?.unban_peer(peer_id).await
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            let _ = peer_id;
+            Err(anyhow::anyhow!("The P2P service is disabled"))
+        }
+    }
+
+    async fn disconnect_peer(&self, peer_id: PeerId) -> anyhow::Result<bool> {
+        #[cfg(feature = "p2p")]
+        {
+            let peer_id = to_libp2p_peer_id(peer_id)?;
+            self.p2p_service()?.disconnect_peer(peer_id).await
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            let _ = peer_id;
+            Err(anyhow::anyhow!("The P2P service is disabled"))
+        }
+    }
+
+    async fn peer_reputations(&self) -> anyhow::Result<Vec<PeerReputation>> {
+        #[cfg(feature = "p2p")]
+        {
+            use fuel_core_types::services::p2p::HeartbeatData;
+            match &self.service {
+                Some(service) => {
+                    let peers = service.get_peer_reputations().await?;
+                    Ok(peers
+                        .into_iter()
+                        .map(|reputation| {
+                            let heartbeat_data = &reputation.peer_info.heartbeat_data;
+                            PeerReputation {
+                                id: PeerId::from(reputation.peer_id.to_bytes()),
+                                reserved: reputation.reserved,
+                                app_score: reputation.peer_info.score,
+                                gossipsub_score: reputation.gossipsub_score,
+                                heartbeat_data: HeartbeatData {
+                                    block_height: heartbeat_data.block_height,
+                                    last_heartbeat: heartbeat_data.last_heartbeat_sys,
+                                },
+                                average_heartbeat_interval: heartbeat_data
+                                    .average_time_between_heartbeats(),
+                            }
+                        })
+                        .collect())
+                }
+                _ => Ok(vec![]),
+            }
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            Ok(vec![])
+        }
+    }
+
+    async fn banned_peers(&self) -> anyhow::Result<Vec<BannedPeer>> {
+        #[cfg(feature = "p2p")]
+        {
+            match &self.service {
+                Some(service) => {
+                    let peers = service.get_banned_peers().await?;
+                    Ok(peers
+                        .into_iter()
+                        .map(|(peer_id, remaining_ban_duration)| BannedPeer {
+                            id: PeerId::from(peer_id.to_bytes()),
+                            remaining_ban_duration,
+                        })
+                        .collect())
+                }
+                _ => Ok(vec![]),
+            }
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            Ok(vec![])
+        }
+    }
+}
+
+#[cfg(feature = "p2p")]
+impl P2PAdapter {
+    fn p2p_service(&self) -> anyhow::Result<&fuel_core_p2p::service::SharedState> {
+        self.service
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("The P2P service is disabled"))
+    }
+}
+
+#[cfg(feature = "p2p")]
+fn to_libp2p_peer_id(peer_id: PeerId) -> anyhow::Result<fuel_core_p2p::PeerId> {
+    fuel_core_p2p::PeerId::from_bytes(&Vec::from(peer_id))
+        .map_err(|e| anyhow::anyhow!("Invalid peer id: {e}"))
 }
 
 impl worker::TxStatusCompletion for TxStatusManagerAdapter {
@@ -2,7 +2,6 @@ use crate::{
     database::{
         Database,
         OffChainIterableKeyValueView,
-        OffChainKeyValueView,
         database_description::{
             IndexationKind,
             off_chain::OffChain,
@@ -36,6 +35,16 @@ use crate::{
                 TotalBalanceAmount,
             },
             coins::CoinsToSpendIndex,
+            fee_statistics::{
+                BlockFeeStatistics,
+                FeeStatistics,
+            },
+            logs::{
+                LogDetails,
+                LogKey,
+                Logs,
+                log_prefix,
+            },
             old::{
                 OldFuelBlockConsensus,
                 OldFuelBlocks,
@@ -81,6 +90,7 @@ use fuel_core_types::{
     fuel_types::{
         BlockHeight,
         Nonce,
+        Word,
     },
     services::transaction_status,
 };
@@ -135,6 +145,44 @@ impl OffChainDatabase for OffChainIterableKeyValueView {
             .into_boxed()
     }
 
+    fn contract_transactions_ids(
+        &self,
+        contract_id: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(TxPointer, TxId)>> {
+        let start = start.map(|tx_pointer| OwnedTransactionIndexCursor {
+            block_height: tx_pointer.block_height(),
+            tx_idx: tx_pointer.tx_index(),
+        });
+        self.contract_transactions(contract_id, start, Some(direction))
+            .into_boxed()
+    }
+
+    fn logs(
+        &self,
+        contract_id: ContractId,
+        rb: Word,
+        start: Option<LogKey>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(LogKey, LogDetails)>> {
+        self.iter_all_filtered::<Logs, _>(
+            Some(log_prefix(&contract_id, rb)),
+            start.as_ref(),
+            Some(direction),
+        )
+        .into_boxed()
+    }
+
+    fn fee_statistics(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<BlockFeeStatistics>> {
+        self.storage_as_ref::<FeeStatistics>()
+            .get(height)
+            .map(|opt| opt.map(|cow| cow.into_owned()))
+    }
+
     fn contract_salt(&self, contract_id: &ContractId) -> StorageResult<Salt> {
         let salt = *self
             .storage_as_ref::<ContractsInfo>()
@@ -310,7 +358,8 @@ impl OffChainIterableKeyValueView {
         owner: &Address,
     ) -> BoxedIter<'_, Result<(AssetId, u128), StorageError>> {
         let base_asset_id = *base_asset_id;
-        let base_balance = self.balance(owner, &base_asset_id, &base_asset_id);
+        let base_balance =
+            OffChainDatabase::balance(self, owner, &base_asset_id, &base_asset_id);
         match base_balance {
             Ok(base_asset_balance) => {
                 if base_asset_balance != 0 {
@@ -410,6 +459,46 @@ impl worker::OffChainDatabase for Database<OffChain> {
     fn asset_metadata_indexation_enabled(&self) -> StorageResult<bool> {
         self.indexation_available(IndexationKind::AssetMetadata)
     }
+
+    fn contract_transactions_indexation_enabled(&self) -> StorageResult<bool> {
+        self.indexation_available(IndexationKind::ContractTransactions)
+    }
+
+    fn logs_indexation_enabled(&self) -> StorageResult<bool> {
+        self.indexation_available(IndexationKind::Logs)
+    }
+
+    fn fee_history_indexation_enabled(&self) -> StorageResult<bool> {
+        self.indexation_available(IndexationKind::FeeHistory)
+    }
 }
 
-impl OffChainDatabaseAt for OffChainKeyValueView {}
+impl OffChainDatabaseAt for OffChainIterableKeyValueView {
+    fn balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        base_asset_id: &AssetId,
+    ) -> StorageResult<TotalBalanceAmount> {
+        OffChainDatabase::balance(self, owner, asset_id, base_asset_id)
+    }
+
+    fn balances<'a>(
+        &'a self,
+        owner: &Address,
+        start: Option<AssetId>,
+        base_asset_id: &'a AssetId,
+        direction: IterDirection,
+    ) -> BoxedIter<'a, StorageResult<(AssetId, TotalBalanceAmount)>> {
+        OffChainDatabase::balances(self, owner, start, base_asset_id, direction)
+    }
+
+    fn owned_coins_ids(
+        &self,
+        owner: &Address,
+        start_coin: Option<UtxoId>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<UtxoId>> {
+        OffChainDatabase::owned_coins_ids(self, owner, start_coin, direction)
+    }
+}
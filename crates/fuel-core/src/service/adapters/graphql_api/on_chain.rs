@@ -2,7 +2,6 @@ use crate::{
     database::{
         Database,
         OnChainIterableKeyValueView,
-        OnChainKeyValueView,
         database_description::on_chain::OnChain,
     },
     fuel_core_graphql_api::ports::{
@@ -170,7 +169,7 @@ impl worker::OnChainDatabase for Database<OnChain> {
     }
 }
 
-impl OnChainDatabaseAt for OnChainKeyValueView {
+impl OnChainDatabaseAt for OnChainIterableKeyValueView {
     fn contract_slot_values(
         &self,
         contract_id: ContractId,
@@ -55,6 +55,7 @@ use fuel_core_types::{
     fuel_tx::{
         ConsensusParameters,
         Transaction,
+        TxId,
     },
     fuel_types::{
         BlockHeight,
@@ -65,7 +66,9 @@ use fuel_core_types::{
         executor::{
             DryRunResult,
             Result as ExecutorResult,
+            StateOverrides,
             StorageReadReplayEvent,
+            TransactionTrace,
             UncommittedResult,
         },
     },
@@ -132,12 +135,14 @@ impl fuel_core_producer::ports::DryRunner for ExecutorAdapter {
         forbid_fake_coins: Option<bool>,
         at_height: Option<BlockHeight>,
         record_storage_read_replay: bool,
+        state_overrides: Option<StateOverrides>,
     ) -> ExecutorResult<DryRunResult> {
         self.executor.dry_run(
             block,
             forbid_fake_coins,
             at_height,
             record_storage_read_replay,
+            state_overrides,
         )
     }
 }
@@ -151,6 +156,16 @@ impl fuel_core_producer::ports::StorageReadReplayRecorder for ExecutorAdapter {
     }
 }
 
+impl fuel_core_producer::ports::TransactionTracer for ExecutorAdapter {
+    fn trace_transaction(
+        &self,
+        block: &Block,
+        tx_id: &TxId,
+    ) -> ExecutorResult<TransactionTrace> {
+        self.executor.trace_transaction(block, tx_id)
+    }
+}
+
 #[async_trait::async_trait]
 impl fuel_core_producer::ports::Relayer for MaybeRelayerAdapter {
     async fn wait_for_at_least_height(
@@ -80,6 +80,9 @@ impl ProtocolPublicKey for ConsensusConfigProtocolPublicKey {
         match &self.inner {
             ConsensusConfig::PoA { signing_key } => *signing_key,
             ConsensusConfig::PoAV2(poa_v2) => poa_v2.latest_address(),
+            // The BFT network has no single block producer delegating
+            // the signing of pre-confirmations, so none of them are trusted.
+            ConsensusConfig::Bft(_) => Address::zeroed(),
         }
     }
 }
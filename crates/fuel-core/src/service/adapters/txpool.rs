@@ -6,6 +6,7 @@ use crate::{
         P2PAdapter,
         PreconfirmationSender,
         StaticGasPrice,
+        TxPoolAdmissionFilter,
     },
 };
 use fuel_core_services::stream::BoxStream;
@@ -19,11 +20,15 @@ use fuel_core_storage::{
         ProcessedTransactions,
     },
 };
-use fuel_core_txpool::ports::{
-    BlockImporter,
-    ChainStateInfoProvider as ChainStateInfoProviderTrait,
-    GasPriceProvider,
-    TxStatusManager,
+use fuel_core_txpool::{
+    TxPoolStats,
+    ports::{
+        AdmissionFilter,
+        BlockImporter,
+        ChainStateInfoProvider as ChainStateInfoProviderTrait,
+        GasPriceProvider,
+        TxStatusManager,
+    },
 };
 use fuel_core_types::{
     blockchain::header::ConsensusParametersVersion,
@@ -61,6 +66,7 @@ use fuel_core_types::{
             TransactionStatus,
             statuses,
         },
+        txpool::PoolTransaction,
     },
 };
 use std::sync::Arc;
@@ -246,6 +252,15 @@ impl ChainStateInfoProviderTrait for ChainStateInfoProvider {
     }
 }
 
+impl AdmissionFilter for TxPoolAdmissionFilter {
+    fn admit(&self, tx: &PoolTransaction, stats: &TxPoolStats) -> Result<(), String> {
+        match &self.filter {
+            Some(filter) => filter.admit(tx, stats),
+            None => Ok(()),
+        }
+    }
+}
+
 impl TxStatusManager for PreconfirmationSender {
     fn status_update(&self, tx_id: TxId, tx_status: TransactionStatus) {
         let permit = self.sender_signature_service.try_reserve();
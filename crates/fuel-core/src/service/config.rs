@@ -1,6 +1,7 @@
 use crate::{
     combined_database::CombinedDatabaseConfig,
     graphql_api::ServiceConfig as GraphQLConfig,
+    service::adapters::TxPoolAdmissionFilter,
 };
 use clap::ValueEnum;
 use fuel_core_chain_config::SnapshotReader;
@@ -15,9 +16,11 @@ use fuel_core_types::{
         AssetId,
         ChainId,
     },
+    secrecy::Secret,
     signer::SignMode,
 };
 use std::{
+    net::SocketAddr,
     num::{
         NonZeroU32,
         NonZeroU64,
@@ -55,10 +58,7 @@ use fuel_core_chain_config::{
 #[cfg(feature = "rpc")]
 use fuel_core_types::fuel_types::BlockHeight;
 #[cfg(feature = "test-helpers")]
-use std::net::{
-    SocketAddr,
-    TcpListener,
-};
+use std::net::TcpListener;
 
 #[derive(Clone, Debug)]
 pub struct RedisLeaderLockConfig {
@@ -73,6 +73,33 @@ pub struct RedisLeaderLockConfig {
     pub quorum_disruption_budget: u32,
 }
 
+/// The leader lock backed by the Raft log replicated between
+/// the block producer replicas themselves.
+#[derive(Clone, Debug)]
+pub struct RaftLeaderLockConfig {
+    /// The identifier of this replica. Must be unique within the cluster.
+    pub node_id: u64,
+    /// The address where this replica accepts connections from its peers.
+    pub listen_addr: SocketAddr,
+    /// Other replicas of the cluster.
+    pub peers: Vec<RaftPeer>,
+    /// The directory for the Raft log.
+    pub data_dir: PathBuf,
+    /// The secret shared by the replicas of the cluster. Only the peers
+    /// knowing it are accepted.
+    pub shared_secret: Secret<String>,
+    pub election_timeout: Duration,
+    pub heartbeat_interval: Duration,
+    pub rpc_timeout: Duration,
+    pub stream_max_len: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RaftPeer {
+    pub id: u64,
+    pub addr: SocketAddr,
+}
+
 #[derive(Clone, Debug)]
 pub struct Config {
     pub graphql_config: GraphQLConfig,
@@ -90,6 +117,12 @@ pub struct Config {
     /// - Enables querying historical contract state and balances.
     pub historical_execution: bool,
     pub expensive_subscriptions: bool,
+    /// Enables indexation of the log receipts by the contract and the log id.
+    pub logs_indexation: bool,
+    /// Enables indexation of the fee statistics of each block for the fee history.
+    pub fee_history_indexation: bool,
+    /// Enables the GraphQL API to manage the p2p peers at runtime.
+    pub peer_admin_api: bool,
     pub utxo_validation: bool,
     pub allow_syscall: bool,
     pub native_executor_version: Option<StateTransitionBytecodeVersion>,
@@ -97,8 +130,12 @@ pub struct Config {
     pub executor_number_of_cores: NonZeroUsize,
     pub block_production: Trigger,
     pub leader_lock: Option<RedisLeaderLockConfig>,
+    pub raft_leader_lock: Option<RaftLeaderLockConfig>,
     pub predefined_blocks_path: Option<PathBuf>,
     pub txpool: TxPoolConfig,
+    /// The filter of transactions admitted into the `TxPool`.
+    /// Allows embedders of the node to install a custom admission policy.
+    pub txpool_admission_filter: TxPoolAdmissionFilter,
     pub tx_status_manager: TxStatusManagerConfig,
     pub block_producer: fuel_core_producer::Config,
     pub gas_price_config: GasPriceConfig,
@@ -115,6 +152,9 @@ pub struct Config {
     #[cfg(feature = "p2p")]
     pub pre_confirmation_signature_service:
         fuel_core_poa::pre_confirmation_signature_service::config::Config,
+    /// The timeouts of the consensus steps when the chain uses BFT consensus.
+    #[cfg(feature = "p2p")]
+    pub bft_timeouts: fuel_core_bft::Timeouts,
     #[cfg(feature = "shared-sequencer")]
     pub shared_sequencer: fuel_core_shared_sequencer::Config,
     pub consensus_signer: SignMode,
@@ -236,6 +276,7 @@ impl Config {
                 max_queries_resolver_recursive_depth: 1,
                 max_queries_directives: 10,
                 max_concurrent_queries: 1024,
+                max_websocket_connections: 1024,
                 request_body_bytes_limit: 16 * 1024 * 1024,
                 query_log_threshold_time: Duration::from_secs(2),
                 api_request_timeout: Duration::from_secs(60),
@@ -244,6 +285,8 @@ impl Config {
                 costs: Default::default(),
                 required_fuel_block_height_tolerance: 10,
                 required_fuel_block_height_timeout: Duration::from_secs(30),
+                rate_limit: crate::graphql_api::RateLimitConfig::unlimited(),
+                api_keys_path: None,
             },
             combined_db_config,
             continue_on_error: false,
@@ -251,6 +294,9 @@ impl Config {
             historical_execution: true,
             allow_syscall: true,
             expensive_subscriptions: true,
+            logs_indexation: true,
+            fee_history_indexation: true,
+            peer_admin_api: false,
             utxo_validation,
             native_executor_version: Some(native_executor_version),
             #[cfg(feature = "parallel-executor")]
@@ -258,12 +304,14 @@ impl Config {
             snapshot_reader,
             block_production: Trigger::Instant,
             leader_lock: None,
+            raft_leader_lock: None,
             predefined_blocks_path: None,
             txpool: TxPoolConfig {
                 utxo_validation,
                 max_txs_ttl: MAX_TXS_TTL,
                 ..Default::default()
             },
+            txpool_admission_filter: TxPoolAdmissionFilter::default(),
             tx_status_manager: TxStatusManagerConfig {
                 subscription_ttl: MAX_TXS_TTL,
                 ..Default::default()
@@ -284,6 +332,8 @@ impl Config {
             pre_confirmation_signature_service:
                 fuel_core_poa::pre_confirmation_signature_service::config::Config::default(
                 ),
+            #[cfg(feature = "p2p")]
+            bft_timeouts: Default::default(),
             #[cfg(feature = "shared-sequencer")]
             shared_sequencer: fuel_core_shared_sequencer::Config::local_node(),
             consensus_signer: SignMode::Key(fuel_core_types::secrecy::Secret::new(
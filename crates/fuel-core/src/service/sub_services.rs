@@ -24,6 +24,11 @@ use crate::service::adapters::consensus_module::poa::pre_confirmation_signature:
     trigger::TimeBasedTrigger,
     tx_receiver::PreconfirmationsReceiver,
 };
+#[cfg(feature = "p2p")]
+use crate::service::adapters::{
+    BftBlockValidatorAdapter,
+    BftSigningStateAdapter,
+};
 use crate::{
     combined_database::CombinedDatabase,
     database::Database,
@@ -56,9 +61,11 @@ use crate::{
             chain_state_info_provider,
             consensus_module::poa::{
                 InDirectoryPredefinedBlocks,
+                LeaderLeaseStatusReporter,
                 NoopReconciliationAdapter,
                 ReconciliationAdapter,
                 RedisLeaderLeaseAdapter,
+                raft_leader_lease,
             },
             fuel_gas_price_provider::FuelGasPriceProvider,
             graphql_api::GraphQLBlockImporter,
@@ -68,6 +75,9 @@ use crate::{
         },
     },
 };
+#[cfg(feature = "p2p")]
+use fuel_core_bft::validators::ValidatorSet;
+use fuel_core_chain_config::ConsensusConfig;
 use fuel_core_compression_service::service::new_service as new_compression_service;
 use fuel_core_gas_price_service::v1::{
     algorithm::AlgorithmV1,
@@ -86,6 +96,8 @@ use fuel_core_storage::{
 };
 #[cfg(feature = "relayer")]
 use fuel_core_types::blockchain::primitives::DaBlockHeight;
+#[cfg(feature = "p2p")]
+use fuel_core_types::fuel_tx::Input;
 use fuel_core_types::signer::SignMode;
 #[cfg(feature = "rpc")]
 use rpc::*;
@@ -121,6 +133,10 @@ pub type PoAService = fuel_core_poa::Service<
 #[cfg(feature = "p2p")]
 pub type P2PService = fuel_core_p2p::service::Service<Database, TxPoolAdapter>;
 pub type TxPoolSharedState = fuel_core_txpool::SharedState;
+
+/// The file in the database directory storing the messages signed by the BFT validator.
+#[cfg(feature = "p2p")]
+const BFT_SIGNING_STATE_FILE: &str = "bft_signing_state";
 pub type BlockProducerService = fuel_core_producer::block_producer::Producer<
     Database,
     TxPoolAdapter,
@@ -168,8 +184,17 @@ pub fn init_sub_services(
     }
 
     #[cfg(feature = "p2p")]
-    let p2p_externals = config
-        .p2p
+    let p2p_config = config.p2p.clone().map(|mut p2p_config| {
+        // The validators of the BFT chain gossip the consensus messages,
+        // and other nodes forward them.
+        if matches!(chain_config.consensus, ConsensusConfig::Bft(_)) {
+            p2p_config.subscribe_to_bft_consensus = true;
+        }
+        p2p_config
+    });
+
+    #[cfg(feature = "p2p")]
+    let p2p_externals = p2p_config
         .clone()
         .map(fuel_core_p2p::service::build_shared_state);
 
@@ -229,6 +254,7 @@ pub fn init_sub_services(
     let verifier = VerifierAdapter::new(
         &genesis_block,
         chain_config.consensus.clone(),
+        chain_id,
         database.on_chain().clone(),
     );
 
@@ -252,6 +278,28 @@ pub fn init_sub_services(
             })
         })
         .transpose()?;
+    let raft_leader_lease = config
+        .raft_leader_lock
+        .as_ref()
+        .map(raft_leader_lease::new_service)
+        .transpose()?;
+    if redis_reconciliation_adapter.is_some() && raft_leader_lease.is_some() {
+        return Err(anyhow::anyhow!(
+            "Only one of the Redis and Raft leader locks can be enabled"
+        ));
+    }
+    let raft_reconciliation_adapter = raft_leader_lease
+        .as_ref()
+        .map(|raft_leader_lease| raft_leader_lease.shared.clone());
+
+    let block_reconciliation_write_adapter = match (
+        redis_reconciliation_adapter.as_ref(),
+        raft_reconciliation_adapter.as_ref(),
+    ) {
+        (Some(redis), _) => BlockReconciliationWriteAdapter::Redis(redis.clone()),
+        (None, Some(raft)) => BlockReconciliationWriteAdapter::Raft(raft.clone()),
+        (None, None) => BlockReconciliationWriteAdapter::Noop(Default::default()),
+    };
 
     let importer_adapter = BlockImporterAdapter::new(
         chain_id,
@@ -259,11 +307,7 @@ pub fn init_sub_services(
         database.on_chain().clone(),
         executor.clone(),
         verifier.clone(),
-        redis_reconciliation_adapter
-            .as_ref()
-            .cloned()
-            .map(BlockReconciliationWriteAdapter::Redis)
-            .unwrap_or_else(|| BlockReconciliationWriteAdapter::Noop(Default::default())),
+        block_reconciliation_write_adapter,
     );
 
     let chain_state_info_provider_service = chain_state_info_provider::new_service(
@@ -340,11 +384,13 @@ pub fn init_sub_services(
         executor.clone(),
         new_txs_updater,
         preconfirmation_sender,
+        config.txpool_admission_filter.clone(),
     );
-    let tx_pool_adapter = TxPoolAdapter::new(txpool.shared.clone());
+    let tx_pool_adapter =
+        TxPoolAdapter::new(txpool.shared.clone(), chain_state_info_provider.clone());
 
     #[cfg(feature = "p2p")]
-    let mut network = config.p2p.clone().zip(p2p_externals).map(
+    let mut network = p2p_config.zip(p2p_externals).map(
         |(p2p_config, (shared_state, request_receiver))| {
             fuel_core_p2p::service::new_service(
                 chain_id,
@@ -382,6 +428,11 @@ pub fn init_sub_services(
         tracing::info!("Enabled manual block production because of `debug` flag");
     }
 
+    // The blocks of the BFT chain are produced by the validators during the consensus.
+    if matches!(chain_config.consensus, ConsensusConfig::Bft(_)) {
+        production_enabled = false;
+    }
+
     let signer = FuelBlockSigner::new(config.consensus_signer.clone());
 
     #[cfg(feature = "shared-sequencer")]
@@ -420,15 +471,66 @@ pub fn init_sub_services(
         })
         .transpose()?;
 
+    #[cfg(feature = "p2p")]
+    let bft = match &chain_config.consensus {
+        ConsensusConfig::Bft(bft_config) if network.is_some() => {
+            let validator_address = config
+                .consensus_signer
+                .public_key()?
+                .map(|public_key| Input::owner(&public_key))
+                .filter(|address| bft_config.validators().contains(address));
+
+            validator_address.map(|validator_address| {
+                let bft_config = fuel_core_bft::Config {
+                    validators: ValidatorSet::new(bft_config.validators().to_vec()),
+                    validator_address,
+                    chain_id,
+                    timeouts: config.bft_timeouts,
+                };
+                let signing_state = match config.combined_db_config.database_type {
+                    DbType::RocksDb => BftSigningStateAdapter::File(
+                        config
+                            .combined_db_config
+                            .database_path
+                            .join(BFT_SIGNING_STATE_FILE),
+                    ),
+                    DbType::InMemory => {
+                        BftSigningStateAdapter::InMemory(Default::default())
+                    }
+                };
+                fuel_core_bft::new_service(
+                    &last_block_header,
+                    bft_config,
+                    producer_adapter.clone(),
+                    BftBlockValidatorAdapter {
+                        verifier: verifier.clone(),
+                        executor: executor.clone(),
+                    },
+                    importer_adapter.clone(),
+                    Arc::new(signer.clone()),
+                    p2p_adapter.clone(),
+                    signing_state,
+                )
+            })
+        }
+        _ => None,
+    };
+
+    #[cfg(feature = "p2p")]
+    let block_production = production_enabled || bft.is_some();
+    #[cfg(not(feature = "p2p"))]
+    let block_production = production_enabled;
+
     let poa = production_enabled
         .then(|| -> anyhow::Result<_> {
-            let reconciliation_port = redis_reconciliation_adapter
-                .as_ref()
-                .cloned()
-                .map(ReconciliationAdapter::Redis)
-                .unwrap_or_else(|| {
-                    ReconciliationAdapter::Noop(NoopReconciliationAdapter)
-                });
+            let reconciliation_port = match (
+                redis_reconciliation_adapter.as_ref(),
+                raft_reconciliation_adapter.as_ref(),
+            ) {
+                (Some(redis), _) => ReconciliationAdapter::Redis(redis.clone()),
+                (None, Some(raft)) => ReconciliationAdapter::Raft(raft.clone()),
+                (None, None) => ReconciliationAdapter::Noop(NoopReconciliationAdapter),
+            };
 
             Ok(fuel_core_poa::new_service(
                 &last_block_header,
@@ -445,7 +547,20 @@ pub fn init_sub_services(
             ))
         })
         .transpose()?;
-    let poa_adapter = PoAAdapter::new(poa.as_ref().map(|service| service.shared.clone()));
+    let leader_lease_status = match (
+        redis_reconciliation_adapter.as_ref(),
+        raft_reconciliation_adapter.as_ref(),
+    ) {
+        (Some(redis), _) => {
+            Some(LeaderLeaseStatusReporter::Redis(redis.status_reporter()))
+        }
+        (None, Some(raft)) => Some(LeaderLeaseStatusReporter::Raft(raft.clone())),
+        (None, None) => None,
+    };
+    let poa_adapter = PoAAdapter::new(
+        poa.as_ref().map(|service| service.shared.clone()),
+        leader_lease_status,
+    );
 
     #[cfg(feature = "p2p")]
     let sync = fuel_core_sync::service::new_service(
@@ -496,6 +611,8 @@ pub fn init_sub_services(
         off_chain_database: database.off_chain().clone(),
         continue_on_error: config.continue_on_error,
         block_subscriptions_queue: config.graphql_config.block_subscriptions_queue,
+        logs_indexation_enabled: config.logs_indexation,
+        fee_history_indexation_enabled: config.fee_history_indexation,
         consensus_parameters: &chain_config.consensus_parameters,
     };
     let graphql_worker =
@@ -510,10 +627,15 @@ pub fn init_sub_services(
         historical_execution: config.historical_execution,
         allow_syscall: config.allow_syscall,
         expensive_subscriptions: config.expensive_subscriptions,
+        logs_indexation: config.logs_indexation,
+        fee_history_indexation: config.fee_history_indexation,
+        peer_admin_api: config.peer_admin_api,
+        block_production,
         max_tx: config.txpool.pool_limits.max_txs,
         max_gas: config.txpool.pool_limits.max_gas,
         max_size: config.txpool.pool_limits.max_bytes_size,
         max_txpool_dependency_chain_length: config.txpool.max_txs_chain_count,
+        max_bundle_size: config.txpool.max_bundle_size,
         chain_name,
     };
 
@@ -604,10 +726,18 @@ pub fn init_sub_services(
         services.push(Box::new(compression_service));
     }
 
+    if let Some(raft_leader_lease) = raft_leader_lease {
+        services.push(Box::new(raft_leader_lease));
+    }
+
     // always make sure that the block producer is inserted last
     if let Some(poa) = poa {
         services.push(Box::new(poa));
     }
+    #[cfg(feature = "p2p")]
+    if let Some(bft) = bft {
+        services.push(Box::new(bft));
+    }
 
     Ok((services, shared))
 }
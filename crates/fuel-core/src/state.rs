@@ -63,7 +63,7 @@ pub trait TransactableStorage<Height>: IterableStore + Debug + Send + Sync {
     fn view_at_height(
         &self,
         height: &Height,
-    ) -> StorageResult<KeyValueView<Self::Column, Height>>;
+    ) -> StorageResult<IterableKeyValueView<Self::Column, Height>>;
 
     fn latest_view(&self) -> StorageResult<IterableKeyValueView<Self::Column, Height>>;
 
@@ -88,7 +88,7 @@ where
     fn view_at_height(
         &self,
         _: &Height,
-    ) -> StorageResult<KeyValueView<Self::Column, Height>> {
+    ) -> StorageResult<IterableKeyValueView<Self::Column, Height>> {
         unimplemented!()
     }
 
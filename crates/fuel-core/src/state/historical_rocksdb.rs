@@ -11,7 +11,6 @@ use crate::{
         ColumnType,
         HeightType,
         IterableKeyValueView,
-        KeyValueView,
         TransactableStorage,
         historical_rocksdb::{
             description::{
@@ -22,7 +21,6 @@ use crate::{
             view_at_height::ViewAtHeight,
         },
         iterable_key_value_view::IterableKeyValueViewWrapper,
-        key_value_view::KeyValueViewWrapper,
         rocks_db::RocksDb,
     },
 };
@@ -605,11 +603,12 @@ where
     fn view_at_height(
         &self,
         height: &Description::Height,
-    ) -> StorageResult<KeyValueView<ColumnType<Description>, HeightType<Description>>>
-    {
+    ) -> StorageResult<
+        IterableKeyValueView<ColumnType<Description>, HeightType<Description>>,
+    > {
         let view = self.create_view_at(height)?;
-        Ok(KeyValueView::from_storage_and_metadata(
-            KeyValueViewWrapper::new(view),
+        Ok(IterableKeyValueView::from_storage_and_metadata(
+            IterableKeyValueViewWrapper::new(view),
             Some(*height),
         ))
     }
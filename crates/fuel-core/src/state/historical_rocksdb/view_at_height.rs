@@ -1,5 +1,8 @@
 use crate::{
-    database::database_description::DatabaseDescription,
+    database::{
+        convert_to_rocksdb_direction,
+        database_description::DatabaseDescription,
+    },
     state::{
         historical_rocksdb::{
             description::{
@@ -11,18 +14,33 @@ use crate::{
         },
         rocks_db::{
             KeyAndValue,
+            KeyOnly,
             RocksDb,
+            next_prefix,
         },
     },
 };
+use core::cmp::Ordering;
 use fuel_core_storage::{
     Result as StorageResult,
+    iter::{
+        BoxedIter,
+        IntoBoxedIter,
+        IterDirection,
+        IterableStore,
+    },
     kv_store::{
+        KVItem,
+        KeyItem,
         KeyValueInspect,
         Value,
         WriteOperation,
     },
 };
+use itertools::{
+    EitherOrBoth,
+    Itertools,
+};
 use rocksdb::{
     IteratorMode,
     ReadOptions,
@@ -40,6 +58,109 @@ where
     pub fn new(height: u64, read_db: RocksDb<Historical<Description>>) -> Self {
         Self { height, read_db }
     }
+
+    /// Returns the sorted keys of the `column` that may exist at the height of the view.
+    /// It lazily merges the keys from the latest state with the keys modified after the height.
+    fn candidate_keys<'a>(
+        &'a self,
+        column: Description::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> impl Iterator<Item = StorageResult<Vec<u8>>> + 'a + use<'a, Description> {
+        let latest_keys = self.read_db.iter_store_keys(
+            Column::OriginalColumn(column),
+            prefix,
+            start,
+            direction,
+        );
+        let modified_keys = self.modified_keys(column, prefix, start, direction);
+
+        latest_keys
+            .merge_join_by(modified_keys, move |latest, modified| {
+                match (latest, modified) {
+                    (Ok(latest), Ok(modified)) => match direction {
+                        IterDirection::Forward => latest.cmp(modified),
+                        IterDirection::Reverse => modified.cmp(latest),
+                    },
+                    // Errors are returned as soon as possible.
+                    (Err(_), _) => Ordering::Less,
+                    (_, Err(_)) => Ordering::Greater,
+                }
+            })
+            .map(|keys| match keys {
+                EitherOrBoth::Both(key, _)
+                | EitherOrBoth::Left(key)
+                | EitherOrBoth::Right(key) => key,
+            })
+    }
+
+    /// Returns the sorted and deduplicated keys of the `column`
+    /// modified at or after the height of the view.
+    fn modified_keys<'a>(
+        &'a self,
+        column: Description::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> impl Iterator<Item = StorageResult<Vec<u8>>> + 'a + use<'a, Description> {
+        let prefix = prefix.unwrap_or_default().to_vec();
+        // The historical keys are the original keys followed by the height,
+        // so the reverse iteration starts after all heights of the `start` key.
+        let seek_key = match (direction, start) {
+            (IterDirection::Forward, Some(start)) => Some(start.to_vec()),
+            (IterDirection::Forward, None) => Some(prefix.clone()),
+            (IterDirection::Reverse, Some(start)) => {
+                Some([start, &u64::MAX.to_be_bytes()].concat())
+            }
+            (IterDirection::Reverse, None) => next_prefix(prefix.clone()),
+        };
+        let iter_mode = match &seek_key {
+            Some(seek_key) => {
+                IteratorMode::From(seek_key, convert_to_rocksdb_direction(direction))
+            }
+            None => IteratorMode::End,
+        };
+        let mut options = ReadOptions::default();
+        // The prefix of the historical column includes the height,
+        // so we can't rely on the prefix bloom filters here.
+        options.set_total_order_seek(true);
+        let height = self.height;
+        let mut last_key: Option<Vec<u8>> = None;
+
+        self.read_db
+            .iterator::<KeyOnly>(
+                Column::HistoricalDuplicateColumn(column),
+                options,
+                iter_mode,
+            )
+            .take_while(move |item| {
+                item.as_ref()
+                    .map(|height_key| height_key.starts_with(&prefix))
+                    .unwrap_or(true)
+            })
+            .filter_map(move |item| {
+                let mut key = match item {
+                    Ok(key) => key,
+                    Err(err) => return Some(Err(err)),
+                };
+                let height_bytes = key.split_off(key.len().saturating_sub(8));
+                let modified_at = match <[u8; 8]>::try_from(height_bytes) {
+                    Ok(height_bytes) => u64::from_be_bytes(height_bytes),
+                    Err(_) => {
+                        return Some(Err(anyhow::anyhow!(
+                            "The historical key is too short"
+                        )
+                        .into()))
+                    }
+                };
+                if modified_at < height || last_key.as_ref() == Some(&key) {
+                    return None
+                }
+                last_key = Some(key.clone());
+                Some(Ok(key))
+            })
+    }
 }
 
 impl<Description> KeyValueInspect for ViewAtHeight<Description>
@@ -84,6 +205,53 @@ where
     }
 }
 
+/// The iteration over the historical view looks up the value of each key at the height
+/// of the view, so it is slower than the iteration over the latest view.
+impl<Description> IterableStore for ViewAtHeight<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn iter_store(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, KVItem> {
+        if let (Some(prefix), Some(start)) = (prefix, start)
+            && !start.starts_with(prefix)
+        {
+            return core::iter::empty().into_boxed();
+        }
+
+        self.candidate_keys(column, prefix, start, direction)
+            .filter_map(move |key| {
+                let key = match key {
+                    Ok(key) => key,
+                    Err(err) => return Some(Err(err)),
+                };
+                match self.get(&key, column) {
+                    Ok(Some(value)) => Some(Ok((key, value))),
+                    Ok(None) => None,
+                    Err(err) => Some(Err(err)),
+                }
+            })
+            .into_boxed()
+    }
+
+    fn iter_store_keys(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, KeyItem> {
+        self.iter_store(column, prefix, start, direction)
+            .map(|item| item.map(|(key, _)| key))
+            .into_boxed()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -101,6 +269,8 @@ mod tests {
         ContractsAssetKey,
         StorageAsMut,
         StorageAsRef,
+        iter::IteratorOverTable,
+        structured_storage::StructuredStorage,
         tables::ContractsAssets,
         transactional::{
             IntoTransaction,
@@ -245,4 +415,91 @@ mod tests {
         assert_eq!(balance_at_height_one, 123);
         assert_eq!(balance_at_height_two, 321);
     }
+
+    #[test]
+    fn historical_rocksdb_view_at_height_iterates_over_the_state_at_that_height() {
+        // Given
+        let rocks_db = RocksDb::<Historical<OnChain>>::default_open_temp().unwrap();
+        let historical_rocks_db =
+            HistoricalRocksDB::new(rocks_db, StateRewindPolicy::RewindFullRange).unwrap();
+        let contract_id = [123; 32].into();
+        let asset = |byte: u8| ContractsAssetKey::new(&contract_id, &[byte; 32].into());
+        let other_contract_asset =
+            ContractsAssetKey::new(&[1; 32].into(), &[1; 32].into());
+
+        // At height 1 the contract has assets 1 and 2.
+        let mut transaction = historical_rocks_db.read_transaction();
+        transaction
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&asset(1), &1)
+            .unwrap();
+        transaction
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&asset(2), &2)
+            .unwrap();
+        transaction
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&other_contract_asset, &100)
+            .unwrap();
+        historical_rocks_db
+            .commit_changes(Some(1u32.into()), transaction.into_changes().into())
+            .unwrap();
+
+        // At height 2 the asset 1 is removed, the asset 2 is updated and the asset 3 is added.
+        let mut transaction = historical_rocks_db.read_transaction();
+        transaction
+            .storage_as_mut::<ContractsAssets>()
+            .remove(&asset(1))
+            .unwrap();
+        transaction
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&asset(2), &20)
+            .unwrap();
+        transaction
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&asset(3), &3)
+            .unwrap();
+        historical_rocks_db
+            .commit_changes(Some(2u32.into()), transaction.into_changes().into())
+            .unwrap();
+
+        // When
+        let view_at_height_one = StructuredStorage::new(
+            historical_rocks_db.create_view_at(&1u32.into()).unwrap(),
+        );
+        let forward: Vec<_> = view_at_height_one
+            .iter_all_by_prefix::<ContractsAssets, _>(Some(contract_id))
+            .try_collect()
+            .unwrap();
+        let reverse: Vec<_> = view_at_height_one
+            .iter_all_filtered::<ContractsAssets, _>(
+                Some(contract_id),
+                None,
+                Some(IterDirection::Reverse),
+            )
+            .try_collect()
+            .unwrap();
+        let forward_from_asset_two: Vec<_> = view_at_height_one
+            .iter_all_filtered::<ContractsAssets, _>(
+                Some(contract_id),
+                Some(&asset(2)),
+                Some(IterDirection::Forward),
+            )
+            .try_collect()
+            .unwrap();
+        let reverse_from_asset_three: Vec<_> = view_at_height_one
+            .iter_all_filtered::<ContractsAssets, _>(
+                Some(contract_id),
+                Some(&asset(3)),
+                Some(IterDirection::Reverse),
+            )
+            .try_collect()
+            .unwrap();
+
+        // Then
+        assert_eq!(forward, vec![(asset(1), 1), (asset(2), 2)]);
+        assert_eq!(reverse, vec![(asset(2), 2), (asset(1), 1)]);
+        assert_eq!(forward_from_asset_two, vec![(asset(2), 2)]);
+        assert_eq!(reverse_from_asset_three, vec![(asset(2), 2), (asset(1), 1)]);
+    }
 }
@@ -8,6 +8,7 @@ use crate::{
 use prometheus_client::{
     encoding::EncodeLabelSet,
     metrics::{
+        counter::Counter,
         family::Family,
         gauge::Gauge,
         histogram::Histogram,
@@ -21,11 +22,20 @@ pub struct Label {
     path: String,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct RateLimitLabel {
+    // the way the client is identified: `ip` or `api_key`
+    client: &'static str,
+}
+
 pub struct GraphqlMetrics {
     // using gauges in case blocks are rolled back for any reason
     pub total_txs_count: Gauge,
     requests: Family<Label, Histogram>,
     queries_complexity: Histogram,
+    rate_limited_requests: Family<RateLimitLabel, Counter>,
+    rate_limited_complexity: Family<RateLimitLabel, Counter>,
+    rate_limit_tracked_clients: Gauge,
 }
 
 impl GraphqlMetrics {
@@ -35,6 +45,9 @@ impl GraphqlMetrics {
         let requests = Family::<Label, Histogram>::new_with_constructor(|| {
             Histogram::new(buckets(Buckets::Timing))
         });
+        let rate_limited_requests = Family::<RateLimitLabel, Counter>::default();
+        let rate_limited_complexity = Family::<RateLimitLabel, Counter>::default();
+        let rate_limit_tracked_clients = Gauge::default();
         let mut registry = global_registry().registry.lock();
         registry.register("graphql_request_duration_seconds", "", requests.clone());
         registry.register(
@@ -43,6 +56,22 @@ impl GraphqlMetrics {
             queries_complexity.clone(),
         );
 
+        registry.register(
+            "graphql_rate_limited_requests",
+            "The number of requests rejected because the client exceeded its complexity budget",
+            rate_limited_requests.clone(),
+        );
+        registry.register(
+            "graphql_rate_limited_complexity",
+            "The accumulated complexity of queries counted against the clients budgets",
+            rate_limited_complexity.clone(),
+        );
+        registry.register(
+            "graphql_rate_limit_tracked_clients",
+            "The number of clients tracked by the rate limiter in the current window",
+            rate_limit_tracked_clients.clone(),
+        );
+
         registry.register(
             "importer_tx_count",
             "the total amount of transactions that have been imported on chain",
@@ -53,6 +82,9 @@ impl GraphqlMetrics {
             total_txs_count: tx_count_gauge,
             queries_complexity,
             requests,
+            rate_limited_requests,
+            rate_limited_complexity,
+            rate_limit_tracked_clients,
         }
     }
 
@@ -66,6 +98,23 @@ impl GraphqlMetrics {
     pub fn graphql_complexity_observe(&self, complexity: f64) {
         self.queries_complexity.observe(complexity);
     }
+
+    pub fn rate_limit_consumed(&self, client: &'static str, complexity: u64) {
+        self.rate_limited_complexity
+            .get_or_create(&RateLimitLabel { client })
+            .inc_by(complexity);
+    }
+
+    pub fn rate_limit_rejected(&self, client: &'static str) {
+        self.rate_limited_requests
+            .get_or_create(&RateLimitLabel { client })
+            .inc();
+    }
+
+    pub fn rate_limit_tracked_clients(&self, clients: usize) {
+        self.rate_limit_tracked_clients
+            .set(i64::try_from(clients).unwrap_or(i64::MAX));
+    }
 }
 
 static GRAPHQL_METRICS: OnceLock<GraphqlMetrics> = OnceLock::new();
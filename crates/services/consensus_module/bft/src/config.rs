@@ -0,0 +1,68 @@
+use crate::{
+    state_machine::TimeoutStep,
+    validators::ValidatorSet,
+};
+use fuel_core_types::fuel_types::{
+    Address,
+    ChainId,
+};
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// The validators of the network.
+    pub validators: ValidatorSet,
+    /// The address of the signing key of this validator.
+    pub validator_address: Address,
+    /// The id of the chain, signed by the validators along with each message.
+    pub chain_id: ChainId,
+    /// The timeouts of the consensus steps.
+    pub timeouts: Timeouts,
+}
+
+/// The timeouts of the consensus steps.
+///
+/// The timeouts of the round grow with the round number, so eventually
+/// they are long enough for the validators to agree under the network delays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timeouts {
+    /// How long the validator waits for the proposal of the round.
+    pub propose: Duration,
+    /// How long the validator waits for the prevotes to agree on the block.
+    pub prevote: Duration,
+    /// How long the validator waits for the precommits to agree on the block.
+    pub precommit: Duration,
+    /// The increase of the step timeouts with each round.
+    pub round_increment: Duration,
+    /// How long the validator waits after the commit of the block before
+    /// it starts the next height. It defines the minimal block time.
+    pub commit: Duration,
+}
+
+impl Default for Timeouts {
+    fn default() -> Self {
+        Self {
+            propose: Duration::from_secs(3),
+            prevote: Duration::from_secs(1),
+            precommit: Duration::from_secs(1),
+            round_increment: Duration::from_millis(500),
+            commit: Duration::from_secs(1),
+        }
+    }
+}
+
+impl Timeouts {
+    /// Returns the timeout of the step in the round.
+    pub fn duration(&self, step: TimeoutStep, round: u32) -> Duration {
+        let base = match step {
+            TimeoutStep::Propose => self.propose,
+            TimeoutStep::Prevote => self.prevote,
+            TimeoutStep::Precommit => self.precommit,
+        };
+        let increment = self
+            .round_increment
+            .checked_mul(round)
+            .unwrap_or(Duration::MAX);
+        base.saturating_add(increment)
+    }
+}
@@ -0,0 +1,87 @@
+use crate::signing_state::SigningState;
+use fuel_core_services::stream::BoxStream;
+use fuel_core_types::{
+    blockchain::{
+        SealedBlock,
+        block::Block,
+        consensus::bft::BftMessage,
+    },
+    fuel_crypto::{
+        Message,
+        Signature,
+    },
+    fuel_types::BlockHeight,
+    services::{
+        block_importer::BlockImportInfo,
+        p2p::{
+            GossipData,
+            GossipsubMessageAcceptance,
+            GossipsubMessageInfo,
+        },
+    },
+    tai64::Tai64,
+};
+use tokio::time::Instant;
+
+/// The consensus message gossiped by the peer.
+pub type BftGossipData = GossipData<BftMessage>;
+
+#[async_trait::async_trait]
+pub trait BlockProducer: Send + Sync {
+    /// Produces the block with transactions from the transaction pool
+    /// without committing it.
+    async fn produce_block(
+        &self,
+        height: BlockHeight,
+        block_time: Tai64,
+        deadline: Instant,
+    ) -> anyhow::Result<Block>;
+}
+
+#[async_trait::async_trait]
+pub trait BlockValidator: Send + Sync {
+    /// Verifies the fields of the proposed block and executes it
+    /// on top of the latest block without committing it.
+    async fn validate_block(&self, block: &Block) -> anyhow::Result<()>;
+}
+
+#[async_trait::async_trait]
+pub trait BlockImporter: Send + Sync {
+    /// Executes and commits the block sealed by the quorum of validators.
+    async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()>;
+
+    /// The stream of the imported blocks, including the blocks
+    /// synchronized from the network.
+    fn block_stream(&self) -> BoxStream<BlockImportInfo>;
+}
+
+#[async_trait::async_trait]
+pub trait Signer: Send + Sync {
+    /// Signs the message with the signing key of the validator.
+    async fn sign_message(&self, message: Message) -> anyhow::Result<Signature>;
+}
+
+pub trait SigningStateStorage: Send + Sync {
+    /// Returns the signing state persisted before the restart, if any.
+    fn load(&self) -> anyhow::Result<Option<SigningState>>;
+
+    /// Durably persists the signing state. The validator signs
+    /// the message only after the state recording it is persisted.
+    fn store(&self, state: &SigningState) -> anyhow::Result<()>;
+}
+
+pub trait P2pPort: Send + Sync {
+    /// Broadcasts the consensus message to the network.
+    fn broadcast_message(&self, message: BftMessage) -> anyhow::Result<()>;
+
+    /// The stream of consensus messages gossiped by the peers.
+    fn gossiped_messages(&self) -> BoxStream<BftGossipData>;
+
+    /// Reports the validity of the gossiped message,
+    /// so the p2p service can forward or drop it.
+    fn notify_message_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        validity: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()>;
+}
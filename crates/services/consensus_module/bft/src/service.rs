@@ -0,0 +1,530 @@
+use crate::{
+    Config,
+    config::Timeouts,
+    ports::{
+        BftGossipData,
+        BlockImporter,
+        BlockProducer,
+        BlockValidator,
+        P2pPort,
+        Signer,
+        SigningStateStorage,
+    },
+    signing_state::SigningState,
+    state_machine::{
+        Action,
+        StateMachine,
+        Timeout,
+    },
+};
+use anyhow::anyhow;
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+    TaskNextAction,
+    stream::BoxStream,
+};
+use fuel_core_types::{
+    blockchain::{
+        SealedBlock,
+        block::Block,
+        consensus::{
+            Consensus,
+            bft::{
+                BftMessage,
+                Proposal,
+                Signed,
+                Vote,
+            },
+        },
+        header::BlockHeader,
+    },
+    fuel_crypto::{
+        Message,
+        Signature,
+    },
+    fuel_types::BlockHeight,
+    services::{
+        block_importer::BlockImportInfo,
+        p2p::{
+            GossipsubMessageAcceptance,
+            GossipsubMessageInfo,
+        },
+    },
+    tai64::Tai64,
+};
+use std::{
+    collections::VecDeque,
+    sync::Arc,
+    time::Duration,
+};
+use tokio::time::{
+    Instant,
+    sleep_until,
+};
+use tokio_stream::StreamExt;
+
+pub type Service<P, V, I, S, N, D> = ServiceRunner<MainTask<P, V, I, S, N, D>>;
+
+/// How long the validator waits before it retries the failed commit of the decided block.
+const COMMIT_RETRY_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Drives the consensus state machine of the validator: produces and validates
+/// proposed blocks, signs and gossips messages, and commits the decided blocks.
+pub struct MainTask<P, V, I, S, N, D> {
+    state_machine: StateMachine,
+    timeouts: Timeouts,
+    block_producer: P,
+    block_validator: V,
+    block_importer: I,
+    signer: Arc<S>,
+    p2p: N,
+    signing_storage: D,
+    /// The persisted state of the messages signed by the validator.
+    signing_state: SigningState,
+    gossiped_messages: BoxStream<BftGossipData>,
+    imported_blocks: BoxStream<BlockImportInfo>,
+    last_height: BlockHeight,
+    last_block_time: Tai64,
+    /// The next height and the instant when the validator starts it.
+    next_height: Option<(BlockHeight, Instant)>,
+    scheduled_timeouts: Vec<(Instant, Timeout)>,
+    /// The decided block that failed to be committed and the instant of the next attempt.
+    failed_commit: Option<(Instant, SealedBlock)>,
+}
+
+impl<P, V, I, S, N, D> MainTask<P, V, I, S, N, D>
+where
+    I: BlockImporter,
+    N: P2pPort,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        last_block: &BlockHeader,
+        config: Config,
+        block_producer: P,
+        block_validator: V,
+        block_importer: I,
+        signer: Arc<S>,
+        p2p: N,
+        signing_storage: D,
+    ) -> Self {
+        let Config {
+            validators,
+            validator_address,
+            chain_id,
+            timeouts,
+        } = config;
+        let gossiped_messages = p2p.gossiped_messages();
+        let imported_blocks = block_importer.block_stream();
+        let last_height = *last_block.height();
+        let next_height = last_height.succ().map(|height| (height, Instant::now()));
+
+        Self {
+            state_machine: StateMachine::new(validators, validator_address, chain_id),
+            timeouts,
+            block_producer,
+            block_validator,
+            block_importer,
+            signer,
+            p2p,
+            signing_storage,
+            signing_state: SigningState::default(),
+            gossiped_messages,
+            imported_blocks,
+            last_height,
+            last_block_time: last_block.time(),
+            next_height,
+            scheduled_timeouts: vec![],
+            failed_commit: None,
+        }
+    }
+}
+
+impl<P, V, I, S, N, D> MainTask<P, V, I, S, N, D>
+where
+    P: BlockProducer,
+    V: BlockValidator,
+    I: BlockImporter,
+    S: Signer,
+    N: P2pPort,
+    D: SigningStateStorage,
+{
+    fn on_block_imported(&mut self, header: &BlockHeader) {
+        let height = *header.height();
+        if height <= self.last_height {
+            return
+        }
+        self.last_height = height;
+        self.last_block_time = header.time();
+        if self
+            .failed_commit
+            .as_ref()
+            .is_some_and(|(_, block)| *block.entity.header().height() <= height)
+        {
+            self.failed_commit = None;
+        }
+
+        let start = Instant::now()
+            .checked_add(self.timeouts.commit)
+            .unwrap_or_else(Instant::now);
+        self.next_height = height.succ().map(|next| (next, start));
+    }
+
+    async fn start_next_height(&mut self) {
+        let Some((height, _)) = self.next_height.take() else {
+            return
+        };
+        tracing::debug!("Starting the consensus for the height {height}");
+        self.scheduled_timeouts
+            .retain(|(_, timeout)| timeout.height >= height);
+        let actions = if self.signing_state.height == height {
+            tracing::info!(
+                "Resuming the consensus for the height {height} from the round {}",
+                self.signing_state.round
+            );
+            self.state_machine.resume_height(&self.signing_state)
+        } else {
+            self.state_machine.start_height(height)
+        };
+        self.execute(actions).await;
+    }
+
+    async fn fire_timeouts(&mut self) {
+        let now = Instant::now();
+        let (expired, scheduled) = core::mem::take(&mut self.scheduled_timeouts)
+            .into_iter()
+            .partition::<Vec<_>, _>(|(at, _)| *at <= now);
+        self.scheduled_timeouts = scheduled;
+
+        for (_, timeout) in expired {
+            let actions = self.state_machine.on_timeout(timeout);
+            self.execute(actions).await;
+        }
+    }
+
+    async fn on_gossiped_message(&mut self, mut message: BftGossipData) {
+        let Some(data) = message.data.take() else {
+            return
+        };
+        let message_info = GossipsubMessageInfo {
+            message_id: message.message_id,
+            peer_id: message.peer_id,
+        };
+
+        let validity = match self.state_machine.on_message(data) {
+            Ok(actions) => {
+                self.report(message_info, GossipsubMessageAcceptance::Accept);
+                self.execute(actions).await;
+                return
+            }
+            Err(err) if err.is_malicious() => {
+                tracing::debug!("Received an invalid consensus message: {err}");
+                GossipsubMessageAcceptance::Reject
+            }
+            Err(_) => GossipsubMessageAcceptance::Ignore,
+        };
+        self.report(message_info, validity);
+    }
+
+    fn report(
+        &self,
+        message_info: GossipsubMessageInfo,
+        validity: GossipsubMessageAcceptance,
+    ) {
+        if let Err(err) = self.p2p.notify_message_validity(message_info, validity) {
+            tracing::error!("Failed to report the validity of the message: {err}");
+        }
+    }
+
+    /// Executes the actions of the state machine along with the actions caused by them.
+    async fn execute(&mut self, actions: Vec<Action>) {
+        let mut actions: VecDeque<_> = actions.into();
+        while let Some(action) = actions.pop_front() {
+            match self.execute_action(action).await {
+                Ok(more) => actions.extend(more),
+                Err(err) => {
+                    tracing::error!("Failed to execute consensus action: {err:?}")
+                }
+            }
+        }
+    }
+
+    async fn execute_action(&mut self, action: Action) -> anyhow::Result<Vec<Action>> {
+        match action {
+            Action::Propose {
+                height,
+                round,
+                valid_block,
+            } => {
+                let (valid_round, block) = match valid_block {
+                    Some((valid_round, block)) => (Some(valid_round), block),
+                    None => (None, self.produce_block(height).await?),
+                };
+                let proposal = Proposal {
+                    height,
+                    round,
+                    valid_round,
+                    block,
+                };
+                let block_id = proposal.block.id();
+                let signature = self
+                    .sign(proposal.signing_message(&self.chain_id()), |state| {
+                        state.record_proposal(height, round, block_id)
+                    })
+                    .await?;
+                let message = BftMessage::Proposal(Signed {
+                    entity: proposal,
+                    signature,
+                });
+                self.broadcast(message).await
+            }
+            Action::ValidateProposal {
+                height,
+                round,
+                block,
+            } => {
+                let valid = match self.block_validator.validate_block(&block).await {
+                    Ok(()) => true,
+                    Err(err) => {
+                        tracing::warn!(
+                            "The proposed block at height {height} round {round} is invalid: {err:?}"
+                        );
+                        false
+                    }
+                };
+                Ok(self.state_machine.on_proposal_validated(
+                    height,
+                    round,
+                    block.id(),
+                    valid,
+                ))
+            }
+            Action::Vote(vote) => {
+                let signature = self
+                    .sign(vote.signing_message(&self.chain_id()), |state| {
+                        state.record_vote(&vote)
+                    })
+                    .await?;
+                let message = BftMessage::Vote(Signed::<Vote> {
+                    entity: vote,
+                    signature,
+                });
+                self.broadcast(message).await
+            }
+            Action::ScheduleTimeout(timeout) => {
+                let duration = self.timeouts.duration(timeout.step, timeout.round);
+                let at = Instant::now()
+                    .checked_add(duration)
+                    .ok_or(anyhow!("The timeout exceeds system limits"))?;
+                self.scheduled_timeouts.push((at, timeout));
+                Ok(vec![])
+            }
+            Action::Commit { block, consensus } => {
+                let height = *block.header().height();
+                tracing::info!(
+                    "The block at height {height} is committed in the round {}",
+                    consensus.round
+                );
+                let sealed_block = SealedBlock {
+                    entity: block,
+                    consensus: Consensus::Bft(consensus),
+                };
+                self.commit(sealed_block).await;
+                Ok(vec![])
+            }
+        }
+    }
+
+    /// Persists the signing state recording the message before signing it,
+    /// so the validator doesn't sign a conflicting message after a restart.
+    async fn sign(
+        &mut self,
+        message: Message,
+        record: impl FnOnce(&mut SigningState) -> bool,
+    ) -> anyhow::Result<Signature> {
+        let mut state = self.signing_state.clone();
+        if !record(&mut state) {
+            return Err(anyhow!(
+                "Refusing to sign the message conflicting with the messages signed at the height {}",
+                state.height
+            ))
+        }
+        state.locked = self.state_machine.locked();
+        state.valid = self.state_machine.valid();
+        if state != self.signing_state {
+            self.signing_storage.store(&state)?;
+            self.signing_state = state;
+        }
+        self.signer.sign_message(message).await
+    }
+
+    /// Commits the decided block. The state machine doesn't leave the height
+    /// without the commit, so the failed commit is retried until it succeeds
+    /// or the block of the height is imported from the network.
+    async fn commit(&mut self, block: SealedBlock) {
+        let height = *block.entity.header().height();
+        if height <= self.last_height {
+            return
+        }
+        match self.block_importer.execute_and_commit(block.clone()).await {
+            Ok(()) => self.failed_commit = None,
+            Err(err) => {
+                tracing::error!(
+                    "Failed to commit the block at height {height}, retrying: {err:?}"
+                );
+                let retry_at = Instant::now()
+                    .checked_add(COMMIT_RETRY_INTERVAL)
+                    .unwrap_or_else(Instant::now);
+                self.failed_commit = Some((retry_at, block));
+            }
+        }
+    }
+
+    async fn retry_commit(&mut self) {
+        if let Some((_, block)) = self.failed_commit.take() {
+            self.commit(block).await;
+        }
+    }
+
+    fn chain_id(&self) -> fuel_core_types::fuel_types::ChainId {
+        self.state_machine.chain_id()
+    }
+
+    async fn produce_block(&self, height: BlockHeight) -> anyhow::Result<Block> {
+        let block_time = Tai64::now().max(self.last_block_time);
+        let production_time = self.timeouts.propose.checked_div(2).unwrap_or_default();
+        let deadline = Instant::now()
+            .checked_add(production_time)
+            .ok_or(anyhow!("The deadline exceeds system limits"))?;
+        self.block_producer
+            .produce_block(height, block_time, deadline)
+            .await
+    }
+
+    /// Gossips the own message and applies it to the state machine.
+    async fn broadcast(&mut self, message: BftMessage) -> anyhow::Result<Vec<Action>> {
+        if let Err(err) = self.p2p.broadcast_message(message.clone()) {
+            tracing::error!("Failed to broadcast the consensus message: {err}");
+        }
+        self.state_machine
+            .on_message(message)
+            .map_err(|err| anyhow!("The own consensus message is invalid: {err}"))
+    }
+}
+
+#[async_trait::async_trait]
+impl<P, V, I, S, N, D> RunnableService for MainTask<P, V, I, S, N, D>
+where
+    Self: RunnableTask,
+    D: SigningStateStorage,
+{
+    const NAME: &'static str = "BFT";
+
+    type SharedData = ();
+    type Task = Self;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(
+        mut self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        if let Some(signing_state) = self.signing_storage.load()? {
+            self.signing_state = signing_state;
+        }
+        Ok(self)
+    }
+}
+
+impl<P, V, I, S, N, D> RunnableTask for MainTask<P, V, I, S, N, D>
+where
+    P: BlockProducer,
+    V: BlockValidator,
+    I: BlockImporter,
+    S: Signer,
+    N: P2pPort,
+    D: SigningStateStorage,
+{
+    async fn run(&mut self, watcher: &mut StateWatcher) -> TaskNextAction {
+        let next_height = self.next_height.map(|(_, at)| at);
+        let next_timeout = self.scheduled_timeouts.iter().map(|(at, _)| *at).min();
+        let commit_retry = self.failed_commit.as_ref().map(|(at, _)| *at);
+
+        tokio::select! {
+            biased;
+            _ = watcher.while_started() => {
+                TaskNextAction::Stop
+            }
+            block = self.imported_blocks.next() => {
+                match block {
+                    Some(block) => {
+                        self.on_block_imported(&block.block_header);
+                        TaskNextAction::Continue
+                    }
+                    None => TaskNextAction::Stop,
+                }
+            }
+            message = self.gossiped_messages.next() => {
+                match message {
+                    Some(message) => {
+                        self.on_gossiped_message(message).await;
+                        TaskNextAction::Continue
+                    }
+                    None => TaskNextAction::Stop,
+                }
+            }
+            _ = sleep_until(next_height.unwrap_or_else(Instant::now)), if next_height.is_some() => {
+                self.start_next_height().await;
+                TaskNextAction::Continue
+            }
+            _ = sleep_until(next_timeout.unwrap_or_else(Instant::now)), if next_timeout.is_some() => {
+                self.fire_timeouts().await;
+                TaskNextAction::Continue
+            }
+            _ = sleep_until(commit_retry.unwrap_or_else(Instant::now)), if commit_retry.is_some() => {
+                self.retry_commit().await;
+                TaskNextAction::Continue
+            }
+        }
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn new_service<P, V, I, S, N, D>(
+    last_block: &BlockHeader,
+    config: Config,
+    block_producer: P,
+    block_validator: V,
+    block_importer: I,
+    signer: Arc<S>,
+    p2p: N,
+    signing_storage: D,
+) -> Service<P, V, I, S, N, D>
+where
+    P: BlockProducer + 'static,
+    V: BlockValidator + 'static,
+    I: BlockImporter + 'static,
+    S: Signer + 'static,
+    N: P2pPort + 'static,
+    D: SigningStateStorage + 'static,
+{
+    Service::new(MainTask::new(
+        last_block,
+        config,
+        block_producer,
+        block_validator,
+        block_importer,
+        signer,
+        p2p,
+        signing_storage,
+    ))
+}
@@ -0,0 +1,100 @@
+use fuel_core_types::{
+    blockchain::{
+        consensus::bft::{
+            Vote,
+            VoteType,
+        },
+        primitives::BlockId,
+    },
+    fuel_types::BlockHeight,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The messages signed by the validator at its latest height, along with
+/// the locked and valid blocks of the height.
+///
+/// The validator persists the state before it signs a message and refuses to sign
+/// messages conflicting with it, so after a restart it neither equivocates
+/// nor forgets the block it is locked on.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SigningState {
+    /// The height of the signed messages.
+    pub height: BlockHeight,
+    /// The latest round in which the validator signed a message.
+    pub round: u32,
+    /// The block the validator is locked on, along with the round in which it locked.
+    pub locked: Option<(u32, BlockId)>,
+    /// The block prevoted by the quorum, along with the round of the quorum.
+    pub valid: Option<(u32, BlockId)>,
+    /// The round and the block of the last signed proposal.
+    pub proposal: Option<(u32, BlockId)>,
+    /// The round and the block of the last signed prevote.
+    pub prevote: Option<(u32, Option<BlockId>)>,
+    /// The round and the block of the last signed precommit.
+    pub precommit: Option<(u32, Option<BlockId>)>,
+}
+
+impl SigningState {
+    /// Records the proposal of the block before signing it.
+    /// Returns `false` if the proposal conflicts with the signed messages.
+    pub fn record_proposal(
+        &mut self,
+        height: BlockHeight,
+        round: u32,
+        block_id: BlockId,
+    ) -> bool {
+        if !self.enter_height(height) || !can_sign(self.proposal, round, block_id) {
+            return false
+        }
+        self.proposal = Some((round, block_id));
+        self.round = self.round.max(round);
+        true
+    }
+
+    /// Records the vote before signing it.
+    /// Returns `false` if the vote conflicts with the signed messages.
+    pub fn record_vote(&mut self, vote: &Vote) -> bool {
+        if !self.enter_height(vote.height) {
+            return false
+        }
+        let last = match vote.vote_type {
+            VoteType::Prevote => &mut self.prevote,
+            VoteType::Precommit => &mut self.precommit,
+        };
+        if !can_sign(*last, vote.round, vote.block_id) {
+            return false
+        }
+        *last = Some((vote.round, vote.block_id));
+        self.round = self.round.max(vote.round);
+        true
+    }
+
+    /// Moves to the `height`, forgetting the messages of the previous one.
+    /// Returns `false` if the validator already signed messages of a later height.
+    fn enter_height(&mut self, height: BlockHeight) -> bool {
+        if height < self.height {
+            return false
+        }
+        if height > self.height {
+            *self = Self {
+                height,
+                ..Default::default()
+            };
+        }
+        true
+    }
+}
+
+/// The validator signs a message of each kind once per round. Signing the same
+/// message again is harmless, while going back to an earlier round is not.
+fn can_sign<T: PartialEq>(last: Option<(u32, T)>, round: u32, value: T) -> bool {
+    last.is_none_or(|(last_round, last_value)| {
+        last_round < round || (last_round == round && last_value == value)
+    })
+}
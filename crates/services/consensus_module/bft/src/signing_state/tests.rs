@@ -0,0 +1,84 @@
+#![allow(non_snake_case)]
+
+use super::*;
+
+fn block_id(byte: u8) -> BlockId {
+    BlockId::from([byte; 32])
+}
+
+#[test]
+fn record_vote__when_vote_conflicts_with_signed_one_then_refuses() {
+    // Given
+    let mut state = SigningState::default();
+    let height = 1u32.into();
+    assert!(state.record_vote(&Vote::prevote(height, 2, Some(block_id(1)))));
+
+    // When
+    let conflicting = state.record_vote(&Vote::prevote(height, 2, None));
+    let earlier_round = state.record_vote(&Vote::prevote(height, 1, Some(block_id(1))));
+    let same = state.record_vote(&Vote::prevote(height, 2, Some(block_id(1))));
+
+    // Then
+    assert!(!conflicting);
+    assert!(!earlier_round);
+    assert!(same);
+    assert_eq!(state.prevote, Some((2, Some(block_id(1)))));
+}
+
+#[test]
+fn record_vote__when_vote_types_differ_then_tracks_them_separately() {
+    // Given
+    let mut state = SigningState::default();
+    let height = 1u32.into();
+    assert!(state.record_vote(&Vote::prevote(height, 0, Some(block_id(1)))));
+
+    // When
+    let recorded = state.record_vote(&Vote::precommit(height, 0, None));
+
+    // Then
+    assert!(recorded);
+    assert_eq!(state.precommit, Some((0, None)));
+}
+
+#[test]
+fn record_proposal__when_block_differs_in_the_same_round_then_refuses() {
+    // Given
+    let mut state = SigningState::default();
+    let height = 1u32.into();
+    assert!(state.record_proposal(height, 0, block_id(1)));
+
+    // When
+    let conflicting = state.record_proposal(height, 0, block_id(2));
+    let next_round = state.record_proposal(height, 1, block_id(2));
+
+    // Then
+    assert!(!conflicting);
+    assert!(next_round);
+    assert_eq!(state.round, 1);
+}
+
+#[test]
+fn record_vote__when_height_changes_then_forgets_previous_height() {
+    // Given
+    let mut state = SigningState::default();
+    let height: BlockHeight = 1u32.into();
+    let next_height = height.succ().unwrap();
+    assert!(state.record_vote(&Vote::precommit(height, 3, Some(block_id(1)))));
+    state.locked = Some((3, block_id(1)));
+
+    // When
+    let next = state.record_vote(&Vote::prevote(next_height, 0, None));
+    let previous = state.record_vote(&Vote::prevote(height, 4, None));
+
+    // Then
+    assert!(next);
+    assert!(!previous);
+    assert_eq!(
+        state,
+        SigningState {
+            height: next_height,
+            prevote: Some((0, None)),
+            ..Default::default()
+        }
+    );
+}
@@ -0,0 +1,833 @@
+//! The Tendermint consensus algorithm.
+//!
+//! The state machine follows the algorithm from the paper
+//! "The latest gossip on BFT consensus" by Buchman, Kwon and Milosevic.
+//! It is deterministic and doesn't perform any IO: it consumes messages,
+//! results of the proposal validation and timeouts, and returns the [`Action`]s
+//! that the caller should perform.
+//!
+//! The safety of the algorithm holds while less than one-third of
+//! validators are faulty, independently of the network delays.
+
+use crate::{
+    signing_state::SigningState,
+    validators::ValidatorSet,
+};
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        consensus::bft::{
+            BftConsensus,
+            BftMessage,
+            Proposal,
+            SignedProposal,
+            SignedVote,
+            Vote,
+            VoteType,
+        },
+        primitives::BlockId,
+    },
+    fuel_crypto::Signature,
+    fuel_tx::Input,
+    fuel_types::{
+        Address,
+        BlockHeight,
+        ChainId,
+    },
+};
+use std::collections::{
+    BTreeMap,
+    HashMap,
+    HashSet,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The maximum number of messages for the next height kept
+/// until the node starts the next height.
+const MAX_FUTURE_MESSAGES: usize = 1024;
+
+/// The maximum number of rounds ahead of the current round for which the messages
+/// are kept. Honest validators move to the next round only after the timeouts or
+/// after one-third of validators moved there, so they are never far ahead. The node
+/// that fell behind catches up by importing the block committed by others.
+const MAX_ROUND_LOOKAHEAD: u32 = 16;
+
+/// The maximum number of conflicting proposals or votes of the validator kept
+/// for the round. The honest validator sends only one message of each kind per round,
+/// while the faulty one can send different messages to different validators.
+const MAX_CONFLICTING_MESSAGES: usize = 4;
+
+/// The step of the round.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Step {
+    /// Waiting for the proposal of the round.
+    Propose,
+    /// The node has prevoted in the round.
+    Prevote,
+    /// The node has precommitted in the round.
+    Precommit,
+    /// The block of the height is committed, or the height is not started yet.
+    Commit,
+}
+
+/// The timeout of the step.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Timeout {
+    /// The height of the timeout.
+    pub height: BlockHeight,
+    /// The round of the timeout.
+    pub round: u32,
+    /// The step that expires with the timeout.
+    pub step: TimeoutStep,
+}
+
+/// The step that expires with the timeout.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeoutStep {
+    /// The node prevotes for nothing if it doesn't receive the proposal in time.
+    Propose,
+    /// The node precommits nothing if prevotes don't agree on the block in time.
+    Prevote,
+    /// The node moves to the next round if precommits don't agree on the block in time.
+    Precommit,
+}
+
+/// The action requested by the state machine.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Action {
+    /// The node is the proposer of the round and should broadcast a signed proposal.
+    Propose {
+        /// The height of the proposal.
+        height: BlockHeight,
+        /// The round of the proposal.
+        round: u32,
+        /// The block that should be proposed again along with the round
+        /// in which the quorum prevoted for it. If it is `None`, the node
+        /// should produce a new block.
+        valid_block: Option<(u32, Block)>,
+    },
+    /// The block of the proposal should be validated and the result
+    /// should be reported with [`StateMachine::on_proposal_validated`].
+    ValidateProposal {
+        /// The height of the proposal.
+        height: BlockHeight,
+        /// The round of the proposal.
+        round: u32,
+        /// The proposed block.
+        block: Block,
+    },
+    /// The node should sign and broadcast the vote.
+    Vote(Vote),
+    /// The node should call [`StateMachine::on_timeout`] after the timeout expires.
+    ScheduleTimeout(Timeout),
+    /// The quorum of validators precommitted the block. The block should be committed.
+    Commit {
+        /// The committed block.
+        block: Block,
+        /// The consensus with the precommit signatures of the validators.
+        consensus: BftConsensus,
+    },
+}
+
+/// The reason why the message was rejected.
+#[derive(Clone, Debug, PartialEq, Eq, derive_more::Display)]
+pub enum MessageError {
+    /// The message is for the height that is already committed.
+    #[display("The message is for the committed height")]
+    Outdated,
+    /// The signer of the message can't be recovered.
+    #[display("Invalid signature")]
+    InvalidSignature,
+    /// The message is signed by an unknown validator.
+    #[display("The message is signed by the unknown validator {_0}")]
+    UnknownValidator(Address),
+    /// The proposal is signed by the validator that is not the proposer of the round.
+    #[display("The proposal is signed by {_0} that is not the proposer of the round")]
+    NotProposer(Address),
+    /// The proposal is malformed.
+    #[display("Invalid proposal: {_0}")]
+    InvalidProposal(&'static str),
+    /// The message is for the round too far ahead of the current round.
+    #[display("The message is for the round {_0} too far ahead of the current round")]
+    FarFutureRound(u32),
+}
+
+impl MessageError {
+    /// Returns `true` if the error is caused by the misbehaviour of the sender.
+    pub fn is_malicious(&self) -> bool {
+        !matches!(
+            self,
+            MessageError::Outdated | MessageError::FarFutureRound(_)
+        )
+    }
+}
+
+struct ProposalState {
+    proposal: Proposal,
+    block_id: BlockId,
+    valid: Option<bool>,
+    validation_requested: bool,
+}
+
+/// The votes of the round of one type.
+///
+/// All conflicting votes of the faulty validator are counted, so the validators
+/// that received the same votes agree on the quorums. It doesn't affect the safety,
+/// because any two quorums intersect in at least one honest validator.
+#[derive(Default)]
+struct RoundVotes {
+    votes: HashMap<Address, Vec<(Option<BlockId>, Signature)>>,
+}
+
+impl RoundVotes {
+    /// Adds the vote. Returns `false` if the vote is already known or the validator
+    /// sent too many conflicting votes.
+    fn insert(
+        &mut self,
+        signer: Address,
+        block_id: Option<BlockId>,
+        signature: Signature,
+    ) -> bool {
+        let votes = self.votes.entry(signer).or_default();
+        if votes.len() >= MAX_CONFLICTING_MESSAGES
+            || votes.iter().any(|(voted_for, _)| *voted_for == block_id)
+        {
+            return false
+        }
+        votes.push((block_id, signature));
+        true
+    }
+
+    /// The number of validators that voted in the round.
+    fn total(&self) -> usize {
+        self.votes.len()
+    }
+
+    /// The number of validators that voted for the block.
+    fn count(&self, block_id: Option<BlockId>) -> usize {
+        self.votes
+            .values()
+            .filter(|votes| votes.iter().any(|(voted_for, _)| *voted_for == block_id))
+            .count()
+    }
+
+    fn signatures(&self, block_id: BlockId) -> Vec<Signature> {
+        self.votes
+            .values()
+            .filter_map(|votes| {
+                votes
+                    .iter()
+                    .find(|(voted_for, _)| *voted_for == Some(block_id))
+                    .map(|(_, signature)| *signature)
+            })
+            .collect()
+    }
+}
+
+/// The Tendermint state machine of the validator.
+pub struct StateMachine {
+    validators: ValidatorSet,
+    address: Address,
+    chain_id: ChainId,
+    height: BlockHeight,
+    round: u32,
+    step: Step,
+    locked: Option<(u32, BlockId)>,
+    valid: Option<(u32, BlockId)>,
+    /// The proposals of each round. The node prevotes only for the first proposal
+    /// of the round, while others are used only if the votes of validators support them.
+    proposals: BTreeMap<u32, Vec<ProposalState>>,
+    votes: HashMap<(u32, VoteType), RoundVotes>,
+    senders: BTreeMap<u32, HashSet<Address>>,
+    prevote_timeout_scheduled: bool,
+    precommit_timeout_scheduled: bool,
+    prevote_quorum_seen: bool,
+    future_messages: Vec<BftMessage>,
+    actions: Vec<Action>,
+}
+
+impl StateMachine {
+    /// Creates the state machine of the validator with the `address`.
+    /// The state machine is idle until the first call of [`Self::start_height`].
+    pub fn new(validators: ValidatorSet, address: Address, chain_id: ChainId) -> Self {
+        Self {
+            validators,
+            address,
+            chain_id,
+            height: BlockHeight::default(),
+            round: 0,
+            step: Step::Commit,
+            locked: None,
+            valid: None,
+            proposals: BTreeMap::new(),
+            votes: HashMap::new(),
+            senders: BTreeMap::new(),
+            prevote_timeout_scheduled: false,
+            precommit_timeout_scheduled: false,
+            prevote_quorum_seen: false,
+            future_messages: Vec::new(),
+            actions: Vec::new(),
+        }
+    }
+
+    /// The current height.
+    pub fn height(&self) -> BlockHeight {
+        self.height
+    }
+
+    /// The current round.
+    pub fn round(&self) -> u32 {
+        self.round
+    }
+
+    /// The id of the chain signed along with the messages.
+    pub fn chain_id(&self) -> ChainId {
+        self.chain_id
+    }
+
+    /// The current step.
+    pub fn step(&self) -> Step {
+        self.step
+    }
+
+    /// The block the validator is locked on, along with the round in which it locked.
+    pub fn locked(&self) -> Option<(u32, BlockId)> {
+        self.locked
+    }
+
+    /// The block prevoted by the quorum, along with the round of the quorum.
+    pub fn valid(&self) -> Option<(u32, BlockId)> {
+        self.valid
+    }
+
+    /// Starts the consensus for the height.
+    pub fn start_height(&mut self, height: BlockHeight) -> Vec<Action> {
+        self.start(height, 0, None, None)
+    }
+
+    /// Resumes the consensus for the height of the signing state persisted
+    /// before the restart. The validator keeps its locked and valid blocks
+    /// and continues from the latest round in which it signed a message.
+    pub fn resume_height(&mut self, state: &SigningState) -> Vec<Action> {
+        self.start(state.height, state.round, state.locked, state.valid)
+    }
+
+    fn start(
+        &mut self,
+        height: BlockHeight,
+        round: u32,
+        locked: Option<(u32, BlockId)>,
+        valid: Option<(u32, BlockId)>,
+    ) -> Vec<Action> {
+        self.height = height;
+        self.locked = locked;
+        self.valid = valid;
+        self.proposals.clear();
+        self.votes.clear();
+        self.senders.clear();
+        self.start_round(round);
+
+        let future_messages = core::mem::take(&mut self.future_messages);
+        for message in future_messages {
+            if message.height() == height {
+                // Errors were already reported when the message was received.
+                let _ = self.insert_message(message);
+            }
+        }
+
+        self.process();
+        self.take_actions()
+    }
+
+    /// Processes the message from the validator.
+    ///
+    /// Messages for the next height are kept until the node starts it.
+    pub fn on_message(
+        &mut self,
+        message: BftMessage,
+    ) -> Result<Vec<Action>, MessageError> {
+        self.insert_message(message)?;
+        self.process();
+        Ok(self.take_actions())
+    }
+
+    /// Processes the result of the validation of the proposed block.
+    pub fn on_proposal_validated(
+        &mut self,
+        height: BlockHeight,
+        round: u32,
+        block_id: BlockId,
+        valid: bool,
+    ) -> Vec<Action> {
+        if height != self.height {
+            return vec![]
+        }
+        if let Some(state) = self.proposal_mut(round, block_id)
+            && state.valid.is_none()
+        {
+            state.valid = Some(valid);
+            self.process();
+        }
+        self.take_actions()
+    }
+
+    /// Processes the expired timeout.
+    pub fn on_timeout(&mut self, timeout: Timeout) -> Vec<Action> {
+        if timeout.height != self.height
+            || timeout.round != self.round
+            || self.step == Step::Commit
+        {
+            return vec![]
+        }
+
+        match timeout.step {
+            TimeoutStep::Propose => {
+                if self.step == Step::Propose {
+                    self.vote(VoteType::Prevote, None);
+                    self.step = Step::Prevote;
+                }
+            }
+            TimeoutStep::Prevote => {
+                if self.step == Step::Prevote {
+                    self.vote(VoteType::Precommit, None);
+                    self.step = Step::Precommit;
+                }
+            }
+            TimeoutStep::Precommit => {
+                self.start_round(self.round.saturating_add(1));
+            }
+        }
+
+        self.process();
+        self.take_actions()
+    }
+
+    fn take_actions(&mut self) -> Vec<Action> {
+        core::mem::take(&mut self.actions)
+    }
+
+    fn insert_message(&mut self, message: BftMessage) -> Result<(), MessageError> {
+        let height = message.height();
+        if height < self.height || (height == self.height && self.step == Step::Commit) {
+            return Err(MessageError::Outdated)
+        }
+
+        let signer = message
+            .signature()
+            .recover(&message.signing_message(&self.chain_id))
+            .map(|public_key| Input::owner(&public_key))
+            .map_err(|_| MessageError::InvalidSignature)?;
+        if !self.validators.contains(&signer) {
+            return Err(MessageError::UnknownValidator(signer))
+        }
+
+        if let BftMessage::Proposal(proposal) = &message {
+            self.check_proposal(signer, proposal)?;
+        }
+
+        // The next height starts from the round 0.
+        let current_round = if height == self.height { self.round } else { 0 };
+        let round = message.round();
+        if round > current_round.saturating_add(MAX_ROUND_LOOKAHEAD) {
+            return Err(MessageError::FarFutureRound(round))
+        }
+
+        if height > self.height {
+            if self.height.succ() == Some(height)
+                && self.future_messages.len() < MAX_FUTURE_MESSAGES
+            {
+                self.future_messages.push(message);
+            }
+            return Ok(())
+        }
+
+        match message {
+            BftMessage::Proposal(proposal) => self.insert_proposal(proposal),
+            BftMessage::Vote(vote) => self.insert_vote(signer, vote),
+        }
+        self.senders.entry(round).or_default().insert(signer);
+
+        Ok(())
+    }
+
+    fn check_proposal(
+        &self,
+        signer: Address,
+        proposal: &SignedProposal,
+    ) -> Result<(), MessageError> {
+        let proposal = &proposal.entity;
+        if self.validators.proposer(proposal.height, proposal.round) != Some(&signer) {
+            return Err(MessageError::NotProposer(signer))
+        }
+        if *proposal.block.header().height() != proposal.height {
+            return Err(MessageError::InvalidProposal(
+                "The height of the block doesn't match the height of the proposal",
+            ))
+        }
+        if proposal
+            .valid_round
+            .is_some_and(|valid_round| valid_round >= proposal.round)
+        {
+            return Err(MessageError::InvalidProposal(
+                "The valid round should be less than the round of the proposal",
+            ))
+        }
+        Ok(())
+    }
+
+    fn insert_proposal(&mut self, proposal: SignedProposal) {
+        let proposal = proposal.entity;
+        let block_id = proposal.block.id();
+        let is_own = self.validators.proposer(proposal.height, proposal.round)
+            == Some(&self.address);
+        let proposals = self.proposals.entry(proposal.round).or_default();
+        if proposals.iter().any(|state| state.block_id == block_id) {
+            return
+        }
+        if proposals.len() >= MAX_CONFLICTING_MESSAGES {
+            tracing::debug!(
+                "Ignoring another proposal for the round {} of the height {}",
+                proposal.round,
+                proposal.height
+            );
+            return
+        }
+        if !proposals.is_empty() {
+            tracing::warn!(
+                "The proposer sent conflicting proposals for the round {} of the height {}",
+                proposal.round,
+                proposal.height
+            );
+        }
+
+        // The node doesn't validate the blocks produced by itself. Conflicting
+        // proposals are validated only when validators vote for them.
+        let valid = is_own.then_some(true);
+        let validation_requested = valid.is_none() && proposals.is_empty();
+        if validation_requested {
+            self.actions.push(Action::ValidateProposal {
+                height: proposal.height,
+                round: proposal.round,
+                block: proposal.block.clone(),
+            });
+        }
+        proposals.push(ProposalState {
+            proposal,
+            block_id,
+            valid,
+            validation_requested,
+        });
+    }
+
+    /// The first proposal of the round, for which the node prevotes.
+    fn first_proposal(&self, round: u32) -> Option<&ProposalState> {
+        self.proposals
+            .get(&round)
+            .and_then(|proposals| proposals.first())
+    }
+
+    fn proposal(&self, round: u32, block_id: BlockId) -> Option<&ProposalState> {
+        self.proposals
+            .get(&round)?
+            .iter()
+            .find(|state| state.block_id == block_id)
+    }
+
+    fn proposal_mut(
+        &mut self,
+        round: u32,
+        block_id: BlockId,
+    ) -> Option<&mut ProposalState> {
+        self.proposals
+            .get_mut(&round)?
+            .iter_mut()
+            .find(|state| state.block_id == block_id)
+    }
+
+    fn insert_vote(&mut self, signer: Address, vote: SignedVote) {
+        let SignedVote {
+            entity: vote,
+            signature,
+        } = vote;
+        let inserted = self
+            .votes
+            .entry((vote.round, vote.vote_type))
+            .or_default()
+            .insert(signer, vote.block_id, signature);
+        if !inserted {
+            tracing::debug!(
+                "Ignoring the {:?} of the validator {} for the round {} of the height {}",
+                vote.vote_type,
+                signer,
+                vote.round,
+                vote.height
+            );
+        }
+    }
+
+    fn start_round(&mut self, round: u32) {
+        self.round = round;
+        self.step = Step::Propose;
+        self.prevote_timeout_scheduled = false;
+        self.precommit_timeout_scheduled = false;
+        self.prevote_quorum_seen = false;
+
+        if self.validators.proposer(self.height, round) == Some(&self.address) {
+            let valid_block = self.valid.and_then(|(valid_round, block_id)| {
+                let state = self.proposal(valid_round, block_id)?;
+                Some((valid_round, state.proposal.block.clone()))
+            });
+            self.actions.push(Action::Propose {
+                height: self.height,
+                round,
+                valid_block,
+            });
+        }
+        self.schedule_timeout(TimeoutStep::Propose);
+    }
+
+    fn schedule_timeout(&mut self, step: TimeoutStep) {
+        self.actions.push(Action::ScheduleTimeout(Timeout {
+            height: self.height,
+            round: self.round,
+            step,
+        }));
+    }
+
+    fn vote(&mut self, vote_type: VoteType, block_id: Option<BlockId>) {
+        self.actions.push(Action::Vote(Vote {
+            height: self.height,
+            round: self.round,
+            vote_type,
+            block_id,
+        }));
+    }
+
+    fn round_votes(&self, round: u32, vote_type: VoteType) -> Option<&RoundVotes> {
+        self.votes.get(&(round, vote_type))
+    }
+
+    fn count_votes(
+        &self,
+        round: u32,
+        vote_type: VoteType,
+        block_id: Option<BlockId>,
+    ) -> usize {
+        self.round_votes(round, vote_type)
+            .map(|votes| votes.count(block_id))
+            .unwrap_or_default()
+    }
+
+    fn total_votes(&self, round: u32, vote_type: VoteType) -> usize {
+        self.round_votes(round, vote_type)
+            .map(RoundVotes::total)
+            .unwrap_or_default()
+    }
+
+    /// Applies the rules of the algorithm until none of them can be applied.
+    fn process(&mut self) {
+        while self.step != Step::Commit {
+            let applied = self.try_commit()
+                || self.try_validate_supported_proposal()
+                || self.try_skip_round()
+                || self.try_prevote()
+                || self.try_precommit_block()
+                || self.try_precommit_nil()
+                || self.try_schedule_vote_timeouts();
+            if !applied {
+                break
+            }
+        }
+    }
+
+    /// Commits the block of any round if the quorum precommitted it.
+    fn try_commit(&mut self) -> bool {
+        let quorum = self.validators.quorum();
+        let committed = self.proposals.iter().find_map(|(round, proposals)| {
+            proposals
+                .iter()
+                .find(|state| {
+                    state.valid == Some(true)
+                        && self.count_votes(
+                            *round,
+                            VoteType::Precommit,
+                            Some(state.block_id),
+                        ) >= quorum
+                })
+                .map(|state| (*round, state))
+        });
+        let Some((round, state)) = committed else {
+            return false
+        };
+
+        let signatures = self
+            .round_votes(round, VoteType::Precommit)
+            .map(|votes| votes.signatures(state.block_id))
+            .unwrap_or_default();
+        let action = Action::Commit {
+            block: state.proposal.block.clone(),
+            consensus: BftConsensus::new(round, signatures),
+        };
+        self.actions.push(action);
+        self.step = Step::Commit;
+        true
+    }
+
+    /// Requests the validation of the conflicting proposal once at least one
+    /// honest validator voted for its block, so the node can lock or commit it.
+    fn try_validate_supported_proposal(&mut self) -> bool {
+        let threshold = self.validators.skip_threshold();
+        let supported = self.proposals.iter().find_map(|(round, proposals)| {
+            proposals
+                .iter()
+                .find(|state| {
+                    !state.validation_requested
+                        && state.valid.is_none()
+                        && [VoteType::Prevote, VoteType::Precommit].into_iter().any(
+                            |vote_type| {
+                                self.count_votes(*round, vote_type, Some(state.block_id))
+                                    >= threshold
+                            },
+                        )
+                })
+                .map(|state| (*round, state.block_id))
+        });
+        let Some((round, block_id)) = supported else {
+            return false
+        };
+
+        let height = self.height;
+        let Some(state) = self.proposal_mut(round, block_id) else {
+            return false
+        };
+        state.validation_requested = true;
+        let block = state.proposal.block.clone();
+        self.actions.push(Action::ValidateProposal {
+            height,
+            round,
+            block,
+        });
+        true
+    }
+
+    /// Moves to the later round if enough validators are already there.
+    fn try_skip_round(&mut self) -> bool {
+        let threshold = self.validators.skip_threshold();
+        let next_round = self
+            .senders
+            .range(self.round.saturating_add(1)..)
+            .find(|(_, senders)| senders.len() >= threshold)
+            .map(|(round, _)| *round);
+        match next_round {
+            Some(round) => {
+                self.start_round(round);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Prevotes for the proposal of the current round.
+    fn try_prevote(&mut self) -> bool {
+        if self.step != Step::Propose {
+            return false
+        }
+        let Some(state) = self.first_proposal(self.round) else {
+            return false
+        };
+        let Some(valid) = state.valid else {
+            return false
+        };
+        let block_id = state.block_id;
+
+        let acceptable = match state.proposal.valid_round {
+            None => self
+                .locked
+                .is_none_or(|(_, locked_id)| locked_id == block_id),
+            Some(valid_round) => {
+                if self.count_votes(valid_round, VoteType::Prevote, Some(block_id))
+                    < self.validators.quorum()
+                {
+                    // Wait for the prevotes that justify the proposal.
+                    return false
+                }
+                self.locked.is_none_or(|(locked_round, locked_id)| {
+                    locked_round <= valid_round || locked_id == block_id
+                })
+            }
+        };
+
+        let vote = (valid && acceptable).then_some(block_id);
+        self.vote(VoteType::Prevote, vote);
+        self.step = Step::Prevote;
+        true
+    }
+
+    /// Locks and precommits the proposed block after the quorum prevoted for it.
+    fn try_precommit_block(&mut self) -> bool {
+        if self.prevote_quorum_seen || self.step < Step::Prevote {
+            return false
+        }
+        let quorum = self.validators.quorum();
+        let Some(block_id) = self.proposals.get(&self.round).and_then(|proposals| {
+            proposals
+                .iter()
+                .find(|state| {
+                    state.valid == Some(true)
+                        && self.count_votes(
+                            self.round,
+                            VoteType::Prevote,
+                            Some(state.block_id),
+                        ) >= quorum
+                })
+                .map(|state| state.block_id)
+        }) else {
+            return false
+        };
+
+        self.prevote_quorum_seen = true;
+        if self.step == Step::Prevote {
+            self.locked = Some((self.round, block_id));
+            self.vote(VoteType::Precommit, Some(block_id));
+            self.step = Step::Precommit;
+        }
+        self.valid = Some((self.round, block_id));
+        true
+    }
+
+    /// Precommits nothing after the quorum prevoted for nothing.
+    fn try_precommit_nil(&mut self) -> bool {
+        if self.step != Step::Prevote
+            || self.count_votes(self.round, VoteType::Prevote, None)
+                < self.validators.quorum()
+        {
+            return false
+        }
+        self.vote(VoteType::Precommit, None);
+        self.step = Step::Precommit;
+        true
+    }
+
+    /// Schedules the timeouts of the voting steps after the quorum voted for anything.
+    fn try_schedule_vote_timeouts(&mut self) -> bool {
+        let quorum = self.validators.quorum();
+        if self.step == Step::Prevote
+            && !self.prevote_timeout_scheduled
+            && self.total_votes(self.round, VoteType::Prevote) >= quorum
+        {
+            self.prevote_timeout_scheduled = true;
+            self.schedule_timeout(TimeoutStep::Prevote);
+            return true
+        }
+        if !self.precommit_timeout_scheduled
+            && self.total_votes(self.round, VoteType::Precommit) >= quorum
+        {
+            self.precommit_timeout_scheduled = true;
+            self.schedule_timeout(TimeoutStep::Precommit);
+            return true
+        }
+        false
+    }
+}
@@ -0,0 +1,808 @@
+#![allow(non_snake_case)]
+#![allow(clippy::arithmetic_side_effects)]
+
+use super::*;
+use crate::verifier::verify_consensus;
+use fuel_core_chain_config::{
+    BftConfig,
+    ConsensusConfig,
+};
+use fuel_core_types::{
+    blockchain::consensus::bft::Signed,
+    fuel_crypto::SecretKey,
+    fuel_types::Bytes32,
+};
+use rand::{
+    Rng,
+    SeedableRng,
+    rngs::StdRng,
+};
+
+const CHAIN_ID: ChainId = ChainId::new(9889);
+
+fn block(height: BlockHeight, seed: u64) -> Block {
+    let mut block = Block::default();
+    let header = block.header_mut();
+    header.set_block_height(height);
+    let mut root = [0u8; 32];
+    root[..8].copy_from_slice(&seed.to_be_bytes());
+    header.set_previous_root(Bytes32::from(root));
+    block
+}
+
+fn sign_proposal(key: &SecretKey, proposal: Proposal) -> BftMessage {
+    let signature = Signature::sign(key, &proposal.signing_message(&CHAIN_ID));
+    BftMessage::Proposal(Signed {
+        entity: proposal,
+        signature,
+    })
+}
+
+fn sign_vote(key: &SecretKey, vote: Vote) -> BftMessage {
+    sign_vote_for_chain(key, vote, &CHAIN_ID)
+}
+
+fn sign_vote_for_chain(key: &SecretKey, vote: Vote, chain_id: &ChainId) -> BftMessage {
+    let signature = Signature::sign(key, &vote.signing_message(chain_id));
+    BftMessage::Vote(Signed {
+        entity: vote,
+        signature,
+    })
+}
+
+fn keys(rng: &mut StdRng, n: usize) -> Vec<SecretKey> {
+    (0..n).map(|_| SecretKey::random(rng)).collect()
+}
+
+fn address(key: &SecretKey) -> Address {
+    Input::owner(&key.public_key())
+}
+
+fn validator_set(keys: &[SecretKey]) -> ValidatorSet {
+    ValidatorSet::new(keys.iter().map(address).collect())
+}
+
+/// The behavior of the validator in the simulated network.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Behavior {
+    /// Follows the protocol.
+    Honest,
+    /// Doesn't send any messages.
+    Crashed,
+    /// Sends conflicting proposals and votes to different validators.
+    Byzantine,
+}
+
+struct Node {
+    key: SecretKey,
+    machine: StateMachine,
+    timeouts: Vec<Timeout>,
+    committed: BTreeMap<BlockHeight, (BlockId, u32)>,
+}
+
+/// The in-memory network of validators that delivers messages in random order
+/// and fires timeouts at random moments. Validators that fall behind synchronize
+/// the blocks committed by others, as the node does with the block importer.
+struct Network {
+    rng: StdRng,
+    keys: Vec<SecretKey>,
+    behaviors: Vec<Behavior>,
+    nodes: Vec<Option<Node>>,
+    consensus_config: ConsensusConfig,
+    queue: Vec<(usize, BftMessage)>,
+    invalid_blocks: HashSet<BlockId>,
+    attacked: HashSet<(BlockHeight, u32, bool)>,
+    target_height: BlockHeight,
+}
+
+impl Network {
+    fn new(seed: u64, behaviors: Vec<Behavior>, target_height: u32) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let keys = keys(&mut rng, behaviors.len());
+        let validators = validator_set(&keys);
+        let consensus_config =
+            ConsensusConfig::Bft(BftConfig::new(keys.iter().map(address).collect()));
+        let nodes = keys
+            .iter()
+            .zip(behaviors.iter())
+            .map(|(key, behavior)| {
+                (*behavior == Behavior::Honest).then(|| Node {
+                    key: *key,
+                    machine: StateMachine::new(
+                        validators.clone(),
+                        address(key),
+                        CHAIN_ID,
+                    ),
+                    timeouts: vec![],
+                    committed: BTreeMap::new(),
+                })
+            })
+            .collect();
+
+        Self {
+            rng,
+            keys,
+            behaviors,
+            nodes,
+            consensus_config,
+            queue: vec![],
+            invalid_blocks: HashSet::new(),
+            attacked: HashSet::new(),
+            target_height: target_height.into(),
+        }
+    }
+
+    fn honest_nodes(&self) -> impl Iterator<Item = &Node> {
+        self.nodes.iter().flatten()
+    }
+
+    fn broadcast(&mut self, from: usize, message: &BftMessage) {
+        for to in 0..self.keys.len() {
+            if to != from {
+                self.queue.push((to, message.clone()));
+            }
+        }
+    }
+
+    fn start(&mut self) {
+        for index in 0..self.nodes.len() {
+            if let Some(node) = self.nodes[index].as_mut() {
+                let actions = node.machine.start_height(1u32.into());
+                self.handle_actions(index, actions);
+            }
+        }
+    }
+
+    /// Runs the network until all honest validators commit the target height.
+    fn run(&mut self, max_steps: usize) {
+        self.start();
+        for _ in 0..max_steps {
+            if self
+                .honest_nodes()
+                .all(|node| node.committed.contains_key(&self.target_height))
+            {
+                return
+            }
+
+            let has_timeouts = self.honest_nodes().any(|node| !node.timeouts.is_empty());
+            let lagging = self.lagging_node();
+            let has_other_events = has_timeouts || lagging.is_some();
+            if !self.queue.is_empty() && (!has_other_events || self.rng.gen_bool(0.95)) {
+                let index = self.rng.gen_range(0..self.queue.len());
+                let (to, message) = self.queue.swap_remove(index);
+                self.deliver(to, message);
+            } else if has_timeouts && (lagging.is_none() || self.rng.gen_bool(0.9)) {
+                self.fire_timeout();
+            } else if let Some(index) = lagging {
+                self.sync(index);
+            } else {
+                panic!("The network is stuck without messages and timeouts");
+            }
+        }
+        panic!("The network didn't reach the target height in {max_steps} steps");
+    }
+
+    /// Returns the validator that didn't commit the height already committed by others.
+    fn lagging_node(&self) -> Option<usize> {
+        self.nodes.iter().position(|node| {
+            node.as_ref().is_some_and(|node| {
+                let height = node.machine.height();
+                !node.committed.contains_key(&height)
+                    && self
+                        .honest_nodes()
+                        .any(|other| other.committed.contains_key(&height))
+            })
+        })
+    }
+
+    /// Imports the block committed by other validators and starts the next height.
+    fn sync(&mut self, index: usize) {
+        let node = self.nodes[index].as_ref().unwrap();
+        let height = node.machine.height();
+        let committed = self
+            .honest_nodes()
+            .find_map(|other| other.committed.get(&height))
+            .copied()
+            .unwrap();
+
+        let node = self.nodes[index].as_mut().unwrap();
+        node.committed.insert(height, committed);
+        node.timeouts.clear();
+        if height < self.target_height {
+            let actions = node.machine.start_height(height.succ().unwrap());
+            self.handle_actions(index, actions);
+        }
+    }
+
+    fn fire_timeout(&mut self) {
+        let candidates: Vec<_> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| node.as_ref().is_some_and(|n| !n.timeouts.is_empty()))
+            .map(|(index, _)| index)
+            .collect();
+        let index = candidates[self.rng.gen_range(0..candidates.len())];
+        let node = self.nodes[index].as_mut().unwrap();
+        let timeout = node.timeouts.remove(0);
+        let actions = node.machine.on_timeout(timeout);
+        self.handle_actions(index, actions);
+    }
+
+    fn deliver(&mut self, to: usize, message: BftMessage) {
+        match self.behaviors[to] {
+            Behavior::Honest => {
+                let node = self.nodes[to].as_mut().unwrap();
+                if let Ok(actions) = node.machine.on_message(message) {
+                    self.handle_actions(to, actions);
+                }
+            }
+            Behavior::Crashed => {}
+            Behavior::Byzantine => self.attack(to, message),
+        }
+    }
+
+    /// The byzantine validator equivocates in every round it learns about:
+    /// it sends two conflicting proposals, if it is the proposer, and two conflicting
+    /// votes of each type. Like the gossip, the network delivers all of them
+    /// to all validators, but in different order.
+    fn attack(&mut self, attacker: usize, message: BftMessage) {
+        let height = message.height();
+        let round = message.round();
+        let known_block = match &message {
+            BftMessage::Proposal(proposal) => Some(proposal.entity.block.id()),
+            BftMessage::Vote(_) => None,
+        };
+        if !self.attacked.insert((height, round, known_block.is_some())) {
+            return
+        }
+
+        let key = self.keys[attacker];
+        let validators = validator_set(&self.keys);
+        let mut messages = vec![];
+        if validators.proposer(height, round) == Some(&address(&key))
+            && known_block.is_none()
+        {
+            for seed in [1_000_000, 1_000_001] {
+                let proposal = Proposal {
+                    height,
+                    round,
+                    valid_round: None,
+                    block: block(height, seed),
+                };
+                messages.push(sign_proposal(&key, proposal));
+            }
+        }
+        for vote_type in [VoteType::Prevote, VoteType::Precommit] {
+            for seed in [2_000_000, 2_000_001] {
+                let fake_block = block(height, seed).id();
+                let block_id = match self.rng.gen_range(0..3) {
+                    0 => None,
+                    1 => Some(fake_block),
+                    _ => known_block.or(Some(fake_block)),
+                };
+                let vote = Vote {
+                    height,
+                    round,
+                    vote_type,
+                    block_id,
+                };
+                messages.push(sign_vote(&key, vote));
+            }
+        }
+        for message in messages {
+            self.broadcast(attacker, &message);
+        }
+    }
+
+    fn handle_actions(&mut self, index: usize, actions: Vec<Action>) {
+        let mut actions: std::collections::VecDeque<_> = actions.into();
+        while let Some(action) = actions.pop_front() {
+            let node = self.nodes[index].as_mut().unwrap();
+            let more = match action {
+                Action::Propose {
+                    height,
+                    round,
+                    valid_block,
+                } => {
+                    let (valid_round, block) = match valid_block {
+                        Some((valid_round, block)) => (Some(valid_round), block),
+                        None => {
+                            (None, block(height, (index as u64) << 32 | round as u64))
+                        }
+                    };
+                    let proposal = Proposal {
+                        height,
+                        round,
+                        valid_round,
+                        block,
+                    };
+                    let message = sign_proposal(&node.key, proposal);
+                    let more = node.machine.on_message(message.clone()).unwrap();
+                    self.broadcast(index, &message);
+                    more
+                }
+                Action::ValidateProposal {
+                    height,
+                    round,
+                    block,
+                } => {
+                    let valid = !self.invalid_blocks.contains(&block.id());
+                    node.machine
+                        .on_proposal_validated(height, round, block.id(), valid)
+                }
+                Action::Vote(vote) => {
+                    let message = sign_vote(&node.key, vote);
+                    let more = node.machine.on_message(message.clone()).unwrap();
+                    self.broadcast(index, &message);
+                    more
+                }
+                Action::ScheduleTimeout(timeout) => {
+                    node.timeouts.push(timeout);
+                    vec![]
+                }
+                Action::Commit { block, consensus } => {
+                    let header = block.header();
+                    let height = *header.height();
+                    assert!(
+                        verify_consensus(
+                            &self.consensus_config,
+                            &CHAIN_ID,
+                            header,
+                            &consensus
+                        ),
+                        "The consensus of the committed block should be valid"
+                    );
+                    assert!(
+                        !self.invalid_blocks.contains(&block.id()),
+                        "The invalid block was committed"
+                    );
+                    for other in self.nodes.iter().flatten() {
+                        if let Some((other_block, _)) = other.committed.get(&height) {
+                            assert_eq!(
+                                *other_block,
+                                block.id(),
+                                "Validators committed different blocks at the height {height}"
+                            );
+                        }
+                    }
+                    let node = self.nodes[index].as_mut().unwrap();
+                    node.committed.insert(height, (block.id(), consensus.round));
+                    node.timeouts.clear();
+                    if height < self.target_height {
+                        node.machine.start_height(height.succ().unwrap())
+                    } else {
+                        vec![]
+                    }
+                }
+            };
+            actions.extend(more);
+        }
+    }
+}
+
+#[test]
+fn honest_validators__commit_the_same_blocks() {
+    for seed in 0..10 {
+        let mut network = Network::new(seed, vec![Behavior::Honest; 4], 5);
+        network.run(100_000);
+    }
+}
+
+#[test]
+fn single_validator__commits_blocks_alone() {
+    let mut network = Network::new(0, vec![Behavior::Honest], 5);
+    network.run(1_000);
+}
+
+#[test]
+fn crashed_minority__does_not_stop_the_network() {
+    for seed in 0..10 {
+        let mut behaviors = vec![Behavior::Honest; 7];
+        behaviors[1] = Behavior::Crashed;
+        behaviors[4] = Behavior::Crashed;
+        let mut network = Network::new(seed, behaviors, 5);
+        network.run(200_000);
+    }
+}
+
+#[test]
+fn byzantine_minority__does_not_break_safety() {
+    for seed in 0..20 {
+        let mut behaviors = vec![Behavior::Honest; 4];
+        behaviors[(seed % 4) as usize] = Behavior::Byzantine;
+        let mut network = Network::new(seed, behaviors, 5);
+        network.run(200_000);
+    }
+}
+
+#[test]
+fn byzantine_minority_of_seven_validators__does_not_break_safety() {
+    for seed in 0..10 {
+        let mut behaviors = vec![Behavior::Honest; 7];
+        behaviors[2] = Behavior::Byzantine;
+        behaviors[3 + (seed % 4) as usize] = Behavior::Byzantine;
+        let mut network = Network::new(seed, behaviors, 3);
+        network.run(400_000);
+    }
+}
+
+#[test]
+fn invalid_proposal__is_not_committed() {
+    let mut network = Network::new(7, vec![Behavior::Honest; 4], 1);
+    let height = 1u32.into();
+    let proposer = validator_set(&network.keys)
+        .proposer(height, 0)
+        .copied()
+        .unwrap();
+    let proposer_index = network
+        .keys
+        .iter()
+        .position(|key| address(key) == proposer)
+        .unwrap();
+    let invalid_block = block(height, (proposer_index as u64) << 32);
+    network.invalid_blocks.insert(invalid_block.id());
+
+    network.run(100_000);
+
+    for node in network.honest_nodes() {
+        let (_, round) = node.committed[&height];
+        assert!(round > 0, "The block of the round 0 is invalid");
+    }
+}
+
+struct Setup {
+    keys: Vec<SecretKey>,
+    machine: StateMachine,
+    height: BlockHeight,
+}
+
+/// Creates the state machine of the validator that is not the proposer
+/// of the first two rounds of the height.
+fn setup() -> Setup {
+    let keys = keys(&mut StdRng::seed_from_u64(5), 4);
+    let validators = validator_set(&keys);
+    let height: BlockHeight = 1u32.into();
+    let own_key = keys
+        .iter()
+        .find(|key| {
+            let address = address(key);
+            validators.proposer(height, 0) != Some(&address)
+                && validators.proposer(height, 1) != Some(&address)
+        })
+        .unwrap();
+    let mut machine = StateMachine::new(validators, address(own_key), CHAIN_ID);
+    let _ = machine.start_height(height);
+    Setup {
+        keys,
+        machine,
+        height,
+    }
+}
+
+fn key_of_proposer(keys: &[SecretKey], height: BlockHeight, round: u32) -> &SecretKey {
+    let proposer = *validator_set(keys).proposer(height, round).unwrap();
+    keys.iter().find(|key| address(key) == proposer).unwrap()
+}
+
+#[test]
+fn on_message__rejects_proposal_from_not_proposer() {
+    let Setup {
+        keys,
+        mut machine,
+        height,
+    } = setup();
+    let proposer = key_of_proposer(&keys, height, 0);
+    let not_proposer = keys.iter().find(|key| *key != proposer).unwrap();
+    let proposal = Proposal {
+        height,
+        round: 0,
+        valid_round: None,
+        block: block(height, 0),
+    };
+
+    let result = machine.on_message(sign_proposal(not_proposer, proposal));
+
+    assert_eq!(
+        result,
+        Err(MessageError::NotProposer(address(not_proposer)))
+    );
+}
+
+#[test]
+fn on_message__rejects_vote_from_unknown_validator() {
+    let Setup {
+        mut machine,
+        height,
+        ..
+    } = setup();
+    let unknown = SecretKey::random(&mut StdRng::seed_from_u64(100));
+
+    let result = machine.on_message(sign_vote(&unknown, Vote::prevote(height, 0, None)));
+
+    assert_eq!(
+        result,
+        Err(MessageError::UnknownValidator(address(&unknown)))
+    );
+}
+
+#[test]
+fn on_message__ignores_messages_of_committed_height() {
+    let Setup {
+        keys,
+        mut machine,
+        height,
+    } = setup();
+    let _ = machine.start_height(height.succ().unwrap());
+
+    let result = machine.on_message(sign_vote(&keys[0], Vote::prevote(height, 0, None)));
+
+    assert_eq!(result, Err(MessageError::Outdated));
+}
+
+#[test]
+fn on_message__requests_validation_of_proposal() {
+    let Setup {
+        keys,
+        mut machine,
+        height,
+    } = setup();
+    let block = block(height, 0);
+    let proposal = Proposal {
+        height,
+        round: 0,
+        valid_round: None,
+        block: block.clone(),
+    };
+
+    let actions = machine
+        .on_message(sign_proposal(key_of_proposer(&keys, height, 0), proposal))
+        .unwrap();
+
+    assert_eq!(
+        actions,
+        vec![Action::ValidateProposal {
+            height,
+            round: 0,
+            block: block.clone()
+        }]
+    );
+    let actions = machine.on_proposal_validated(height, 0, block.id(), true);
+    assert_eq!(
+        actions,
+        vec![Action::Vote(Vote::prevote(height, 0, Some(block.id())))]
+    );
+}
+
+#[test]
+fn on_proposal_validated__prevotes_nil_for_invalid_block() {
+    let Setup {
+        keys,
+        mut machine,
+        height,
+    } = setup();
+    let block = block(height, 0);
+    let proposal = Proposal {
+        height,
+        round: 0,
+        valid_round: None,
+        block: block.clone(),
+    };
+    let _ = machine
+        .on_message(sign_proposal(key_of_proposer(&keys, height, 0), proposal))
+        .unwrap();
+
+    let actions = machine.on_proposal_validated(height, 0, block.id(), false);
+
+    assert_eq!(actions, vec![Action::Vote(Vote::prevote(height, 0, None))]);
+}
+
+#[test]
+fn on_message__processes_messages_of_next_height_after_its_start() {
+    let Setup {
+        keys,
+        mut machine,
+        height,
+    } = setup();
+    let next_height = height.succ().unwrap();
+    let block = block(next_height, 0);
+    let proposal = Proposal {
+        height: next_height,
+        round: 0,
+        valid_round: None,
+        block: block.clone(),
+    };
+
+    let actions = machine
+        .on_message(sign_proposal(
+            key_of_proposer(&keys, next_height, 0),
+            proposal,
+        ))
+        .unwrap();
+    assert_eq!(actions, vec![]);
+
+    let actions = machine.start_height(next_height);
+    assert!(actions.contains(&Action::ValidateProposal {
+        height: next_height,
+        round: 0,
+        block,
+    }));
+}
+
+#[test]
+fn locked_validator__prevotes_nil_for_another_new_block() {
+    let Setup {
+        keys,
+        mut machine,
+        height,
+    } = setup();
+    let locked_block = block(height, 0);
+    let proposal = Proposal {
+        height,
+        round: 0,
+        valid_round: None,
+        block: locked_block.clone(),
+    };
+    let _ = machine
+        .on_message(sign_proposal(key_of_proposer(&keys, height, 0), proposal))
+        .unwrap();
+    let _ = machine.on_proposal_validated(height, 0, locked_block.id(), true);
+
+    // Given the quorum of prevotes for the block, the validator locks on it.
+    let mut actions = vec![];
+    for key in &keys[..3] {
+        let vote = Vote::prevote(height, 0, Some(locked_block.id()));
+        actions.extend(machine.on_message(sign_vote(key, vote)).unwrap());
+    }
+    assert!(actions.contains(&Action::Vote(Vote::precommit(
+        height,
+        0,
+        Some(locked_block.id())
+    ))));
+
+    // The round 0 ends without the commit.
+    let _ = machine.on_timeout(Timeout {
+        height,
+        round: 0,
+        step: TimeoutStep::Precommit,
+    });
+    assert_eq!(machine.round(), 1);
+
+    // When
+    let another_block = block(height, 1);
+    let proposal = Proposal {
+        height,
+        round: 1,
+        valid_round: None,
+        block: another_block.clone(),
+    };
+    let _ = machine
+        .on_message(sign_proposal(key_of_proposer(&keys, height, 1), proposal))
+        .unwrap();
+    let actions = machine.on_proposal_validated(height, 1, another_block.id(), true);
+
+    // Then
+    assert_eq!(actions, vec![Action::Vote(Vote::prevote(height, 1, None))]);
+}
+
+#[test]
+fn conflicting_proposal__is_committed_after_quorum_of_precommits() {
+    let Setup {
+        keys,
+        mut machine,
+        height,
+    } = setup();
+    let proposer = key_of_proposer(&keys, height, 0);
+    let first_block = block(height, 0);
+    let conflicting_block = block(height, 1);
+    for block in [&first_block, &conflicting_block] {
+        let proposal = Proposal {
+            height,
+            round: 0,
+            valid_round: None,
+            block: block.clone(),
+        };
+        let _ = machine
+            .on_message(sign_proposal(proposer, proposal))
+            .unwrap();
+    }
+    let _ = machine.on_proposal_validated(height, 0, first_block.id(), true);
+
+    // Given the votes of validators for the conflicting block,
+    // the validator validates it.
+    let mut actions = vec![];
+    for key in &keys[..3] {
+        let vote = Vote::precommit(height, 0, Some(conflicting_block.id()));
+        actions.extend(machine.on_message(sign_vote(key, vote)).unwrap());
+    }
+    assert!(actions.contains(&Action::ValidateProposal {
+        height,
+        round: 0,
+        block: conflicting_block.clone(),
+    }));
+
+    // When
+    let actions = machine.on_proposal_validated(height, 0, conflicting_block.id(), true);
+
+    // Then
+    let [Action::Commit { block, consensus }] = actions.as_slice() else {
+        panic!("Expected the commit of the block, got {actions:?}");
+    };
+    assert_eq!(block, &conflicting_block);
+    assert_eq!(consensus.round, 0);
+    assert_eq!(consensus.signatures.len(), 3);
+}
+
+#[test]
+fn on_message__rejects_vote_signed_for_another_chain() {
+    let Setup {
+        keys,
+        mut machine,
+        height,
+    } = setup();
+    let vote = Vote::prevote(height, 0, None);
+
+    let result =
+        machine.on_message(sign_vote_for_chain(&keys[0], vote, &ChainId::new(1)));
+
+    assert!(matches!(result, Err(MessageError::UnknownValidator(_))));
+}
+
+#[test]
+fn on_message__ignores_messages_of_rounds_far_ahead() {
+    let Setup {
+        keys,
+        mut machine,
+        height,
+    } = setup();
+    let far_round = MAX_ROUND_LOOKAHEAD + 1;
+
+    let far =
+        machine.on_message(sign_vote(&keys[0], Vote::prevote(height, far_round, None)));
+    let near = machine.on_message(sign_vote(
+        &keys[0],
+        Vote::prevote(height, MAX_ROUND_LOOKAHEAD, None),
+    ));
+
+    assert_eq!(far, Err(MessageError::FarFutureRound(far_round)));
+    assert!(!far.unwrap_err().is_malicious());
+    assert!(near.is_ok());
+}
+
+#[test]
+fn resume_height__keeps_the_lock_of_signing_state() {
+    let Setup {
+        keys,
+        mut machine,
+        height,
+    } = setup();
+    let locked_block = block(height, 0);
+    let state = SigningState {
+        height,
+        round: 1,
+        locked: Some((0, locked_block.id())),
+        precommit: Some((0, Some(locked_block.id()))),
+        ..Default::default()
+    };
+
+    // Given
+    let _ = machine.resume_height(&state);
+    assert_eq!(machine.round(), 1);
+    assert_eq!(machine.locked(), Some((0, locked_block.id())));
+
+    // When
+    let another_block = block(height, 1);
+    let proposal = Proposal {
+        height,
+        round: 1,
+        valid_round: None,
+        block: another_block.clone(),
+    };
+    let _ = machine
+        .on_message(sign_proposal(key_of_proposer(&keys, height, 1), proposal))
+        .unwrap();
+    let actions = machine.on_proposal_validated(height, 1, another_block.id(), true);
+
+    // Then
+    assert_eq!(actions, vec![Action::Vote(Vote::prevote(height, 1, None))]);
+}
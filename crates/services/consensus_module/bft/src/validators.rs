@@ -0,0 +1,105 @@
+//! The set of validators that participate in the consensus.
+
+use fuel_core_chain_config::BftConfig;
+use fuel_core_types::fuel_types::{
+    Address,
+    BlockHeight,
+};
+
+/// The validators of the network, identified by the addresses of their signing keys.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidatorSet {
+    validators: Vec<Address>,
+    quorum: usize,
+}
+
+impl ValidatorSet {
+    /// Creates the validator set from the addresses of the signing keys.
+    pub fn new(validators: Vec<Address>) -> Self {
+        Self::from(&BftConfig::new(validators))
+    }
+
+    /// The number of validators.
+    pub fn len(&self) -> usize {
+        self.validators.len()
+    }
+
+    /// Returns `true` if the set has no validators.
+    pub fn is_empty(&self) -> bool {
+        self.validators.is_empty()
+    }
+
+    /// Returns `true` if the address belongs to the validator.
+    pub fn contains(&self, address: &Address) -> bool {
+        self.validators.contains(address)
+    }
+
+    /// The number of validators required to commit a block.
+    pub fn quorum(&self) -> usize {
+        self.quorum
+    }
+
+    /// The number of validators that guarantees at least one honest validator
+    /// among them. If that many validators are in a later round, the node
+    /// skips to this round.
+    pub fn skip_threshold(&self) -> usize {
+        self.len().saturating_sub(self.quorum).saturating_add(1)
+    }
+
+    /// Returns the proposer of the round at the height.
+    /// The proposers are rotated in the round-robin order.
+    pub fn proposer(&self, height: BlockHeight, round: u32) -> Option<&Address> {
+        let len = u64::try_from(self.len()).ok()?;
+        let index = u64::from(u32::from(height))
+            .wrapping_add(u64::from(round))
+            .checked_rem(len)?;
+        self.validators.get(usize::try_from(index).ok()?)
+    }
+}
+
+impl From<&BftConfig> for ValidatorSet {
+    fn from(config: &BftConfig) -> Self {
+        Self {
+            validators: config.validators().to_vec(),
+            quorum: config.quorum(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    fn validators(n: u8) -> ValidatorSet {
+        ValidatorSet::new((0..n).map(|i| Address::from([i; 32])).collect())
+    }
+
+    #[test]
+    fn skip_threshold__is_more_than_one_third_of_validators() {
+        assert_eq!(validators(1).skip_threshold(), 1);
+        assert_eq!(validators(4).skip_threshold(), 2);
+        assert_eq!(validators(7).skip_threshold(), 3);
+        assert_eq!(validators(10).skip_threshold(), 4);
+    }
+
+    #[test]
+    fn proposer__rotates_with_height_and_round() {
+        let validators = validators(4);
+
+        assert_eq!(
+            validators.proposer(1u32.into(), 0),
+            Some(&Address::from([1; 32]))
+        );
+        assert_eq!(
+            validators.proposer(1u32.into(), 1),
+            Some(&Address::from([2; 32]))
+        );
+        assert_eq!(
+            validators.proposer(2u32.into(), 3),
+            Some(&Address::from([1; 32]))
+        );
+        assert_eq!(ValidatorSet::new(vec![]).proposer(1u32.into(), 0), None);
+    }
+}
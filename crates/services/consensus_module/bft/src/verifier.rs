@@ -0,0 +1,43 @@
+use crate::validators::ValidatorSet;
+use fuel_core_chain_config::ConsensusConfig;
+use fuel_core_types::{
+    blockchain::{
+        consensus::bft::BftConsensus,
+        header::BlockHeader,
+    },
+    fuel_tx::Input,
+    fuel_types::ChainId,
+};
+use std::collections::HashSet;
+
+#[cfg(test)]
+mod tests;
+
+/// Verifies that the block was committed by a quorum of validators.
+///
+/// Each signature should belong to a different validator from the chain config.
+pub fn verify_consensus(
+    consensus_config: &ConsensusConfig,
+    chain_id: &ChainId,
+    header: &BlockHeader,
+    consensus: &BftConsensus,
+) -> bool {
+    let ConsensusConfig::Bft(config) = consensus_config else {
+        return false
+    };
+    let validators = ValidatorSet::from(config);
+    let message = consensus.commit_message(chain_id, *header.height(), header.id());
+
+    let mut signers = HashSet::with_capacity(consensus.signatures.len());
+    for signature in &consensus.signatures {
+        let Ok(public_key) = signature.recover(&message) else {
+            return false
+        };
+        let signer = Input::owner(&public_key);
+        if !validators.contains(&signer) || !signers.insert(signer) {
+            return false
+        }
+    }
+
+    signers.len() >= validators.quorum()
+}
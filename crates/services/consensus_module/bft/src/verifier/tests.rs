@@ -0,0 +1,165 @@
+#![allow(non_snake_case)]
+
+use super::*;
+use fuel_core_chain_config::BftConfig;
+use fuel_core_types::{
+    blockchain::consensus::bft::Vote,
+    fuel_crypto::{
+        SecretKey,
+        Signature,
+    },
+    fuel_types::{
+        Address,
+        ChainId,
+    },
+};
+use rand::{
+    SeedableRng,
+    rngs::StdRng,
+};
+
+const CHAIN_ID: ChainId = ChainId::new(9889);
+
+struct Setup {
+    config: ConsensusConfig,
+    keys: Vec<SecretKey>,
+    header: BlockHeader,
+}
+
+fn setup(validators: usize) -> Setup {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let keys: Vec<_> = (0..validators)
+        .map(|_| SecretKey::random(&mut rng))
+        .collect();
+    let addresses = keys
+        .iter()
+        .map(|key| Input::owner(&key.public_key()))
+        .collect();
+    let mut header = BlockHeader::default();
+    header.set_block_height(5u32.into());
+    Setup {
+        config: ConsensusConfig::Bft(BftConfig::new(addresses)),
+        keys,
+        header,
+    }
+}
+
+fn precommit_signature(
+    key: &SecretKey,
+    chain_id: &ChainId,
+    header: &BlockHeader,
+    round: u32,
+) -> Signature {
+    let vote = Vote::precommit(*header.height(), round, Some(header.id()));
+    Signature::sign(key, &vote.signing_message(chain_id))
+}
+
+fn seal_for_chain(
+    keys: &[SecretKey],
+    chain_id: &ChainId,
+    header: &BlockHeader,
+    round: u32,
+) -> BftConsensus {
+    BftConsensus::new(
+        round,
+        keys.iter()
+            .map(|key| precommit_signature(key, chain_id, header, round))
+            .collect(),
+    )
+}
+
+fn seal(keys: &[SecretKey], header: &BlockHeader, round: u32) -> BftConsensus {
+    seal_for_chain(keys, &CHAIN_ID, header, round)
+}
+
+#[test]
+fn verify_consensus__accepts_quorum_of_validators() {
+    let Setup {
+        config,
+        keys,
+        header,
+    } = setup(4);
+
+    let consensus = seal(&keys[..3], &header, 2);
+
+    assert!(verify_consensus(&config, &CHAIN_ID, &header, &consensus));
+}
+
+#[test]
+fn verify_consensus__rejects_less_than_quorum() {
+    let Setup {
+        config,
+        keys,
+        header,
+    } = setup(4);
+
+    let consensus = seal(&keys[..2], &header, 0);
+
+    assert!(!verify_consensus(&config, &CHAIN_ID, &header, &consensus));
+}
+
+#[test]
+fn verify_consensus__rejects_duplicated_signatures() {
+    let Setup {
+        config,
+        keys,
+        header,
+    } = setup(4);
+
+    let consensus = seal(&[keys[0], keys[0], keys[1]], &header, 0);
+
+    assert!(!verify_consensus(&config, &CHAIN_ID, &header, &consensus));
+}
+
+#[test]
+fn verify_consensus__rejects_signature_of_not_validator() {
+    let Setup {
+        config,
+        mut keys,
+        header,
+    } = setup(4);
+    keys[0] = SecretKey::random(&mut StdRng::seed_from_u64(1));
+
+    let consensus = seal(&keys[..3], &header, 0);
+
+    assert!(!verify_consensus(&config, &CHAIN_ID, &header, &consensus));
+}
+
+#[test]
+fn verify_consensus__rejects_signatures_for_another_round() {
+    let Setup {
+        config,
+        keys,
+        header,
+    } = setup(4);
+
+    let mut consensus = seal(&keys[..3], &header, 0);
+    consensus.round = 1;
+
+    assert!(!verify_consensus(&config, &CHAIN_ID, &header, &consensus));
+}
+
+#[test]
+fn verify_consensus__rejects_not_bft_consensus_config() {
+    let Setup { keys, header, .. } = setup(4);
+    let config = ConsensusConfig::PoA {
+        signing_key: Address::default(),
+    };
+
+    let consensus = seal(&keys, &header, 0);
+
+    assert!(!verify_consensus(&config, &CHAIN_ID, &header, &consensus));
+}
+
+#[test]
+fn verify_consensus__rejects_signatures_for_another_chain() {
+    let Setup {
+        config,
+        keys,
+        header,
+    } = setup(4);
+
+    let consensus = seal_for_chain(&keys, &ChainId::new(1), &header, 0);
+
+    assert!(!verify_consensus(&config, &CHAIN_ID, &header, &consensus));
+}
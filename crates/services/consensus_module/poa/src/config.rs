@@ -44,4 +44,14 @@ pub enum Trigger {
     /// Opens the block production immediately and keeps it open for the specified period.
     /// After period is over, the block is produced.
     Open { period: Duration },
+    /// A new block is produced as soon as the pending transactions fill
+    /// `fill_threshold_percent` of the block gas or transactions limit.
+    /// Otherwise, the block is produced when `block_time` has elapsed.
+    /// If `max_idle_time` is set, empty blocks are not produced
+    /// until `max_idle_time` has elapsed since the last block.
+    Hybrid {
+        block_time: Duration,
+        fill_threshold_percent: u8,
+        max_idle_time: Option<Duration>,
+    },
 }
@@ -31,6 +31,43 @@ use tokio::time::Instant;
 #[cfg_attr(test, mockall::automock)]
 pub trait TransactionPool: Send + Sync {
     fn new_txs_watcher(&self) -> tokio::sync::watch::Receiver<()>;
+
+    /// Returns how much of the next block can be filled by the transactions
+    /// currently waiting in the `TxPool`.
+    fn block_fill(&self) -> BlockFill;
+}
+
+/// The amount of pending transactions in the `TxPool` relative to the capacity of the block.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BlockFill {
+    /// The total gas of the pending transactions.
+    pub pending_gas: u64,
+    /// The number of the pending transactions.
+    pub pending_transactions: u64,
+    /// The gas limit of the block.
+    pub max_gas: u64,
+    /// The maximum number of transactions in the block.
+    pub max_transactions: u64,
+}
+
+impl BlockFill {
+    /// Returns `true` if there are no pending transactions.
+    pub fn is_empty(&self) -> bool {
+        self.pending_transactions == 0
+    }
+
+    /// Returns `true` if the pending transactions use at least `percent`
+    /// of the gas or of the transactions limit of the block.
+    pub fn is_filled(&self, percent: u8) -> bool {
+        let reached = |pending: u64, max: u64| {
+            u128::from(pending).saturating_mul(100)
+                >= u128::from(max).saturating_mul(u128::from(percent))
+        };
+
+        !self.is_empty()
+            && (reached(self.pending_gas, self.max_gas)
+                || reached(self.pending_transactions, self.max_transactions))
+    }
 }
 
 /// The source of transactions for the block.
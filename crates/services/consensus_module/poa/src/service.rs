@@ -128,6 +128,7 @@ pub struct MainTask<B, I, S, PB, C, RS, RP> {
     signer: Arc<S>,
     block_producer: B,
     block_importer: I,
+    txpool: Box<dyn TransactionPool>,
     new_txs_watcher: tokio::sync::watch::Receiver<()>,
     request_receiver: mpsc::Receiver<Request>,
     shared_state: SharedState,
@@ -157,7 +158,7 @@ where
     RP: BlockReconciliationReadPort,
 {
     #[allow(clippy::too_many_arguments)]
-    pub fn new<P: P2pPort, T: TransactionPool>(
+    pub fn new<P: P2pPort, T: TransactionPool + 'static>(
         last_block: &BlockHeader,
         config: Config,
         txpool: T,
@@ -206,6 +207,7 @@ where
             signer,
             block_producer,
             block_importer,
+            txpool: Box::new(txpool),
             new_txs_watcher,
             request_receiver,
             shared_state: SharedState { request_sender },
@@ -246,7 +248,7 @@ where
     fn next_time(&self, request_type: RequestType) -> anyhow::Result<Tai64> {
         match request_type {
             RequestType::Manual => match self.trigger {
-                Trigger::Never | Trigger::Instant => {
+                Trigger::Never | Trigger::Instant | Trigger::Hybrid { .. } => {
                     let duration = self.last_block_created.elapsed();
                     increase_time(self.last_timestamp, duration)
                 }
@@ -571,6 +573,7 @@ where
         match self.trigger {
             Trigger::Interval { block_time } => block_time,
             Trigger::Open { period } => period,
+            Trigger::Hybrid { block_time, .. } => block_time,
             _ => Duration::from_secs(1),
         }
     }
@@ -707,7 +710,7 @@ where
 
         match self.trigger {
             Trigger::Never | Trigger::Instant => {}
-            Trigger::Interval { .. } | Trigger::Open { .. } => {
+            Trigger::Interval { .. } | Trigger::Open { .. } | Trigger::Hybrid { .. } => {
                 return Ok(Self {
                     last_block_created: Instant::now(),
                     ..self
@@ -781,6 +784,38 @@ where
                 };
                 Box::pin(async move { deadline })
             }
+            Trigger::Hybrid {
+                block_time,
+                fill_threshold_percent,
+                max_idle_time,
+            } => {
+                let next_block_time = match self
+                    .last_block_created
+                    .checked_add(block_time)
+                    .ok_or(anyhow!("Time exceeds system limits"))
+                {
+                    Ok(time) => time,
+                    Err(err) => return TaskNextAction::ErrorContinue(err),
+                };
+                let idle_deadline = match max_idle_time
+                    .map(|idle_time| {
+                        self.last_block_created
+                            .checked_add(idle_time)
+                            .ok_or(anyhow!("Time exceeds system limits"))
+                    })
+                    .transpose()
+                {
+                    Ok(time) => time,
+                    Err(err) => return TaskNextAction::ErrorContinue(err),
+                };
+                Box::pin(next_hybrid_block_production(
+                    self.txpool.as_ref(),
+                    &mut self.new_txs_watcher,
+                    fill_threshold_percent,
+                    next_block_time,
+                    idle_deadline,
+                ))
+            }
         };
 
         tokio::select! {
@@ -853,6 +888,44 @@ where
     ))
 }
 
+/// Waits until the block should be produced by the `Trigger::Hybrid`.
+async fn next_hybrid_block_production(
+    txpool: &dyn TransactionPool,
+    new_txs_watcher: &mut tokio::sync::watch::Receiver<()>,
+    fill_threshold_percent: u8,
+    next_block_time: Instant,
+    idle_deadline: Option<Instant>,
+) -> Instant {
+    loop {
+        let block_fill = txpool.block_fill();
+        if block_fill.is_filled(fill_threshold_percent) {
+            return Instant::now();
+        }
+
+        let now = Instant::now();
+        let wake_up_at = if now < next_block_time {
+            next_block_time
+        } else {
+            match idle_deadline {
+                // The empty block is skipped until the idle deadline.
+                Some(idle_deadline) if block_fill.is_empty() && now < idle_deadline => {
+                    idle_deadline
+                }
+                _ => return now,
+            }
+        };
+
+        tokio::select! {
+            result = new_txs_watcher.changed() => {
+                if result.is_err() {
+                    sleep_until(wake_up_at).await;
+                }
+            }
+            _ = sleep_until(wake_up_at) => {}
+        }
+    }
+}
+
 fn increase_time(time: Tai64, duration: Duration) -> anyhow::Result<Tai64> {
     let timestamp = time.0;
     let timestamp = timestamp
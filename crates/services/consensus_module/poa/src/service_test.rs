@@ -7,6 +7,7 @@ use crate::{
     Trigger,
     new_service,
     ports::{
+        BlockFill,
         BlockProducer,
         BlockReconciliationReadPort,
         BlockSigner,
@@ -560,6 +561,9 @@ async fn run__when_execute_and_commit_fails_during_reconcile_then_does_not_produ
     let _ = stop_result.unwrap();
 }
 
+/// The transactions limit of the block reported by the mocked `TxPool`.
+const MAX_TXS_PER_BLOCK: u64 = 10;
+
 impl MockTransactionPool {
     fn no_tx_updates() -> Self {
         let mut txpool = MockTransactionPool::default();
@@ -567,6 +571,7 @@ impl MockTransactionPool {
             let (sender, _) = watch::channel(());
             move || sender.subscribe()
         });
+        txpool.expect_block_fill().returning(BlockFill::default);
         txpool
     }
 
@@ -579,6 +584,15 @@ impl MockTransactionPool {
             let sender = new_txs_notifier.clone();
             move || sender.subscribe()
         });
+        txpool.expect_block_fill().returning({
+            let txs = txs.clone();
+            move || BlockFill {
+                pending_gas: 0,
+                pending_transactions: txs.lock().unwrap().len() as u64,
+                max_gas: u64::MAX,
+                max_transactions: MAX_TXS_PER_BLOCK,
+            }
+        });
 
         TxPoolContext {
             txpool,
@@ -14,6 +14,11 @@ async fn clean_startup_shutdown_each_trigger() -> anyhow::Result<()> {
         Trigger::Interval {
             block_time: Duration::new(1, 0),
         },
+        Trigger::Hybrid {
+            block_time: Duration::new(1, 0),
+            fill_threshold_percent: 50,
+            max_idle_time: None,
+        },
     ] {
         let mut ctx_builder = TestContextBuilder::new();
         ctx_builder.with_config(Config {
@@ -592,3 +597,173 @@ async fn open_trigger__produce_blocks_with_correct_time() {
     assert_eq!(first_block_time.0, expected_first_block_time);
     assert_eq!(second_block_time.0, expected_second_block_time);
 }
+
+#[tokio::test]
+async fn hybrid_trigger__produces_block_as_soon_as_txpool_fills_threshold() {
+    // given
+    let block_time = Duration::new(10, 0);
+    let mut ctx = DefaultContext::new(Config {
+        trigger: Trigger::Hybrid {
+            block_time,
+            fill_threshold_percent: 50,
+            max_idle_time: None,
+        },
+        signer: SignMode::Key(test_signing_key()),
+        metrics: false,
+        ..Default::default()
+    })
+    .await;
+    ctx.new_txs_notifier.send_replace(());
+    time::sleep(Duration::new(1, 0)).await;
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+
+    // when
+    let filled_at = Instant::now();
+    {
+        let mut guard = ctx.txs.lock().unwrap();
+        for _ in 0..4 {
+            guard.push(make_tx(&mut ctx.rng));
+        }
+        ctx.new_txs_notifier.send_replace(());
+    }
+    let block = ctx.block_import.recv().await;
+    ctx.txs.lock().unwrap().clear();
+
+    // then
+    assert!(block.is_ok());
+    assert!(filled_at.elapsed() < block_time);
+
+    // Stop
+    assert_eq!(ctx.test_ctx.stop().await, State::Stopped);
+}
+
+#[tokio::test]
+async fn hybrid_trigger__produces_block_at_deadline_when_txpool_is_below_threshold() {
+    // given
+    let mut ctx = DefaultContext::new(Config {
+        trigger: Trigger::Hybrid {
+            block_time: Duration::new(2, 0),
+            fill_threshold_percent: 50,
+            max_idle_time: None,
+        },
+        signer: SignMode::Key(test_signing_key()),
+        metrics: false,
+        ..Default::default()
+    })
+    .await;
+
+    // when
+    ctx.new_txs_notifier.send_replace(());
+    time::sleep(Duration::new(1, 0)).await;
+    let before_deadline = ctx.block_import.try_recv();
+    time::sleep(Duration::new(2, 0)).await;
+    let after_deadline = ctx.block_import.try_recv();
+
+    // then
+    assert!(matches!(
+        before_deadline,
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+    assert!(after_deadline.is_ok());
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+
+    // Stop
+    assert_eq!(ctx.test_ctx.stop().await, State::Stopped);
+}
+
+#[tokio::test]
+async fn hybrid_trigger__produces_empty_blocks_at_deadline_without_max_idle_time() {
+    // given
+    let mut ctx = DefaultContext::new(Config {
+        trigger: Trigger::Hybrid {
+            block_time: Duration::new(2, 0),
+            fill_threshold_percent: 50,
+            max_idle_time: None,
+        },
+        signer: SignMode::Key(test_signing_key()),
+        metrics: false,
+        ..Default::default()
+    })
+    .await;
+    ctx.txs.lock().unwrap().clear();
+
+    // when
+    time::sleep(Duration::new(3, 0)).await;
+
+    // then
+    assert!(ctx.block_import.try_recv().is_ok());
+
+    // Stop
+    assert_eq!(ctx.test_ctx.stop().await, State::Stopped);
+}
+
+#[tokio::test]
+async fn hybrid_trigger__skips_empty_blocks_until_max_idle_time() {
+    // given
+    let mut ctx = DefaultContext::new(Config {
+        trigger: Trigger::Hybrid {
+            block_time: Duration::new(2, 0),
+            fill_threshold_percent: 50,
+            max_idle_time: Some(Duration::new(10, 0)),
+        },
+        signer: SignMode::Key(test_signing_key()),
+        metrics: false,
+        ..Default::default()
+    })
+    .await;
+    ctx.txs.lock().unwrap().clear();
+
+    // when
+    time::sleep(Duration::new(9, 0)).await;
+    let before_idle_deadline = ctx.block_import.try_recv();
+    time::sleep(Duration::new(2, 0)).await;
+    let after_idle_deadline = ctx.block_import.try_recv();
+
+    // then
+    assert!(matches!(
+        before_idle_deadline,
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+    assert!(after_idle_deadline.is_ok());
+
+    // Stop
+    assert_eq!(ctx.test_ctx.stop().await, State::Stopped);
+}
+
+#[tokio::test]
+async fn hybrid_trigger__produces_block_at_deadline_when_txs_arrive_while_idle() {
+    // given
+    let mut ctx = DefaultContext::new(Config {
+        trigger: Trigger::Hybrid {
+            block_time: Duration::new(2, 0),
+            fill_threshold_percent: 50,
+            max_idle_time: Some(Duration::new(10, 0)),
+        },
+        signer: SignMode::Key(test_signing_key()),
+        metrics: false,
+        ..Default::default()
+    })
+    .await;
+    ctx.txs.lock().unwrap().clear();
+    time::sleep(Duration::new(3, 0)).await;
+
+    // when
+    {
+        let mut guard = ctx.txs.lock().unwrap();
+        guard.push(make_tx(&mut ctx.rng));
+        ctx.new_txs_notifier.send_replace(());
+    }
+    time::sleep(Duration::from_millis(1)).await;
+
+    // then
+    assert!(ctx.block_import.try_recv().is_ok());
+
+    // Stop
+    assert_eq!(ctx.test_ctx.stop().await, State::Stopped);
+}
@@ -37,6 +37,7 @@ pub fn verify_consensus(
                 .recover(m)
                 .is_ok_and(|k| Input::owner(&k) == signing_key)
         }
+        ConsensusConfig::Bft(_) => false,
     }
 }
 
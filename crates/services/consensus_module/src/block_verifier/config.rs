@@ -3,7 +3,10 @@
 use fuel_core_chain_config::ConsensusConfig;
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
-    fuel_types::BlockHeight,
+    fuel_types::{
+        BlockHeight,
+        ChainId,
+    },
 };
 
 /// The config of the block verifier.
@@ -14,6 +17,8 @@ pub struct Config {
     pub block_height: BlockHeight,
     /// The DA block height at genesis block.
     pub da_block_height: DaBlockHeight,
+    /// The chain id signed by the BFT validators along with the blocks.
+    pub chain_id: ChainId,
 }
 
 impl Config {
@@ -22,11 +27,13 @@ impl Config {
         consensus: ConsensusConfig,
         block_height: BlockHeight,
         da_block_height: DaBlockHeight,
+        chain_id: ChainId,
     ) -> Self {
         Self {
             consensus,
             block_height,
             da_block_height,
+            chain_id,
         }
     }
 }
@@ -0,0 +1,257 @@
+use fuel_core_types::{
+    fuel_asm::{
+        RegId,
+        Word,
+    },
+    fuel_tx::Receipt,
+    fuel_vm::{
+        Interpreter,
+        InterpreterError,
+        InterpreterStorage,
+        checked_transaction::{
+            IntoChecked,
+            Ready,
+        },
+        interpreter::{
+            CheckedMetadata,
+            EcalHandler,
+            ExecutableTransaction,
+            Memory,
+        },
+        state::StateTransition,
+        verification::Verifier,
+    },
+    services::executor::{
+        CallFrameTrace,
+        TransactionTrace,
+    },
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Executes the transaction instruction by instruction, building the call tree
+/// from the receipts emitted by each instruction and the global gas left after it.
+#[allow(clippy::type_complexity)]
+pub(crate) fn transact_with_call_trace<M, S, Tx, Ecal, V>(
+    mut vm: Interpreter<M, S, Tx, Ecal, V>,
+    tx: Ready<Tx>,
+) -> Result<(StateTransition<Tx, V>, TransactionTrace), InterpreterError<S::DataError>>
+where
+    M: Memory,
+    S: InterpreterStorage,
+    Tx: ExecutableTransaction,
+    <Tx as IntoChecked>::Metadata: CheckedMetadata,
+    Ecal: EcalHandler,
+    V: Verifier + Clone,
+{
+    vm.set_single_stepping(true);
+
+    let mut tracer = CallTracer::default();
+    let mut state = *vm.transact(tx)?.state();
+    while state.is_debug() {
+        tracer.observe(vm.receipts(), vm.registers()[RegId::GGAS]);
+        state = vm.resume()?;
+    }
+    let trace = tracer.finish(vm.receipts(), vm.registers()[RegId::GGAS]);
+
+    let transition = StateTransition::new(
+        state,
+        vm.transaction().clone(),
+        vm.receipts().to_vec(),
+        vm.verifier().clone(),
+    );
+    Ok((transition, trace))
+}
+
+#[derive(Default)]
+struct CallTracer {
+    trace: TransactionTrace,
+    /// The number of receipts already processed by the tracer.
+    observed_receipts: usize,
+    /// Indexes of the open frames with the global gas left at the moment of the call.
+    open_frames: Vec<(usize, Word)>,
+}
+
+impl CallTracer {
+    /// Processes receipts emitted since the last observation.
+    /// Each instruction emits at most one receipt, so the `global_gas` is the gas
+    /// left right after the instruction that emitted it.
+    fn observe(&mut self, receipts: &[Receipt], global_gas: Word) {
+        for receipt in receipts.iter().skip(self.observed_receipts) {
+            self.record(receipt, global_gas);
+        }
+        self.observed_receipts = receipts.len();
+    }
+
+    fn record(&mut self, receipt: &Receipt, global_gas: Word) {
+        match receipt {
+            Receipt::Call {
+                to,
+                amount,
+                asset_id,
+                gas,
+                param1,
+                param2,
+                ..
+            } => {
+                self.current_receipts().push(receipt.clone());
+                let parent = self
+                    .open_frames
+                    .last()
+                    .map(|(index, _)| u32::try_from(*index).unwrap_or(u32::MAX));
+                self.open_frames.push((self.trace.frames.len(), global_gas));
+                self.trace.frames.push(CallFrameTrace {
+                    parent,
+                    contract_id: *to,
+                    function_selector: *param1,
+                    param2: *param2,
+                    asset_id: *asset_id,
+                    amount: *amount,
+                    gas_forwarded: *gas,
+                    gas_used: 0,
+                    receipts: Vec::new(),
+                    revert: None,
+                });
+            }
+            Receipt::Return { .. } | Receipt::ReturnData { .. }
+                if !self.open_frames.is_empty() =>
+            {
+                self.current_receipts().push(receipt.clone());
+                self.close_frame(global_gas);
+            }
+            Receipt::Revert { .. } | Receipt::Panic { .. } => {
+                self.current_receipts().push(receipt.clone());
+                match self.open_frames.last() {
+                    Some((index, _)) => {
+                        self.trace.frames[*index].revert = Some(receipt.clone())
+                    }
+                    None => self.trace.revert = Some(receipt.clone()),
+                }
+            }
+            Receipt::ScriptResult { gas_used, .. } => {
+                // The frames stay open if the execution reverted inside of them.
+                while !self.open_frames.is_empty() {
+                    self.close_frame(global_gas);
+                }
+                self.trace.gas_used = *gas_used;
+                self.trace.receipts.push(receipt.clone());
+            }
+            _ => self.current_receipts().push(receipt.clone()),
+        }
+    }
+
+    fn current_receipts(&mut self) -> &mut Vec<Receipt> {
+        match self.open_frames.last() {
+            Some((index, _)) => &mut self.trace.frames[*index].receipts,
+            None => &mut self.trace.receipts,
+        }
+    }
+
+    fn close_frame(&mut self, global_gas: Word) {
+        if let Some((index, gas_at_call)) = self.open_frames.pop() {
+            self.trace.frames[index].gas_used = gas_at_call.saturating_sub(global_gas);
+        }
+    }
+
+    /// Processes the receipts emitted after the last instruction.
+    fn finish(mut self, receipts: &[Receipt], global_gas: Word) -> TransactionTrace {
+        self.observe(receipts, global_gas);
+        self.trace
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::{
+        fuel_asm::PanicInstruction,
+        fuel_tx::{
+            AssetId,
+            ContractId,
+            PanicReason,
+            ScriptExecutionResult,
+        },
+    };
+
+    fn call(from: ContractId, to: ContractId, gas: Word) -> Receipt {
+        Receipt::call(from, to, 0, AssetId::zeroed(), gas, 1, 2, 0, 0)
+    }
+
+    #[test]
+    fn call_tracer__builds_nested_frames_with_gas_used() {
+        // Given
+        let a = ContractId::from([1; 32]);
+        let b = ContractId::from([2; 32]);
+        let script_result = Receipt::script_result(ScriptExecutionResult::Success, 90);
+        let mut receipts = vec![];
+        let mut tracer = CallTracer::default();
+
+        // When
+        receipts.push(call(ContractId::zeroed(), a, 80));
+        tracer.observe(&receipts, 95);
+        receipts.push(call(a, b, 50));
+        tracer.observe(&receipts, 90);
+        receipts.push(Receipt::log(b, 1, 2, 3, 4, 0, 0));
+        tracer.observe(&receipts, 85);
+        receipts.push(Receipt::ret(b, 0, 0, 0));
+        tracer.observe(&receipts, 70);
+        receipts.push(Receipt::ret(a, 0, 0, 0));
+        tracer.observe(&receipts, 60);
+        receipts.push(Receipt::ret(ContractId::zeroed(), 0, 0, 0));
+        tracer.observe(&receipts, 55);
+        receipts.push(script_result.clone());
+        let trace = tracer.finish(&receipts, 55);
+
+        // Then
+        assert_eq!(trace.gas_used, 90);
+        assert_eq!(trace.revert, None);
+        assert_eq!(
+            trace.receipts,
+            vec![receipts[0].clone(), receipts[5].clone(), script_result]
+        );
+        assert_eq!(trace.frames.len(), 2);
+        let frame_a = &trace.frames[0];
+        assert_eq!(frame_a.parent, None);
+        assert_eq!(frame_a.contract_id, a);
+        assert_eq!(frame_a.gas_forwarded, 80);
+        assert_eq!(frame_a.gas_used, 35);
+        assert_eq!(
+            frame_a.receipts,
+            vec![receipts[1].clone(), receipts[4].clone()]
+        );
+        let frame_b = &trace.frames[1];
+        assert_eq!(frame_b.parent, Some(0));
+        assert_eq!(frame_b.contract_id, b);
+        assert_eq!(frame_b.function_selector, 1);
+        assert_eq!(frame_b.gas_used, 20);
+        assert_eq!(
+            frame_b.receipts,
+            vec![receipts[2].clone(), receipts[3].clone()]
+        );
+    }
+
+    #[test]
+    fn call_tracer__attributes_panic_to_the_innermost_frame() {
+        // Given
+        let a = ContractId::from([1; 32]);
+        let panic =
+            Receipt::panic(a, PanicInstruction::error(PanicReason::OutOfGas, 0), 12, 0);
+        let mut receipts = vec![call(ContractId::zeroed(), a, 80)];
+        let mut tracer = CallTracer::default();
+        tracer.observe(&receipts, 95);
+
+        // When
+        receipts.push(panic.clone());
+        receipts.push(Receipt::script_result(ScriptExecutionResult::Panic, 100));
+        let trace = tracer.finish(&receipts, 0);
+
+        // Then
+        assert_eq!(trace.revert, None);
+        assert_eq!(trace.frames[0].revert, Some(panic.clone()));
+        assert_eq!(trace.frames[0].receipts, vec![panic]);
+        assert_eq!(trace.frames[0].gas_used, 95);
+        assert_eq!(trace.gas_used, 100);
+    }
+}
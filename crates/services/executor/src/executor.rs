@@ -1,4 +1,5 @@
 use crate::{
+    call_trace::transact_with_call_trace,
     contract_state_hash::{
         compute_balances_hash,
         compute_state_hash,
@@ -145,6 +146,7 @@ use fuel_core_types::{
             Result as ExecutorResult,
             TransactionExecutionResult,
             TransactionExecutionStatus,
+            TransactionTrace,
             TransactionValidityError,
             UncommittedResult,
             UncommittedValidationResult,
@@ -158,6 +160,9 @@ use fuel_core_types::{
     },
 };
 use parking_lot::Mutex as ParkingMutex;
+
+#[cfg(feature = "fault-proving")]
+use fuel_core_types::fuel_types::ChainId;
 use tracing::{
     debug,
     warn,
@@ -332,6 +337,7 @@ pub struct ExecutionData {
     changes: Changes,
     pub skipped_transactions: Vec<(TxId, ExecutorError)>,
     event_inbox_root: Bytes32,
+    call_trace: Option<TransactionTrace>,
 }
 
 impl ExecutionData {
@@ -348,7 +354,82 @@ impl ExecutionData {
             changes: Default::default(),
             skipped_transactions: Vec::new(),
             event_inbox_root: Default::default(),
+            call_trace: None,
+        }
+    }
+
+    /// Creates the execution data for a batch of transactions that will be
+    /// placed in the block starting at the `tx_offset` position.
+    pub fn with_tx_offset(tx_offset: u16) -> Self {
+        ExecutionData {
+            tx_count: tx_offset,
+            ..Self::new()
+        }
+    }
+
+    /// The number of transactions in the block, including the offset.
+    pub fn tx_count(&self) -> u16 {
+        self.tx_count
+    }
+
+    /// The gas used by the executed transactions.
+    pub fn used_gas(&self) -> u64 {
+        self.used_gas
+    }
+
+    /// The size used by the executed transactions.
+    pub fn used_size(&self) -> u32 {
+        self.used_size
+    }
+
+    /// Appends the execution data of the batch executed right after `self`.
+    /// The batch must be created with [`ExecutionData::with_tx_offset`] using
+    /// the current `tx_count` of `self`.
+    pub fn append(&mut self, batch: ExecutionData) -> ExecutorResult<()> {
+        let ExecutionData {
+            coinbase,
+            used_gas,
+            used_size,
+            tx_count,
+            found_mint,
+            message_ids,
+            tx_status,
+            events,
+            skipped_transactions,
+            call_trace,
+            ..
+        } = batch;
+
+        if tx_count < self.tx_count {
+            return Err(ExecutorError::Other(format!(
+                "The batch ends at {tx_count}, but the block already has {} transactions",
+                self.tx_count
+            )));
         }
+
+        self.coinbase = self
+            .coinbase
+            .checked_add(coinbase)
+            .ok_or(ExecutorError::FeeOverflow)?;
+        self.used_gas = self.used_gas.checked_add(used_gas).ok_or_else(|| {
+            ExecutorError::GasOverflow(
+                "Execution used gas overflowed.".into(),
+                self.used_gas,
+                used_gas,
+            )
+        })?;
+        self.used_size = self
+            .used_size
+            .checked_add(used_size)
+            .ok_or(ExecutorError::TxSizeOverflow)?;
+        self.tx_count = tx_count;
+        self.found_mint |= found_mint;
+        self.message_ids.extend(message_ids);
+        self.tx_status.extend(tx_status);
+        self.events.extend(events);
+        self.skipped_transactions.extend(skipped_transactions);
+        self.call_trace = self.call_trace.take().or(call_trace);
+        Ok(())
     }
 }
 
@@ -371,6 +452,8 @@ struct ExecutionOptionsInner {
     pub forbid_fake_coins: bool,
     pub allow_syscall: bool,
     pub dry_run: bool,
+    /// The transaction to build the call tree for.
+    pub trace_transaction: Option<TxId>,
 }
 
 /// The executor instance performs block production and validation. Given a block, it will execute all
@@ -425,43 +508,12 @@ where
             .execute(components, storage_tx, memory.as_mut())
             .await?;
 
-        let ExecutionData {
-            message_ids,
-            event_inbox_root,
-            changes,
-            events,
-            tx_status,
-            skipped_transactions,
-            coinbase,
-            used_gas,
-            used_size,
-            ..
-        } = execution_data;
-
-        let block = partial_block
-            .generate(
-                &message_ids[..],
-                event_inbox_root,
-                #[cfg(feature = "fault-proving")]
-                &chain_id,
-            )
-            .map_err(ExecutorError::BlockHeaderError)?;
-
-        let finalized_block_id = block.id();
-
-        debug!(
-            "Block {:#x} fees: {} gas: {} tx_size: {}",
-            finalized_block_id, coinbase, used_gas, used_size
-        );
-
-        let result = ExecutionResult {
-            block,
-            skipped_transactions,
-            tx_status,
-            events,
-        };
-
-        Ok(UncommittedResult::new(result, changes))
+        generate_execution_result(
+            partial_block,
+            execution_data,
+            #[cfg(feature = "fault-proving")]
+            &chain_id,
+        )
     }
 
     pub fn validate_without_commit(
@@ -498,6 +550,32 @@ where
         Ok(UncommittedValidationResult::new(result, changes))
     }
 
+    /// Validates the `block` and builds the call tree of the transaction with `tx_id`.
+    pub fn trace_without_commit(
+        self,
+        block: &Block,
+        tx_id: &TxId,
+    ) -> ExecutorResult<TransactionTrace> {
+        let consensus_params_version = block.header().consensus_parameters_version();
+        let (mut block_executor, storage_tx, mut memory) = self.into_executor(
+            consensus_params_version,
+            TimeoutOnlyTxWaiter,
+            TransparentPreconfirmationSender,
+            false,
+        )?;
+        block_executor.options.trace_transaction = Some(*tx_id);
+
+        let ExecutionData { call_trace, .. } =
+            block_executor.validate_block(block, storage_tx, memory.as_mut())?;
+
+        call_trace.ok_or_else(|| {
+            ExecutorError::Other(format!(
+                "Transaction {tx_id:#x} is not executed by the VM in the block {}",
+                block.header().height()
+            ))
+        })
+    }
+
     #[allow(clippy::type_complexity)]
     fn into_executor<N, P>(
         self,
@@ -529,6 +607,51 @@ where
     }
 }
 
+/// Generates the final block from the `partial_block` and the `execution_data`.
+fn generate_execution_result(
+    partial_block: PartialFuelBlock,
+    execution_data: ExecutionData,
+    #[cfg(feature = "fault-proving")] chain_id: &ChainId,
+) -> ExecutorResult<UncommittedResult<Changes>> {
+    let ExecutionData {
+        message_ids,
+        event_inbox_root,
+        changes,
+        events,
+        tx_status,
+        skipped_transactions,
+        coinbase,
+        used_gas,
+        used_size,
+        ..
+    } = execution_data;
+
+    let block = partial_block
+        .generate(
+            &message_ids[..],
+            event_inbox_root,
+            #[cfg(feature = "fault-proving")]
+            chain_id,
+        )
+        .map_err(ExecutorError::BlockHeaderError)?;
+
+    let finalized_block_id = block.id();
+
+    debug!(
+        "Block {:#x} fees: {} gas: {} tx_size: {}",
+        finalized_block_id, coinbase, used_gas, used_size
+    );
+
+    let result = ExecutionResult {
+        block,
+        skipped_transactions,
+        tx_status,
+        events,
+    };
+
+    Ok(UncommittedResult::new(result, changes))
+}
+
 type BlockStorageTransaction<T> = StorageTransaction<T>;
 type TxStorageTransaction<'a, T> = StorageTransaction<&'a mut BlockStorageTransaction<T>>;
 
@@ -559,6 +682,7 @@ impl<R, TxWaiter, PreconfirmationSender>
                 forbid_fake_coins: options.forbid_fake_coins,
                 allow_syscall: options.allow_syscall,
                 dry_run,
+                trace_transaction: None,
             },
             new_tx_waiter,
             preconfirmation_sender,
@@ -572,6 +696,113 @@ where
     N: NewTxWaiterPort,
     P: PreconfirmationSenderPort,
 {
+    /// Executes the relayed transactions from the DA layer that should be
+    /// included at the beginning of the `block`.
+    ///
+    /// It is the first step of the block production split into batches.
+    /// The next steps are [`Self::execute_l2_transactions`] and
+    /// [`Self::finalize_produced_block`].
+    pub fn execute_l1_transactions<D>(
+        &mut self,
+        block: &mut PartialFuelBlock,
+        coinbase_contract_id: ContractId,
+        storage_tx: &mut StorageTransaction<D>,
+        data: &mut ExecutionData,
+        memory: &mut MemoryInstance,
+    ) -> ExecutorResult<()>
+    where
+        D: KeyValueInspect<Column = Column>,
+    {
+        self.process_l1_txs(block, coinbase_contract_id, storage_tx, data, memory)
+    }
+
+    /// Executes the batch of L2 `transactions` one by one on top of the `storage_tx`.
+    ///
+    /// The executed transactions are appended to the `block`, while transactions that
+    /// failed the execution are recorded in the `data` as skipped. The position of
+    /// the first transaction in the block is defined by the `tx_count` of the `data`,
+    /// so batches can be executed independently and appended with [`ExecutionData::append`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn execute_l2_transactions<D>(
+        &self,
+        block: &mut PartialFuelBlock,
+        transactions: Vec<MaybeCheckedTransaction>,
+        coinbase_contract_id: ContractId,
+        gas_price: Word,
+        storage_tx: &mut StorageTransaction<D>,
+        data: &mut ExecutionData,
+        memory: &mut MemoryInstance,
+    ) -> ExecutorResult<()>
+    where
+        D: KeyValueInspect<Column = Column>,
+    {
+        let block_gas_limit = self.consensus_params.block_gas_limit();
+
+        for transaction in transactions {
+            let tx_id = transaction.id(&self.consensus_params.chain_id());
+            let tx_max_gas = transaction.max_gas(&self.consensus_params)?;
+            let remaining_gas_limit = block_gas_limit.saturating_sub(data.used_gas);
+            if tx_max_gas > remaining_gas_limit {
+                data.skipped_transactions.push((
+                    tx_id,
+                    ExecutorError::GasOverflow(
+                        format!("Transaction cannot fit in remaining gas limit: ({remaining_gas_limit})."),
+                        tx_max_gas,
+                        remaining_gas_limit,
+                    ),
+                ));
+                continue;
+            }
+
+            if let Err(err) = self.execute_transaction_and_commit(
+                block,
+                storage_tx,
+                data,
+                transaction,
+                gas_price,
+                coinbase_contract_id,
+                memory,
+            ) {
+                data.skipped_transactions.push((tx_id, err));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Executes the mint transaction on top of the `storage_tx` and generates
+    /// the final block with all changes made to the storage.
+    pub fn finalize_produced_block<D>(
+        &self,
+        mut block: PartialFuelBlock,
+        coinbase_contract_id: ContractId,
+        gas_price: Word,
+        mut storage_tx: StorageTransaction<D>,
+        mut data: ExecutionData,
+        memory: &mut MemoryInstance,
+    ) -> ExecutorResult<UncommittedResult<Changes>>
+    where
+        D: KeyValueInspect<Column = Column>,
+    {
+        self.produce_mint_tx(
+            &mut block,
+            coinbase_contract_id,
+            gas_price,
+            &mut storage_tx,
+            &mut data,
+            memory,
+        )?;
+        debug_assert!(data.found_mint, "Mint transaction is not found");
+
+        data.changes = storage_tx.into_changes();
+        generate_execution_result(
+            block,
+            data,
+            #[cfg(feature = "fault-proving")]
+            &self.consensus_params.chain_id(),
+        )
+    }
+
     async fn execute<TxSource, D>(
         self,
         components: Components<TxSource>,
@@ -634,7 +865,8 @@ where
 
         self.produce_mint_tx(
             &mut partial_block,
-            &components,
+            components.coinbase_recipient,
+            components.gas_price,
             &mut block_storage_tx,
             &mut data,
             memory,
@@ -645,10 +877,11 @@ where
         Ok((partial_block, data))
     }
 
-    fn produce_mint_tx<TxSource, T>(
+    fn produce_mint_tx<T>(
         &self,
         block: &mut PartialFuelBlock,
-        components: &Components<TxSource>,
+        coinbase_contract_id: ContractId,
+        gas_price: Word,
         storage_tx: &mut BlockStorageTransaction<T>,
         data: &mut ExecutionData,
         memory: &mut MemoryInstance,
@@ -656,12 +889,6 @@ where
     where
         T: KeyValueInspect<Column = Column>,
     {
-        let Components {
-            coinbase_recipient: coinbase_contract_id,
-            gas_price,
-            ..
-        } = *components;
-
         let amount_to_mint = if coinbase_contract_id != ContractId::zeroed() {
             data.coinbase
         } else {
@@ -1489,14 +1716,19 @@ where
             checked_tx = self.extra_tx_checks(checked_tx, header, storage_tx, memory)?;
         }
 
-        let (reverted, state, tx, receipts) = self.attempt_tx_execution_with_vm(
-            checked_tx,
-            header,
-            coinbase_contract_id,
-            gas_price,
-            storage_tx,
-            memory,
-        )?;
+        let (reverted, state, tx, receipts, call_trace) = self
+            .attempt_tx_execution_with_vm(
+                checked_tx,
+                header,
+                coinbase_contract_id,
+                gas_price,
+                storage_tx,
+                memory,
+            )?;
+
+        if call_trace.is_some() {
+            execution_data.call_trace = call_trace;
+        }
 
         self.spend_input_utxos(tx.inputs(), storage_tx, reverted, execution_data)?;
 
@@ -1858,7 +2090,13 @@ where
         gas_price: Word,
         storage_tx: &mut TxStorageTransaction<T>,
         memory: &mut MemoryInstance,
-    ) -> ExecutorResult<(bool, ProgramState, Tx, Arc<Vec<Receipt>>)>
+    ) -> ExecutorResult<(
+        bool,
+        ProgramState,
+        Tx,
+        Arc<Vec<Receipt>>,
+        Option<TransactionTrace>,
+    )>
     where
         Tx: ExecutableTransaction + Cacheable,
         <Tx as IntoChecked>::Metadata: CheckedMetadataTrait + Send + Sync,
@@ -1902,6 +2140,8 @@ where
             ..Default::default()
         };
 
+        let mut call_trace = None;
+
         let (state, mut tx, receipts) = if !self.options.dry_run {
             let vm = Interpreter::<_, _, _, EcalLogCollector, verification::Normal>::with_storage_and_ecal(
                 memory,
@@ -1910,12 +2150,18 @@ where
                 ecal.clone(),
             );
 
+            let vm_result = if self.options.trace_transaction == Some(tx_id) {
+                transact_with_call_trace(vm, ready_tx).map(|(vm_result, trace)| {
+                    call_trace = Some(trace);
+                    vm_result
+                })
+            } else {
+                vm.into_transact(ready_tx)
+            };
             let vm_result: StateTransition<_, _> =
-                vm.into_transact(ready_tx).map_err(|error| {
-                    ExecutorError::VmExecution {
-                        error: error.to_string(),
-                        transaction_id: tx_id,
-                    }
+                vm_result.map_err(|error| ExecutorError::VmExecution {
+                    error: error.to_string(),
+                    transaction_id: tx_id,
                 })?;
 
             reverted = vm_result.should_revert();
@@ -2004,7 +2250,7 @@ where
 
         self.update_tx_outputs(tx_id, &mut tx, &state_after)?;
 
-        Ok((reverted, state, tx, receipts))
+        Ok((reverted, state, tx, receipts, call_trace))
     }
 
     fn verify_inputs_exist_and_values_match<T>(
@@ -10,9 +10,10 @@ extern crate alloc;
 pub mod executor;
 pub mod ports;
 pub mod refs;
+pub mod storage_access_recorder;
 
+mod call_trace;
 mod contract_state_hash;
-mod storage_access_recorder;
 
 #[cfg(test)]
 fuel_core_trace::enable_tracing!();
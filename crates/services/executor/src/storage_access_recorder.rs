@@ -55,13 +55,19 @@ pub(crate) struct ContractAccessesWithValues {
     pub slots: BTreeMap<Bytes32, Option<Vec<u8>>>,
 }
 
+/// The record of the keys accessed through the [`StorageAccessRecorder`].
+pub trait AccessRecord: Default {
+    /// Mark some key as accessed
+    fn mark(&mut self, key: &[u8], column_id: u32);
+}
+
+/// The contract assets and slots accessed through the [`StorageAccessRecorder`].
 #[derive(Debug, Clone, Default)]
-pub(crate) struct ReadsPerContract {
-    pub per_contract: BTreeMap<ContractId, ContractAccesses>,
+pub struct ReadsPerContract {
+    pub(crate) per_contract: BTreeMap<ContractId, ContractAccesses>,
 }
 
-impl ReadsPerContract {
-    /// Mark some key as accessed
+impl AccessRecord for ReadsPerContract {
     fn mark(&mut self, key: &[u8], column_id: u32) {
         if column_id == Column::ContractsAssets.as_u32() {
             let key = ContractsAssetKey::from_slice(key).unwrap();
@@ -79,7 +85,9 @@ impl ReadsPerContract {
                 .insert(*key.state_key());
         }
     }
+}
 
+impl ReadsPerContract {
     /// Returns slot values before and after applying the changes
     pub(crate) fn finalize<S>(
         self,
@@ -162,12 +170,13 @@ impl ReadsPerContract {
     }
 }
 
-pub struct StorageAccessRecorder<S>
+/// Records the keys read from the underlying storage into the `R`.
+pub struct StorageAccessRecorder<S, R = ReadsPerContract>
 where
     S: KeyValueInspect,
 {
     pub storage: S,
-    pub record: RefCell<ReadsPerContract>,
+    pub record: RefCell<R>,
 }
 
 impl<S> StorageAccessRecorder<S>
@@ -175,13 +184,24 @@ where
     S: KeyValueInspect,
 {
     pub fn new(storage: S) -> Self {
+        Self::with_record(storage, Default::default())
+    }
+}
+
+impl<S, R> StorageAccessRecorder<S, R>
+where
+    S: KeyValueInspect,
+    R: AccessRecord,
+{
+    /// Creates the recorder that marks the accessed keys in the `record`.
+    pub fn with_record(storage: S, record: R) -> Self {
         Self {
             storage,
-            record: Default::default(),
+            record: RefCell::new(record),
         }
     }
 
-    pub fn into_inner(self) -> (S, ReadsPerContract) {
+    pub fn into_inner(self) -> (S, R) {
         (self.storage, self.record.take())
     }
 
@@ -191,9 +211,10 @@ where
     }
 }
 
-impl<S> KeyValueInspect for StorageAccessRecorder<S>
+impl<S, R> KeyValueInspect for StorageAccessRecorder<S, R>
 where
     S: KeyValueInspect,
+    R: AccessRecord,
 {
     type Column = S::Column;
 
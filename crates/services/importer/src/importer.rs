@@ -761,7 +761,7 @@ fn create_block_changes<D: ImporterDatabase + Transactional>(
             }
             actual_next_height
         }
-        Consensus::PoA(_) => {
+        Consensus::PoA(_) | Consensus::Bft(_) => {
             if actual_next_height == BlockHeight::from(0u32) {
                 return Err(Error::ZeroNonGenericHeight)
             }
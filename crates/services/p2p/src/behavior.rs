@@ -249,7 +249,6 @@ impl FuelBehaviour {
         self.heartbeat.update_block_height(block_height);
     }
 
-    #[cfg(test)]
     pub fn get_peer_score(&self, peer_id: &PeerId) -> Option<f64> {
         self.gossipsub.peer_score(peer_id)
     }
@@ -257,4 +256,33 @@ impl FuelBehaviour {
     pub fn block_peer(&mut self, peer_id: PeerId) {
         self.blocked_peer.block_peer(peer_id)
     }
+
+    pub fn unblock_peer(&mut self, peer_id: PeerId) {
+        self.blocked_peer.unblock_peer(peer_id)
+    }
+
+    pub fn add_reserved_peer(
+        &mut self,
+        peer_id: PeerId,
+        multiaddr: Multiaddr,
+        connected: bool,
+    ) {
+        self.connection_limits
+            .connections()
+            .lock()
+            .reserved_peers
+            .insert(peer_id);
+        self.discovery.add_address(&peer_id, multiaddr.clone());
+        self.peer_report
+            .add_reserved_node(peer_id, multiaddr, connected);
+    }
+
+    pub fn remove_reserved_peer(&mut self, peer_id: &PeerId) -> bool {
+        self.connection_limits
+            .connections()
+            .lock()
+            .reserved_peers
+            .remove(peer_id);
+        self.peer_report.remove_reserved_node(peer_id)
+    }
 }
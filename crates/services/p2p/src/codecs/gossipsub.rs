@@ -12,7 +12,13 @@ use crate::{
     },
     ports::P2PPreConfirmationMessage,
 };
-use fuel_core_types::fuel_tx::Transaction;
+use fuel_core_types::{
+    blockchain::consensus::bft::BftMessage,
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
+};
 use std::{
     io,
     ops::Deref,
@@ -27,8 +33,12 @@ impl<Codec> GossipsubCodec for GossipsubMessageHandler<Codec>
 where
     Codec: Encode<Transaction, Error = io::Error>
         + Decode<Transaction, Error = io::Error>
+        + Encode<TxId, Error = io::Error>
+        + Decode<TxId, Error = io::Error>
         + Encode<P2PPreConfirmationMessage, Error = io::Error>
-        + Decode<P2PPreConfirmationMessage, Error = io::Error>,
+        + Decode<P2PPreConfirmationMessage, Error = io::Error>
+        + Encode<BftMessage, Error = io::Error>
+        + Decode<BftMessage, Error = io::Error>,
 {
     type RequestMessage = GossipsubBroadcastRequest;
     type ResponseMessage = GossipsubMessage;
@@ -38,9 +48,15 @@ where
             GossipsubBroadcastRequest::NewTx(tx) => {
                 Ok(self.codec.encode(tx.deref())?.into_bytes())
             }
+            GossipsubBroadcastRequest::NewTxAnnouncement(tx_id) => {
+                Ok(self.codec.encode(&tx_id)?.into_bytes())
+            }
             GossipsubBroadcastRequest::TxPreConfirmations(msg) => {
                 Ok(self.codec.encode(msg.deref())?.into_bytes())
             }
+            GossipsubBroadcastRequest::BftConsensus(msg) => {
+                Ok(self.codec.encode(msg.deref())?.into_bytes())
+            }
         }
     }
 
@@ -53,9 +69,15 @@ where
             GossipTopicTag::NewTx => {
                 GossipsubMessage::NewTx(self.codec.decode(encoded_data)?)
             }
+            GossipTopicTag::NewTxAnnouncement => {
+                GossipsubMessage::NewTxAnnouncement(self.codec.decode(encoded_data)?)
+            }
             GossipTopicTag::TxPreconfirmations => {
                 GossipsubMessage::TxPreConfirmations(self.codec.decode(encoded_data)?)
             }
+            GossipTopicTag::BftConsensus => {
+                GossipsubMessage::BftConsensus(self.codec.decode(encoded_data)?)
+            }
         };
 
         Ok(decoded_response)
@@ -53,6 +53,20 @@ pub const MAX_HEADERS_PER_REQUEST: usize = 100;
 /// Maximum number of transactions ids asked per request.
 pub const MAX_TXS_PER_REQUEST: usize = 10000;
 
+/// Maximum number of announced transactions fetched from peers at the same time.
+pub const MAX_PENDING_TX_FETCHES: usize = 256;
+
+/// Defines how the node gossips transactions to other peers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TxGossipMode {
+    /// The whole transaction is gossiped.
+    #[default]
+    Full,
+    /// Only the id of the transaction is gossiped. Peers that don't know
+    /// the transaction fetch it with the request-response protocol.
+    Announce,
+}
+
 #[derive(Clone, Debug)]
 pub struct Config<State = Initialized> {
     /// The keypair used for handshake during communication with other p2p nodes.
@@ -155,8 +169,20 @@ pub struct Config<State = Initialized> {
     /// If true, the node will subscribe to transactions topic
     pub subscribe_to_transactions: bool,
 
+    /// If true, the node will subscribe to BFT consensus topic
+    pub subscribe_to_bft_consensus: bool,
+
     /// The cache size for the p2p req/res protocol
     pub cache_size: Option<NonZeroUsize>,
+
+    /// The way the node gossips its transactions.
+    pub tx_gossip_mode: TxGossipMode,
+
+    /// The maximum number of announced transactions fetched from peers at the same time.
+    /// Announcements of new transactions received above this limit are ignored.
+    /// Announcements of transactions being fetched are used to retry the fetching
+    /// with other peers.
+    pub max_pending_tx_fetches: usize,
 }
 
 /// The initialized state can be achieved only by the `init` function because `()` is private.
@@ -208,7 +234,10 @@ impl Config<NotInitialized> {
             state: Initialized(()),
             subscribe_to_pre_confirmations: self.subscribe_to_pre_confirmations,
             subscribe_to_transactions: self.subscribe_to_transactions,
+            subscribe_to_bft_consensus: self.subscribe_to_bft_consensus,
             cache_size: self.cache_size,
+            tx_gossip_mode: self.tx_gossip_mode,
+            max_pending_tx_fetches: self.max_pending_tx_fetches,
         })
     }
 }
@@ -265,7 +294,10 @@ impl Config<NotInitialized> {
             state: NotInitialized,
             subscribe_to_pre_confirmations: true,
             subscribe_to_transactions: true,
+            subscribe_to_bft_consensus: false,
             cache_size: None,
+            tx_gossip_mode: TxGossipMode::Full,
+            max_pending_tx_fetches: MAX_PENDING_TX_FETCHES,
         }
     }
 }
@@ -1,4 +1,6 @@
 use super::topics::{
+    BFT_CONSENSUS_GOSSIP_TOPIC,
+    NEW_TX_ANNOUNCEMENT_GOSSIP_TOPIC,
     NEW_TX_GOSSIP_TOPIC,
     TX_PRECONFIRMATIONS_GOSSIP_TOPIC,
 };
@@ -52,8 +54,14 @@ const MESH_SIZE: usize = 8;
 // The weight applied to the score for delivering new transactions.
 const NEW_TX_GOSSIP_WEIGHT: f64 = 0.05;
 
+// The weight applied to the score for delivering new transaction announcements.
+const NEW_TX_ANNOUNCEMENT_GOSSIP_WEIGHT: f64 = 0.05;
+
 const TX_PRECONFIRMATIONS_GOSSIP_WEIGHT: f64 = 0.05;
 
+// The weight applied to the score for delivering consensus messages of the validators.
+const BFT_CONSENSUS_GOSSIP_WEIGHT: f64 = 0.05;
+
 // The threshold for a peer's score to be considered for greylisting.
 // If a peer's score falls below this value, they will be greylisted.
 // Greylisting is a lighter form of banning, where the peer's messages might be ignored or given lower priority,
@@ -230,9 +238,15 @@ fn initialize_gossipsub(gossipsub: &mut gossipsub::Behaviour, p2p_config: &Confi
     // Create the list of topics to subscribe to based on configuration
     let mut topics = Vec::new();
 
-    // Only subscribe to transactions topic if configured to do so
+    // Only subscribe to transactions topics if configured to do so.
+    // Both topics are used independently of the `tx_gossip_mode`,
+    // so the node can receive transactions from peers in any mode.
     if p2p_config.subscribe_to_transactions {
         topics.push((NEW_TX_GOSSIP_TOPIC, NEW_TX_GOSSIP_WEIGHT));
+        topics.push((
+            NEW_TX_ANNOUNCEMENT_GOSSIP_TOPIC,
+            NEW_TX_ANNOUNCEMENT_GOSSIP_WEIGHT,
+        ));
     }
 
     // Only subscribe to pre-confirmations topic if configured to do so
@@ -243,6 +257,11 @@ fn initialize_gossipsub(gossipsub: &mut gossipsub::Behaviour, p2p_config: &Confi
         ));
     }
 
+    // Only subscribe to BFT consensus topic if configured to do so
+    if p2p_config.subscribe_to_bft_consensus {
+        topics.push((BFT_CONSENSUS_GOSSIP_TOPIC, BFT_CONSENSUS_GOSSIP_WEIGHT));
+    }
+
     // subscribe to gossipsub topics with the network name suffix
     for (topic, weight) in topics {
         let t: Sha256Topic = Topic::new(format!("{}/{}", topic, p2p_config.network_name));
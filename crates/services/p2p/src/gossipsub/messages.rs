@@ -1,5 +1,11 @@
 use crate::ports::P2PPreConfirmationMessage;
-use fuel_core_types::fuel_tx::Transaction;
+use fuel_core_types::{
+    blockchain::consensus::bft::BftMessage,
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
+};
 use serde::{
     Deserialize,
     Serialize,
@@ -11,7 +17,9 @@ use std::sync::Arc;
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum GossipTopicTag {
     NewTx,
+    NewTxAnnouncement,
     TxPreconfirmations,
+    BftConsensus,
 }
 
 /// Takes `Arc<T>` and wraps it in a matching GossipsubBroadcastRequest
@@ -20,12 +28,16 @@ pub enum GossipTopicTag {
 #[derive(Debug, Clone)]
 pub enum GossipsubBroadcastRequest {
     NewTx(Arc<Transaction>),
+    NewTxAnnouncement(TxId),
     TxPreConfirmations(Arc<P2PPreConfirmationMessage>),
+    BftConsensus(Arc<BftMessage>),
 }
 
 #[allow(clippy::large_enum_variant)]
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum GossipsubMessage {
     NewTx(Transaction),
+    NewTxAnnouncement(TxId),
     TxPreConfirmations(P2PPreConfirmationMessage),
+    BftConsensus(BftMessage),
 }
@@ -10,7 +10,9 @@ use super::messages::{
 };
 
 pub const NEW_TX_GOSSIP_TOPIC: &str = "new_tx";
+pub const NEW_TX_ANNOUNCEMENT_GOSSIP_TOPIC: &str = "new_tx_announcement";
 pub const TX_PRECONFIRMATIONS_GOSSIP_TOPIC: &str = "tx_preconfirmations";
+pub const BFT_CONSENSUS_GOSSIP_TOPIC: &str = "bft_consensus";
 
 /// Holds used Gossipsub Topics
 /// Each field contains TopicHash of existing topics
@@ -18,18 +20,26 @@ pub const TX_PRECONFIRMATIONS_GOSSIP_TOPIC: &str = "tx_preconfirmations";
 #[derive(Debug)]
 pub struct GossipsubTopics {
     new_tx_topic: TopicHash,
+    new_tx_announcement_topic: TopicHash,
     tx_preconfirmations_topic: TopicHash,
+    bft_consensus_topic: TopicHash,
 }
 
 impl GossipsubTopics {
     pub fn new(network_name: &str) -> Self {
         let new_tx_topic: Sha256Topic =
             Topic::new(format!("{NEW_TX_GOSSIP_TOPIC}/{network_name}"));
+        let new_tx_announcement_topic: Sha256Topic =
+            Topic::new(format!("{NEW_TX_ANNOUNCEMENT_GOSSIP_TOPIC}/{network_name}"));
         let tx_preconfirmations_topic: Sha256Topic =
             Topic::new(format!("{TX_PRECONFIRMATIONS_GOSSIP_TOPIC}/{network_name}"));
+        let bft_consensus_topic: Sha256Topic =
+            Topic::new(format!("{BFT_CONSENSUS_GOSSIP_TOPIC}/{network_name}"));
         Self {
             new_tx_topic: new_tx_topic.hash(),
+            new_tx_announcement_topic: new_tx_announcement_topic.hash(),
             tx_preconfirmations_topic: tx_preconfirmations_topic.hash(),
+            bft_consensus_topic: bft_consensus_topic.hash(),
         }
     }
 
@@ -40,9 +50,15 @@ impl GossipsubTopics {
     ) -> Option<GossipTopicTag> {
         match incoming_topic {
             hash if hash == &self.new_tx_topic => Some(GossipTopicTag::NewTx),
+            hash if hash == &self.new_tx_announcement_topic => {
+                Some(GossipTopicTag::NewTxAnnouncement)
+            }
             hash if hash == &self.tx_preconfirmations_topic => {
                 Some(GossipTopicTag::TxPreconfirmations)
             }
+            hash if hash == &self.bft_consensus_topic => {
+                Some(GossipTopicTag::BftConsensus)
+            }
             _ => None,
         }
     }
@@ -55,9 +71,15 @@ impl GossipsubTopics {
     ) -> TopicHash {
         match outgoing_request {
             GossipsubBroadcastRequest::NewTx(_) => self.new_tx_topic.clone(),
+            GossipsubBroadcastRequest::NewTxAnnouncement(_) => {
+                self.new_tx_announcement_topic.clone()
+            }
             GossipsubBroadcastRequest::TxPreConfirmations(_) => {
                 self.tx_preconfirmations_topic.clone()
             }
+            GossipsubBroadcastRequest::BftConsensus(_) => {
+                self.bft_consensus_topic.clone()
+            }
         }
     }
 }
@@ -65,7 +87,14 @@ impl GossipsubTopics {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use fuel_core_types::fuel_tx::Transaction;
+    use fuel_core_types::{
+        blockchain::consensus::bft::{
+            BftMessage,
+            Signed,
+            Vote,
+        },
+        fuel_tx::Transaction,
+    };
     use libp2p::gossipsub::Topic;
     use std::sync::Arc;
 
@@ -94,4 +123,46 @@ mod tests {
             new_tx_topic.hash()
         );
     }
+
+    #[test]
+    fn test_gossipsub_topics_new_tx_announcement() {
+        let network_name = "fuel_test_network";
+        let new_tx_announcement_topic: Sha256Topic =
+            Topic::new(format!("{NEW_TX_ANNOUNCEMENT_GOSSIP_TOPIC}/{network_name}"));
+
+        let gossipsub_topics = GossipsubTopics::new(network_name);
+
+        assert_eq!(
+            gossipsub_topics.get_gossipsub_tag(&new_tx_announcement_topic.hash()),
+            Some(GossipTopicTag::NewTxAnnouncement)
+        );
+        let broadcast_req = GossipsubBroadcastRequest::NewTxAnnouncement([1; 32].into());
+        assert_eq!(
+            gossipsub_topics.get_gossipsub_topic_hash(&broadcast_req),
+            new_tx_announcement_topic.hash()
+        );
+    }
+
+    #[test]
+    fn test_gossipsub_topics_bft_consensus() {
+        let network_name = "fuel_test_network";
+        let bft_consensus_topic: Sha256Topic =
+            Topic::new(format!("{BFT_CONSENSUS_GOSSIP_TOPIC}/{network_name}"));
+
+        let gossipsub_topics = GossipsubTopics::new(network_name);
+
+        assert_eq!(
+            gossipsub_topics.get_gossipsub_tag(&bft_consensus_topic.hash()),
+            Some(GossipTopicTag::BftConsensus)
+        );
+        let broadcast_req =
+            GossipsubBroadcastRequest::BftConsensus(Arc::new(BftMessage::Vote(Signed {
+                entity: Vote::prevote(1u32.into(), 0, None),
+                signature: Default::default(),
+            })));
+        assert_eq!(
+            gossipsub_topics.get_gossipsub_topic_hash(&broadcast_req),
+            bft_consensus_topic.hash()
+        );
+    }
 }
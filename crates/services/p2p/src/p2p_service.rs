@@ -26,6 +26,7 @@ use crate::{
     heartbeat,
     peer_manager::{
         ConnectionState,
+        PeerInfo,
         PeerManager,
         Punisher,
     },
@@ -81,7 +82,10 @@ use std::{
     collections::HashMap,
     time::Duration,
 };
-use tokio::sync::broadcast;
+use tokio::{
+    sync::broadcast,
+    time::Instant,
+};
 use tracing::{
     debug,
     warn,
@@ -142,6 +146,18 @@ pub struct FuelP2PService {
 
     /// Holds peers' information, and manages existing connections
     peer_manager: PeerManager,
+
+    /// Peers banned by the node operator along with the expiration of the ban
+    banned_peers: HashMap<PeerId, Instant>,
+}
+
+/// The reputation of the connected peer
+#[derive(Debug, Clone)]
+pub struct PeerReputation {
+    pub peer_id: PeerId,
+    pub reserved: bool,
+    pub peer_info: PeerInfo,
+    pub gossipsub_score: Option<f64>,
 }
 
 #[derive(Debug)]
@@ -304,6 +320,7 @@ impl FuelP2PService {
                 connection_state_writer,
                 usize::try_from(config.max_discovery_peers_connected)?,
             ),
+            banned_peers: HashMap::default(),
         })
     }
 
@@ -486,7 +503,6 @@ impl FuelP2PService {
         }
     }
 
-    #[cfg(test)]
     pub fn get_peer_score(&self, peer_id: &PeerId) -> Option<f64> {
         self.swarm.behaviour().get_peer_score(peer_id)
     }
@@ -508,6 +524,90 @@ impl FuelP2PService {
         );
     }
 
+    /// Adds the reserved peer at runtime. The peer is dialed if it is not connected.
+    pub fn add_reserved_peer(&mut self, multiaddr: Multiaddr) -> anyhow::Result<PeerId> {
+        let peer_id = multiaddr.try_to_peer_id().ok_or_else(|| {
+            anyhow::anyhow!("The address `{multiaddr}` doesn't contain the peer id")
+        })?;
+        let connected = self.swarm.is_connected(&peer_id);
+        self.peer_manager.add_reserved_peer(peer_id);
+        self.swarm
+            .behaviour_mut()
+            .add_reserved_peer(peer_id, multiaddr, connected);
+        Ok(peer_id)
+    }
+
+    /// Removes the reserved peer at runtime. The peer stays connected
+    /// as a non-reserved peer. Returns `false` if the peer was not reserved.
+    pub fn remove_reserved_peer(&mut self, peer_id: &PeerId) -> bool {
+        let removed = self.peer_manager.remove_reserved_peer(peer_id);
+        self.swarm.behaviour_mut().remove_reserved_peer(peer_id) || removed
+    }
+
+    /// Bans the peer for the `duration`. All connections to the peer are closed.
+    pub fn ban_peer(
+        &mut self,
+        peer_id: PeerId,
+        duration: Duration,
+    ) -> anyhow::Result<()> {
+        let expiration = Instant::now().checked_add(duration).ok_or_else(|| {
+            anyhow::anyhow!("The ban duration {duration:?} is too long")
+        })?;
+        self.banned_peers.insert(peer_id, expiration);
+        self.swarm.behaviour_mut().block_peer(peer_id);
+        Ok(())
+    }
+
+    /// Unbans the peer, regardless of whether it was banned by the node operator
+    /// or because of its reputation.
+    pub fn unban_peer(&mut self, peer_id: PeerId) {
+        self.banned_peers.remove(&peer_id);
+        self.swarm.behaviour_mut().unblock_peer(peer_id);
+    }
+
+    /// Unbans the peers with the expired ban.
+    pub fn unban_expired_peers(&mut self) {
+        let now = Instant::now();
+        let expired: Vec<_> = self
+            .banned_peers
+            .iter()
+            .filter(|(_, expiration)| **expiration <= now)
+            .map(|(peer_id, _)| *peer_id)
+            .collect();
+        for peer_id in expired {
+            debug!(target: "fuel-p2p", "The ban of the peer {peer_id} has expired");
+            self.unban_peer(peer_id);
+        }
+    }
+
+    /// Returns the peers banned by the node operator along with the remaining time of the ban.
+    pub fn banned_peers(&self) -> Vec<(PeerId, Duration)> {
+        let now = Instant::now();
+        self.banned_peers
+            .iter()
+            .map(|(peer_id, expiration)| {
+                (*peer_id, expiration.saturating_duration_since(now))
+            })
+            .collect()
+    }
+
+    /// Closes all connections to the peer. Returns `false` if the peer was not connected.
+    pub fn disconnect_peer(&mut self, peer_id: PeerId) -> bool {
+        self.swarm.disconnect_peer_id(peer_id).is_ok()
+    }
+
+    pub fn peer_reputations(&self) -> Vec<PeerReputation> {
+        self.peer_manager
+            .get_all_peers()
+            .map(|(peer_id, peer_info)| PeerReputation {
+                peer_id: *peer_id,
+                reserved: self.peer_manager.is_reserved(peer_id),
+                peer_info: peer_info.clone(),
+                gossipsub_score: self.get_peer_score(peer_id),
+            })
+            .collect()
+    }
+
     #[tracing::instrument(skip_all,
         level = "debug",
         fields(
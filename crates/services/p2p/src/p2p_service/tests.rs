@@ -18,6 +18,8 @@ use fuel_core_p2p::{
             GossipsubMessage,
         },
         topics::{
+            BFT_CONSENSUS_GOSSIP_TOPIC,
+            NEW_TX_ANNOUNCEMENT_GOSSIP_TOPIC,
             NEW_TX_GOSSIP_TOPIC,
             TX_PRECONFIRMATIONS_GOSSIP_TOPIC,
         },
@@ -41,6 +43,11 @@ use fuel_core_types::{
         SealedBlockHeader,
         consensus::{
             Consensus,
+            bft::{
+                BftMessage,
+                Signed,
+                Vote,
+            },
             poa::PoAConsensus,
         },
         header::BlockHeader,
@@ -267,6 +274,95 @@ async fn dont_connect_to_node_with_same_peer_id() {
     assert_eq!(node_b.peer_manager().total_peers_connected(), 0);
 }
 
+#[tokio::test]
+#[instrument]
+async fn added_reserved_node_is_dialed() {
+    let p2p_config = Config::default_initialized("added_reserved_node_is_dialed");
+    let mut node_a = build_service_from_config(p2p_config.clone()).await;
+    let node_b = build_service_from_config(p2p_config).await;
+    let node_b_peer_id = node_b.local_peer_id;
+    let node_b_address = node_b.multiaddrs().pop().unwrap();
+
+    let (stop_sender, _) = watch::channel(());
+    spawn(&stop_sender, node_b);
+
+    // When
+    let peer_id = node_a.add_reserved_peer(node_b_address).unwrap();
+
+    // Then
+    assert_eq!(peer_id, node_b_peer_id);
+    assert!(node_a.peer_manager().is_reserved(&node_b_peer_id));
+    tokio::time::timeout(Duration::from_secs(10), async {
+        loop {
+            if let Some(FuelP2PEvent::PeerConnected(peer_id)) = node_a.next_event().await
+                && peer_id == node_b_peer_id
+            {
+                break
+            }
+        }
+    })
+    .await
+    .expect("The reserved node should be dialed");
+    stop_sender.send(()).unwrap();
+}
+
+#[tokio::test]
+#[instrument]
+async fn banned_peer_is_disconnected_until_unbanned() {
+    let mut p2p_config =
+        Config::default_initialized("banned_peer_is_disconnected_until_unbanned");
+    let node_b = build_service_from_config(p2p_config.clone()).await;
+    let node_b_peer_id = node_b.local_peer_id;
+    p2p_config.bootstrap_nodes = node_b.multiaddrs();
+    let mut node_a = build_service_from_config(p2p_config).await;
+
+    let (stop_sender, _) = watch::channel(());
+    spawn(&stop_sender, node_b);
+
+    // Given
+    tokio::time::timeout(Duration::from_secs(10), async {
+        loop {
+            if let Some(FuelP2PEvent::PeerConnected(peer_id)) = node_a.next_event().await
+                && peer_id == node_b_peer_id
+            {
+                break
+            }
+        }
+    })
+    .await
+    .expect("The nodes should connect");
+
+    // When
+    node_a
+        .ban_peer(node_b_peer_id, Duration::from_secs(3600))
+        .unwrap();
+
+    // Then
+    tokio::time::timeout(Duration::from_secs(10), async {
+        loop {
+            if let Some(FuelP2PEvent::PeerDisconnected(peer_id)) =
+                node_a.next_event().await
+                && peer_id == node_b_peer_id
+            {
+                break
+            }
+        }
+    })
+    .await
+    .expect("The banned peer should be disconnected");
+    let banned_peers = node_a.banned_peers();
+    assert_eq!(banned_peers.len(), 1);
+    assert_eq!(banned_peers[0].0, node_b_peer_id);
+
+    // the ban is not expired yet
+    node_a.unban_expired_peers();
+    assert_eq!(node_a.banned_peers().len(), 1);
+
+    node_a.unban_peer(node_b_peer_id);
+    assert!(node_a.banned_peers().is_empty());
+    stop_sender.send(()).unwrap();
+}
+
 // We start with two nodes, node_a and node_b, bootstrapped with `bootstrap_nodes_count` other nodes.
 // Yet node_a and node_b are only allowed to connect to specified amount of nodes.
 #[tokio::test]
@@ -615,6 +711,25 @@ async fn gossipsub_broadcast_tx_with_accept__new_tx() {
     }
 }
 
+#[tokio::test]
+#[instrument]
+async fn gossipsub_broadcast_tx_with_accept__new_tx_announcement() {
+    for _ in 0..100 {
+        tokio::time::timeout(
+            Duration::from_secs(5),
+            gossipsub_broadcast(
+                GossipsubBroadcastRequest::NewTxAnnouncement(
+                    Transaction::default_test_tx().id(&Default::default()),
+                ),
+                GossipsubMessageAcceptance::Accept,
+                None,
+            ),
+        )
+        .await
+        .unwrap();
+    }
+}
+
 #[tokio::test]
 #[instrument]
 async fn gossipsub_broadcast_tx_with_accept__tx_preconfirmations() {
@@ -634,6 +749,28 @@ async fn gossipsub_broadcast_tx_with_accept__tx_preconfirmations() {
     }
 }
 
+#[tokio::test]
+#[instrument]
+async fn gossipsub_broadcast_tx_with_accept__bft_consensus() {
+    for _ in 0..100 {
+        tokio::time::timeout(
+            Duration::from_secs(5),
+            gossipsub_broadcast(
+                GossipsubBroadcastRequest::BftConsensus(Arc::new(BftMessage::Vote(
+                    Signed {
+                        entity: Vote::prevote(1u32.into(), 0, None),
+                        signature: Default::default(),
+                    },
+                ))),
+                GossipsubMessageAcceptance::Accept,
+                None,
+            ),
+        )
+        .await
+        .unwrap();
+    }
+}
+
 #[tokio::test]
 #[instrument]
 async fn gossipsub_broadcast_tx_with_reject__new_tx() {
@@ -805,16 +942,24 @@ async fn gossipsub_broadcast(
     }
 
     p2p_config.subscribe_to_pre_confirmations = true;
+    p2p_config.subscribe_to_bft_consensus = true;
 
     let (selected_topic, selected_tag): (Sha256Topic, GossipTopicTag) = {
         let (topic, tag) = match broadcast_request {
             GossipsubBroadcastRequest::NewTx(_) => {
                 (NEW_TX_GOSSIP_TOPIC, GossipTopicTag::NewTx)
             }
+            GossipsubBroadcastRequest::NewTxAnnouncement(_) => (
+                NEW_TX_ANNOUNCEMENT_GOSSIP_TOPIC,
+                GossipTopicTag::NewTxAnnouncement,
+            ),
             GossipsubBroadcastRequest::TxPreConfirmations(_) => (
                 TX_PRECONFIRMATIONS_GOSSIP_TOPIC,
                 GossipTopicTag::TxPreconfirmations,
             ),
+            GossipsubBroadcastRequest::BftConsensus(_) => {
+                (BFT_CONSENSUS_GOSSIP_TOPIC, GossipTopicTag::BftConsensus)
+            }
         };
 
         (
@@ -940,6 +1085,13 @@ fn check_message_matches_request(
                 "Both messages were `NewTx`s, but the received message did not match the requested message"
             );
         }
+        (
+            GossipsubMessage::NewTxAnnouncement(received),
+            GossipsubBroadcastRequest::NewTxAnnouncement(requested),
+        ) => assert_eq!(
+            requested, received,
+            "Both messages were `NewTxAnnouncement`s, but the received message did not match the requested message"
+        ),
         (
             GossipsubMessage::TxPreConfirmations(received),
             GossipsubBroadcastRequest::TxPreConfirmations(requested),
@@ -948,6 +1100,14 @@ fn check_message_matches_request(
             received,
             "Both messages were `Preconfirmations`, but the received message did not match the requested message"
         ),
+        (
+            GossipsubMessage::BftConsensus(received),
+            GossipsubBroadcastRequest::BftConsensus(requested),
+        ) => assert_eq!(
+            requested.deref(),
+            received,
+            "Both messages were `BftConsensus`, but the received message did not match the requested message"
+        ),
         _ => panic!(
             "Message does not match the expected request, expected: {:?}, actual: {:?}",
             expected, message
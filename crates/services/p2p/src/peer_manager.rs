@@ -225,6 +225,47 @@ impl PeerManager {
         }
     }
 
+    /// Marks the peer as reserved. If the peer is already connected,
+    /// it releases the slot of non-reserved peers.
+    pub fn add_reserved_peer(&mut self, peer_id: PeerId) {
+        if !self.reserved_peers.insert(peer_id) {
+            return
+        }
+
+        let all_slots_taken =
+            self.non_reserved_connected_peers.len() >= self.max_non_reserved_peers;
+        if let Some(peer_info) = self.non_reserved_connected_peers.remove(&peer_id) {
+            if all_slots_taken {
+                self.connection_state_writer.write(|data| {
+                    data.allow_new_peers();
+                });
+            }
+            self.reserved_connected_peers.insert(peer_id, peer_info);
+            self.send_reserved_peers_update();
+        }
+    }
+
+    /// Removes the peer from the reserved peers. The connected peer stays connected
+    /// as a non-reserved peer. Returns `false` if the peer was not reserved.
+    pub fn remove_reserved_peer(&mut self, peer_id: &PeerId) -> bool {
+        if !self.reserved_peers.remove(peer_id) {
+            return false
+        }
+
+        if let Some(peer_info) = self.reserved_connected_peers.remove(peer_id) {
+            self.non_reserved_connected_peers
+                .insert(*peer_id, peer_info);
+            if self.non_reserved_connected_peers.len() >= self.max_non_reserved_peers {
+                self.connection_state_writer.write(|data| {
+                    data.deny_new_peers();
+                });
+            }
+            self.send_reserved_peers_update();
+        }
+
+        true
+    }
+
     /// Find a peer that is holding the given block height.
     pub fn get_peer_id_with_height(&self, height: &BlockHeight) -> Option<PeerId> {
         let mut range = rand::thread_rng();
@@ -493,4 +534,60 @@ mod tests {
             reserved_peers.len() + max_non_reserved_peers
         );
     }
+
+    #[test]
+    fn added_reserved_peer_releases_non_reserved_slot() {
+        let max_non_reserved_peers = 5;
+        let mut peer_manager = initialize_peer_manager(vec![], max_non_reserved_peers);
+
+        // take all the non-reserved slots
+        let random_peers = get_random_peers(max_non_reserved_peers);
+        for peer_id in &random_peers {
+            peer_manager.handle_initial_connection(peer_id);
+        }
+
+        // mark one of the connected peers as reserved
+        let reserved_peer = *random_peers.first().unwrap();
+        peer_manager.add_reserved_peer(reserved_peer);
+        assert!(peer_manager.is_reserved(&reserved_peer));
+
+        // the released slot can be taken by a new peer
+        let new_peer = PeerId::random();
+        let should_disconnect = peer_manager.handle_initial_connection(&new_peer);
+
+        assert!(!should_disconnect);
+        assert_eq!(
+            peer_manager.total_peers_connected(),
+            max_non_reserved_peers + 1
+        );
+    }
+
+    #[test]
+    fn removed_reserved_peer_stays_connected_as_non_reserved() {
+        let max_non_reserved_peers = 5;
+        let reserved_peers = get_random_peers(2);
+        let mut peer_manager =
+            initialize_peer_manager(reserved_peers.clone(), max_non_reserved_peers);
+
+        for peer_id in &reserved_peers {
+            peer_manager.handle_initial_connection(peer_id);
+        }
+
+        let removed_peer = *reserved_peers.first().unwrap();
+        assert!(peer_manager.remove_reserved_peer(&removed_peer));
+        assert!(!peer_manager.remove_reserved_peer(&removed_peer));
+
+        assert!(!peer_manager.is_reserved(&removed_peer));
+        assert_eq!(peer_manager.total_peers_connected(), reserved_peers.len());
+
+        // the removed peer takes one of the non-reserved slots
+        let random_peers = get_random_peers(max_non_reserved_peers * 2);
+        for peer_id in &random_peers {
+            peer_manager.handle_initial_connection(peer_id);
+        }
+        assert_eq!(
+            peer_manager.total_peers_connected(),
+            reserved_peers.len() - 1 + max_non_reserved_peers
+        );
+    }
 }
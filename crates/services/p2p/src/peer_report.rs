@@ -113,6 +113,40 @@ impl Behaviour {
     pub fn reserved_nodes_multiaddr(&self) -> &BTreeMap<PeerId, Vec<Multiaddr>> {
         &self.reserved_nodes_multiaddr
     }
+
+    /// Adds the reserved node that will be reconnected after disconnection.
+    /// If the node is not `connected`, it is dialed on the next health check.
+    pub fn add_reserved_node(
+        &mut self,
+        peer_id: PeerId,
+        multiaddr: Multiaddr,
+        connected: bool,
+    ) {
+        let multiaddrs = self.reserved_nodes_multiaddr.entry(peer_id).or_default();
+        if !multiaddrs.contains(&multiaddr) {
+            multiaddrs.push(multiaddr);
+        }
+
+        if connected {
+            self.connected_reserved_nodes.insert(peer_id);
+        } else if !self
+            .reserved_nodes_to_connect
+            .iter()
+            .any(|(_, queued)| queued == &peer_id)
+        {
+            self.reserved_nodes_to_connect
+                .push_back((Instant::now(), peer_id));
+        }
+    }
+
+    /// Removes the reserved node, so it is not reconnected anymore.
+    /// Returns `false` if the node was not reserved.
+    pub fn remove_reserved_node(&mut self, peer_id: &PeerId) -> bool {
+        self.connected_reserved_nodes.remove(peer_id);
+        self.reserved_nodes_to_connect
+            .retain(|(_, queued)| queued != peer_id);
+        self.reserved_nodes_multiaddr.remove(peer_id).is_some()
+    }
 }
 
 impl NetworkBehaviour for Behaviour {
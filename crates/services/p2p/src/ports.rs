@@ -3,7 +3,10 @@ use fuel_core_storage::Result as StorageResult;
 use fuel_core_types::{
     blockchain::{
         SealedBlockHeader,
-        consensus::Genesis,
+        consensus::{
+            Genesis,
+            bft::BftMessage,
+        },
     },
     fuel_tx::{
         Bytes64,
@@ -60,3 +63,5 @@ pub type P2PPreConfirmationMessage =
     PreConfirmationMessage<DelegatePublicKey, Bytes64, ProtocolSignature>;
 
 pub type P2PPreConfirmationGossipData = GossipData<P2PPreConfirmationMessage>;
+
+pub type BftGossipData = GossipData<BftMessage>;
@@ -7,6 +7,7 @@ use crate::{
     config::{
         Config,
         NotInitialized,
+        TxGossipMode,
     },
     gossipsub::messages::{
         GossipTopicTag,
@@ -16,9 +17,11 @@ use crate::{
     p2p_service::{
         FuelP2PEvent,
         FuelP2PService,
+        PeerReputation,
     },
     peer_manager::PeerInfo,
     ports::{
+        BftGossipData,
         BlockHeightImporter,
         P2PPreConfirmationGossipData,
         P2PPreConfirmationMessage,
@@ -49,7 +52,10 @@ use fuel_core_services::{
 };
 use fuel_core_storage::transactional::AtomicView;
 use fuel_core_types::{
-    blockchain::SealedBlockHeader,
+    blockchain::{
+        SealedBlockHeader,
+        consensus::bft::BftMessage,
+    },
     fuel_tx::{
         Transaction,
         TxId,
@@ -79,6 +85,7 @@ use futures::{
     future::BoxFuture,
 };
 use libp2p::{
+    Multiaddr,
     PeerId,
     gossipsub::{
         MessageAcceptance,
@@ -115,6 +122,15 @@ pub mod broadcast_tests;
 #[cfg(test)]
 pub mod task_tests;
 
+mod tx_fetcher;
+
+use tx_fetcher::{
+    FetchResult,
+    FetchedTx,
+    MISSING_ANNOUNCED_TX_PENALTY,
+    TxFetcher,
+};
+
 const CHANNEL_SIZE: usize = 1024 * 10;
 
 pub type Service<V, T> = ServiceRunner<UninitializedTask<V, SharedState, T>>;
@@ -130,6 +146,8 @@ pub enum TaskRequest {
     BroadcastTransaction(Arc<Transaction>),
     // Broadcast Preconfirmations to p2p network
     BroadcastPreConfirmations(Arc<P2PPreConfirmationMessage>),
+    // Broadcast BFT consensus message to p2p network
+    BroadcastBftMessage(Arc<BftMessage>),
     // Request to get information about all connected peers
     GetAllPeerInfo {
         channel: oneshot::Sender<Vec<(PeerId, PeerInfo)>>,
@@ -186,6 +204,34 @@ pub enum TaskRequest {
             Result<Vec<Option<NetworkableTransactionPool>>, ResponseMessageErrorCode>,
         request_id: InboundRequestId,
     },
+    // Administrative requests to manage the peers at runtime
+    AddReservedPeer {
+        address: Multiaddr,
+        channel: oneshot::Sender<anyhow::Result<PeerId>>,
+    },
+    RemoveReservedPeer {
+        peer_id: PeerId,
+        channel: oneshot::Sender<bool>,
+    },
+    BanPeer {
+        peer_id: PeerId,
+        duration: Duration,
+        channel: oneshot::Sender<anyhow::Result<()>>,
+    },
+    UnbanPeer {
+        peer_id: PeerId,
+        channel: oneshot::Sender<()>,
+    },
+    DisconnectPeer {
+        peer_id: PeerId,
+        channel: oneshot::Sender<bool>,
+    },
+    GetPeerReputations {
+        channel: oneshot::Sender<Vec<PeerReputation>>,
+    },
+    GetBannedPeers {
+        channel: oneshot::Sender<Vec<(PeerId, Duration)>>,
+    },
 }
 
 impl Debug for TaskRequest {
@@ -197,6 +243,9 @@ impl Debug for TaskRequest {
             TaskRequest::BroadcastPreConfirmations(_) => {
                 write!(f, "TaskRequest::BroadcastPreConfirmations")
             }
+            TaskRequest::BroadcastBftMessage(_) => {
+                write!(f, "TaskRequest::BroadcastBftMessage")
+            }
             TaskRequest::GetSealedHeaders { .. } => {
                 write!(f, "TaskRequest::GetSealedHeaders")
             }
@@ -233,6 +282,27 @@ impl Debug for TaskRequest {
             TaskRequest::TxPoolFullTransactions { .. } => {
                 write!(f, "TaskRequest::TxPoolFullTransactions")
             }
+            TaskRequest::AddReservedPeer { .. } => {
+                write!(f, "TaskRequest::AddReservedPeer")
+            }
+            TaskRequest::RemoveReservedPeer { .. } => {
+                write!(f, "TaskRequest::RemoveReservedPeer")
+            }
+            TaskRequest::BanPeer { .. } => {
+                write!(f, "TaskRequest::BanPeer")
+            }
+            TaskRequest::UnbanPeer { .. } => {
+                write!(f, "TaskRequest::UnbanPeer")
+            }
+            TaskRequest::DisconnectPeer { .. } => {
+                write!(f, "TaskRequest::DisconnectPeer")
+            }
+            TaskRequest::GetPeerReputations { .. } => {
+                write!(f, "TaskRequest::GetPeerReputations")
+            }
+            TaskRequest::GetBannedPeers { .. } => {
+                write!(f, "TaskRequest::GetBannedPeers")
+            }
         }
     }
 }
@@ -285,6 +355,22 @@ pub trait TaskP2PService: Send {
     fn update_metrics<T>(&self, update_fn: T)
     where
         T: FnOnce();
+
+    fn add_reserved_peer(&mut self, address: Multiaddr) -> anyhow::Result<PeerId>;
+
+    fn remove_reserved_peer(&mut self, peer_id: &PeerId) -> bool;
+
+    fn ban_peer(&mut self, peer_id: PeerId, duration: Duration) -> anyhow::Result<()>;
+
+    fn unban_peer(&mut self, peer_id: PeerId);
+
+    fn unban_expired_peers(&mut self);
+
+    fn disconnect_peer(&mut self, peer_id: PeerId) -> bool;
+
+    fn peer_reputations(&self) -> Vec<PeerReputation>;
+
+    fn banned_peers(&self) -> Vec<(PeerId, Duration)>;
 }
 
 impl TaskP2PService for FuelP2PService {
@@ -367,6 +453,38 @@ impl TaskP2PService for FuelP2PService {
         self.update_block_height(height);
         Ok(())
     }
+
+    fn add_reserved_peer(&mut self, address: Multiaddr) -> anyhow::Result<PeerId> {
+        self.add_reserved_peer(address)
+    }
+
+    fn remove_reserved_peer(&mut self, peer_id: &PeerId) -> bool {
+        self.remove_reserved_peer(peer_id)
+    }
+
+    fn ban_peer(&mut self, peer_id: PeerId, duration: Duration) -> anyhow::Result<()> {
+        self.ban_peer(peer_id, duration)
+    }
+
+    fn unban_peer(&mut self, peer_id: PeerId) {
+        self.unban_peer(peer_id)
+    }
+
+    fn unban_expired_peers(&mut self) {
+        self.unban_expired_peers()
+    }
+
+    fn disconnect_peer(&mut self, peer_id: PeerId) -> bool {
+        self.disconnect_peer(peer_id)
+    }
+
+    fn peer_reputations(&self) -> Vec<PeerReputation> {
+        self.peer_reputations()
+    }
+
+    fn banned_peers(&self) -> Vec<(PeerId, Duration)> {
+        self.banned_peers()
+    }
 }
 
 pub trait Broadcast: Send {
@@ -389,6 +507,8 @@ pub trait Broadcast: Send {
         confirmations: P2PPreConfirmationGossipData,
     ) -> anyhow::Result<()>;
 
+    fn bft_message_broadcast(&self, message: BftGossipData) -> anyhow::Result<()>;
+
     fn new_tx_subscription_broadcast(&self, peer_id: FuelPeerId) -> anyhow::Result<()>;
 }
 
@@ -423,6 +543,11 @@ impl Broadcast for SharedState {
         Ok(())
     }
 
+    fn bft_message_broadcast(&self, message: BftGossipData) -> anyhow::Result<()> {
+        self.bft_messages_broadcast.send(message)?;
+        Ok(())
+    }
+
     fn new_tx_subscription_broadcast(&self, peer_id: FuelPeerId) -> anyhow::Result<()> {
         self.new_tx_subscription_broadcast.send(peer_id)?;
         Ok(())
@@ -467,6 +592,8 @@ pub struct Task<P, V, B, T> {
     heartbeat_peer_reputation_config: HeartbeatPeerReputationConfig,
     // cached view
     cached_view: Arc<CachedView>,
+    tx_gossip_mode: TxGossipMode,
+    tx_fetcher: TxFetcher,
 }
 
 impl<P, V, B, T> Task<P, V, B, T>
@@ -484,14 +611,164 @@ where
 
         match message {
             GossipsubMessage::NewTx(transaction) => {
+                self.tx_fetcher.mark_known(transaction.id(&self.chain_id));
                 let next_transaction = GossipData::new(transaction, peer_id, message_id);
                 let _ = self.broadcast.tx_broadcast(next_transaction);
             }
+            GossipsubMessage::NewTxAnnouncement(tx_id) => {
+                self.handle_tx_announcement(tx_id, message_id, peer_id);
+            }
             GossipsubMessage::TxPreConfirmations(confirmations) => {
                 let data = GossipData::new(confirmations, peer_id, message_id);
                 let _ = self.broadcast.pre_confirmation_broadcast(data);
             }
+            GossipsubMessage::BftConsensus(message) => {
+                let data = GossipData::new(message, peer_id, message_id);
+                let _ = self.broadcast.bft_message_broadcast(data);
+            }
+        }
+    }
+
+    /// Fetches the announced transaction from the announcing peer if the node doesn't know it.
+    /// If the transaction is already being fetched, the announcement is remembered to retry
+    /// the fetching with its peer in the case of failure.
+    /// The announcement is ignored if the transaction is known, enough announcers are
+    /// remembered, or too many transactions are being fetched at the moment.
+    fn handle_tx_announcement(
+        &mut self,
+        tx_id: TxId,
+        message_id: Vec<u8>,
+        peer_id: PeerId,
+    ) {
+        let message_info = GossipsubMessageInfo {
+            message_id,
+            peer_id: FuelPeerId::from(peer_id.to_bytes()),
+        };
+
+        let message_info =
+            match self.tx_fetcher.add_announcer(&tx_id, peer_id, message_info) {
+                Ok(()) => return,
+                Err(message_info) => message_info,
+            };
+
+        if !self.tx_fetcher.should_fetch(&tx_id) {
+            let _ = self
+                .p2p_service
+                .report_message(message_info, GossipsubMessageAcceptance::Ignore);
+            return;
+        }
+
+        self.request_announced_tx(tx_id, peer_id, message_info);
+    }
+
+    fn request_announced_tx(
+        &mut self,
+        tx_id: TxId,
+        peer_id: PeerId,
+        message_info: GossipsubMessageInfo,
+    ) {
+        let (sender, receiver) = oneshot::channel();
+        let request_msg = RequestMessage::TxPoolFullTransactions(vec![tx_id]);
+        let channel = ResponseSender::TxPoolFullTransactions(sender);
+        match self
+            .p2p_service
+            .send_request_msg(Some(peer_id), request_msg, channel)
+        {
+            Ok(()) => self
+                .tx_fetcher
+                .start_fetch(tx_id, peer_id, message_info, receiver),
+            Err(e) => {
+                tracing::debug!(
+                    "Failed to request announced transaction {} from peer {}: {}",
+                    tx_id,
+                    peer_id,
+                    e
+                );
+                let _ = self
+                    .p2p_service
+                    .report_message(message_info, GossipsubMessageAcceptance::Ignore);
+                self.retry_tx_fetch(tx_id);
+            }
+        }
+    }
+
+    /// Requests the transaction from the next peer that announced it, if any.
+    fn retry_tx_fetch(&mut self, tx_id: TxId) {
+        if let Some((peer_id, message_info)) = self.tx_fetcher.next_announcer(&tx_id) {
+            self.request_announced_tx(tx_id, peer_id, message_info);
+        }
+    }
+
+    /// Forwards the fetched transaction to the TxPool as if it was gossiped.
+    /// The TxPool reports the validity of the transaction for the announcement,
+    /// so valid announcements are propagated further.
+    ///
+    /// The peer is penalized if it responds without the transaction it announced.
+    /// If the peer doesn't provide the transaction, the fetching is retried with
+    /// other peers that announced it.
+    fn handle_fetched_tx(&mut self, fetched: FetchedTx) {
+        let FetchedTx {
+            tx_id,
+            peer_id,
+            message_info,
+            result,
+        } = fetched;
+
+        match result {
+            FetchResult::Fetched(transaction)
+                if transaction.id(&self.chain_id) == tx_id =>
+            {
+                self.tx_fetcher.mark_known(tx_id);
+                for duplicate in self.tx_fetcher.finish_fetch(&tx_id) {
+                    let _ = self
+                        .p2p_service
+                        .report_message(duplicate, GossipsubMessageAcceptance::Ignore);
+                }
+                let GossipsubMessageInfo {
+                    message_id,
+                    peer_id,
+                } = message_info;
+                let next_transaction = GossipData {
+                    data: Some(*transaction),
+                    peer_id,
+                    message_id,
+                };
+                let _ = self.broadcast.tx_broadcast(next_transaction);
+                return;
+            }
+            FetchResult::Fetched(_) => {
+                tracing::debug!(
+                    "Peer {} responded with a different transaction than announced {}",
+                    peer_id,
+                    tx_id
+                );
+                let _ = self
+                    .p2p_service
+                    .report_message(message_info, GossipsubMessageAcceptance::Reject);
+            }
+            FetchResult::Missing => {
+                tracing::debug!(
+                    "Peer {} responded without the announced transaction {}",
+                    peer_id,
+                    tx_id
+                );
+                let _ = self
+                    .p2p_service
+                    .report_message(message_info, GossipsubMessageAcceptance::Ignore);
+                let _ = self.p2p_service.report_peer(
+                    peer_id,
+                    MISSING_ANNOUNCED_TX_PENALTY,
+                    "p2p",
+                );
+            }
+            FetchResult::Failed => {
+                let _ = self
+                    .p2p_service
+                    .report_message(message_info, GossipsubMessageAcceptance::Ignore);
+            }
         }
+
+        self.retry_tx_fetch(tx_id);
     }
 }
 
@@ -866,6 +1143,8 @@ where
             tx_pool_threads,
             metrics,
             cache_size,
+            tx_gossip_mode,
+            max_pending_tx_fetches,
             ..
         } = config;
 
@@ -925,6 +1204,8 @@ where
                     .unwrap_or(1_535),
                 metrics,
             )),
+            tx_gossip_mode,
+            tx_fetcher: TxFetcher::new(max_pending_tx_fetches),
         };
         Ok(task)
     }
@@ -958,7 +1239,11 @@ where
                 match next_service_request {
                     Some(TaskRequest::BroadcastTransaction(transaction)) => {
                         let tx_id = transaction.id(&self.chain_id);
-                        let broadcast = GossipsubBroadcastRequest::NewTx(transaction);
+                        self.tx_fetcher.mark_known(tx_id);
+                        let broadcast = match self.tx_gossip_mode {
+                            TxGossipMode::Full => GossipsubBroadcastRequest::NewTx(transaction),
+                            TxGossipMode::Announce => GossipsubBroadcastRequest::NewTxAnnouncement(tx_id),
+                        };
                         let result = self.p2p_service.publish_message(broadcast);
                         if let Err(e) = result {
                             tracing::error!("Got an error during transaction {} broadcasting {}", tx_id, e);
@@ -971,6 +1256,13 @@ where
                             tracing::error!("Got an error during pre-confirmation message broadcasting {:?}: {}", broadcast, e);
                         }
                     }
+                    Some(TaskRequest::BroadcastBftMessage(message)) => {
+                        let broadcast = GossipsubBroadcastRequest::BftConsensus(message);
+                        let result = self.p2p_service.publish_message(broadcast);
+                        if let Err(e) = result {
+                            tracing::error!("Got an error during BFT consensus message broadcasting: {}", e);
+                        }
+                    }
                     Some(TaskRequest::GetSealedHeaders { block_height_range, channel}) => {
                         // Note: this range has already been checked for
                         // validity in `SharedState::get_sealed_block_headers`.
@@ -1036,6 +1328,28 @@ where
                     Some(TaskRequest::TxPoolFullTransactions { response, request_id }) => {
                         let _ = self.p2p_service.send_response_msg(request_id, V2ResponseMessage::TxPoolFullTransactions(response));
                     }
+                    Some(TaskRequest::AddReservedPeer { address, channel }) => {
+                        let _ = channel.send(self.p2p_service.add_reserved_peer(address));
+                    }
+                    Some(TaskRequest::RemoveReservedPeer { peer_id, channel }) => {
+                        let _ = channel.send(self.p2p_service.remove_reserved_peer(&peer_id));
+                    }
+                    Some(TaskRequest::BanPeer { peer_id, duration, channel }) => {
+                        let _ = channel.send(self.p2p_service.ban_peer(peer_id, duration));
+                    }
+                    Some(TaskRequest::UnbanPeer { peer_id, channel }) => {
+                        self.p2p_service.unban_peer(peer_id);
+                        let _ = channel.send(());
+                    }
+                    Some(TaskRequest::DisconnectPeer { peer_id, channel }) => {
+                        let _ = channel.send(self.p2p_service.disconnect_peer(peer_id));
+                    }
+                    Some(TaskRequest::GetPeerReputations { channel }) => {
+                        let _ = channel.send(self.p2p_service.peer_reputations());
+                    }
+                    Some(TaskRequest::GetBannedPeers { channel }) => {
+                        let _ = channel.send(self.p2p_service.banned_peers());
+                    }
                     None => {
                         tracing::error!("The P2P `Task` should be holder of the `Sender`");
                         return TaskNextAction::Stop
@@ -1073,7 +1387,12 @@ where
                 }
                 TaskNextAction::Continue
             },
+            fetched_tx = self.tx_fetcher.next_fetched() => {
+                self.handle_fetched_tx(fetched_tx);
+                TaskNextAction::Continue
+            },
             _  = tokio::time::sleep_until(self.next_check_time) => {
+                self.p2p_service.unban_expired_peers();
                 let res = self.peer_heartbeat_reputation_checks();
                 match res {
                     Ok(_) => tracing::debug!("Peer heartbeat reputation checks completed"),
@@ -1113,6 +1432,8 @@ pub struct SharedState {
     tx_broadcast: broadcast::Sender<TransactionGossipData>,
     /// Sender of p2p transaction preconfirmations used for subscribing.
     pre_confirmations_broadcast: broadcast::Sender<P2PPreConfirmationGossipData>,
+    /// Sender of p2p BFT consensus messages used for subscribing.
+    bft_messages_broadcast: broadcast::Sender<BftGossipData>,
     /// Sender of reserved peers connection updates.
     reserved_peers_broadcast: broadcast::Sender<usize>,
     /// Used for communicating with the `Task`.
@@ -1335,6 +1656,12 @@ impl SharedState {
         Ok(())
     }
 
+    pub fn broadcast_bft_message(&self, message: Arc<BftMessage>) -> anyhow::Result<()> {
+        self.request_sender
+            .try_send(TaskRequest::BroadcastBftMessage(message))?;
+        Ok(())
+    }
+
     pub async fn get_all_peers(&self) -> anyhow::Result<Vec<(PeerId, PeerInfo)>> {
         let (sender, receiver) = oneshot::channel();
 
@@ -1345,6 +1672,96 @@ impl SharedState {
         receiver.await.map_err(|e| anyhow!("{}", e))
     }
 
+    pub async fn add_reserved_peer(&self, address: Multiaddr) -> anyhow::Result<PeerId> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(TaskRequest::AddReservedPeer {
+                address,
+                channel: sender,
+            })
+            .await?;
+
+        receiver.await.map_err(|e| anyhow!("{}", e))?
+    }
+
+    pub async fn remove_reserved_peer(&self, peer_id: PeerId) -> anyhow::Result<bool> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(TaskRequest::RemoveReservedPeer {
+                peer_id,
+                channel: sender,
+            })
+            .await?;
+
+        receiver.await.map_err(|e| anyhow!("{}", e))
+    }
+
+    pub async fn ban_peer(
+        &self,
+        peer_id: PeerId,
+        duration: Duration,
+    ) -> anyhow::Result<()> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(TaskRequest::BanPeer {
+                peer_id,
+                duration,
+                channel: sender,
+            })
+            .await?;
+
+        receiver.await.map_err(|e| anyhow!("{}", e))?
+    }
+
+    pub async fn unban_peer(&self, peer_id: PeerId) -> anyhow::Result<()> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(TaskRequest::UnbanPeer {
+                peer_id,
+                channel: sender,
+            })
+            .await?;
+
+        receiver.await.map_err(|e| anyhow!("{}", e))
+    }
+
+    pub async fn disconnect_peer(&self, peer_id: PeerId) -> anyhow::Result<bool> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(TaskRequest::DisconnectPeer {
+                peer_id,
+                channel: sender,
+            })
+            .await?;
+
+        receiver.await.map_err(|e| anyhow!("{}", e))
+    }
+
+    pub async fn get_peer_reputations(&self) -> anyhow::Result<Vec<PeerReputation>> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(TaskRequest::GetPeerReputations { channel: sender })
+            .await?;
+
+        receiver.await.map_err(|e| anyhow!("{}", e))
+    }
+
+    pub async fn get_banned_peers(&self) -> anyhow::Result<Vec<(PeerId, Duration)>> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(TaskRequest::GetBannedPeers { channel: sender })
+            .await?;
+
+        receiver.await.map_err(|e| anyhow!("{}", e))
+    }
+
     pub fn subscribe_new_peers(&self) -> broadcast::Receiver<FuelPeerId> {
         self.new_tx_subscription_broadcast.subscribe()
     }
@@ -1359,6 +1776,10 @@ impl SharedState {
         self.pre_confirmations_broadcast.subscribe()
     }
 
+    pub fn subscribe_bft_messages(&self) -> broadcast::Receiver<BftGossipData> {
+        self.bft_messages_broadcast.subscribe()
+    }
+
     pub fn subscribe_block_height(
         &self,
     ) -> broadcast::Receiver<BlockHeightHeartbeatData> {
@@ -1402,6 +1823,7 @@ pub fn build_shared_state(
     let (request_sender, request_receiver) = mpsc::channel(CHANNEL_SIZE);
     let (tx_broadcast, _) = broadcast::channel(CHANNEL_SIZE);
     let (preconfirmations_broadcast, _) = broadcast::channel(CHANNEL_SIZE);
+    let (bft_messages_broadcast, _) = broadcast::channel(CHANNEL_SIZE);
     let (new_tx_subscription_broadcast, _) = broadcast::channel(CHANNEL_SIZE);
     let (block_height_broadcast, _) = broadcast::channel(CHANNEL_SIZE);
 
@@ -1419,6 +1841,7 @@ pub fn build_shared_state(
             new_tx_subscription_broadcast,
             tx_broadcast,
             pre_confirmations_broadcast: preconfirmations_broadcast,
+            bft_messages_broadcast,
             reserved_peers_broadcast,
             block_height_broadcast,
             max_txs_per_request: config.max_txs_per_request,
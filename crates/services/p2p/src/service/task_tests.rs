@@ -4,7 +4,11 @@ use crate::ports::P2pDb;
 use super::*;
 
 use crate::{
-    gossipsub::topics::TX_PRECONFIRMATIONS_GOSSIP_TOPIC,
+    gossipsub::topics::{
+        NEW_TX_ANNOUNCEMENT_GOSSIP_TOPIC,
+        NEW_TX_GOSSIP_TOPIC,
+        TX_PRECONFIRMATIONS_GOSSIP_TOPIC,
+    },
     peer_manager::heartbeat_data::HeartbeatData,
 };
 use fuel_core_services::{
@@ -14,6 +18,7 @@ use fuel_core_services::{
 use fuel_core_storage::Result as StorageResult;
 use fuel_core_types::{
     blockchain::consensus::Genesis,
+    fuel_tx::TransactionBuilder,
     fuel_types::BlockHeight,
 };
 use futures::FutureExt;
@@ -103,9 +108,15 @@ async fn start_and_stop_awaits_works() {
     assert!(service.stop_and_await().await.unwrap().stopped());
 }
 
+type SentRequest = (Option<PeerId>, RequestMessage, ResponseSender);
+
 struct FakeP2PService {
     peer_info: Vec<(PeerId, PeerInfo)>,
     next_event_stream: BoxStream<FuelP2PEvent>,
+    published_messages: mpsc::Sender<GossipsubBroadcastRequest>,
+    sent_requests: mpsc::Sender<SentRequest>,
+    message_reports: mpsc::Sender<(GossipsubMessageInfo, GossipsubMessageAcceptance)>,
+    peer_reports: mpsc::Sender<(PeerId, AppScore)>,
 }
 
 impl TaskP2PService for FakeP2PService {
@@ -130,18 +141,22 @@ impl TaskP2PService for FakeP2PService {
 
     fn publish_message(
         &mut self,
-        _message: GossipsubBroadcastRequest,
+        message: GossipsubBroadcastRequest,
     ) -> anyhow::Result<()> {
-        todo!()
+        self.published_messages.try_send(message)?;
+        Ok(())
     }
 
     fn send_request_msg(
         &mut self,
-        _peer_id: Option<PeerId>,
-        _request_msg: RequestMessage,
-        _on_response: ResponseSender,
+        peer_id: Option<PeerId>,
+        request_msg: RequestMessage,
+        on_response: ResponseSender,
     ) -> anyhow::Result<()> {
-        todo!()
+        self.sent_requests
+            .try_send((peer_id, request_msg, on_response))
+            .map_err(|_| anyhow!("Failed to send the request"))?;
+        Ok(())
     }
 
     fn send_response_msg(
@@ -154,24 +169,56 @@ impl TaskP2PService for FakeP2PService {
 
     fn report_message(
         &mut self,
-        _message: GossipsubMessageInfo,
-        _acceptance: GossipsubMessageAcceptance,
+        message: GossipsubMessageInfo,
+        acceptance: GossipsubMessageAcceptance,
     ) -> anyhow::Result<()> {
+        let _ = self.message_reports.try_send((message, acceptance));
         Ok(())
     }
 
     fn report_peer(
         &mut self,
-        _peer_id: PeerId,
-        _score: AppScore,
+        peer_id: PeerId,
+        score: AppScore,
         _reporting_service: &str,
     ) -> anyhow::Result<()> {
-        todo!()
+        let _ = self.peer_reports.try_send((peer_id, score));
+        Ok(())
     }
 
     fn update_block_height(&mut self, _height: BlockHeight) -> anyhow::Result<()> {
         Ok(())
     }
+
+    fn add_reserved_peer(&mut self, _address: Multiaddr) -> anyhow::Result<PeerId> {
+        todo!()
+    }
+
+    fn remove_reserved_peer(&mut self, _peer_id: &PeerId) -> bool {
+        todo!()
+    }
+
+    fn ban_peer(&mut self, _peer_id: PeerId, _duration: Duration) -> anyhow::Result<()> {
+        todo!()
+    }
+
+    fn unban_peer(&mut self, _peer_id: PeerId) {
+        todo!()
+    }
+
+    fn unban_expired_peers(&mut self) {}
+
+    fn disconnect_peer(&mut self, _peer_id: PeerId) -> bool {
+        todo!()
+    }
+
+    fn peer_reputations(&self) -> Vec<PeerReputation> {
+        todo!()
+    }
+
+    fn banned_peers(&self) -> Vec<(PeerId, Duration)> {
+        todo!()
+    }
 }
 
 #[derive(Clone)]
@@ -208,6 +255,7 @@ impl P2pDb for FakeDB {
 struct FakeBroadcast {
     pub peer_reports: mpsc::Sender<(FuelPeerId, AppScore, String)>,
     pub confirmation_gossip_broadcast: mpsc::Sender<P2PPreConfirmationGossipData>,
+    pub tx_gossip_broadcast: mpsc::Sender<TransactionGossipData>,
 }
 
 impl Broadcast for FakeBroadcast {
@@ -229,8 +277,9 @@ impl Broadcast for FakeBroadcast {
         todo!()
     }
 
-    fn tx_broadcast(&self, _transaction: TransactionGossipData) -> anyhow::Result<()> {
-        todo!()
+    fn tx_broadcast(&self, transaction: TransactionGossipData) -> anyhow::Result<()> {
+        self.tx_gossip_broadcast.try_send(transaction)?;
+        Ok(())
     }
 
     fn pre_confirmation_broadcast(
@@ -241,6 +290,10 @@ impl Broadcast for FakeBroadcast {
         Ok(())
     }
 
+    fn bft_message_broadcast(&self, _message: BftGossipData) -> anyhow::Result<()> {
+        todo!()
+    }
+
     fn new_tx_subscription_broadcast(&self, _peer_id: FuelPeerId) -> anyhow::Result<()> {
         todo!()
     }
@@ -272,6 +325,10 @@ async fn peer_heartbeat_reputation_checks__slow_heartbeat_sends_reports() {
     let p2p_service = FakeP2PService {
         peer_info,
         next_event_stream: Box::pin(futures::stream::pending()),
+        published_messages: mpsc::channel(100).0,
+        sent_requests: mpsc::channel(100).0,
+        message_reports: mpsc::channel(100).0,
+        peer_reports: mpsc::channel(100).0,
     };
     let (request_sender, request_receiver) = mpsc::channel(100);
 
@@ -279,6 +336,7 @@ async fn peer_heartbeat_reputation_checks__slow_heartbeat_sends_reports() {
     let broadcast = FakeBroadcast {
         peer_reports: report_sender,
         confirmation_gossip_broadcast: mpsc::channel(100).0,
+        tx_gossip_broadcast: mpsc::channel(100).0,
     };
 
     // Less than actual
@@ -312,6 +370,8 @@ async fn peer_heartbeat_reputation_checks__slow_heartbeat_sends_reports() {
         next_check_time: Instant::now(),
         heartbeat_peer_reputation_config: heartbeat_peer_reputation_config.clone(),
         cached_view: Arc::new(CachedView::new(100, false)),
+        tx_gossip_mode: TxGossipMode::Full,
+        tx_fetcher: TxFetcher::new(100),
     };
     let (watch_sender, watch_receiver) = tokio::sync::watch::channel(State::Started);
     let mut watcher = StateWatcher::from(watch_receiver);
@@ -366,6 +426,10 @@ async fn peer_heartbeat_reputation_checks__old_heartbeat_sends_reports() {
     let p2p_service = FakeP2PService {
         peer_info,
         next_event_stream: Box::pin(futures::stream::pending()),
+        published_messages: mpsc::channel(100).0,
+        sent_requests: mpsc::channel(100).0,
+        message_reports: mpsc::channel(100).0,
+        peer_reports: mpsc::channel(100).0,
     };
     let (request_sender, request_receiver) = mpsc::channel(100);
 
@@ -373,6 +437,7 @@ async fn peer_heartbeat_reputation_checks__old_heartbeat_sends_reports() {
     let broadcast = FakeBroadcast {
         peer_reports: report_sender,
         confirmation_gossip_broadcast: mpsc::channel(100).0,
+        tx_gossip_broadcast: mpsc::channel(100).0,
     };
 
     // Greater than actual
@@ -406,6 +471,8 @@ async fn peer_heartbeat_reputation_checks__old_heartbeat_sends_reports() {
         next_check_time: Instant::now(),
         heartbeat_peer_reputation_config: heartbeat_peer_reputation_config.clone(),
         cached_view: Arc::new(CachedView::new(100, false)),
+        tx_gossip_mode: TxGossipMode::Full,
+        tx_fetcher: TxFetcher::new(100),
     };
     let (watch_sender, watch_receiver) = tokio::sync::watch::channel(State::Started);
     let mut watcher = StateWatcher::from(watch_receiver);
@@ -458,6 +525,10 @@ async fn should_process_all_imported_block_under_infinite_events_from_p2p() {
     let p2p_service = FakeP2PService {
         peer_info: vec![],
         next_event_stream: infinite_event_stream,
+        published_messages: mpsc::channel(100).0,
+        sent_requests: mpsc::channel(100).0,
+        message_reports: mpsc::channel(100).0,
+        peer_reports: mpsc::channel(100).0,
     };
 
     // Initialization
@@ -465,6 +536,7 @@ async fn should_process_all_imported_block_under_infinite_events_from_p2p() {
     let broadcast = FakeBroadcast {
         peer_reports: mpsc::channel(100).0,
         confirmation_gossip_broadcast: mpsc::channel(100).0,
+        tx_gossip_broadcast: mpsc::channel(100).0,
     };
     let mut task = Task {
         chain_id: Default::default(),
@@ -486,6 +558,8 @@ async fn should_process_all_imported_block_under_infinite_events_from_p2p() {
         next_check_time: Instant::now(),
         heartbeat_peer_reputation_config: Default::default(),
         cached_view: Arc::new(CachedView::new(100, false)),
+        tx_gossip_mode: TxGossipMode::Full,
+        tx_fetcher: TxFetcher::new(100),
     };
     let mut watcher = StateWatcher::started();
     // End of initialization
@@ -524,11 +598,16 @@ async fn run__gossip_message_from_p2p_service_is_broadcasted__tx_preconfirmation
     let p2p_service = FakeP2PService {
         peer_info: vec![],
         next_event_stream: Box::pin(event_stream),
+        published_messages: mpsc::channel(100).0,
+        sent_requests: mpsc::channel(100).0,
+        message_reports: mpsc::channel(100).0,
+        peer_reports: mpsc::channel(100).0,
     };
     let (preconfirmations_sender, mut preconfirmations_receiver) = mpsc::channel(100);
     let broadcast = FakeBroadcast {
         peer_reports: mpsc::channel(100).0,
         confirmation_gossip_broadcast: preconfirmations_sender,
+        tx_gossip_broadcast: mpsc::channel(100).0,
     };
     let (request_sender, request_receiver) = mpsc::channel(100);
     let mut task = Task {
@@ -551,6 +630,8 @@ async fn run__gossip_message_from_p2p_service_is_broadcasted__tx_preconfirmation
         next_check_time: Instant::now(),
         heartbeat_peer_reputation_config: Default::default(),
         cached_view: Arc::new(CachedView::new(100, false)),
+        tx_gossip_mode: TxGossipMode::Full,
+        tx_fetcher: TxFetcher::new(100),
     };
 
     // when
@@ -568,3 +649,283 @@ async fn run__gossip_message_from_p2p_service_is_broadcasted__tx_preconfirmation
     };
     assert_eq!(expected, actual);
 }
+
+struct TxGossipTestContext {
+    task: Task<FakeP2PService, FakeDB, FakeBroadcast, FakeTxPool>,
+    published_messages: mpsc::Receiver<GossipsubBroadcastRequest>,
+    sent_requests: mpsc::Receiver<SentRequest>,
+    message_reports: mpsc::Receiver<(GossipsubMessageInfo, GossipsubMessageAcceptance)>,
+    peer_reports: mpsc::Receiver<(PeerId, AppScore)>,
+    tx_gossip_broadcast: mpsc::Receiver<TransactionGossipData>,
+}
+
+fn tx_gossip_test_context(
+    events: Vec<FuelP2PEvent>,
+    tx_gossip_mode: TxGossipMode,
+    max_pending_tx_fetches: usize,
+) -> TxGossipTestContext {
+    let (published_messages_sender, published_messages) = mpsc::channel(100);
+    let (sent_requests_sender, sent_requests) = mpsc::channel(100);
+    let (message_reports_sender, message_reports) = mpsc::channel(100);
+    let (peer_reports_sender, peer_reports) = mpsc::channel(100);
+    let (tx_gossip_broadcast_sender, tx_gossip_broadcast) = mpsc::channel(100);
+    let event_stream = futures::stream::iter(events).chain(futures::stream::pending());
+    let p2p_service = FakeP2PService {
+        peer_info: vec![],
+        next_event_stream: Box::pin(event_stream),
+        published_messages: published_messages_sender,
+        sent_requests: sent_requests_sender,
+        message_reports: message_reports_sender,
+        peer_reports: peer_reports_sender,
+    };
+    let broadcast = FakeBroadcast {
+        peer_reports: mpsc::channel(100).0,
+        confirmation_gossip_broadcast: mpsc::channel(100).0,
+        tx_gossip_broadcast: tx_gossip_broadcast_sender,
+    };
+    let (request_sender, request_receiver) = mpsc::channel(100);
+    let task = Task {
+        chain_id: Default::default(),
+        response_timeout: Default::default(),
+        p2p_service,
+        view_provider: FakeDB,
+        next_block_height: FakeBlockImporter.next_block_height(),
+        tx_pool: FakeTxPool,
+        request_receiver,
+        request_sender,
+        db_heavy_task_processor: SyncProcessor::new("Test", 1, 1).unwrap(),
+        tx_pool_heavy_task_processor: AsyncProcessor::new("Test", 1, 1).unwrap(),
+        broadcast,
+        max_headers_per_request: 0,
+        max_txs_per_request: 100,
+        heartbeat_check_interval: Duration::from_secs(10),
+        heartbeat_max_avg_interval: Default::default(),
+        heartbeat_max_time_since_last: Default::default(),
+        next_check_time: Instant::now().checked_add(Duration::from_secs(10)).unwrap(),
+        heartbeat_peer_reputation_config: Default::default(),
+        cached_view: Arc::new(CachedView::new(100, false)),
+        tx_gossip_mode,
+        tx_fetcher: TxFetcher::new(max_pending_tx_fetches),
+    };
+
+    TxGossipTestContext {
+        task,
+        published_messages,
+        sent_requests,
+        message_reports,
+        peer_reports,
+        tx_gossip_broadcast,
+    }
+}
+
+fn arb_tx(script_gas_limit: u64) -> Transaction {
+    TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(script_gas_limit)
+        .add_fee_input()
+        .finalize_as_transaction()
+}
+
+fn tx_announcement_event(tx: &Transaction, peer_id: PeerId) -> FuelP2PEvent {
+    let tx_id = tx.id(&Default::default());
+    FuelP2PEvent::GossipsubMessage {
+        peer_id,
+        message_id: tx_id.to_vec().into(),
+        topic_hash: TopicHash::from_raw(NEW_TX_ANNOUNCEMENT_GOSSIP_TOPIC),
+        message: GossipsubMessage::NewTxAnnouncement(tx_id),
+    }
+}
+
+fn respond_with_txs(on_response: ResponseSender, peer_id: PeerId, txs: Vec<Transaction>) {
+    let ResponseSender::TxPoolFullTransactions(channel) = on_response else {
+        panic!("Expected the request of full transactions");
+    };
+    let txs = txs
+        .into_iter()
+        .map(|tx| Some(NetworkableTransactionPool::Transaction(tx)))
+        .collect();
+    channel.send((peer_id, Ok(Ok(txs)))).unwrap();
+}
+
+fn respond_without_txs(on_response: ResponseSender, peer_id: PeerId) {
+    let ResponseSender::TxPoolFullTransactions(channel) = on_response else {
+        panic!("Expected the request of full transactions");
+    };
+    channel.send((peer_id, Ok(Ok(vec![None])))).unwrap();
+}
+
+#[tokio::test]
+async fn run__broadcast_transaction__announce_mode_publishes_only_tx_id() {
+    // given
+    let mut ctx = tx_gossip_test_context(vec![], TxGossipMode::Announce, 10);
+    let tx = arb_tx(1);
+    let tx_id = tx.id(&Default::default());
+    ctx.task
+        .request_sender
+        .try_send(TaskRequest::BroadcastTransaction(Arc::new(tx)))
+        .unwrap();
+
+    // when
+    let mut watcher = StateWatcher::started();
+    let _ = ctx.task.run(&mut watcher).await;
+
+    // then
+    let published = ctx.published_messages.try_recv().unwrap();
+    let GossipsubBroadcastRequest::NewTxAnnouncement(published_tx_id) = published else {
+        panic!("Expected the announcement of the transaction");
+    };
+    assert_eq!(published_tx_id, tx_id);
+}
+
+#[tokio::test]
+async fn run__tx_announcement__unknown_tx_is_fetched_from_announcing_peer() {
+    // given
+    let peer_id = PeerId::random();
+    let tx = arb_tx(1);
+    let tx_id = tx.id(&Default::default());
+    let event = tx_announcement_event(&tx, peer_id);
+    let mut ctx = tx_gossip_test_context(vec![event], TxGossipMode::Announce, 10);
+    let mut watcher = StateWatcher::started();
+
+    // when
+    let _ = ctx.task.run(&mut watcher).await;
+    let (requested_peer, request, on_response) = ctx.sent_requests.try_recv().unwrap();
+    respond_with_txs(on_response, peer_id, vec![tx.clone()]);
+    let _ = ctx.task.run(&mut watcher).await;
+
+    // then
+    assert_eq!(requested_peer, Some(peer_id));
+    assert!(
+        matches!(request, RequestMessage::TxPoolFullTransactions(ids) if ids == vec![tx_id])
+    );
+    let gossiped = ctx.tx_gossip_broadcast.try_recv().unwrap();
+    assert_eq!(gossiped.data, Some(tx));
+    assert_eq!(gossiped.message_id, tx_id.to_vec());
+    assert_eq!(gossiped.peer_id, FuelPeerId::from(peer_id.to_bytes()));
+}
+
+#[tokio::test]
+async fn run__tx_announcement__known_tx_is_ignored() {
+    // given
+    let peer_id = PeerId::random();
+    let tx = arb_tx(1);
+    let full_tx_event = FuelP2PEvent::GossipsubMessage {
+        peer_id,
+        message_id: vec![1, 2, 3].into(),
+        topic_hash: TopicHash::from_raw(NEW_TX_GOSSIP_TOPIC),
+        message: GossipsubMessage::NewTx(tx.clone()),
+    };
+    let announcement_event = tx_announcement_event(&tx, PeerId::random());
+    let mut ctx = tx_gossip_test_context(
+        vec![full_tx_event, announcement_event],
+        TxGossipMode::Announce,
+        10,
+    );
+    let mut watcher = StateWatcher::started();
+
+    // when
+    let _ = ctx.task.run(&mut watcher).await;
+    let _ = ctx.task.run(&mut watcher).await;
+
+    // then
+    assert!(ctx.sent_requests.try_recv().is_err());
+    let (_, acceptance) = ctx.message_reports.try_recv().unwrap();
+    assert_eq!(acceptance, GossipsubMessageAcceptance::Ignore);
+}
+
+#[tokio::test]
+async fn run__tx_announcement__ignored_when_max_pending_fetches_reached() {
+    // given
+    let first_event = tx_announcement_event(&arb_tx(1), PeerId::random());
+    let second_event = tx_announcement_event(&arb_tx(2), PeerId::random());
+    let mut ctx = tx_gossip_test_context(
+        vec![first_event, second_event],
+        TxGossipMode::Announce,
+        1,
+    );
+    let mut watcher = StateWatcher::started();
+
+    // when
+    let _ = ctx.task.run(&mut watcher).await;
+    let _ = ctx.task.run(&mut watcher).await;
+
+    // then
+    assert!(ctx.sent_requests.try_recv().is_ok());
+    assert!(ctx.sent_requests.try_recv().is_err());
+    let (_, acceptance) = ctx.message_reports.try_recv().unwrap();
+    assert_eq!(acceptance, GossipsubMessageAcceptance::Ignore);
+}
+
+#[tokio::test]
+async fn run__tx_announcement__different_tx_in_response_is_rejected() {
+    // given
+    let peer_id = PeerId::random();
+    let event = tx_announcement_event(&arb_tx(1), peer_id);
+    let mut ctx = tx_gossip_test_context(vec![event], TxGossipMode::Announce, 10);
+    let mut watcher = StateWatcher::started();
+
+    // when
+    let _ = ctx.task.run(&mut watcher).await;
+    let (_, _, on_response) = ctx.sent_requests.try_recv().unwrap();
+    respond_with_txs(on_response, peer_id, vec![arb_tx(2)]);
+    let _ = ctx.task.run(&mut watcher).await;
+
+    // then
+    assert!(ctx.tx_gossip_broadcast.try_recv().is_err());
+    let (_, acceptance) = ctx.message_reports.try_recv().unwrap();
+    assert_eq!(acceptance, GossipsubMessageAcceptance::Reject);
+}
+
+#[tokio::test]
+async fn run__tx_announcement__missing_tx_in_response_penalizes_peer() {
+    // given
+    let peer_id = PeerId::random();
+    let event = tx_announcement_event(&arb_tx(1), peer_id);
+    let mut ctx = tx_gossip_test_context(vec![event], TxGossipMode::Announce, 10);
+    let mut watcher = StateWatcher::started();
+
+    // when
+    let _ = ctx.task.run(&mut watcher).await;
+    let (_, _, on_response) = ctx.sent_requests.try_recv().unwrap();
+    respond_without_txs(on_response, peer_id);
+    let _ = ctx.task.run(&mut watcher).await;
+
+    // then
+    assert!(ctx.tx_gossip_broadcast.try_recv().is_err());
+    let (_, acceptance) = ctx.message_reports.try_recv().unwrap();
+    assert_eq!(acceptance, GossipsubMessageAcceptance::Ignore);
+    let (penalized_peer, score) = ctx.peer_reports.try_recv().unwrap();
+    assert_eq!(penalized_peer, peer_id);
+    assert!(score < 0.);
+}
+
+#[tokio::test]
+async fn run__tx_announcement__fetch_is_retried_with_another_announcer() {
+    // given
+    let tx = arb_tx(1);
+    let first_peer = PeerId::random();
+    let second_peer = PeerId::random();
+    let events = vec![
+        tx_announcement_event(&tx, first_peer),
+        tx_announcement_event(&tx, second_peer),
+    ];
+    let mut ctx = tx_gossip_test_context(events, TxGossipMode::Announce, 10);
+    let mut watcher = StateWatcher::started();
+    let _ = ctx.task.run(&mut watcher).await;
+    let _ = ctx.task.run(&mut watcher).await;
+    let (requested_peer, _, on_response) = ctx.sent_requests.try_recv().unwrap();
+    assert_eq!(requested_peer, Some(first_peer));
+    assert!(ctx.sent_requests.try_recv().is_err());
+
+    // when
+    respond_without_txs(on_response, first_peer);
+    let _ = ctx.task.run(&mut watcher).await;
+    let (requested_peer, _, on_response) = ctx.sent_requests.try_recv().unwrap();
+    respond_with_txs(on_response, second_peer, vec![tx.clone()]);
+    let _ = ctx.task.run(&mut watcher).await;
+
+    // then
+    assert_eq!(requested_peer, Some(second_peer));
+    let gossiped = ctx.tx_gossip_broadcast.try_recv().unwrap();
+    assert_eq!(gossiped.data, Some(tx));
+    assert_eq!(gossiped.peer_id, FuelPeerId::from(second_peer.to_bytes()));
+}
@@ -0,0 +1,215 @@
+//! Tracks the transactions announced by peers and fetches the unknown ones.
+
+use crate::request_response::messages::{
+    ResponseError,
+    ResponseMessageErrorCode,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
+    services::p2p::{
+        GossipsubMessageInfo,
+        NetworkableTransactionPool,
+        peer_reputation::AppScore,
+    },
+};
+use futures::{
+    StreamExt,
+    future::BoxFuture,
+    stream::FuturesUnordered,
+};
+use libp2p::PeerId;
+use std::collections::{
+    HashMap,
+    HashSet,
+    VecDeque,
+};
+use tokio::sync::oneshot;
+
+/// The number of recently seen transaction ids remembered by the node.
+const KNOWN_TXS_CAPACITY: usize = 50_000;
+
+/// The number of other peers that announced the transaction being fetched,
+/// remembered to retry the fetching with them.
+const MAX_ALTERNATIVE_ANNOUNCERS: usize = 4;
+
+/// The penalty of the peer that announced the transaction
+/// but didn't return it when requested.
+pub(crate) const MISSING_ANNOUNCED_TX_PENALTY: AppScore = -5.;
+
+type FullTransactionsResponse = (
+    PeerId,
+    Result<
+        Result<Vec<Option<NetworkableTransactionPool>>, ResponseMessageErrorCode>,
+        ResponseError,
+    >,
+);
+
+/// The outcome of the request of the announced transaction.
+pub(crate) enum FetchResult {
+    /// The peer returned the transaction.
+    Fetched(Box<Transaction>),
+    /// The peer responded, but without the transaction.
+    Missing,
+    /// The request failed, e.g. timed out or the peer disconnected.
+    Failed,
+}
+
+/// The result of the fetching of an announced transaction.
+pub(crate) struct FetchedTx {
+    /// The id of the announced transaction.
+    pub tx_id: TxId,
+    /// The peer the transaction was requested from.
+    pub peer_id: PeerId,
+    /// The announcement that triggered the fetching.
+    pub message_info: GossipsubMessageInfo,
+    /// The outcome of the request.
+    pub result: FetchResult,
+}
+
+pub(crate) struct TxFetcher {
+    max_pending_fetches: usize,
+    known_txs: KnownTxs,
+    /// Transactions being fetched along with the announcements of other peers
+    /// that can be used to retry the fetching.
+    pending_fetches: HashMap<TxId, VecDeque<(PeerId, GossipsubMessageInfo)>>,
+    fetches: FuturesUnordered<BoxFuture<'static, FetchedTx>>,
+}
+
+impl TxFetcher {
+    pub fn new(max_pending_fetches: usize) -> Self {
+        Self {
+            max_pending_fetches,
+            known_txs: KnownTxs::new(KNOWN_TXS_CAPACITY),
+            pending_fetches: HashMap::new(),
+            fetches: FuturesUnordered::new(),
+        }
+    }
+
+    /// Remembers the transaction, so its announcements are not fetched.
+    pub fn mark_known(&mut self, tx_id: TxId) {
+        self.known_txs.insert(tx_id);
+    }
+
+    /// Returns `true` if the announced transaction is unknown, isn't being fetched,
+    /// and the limit of pending fetches is not reached.
+    pub fn should_fetch(&self, tx_id: &TxId) -> bool {
+        !self.known_txs.contains(tx_id)
+            && !self.pending_fetches.contains_key(tx_id)
+            && self.pending_fetches.len() < self.max_pending_fetches
+    }
+
+    /// Remembers the announcement of the transaction being fetched, so the fetching
+    /// can be retried with its peer. Returns the announcement back if the transaction
+    /// isn't being fetched or enough announcements are remembered already.
+    pub fn add_announcer(
+        &mut self,
+        tx_id: &TxId,
+        peer_id: PeerId,
+        message_info: GossipsubMessageInfo,
+    ) -> Result<(), GossipsubMessageInfo> {
+        match self.pending_fetches.get_mut(tx_id) {
+            Some(announcers) if announcers.len() < MAX_ALTERNATIVE_ANNOUNCERS => {
+                announcers.push_back((peer_id, message_info));
+                Ok(())
+            }
+            _ => Err(message_info),
+        }
+    }
+
+    pub fn start_fetch(
+        &mut self,
+        tx_id: TxId,
+        peer_id: PeerId,
+        message_info: GossipsubMessageInfo,
+        response: oneshot::Receiver<FullTransactionsResponse>,
+    ) {
+        self.pending_fetches.entry(tx_id).or_default();
+        self.fetches.push(Box::pin(async move {
+            let result = match response.await {
+                Ok((_, Ok(Ok(txs)))) => txs
+                    .into_iter()
+                    .next()
+                    .flatten()
+                    .and_then(|tx| Transaction::try_from(tx).ok())
+                    .map_or(FetchResult::Missing, |tx| {
+                        FetchResult::Fetched(Box::new(tx))
+                    }),
+                _ => FetchResult::Failed,
+            };
+            FetchedTx {
+                tx_id,
+                peer_id,
+                message_info,
+                result,
+            }
+        }));
+    }
+
+    /// Returns the next peer that announced the transaction to retry the fetching.
+    /// The fetching is finished if there are no more announcers.
+    pub fn next_announcer(
+        &mut self,
+        tx_id: &TxId,
+    ) -> Option<(PeerId, GossipsubMessageInfo)> {
+        let next = self.pending_fetches.get_mut(tx_id)?.pop_front();
+        if next.is_none() {
+            self.pending_fetches.remove(tx_id);
+        }
+        next
+    }
+
+    /// Finishes the fetching of the transaction and returns
+    /// the announcements of other peers that weren't used.
+    pub fn finish_fetch(&mut self, tx_id: &TxId) -> Vec<GossipsubMessageInfo> {
+        self.pending_fetches
+            .remove(tx_id)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(_, message_info)| message_info)
+            .collect()
+    }
+
+    /// Waits for the next finished request. Never resolves if there are no pending fetches.
+    pub async fn next_fetched(&mut self) -> FetchedTx {
+        match self.fetches.next().await {
+            Some(fetched) => fetched,
+            None => futures::future::pending().await,
+        }
+    }
+}
+
+/// The bounded set of transaction ids, that forgets the oldest ids first.
+struct KnownTxs {
+    capacity: usize,
+    ids: HashSet<TxId>,
+    order: VecDeque<TxId>,
+}
+
+impl KnownTxs {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            ids: HashSet::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    fn contains(&self, tx_id: &TxId) -> bool {
+        self.ids.contains(tx_id)
+    }
+
+    fn insert(&mut self, tx_id: TxId) {
+        if !self.ids.insert(tx_id) {
+            return;
+        }
+        self.order.push_back(tx_id);
+        if self.order.len() > self.capacity
+            && let Some(oldest) = self.order.pop_front()
+        {
+            self.ids.remove(&oldest);
+        }
+    }
+}
@@ -1,29 +1,61 @@
 use crate::{
     config::Config,
     ports::TransactionsSource,
+    scheduler::{
+        BlockContext,
+        Scheduler,
+    },
+};
+use fuel_core_executor::{
+    executor::{
+        ExecutionInstance,
+        ExecutionOptions,
+        OnceTransactionsSource,
+        TimeoutOnlyTxWaiter,
+        TransparentPreconfirmationSender,
+    },
+    ports::RelayerPort,
+};
+use fuel_core_storage::{
+    StorageAsRef,
+    column::Column,
+    kv_store::KeyValueInspect,
+    tables::ConsensusParametersVersions,
+    transactional::{
+        AtomicView,
+        Changes,
+        ReadTransaction,
+    },
 };
-use fuel_core_storage::transactional::Changes;
 use fuel_core_types::{
-    blockchain::block::Block,
-    fuel_tx::Transaction,
+    blockchain::{
+        block::Block,
+        header::{
+            ConsensusParametersVersion,
+            StateTransitionBytecodeVersion,
+        },
+    },
+    fuel_tx::{
+        ConsensusParameters,
+        Transaction,
+    },
     services::{
         Uncommitted,
         block_producer::Components,
         executor::{
+            Error as ExecutorError,
             ExecutionResult,
             Result as ExecutorResult,
             TransactionExecutionStatus,
             ValidationResult,
+            memory::MemoryPool,
         },
     },
 };
 use fuel_core_upgradable_executor::executor::Executor as UpgradableExecutor;
+use futures::FutureExt;
 use std::{
     num::NonZeroUsize,
-    sync::{
-        Arc,
-        RwLock,
-    },
     time::Duration,
 };
 use tokio::runtime::Runtime;
@@ -36,12 +68,20 @@ const RUNTIME_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
 use fuel_core_upgradable_executor::error::UpgradableError;
 
 #[cfg(feature = "wasm-executor")]
-use fuel_core_types::fuel_merkle::common::Bytes32;
+use fuel_core_storage::transactional::HistoricalView;
+
+#[cfg(feature = "wasm-executor")]
+use fuel_core_types::{
+    fuel_tx::Bytes32,
+    fuel_types::BlockHeight,
+};
 
 pub struct Executor<S, R> {
-    _executor: Arc<RwLock<UpgradableExecutor<S, R>>>,
+    executor: UpgradableExecutor<S, R>,
     runtime: Option<Runtime>,
-    _number_of_cores: NonZeroUsize,
+    number_of_cores: NonZeroUsize,
+    /// Pool of VM memory instances reused by the workers.
+    memory_pool: MemoryPool,
 }
 
 // Shutdown the tokio runtime to avoid panic if executor is already
@@ -88,47 +128,197 @@ impl<S, R> Executor<S, R> {
         let number_of_cores = config.number_of_cores;
 
         Self {
-            _executor: Arc::new(RwLock::new(executor)),
+            executor,
             runtime: Some(runtime),
-            _number_of_cores: number_of_cores,
+            number_of_cores,
+            memory_pool: Default::default(),
         }
     }
 }
 
-impl<S, R> Executor<S, R> {
+impl<S, R> Executor<S, R>
+where
+    S: AtomicView,
+    S::LatestView: KeyValueInspect<Column = Column> + Send + Sync + 'static,
+    R: RelayerPort + Clone + Send + Sync + 'static,
+{
     /// Produces the block and returns the result of the execution without committing the changes.
+    ///
+    /// Transactions that don't use the same contracts are executed concurrently
+    /// on `number_of_cores` threads. The result is the same as the result of the
+    /// sequential execution of the transactions in the order of the block.
     pub async fn produce_without_commit_with_source<TxSource>(
         &self,
-        _components: Components<TxSource>,
+        components: Components<TxSource>,
     ) -> ExecutorResult<Uncommitted<ExecutionResult, Changes>>
     where
         TxSource: TransactionsSource + Send + Sync + 'static,
     {
-        unimplemented!("Not implemented yet");
+        let Components {
+            header_to_produce,
+            transactions_source,
+            coinbase_recipient,
+            gas_price,
+        } = components;
+        self.ensure_native_version(header_to_produce.state_transition_bytecode_version)?;
+
+        let view = self.executor.storage_view_provider.latest_view()?;
+        let consensus_params = get_consensus_parameters(
+            &view,
+            header_to_produce.consensus_parameters_version,
+        )?;
+        let context = BlockContext {
+            relayer: self.executor.relayer_view_provider.clone(),
+            options: self.executor.config.as_ref().into(),
+            consensus_params,
+            header: header_to_produce,
+            coinbase_recipient,
+            gas_price,
+            memory_pool: self.memory_pool.clone(),
+        };
+
+        let runtime = self.runtime()?.handle().clone();
+        let scheduler = Scheduler::new(
+            context,
+            view,
+            transactions_source,
+            self.number_of_cores,
+            runtime.clone(),
+        );
+
+        runtime
+            .spawn_blocking(move || scheduler.run())
+            .await
+            .map_err(|e| {
+                ExecutorError::Other(format!("The block production panicked: {e}"))
+            })?
     }
 
     pub fn validate(
         &self,
-        _block: &Block,
+        block: &Block,
     ) -> ExecutorResult<Uncommitted<ValidationResult, Changes>> {
-        unimplemented!("Not implemented yet");
-    }
+        self.ensure_native_version(block.header().state_transition_bytecode_version())?;
+        let view = self.executor.storage_view_provider.latest_view()?;
 
-    #[cfg(feature = "wasm-executor")]
-    pub fn validate_uploaded_wasm(
-        &self,
-        _wasm_root: &Bytes32,
-    ) -> Result<(), UpgradableError> {
-        unimplemented!("Not implemented yet");
+        ExecutionInstance::new(
+            self.executor.relayer_view_provider.clone(),
+            view,
+            self.executor.config.as_ref().into(),
+            self.memory_pool.take_raw(),
+        )
+        .validate_without_commit(block)
     }
 
     /// Executes the block and returns the result of the execution without committing
     /// the changes in the dry run mode.
     pub fn dry_run(
         &self,
-        _component: Components<Vec<Transaction>>,
-        _utxo_validation: Option<bool>,
+        component: Components<Vec<Transaction>>,
+        utxo_validation: Option<bool>,
     ) -> ExecutorResult<Vec<TransactionExecutionStatus>> {
-        unimplemented!("Not implemented yet");
+        self.ensure_native_version(
+            component
+                .header_to_produce
+                .state_transition_bytecode_version,
+        )?;
+        let view = self.executor.storage_view_provider.latest_view()?;
+        let options = ExecutionOptions {
+            forbid_fake_coins: utxo_validation
+                .unwrap_or(self.executor.config.forbid_fake_coins_default),
+            allow_syscall: self.executor.config.allow_syscall,
+        };
+        let component = Components {
+            header_to_produce: component.header_to_produce,
+            transactions_source: OnceTransactionsSource::new(
+                component.transactions_source,
+            ),
+            coinbase_recipient: Default::default(),
+            gas_price: component.gas_price,
+        };
+
+        let ExecutionResult {
+            skipped_transactions,
+            tx_status,
+            ..
+        } = ExecutionInstance::new(
+            self.executor.relayer_view_provider.clone(),
+            view,
+            options,
+            self.memory_pool.take_raw(),
+        )
+        .produce_without_commit(
+            component,
+            true,
+            TimeoutOnlyTxWaiter,
+            TransparentPreconfirmationSender,
+        )
+        .now_or_never()
+        .ok_or_else(|| {
+            ExecutorError::Other(
+                "Impossible to resolve the executor's future immediately".to_string(),
+            )
+        })??
+        .into_result();
+
+        // If any of the transactions fails, return an error.
+        if let Some((_, err)) = skipped_transactions.into_iter().next() {
+            return Err(err);
+        }
+
+        Ok(tx_status)
+    }
+
+    fn ensure_native_version(
+        &self,
+        version: StateTransitionBytecodeVersion,
+    ) -> ExecutorResult<()> {
+        let native_executor_version = self.executor.native_executor_version();
+        if version == native_executor_version {
+            Ok(())
+        } else {
+            Err(ExecutorError::Other(format!(
+                "Not supported version `{version}`. Expected version is `{native_executor_version}`"
+            )))
+        }
+    }
+
+    fn runtime(&self) -> ExecutorResult<&Runtime> {
+        self.runtime.as_ref().ok_or_else(|| {
+            ExecutorError::Other("The executor runtime is shut down".to_string())
+        })
+    }
+}
+
+#[cfg(feature = "wasm-executor")]
+impl<S, R> Executor<S, R>
+where
+    S: HistoricalView<Height = BlockHeight>,
+    S::LatestView: KeyValueInspect<Column = Column> + Send + Sync + 'static,
+    S::ViewAtHeight: KeyValueInspect<Column = Column> + Send + Sync + 'static,
+    R: AtomicView,
+    R::LatestView: RelayerPort + Send + Sync + 'static,
+{
+    pub fn validate_uploaded_wasm(
+        &self,
+        wasm_root: &Bytes32,
+    ) -> Result<(), UpgradableError> {
+        self.executor.validate_uploaded_wasm(wasm_root)
     }
 }
+
+fn get_consensus_parameters<View>(
+    view: &View,
+    version: ConsensusParametersVersion,
+) -> ExecutorResult<ConsensusParameters>
+where
+    View: KeyValueInspect<Column = Column>,
+{
+    let consensus_params = view
+        .read_transaction()
+        .storage::<ConsensusParametersVersions>()
+        .get(&version)?
+        .ok_or(ExecutorError::ConsensusParametersNotFound(version))?
+        .into_owned();
+    Ok(consensus_params)
+}
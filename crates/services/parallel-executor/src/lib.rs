@@ -3,5 +3,8 @@ pub mod executor;
 pub mod once_transaction_source;
 pub mod ports;
 
+mod scheduler;
+mod storage_keys;
+
 #[cfg(test)]
 mod tests;
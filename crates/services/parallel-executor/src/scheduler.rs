@@ -0,0 +1,456 @@
+//! The scheduler splits the block production into batches of transactions and
+//! executes them concurrently.
+//!
+//! Each batch is fetched from the [`TransactionsSource`] with a [`Filter`] that
+//! excludes contracts used by the batches in flight, and is executed on top of
+//! the snapshot of the block state at the moment of fetching. Batches are merged
+//! into the block in the order they were fetched. Before merging, the scheduler
+//! checks that the batch didn't read or write any key modified by the batches
+//! merged after its snapshot was taken. If it did, the batch is re-executed
+//! sequentially on top of the up-to-date state. That way, the final result is the
+//! same as if all transactions were executed one by one in the block order.
+
+use crate::{
+    ports::{
+        Filter,
+        TransactionFiltered,
+        TransactionsSource,
+    },
+    storage_keys::StorageKeys,
+};
+use fuel_core_executor::{
+    executor::{
+        BlockExecutor,
+        ExecutionData,
+        ExecutionOptions,
+        TimeoutOnlyTxWaiter,
+        TransparentPreconfirmationSender,
+        max_tx_count,
+    },
+    ports::{
+        MaybeCheckedTransaction,
+        RelayerPort,
+    },
+    storage_access_recorder::StorageAccessRecorder,
+};
+use fuel_core_storage::{
+    column::Column,
+    kv_store::KeyValueInspect,
+    transactional::{
+        Changes,
+        ConflictPolicy,
+        Modifiable,
+        StorageTransaction,
+    },
+};
+use fuel_core_types::{
+    blockchain::{
+        block::PartialFuelBlock,
+        header::PartialBlockHeader,
+        transaction::TransactionExt,
+    },
+    fuel_tx::{
+        Chargeable,
+        ConsensusParameters,
+        ContractId,
+        Input,
+        Output,
+        Transaction,
+        Word,
+    },
+    fuel_vm::checked_transaction::CheckedTransaction,
+    services::{
+        Uncommitted,
+        executor::{
+            Error as ExecutorError,
+            ExecutionResult,
+            Result as ExecutorResult,
+            memory::MemoryPool,
+        },
+    },
+};
+use std::{
+    collections::{
+        HashSet,
+        VecDeque,
+    },
+    num::NonZeroUsize,
+    sync::{
+        Arc,
+        mpsc,
+    },
+};
+use tokio::runtime::Handle;
+
+/// The block-level parameters shared by all batches of the block.
+pub(crate) struct BlockContext<R> {
+    pub relayer: R,
+    pub options: ExecutionOptions,
+    pub consensus_params: ConsensusParameters,
+    pub header: PartialBlockHeader,
+    pub coinbase_recipient: ContractId,
+    pub gas_price: Word,
+    pub memory_pool: MemoryPool,
+}
+
+impl<R> BlockContext<R>
+where
+    R: RelayerPort + Clone,
+{
+    fn block_executor(
+        &self,
+    ) -> ExecutorResult<
+        BlockExecutor<R, TimeoutOnlyTxWaiter, TransparentPreconfirmationSender>,
+    > {
+        BlockExecutor::new(
+            self.relayer.clone(),
+            self.options.clone(),
+            self.consensus_params.clone(),
+            TimeoutOnlyTxWaiter,
+            TransparentPreconfirmationSender,
+            false,
+        )
+    }
+
+    /// Executes the `transactions` on top of the `storage`, placing them in the
+    /// block starting at the `tx_offset` position.
+    fn execute_batch<S>(
+        &self,
+        storage: S,
+        tx_offset: u16,
+        transactions: Vec<CheckedTransaction>,
+    ) -> ExecutorResult<ExecutedBatch>
+    where
+        S: KeyValueInspect<Column = Column>,
+    {
+        let executor = self.block_executor()?;
+        let mut memory = self.memory_pool.take_raw();
+        let mut block = PartialFuelBlock::new(self.header, vec![]);
+        let mut data = ExecutionData::with_tx_offset(tx_offset);
+        let version = self.header.consensus_parameters_version;
+        let transactions = transactions
+            .into_iter()
+            .map(|tx| MaybeCheckedTransaction::CheckedTransaction(tx, version))
+            .collect();
+
+        let recorder =
+            StorageAccessRecorder::with_record(storage, StorageKeys::default());
+        let mut storage_tx = StorageTransaction::transaction(
+            &recorder,
+            ConflictPolicy::Overwrite,
+            Changes::default(),
+        );
+        executor.execute_l2_transactions(
+            &mut block,
+            transactions,
+            self.coinbase_recipient,
+            self.gas_price,
+            &mut storage_tx,
+            &mut data,
+            memory.as_mut(),
+        )?;
+        let changes = storage_tx.into_changes();
+        let (_, reads) = recorder.into_inner();
+
+        Ok(ExecutedBatch {
+            transactions: block.transactions,
+            data,
+            changes,
+            reads,
+        })
+    }
+}
+
+/// The result of the batch execution that is not merged into the block yet.
+struct ExecutedBatch {
+    transactions: Vec<Transaction>,
+    data: ExecutionData,
+    changes: Changes,
+    reads: StorageKeys,
+}
+
+impl ExecutedBatch {
+    /// Returns `true` if the batch accessed any key from the `modified` set.
+    fn conflicts_with(&self, modified: &StorageKeys) -> bool {
+        self.reads.intersects(modified)
+            || StorageKeys::from_changes(&self.changes).intersects(modified)
+    }
+}
+
+/// The batch fetched from the transactions source.
+struct Batch {
+    id: usize,
+    transactions: Vec<CheckedTransaction>,
+    contracts: HashSet<ContractId>,
+    tx_offset: u16,
+    /// The number of batches merged into the block state at the moment of
+    /// execution start.
+    snapshot_version: usize,
+    result: Option<ExecutorResult<ExecutedBatch>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SourceState {
+    /// The source may have more transactions.
+    Open,
+    /// The source has transactions, but they use contracts of the batches in flight.
+    Blocked,
+    /// The source has no more transactions for this block.
+    Exhausted,
+}
+
+pub(crate) struct Scheduler<R, View, TxSource> {
+    context: Arc<BlockContext<R>>,
+    view: Arc<View>,
+    transactions_source: TxSource,
+    number_of_workers: NonZeroUsize,
+    runtime: Handle,
+}
+
+impl<R, View, TxSource> Scheduler<R, View, TxSource>
+where
+    R: RelayerPort + Clone + Send + Sync + 'static,
+    View: KeyValueInspect<Column = Column> + Send + Sync + 'static,
+    TxSource: TransactionsSource,
+{
+    pub fn new(
+        context: BlockContext<R>,
+        view: View,
+        transactions_source: TxSource,
+        number_of_workers: NonZeroUsize,
+        runtime: Handle,
+    ) -> Self {
+        Self {
+            context: Arc::new(context),
+            view: Arc::new(view),
+            transactions_source,
+            number_of_workers,
+            runtime,
+        }
+    }
+
+    /// Produces the block. The function blocks the current thread until all
+    /// batches are executed.
+    pub fn run(mut self) -> ExecutorResult<Uncommitted<ExecutionResult, Changes>> {
+        let context = self.context.clone();
+        let mut block_executor = context.block_executor()?;
+        let mut memory = context.memory_pool.take_raw();
+        let mut block = PartialFuelBlock::new(context.header, vec![]);
+        let mut data = ExecutionData::new();
+        let mut block_state = StorageTransaction::transaction(
+            self.view.clone(),
+            ConflictPolicy::Overwrite,
+            Changes::default(),
+        );
+
+        block_executor.execute_l1_transactions(
+            &mut block,
+            context.coinbase_recipient,
+            &mut block_state,
+            &mut data,
+            memory.as_mut(),
+        )?;
+
+        let block_gas_limit = context.consensus_params.block_gas_limit();
+        let block_transaction_size_limit: u32 = context
+            .consensus_params
+            .block_transaction_size_limit()
+            .try_into()
+            .unwrap_or(u32::MAX);
+        // The scheduler reserves `max_gas` of each fetched transaction, so the
+        // sequential execution of the same transactions never runs out of gas.
+        let mut reserved_gas = data.used_gas();
+        let mut reserved_size = data.used_size();
+        let mut reserved_tx_count = data.tx_count();
+
+        let (sender, receiver) = mpsc::channel();
+        let mut in_flight = VecDeque::<Batch>::new();
+        let mut merged_writes = Vec::<StorageKeys>::new();
+        let mut source_state = SourceState::Open;
+        let mut next_batch_id = 0usize;
+
+        loop {
+            while source_state == SourceState::Open
+                && in_flight.len() < self.number_of_workers.get()
+            {
+                let remaining_gas = block_gas_limit.saturating_sub(reserved_gas);
+                let remaining_tx_count = max_tx_count().saturating_sub(reserved_tx_count);
+                let remaining_size =
+                    block_transaction_size_limit.saturating_sub(reserved_size);
+                if remaining_gas == 0 || remaining_tx_count == 0 || remaining_size == 0 {
+                    source_state = SourceState::Exhausted;
+                    break;
+                }
+
+                let excluded_contract_ids = in_flight
+                    .iter()
+                    .flat_map(|batch| batch.contracts.iter().copied())
+                    .collect();
+                let response = self.transactions_source.get_executable_transactions(
+                    remaining_gas,
+                    remaining_tx_count,
+                    remaining_size,
+                    Filter::new(excluded_contract_ids),
+                );
+
+                if response.transactions.is_empty() {
+                    source_state = match response.filtered {
+                        TransactionFiltered::Filtered => SourceState::Blocked,
+                        TransactionFiltered::NotFiltered => SourceState::Exhausted,
+                    };
+                    break;
+                }
+
+                let mut transactions = response.transactions;
+                transactions.truncate(remaining_tx_count as usize);
+
+                let mut contracts = HashSet::new();
+                for tx in &transactions {
+                    contracts.extend(used_contracts(tx));
+                    reserved_gas = reserved_gas
+                        .saturating_add(tx.max_gas(&context.consensus_params)?);
+                    reserved_size = reserved_size.saturating_add(metered_bytes_size(tx));
+                }
+                let tx_offset = in_flight
+                    .iter()
+                    .map(|batch| batch.transactions.len())
+                    .sum::<usize>()
+                    .saturating_add(data.tx_count() as usize);
+                let tx_offset = u16::try_from(tx_offset)
+                    .map_err(|_| ExecutorError::TooManyTransactions)?;
+                let batch_len = u16::try_from(transactions.len())
+                    .map_err(|_| ExecutorError::TooManyTransactions)?;
+                reserved_tx_count = reserved_tx_count.saturating_add(batch_len);
+
+                let id = next_batch_id;
+                next_batch_id = next_batch_id.saturating_add(1);
+
+                let snapshot = StorageTransaction::transaction(
+                    self.view.clone(),
+                    ConflictPolicy::Overwrite,
+                    block_state.changes().clone(),
+                );
+                let worker_transactions = transactions.clone();
+                let worker_context = context.clone();
+                let worker_sender = sender.clone();
+                self.runtime.spawn_blocking(move || {
+                    let result = worker_context.execute_batch(
+                        snapshot,
+                        tx_offset,
+                        worker_transactions,
+                    );
+                    // The scheduler may stop waiting for the result if another batch failed.
+                    let _ = worker_sender.send((id, result));
+                });
+
+                in_flight.push_back(Batch {
+                    id,
+                    transactions,
+                    contracts,
+                    tx_offset,
+                    snapshot_version: merged_writes.len(),
+                    result: None,
+                });
+            }
+
+            if in_flight.is_empty() {
+                // Without batches in flight, the filter is empty, so the source
+                // can't have filtered transactions anymore.
+                break;
+            }
+
+            let (id, result) = receiver.recv().map_err(|_| {
+                ExecutorError::Other(
+                    "The batch executor stopped unexpectedly".to_string(),
+                )
+            })?;
+            if let Some(batch) = in_flight.iter_mut().find(|batch| batch.id == id) {
+                batch.result = Some(result);
+            }
+
+            while in_flight
+                .front()
+                .is_some_and(|batch| batch.result.is_some())
+            {
+                let batch = in_flight.pop_front().expect("Checked above; qed");
+                let Batch {
+                    transactions,
+                    tx_offset,
+                    snapshot_version,
+                    result,
+                    ..
+                } = batch;
+                let mut executed = result.expect("Checked above; qed")?;
+
+                let mut modified_since_snapshot = StorageKeys::default();
+                for writes in merged_writes.iter().skip(snapshot_version) {
+                    modified_since_snapshot.extend(writes.clone());
+                }
+                // Previous batches may skip some transactions, which shifts the
+                // position of the batch in the block.
+                if tx_offset != data.tx_count()
+                    || executed.conflicts_with(&modified_since_snapshot)
+                {
+                    executed = context.execute_batch(
+                        &block_state,
+                        data.tx_count(),
+                        transactions,
+                    )?;
+                }
+
+                let ExecutedBatch {
+                    transactions,
+                    data: batch_data,
+                    changes,
+                    ..
+                } = executed;
+                merged_writes.push(StorageKeys::from_changes(&changes));
+                block_state.commit_changes(changes)?;
+                data.append(batch_data)?;
+                block.transactions.extend(transactions);
+
+                if source_state == SourceState::Blocked {
+                    source_state = SourceState::Open;
+                }
+            }
+        }
+
+        block_executor.finalize_produced_block(
+            block,
+            context.coinbase_recipient,
+            context.gas_price,
+            block_state,
+            data,
+            memory.as_mut(),
+        )
+    }
+}
+
+/// Returns contracts that the transaction uses or creates.
+fn used_contracts(tx: &CheckedTransaction) -> impl Iterator<Item = ContractId> {
+    let inputs = tx
+        .inputs()
+        .iter()
+        .filter_map(Input::contract_id)
+        .copied()
+        .collect::<Vec<_>>();
+    let outputs = tx
+        .outputs()
+        .iter()
+        .filter_map(Output::contract_id)
+        .copied()
+        .collect::<Vec<_>>();
+    inputs.into_iter().chain(outputs)
+}
+
+fn metered_bytes_size(tx: &CheckedTransaction) -> u32 {
+    let size = match tx {
+        CheckedTransaction::Script(tx) => tx.transaction().metered_bytes_size(),
+        CheckedTransaction::Create(tx) => tx.transaction().metered_bytes_size(),
+        CheckedTransaction::Upgrade(tx) => tx.transaction().metered_bytes_size(),
+        CheckedTransaction::Upload(tx) => tx.transaction().metered_bytes_size(),
+        CheckedTransaction::Blob(tx) => tx.transaction().metered_bytes_size(),
+        CheckedTransaction::Mint(_) => 0,
+    };
+    size.try_into().unwrap_or(u32::MAX)
+}
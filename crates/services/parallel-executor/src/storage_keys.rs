@@ -0,0 +1,55 @@
+use fuel_core_executor::storage_access_recorder::AccessRecord;
+use fuel_core_storage::transactional::{
+    Changes,
+    ReferenceBytesKey,
+};
+use std::collections::{
+    HashMap,
+    HashSet,
+};
+
+/// The set of storage keys grouped by the column.
+///
+/// The executor of the batch works on top of the snapshot of the block state.
+/// The keys read by the batch are used to detect whether the result of the batch
+/// depends on the changes of other batches executed concurrently.
+#[derive(Debug, Clone, Default)]
+pub struct StorageKeys {
+    per_column: HashMap<u32, HashSet<ReferenceBytesKey>>,
+}
+
+impl StorageKeys {
+    /// Returns the keys modified by the `changes`.
+    pub fn from_changes(changes: &Changes) -> Self {
+        let per_column = changes
+            .iter()
+            .map(|(column, operations)| (*column, operations.keys().cloned().collect()))
+            .collect();
+        Self { per_column }
+    }
+
+    /// Returns `true` if at least one key is present in both sets.
+    pub fn intersects(&self, other: &StorageKeys) -> bool {
+        self.per_column.iter().any(|(column, keys)| {
+            other
+                .per_column
+                .get(column)
+                .is_some_and(|other_keys| !keys.is_disjoint(other_keys))
+        })
+    }
+
+    pub fn extend(&mut self, other: StorageKeys) {
+        for (column, keys) in other.per_column {
+            self.per_column.entry(column).or_default().extend(keys);
+        }
+    }
+}
+
+impl AccessRecord for StorageKeys {
+    fn mark(&mut self, key: &[u8], column_id: u32) {
+        self.per_column
+            .entry(column_id)
+            .or_default()
+            .insert(key.to_vec().into());
+    }
+}
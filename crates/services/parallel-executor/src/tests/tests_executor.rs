@@ -1,5 +1,11 @@
 #![allow(non_snake_case)]
 
+use fuel_core_executor::executor::{
+    ExecutionInstance,
+    OnceTransactionsSource as SequentialTransactionsSource,
+    TimeoutOnlyTxWaiter,
+    TransparentPreconfirmationSender,
+};
 use fuel_core_storage::{
     Result as StorageResult,
     StorageAsMut,
@@ -14,9 +20,11 @@ use fuel_core_storage::{
     tables::{
         Coins,
         ConsensusParametersVersions,
+        FuelBlocks,
     },
     transactional::{
         AtomicView,
+        Changes,
         Modifiable,
         ReadTransaction,
         StorageChanges,
@@ -24,7 +32,11 @@ use fuel_core_storage::{
     },
 };
 use fuel_core_types::{
-    blockchain::transaction::TransactionExt,
+    blockchain::{
+        block::Block,
+        header::PartialBlockHeader,
+        transaction::TransactionExt,
+    },
     entities::coins::coin::Coin,
     fuel_asm::{
         RegId,
@@ -51,8 +63,12 @@ use fuel_core_types::{
         Salt,
         SecretKey,
         checked_transaction::IntoChecked,
+        interpreter::MemoryInstance,
+    },
+    services::{
+        block_producer::Components,
+        executor::ExecutionResult,
     },
-    services::block_producer::Components,
 };
 use rand::SeedableRng;
 
@@ -213,9 +229,29 @@ fn add_consensus_parameters(
     database
 }
 
+fn add_genesis_block(mut database: Storage) -> Storage {
+    // The executor requires the previous block to produce a new one.
+    let mut tx = database.0.write_transaction();
+    tx.storage_as_mut::<FuelBlocks>()
+        .insert(
+            &0u32.into(),
+            &Block::default().compress(&ChainId::default()),
+        )
+        .unwrap();
+    tx.commit().unwrap();
+    database
+}
+
+fn next_block_header() -> PartialBlockHeader {
+    let mut header = PartialBlockHeader::default();
+    header.consensus.height = 1u32.into();
+    header
+}
+
 async fn contract_creation_changes(rng: &mut StdRng) -> (ContractId, StorageChanges) {
     let mut storage = Storage::default();
     storage = add_consensus_parameters(storage, &ConsensusParameters::default());
+    storage = add_genesis_block(storage);
     let tx_creation = TransactionBuilder::create(
         Default::default(),
         Salt::new(rng.r#gen()),
@@ -242,7 +278,7 @@ async fn contract_creation_changes(rng: &mut StdRng) -> (ContractId, StorageChan
     );
     let res = executor
         .produce_without_commit_with_source(Components {
-            header_to_produce: Default::default(),
+            header_to_produce: next_block_header(),
             transactions_source: OnceTransactionsSource::new(
                 vec![
                     tx_creation
@@ -261,12 +297,12 @@ async fn contract_creation_changes(rng: &mut StdRng) -> (ContractId, StorageChan
     (contract_id, StorageChanges::Changes(res))
 }
 
-#[should_panic]
 #[tokio::test]
 async fn execute__simple_independent_transactions_sorted() {
     let mut rng = rand::rngs::StdRng::seed_from_u64(2322);
     let mut storage = Storage::default();
     storage = add_consensus_parameters(storage, &ConsensusParameters::default());
+    storage = add_genesis_block(storage);
 
     // Given
     let tx1: Transaction = basic_tx(&mut rng, &mut storage);
@@ -287,7 +323,7 @@ async fn execute__simple_independent_transactions_sorted() {
 
     // When
     let future = executor.produce_without_commit_with_source(Components {
-        header_to_produce: Default::default(),
+        header_to_produce: next_block_header(),
         transactions_source,
         coinbase_recipient: Default::default(),
         gas_price: 0,
@@ -323,7 +359,6 @@ async fn execute__simple_independent_transactions_sorted() {
     assert_eq!(expected_ids, actual_ids);
 }
 
-#[should_panic]
 #[tokio::test]
 async fn execute__filter_contract_id_currently_executed_and_fetch_after() {
     let mut rng = rand::rngs::StdRng::seed_from_u64(2322);
@@ -331,6 +366,7 @@ async fn execute__filter_contract_id_currently_executed_and_fetch_after() {
     let mut storage = Storage::default();
     storage.merge_changes(changes).unwrap();
     storage = add_consensus_parameters(storage, &ConsensusParameters::default());
+    storage = add_genesis_block(storage);
 
     // Given
     let script = [op::jmp(RegId::ZERO)];
@@ -363,7 +399,7 @@ async fn execute__filter_contract_id_currently_executed_and_fetch_after() {
 
     // When
     let future = executor.produce_without_commit_with_source(Components {
-        header_to_produce: Default::default(),
+        header_to_produce: next_block_header(),
         transactions_source,
         coinbase_recipient: Default::default(),
         gas_price: 0,
@@ -397,7 +433,6 @@ async fn execute__filter_contract_id_currently_executed_and_fetch_after() {
     let _ = future.await.unwrap().into_result();
 }
 
-#[should_panic]
 #[tokio::test]
 async fn execute__gas_left_updated_when_state_merges() {
     let mut rng = rand::rngs::StdRng::seed_from_u64(2322);
@@ -407,6 +442,7 @@ async fn execute__gas_left_updated_when_state_merges() {
     storage.merge_changes(changes_1).unwrap();
     storage.merge_changes(changes_2).unwrap();
     storage = add_consensus_parameters(storage, &ConsensusParameters::default());
+    storage = add_genesis_block(storage);
 
     // Given
     let tx_contract_1: Transaction = TransactionBuilder::script(vec![], vec![])
@@ -475,7 +511,7 @@ async fn execute__gas_left_updated_when_state_merges() {
 
     // When
     let future = executor.produce_without_commit_with_source(Components {
-        header_to_produce: Default::default(),
+        header_to_produce: next_block_header(),
         transactions_source,
         coinbase_recipient: Default::default(),
         gas_price: 0,
@@ -518,7 +554,6 @@ async fn execute__gas_left_updated_when_state_merges() {
     let _ = future.await.unwrap().into_result();
 }
 
-#[should_panic]
 #[tokio::test]
 async fn execute__utxo_ordering_kept() {
     let mut rng = rand::rngs::StdRng::seed_from_u64(2322);
@@ -526,6 +561,7 @@ async fn execute__utxo_ordering_kept() {
     let owner = Input::predicate_owner(&predicate);
     let mut storage = Storage::default();
     storage = add_consensus_parameters(storage, &ConsensusParameters::default());
+    storage = add_genesis_block(storage);
 
     // Given
     let script = [op::add(RegId::ONE, 0x02, 0x03)];
@@ -562,7 +598,7 @@ async fn execute__utxo_ordering_kept() {
 
     // When
     let future = executor.produce_without_commit_with_source(Components {
-        header_to_produce: Default::default(),
+        header_to_produce: next_block_header(),
         transactions_source,
         coinbase_recipient: Default::default(),
         gas_price: 0,
@@ -600,3 +636,171 @@ async fn execute__utxo_ordering_kept() {
         tx2.id(&ChainId::default())
     );
 }
+
+async fn sequential_execution(
+    storage: Storage,
+    transactions: Vec<Transaction>,
+) -> (ExecutionResult, Changes) {
+    ExecutionInstance::new(
+        MockRelayer,
+        storage,
+        Default::default(),
+        MemoryInstance::new(),
+    )
+    .produce_without_commit(
+        Components {
+            header_to_produce: next_block_header(),
+            transactions_source: SequentialTransactionsSource::new(transactions),
+            coinbase_recipient: Default::default(),
+            gas_price: 0,
+        },
+        false,
+        TimeoutOnlyTxWaiter,
+        TransparentPreconfirmationSender,
+    )
+    .await
+    .unwrap()
+    .into()
+}
+
+fn assert_same_as_sequential(
+    parallel: (ExecutionResult, Changes),
+    sequential: (ExecutionResult, Changes),
+) {
+    let (parallel_result, parallel_changes) = parallel;
+    let (sequential_result, sequential_changes) = sequential;
+    assert_eq!(
+        format!("{parallel_result:?}"),
+        format!("{sequential_result:?}")
+    );
+    assert_eq!(parallel_changes, sequential_changes);
+}
+
+fn block_transactions_without_mint(result: &ExecutionResult) -> Vec<Transaction> {
+    let transactions = result.block.transactions();
+    transactions[..transactions.len() - 1].to_vec()
+}
+
+#[tokio::test]
+async fn execute__independent_batches_match_sequential_execution() {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(2322);
+    let mut storage = Storage::default();
+    storage = add_consensus_parameters(storage, &ConsensusParameters::default());
+    storage = add_genesis_block(storage);
+
+    // Given
+    let tx1: Transaction = basic_tx(&mut rng, &mut storage);
+    let tx2: Transaction = basic_tx(&mut rng, &mut storage);
+    let tx3: Transaction = basic_tx(&mut rng, &mut storage);
+    let tx4: Transaction = basic_tx(&mut rng, &mut storage);
+
+    let executor: Executor<Storage, MockRelayer> = Executor::new(
+        storage.clone(),
+        MockRelayer,
+        Config {
+            executor_config: Default::default(),
+            number_of_cores: std::num::NonZeroUsize::new(2)
+                .expect("The value is not zero; qed"),
+        },
+    );
+    let (transactions_source, mock_tx_pool) = MockTransactionsSource::new();
+    for tx in [&tx1, &tx2, &tx3, &tx4] {
+        mock_tx_pool.push_response(MockTxPoolResponse::new(
+            &[tx],
+            TransactionFiltered::NotFiltered,
+        ));
+    }
+    mock_tx_pool.push_response(MockTxPoolResponse::new(
+        &[],
+        TransactionFiltered::NotFiltered,
+    ));
+
+    // When
+    let parallel: (ExecutionResult, Changes) = executor
+        .produce_without_commit_with_source(Components {
+            header_to_produce: next_block_header(),
+            transactions_source,
+            coinbase_recipient: Default::default(),
+            gas_price: 0,
+        })
+        .await
+        .unwrap()
+        .into();
+
+    // Then
+    let transactions = block_transactions_without_mint(&parallel.0);
+    assert_eq!(transactions.len(), 4);
+    let sequential = sequential_execution(storage, transactions).await;
+    assert_same_as_sequential(parallel, sequential);
+}
+
+#[tokio::test]
+async fn execute__conflicting_batches_match_sequential_execution() {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(2322);
+    let predicate = op::ret(RegId::ONE).to_bytes().to_vec();
+    let owner = Input::predicate_owner(&predicate);
+    let mut storage = Storage::default();
+    storage = add_consensus_parameters(storage, &ConsensusParameters::default());
+    storage = add_genesis_block(storage);
+
+    // Given
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .add_stored_coin_input(&mut rng, &mut storage, 1000)
+        .add_output(Output::coin(owner, 1000, Default::default()))
+        .finalize_as_transaction();
+    let coin_utxo = UtxoId::new(tx1.id(&ChainId::default()), 0);
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .add_input(Input::coin_predicate(
+            coin_utxo,
+            owner,
+            1000,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            predicate.clone(),
+            vec![],
+        ))
+        .add_output(Output::coin(owner, 1000, Default::default()))
+        .finalize_as_transaction();
+
+    let executor: Executor<Storage, MockRelayer> = Executor::new(
+        storage.clone(),
+        MockRelayer,
+        Config {
+            executor_config: Default::default(),
+            number_of_cores: std::num::NonZeroUsize::new(2)
+                .expect("The value is not zero; qed"),
+        },
+    );
+    let (transactions_source, mock_tx_pool) = MockTransactionsSource::new();
+    mock_tx_pool.push_response(MockTxPoolResponse::new(
+        &[&tx1],
+        TransactionFiltered::NotFiltered,
+    ));
+    mock_tx_pool.push_response(MockTxPoolResponse::new(
+        &[&tx2],
+        TransactionFiltered::NotFiltered,
+    ));
+    mock_tx_pool.push_response(MockTxPoolResponse::new(
+        &[],
+        TransactionFiltered::NotFiltered,
+    ));
+
+    // When
+    let parallel: (ExecutionResult, Changes) = executor
+        .produce_without_commit_with_source(Components {
+            header_to_produce: next_block_header(),
+            transactions_source,
+            coinbase_recipient: Default::default(),
+            gas_price: 0,
+        })
+        .await
+        .unwrap()
+        .into();
+
+    // Then
+    let transactions = block_transactions_without_mint(&parallel.0);
+    assert_eq!(transactions.len(), 2);
+    let sequential = sequential_execution(storage, transactions).await;
+    assert_same_as_sequential(parallel, sequential);
+}
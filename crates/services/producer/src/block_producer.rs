@@ -33,6 +33,7 @@ use fuel_core_types::{
     },
     fuel_tx::{
         Transaction,
+        TxId,
         field::{
             InputContract,
             MintGasPrice,
@@ -46,7 +47,9 @@ use fuel_core_types::{
         block_producer::Components,
         executor::{
             DryRunResult,
+            StateOverrides,
             StorageReadReplayEvent,
+            TransactionTrace,
             UncommittedResult,
         },
     },
@@ -357,6 +360,7 @@ where
     /// Simulates multiple transactions without altering any state. Does not acquire the production lock.
     /// since it is basically a "read only" operation and shouldn't get in the way of normal
     /// production.
+    #[allow(clippy::too_many_arguments)]
     pub async fn dry_run(
         &self,
         transactions: Vec<Transaction>,
@@ -365,6 +369,7 @@ where
         utxo_validation: Option<bool>,
         gas_price: Option<u64>,
         record_storage_reads: bool,
+        state_overrides: Option<StateOverrides>,
     ) -> anyhow::Result<DryRunResult> {
         let view = self.view_provider.latest_view()?;
         let latest_height = view.latest_height().unwrap_or_default();
@@ -409,7 +414,13 @@ where
 
         // use the blocking threadpool for dry_run to avoid clogging up the main async runtime
         let result = tokio_rayon::spawn_fifo(move || {
-            executor.dry_run(component, utxo_validation, height, record_storage_reads)
+            executor.dry_run(
+                component,
+                utxo_validation,
+                height,
+                record_storage_reads,
+                state_overrides,
+            )
         })
         .await?;
 
@@ -450,6 +461,34 @@ where
     }
 }
 
+impl<ViewProvider, TxPool, Executor, GasPriceProvider, ChainStateProvider>
+    Producer<ViewProvider, TxPool, Executor, GasPriceProvider, ChainStateProvider>
+where
+    ViewProvider: HistoricalView + 'static,
+    ViewProvider::LatestView: BlockProducerDatabase,
+    Executor: ports::TransactionTracer + 'static,
+    GasPriceProvider: GasPriceProviderConstraint,
+    ChainStateProvider: ChainStateInfoProvider,
+{
+    /// Re-executes an old block, getting the call tree of the transaction.
+    pub async fn transaction_trace(
+        &self,
+        height: BlockHeight,
+        tx_id: TxId,
+    ) -> anyhow::Result<TransactionTrace> {
+        let view = self.view_provider.latest_view()?;
+
+        let executor = self.executor.clone();
+
+        // use the blocking threadpool to avoid clogging up the main async runtime
+        tokio_rayon::spawn_fifo(move || {
+            let block = view.get_full_block(&height)?;
+            Ok(executor.trace_transaction(&block, &tx_id)?)
+        })
+        .await
+    }
+}
+
 pub const NO_NEW_DA_HEIGHT_FOUND: &str = "No new da_height found";
 
 impl<ViewProvider, TxPool, Executor, GP, ChainStateProvider>
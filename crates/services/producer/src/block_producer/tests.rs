@@ -596,7 +596,15 @@ mod dry_run {
         // When
         let _ = ctx
             .producer()
-            .dry_run(vec![], None, Some(simulated_block_time), None, None, false)
+            .dry_run(
+                vec![],
+                None,
+                Some(simulated_block_time),
+                None,
+                None,
+                false,
+                None,
+            )
             .await;
 
         // Then
@@ -617,7 +625,15 @@ mod dry_run {
         // When
         let _ = ctx
             .producer()
-            .dry_run(vec![], None, Some(simulated_block_time), None, None, false)
+            .dry_run(
+                vec![],
+                None,
+                Some(simulated_block_time),
+                None,
+                None,
+                false,
+                None,
+            )
             .await;
 
         // Then
@@ -637,7 +653,7 @@ mod dry_run {
         // When
         let _ = ctx
             .producer()
-            .dry_run(vec![], None, None, None, None, false)
+            .dry_run(vec![], None, None, None, None, false, None)
             .await;
 
         // Then
@@ -664,7 +680,15 @@ mod dry_run {
 
         // When
         let result = producer
-            .dry_run(vec![], Some(SAME_HEIGHT.into()), None, None, None, false)
+            .dry_run(
+                vec![],
+                Some(SAME_HEIGHT.into()),
+                None,
+                None,
+                None,
+                false,
+                None,
+            )
             .await;
 
         // Then
@@ -710,7 +734,7 @@ mod dry_run {
         // When
         let block_height = Some(*block_height);
         let _result = producer
-            .dry_run(vec![], block_height, None, None, None, false)
+            .dry_run(vec![], block_height, None, None, None, false, None)
             .await
             .unwrap();
 
@@ -750,7 +774,7 @@ mod dry_run {
         // When
         let block_height = None;
         let _result = producer
-            .dry_run(vec![], block_height, None, None, None, false)
+            .dry_run(vec![], block_height, None, None, None, false, None)
             .await
             .unwrap();
 
@@ -788,7 +812,7 @@ mod dry_run {
         // When
         let block_height = Some(*block_height);
         let _result = producer
-            .dry_run(vec![], block_height, None, None, None, false)
+            .dry_run(vec![], block_height, None, None, None, false, None)
             .await
             .unwrap();
 
@@ -827,7 +851,7 @@ mod dry_run {
         // When
         let block_height = None;
         let _result = producer
-            .dry_run(vec![], block_height, None, None, None, false)
+            .dry_run(vec![], block_height, None, None, None, false, None)
             .await
             .unwrap();
 
@@ -40,6 +40,7 @@ use fuel_core_types::{
             Error as ExecutorError,
             ExecutionResult,
             Result as ExecutorResult,
+            StateOverrides,
             UncommittedResult,
         },
     },
@@ -214,6 +215,7 @@ impl DryRunner for MockExecutorWithCapture {
         _utxo_validation: Option<bool>,
         _height: Option<BlockHeight>,
         _record_storage_read_replay: bool,
+        _state_overrides: Option<StateOverrides>,
     ) -> ExecutorResult<DryRunResult> {
         *self.captured.lock().unwrap() = Some(block);
 
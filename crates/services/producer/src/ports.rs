@@ -17,6 +17,7 @@ use fuel_core_types::{
     fuel_tx::{
         Bytes32,
         Transaction,
+        TxId,
     },
     fuel_types::BlockHeight,
     services::{
@@ -24,7 +25,9 @@ use fuel_core_types::{
         executor::{
             DryRunResult,
             Result as ExecutorResult,
+            StateOverrides,
             StorageReadReplayEvent,
+            TransactionTrace,
             UncommittedResult,
         },
     },
@@ -107,12 +110,14 @@ pub trait DryRunner: Send + Sync {
     /// Executes the block without committing it to the database. During execution collects the
     /// receipts to return them. The `forbid_fake_coins` field can be used to enable/disable the validation
     /// of utxos during execution. The `at_height` field can be used to dry run on top of a past block.
+    /// The `state_overrides` are applied on top of the state before the execution.
     fn dry_run(
         &self,
         block: Components<Vec<Transaction>>,
         forbid_fake_coins: Option<bool>,
         at_height: Option<BlockHeight>,
         record_storage_read_replay: bool,
+        state_overrides: Option<StateOverrides>,
     ) -> ExecutorResult<DryRunResult>;
 }
 
@@ -122,3 +127,12 @@ pub trait StorageReadReplayRecorder: Send + Sync {
         block: &Block,
     ) -> ExecutorResult<Vec<StorageReadReplayEvent>>;
 }
+
+pub trait TransactionTracer: Send + Sync {
+    /// Re-executes the `block`, building the call tree of the transaction with `tx_id`.
+    fn trace_transaction(
+        &self,
+        block: &Block,
+        tx_id: &TxId,
+    ) -> ExecutorResult<TransactionTrace>;
+}
@@ -1,5 +1,6 @@
 use std::{
     collections::HashSet,
+    path::PathBuf,
     time::Duration,
 };
 
@@ -130,6 +131,10 @@ pub struct Config {
     pub max_txs_chain_count: usize,
     /// Pool limits
     pub pool_limits: PoolLimits,
+    /// Limits of the pool for transactions of one owner. The owner of a transaction
+    /// is the owner of any coin or the recipient of any message it spends;
+    /// for predicates, it is the predicate root.
+    pub per_owner_limits: PoolLimits,
     /// Service channel limits
     pub service_channel_limits: ServiceChannelLimits,
     /// Interval for checking the time to live of transactions.
@@ -144,8 +149,94 @@ pub struct Config {
     pub pending_pool_tx_ttl: Duration,
     /// Maximum percentage of the pool size to be used for the pending pool.
     pub max_pending_pool_size_percentage: u16,
+    /// Maximum number of blocks ahead of the next block height for the maturity
+    /// of the transaction. Such transactions are held in the future pool until
+    /// the chain reaches their maturity. Transactions with a maturity further ahead
+    /// are rejected.
+    pub max_maturity_lookahead: u32,
+    /// Maximum percentage of the pool size to be used for the future pool.
+    pub max_future_pool_size_percentage: u16,
     /// Enable metrics when set to true
     pub metrics: bool,
+    /// The policy used to select transactions for the block.
+    pub selection_policy: SelectionPolicy,
+    /// The on-disk journal of the pool. When set, the transactions of the pool
+    /// are persisted and replayed after the restart of the node.
+    pub journal: Option<JournalConfig>,
+    /// Maximum number of transactions in one bundle.
+    pub max_bundle_size: usize,
+    /// The policy of replacing colliding transactions.
+    pub replacement_policy: ReplacementPolicy,
+}
+
+/// The policy of selecting transactions from the pool for the block production.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum SelectionPolicy {
+    /// Transactions with the highest tip/gas ratio are selected first.
+    #[default]
+    RatioTipGas,
+    /// Transactions are selected strictly in the order of their arrival into the pool.
+    /// A transaction that doesn't fit into the block stops the selection,
+    /// so later transactions can't overtake it.
+    Fifo,
+    /// A part of the block gas is reserved for transactions from priority addresses.
+    Lanes(LanesConfig),
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LanesConfig {
+    /// Transactions that spend inputs owned by these addresses use the priority lane.
+    pub priority_addresses: HashSet<Address>,
+    /// Percentage of the block gas reserved for the priority lane.
+    pub reserved_gas_percentage: u8,
+}
+
+/// The replace-by-fee policy. A new transaction that collides with transactions
+/// of the pool replaces them only if it satisfies all rules of the policy.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReplacementPolicy {
+    /// Minimum percentage by which the tip per gas of the new transaction must exceed
+    /// the tip per gas of each collided transaction along with its dependents.
+    /// With `0`, any strictly higher tip per gas is enough.
+    pub min_tip_bump_percentage: u16,
+    /// Maximum number of transactions, including dependents and the bundles
+    /// removed along with them, that one replacement is allowed to evict from the pool.
+    pub max_evicted_transactions: usize,
+    /// Inputs for which the replacement is disabled. A transaction colliding
+    /// on such an input is rejected regardless of its tip. Regardless of this policy,
+    /// a transaction can opt out of the replacement when it is submitted.
+    pub non_replaceable_inputs: HashSet<ReplaceableInput>,
+}
+
+impl Default for ReplacementPolicy {
+    fn default() -> Self {
+        Self {
+            min_tip_bump_percentage: 0,
+            max_evicted_transactions: 100,
+            non_replaceable_inputs: HashSet::new(),
+        }
+    }
+}
+
+/// The kind of the input on which transactions can collide.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, derive_more::Display)]
+pub enum ReplaceableInput {
+    #[display("coin")]
+    Coin,
+    #[display("message")]
+    Message,
+    #[display("contract creation")]
+    ContractCreation,
+    #[display("blob")]
+    Blob,
+}
+
+#[derive(Clone, Debug)]
+pub struct JournalConfig {
+    /// The path to the journal file.
+    pub path: PathBuf,
+    /// Interval for dropping the transactions that left the pool from the journal.
+    pub rotation_interval: Duration,
 }
 
 #[derive(Clone, Debug)]
@@ -193,6 +284,11 @@ impl Default for Config {
                 max_gas: 100_000_000_000,
                 max_bytes_size: 1_000_000_000,
             },
+            per_owner_limits: PoolLimits {
+                max_txs: 10000,
+                max_gas: 100_000_000_000,
+                max_bytes_size: 1_000_000_000,
+            },
             heavy_work: HeavyWorkConfig {
                 // It is important for tests to have only one thread for verification
                 // because some of them rely on the ordering of insertion.
@@ -207,7 +303,13 @@ impl Default for Config {
             },
             pending_pool_tx_ttl: Duration::from_secs(3),
             max_pending_pool_size_percentage: 50,
+            max_maturity_lookahead: 3600,
+            max_future_pool_size_percentage: 10,
             metrics: false,
+            selection_policy: SelectionPolicy::default(),
+            journal: None,
+            max_bundle_size: 16,
+            replacement_policy: ReplacementPolicy::default(),
         }
     }
 }
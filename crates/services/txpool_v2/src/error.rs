@@ -12,6 +12,7 @@ use fuel_core_types::{
 };
 
 use crate::{
+    config::ReplaceableInput,
     pending_pool::MissingInput,
     ports::WasmValidityError,
 };
@@ -40,6 +41,12 @@ pub enum Error {
     MintIsDisallowed,
     #[display("Pool limit is hit, try to increase gas_price")]
     NotInsertedLimitHit,
+    #[display(
+        "Pool limit for the owner {_0} is hit, try to increase gas_price or wait for previous transactions"
+    )]
+    NotInsertedOwnerLimitHit(Address),
+    #[display("Transaction is rejected by the admission filter: {_0}")]
+    NotAdmitted(String),
     #[display("Transaction is removed: {_0}")]
     Removed(RemovedReason),
     #[display("Transaction has been skipped during block insertion: {_0}")]
@@ -65,6 +72,8 @@ pub enum Error {
     MessageInputWasAlreadySpent(Nonce),
     #[display("The UTXO input {_0:#x} was already spent")]
     UtxoInputWasAlreadySpent(UtxoId),
+    #[display("Bundle error: {_0}")]
+    Bundle(BundleError),
 }
 
 impl Error {
@@ -86,6 +95,26 @@ pub enum RemovedReason {
         "Transaction expired because it exceeded the configured time to live `tx-pool-ttl`."
     )]
     Ttl,
+    #[display(
+        "Transaction was removed because another transaction of its bundle (id: {_0}) was included separately"
+    )]
+    BundleBroken(TxId),
+}
+
+#[derive(Clone, Debug, derive_more::Display)]
+pub enum BundleError {
+    #[display("The bundle doesn't contain transactions")]
+    Empty,
+    #[display("The bundle contains {_0} transactions, while the limit is {_1}")]
+    TooManyTransactions(usize, usize),
+    #[display(
+        "The transaction {_0} of the bundle depends on a transaction outside of the bundle"
+    )]
+    DependsOnNonMember(TxId),
+    #[display("The transaction {_0} of the bundle collides with another transaction")]
+    Collision(TxId),
+    #[display("The transaction {index} of the bundle is invalid: {error}")]
+    InvalidTransaction { index: usize, error: Box<Error> },
 }
 
 #[derive(Clone, Debug, derive_more::Display)]
@@ -204,6 +233,18 @@ pub enum CollisionReason {
         "This transaction have dependencies and is colliding with multiple transactions"
     )]
     MultipleCollisions,
+    #[display(
+        "The tip per gas of the transaction must exceed the tip per gas of the collided transactions by at least {min_tip_bump_percentage}%"
+    )]
+    InsufficientTipBump { min_tip_bump_percentage: u16 },
+    #[display(
+        "The transaction would evict {evicted} transactions from the pool, but the maximum is {max}"
+    )]
+    TooManyEvictions { evicted: usize, max: usize },
+    #[display("Replacement of transactions colliding on the {_0} input is disabled")]
+    ReplacementDisabled(ReplaceableInput),
+    #[display("The transaction {_0} was submitted as non-replaceable")]
+    NonReplaceable(TxId),
 }
 
 impl From<CheckError> for Error {
@@ -0,0 +1,267 @@
+use std::{
+    collections::{
+        BTreeMap,
+        HashMap,
+        VecDeque,
+        hash_map::Entry,
+    },
+    time::{
+        Duration,
+        SystemTime,
+    },
+};
+
+use fuel_core_types::{
+    fuel_tx::{
+        TxId,
+        UtxoId,
+    },
+    fuel_types::{
+        BlockHeight,
+        Nonce,
+    },
+    services::txpool::ArcPoolTx,
+};
+use tokio::sync::mpsc::Sender;
+
+use crate::{
+    error::{
+        CollisionReason,
+        Error,
+        RemovedReason,
+    },
+    inspection::{
+        InspectedTransaction,
+        InspectionFilter,
+        InspectionIndex,
+        NotExecutableReason,
+    },
+    pool::owners::OwnersUsage,
+    pool_worker::{
+        InsertionSource,
+        PoolNotification,
+    },
+};
+
+// This is a simple temporary storage for transactions that are not executable yet,
+// because their maturity is above the next block height.
+// This storage should not have a lot of complexity.
+//
+// Insertion rules:
+// - If the maturity of the transaction is above the next block height.
+// - If no other future transaction spends the same coin or message.
+//
+// Deletion rules:
+// - If the chain reaches the height after which the transaction becomes mature.
+// - If the transaction hasn't matured for a certain amount of time.
+pub(crate) struct FuturePool {
+    ttl: Duration,
+    future_txs_by_maturity: BTreeMap<BlockHeight, Vec<TxId>>,
+    future_txs_by_input: HashMap<SpentInput, TxId>,
+    future_txs: HashMap<TxId, FutureTx>,
+    ttl_check: VecDeque<(SystemTime, TxId)>,
+    /// The resources of the future pool used by each owner.
+    pub(crate) owners_usage: OwnersUsage,
+    /// Future transactions in the inspection order.
+    pub(crate) inspection_index: InspectionIndex,
+    pub(crate) current_bytes: usize,
+    pub(crate) current_txs: usize,
+    pub(crate) current_gas: u64,
+}
+
+#[derive(Debug)]
+pub(crate) struct FutureTx {
+    pub tx: ArcPoolTx,
+    pub insertion_source: InsertionSource,
+    pub creation_instant: SystemTime,
+}
+
+/// The coin or the message spent by the future transaction.
+#[derive(Debug, Hash, Eq, PartialEq, Copy, Clone)]
+enum SpentInput {
+    Utxo(UtxoId),
+    Message(Nonce),
+}
+
+impl From<SpentInput> for CollisionReason {
+    fn from(value: SpentInput) -> Self {
+        match value {
+            SpentInput::Utxo(utxo_id) => CollisionReason::Utxo(utxo_id),
+            SpentInput::Message(nonce) => CollisionReason::Message(nonce),
+        }
+    }
+}
+
+fn spent_inputs(tx: &ArcPoolTx) -> impl Iterator<Item = SpentInput> + '_ {
+    tx.inputs().iter().filter_map(|input| {
+        input
+            .utxo_id()
+            .map(|utxo_id| SpentInput::Utxo(*utxo_id))
+            .or_else(|| input.nonce().map(|nonce| SpentInput::Message(*nonce)))
+    })
+}
+
+impl FuturePool {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            future_txs_by_maturity: BTreeMap::new(),
+            future_txs_by_input: HashMap::new(),
+            future_txs: HashMap::new(),
+            ttl_check: VecDeque::new(),
+            owners_usage: OwnersUsage::default(),
+            inspection_index: InspectionIndex::default(),
+            current_bytes: 0,
+            current_txs: 0,
+            current_gas: 0,
+        }
+    }
+
+    pub fn contains(&self, tx_id: &TxId) -> bool {
+        self.future_txs.contains_key(tx_id)
+    }
+
+    /// Returns the collision if another future transaction spends
+    /// the same coin or message as the `transaction`.
+    pub fn find_collision(&self, transaction: &ArcPoolTx) -> Option<CollisionReason> {
+        spent_inputs(transaction)
+            .find(|input| self.future_txs_by_input.contains_key(input))
+            .map(Into::into)
+    }
+
+    pub fn insert_transaction(
+        &mut self,
+        transaction: ArcPoolTx,
+        insertion_source: InsertionSource,
+    ) {
+        let tx_id = transaction.id();
+        let now = SystemTime::now();
+        self.current_bytes = self
+            .current_bytes
+            .saturating_add(transaction.metered_bytes_size());
+        self.current_gas = self.current_gas.saturating_add(transaction.max_gas());
+        self.current_txs = self.current_txs.saturating_add(1);
+        self.owners_usage.on_stored_transaction(&transaction);
+        self.inspection_index.insert(&transaction, now);
+        for input in spent_inputs(&transaction) {
+            self.future_txs_by_input.insert(input, tx_id);
+        }
+        self.future_txs_by_maturity
+            .entry(transaction.maturity())
+            .or_default()
+            .push(tx_id);
+        self.future_txs.insert(
+            tx_id,
+            FutureTx {
+                tx: transaction,
+                insertion_source,
+                creation_instant: now,
+            },
+        );
+        self.ttl_check.push_front((
+            now.checked_add(self.ttl)
+                .expect("The system time should be valid; qed"),
+            tx_id,
+        ));
+    }
+
+    /// Removes and returns transactions that are executable at the `height`,
+    /// in the order of their maturity.
+    pub fn take_matured_transactions(
+        &mut self,
+        height: BlockHeight,
+    ) -> Vec<(ArcPoolTx, InsertionSource)> {
+        let not_matured = match height.succ() {
+            Some(next_height) => self.future_txs_by_maturity.split_off(&next_height),
+            None => BTreeMap::new(),
+        };
+        let matured = std::mem::replace(&mut self.future_txs_by_maturity, not_matured);
+
+        matured
+            .into_values()
+            .flatten()
+            .filter_map(|tx_id| self.remove(&tx_id))
+            .map(
+                |FutureTx {
+                     tx,
+                     insertion_source,
+                     ..
+                 }| (tx, insertion_source),
+            )
+            .collect()
+    }
+
+    pub fn expire_transactions(&mut self, notification_sender: Sender<PoolNotification>) {
+        let now = SystemTime::now();
+        while let Some((ttl, tx_id)) = self.ttl_check.back().copied() {
+            if ttl > now {
+                break;
+            }
+            if let Some(FutureTx {
+                tx,
+                insertion_source,
+                ..
+            }) = self.remove(&tx_id)
+                && let Err(e) =
+                    notification_sender.try_send(PoolNotification::ErrorInsertion {
+                        tx_id: tx.id(),
+                        source: insertion_source,
+                        error: Error::Removed(RemovedReason::Ttl),
+                    })
+            {
+                tracing::error!("Failed to send error insertion notification: {}", e);
+            }
+            self.ttl_check.pop_back();
+        }
+    }
+
+    /// Returns the future transaction if it matches the `filter`.
+    pub fn inspect(
+        &self,
+        tx_id: &TxId,
+        filter: &InspectionFilter,
+    ) -> Option<InspectedTransaction> {
+        let future_tx = self.future_txs.get(tx_id)?;
+        let reason = NotExecutableReason::Immature(future_tx.tx.maturity());
+        filter
+            .matches(&future_tx.tx, &[], Some(&reason))
+            .then(|| InspectedTransaction {
+                tx: future_tx.tx.clone(),
+                bundle: vec![],
+                creation_instant: future_tx.creation_instant,
+                dependents: 0,
+                not_executable_reason: Some(reason),
+            })
+    }
+
+    fn remove(&mut self, tx_id: &TxId) -> Option<FutureTx> {
+        let future_tx = self.future_txs.remove(tx_id)?;
+        for input in spent_inputs(&future_tx.tx) {
+            if let Entry::Occupied(entry) = self.future_txs_by_input.entry(input)
+                && entry.get() == tx_id
+            {
+                entry.remove();
+            }
+        }
+        if let Some(tx_ids) = self
+            .future_txs_by_maturity
+            .get_mut(&future_tx.tx.maturity())
+        {
+            tx_ids.retain(|id| id != tx_id);
+            if tx_ids.is_empty() {
+                self.future_txs_by_maturity.remove(&future_tx.tx.maturity());
+            }
+        }
+        self.owners_usage.on_removed_transaction(&future_tx.tx);
+        self.inspection_index
+            .remove(&future_tx.tx, future_tx.creation_instant);
+        self.decrease_pool_size(&future_tx.tx);
+        Some(future_tx)
+    }
+
+    fn decrease_pool_size(&mut self, tx: &ArcPoolTx) {
+        self.current_bytes = self.current_bytes.saturating_sub(tx.metered_bytes_size());
+        self.current_gas = self.current_gas.saturating_sub(tx.max_gas());
+        self.current_txs = self.current_txs.saturating_sub(1);
+    }
+}
@@ -0,0 +1,261 @@
+//! Types used to inspect the content of the transaction pool.
+
+use std::{
+    cmp::Ordering,
+    collections::BTreeSet,
+    iter,
+    ops::Bound,
+    time::SystemTime,
+};
+
+use fuel_core_storage::iter::IterDirection;
+use fuel_core_types::{
+    fuel_tx::{
+        Address,
+        ContractId,
+        Input,
+        Output,
+        TxId,
+        UtxoId,
+    },
+    fuel_types::BlockHeight,
+    services::txpool::{
+        ArcPoolTx,
+        PoolTransaction,
+    },
+};
+use num_rational::Ratio;
+
+/// The filter applied to the transactions of the pool during the inspection.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InspectionFilter {
+    /// Only transactions that spend inputs of this owner.
+    pub owner: Option<Address>,
+    /// Only transactions that use or create this contract.
+    pub contract: Option<ContractId>,
+    /// Only executable(`true`) or not executable(`false`) transactions.
+    pub executable: Option<bool>,
+    /// The position to start the inspection from, inclusive.
+    /// The inspection starts from the beginning of the order if `None`.
+    pub start: Option<InspectionCursor>,
+    /// The direction of the inspection.
+    pub direction: IterDirection,
+    /// The maximum number of returned transactions.
+    pub limit: usize,
+}
+
+impl InspectionFilter {
+    /// Create a filter that matches all transactions, up to the `limit`.
+    pub fn new(limit: usize) -> Self {
+        Self {
+            owner: None,
+            contract: None,
+            executable: None,
+            start: None,
+            direction: IterDirection::Forward,
+            limit,
+        }
+    }
+
+    /// Checks the transaction along with other transactions of its bundle.
+    pub(crate) fn matches(
+        &self,
+        tx: &PoolTransaction,
+        bundle: &[ArcPoolTx],
+        not_executable_reason: Option<&NotExecutableReason>,
+    ) -> bool {
+        if let Some(executable) = self.executable
+            && executable != not_executable_reason.is_none()
+        {
+            return false;
+        }
+
+        let mut txs = iter::once(tx).chain(bundle.iter().map(AsRef::as_ref));
+        txs.any(|tx| self.matches_content(tx))
+    }
+
+    fn matches_content(&self, tx: &PoolTransaction) -> bool {
+        if let Some(owner) = &self.owner
+            && !tx
+                .inputs()
+                .iter()
+                .any(|input| input.input_owner() == Some(owner))
+        {
+            return false;
+        }
+
+        if let Some(contract_id) = &self.contract {
+            let uses_contract = tx.inputs().iter().any(|input| {
+                matches!(
+                    input,
+                    Input::Contract(contract) if contract.contract_id == *contract_id
+                )
+            });
+            let creates_contract = tx.outputs().iter().any(|output| {
+                matches!(
+                    output,
+                    Output::ContractCreated { contract_id: created, .. }
+                        if created == contract_id
+                )
+            });
+            if !uses_contract && !creates_contract {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// The reason why the transaction can't be included in the next block.
+#[derive(Clone, Debug, PartialEq, Eq, derive_more::Display)]
+pub enum NotExecutableReason {
+    #[display("The transaction depends on other transactions of the pool")]
+    WaitsForDependencies,
+    #[display(
+        "The transaction waits for {} UTXO(s) and {} contract(s) to be created",
+        utxos.len(),
+        contracts.len()
+    )]
+    MissingInputs {
+        utxos: Vec<UtxoId>,
+        contracts: Vec<ContractId>,
+    },
+    #[display("The transaction is not mature until the block height {_0}")]
+    Immature(BlockHeight),
+}
+
+/// The transaction of the pool along with the information about its state.
+#[derive(Clone, Debug)]
+pub struct InspectedTransaction {
+    /// The transaction.
+    pub tx: ArcPoolTx,
+    /// Other transactions of the bundle headed by the transaction, in the order
+    /// of the bundle. Members of a bundle are only reported through its head.
+    pub bundle: Vec<ArcPoolTx>,
+    /// The instant when the transaction was added to the pool.
+    pub creation_instant: SystemTime,
+    /// The number of transactions of the pool that depend on this transaction.
+    pub dependents: usize,
+    /// The reason why the transaction is not executable, `None` if it is executable.
+    pub not_executable_reason: Option<NotExecutableReason>,
+}
+
+impl InspectedTransaction {
+    /// Returns `true` if the transaction can be included in the next block.
+    pub fn is_executable(&self) -> bool {
+        self.not_executable_reason.is_none()
+    }
+
+    /// Returns the position of the transaction in the inspection order.
+    pub fn cursor(&self) -> InspectionCursor {
+        InspectionCursor::new(&self.tx, self.creation_instant)
+    }
+}
+
+/// The position of the transaction in the inspection order. Transactions are sorted
+/// by their tip per gas ratio, from the highest to the lowest. Transactions with
+/// the same ratio are sorted by their insertion time, from the oldest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InspectionCursor {
+    /// The tip of the transaction.
+    pub tip: u64,
+    /// The maximum gas the transaction can consume.
+    pub max_gas: u64,
+    /// The instant when the transaction was added to the pool.
+    pub creation_instant: SystemTime,
+    /// The id of the transaction.
+    pub tx_id: TxId,
+}
+
+impl InspectionCursor {
+    pub fn new(tx: &PoolTransaction, creation_instant: SystemTime) -> Self {
+        Self {
+            tip: tx.tip(),
+            max_gas: tx.max_gas(),
+            creation_instant,
+            tx_id: tx.id(),
+        }
+    }
+
+    fn tip_ratio(&self) -> Ratio<u64> {
+        Ratio::new(self.tip, self.max_gas.max(1))
+    }
+}
+
+impl Ord for InspectionCursor {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .tip_ratio()
+            .cmp(&self.tip_ratio())
+            .then_with(|| self.creation_instant.cmp(&other.creation_instant))
+            .then_with(|| self.tx_id.cmp(&other.tx_id))
+            .then_with(|| self.tip.cmp(&other.tip))
+            .then_with(|| self.max_gas.cmp(&other.max_gas))
+    }
+}
+
+impl PartialOrd for InspectionCursor {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Transactions of one of the pools sorted in the inspection order. It allows
+/// paginating over the pool without scanning and sorting all of its transactions.
+#[derive(Debug, Default)]
+pub(crate) struct InspectionIndex {
+    cursors: BTreeSet<InspectionCursor>,
+}
+
+impl InspectionIndex {
+    pub fn insert(&mut self, tx: &PoolTransaction, creation_instant: SystemTime) {
+        self.cursors
+            .insert(InspectionCursor::new(tx, creation_instant));
+    }
+
+    pub fn remove(&mut self, tx: &PoolTransaction, creation_instant: SystemTime) {
+        self.cursors
+            .remove(&InspectionCursor::new(tx, creation_instant));
+    }
+
+    /// Iterates over the cursors from the `start`, inclusive, in the `direction`.
+    pub fn iter<'a>(
+        &'a self,
+        start: Option<&InspectionCursor>,
+        direction: IterDirection,
+    ) -> Box<dyn Iterator<Item = &'a InspectionCursor> + 'a> {
+        match (start, direction) {
+            (None, IterDirection::Forward) => Box::new(self.cursors.iter()),
+            (None, IterDirection::Reverse) => Box::new(self.cursors.iter().rev()),
+            (Some(start), IterDirection::Forward) => Box::new(
+                self.cursors
+                    .range((Bound::Included(start), Bound::Unbounded)),
+            ),
+            (Some(start), IterDirection::Reverse) => Box::new(
+                self.cursors
+                    .range((Bound::Unbounded, Bound::Included(start)))
+                    .rev(),
+            ),
+        }
+    }
+}
+
+/// Merges cursors of several indexes into one iterator that preserves the `direction`.
+pub(crate) fn merge<'a, const N: usize>(
+    iters: [Box<dyn Iterator<Item = &'a InspectionCursor> + 'a>; N],
+    direction: IterDirection,
+) -> impl Iterator<Item = &'a InspectionCursor> {
+    let mut iters = iters.map(Iterator::peekable);
+    iter::from_fn(move || {
+        let candidates = iters
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(index, iter)| iter.peek().map(|cursor| (index, *cursor)));
+        let (index, _) = match direction {
+            IterDirection::Forward => candidates.min_by_key(|(_, cursor)| *cursor),
+            IterDirection::Reverse => candidates.max_by_key(|(_, cursor)| *cursor),
+        }?;
+        iters.get_mut(index)?.next()
+    })
+}
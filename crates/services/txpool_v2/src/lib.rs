@@ -45,6 +45,8 @@ mod collision_manager;
 pub mod config;
 pub mod error;
 mod extracted_outputs;
+mod future_pool;
+pub mod inspection;
 mod pending_pool;
 mod pool;
 mod pool_worker;
@@ -32,6 +32,12 @@ use crate::{
         Error,
         InputValidationError,
     },
+    inspection::{
+        InspectedTransaction,
+        InspectionFilter,
+        InspectionIndex,
+        NotExecutableReason,
+    },
     pool_worker::{
         InsertionSource,
         PoolNotification,
@@ -52,6 +58,8 @@ pub(crate) struct PendingPool {
     pending_txs_by_inputs: HashMap<MissingInput, HashSet<TxId>>,
     pending_inputs_by_tx: HashMap<TxId, PendingTx>,
     ttl_check: VecDeque<(SystemTime, TxId)>,
+    /// Pending transactions in the inspection order.
+    pub(crate) inspection_index: InspectionIndex,
     pub(crate) current_bytes: usize,
     pub(crate) current_txs: usize,
     pub(crate) current_gas: u64,
@@ -62,6 +70,7 @@ pub(crate) struct PendingTx {
     pub tx: ArcPoolTx,
     pub insertion_source: InsertionSource,
     pub missing_inputs: Vec<MissingInput>,
+    pub creation_instant: SystemTime,
 }
 
 #[derive(Debug, Hash, Eq, PartialEq, Copy, Clone)]
@@ -103,12 +112,17 @@ impl PendingPool {
             pending_txs_by_inputs: HashMap::default(),
             pending_inputs_by_tx: HashMap::default(),
             ttl_check: VecDeque::new(),
+            inspection_index: InspectionIndex::default(),
             current_bytes: 0,
             current_txs: 0,
             current_gas: 0,
         }
     }
 
+    pub fn contains(&self, tx_id: &TxId) -> bool {
+        self.pending_inputs_by_tx.contains_key(tx_id)
+    }
+
     pub fn insert_transaction(
         &mut self,
         transaction: ArcPoolTx,
@@ -116,11 +130,13 @@ impl PendingPool {
         missing_inputs: Vec<MissingInput>,
     ) {
         let tx_id = transaction.id();
+        let now = SystemTime::now();
         self.current_bytes = self
             .current_bytes
             .saturating_add(transaction.metered_bytes_size());
         self.current_gas = self.current_gas.saturating_add(transaction.max_gas());
         self.current_txs = self.current_txs.saturating_add(1);
+        self.inspection_index.insert(&transaction, now);
         for input in &missing_inputs {
             self.pending_txs_by_inputs
                 .entry(*input)
@@ -133,11 +149,11 @@ impl PendingPool {
                 tx: transaction,
                 insertion_source,
                 missing_inputs,
+                creation_instant: now,
             },
         );
         self.ttl_check.push_front((
-            SystemTime::now()
-                .checked_add(self.ttl)
+            now.checked_add(self.ttl)
                 .expect("The system time should be valid; qed"),
             tx_id,
         ));
@@ -171,9 +187,11 @@ impl PendingPool {
                         let PendingTx {
                             tx,
                             insertion_source,
-                            missing_inputs: _,
+                            creation_instant,
+                            ..
                         } = entry.remove();
                         self.decrease_pool_size(&tx);
+                        self.inspection_index.remove(&tx, creation_instant);
                         resolved_txs.push((tx, insertion_source));
                     }
                 }
@@ -191,9 +209,11 @@ impl PendingPool {
                 tx,
                 insertion_source,
                 missing_inputs,
+                creation_instant,
             }) = self.pending_inputs_by_tx.remove(&tx_id)
             {
                 self.decrease_pool_size(&tx);
+                self.inspection_index.remove(&tx, creation_instant);
                 for input in &missing_inputs {
                     // Remove tx_id from the list of unresolved transactions for this input
                     // If the list becomes empty, remove the entry
@@ -221,6 +241,35 @@ impl PendingPool {
         }
     }
 
+    /// Returns the pending transaction if it matches the `filter`.
+    pub fn inspect(
+        &self,
+        tx_id: &TxId,
+        filter: &InspectionFilter,
+    ) -> Option<InspectedTransaction> {
+        let pending_tx = self.pending_inputs_by_tx.get(tx_id)?;
+        let (utxos, contracts) = pending_tx.missing_inputs.iter().fold(
+            (vec![], vec![]),
+            |(mut utxos, mut contracts), input| {
+                match input {
+                    MissingInput::Utxo(utxo_id) => utxos.push(*utxo_id),
+                    MissingInput::Contract(contract_id) => contracts.push(*contract_id),
+                }
+                (utxos, contracts)
+            },
+        );
+        let reason = NotExecutableReason::MissingInputs { utxos, contracts };
+        filter
+            .matches(&pending_tx.tx, &[], Some(&reason))
+            .then(|| InspectedTransaction {
+                tx: pending_tx.tx.clone(),
+                bundle: vec![],
+                creation_instant: pending_tx.creation_instant,
+                dependents: 0,
+                not_executable_reason: Some(reason),
+            })
+    }
+
     fn decrease_pool_size(&mut self, tx: &ArcPoolTx) {
         self.current_bytes = self.current_bytes.saturating_sub(tx.metered_bytes_size());
         self.current_gas = self.current_gas.saturating_sub(tx.max_gas());
@@ -424,6 +473,7 @@ mod tests {
             dependent_tx.clone(),
             InsertionSource::RPC {
                 response_channel: None,
+                replaceable: true,
             },
             vec![MissingInput::Utxo(utxo)],
         );
@@ -475,6 +525,7 @@ mod tests {
             dependent_tx.clone(),
             InsertionSource::RPC {
                 response_channel: None,
+                replaceable: true,
             },
             vec![MissingInput::Contract(contract_id)],
         );
@@ -512,6 +563,7 @@ mod tests {
             dependent_tx_1.clone(),
             InsertionSource::RPC {
                 response_channel: None,
+                replaceable: true,
             },
             vec![MissingInput::Utxo(utxo)],
         );
@@ -519,6 +571,7 @@ mod tests {
             dependent_tx_2.clone(),
             InsertionSource::RPC {
                 response_channel: None,
+                replaceable: true,
             },
             vec![MissingInput::Utxo(utxo)],
         );
@@ -564,6 +617,7 @@ mod tests {
             dependent_tx.clone(),
             InsertionSource::RPC {
                 response_channel: None,
+                replaceable: true,
             },
             vec![MissingInput::Utxo(utxo_1), MissingInput::Utxo(utxo_2)],
         );
@@ -587,6 +641,7 @@ mod tests {
             tx1.clone(),
             InsertionSource::RPC {
                 response_channel: None,
+                replaceable: true,
             },
             vec![MissingInput::Utxo(UtxoId::new(tx1.id(), 0))],
         );
@@ -594,6 +649,7 @@ mod tests {
             tx2.clone(),
             InsertionSource::RPC {
                 response_channel: None,
+                replaceable: true,
             },
             vec![MissingInput::Utxo(UtxoId::new(tx2.id(), 0))],
         );
@@ -1,8 +1,12 @@
 mod collisions;
+pub(crate) mod owners;
 
 use core::num::NonZeroUsize;
 use std::{
-    collections::HashMap,
+    collections::{
+        HashMap,
+        HashSet,
+    },
     iter,
     sync::Arc,
     time::{
@@ -15,6 +19,7 @@ use collisions::CollisionsExt;
 use fuel_core_metrics::txpool_metrics::txpool_metrics;
 use fuel_core_types::{
     fuel_tx::{
+        Address,
         TxId,
         field::BlobId,
     },
@@ -31,6 +36,11 @@ use fuel_core_types::{
     tai64::Tai64,
 };
 use num_rational::Ratio;
+use owners::{
+    OwnersUsage,
+    Usage,
+    owners,
+};
 
 use crate::{
     collision_manager::{
@@ -39,13 +49,22 @@ use crate::{
     },
     config::Config,
     error::{
+        BundleError,
+        CollisionReason,
         DependencyError,
         Error,
         InputValidationError,
         InsertionErrorType,
     },
     extracted_outputs::ExtractedOutputs,
+    inspection::{
+        InspectedTransaction,
+        InspectionFilter,
+        InspectionIndex,
+        NotExecutableReason,
+    },
     ports::{
+        AdmissionFilter,
         TxPoolPersistentStorage,
         TxStatusManager as TxStatusManagerTrait,
     },
@@ -64,8 +83,6 @@ use crate::{
     error::RemovedReason,
     spent_inputs::SpentInputs,
 };
-#[cfg(test)]
-use std::collections::HashSet;
 
 #[derive(Debug, Clone, Copy, Default)]
 pub struct TxPoolStats {
@@ -95,16 +112,28 @@ pub struct Pool<S, SI, CM, SA, TxStatusManager> {
     pub(crate) current_gas: u64,
     /// Current pool size in bytes.
     pub(crate) current_bytes_size: usize,
+    /// The resources of the pool used by each owner.
+    pub(crate) owners_usage: OwnersUsage,
+    /// Transactions submitted as non-replaceable, they can't be replaced by
+    /// colliding transactions regardless of the tip.
+    pub(crate) non_replaceable_txs: HashSet<TxId>,
+    /// Transactions of the pool in the inspection order.
+    pub(crate) inspection_index: InspectionIndex,
     /// The current pool gas.
     pub(crate) pool_stats_sender: tokio::sync::watch::Sender<TxPoolStats>,
-    /// New executable transactions notifier.
+    /// New executable transactions notifier. The pool updates the stats before
+    /// the notification, because the block producer reads the fill of the pool
+    /// from them when it is notified.
     pub(crate) new_executable_txs_notifier: tokio::sync::watch::Sender<()>,
     /// Transaction status manager.
     pub(crate) tx_status_manager: Arc<TxStatusManager>,
+    /// The filter of transactions admitted into the pool.
+    pub(crate) admission_filter: Arc<dyn AdmissionFilter>,
 }
 
 impl<S, SI, CM, SA, TxStatusManager> Pool<S, SI, CM, SA, TxStatusManager> {
     /// Create a new pool.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         storage: S,
         collision_manager: CM,
@@ -113,6 +142,7 @@ impl<S, SI, CM, SA, TxStatusManager> Pool<S, SI, CM, SA, TxStatusManager> {
         pool_stats_sender: tokio::sync::watch::Sender<TxPoolStats>,
         new_executable_txs_notifier: tokio::sync::watch::Sender<()>,
         tx_status_manager: Arc<TxStatusManager>,
+        admission_filter: Arc<dyn AdmissionFilter>,
     ) -> Self {
         let capacity = NonZeroUsize::new(config.pool_limits.max_txs.saturating_add(1))
             .expect("Max txs is greater than 0");
@@ -127,9 +157,13 @@ impl<S, SI, CM, SA, TxStatusManager> Pool<S, SI, CM, SA, TxStatusManager> {
             spent_inputs,
             current_gas: 0,
             current_bytes_size: 0,
+            owners_usage: OwnersUsage::default(),
+            non_replaceable_txs: HashSet::new(),
+            inspection_index: InspectionIndex::default(),
             pool_stats_sender,
             new_executable_txs_notifier,
             tx_status_manager,
+            admission_filter,
         }
     }
 
@@ -200,45 +234,17 @@ where
             removed_transactions.extend(removed);
         }
 
-        let tx = checked_transaction.tx();
-        let tx_id = tx.id();
-        let gas = tx.max_gas();
-        let creation_instant = SystemTime::now();
-        let bytes_size = tx.metered_bytes_size();
-
-        let storage_id = self
-            .storage
-            .store_transaction(checked_transaction, creation_instant);
-
-        self.current_gas = self.current_gas.saturating_add(gas);
-        self.current_bytes_size = self.current_bytes_size.saturating_add(bytes_size);
-        debug_assert!(!self.tx_id_to_storage_id.contains_key(&tx_id));
-        self.tx_id_to_storage_id.insert(tx_id, storage_id);
-
-        if self.config.metrics {
-            txpool_metrics().tx_size.observe(bytes_size as f64);
-        };
-
+        let storage_id = self.store_checked_transaction(checked_transaction);
         let tx =
             Storage::get(&self.storage, &storage_id).expect("Transaction is set above");
-        self.collision_manager.on_stored_transaction(storage_id, tx);
+        let tx_id = tx.transaction.id();
+        self.tx_status_manager
+            .status_update(tx_id, submitted_status(tx.creation_instant));
 
-        let duration = i64::try_from(
-            creation_instant
-                .duration_since(SystemTime::UNIX_EPOCH)
-                .expect("Time can't be less than UNIX EPOCH")
-                .as_secs(),
-        )
-        .expect("Duration is less than i64::MAX");
-        self.tx_status_manager.status_update(
-            tx_id,
-            TransactionStatus::submitted(Tai64::from_unix(duration)),
-        );
         // No dependencies directly in the graph and the sorted transactions
         if !has_dependencies {
             self.selection_algorithm
                 .new_executable_transaction(storage_id, tx);
-            self.new_executable_txs_notifier.send_replace(());
         }
 
         let removed_transactions = removed_transactions
@@ -259,15 +265,249 @@ where
         }
 
         self.update_stats();
+        if !has_dependencies {
+            self.new_executable_txs_notifier.send_replace(());
+        }
         Ok(())
     }
 
-    fn update_stats(&self) {
-        let _ = self.pool_stats_sender.send(TxPoolStats {
+    /// Insert the bundle of transactions into the pool.
+    ///
+    /// The transactions of the bundle are stored as a unit: the bundle is selected into
+    /// the block all-or-nothing and contiguously, and it is removed from the pool as a whole.
+    /// If any transaction of the bundle can't be inserted, the whole bundle is rejected.
+    pub fn insert_bundle(
+        &mut self,
+        txs: Vec<ArcPoolTx>,
+        persistent_storage: &impl TxPoolPersistentStorage,
+    ) -> Result<(), Error> {
+        let insertion_result = self.insert_bundle_inner(txs, persistent_storage);
+        self.register_transaction_counts();
+        insertion_result
+    }
+
+    fn insert_bundle_inner(
+        &mut self,
+        txs: Vec<ArcPoolTx>,
+        persistent_storage: &impl TxPoolPersistentStorage,
+    ) -> Result<(), Error> {
+        if txs.is_empty() {
+            return Err(Error::Bundle(BundleError::Empty))
+        }
+        if txs.len() > self.config.max_bundle_size {
+            return Err(Error::Bundle(BundleError::TooManyTransactions(
+                txs.len(),
+                self.config.max_bundle_size,
+            )))
+        }
+
+        // The bundle can't replace other transactions of the pool,
+        // so it should fit into the free space of the pool.
+        let (gas, bytes_size) = txs.iter().fold((0u64, 0usize), |(gas, bytes), tx| {
+            (
+                gas.saturating_add(tx.max_gas()),
+                bytes.saturating_add(tx.metered_bytes_size()),
+            )
+        });
+        if self.current_gas.saturating_add(gas) > self.config.pool_limits.max_gas
+            || self.current_bytes_size.saturating_add(bytes_size)
+                > self.config.pool_limits.max_bytes_size
+            || self.tx_count().saturating_add(txs.len()) > self.config.pool_limits.max_txs
+        {
+            return Err(Error::NotInsertedLimitHit)
+        }
+
+        let mut members = Vec::with_capacity(txs.len());
+        for (index, tx) in txs.into_iter().enumerate() {
+            match self.store_bundle_transaction(tx, &members, persistent_storage) {
+                Ok(storage_id) => members.push(storage_id),
+                Err(error) => {
+                    for storage_id in members.into_iter().rev() {
+                        if let Some(removed) = self.storage.remove_transaction(storage_id)
+                        {
+                            self.update_components_and_caches_on_removal(iter::once(
+                                &removed,
+                            ));
+                        }
+                    }
+                    let error = match error {
+                        Error::Bundle(error) => error,
+                        error => BundleError::InvalidTransaction {
+                            index,
+                            error: Box::new(error),
+                        },
+                    };
+                    return Err(Error::Bundle(error))
+                }
+            }
+        }
+        self.storage.store_bundle(&members);
+
+        for storage_id in &members {
+            let tx = Storage::get(&self.storage, storage_id)
+                .expect("Transaction of the bundle is set above");
+            self.tx_status_manager.status_update(
+                tx.transaction.id(),
+                submitted_status(tx.creation_instant),
+            );
+        }
+
+        // Only the first transaction of the bundle is executable,
+        // other transactions are selected along with it.
+        let head = *members.first().expect("The bundle is not empty; qed");
+        let tx = Storage::get(&self.storage, &head).expect("Transaction is set above");
+        self.selection_algorithm
+            .new_executable_transaction(head, tx);
+
+        self.update_stats();
+        self.new_executable_txs_notifier.send_replace(());
+        Ok(())
+    }
+
+    /// Stores the transaction of the bundle. Transactions of the bundle can depend only
+    /// on the previous transactions of the same bundle and can't collide with others.
+    fn store_bundle_transaction(
+        &mut self,
+        tx: ArcPoolTx,
+        previous_members: &[S::StorageIndex],
+        persistent_storage: &impl TxPoolPersistentStorage,
+    ) -> Result<S::StorageIndex, Error> {
+        let tx_id = tx.id();
+        if self.spent_inputs.is_spent_tx(&tx_id)
+            || persistent_storage
+                .contains_tx(&tx_id)
+                .map_err(|e| Error::Database(format!("{:?}", e)))?
+        {
+            return Err(Error::InputValidation(InputValidationError::DuplicateTxId(
+                tx_id,
+            )))
+        }
+
+        let CanStoreTransaction {
+            checked_transaction,
+            transactions_to_remove,
+            collisions,
+            _guard,
+        } = self
+            .can_insert_transaction(tx, persistent_storage)
+            .map_err(|error| match error {
+                InsertionErrorType::Error(error) => error,
+                InsertionErrorType::MissingInputs(missing_inputs) => missing_inputs
+                    .first()
+                    .expect("Missing inputs is not empty; qed")
+                    .into(),
+            })?;
+
+        if !collisions.is_empty() {
+            return Err(Error::Bundle(BundleError::Collision(tx_id)))
+        }
+        if !transactions_to_remove.is_empty() {
+            return Err(Error::NotInsertedLimitHit)
+        }
+        if checked_transaction
+            .all_dependencies()
+            .iter()
+            .any(|dependency| !previous_members.contains(dependency))
+        {
+            return Err(Error::Bundle(BundleError::DependsOnNonMember(tx_id)))
+        }
+
+        Ok(self.store_checked_transaction(checked_transaction))
+    }
+
+    /// Stores the transaction and updates the components of the pool.
+    fn store_checked_transaction(
+        &mut self,
+        checked_transaction: S::CheckedTransaction,
+    ) -> S::StorageIndex {
+        let tx = checked_transaction.tx();
+        let tx_id = tx.id();
+        let gas = tx.max_gas();
+        let creation_instant = SystemTime::now();
+        let bytes_size = tx.metered_bytes_size();
+
+        let storage_id = self
+            .storage
+            .store_transaction(checked_transaction, creation_instant);
+
+        self.current_gas = self.current_gas.saturating_add(gas);
+        self.current_bytes_size = self.current_bytes_size.saturating_add(bytes_size);
+        debug_assert!(!self.tx_id_to_storage_id.contains_key(&tx_id));
+        self.tx_id_to_storage_id.insert(tx_id, storage_id);
+
+        if self.config.metrics {
+            txpool_metrics().tx_size.observe(bytes_size as f64);
+        };
+
+        let tx =
+            Storage::get(&self.storage, &storage_id).expect("Transaction is set above");
+        self.owners_usage.on_stored_transaction(&tx.transaction);
+        self.inspection_index
+            .insert(&tx.transaction, tx.creation_instant);
+        self.collision_manager.on_stored_transaction(storage_id, tx);
+
+        storage_id
+    }
+
+    /// Marks the transaction of the pool as non-replaceable.
+    pub fn mark_non_replaceable(&mut self, tx_id: TxId) {
+        if self.tx_id_to_storage_id.contains_key(&tx_id) {
+            self.non_replaceable_txs.insert(tx_id);
+        }
+    }
+
+    /// Checks the rules of the replacement that depend on the state of the pool:
+    /// - None of the collided transactions is non-replaceable.
+    /// - The collided transactions, along with their dependents and the bundles
+    ///   removed with them, contain no more than `max_evicted_transactions`.
+    fn check_replacement(
+        &self,
+        collisions: &Collisions<S::StorageIndex>,
+    ) -> Result<(), CollisionReason> {
+        let mut evicted = HashSet::new();
+        for collision in collisions.keys() {
+            let tx_id = Storage::get(&self.storage, collision)
+                .ok_or(CollisionReason::Unknown)?
+                .transaction
+                .id();
+            if self.non_replaceable_txs.contains(&tx_id) {
+                return Err(CollisionReason::NonReplaceable(tx_id));
+            }
+            self.collect_subtree(*collision, &mut evicted);
+        }
+
+        let max = self.config.replacement_policy.max_evicted_transactions;
+        if evicted.len() > max {
+            return Err(CollisionReason::TooManyEvictions {
+                evicted: evicted.len(),
+                max,
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns other transactions of the bundle the transaction belongs to.
+    fn bundle_siblings(&self, storage_id: &S::StorageIndex) -> Vec<TxId> {
+        self.storage
+            .get_bundle(storage_id)
+            .unwrap_or_default()
+            .iter()
+            .filter(|member| *member != storage_id)
+            .filter_map(|member| Storage::get(&self.storage, member))
+            .map(|member| member.transaction.id())
+            .collect()
+    }
+
+    fn stats(&self) -> TxPoolStats {
+        TxPoolStats {
             tx_count: self.tx_count() as u64,
             total_size: self.current_bytes_size as u64,
             total_gas: self.current_gas,
-        });
+        }
+    }
+
+    fn update_stats(&self) {
+        let _ = self.pool_stats_sender.send(self.stats());
     }
 
     /// Check if a transaction can be inserted into the pool.
@@ -294,6 +534,10 @@ where
             .check_blacklisting(&tx)
             .map_err(Error::Blacklisted)?;
 
+        self.admission_filter
+            .admit(&tx, &self.stats())
+            .map_err(Error::NotAdmitted)?;
+
         Self::check_blob_does_not_exist(&tx, persistent_storage)?;
         self.storage.validate_inputs(
             &tx,
@@ -321,14 +565,23 @@ where
                 checked_transaction.tx(),
                 has_dependencies,
                 &self.storage,
+                &self.config.replacement_policy,
             )
             .map_err(Error::Collided)?;
+        self.check_replacement(&collisions)
+            .map_err(Error::Collided)?;
 
-        let can_fit_into_pool = self.can_fit_into_pool(&checked_transaction)?;
+        let (mut transactions_to_remove, freed) =
+            self.find_owners_free_space(&checked_transaction, &collisions)?;
+
+        let can_fit_into_pool = self.can_fit_into_pool(&checked_transaction, freed)?;
 
-        let mut transactions_to_remove = vec![];
         if let SpaceCheckResult::NotEnoughSpace(left) = can_fit_into_pool {
-            transactions_to_remove = self.find_free_space(left, &checked_transaction)?;
+            transactions_to_remove.extend(self.find_free_space(
+                left,
+                &checked_transaction,
+                &transactions_to_remove,
+            )?);
         }
 
         let can_store_transaction = CanStoreTransaction {
@@ -341,6 +594,52 @@ where
         Ok(can_store_transaction)
     }
 
+    /// Checks if the immature transaction can be accepted by the future pool.
+    ///
+    /// The transaction passes the same admission checks as transactions of the pool,
+    /// and the future transactions of the owner count against the per-owner limits
+    /// along with the transactions of the owner in the pool.
+    pub fn can_insert_future_transaction(
+        &self,
+        tx: &ArcPoolTx,
+        future_owners_usage: &OwnersUsage,
+    ) -> Result<(), Error> {
+        if tx.max_gas() == 0 {
+            return Err(Error::InputValidation(InputValidationError::MaxGasZero));
+        }
+
+        let tx_id = tx.id();
+        if self.tx_id_to_storage_id.contains_key(&tx_id)
+            || self.spent_inputs.is_spent_tx(&tx_id)
+        {
+            return Err(Error::InputValidation(InputValidationError::DuplicateTxId(
+                tx_id,
+            )));
+        }
+
+        self.config
+            .black_list
+            .check_blacklisting(tx)
+            .map_err(Error::Blacklisted)?;
+
+        self.admission_filter
+            .admit(tx, &self.stats())
+            .map_err(Error::NotAdmitted)?;
+
+        for owner in owners(tx) {
+            let usage = self
+                .owners_usage
+                .get(&owner)
+                .saturating_add(future_owners_usage.get(&owner))
+                .saturating_add(Usage::of(tx));
+            if usage.exceeds(&self.config.per_owner_limits) {
+                return Err(Error::NotInsertedOwnerLimitHit(owner));
+            }
+        }
+
+        Ok(())
+    }
+
     fn record_transaction_time_in_txpool(tx: &StorageData) {
         if let Ok(elapsed) = tx.creation_instant.elapsed() {
             txpool_metrics()
@@ -426,6 +725,40 @@ where
         self.tx_id_to_storage_id.keys()
     }
 
+    /// Returns the transaction of the pool if it matches the `filter`.
+    /// Transactions that depend on other transactions of the pool are not executable.
+    /// Members of a bundle are returned only as a part of the bundle's head.
+    pub fn inspect(
+        &self,
+        tx_id: &TxId,
+        filter: &InspectionFilter,
+    ) -> Option<InspectedTransaction> {
+        let storage_id = self.tx_id_to_storage_id.get(tx_id)?;
+        let storage_data = self.storage.get(storage_id)?;
+        let bundle = match self.storage.get_bundle(storage_id) {
+            Some([head, members @ ..]) if head == storage_id => members
+                .iter()
+                .filter_map(|member| self.storage.get(member))
+                .map(|member| member.transaction.clone())
+                .collect(),
+            Some(_) => return None,
+            None => vec![],
+        };
+        let reason = self
+            .storage
+            .has_dependencies(storage_id)
+            .then_some(NotExecutableReason::WaitsForDependencies);
+        filter
+            .matches(&storage_data.transaction, &bundle, reason.as_ref())
+            .then(|| InspectedTransaction {
+                tx: storage_data.transaction.clone(),
+                bundle,
+                creation_instant: storage_data.creation_instant,
+                dependents: storage_data.number_dependents_in_chain.saturating_sub(1),
+                not_executable_reason: reason,
+            })
+    }
+
     /// Process a preconfirmed transaction as committed while recording its spent
     /// inputs so they can be rolled back later if the transaction is not
     /// included in the canonical block.
@@ -438,9 +771,13 @@ where
             self.spent_inputs.move_spender_to_tentative(tx_id);
         }
         self.spent_inputs.spend_inputs_by_tx_id(tx_id);
+        self.non_replaceable_txs.remove(&tx_id);
+        let mut bundle_siblings = vec![];
+        let mut new_executable_transaction = false;
         if let Some(storage_id) = self.tx_id_to_storage_id.remove(&tx_id) {
             let dependents: Vec<S::StorageIndex> =
                 self.storage.get_direct_dependents(storage_id).collect();
+            bundle_siblings = self.bundle_siblings(&storage_id);
             let Some(transaction) = self.storage.remove_transaction(storage_id) else {
                 // Invariant violation. Panic in tests, log in production.
                 debug_assert!(false, "Storage data not found for the transaction");
@@ -457,7 +794,6 @@ where
                 .spend_inputs(tx_id, transaction.transaction.inputs());
             self.update_components_and_caches_on_removal(iter::once(&transaction));
 
-            let mut new_executable_transaction = false;
             for dependent in dependents {
                 if !self.storage.has_dependencies(&dependent)
                     && let Some(storage_data) = self.storage.get(&dependent)
@@ -467,12 +803,19 @@ where
                     new_executable_transaction = true;
                 }
             }
-            if new_executable_transaction {
-                self.new_executable_txs_notifier.send_replace(());
-            }
+        }
+
+        if !bundle_siblings.is_empty() {
+            self.remove_transactions_and_dependents(
+                bundle_siblings,
+                Error::Removed(RemovedReason::BundleBroken(tx_id)),
+            );
         }
 
         self.update_stats();
+        if new_executable_transaction {
+            self.new_executable_txs_notifier.send_replace(());
+        }
     }
 
     /// Process committed transactions:
@@ -480,11 +823,17 @@ where
     /// - Notify about possible new executable transactions.
     pub fn process_committed_transactions(&mut self, tx_ids: impl Iterator<Item = TxId>) {
         let mut transactions_to_promote = vec![];
+        let mut broken_bundles = vec![];
         for tx_id in tx_ids {
             self.spent_inputs.spend_inputs_by_tx_id(tx_id);
+            self.non_replaceable_txs.remove(&tx_id);
             if let Some(storage_id) = self.tx_id_to_storage_id.remove(&tx_id) {
                 let dependents: Vec<S::StorageIndex> =
                     self.storage.get_direct_dependents(storage_id).collect();
+                let bundle_siblings = self.bundle_siblings(&storage_id);
+                if !bundle_siblings.is_empty() {
+                    broken_bundles.push((tx_id, bundle_siblings));
+                }
                 let Some(transaction) = self.storage.remove_transaction(storage_id)
                 else {
                     // Invariant violation. Panic in tests, log in production.
@@ -523,18 +872,155 @@ where
             new_executable_transaction = true;
         }
 
-        if new_executable_transaction {
-            self.new_executable_txs_notifier.send_replace(());
+        // The rest of the bundle can't be included atomically anymore. Transactions
+        // of the bundle committed in the same block are already removed from the pool.
+        for (tx_id, bundle_siblings) in broken_bundles {
+            self.remove_transactions_and_dependents(
+                bundle_siblings,
+                Error::Removed(RemovedReason::BundleBroken(tx_id)),
+            );
         }
 
         self.update_stats();
+        if new_executable_transaction {
+            self.new_executable_txs_notifier.send_replace(());
+        }
     }
 
     /// Check if the pool has enough space to store a transaction.
     ///
+    /// Checks that every owner of the transaction stays within the per-owner limits.
+    ///
+    /// If the transaction exceeds the limits of its owner, the least worth transactions
+    /// of this owner are marked for removal along with their dependents subtree.
+    /// The transactions of the collided subtrees are considered as already removed.
+    ///
+    /// Returns the list of transactions that must be removed from the pool and the
+    /// resources of the pool freed by their removal.
+    ///
+    /// Returns an error if the owner can't make space for the transaction
+    /// by evicting less worth transactions.
+    fn find_owners_free_space(
+        &self,
+        checked_transaction: &S::CheckedTransaction,
+        collisions: &Collisions<S::StorageIndex>,
+    ) -> Result<(Vec<S::StorageIndex>, Usage), Error> {
+        let tx = checked_transaction.tx();
+        let limits = &self.config.per_owner_limits;
+        let new_tx_ratio = Ratio::new(tx.tip(), tx.max_gas());
+        let has_dependencies = !checked_transaction.all_dependencies().is_empty();
+
+        let mut removed = HashSet::new();
+        for collision in collisions.keys() {
+            self.collect_subtree(*collision, &mut removed);
+        }
+
+        let mut transactions_to_remove = vec![];
+        let mut freed = Usage::default();
+        for owner in owners(tx) {
+            let mut usage = self
+                .owners_usage
+                .get(&owner)
+                .saturating_add(Usage::of(tx))
+                .saturating_sub(self.owner_usage_of(&owner, removed.iter()));
+
+            if !usage.exceeds(limits) {
+                continue;
+            }
+
+            // If the transaction has a dependency, we can't remove less worth transactions
+            // of the owner because they could be dependencies of the transaction.
+            if has_dependencies {
+                return Err(Error::NotInsertedOwnerLimitHit(owner));
+            }
+
+            let mut sorted_txs = self.selection_algorithm.get_less_worth_txs();
+            while usage.exceeds(limits) {
+                let storage_id = sorted_txs
+                    .next()
+                    .ok_or(Error::NotInsertedOwnerLimitHit(owner))?;
+
+                if removed.contains(storage_id) {
+                    continue;
+                }
+
+                let Some(storage_data) = self.storage.get(storage_id) else {
+                    debug_assert!(
+                        false,
+                        "Storage data not found for one of the less worth transactions"
+                    );
+                    tracing::warn!(
+                        "Storage data not found for one of the less worth transactions"
+                    );
+                    continue;
+                };
+
+                if !owners(&storage_data.transaction).contains(&owner) {
+                    continue;
+                }
+
+                let ratio = Ratio::new(
+                    storage_data.dependents_cumulative_tip,
+                    storage_data.dependents_cumulative_gas,
+                );
+                if ratio > new_tx_ratio {
+                    return Err(Error::NotInsertedOwnerLimitHit(owner));
+                }
+
+                let mut subtree = HashSet::new();
+                self.collect_subtree(*storage_id, &mut subtree);
+                subtree.retain(|index| !removed.contains(index));
+                usage = usage.saturating_sub(self.owner_usage_of(&owner, subtree.iter()));
+                for index in &subtree {
+                    if let Some(data) = self.storage.get(index) {
+                        freed = freed.saturating_add(Usage::of(&data.transaction));
+                    }
+                }
+                removed.extend(subtree);
+
+                transactions_to_remove.push(*storage_id);
+            }
+        }
+
+        Ok((transactions_to_remove, freed))
+    }
+
+    /// Collects the transaction along with its dependents subtree and the
+    /// bundles they belong to, because they are removed from the pool together.
+    fn collect_subtree(
+        &self,
+        storage_id: S::StorageIndex,
+        subtree: &mut HashSet<S::StorageIndex>,
+    ) {
+        let mut to_visit = vec![storage_id];
+        while let Some(storage_id) = to_visit.pop() {
+            if !subtree.insert(storage_id) {
+                continue;
+            }
+            to_visit.extend(self.storage.get_direct_dependents(storage_id));
+            if let Some(bundle) = self.storage.get_bundle(&storage_id) {
+                to_visit.extend(bundle.iter().copied());
+            }
+        }
+    }
+
+    /// Returns the resources used by the transactions of the owner among `storage_ids`.
+    fn owner_usage_of<'a>(
+        &'a self,
+        owner: &Address,
+        storage_ids: impl Iterator<Item = &'a S::StorageIndex>,
+    ) -> Usage {
+        storage_ids
+            .filter_map(|storage_id| self.storage.get(storage_id))
+            .filter(|data| owners(&data.transaction).contains(owner))
+            .fold(Usage::default(), |usage, data| {
+                usage.saturating_add(Usage::of(&data.transaction))
+            })
+    }
+
     /// It returns `true` if:
     /// - Pool is not full
-    /// - Removing colliding subtree is enough to make space
+    /// - Removing transactions `freed` by the per-owner limits is enough to make space
     ///
     /// It returns an error if the pool is full and transactions has dependencies.
     ///
@@ -542,13 +1028,24 @@ where
     fn can_fit_into_pool(
         &self,
         checked_transaction: &S::CheckedTransaction,
+        freed: Usage,
     ) -> Result<SpaceCheckResult, Error> {
         let tx = checked_transaction.tx();
         let tx_gas = tx.max_gas();
         let bytes_size = tx.metered_bytes_size();
-        let gas_left = self.current_gas.saturating_add(tx_gas);
-        let bytes_left = self.current_bytes_size.saturating_add(bytes_size);
-        let txs_left = self.tx_id_to_storage_id.len().saturating_add(1);
+        let gas_left = self
+            .current_gas
+            .saturating_add(tx_gas)
+            .saturating_sub(freed.gas);
+        let bytes_left = self
+            .current_bytes_size
+            .saturating_add(bytes_size)
+            .saturating_sub(freed.bytes);
+        let txs_left = self
+            .tx_id_to_storage_id
+            .len()
+            .saturating_add(1)
+            .saturating_sub(freed.txs);
         if gas_left <= self.config.pool_limits.max_gas
             && bytes_left <= self.config.pool_limits.max_bytes_size
             && txs_left <= self.config.pool_limits.max_txs
@@ -582,6 +1079,7 @@ where
         &self,
         left: NotEnoughSpace,
         checked_transaction: &S::CheckedTransaction,
+        already_removed: &[S::StorageIndex],
     ) -> Result<Vec<S::StorageIndex>, Error> {
         let tx = checked_transaction.tx();
         let NotEnoughSpace {
@@ -607,7 +1105,9 @@ where
         {
             let storage_id = sorted_txs.next().ok_or(Error::NotInsertedLimitHit)?;
 
-            if checked_transaction.all_dependencies().contains(storage_id) {
+            if checked_transaction.all_dependencies().contains(storage_id)
+                || already_removed.contains(storage_id)
+            {
                 continue;
             }
 
@@ -841,6 +1341,10 @@ where
                 .current_bytes_size
                 .saturating_sub(tx.metered_bytes_size());
             self.tx_id_to_storage_id.remove(&tx.id());
+            self.non_replaceable_txs.remove(&tx.id());
+            self.owners_usage.on_removed_transaction(tx);
+            self.inspection_index
+                .remove(tx, storage_entry.creation_instant);
             self.collision_manager.on_removed_transaction(tx);
             self.selection_algorithm
                 .on_removed_transaction(storage_entry);
@@ -865,6 +1369,17 @@ where
     }
 }
 
+pub(crate) fn submitted_status(creation_instant: SystemTime) -> TransactionStatus {
+    let duration = i64::try_from(
+        creation_instant
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("Time can't be less than UNIX EPOCH")
+            .as_secs(),
+    )
+    .expect("Duration is less than i64::MAX");
+    TransactionStatus::submitted(Tai64::from_unix(duration))
+}
+
 pub struct NotEnoughSpace {
     gas_left: u64,
     bytes_left: usize,
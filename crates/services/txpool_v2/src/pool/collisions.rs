@@ -1,5 +1,9 @@
 use crate::{
     collision_manager::Collisions,
+    config::{
+        ReplaceableInput,
+        ReplacementPolicy,
+    },
     error::CollisionReason,
     storage::Storage,
 };
@@ -15,6 +19,7 @@ pub trait CollisionsExt<S> {
         tx: &PoolTransaction,
         has_dependencies: bool,
         storage: &S,
+        policy: &ReplacementPolicy,
     ) -> Result<(), CollisionReason>;
 }
 
@@ -32,11 +37,19 @@ where
     /// - A transaction doesn't have dependencies:
     ///     - A new transaction can be accepted if its profitability is higher
     ///       than the collided subtrees'.
+    /// - In both cases, the replacement must satisfy the `ReplacementPolicy`:
+    ///     - None of the collided inputs is non-replaceable.
+    ///     - The profitability is higher by at least `min_tip_bump_percentage`.
+    ///
+    /// The rules that depend on the state of the whole pool, like the opt-out of
+    /// the collided transactions and the number of evicted transactions,
+    /// are checked by the pool.
     fn check_collision_requirements(
         &self,
         tx: &PoolTransaction,
         has_dependencies: bool,
         storage: &S,
+        policy: &ReplacementPolicy,
     ) -> Result<(), CollisionReason> {
         if has_dependencies && self.len() > 1 {
             return Err(CollisionReason::MultipleCollisions);
@@ -59,12 +72,61 @@ where
                     return Err(CollisionReason::Unknown);
                 }
             }
+
+            if let Some(input) = reason
+                .iter()
+                .filter_map(replaceable_input)
+                .find(|input| policy.non_replaceable_inputs.contains(input))
+            {
+                return Err(CollisionReason::ReplacementDisabled(input));
+            }
+
+            if !is_bumped_enough(tx, collision, storage, policy.min_tip_bump_percentage)?
+            {
+                return Err(CollisionReason::InsufficientTipBump {
+                    min_tip_bump_percentage: policy.min_tip_bump_percentage,
+                });
+            }
         }
 
         Ok(())
     }
 }
 
+fn replaceable_input(reason: &CollisionReason) -> Option<ReplaceableInput> {
+    match reason {
+        CollisionReason::Utxo(_) => Some(ReplaceableInput::Coin),
+        CollisionReason::Message(_) => Some(ReplaceableInput::Message),
+        CollisionReason::ContractCreation(_) => Some(ReplaceableInput::ContractCreation),
+        CollisionReason::Blob(_) => Some(ReplaceableInput::Blob),
+        _ => None,
+    }
+}
+
+/// Checks that the tip per gas of the transaction exceeds the tip per gas of the
+/// collided subtree by at least `min_tip_bump_percentage` percent.
+fn is_bumped_enough<S>(
+    tx: &PoolTransaction,
+    collision: &S::StorageIndex,
+    storage: &S,
+    min_tip_bump_percentage: u16,
+) -> Result<bool, CollisionReason>
+where
+    S: Storage,
+{
+    let colliding_tx = storage.get(collision).ok_or(CollisionReason::Unknown)?;
+    let new_tx_ratio = Ratio::new(
+        u128::from(tx.tip()).saturating_mul(100),
+        u128::from(tx.max_gas()),
+    );
+    let required_ratio = Ratio::new(
+        u128::from(colliding_tx.dependents_cumulative_tip)
+            .saturating_mul(u128::from(min_tip_bump_percentage).saturating_add(100)),
+        u128::from(colliding_tx.dependents_cumulative_gas),
+    );
+    Ok(new_tx_ratio >= required_ratio)
+}
+
 fn is_better_than_collision<S>(
     tx: &PoolTransaction,
     collision: &S::StorageIndex,
@@ -0,0 +1,100 @@
+use std::collections::{
+    HashMap,
+    HashSet,
+};
+
+use fuel_core_types::{
+    fuel_tx::Address,
+    services::txpool::PoolTransaction,
+};
+
+use crate::config::PoolLimits;
+
+/// Returns the owners of the transaction: the owners of the coins
+/// and the recipients of the messages spent by the transaction.
+/// For predicates, the owner is the predicate root.
+pub(crate) fn owners(tx: &PoolTransaction) -> HashSet<Address> {
+    tx.inputs()
+        .iter()
+        .filter_map(|input| input.input_owner())
+        .copied()
+        .collect()
+}
+
+/// The resources of the pool used by transactions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Usage {
+    pub txs: usize,
+    pub gas: u64,
+    pub bytes: usize,
+}
+
+impl Usage {
+    pub fn of(tx: &PoolTransaction) -> Self {
+        Self {
+            txs: 1,
+            gas: tx.max_gas(),
+            bytes: tx.metered_bytes_size(),
+        }
+    }
+
+    pub fn saturating_add(self, other: Self) -> Self {
+        Self {
+            txs: self.txs.saturating_add(other.txs),
+            gas: self.gas.saturating_add(other.gas),
+            bytes: self.bytes.saturating_add(other.bytes),
+        }
+    }
+
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Self {
+            txs: self.txs.saturating_sub(other.txs),
+            gas: self.gas.saturating_sub(other.gas),
+            bytes: self.bytes.saturating_sub(other.bytes),
+        }
+    }
+
+    pub fn exceeds(&self, limits: &PoolLimits) -> bool {
+        self.txs > limits.max_txs
+            || self.gas > limits.max_gas
+            || self.bytes > limits.max_bytes_size
+    }
+}
+
+/// Tracks the resources of the pool used by each owner.
+/// A transaction with several owners is accounted for each of them.
+#[derive(Default)]
+pub(crate) struct OwnersUsage {
+    usage: HashMap<Address, Usage>,
+}
+
+impl OwnersUsage {
+    pub fn get(&self, owner: &Address) -> Usage {
+        self.usage.get(owner).copied().unwrap_or_default()
+    }
+
+    pub fn on_stored_transaction(&mut self, tx: &PoolTransaction) {
+        let usage = Usage::of(tx);
+        for owner in owners(tx) {
+            let entry = self.usage.entry(owner).or_default();
+            *entry = entry.saturating_add(usage);
+        }
+    }
+
+    pub fn on_removed_transaction(&mut self, tx: &PoolTransaction) {
+        let usage = Usage::of(tx);
+        for owner in owners(tx) {
+            if let Some(entry) = self.usage.get_mut(&owner) {
+                *entry = entry.saturating_sub(usage);
+                if entry.txs == 0 {
+                    self.usage.remove(&owner);
+                }
+            }
+        }
+    }
+
+    #[cfg(test)]
+    pub fn is_empty(&self) -> bool {
+        self.usage.is_empty()
+    }
+}
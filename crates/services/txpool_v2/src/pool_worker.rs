@@ -45,9 +45,17 @@ use crate::{
     config::ServiceChannelLimits,
     error::{
         Error,
+        InputValidationError,
         InsertionErrorType,
     },
+    future_pool::FuturePool,
+    inspection::{
+        self,
+        InspectedTransaction,
+        InspectionFilter,
+    },
     pending_pool::PendingPool,
+    pool::submitted_status,
     ports::{
         TxPoolPersistentStorage,
         TxStatusManager as TxStatusManagerTrait,
@@ -136,6 +144,7 @@ impl PoolWorkerInterface {
                         .tx_status_manager
                         .preconfirmations_update_listener(),
                     pending_pool: PendingPool::new(tx_pool.config.pending_pool_tx_ttl),
+                    future_pool: FuturePool::new(tx_pool.config.max_txs_ttl),
                     pool: tx_pool,
                     view_provider,
                     tentative_preconfs: BTreeMap::new(),
@@ -143,12 +152,10 @@ impl PoolWorkerInterface {
                 };
 
                 tokio_runtime.block_on(async {
-                    let mut pending_pool_expiration_check =
-                        tokio::time::interval(pending_pool_tx_ttl);
-                    pending_pool_expiration_check
-                        .set_missed_tick_behavior(MissedTickBehavior::Skip);
+                    let mut expiration_check = tokio::time::interval(pending_pool_tx_ttl);
+                    expiration_check.set_missed_tick_behavior(MissedTickBehavior::Skip);
                     loop {
-                        let result = worker.run(&mut pending_pool_expiration_check).await;
+                        let result = worker.run(&mut expiration_check).await;
 
                         if matches!(result, TaskNextAction::Stop) {
                             break;
@@ -201,6 +208,8 @@ pub(super) enum InsertionSource {
     },
     RPC {
         response_channel: Option<oneshot::Sender<Result<(), Error>>>,
+        /// If `false`, colliding transactions can't replace the transaction in the pool.
+        replaceable: bool,
     },
 }
 
@@ -209,6 +218,10 @@ pub(super) enum PoolInsertRequest {
         tx: ArcPoolTx,
         source: InsertionSource,
     },
+    InsertBundle {
+        txs: Vec<ArcPoolTx>,
+        response_channel: oneshot::Sender<Result<(), Error>>,
+    },
 }
 
 pub(super) enum PoolExtractBlockTransactions {
@@ -235,6 +248,16 @@ pub(super) enum PoolReadRequest {
         max_txs: usize,
         response_channel: oneshot::Sender<Vec<TxId>>,
     },
+    /// Returns the transactions that are absent from all pools,
+    /// including the pending and the future pools.
+    AbsentTxs {
+        tx_ids: Vec<TxId>,
+        response_channel: oneshot::Sender<Vec<TxId>>,
+    },
+    Inspect {
+        filter: InspectionFilter,
+        response_channel: oneshot::Sender<Vec<InspectedTransaction>>,
+    },
 }
 
 #[allow(clippy::upper_case_acronyms)]
@@ -250,7 +273,7 @@ pub(super) enum ExtendedInsertionSource {
 
 pub(super) enum PoolNotification {
     Inserted {
-        tx_id: TxId,
+        tx: ArcPoolTx,
         time: SystemTime,
         expiration: BlockHeight,
         source: ExtendedInsertionSource,
@@ -260,6 +283,19 @@ pub(super) enum PoolNotification {
         error: Error,
         source: InsertionSource,
     },
+    /// The transaction is accepted by the pending or the future pool.
+    InsertedNotExecutable { tx: ArcPoolTx, time: SystemTime },
+    BundleInserted {
+        /// Ids of the transactions of the bundle along with their expiration heights.
+        txs: Vec<(TxId, BlockHeight)>,
+        time: SystemTime,
+        response_channel: oneshot::Sender<Result<(), Error>>,
+    },
+    BundleErrorInsertion {
+        tx_ids: Vec<TxId>,
+        error: Error,
+        response_channel: oneshot::Sender<Result<(), Error>>,
+    },
 }
 
 pub(super) struct PoolWorker<View, TxStatusManager> {
@@ -272,6 +308,7 @@ pub(super) struct PoolWorker<View, TxStatusManager> {
     preconfirmations_update_listener: broadcast::Receiver<(TxId, PreConfirmationStatus)>,
     pool: TxPool<TxStatusManager>,
     pending_pool: PendingPool,
+    future_pool: FuturePool,
     view_provider: Arc<dyn AtomicView<LatestView = View>>,
     notification_sender: Sender<PoolNotification>,
     /// Tracks preconfirmed transaction IDs by their tentative block height.
@@ -289,10 +326,7 @@ where
     View: TxPoolPersistentStorage,
     TxStatusManager: TxStatusManagerTrait,
 {
-    pub async fn run(
-        &mut self,
-        pending_pool_expiration_check: &mut Interval,
-    ) -> TaskNextAction {
+    pub async fn run(&mut self, expiration_check: &mut Interval) -> TaskNextAction {
         let mut update_buffer = vec![];
         let mut read_buffer = vec![];
         let mut insert_buffer = vec![];
@@ -354,6 +388,15 @@ where
                         } => {
                             self.get_non_existing_txs(tx_ids, response_channel);
                         }
+                        PoolReadRequest::AbsentTxs {
+                            tx_ids,
+                            response_channel,
+                        } => {
+                            self.get_absent_txs(tx_ids, response_channel);
+                        }
+                        PoolReadRequest::Inspect { filter, response_channel } => {
+                            self.inspect(filter, response_channel);
+                        }
                     }
                 }
             }
@@ -362,22 +405,30 @@ where
                     return TaskNextAction::Stop;
                 }
                 for insert in insert_buffer {
-                    let PoolInsertRequest::Insert {
-                        tx,
-                        source,
-                    } = insert;
-
-                    self.insert(tx, source);
+                    match insert {
+                        PoolInsertRequest::Insert { tx, source } => {
+                            self.insert(tx, source);
+                        }
+                        PoolInsertRequest::InsertBundle { txs, response_channel } => {
+                            self.insert_bundle(txs, response_channel);
+                        }
+                    }
                 }
             }
-            _ = pending_pool_expiration_check.tick() => {
+            _ = expiration_check.tick() => {
                 self.pending_pool.expire_transactions(self.notification_sender.clone());
+                self.future_pool.expire_transactions(self.notification_sender.clone());
             }
         }
         TaskNextAction::Continue
     }
 
     fn insert(&mut self, tx: ArcPoolTx, source: InsertionSource) {
+        if tx.maturity() > self.next_block_height() {
+            self.insert_future_transaction(tx, source);
+            return;
+        }
+
         let tx_id = tx.id();
         let expiration = tx.expiration();
         let result = self.view_provider.latest_view();
@@ -405,7 +456,13 @@ where
                     InsertionSource::P2P { from_peer_info } => {
                         ExtendedInsertionSource::P2P { from_peer_info }
                     }
-                    InsertionSource::RPC { response_channel } => {
+                    InsertionSource::RPC {
+                        response_channel,
+                        replaceable,
+                    } => {
+                        if !replaceable {
+                            self.pool.mark_non_replaceable(tx_id);
+                        }
                         let tx: Transaction = self
                             .pool
                             .get(&tx_id)
@@ -423,7 +480,7 @@ where
                 if let Err(e) =
                     self.notification_sender
                         .try_send(PoolNotification::Inserted {
-                            tx_id,
+                            tx: tx.clone(),
                             expiration,
                             time: SystemTime::now(),
                             source: extended_source,
@@ -468,6 +525,7 @@ where
                         );
                     }
                 } else {
+                    self.notify_inserted_not_executable(&tx);
                     self.pending_pool
                         .insert_transaction(tx, source, missing_inputs);
                 }
@@ -487,6 +545,126 @@ where
         }
     }
 
+    fn insert_future_transaction(&mut self, tx: ArcPoolTx, source: InsertionSource) {
+        let tx_id = tx.id();
+        let error = if self.future_pool.contains(&tx_id) {
+            Some(Error::InputValidation(InputValidationError::DuplicateTxId(
+                tx_id,
+            )))
+        } else if let Some(collision) = self.future_pool.find_collision(&tx) {
+            Some(Error::Collided(collision))
+        } else if let Err(error) = self
+            .pool
+            .can_insert_future_transaction(&tx, &self.future_pool.owners_usage)
+        {
+            Some(error)
+        } else if !self.has_enough_space_in_future_pool(&tx) {
+            Some(Error::NotInsertedLimitHit)
+        } else {
+            None
+        };
+
+        if let Some(error) = error {
+            if let Err(e) =
+                self.notification_sender
+                    .try_send(PoolNotification::ErrorInsertion {
+                        tx_id,
+                        source,
+                        error,
+                    })
+            {
+                tracing::error!("Failed to send error insertion notification: {}", e);
+            }
+            return;
+        }
+
+        // The transaction is accepted by the node, so the RPC caller gets the response
+        // right away instead of waiting for the maturity of the transaction.
+        let source = match source {
+            InsertionSource::RPC {
+                response_channel,
+                replaceable,
+            } => {
+                if let Some(channel) = response_channel
+                    && channel.send(Ok(())).is_err()
+                {
+                    tracing::error!("Failed to send the response to the RPC");
+                }
+                InsertionSource::RPC {
+                    response_channel: None,
+                    replaceable,
+                }
+            }
+            source @ InsertionSource::P2P { .. } => source,
+        };
+        self.pool
+            .tx_status_manager
+            .status_update(tx_id, submitted_status(SystemTime::now()));
+        self.notify_inserted_not_executable(&tx);
+        self.future_pool.insert_transaction(tx, source);
+    }
+
+    fn notify_inserted_not_executable(&self, tx: &ArcPoolTx) {
+        if let Err(e) =
+            self.notification_sender
+                .try_send(PoolNotification::InsertedNotExecutable {
+                    tx: tx.clone(),
+                    time: SystemTime::now(),
+                })
+        {
+            tracing::error!("Failed to send inserted notification: {}", e);
+        }
+    }
+
+    fn insert_bundle(
+        &mut self,
+        txs: Vec<ArcPoolTx>,
+        response_channel: oneshot::Sender<Result<(), Error>>,
+    ) {
+        let tx_ids = txs.iter().map(|tx| tx.id()).collect::<Vec<_>>();
+        let result = self
+            .view_provider
+            .latest_view()
+            .map_err(|err| Error::Database(format!("{:?}", err)))
+            .and_then(|view| self.pool.insert_bundle(txs.clone(), &view));
+
+        let notification = match result {
+            Ok(()) => {
+                for tx in &txs {
+                    let resolved_txs =
+                        self.pending_pool.new_known_tx(tx.utxo_ids_with_outputs());
+
+                    for (tx, source) in resolved_txs {
+                        if let Err(e) = self
+                            .tx_insert_from_pending_sender
+                            .try_send(PoolInsertRequest::Insert { tx, source })
+                        {
+                            tracing::error!(
+                                "Failed to send resolved transaction to pending pool: {}",
+                                e
+                            );
+                        }
+                    }
+                }
+
+                PoolNotification::BundleInserted {
+                    txs: txs.iter().map(|tx| (tx.id(), tx.expiration())).collect(),
+                    time: SystemTime::now(),
+                    response_channel,
+                }
+            }
+            Err(error) => PoolNotification::BundleErrorInsertion {
+                tx_ids,
+                error,
+                response_channel,
+            },
+        };
+
+        if let Err(e) = self.notification_sender.try_send(notification) {
+            tracing::error!("Failed to send bundle insertion notification: {}", e);
+        }
+    }
+
     fn extract_block_transactions(
         &mut self,
         constraints: Constraints,
@@ -559,6 +737,20 @@ where
         for (tx, source) in resolved_txs {
             self.insert(tx, source);
         }
+
+        let matured_txs = self
+            .future_pool
+            .take_matured_transactions(self.next_block_height());
+        for (tx, source) in matured_txs {
+            self.insert(tx, source);
+        }
+    }
+
+    /// Transactions from the pool are executed in the next block.
+    fn next_block_height(&self) -> BlockHeight {
+        self.current_canonical_height
+            .succ()
+            .unwrap_or(self.current_canonical_height)
     }
 
     fn remove_skipped_transaction(&mut self, id: TxId, reason: String) {
@@ -696,6 +888,60 @@ where
         }
     }
 
+    fn get_absent_txs(
+        &mut self,
+        tx_ids: Vec<TxId>,
+        response_channel: oneshot::Sender<Vec<TxId>>,
+    ) {
+        let absent_txs: Vec<TxId> = tx_ids
+            .into_iter()
+            .filter(|tx_id| {
+                !self.pool.contains(tx_id)
+                    && !self.pending_pool.contains(tx_id)
+                    && !self.future_pool.contains(tx_id)
+            })
+            .collect();
+        if response_channel.send(absent_txs).is_err() {
+            tracing::error!("Failed to send absent txs");
+        }
+    }
+
+    fn inspect(
+        &mut self,
+        filter: InspectionFilter,
+        response_channel: oneshot::Sender<Vec<InspectedTransaction>>,
+    ) {
+        let start = filter.start.as_ref();
+        let cursors = inspection::merge(
+            [
+                self.pool.inspection_index.iter(start, filter.direction),
+                self.pending_pool
+                    .inspection_index
+                    .iter(start, filter.direction),
+                self.future_pool
+                    .inspection_index
+                    .iter(start, filter.direction),
+            ],
+            filter.direction,
+        );
+        let txs = cursors
+            .filter_map(|cursor| {
+                let tx_id = &cursor.tx_id;
+                if self.pool.contains(tx_id) {
+                    self.pool.inspect(tx_id, &filter)
+                } else if self.pending_pool.contains(tx_id) {
+                    self.pending_pool.inspect(tx_id, &filter)
+                } else {
+                    self.future_pool.inspect(tx_id, &filter)
+                }
+            })
+            .take(filter.limit)
+            .collect();
+        if response_channel.send(txs).is_err() {
+            tracing::error!("Failed to send inspected txs from PoolWorker");
+        }
+    }
+
     fn has_enough_space_in_pools(&self, tx: &ArcPoolTx) -> bool {
         let tx_gas = tx.max_gas();
         let bytes_size = tx.metered_bytes_size();
@@ -712,36 +958,54 @@ where
         }
 
         // Check the percentage used by the pending pool
-        let gas_used = self.pending_pool.current_gas.saturating_add(tx_gas);
-        let bytes_used = self.pending_pool.current_bytes.saturating_add(bytes_size);
-        let txs_used = self.pending_pool.current_txs.saturating_add(1);
+        self.fits_into_pool_percentage(
+            self.pool.config.max_pending_pool_size_percentage,
+            self.pending_pool.current_gas.saturating_add(tx_gas),
+            self.pending_pool.current_bytes.saturating_add(bytes_size),
+            self.pending_pool.current_txs.saturating_add(1),
+        )
+    }
 
+    fn has_enough_space_in_future_pool(&self, tx: &ArcPoolTx) -> bool {
+        self.fits_into_pool_percentage(
+            self.pool.config.max_future_pool_size_percentage,
+            self.future_pool.current_gas.saturating_add(tx.max_gas()),
+            self.future_pool
+                .current_bytes
+                .saturating_add(tx.metered_bytes_size()),
+            self.future_pool.current_txs.saturating_add(1),
+        )
+    }
+
+    fn fits_into_pool_percentage(
+        &self,
+        percentage: u16,
+        gas_used: u64,
+        bytes_used: usize,
+        txs_used: usize,
+    ) -> bool {
         let max_gas = self
             .pool
             .config
             .pool_limits
             .max_gas
-            .saturating_mul(self.pool.config.max_pending_pool_size_percentage as u64)
+            .saturating_mul(percentage as u64)
             .saturating_div(100);
         let max_bytes = self
             .pool
             .config
             .pool_limits
             .max_bytes_size
-            .saturating_mul(self.pool.config.max_pending_pool_size_percentage as usize)
+            .saturating_mul(percentage as usize)
             .saturating_div(100);
         let max_txs = self
             .pool
             .config
             .pool_limits
             .max_txs
-            .saturating_mul(self.pool.config.max_pending_pool_size_percentage as usize)
+            .saturating_mul(percentage as usize)
             .saturating_div(100);
 
-        if gas_used > max_gas || bytes_used > max_bytes || txs_used > max_txs {
-            return false;
-        }
-
-        true
+        gas_used <= max_gas && bytes_used <= max_bytes && txs_used <= max_txs
     }
 }
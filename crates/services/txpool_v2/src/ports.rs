@@ -33,11 +33,15 @@ use fuel_core_types::{
             PreConfirmationStatus,
             TransactionStatus,
         },
+        txpool::PoolTransaction,
     },
 };
 use tokio::sync::broadcast;
 
-use crate::GasPrice;
+use crate::{
+    GasPrice,
+    pool::TxPoolStats,
+};
 
 pub use fuel_core_storage::transactional::AtomicView;
 use fuel_core_types::services::transaction_status::statuses;
@@ -107,6 +111,17 @@ pub trait WasmChecker: Send + Sync + 'static {
     ) -> Result<(), WasmValidityError>;
 }
 
+/// The programmable filter of transactions admitted into the pool.
+/// Unlike the static `BlackList`, the filter can change its decisions at runtime.
+pub trait AdmissionFilter: Send + Sync + 'static {
+    /// Decides whether the verified transaction can be admitted into the pool
+    /// with the current `stats`. Returns the reason if the transaction is rejected.
+    ///
+    /// The filter is called for every inserted transaction while the pool is locked,
+    /// so it should be fast.
+    fn admit(&self, tx: &PoolTransaction, stats: &TxPoolStats) -> Result<(), String>;
+}
+
 pub trait P2PSubscriptions {
     type GossipedTransaction: NetworkData<Transaction>;
 
@@ -0,0 +1,183 @@
+use std::{
+    collections::BTreeMap,
+    ops::Bound,
+    time::SystemTime,
+};
+
+use fuel_core_types::fuel_tx::TxId;
+
+use crate::storage::{
+    RemovedTransactions,
+    StorageData,
+};
+
+use super::{
+    BlockSpace,
+    Constraints,
+    SelectionAlgorithm,
+    SelectionAlgorithmStorage,
+    UnitSelection,
+    remove_selected,
+    select_unit,
+};
+
+#[cfg(test)]
+use fuel_core_types::services::txpool::ArcPoolTx;
+
+#[cfg(test)]
+use std::collections::HashMap;
+
+/// Key used to sort transactions by the arrival time.
+/// It first compares the creation instant and then the transaction id.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Debug)]
+pub struct Key {
+    creation_instant: SystemTime,
+    tx_id: TxId,
+}
+
+impl Key {
+    fn new(store_entry: &StorageData) -> Self {
+        Self {
+            creation_instant: store_entry.creation_instant,
+            tx_id: store_entry.transaction.id(),
+        }
+    }
+}
+
+/// The selection algorithm that selects transactions strictly in the order of their arrival.
+///
+/// Transactions that can't be included into the block at all (because of the minimal
+/// gas price or excluded contracts) are skipped. But if the oldest transaction doesn't
+/// fit into the space left in the block, the selection stops, so newer transactions
+/// never overtake older ones. A bundle arrives with its first transaction.
+pub struct FifoSelection<S>
+where
+    S: SelectionAlgorithmStorage,
+{
+    executable_transactions_sorted_by_arrival: BTreeMap<Key, S::StorageIndex>,
+    new_executable_txs_notifier: tokio::sync::watch::Sender<()>,
+}
+
+impl<S> FifoSelection<S>
+where
+    S: SelectionAlgorithmStorage,
+{
+    pub fn new(new_executable_txs_notifier: tokio::sync::watch::Sender<()>) -> Self {
+        Self {
+            executable_transactions_sorted_by_arrival: BTreeMap::new(),
+            new_executable_txs_notifier,
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn assert_integrity(&self, expected_txs: &[ArcPoolTx]) {
+        let mut expected_txs: HashMap<TxId, ArcPoolTx> = expected_txs
+            .iter()
+            .map(|tx| (tx.id(), tx.clone()))
+            .collect();
+        for key in self.executable_transactions_sorted_by_arrival.keys() {
+            expected_txs.remove(&key.tx_id).unwrap_or_else(|| {
+                panic!(
+                    "Transaction with id {:?} is not in the expected transactions.",
+                    key.tx_id
+                )
+            });
+        }
+        assert!(
+            expected_txs.is_empty(),
+            "Some transactions are missing from the selection algorithm: {:?}",
+            expected_txs.keys().collect::<Vec<_>>()
+        );
+    }
+}
+
+impl<S> SelectionAlgorithm for FifoSelection<S>
+where
+    S: SelectionAlgorithmStorage,
+{
+    type Storage = S;
+    type StorageIndex = S::StorageIndex;
+
+    fn gather_best_txs(
+        &mut self,
+        constraints: Constraints,
+        storage: &mut S,
+    ) -> RemovedTransactions {
+        let mut space = BlockSpace::new(&constraints);
+        let mut result = Vec::new();
+        let mut add_new_executable = false;
+        // All transactions before the cursor are either selected or skipped.
+        // Dependents promoted by the selected transaction arrived after it,
+        // so they are always after the cursor.
+        let mut cursor = Bound::Unbounded;
+
+        while !space.is_exhausted() {
+            let next = self
+                .executable_transactions_sorted_by_arrival
+                .range((cursor, Bound::Unbounded))
+                .next()
+                .map(|(key, storage_id)| (*key, *storage_id));
+            let Some((key, storage_id)) = next else { break };
+            cursor = Bound::Excluded(key);
+
+            if storage.get(&storage_id).is_none() {
+                debug_assert!(
+                    false,
+                    "Transaction not found in the storage during `gather_best_txs`."
+                );
+                tracing::warn!(
+                    "Transaction not found in the storage during `gather_best_txs`."
+                );
+                self.executable_transactions_sorted_by_arrival.remove(&key);
+                continue
+            }
+
+            let selected =
+                match select_unit(storage, &storage_id, &constraints, &mut space) {
+                    UnitSelection::Selected(selected) => selected,
+                    UnitSelection::NotSelectable => continue,
+                    UnitSelection::DoesNotFit => break,
+                };
+
+            self.executable_transactions_sorted_by_arrival.remove(&key);
+            for dependent in remove_selected(storage, &selected, &mut result) {
+                let storage = storage.get(&dependent).expect(
+                    "We just get the dependent from the storage, it should exist.",
+                );
+                self.new_executable_transaction(dependent, storage);
+                add_new_executable = true;
+            }
+        }
+
+        if add_new_executable {
+            self.new_executable_txs_notifier.send_replace(());
+        }
+
+        result
+    }
+
+    fn new_executable_transaction(
+        &mut self,
+        storage_id: Self::StorageIndex,
+        store_entry: &StorageData,
+    ) {
+        self.executable_transactions_sorted_by_arrival
+            .insert(Key::new(store_entry), storage_id);
+    }
+
+    fn number_of_executable_transactions(&self) -> usize {
+        self.executable_transactions_sorted_by_arrival.len()
+    }
+
+    fn get_less_worth_txs(&self) -> impl Iterator<Item = &Self::StorageIndex> {
+        // The newest transactions are the first to be evicted.
+        self.executable_transactions_sorted_by_arrival
+            .values()
+            .rev()
+    }
+
+    fn on_removed_transaction(&mut self, storage_entry: &StorageData) {
+        self.executable_transactions_sorted_by_arrival
+            .remove(&Key::new(storage_entry));
+    }
+}
@@ -0,0 +1,300 @@
+use std::{
+    cmp::Reverse,
+    collections::BTreeMap,
+};
+
+use fuel_core_types::services::txpool::PoolTransaction;
+
+use crate::{
+    config::LanesConfig,
+    storage::{
+        RemovedTransactions,
+        StorageData,
+    },
+};
+
+use super::{
+    BlockSpace,
+    Constraints,
+    SelectionAlgorithm,
+    SelectionAlgorithmStorage,
+    UnitSelection,
+    ratio_tip_gas::Key,
+    remove_selected,
+    select_unit,
+};
+
+#[cfg(test)]
+use fuel_core_types::{
+    fuel_tx::TxId,
+    services::txpool::ArcPoolTx,
+};
+
+#[cfg(test)]
+use std::collections::HashMap;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Lane {
+    Priority,
+    Regular,
+}
+
+/// The selection algorithm that reserves a part of the block gas for transactions
+/// from the priority addresses. Inside each lane, transactions are sorted by the tip/gas ratio.
+///
+/// Priority transactions are selected first, using at most the reserved gas.
+/// The rest of the gas is used by regular transactions and after by priority
+/// transactions that didn't fit into the reserved gas. So the reserved gas
+/// not used by the priority lane is available for regular transactions.
+/// A bundle uses the lane of its first transaction.
+pub struct LanesSelection<S>
+where
+    S: SelectionAlgorithmStorage,
+{
+    config: LanesConfig,
+    priority_transactions: BTreeMap<Reverse<Key>, S::StorageIndex>,
+    regular_transactions: BTreeMap<Reverse<Key>, S::StorageIndex>,
+    new_executable_txs_notifier: tokio::sync::watch::Sender<()>,
+}
+
+impl<S> LanesSelection<S>
+where
+    S: SelectionAlgorithmStorage,
+{
+    pub fn new(
+        config: LanesConfig,
+        new_executable_txs_notifier: tokio::sync::watch::Sender<()>,
+    ) -> Self {
+        Self {
+            config,
+            priority_transactions: BTreeMap::new(),
+            regular_transactions: BTreeMap::new(),
+            new_executable_txs_notifier,
+        }
+    }
+
+    fn lane_of(&self, tx: &PoolTransaction) -> Lane {
+        let is_priority = tx.inputs().iter().any(|input| {
+            input
+                .input_owner()
+                .is_some_and(|owner| self.config.priority_addresses.contains(owner))
+        });
+
+        if is_priority {
+            Lane::Priority
+        } else {
+            Lane::Regular
+        }
+    }
+
+    fn lane(&self, lane: Lane) -> &BTreeMap<Reverse<Key>, S::StorageIndex> {
+        match lane {
+            Lane::Priority => &self.priority_transactions,
+            Lane::Regular => &self.regular_transactions,
+        }
+    }
+
+    fn lane_mut(&mut self, lane: Lane) -> &mut BTreeMap<Reverse<Key>, S::StorageIndex> {
+        match lane {
+            Lane::Priority => &mut self.priority_transactions,
+            Lane::Regular => &mut self.regular_transactions,
+        }
+    }
+
+    fn reserved_gas(&self, max_gas: u64) -> u64 {
+        let reserved = u128::from(max_gas)
+            .saturating_mul(u128::from(self.config.reserved_gas_percentage))
+            / 100;
+        u64::try_from(reserved).unwrap_or(max_gas).min(max_gas)
+    }
+
+    /// Selects transactions from the `lane` while they fit into the `space`.
+    /// Returns `true` if at least one transaction was selected.
+    fn gather_from_lane(
+        &mut self,
+        lane: Lane,
+        constraints: &Constraints,
+        space: &mut BlockSpace,
+        storage: &mut S,
+        result: &mut RemovedTransactions,
+        add_new_executable: &mut bool,
+    ) -> bool {
+        let mut selected_any = false;
+
+        // Selected transactions can promote their dependents to the same lane,
+        // so we repeat until nothing is selected.
+        loop {
+            let mut selected = Vec::new();
+            let mut missing = Vec::new();
+            for (key, storage_id) in self.lane(lane) {
+                if space.is_exhausted() {
+                    break
+                }
+
+                if storage.get(storage_id).is_none() {
+                    debug_assert!(
+                        false,
+                        "Transaction not found in the storage during `gather_best_txs`."
+                    );
+                    tracing::warn!(
+                        "Transaction not found in the storage during `gather_best_txs`."
+                    );
+                    missing.push(*key);
+                    continue
+                }
+
+                if let UnitSelection::Selected(unit) =
+                    select_unit(storage, storage_id, constraints, space)
+                {
+                    selected.push((*key, unit));
+                }
+            }
+
+            for key in missing {
+                self.lane_mut(lane).remove(&key);
+            }
+
+            if selected.is_empty() {
+                return selected_any
+            }
+            selected_any = true;
+
+            for (key, unit) in selected {
+                self.lane_mut(lane).remove(&key);
+                for dependent in remove_selected(storage, &unit, result) {
+                    let storage = storage.get(&dependent).expect(
+                        "We just get the dependent from the storage, it should exist.",
+                    );
+                    self.new_executable_transaction(dependent, storage);
+                    *add_new_executable = true;
+                }
+            }
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn assert_integrity(&self, expected_txs: &[ArcPoolTx]) {
+        let mut expected_txs: HashMap<TxId, ArcPoolTx> = expected_txs
+            .iter()
+            .map(|tx| (tx.id(), tx.clone()))
+            .collect();
+        for (lane, transactions) in [
+            (Lane::Priority, &self.priority_transactions),
+            (Lane::Regular, &self.regular_transactions),
+        ] {
+            for key in transactions.keys() {
+                let tx_id = key.0.tx_id();
+                let tx = expected_txs.remove(tx_id).unwrap_or_else(|| {
+                    panic!(
+                        "Transaction with id {tx_id:?} is not in the expected transactions."
+                    )
+                });
+                assert_eq!(
+                    self.lane_of(&tx),
+                    lane,
+                    "Transaction with id {tx_id:?} is in the wrong lane."
+                );
+            }
+        }
+        assert!(
+            expected_txs.is_empty(),
+            "Some transactions are missing from the selection algorithm: {:?}",
+            expected_txs.keys().collect::<Vec<_>>()
+        );
+    }
+}
+
+impl<S> SelectionAlgorithm for LanesSelection<S>
+where
+    S: SelectionAlgorithmStorage,
+{
+    type Storage = S;
+    type StorageIndex = S::StorageIndex;
+
+    fn gather_best_txs(
+        &mut self,
+        constraints: Constraints,
+        storage: &mut S,
+    ) -> RemovedTransactions {
+        let mut space = BlockSpace::new(&constraints);
+        let mut result = Vec::new();
+        let mut add_new_executable = false;
+
+        let reserved_gas = self.reserved_gas(constraints.max_gas);
+        let mut priority_space = BlockSpace {
+            gas: reserved_gas,
+            ..space
+        };
+        self.gather_from_lane(
+            Lane::Priority,
+            &constraints,
+            &mut priority_space,
+            storage,
+            &mut result,
+            &mut add_new_executable,
+        );
+        let used_gas = reserved_gas.saturating_sub(priority_space.gas);
+        space = BlockSpace {
+            gas: space.gas.saturating_sub(used_gas),
+            ..priority_space
+        };
+
+        loop {
+            let regular_selected = self.gather_from_lane(
+                Lane::Regular,
+                &constraints,
+                &mut space,
+                storage,
+                &mut result,
+                &mut add_new_executable,
+            );
+            let priority_selected = self.gather_from_lane(
+                Lane::Priority,
+                &constraints,
+                &mut space,
+                storage,
+                &mut result,
+                &mut add_new_executable,
+            );
+            if !regular_selected && !priority_selected {
+                break
+            }
+        }
+
+        if add_new_executable {
+            self.new_executable_txs_notifier.send_replace(());
+        }
+
+        result
+    }
+
+    fn new_executable_transaction(
+        &mut self,
+        storage_id: Self::StorageIndex,
+        store_entry: &StorageData,
+    ) {
+        let lane = self.lane_of(&store_entry.transaction);
+        self.lane_mut(lane)
+            .insert(Reverse(Key::new(store_entry)), storage_id);
+    }
+
+    fn number_of_executable_transactions(&self) -> usize {
+        self.priority_transactions
+            .len()
+            .saturating_add(self.regular_transactions.len())
+    }
+
+    fn get_less_worth_txs(&self) -> impl Iterator<Item = &Self::StorageIndex> {
+        // Regular transactions are evicted before priority ones.
+        self.regular_transactions
+            .values()
+            .rev()
+            .chain(self.priority_transactions.values().rev())
+    }
+
+    fn on_removed_transaction(&mut self, storage_entry: &StorageData) {
+        let lane = self.lane_of(&storage_entry.transaction);
+        self.lane_mut(lane)
+            .remove(&Reverse(Key::new(storage_entry)));
+    }
+}
@@ -1,14 +1,35 @@
-use std::collections::HashSet;
+use std::{
+    collections::HashSet,
+    fmt::Debug,
+};
 
-use fuel_core_types::fuel_tx::ContractId;
+use fuel_core_types::{
+    fuel_tx::{
+        ContractId,
+        Input,
+    },
+    services::txpool::PoolTransaction,
+};
 
-use crate::storage::{
-    RemovedTransactions,
-    StorageData,
+use crate::{
+    config::SelectionPolicy,
+    storage::{
+        RemovedTransactions,
+        StorageData,
+    },
 };
 
+#[cfg(test)]
+use fuel_core_types::services::txpool::ArcPoolTx;
+
+pub mod fifo;
+pub mod lanes;
 pub mod ratio_tip_gas;
 
+use fifo::FifoSelection;
+use lanes::LanesSelection;
+use ratio_tip_gas::RatioTipGasSelection;
+
 /// Constraints that the selection algorithm has to respect.
 pub struct Constraints {
     /// Minimum gas price that all transaction must support.
@@ -52,3 +73,266 @@ pub trait SelectionAlgorithm {
     /// Inform the selection algorithm that a transaction was removed from the pool.
     fn on_removed_transaction(&mut self, storage_entry: &StorageData);
 }
+
+/// The storage used by the selection algorithms.
+pub trait SelectionAlgorithmStorage {
+    type StorageIndex: Copy + Debug + PartialEq;
+
+    fn get(&self, index: &Self::StorageIndex) -> Option<&StorageData>;
+
+    fn get_dependents(
+        &self,
+        index: &Self::StorageIndex,
+    ) -> impl Iterator<Item = Self::StorageIndex>;
+
+    fn has_dependencies(&self, index: &Self::StorageIndex) -> bool;
+
+    /// Returns all transactions of the bundle the transaction belongs to
+    /// in the order of the bundle.
+    fn get_bundle(&self, index: &Self::StorageIndex) -> Option<&[Self::StorageIndex]>;
+
+    fn remove(&mut self, index: &Self::StorageIndex) -> Option<StorageData>;
+}
+
+/// Returns `true` if the transaction can be included into the block with the `constraints`.
+fn is_selectable(constraints: &Constraints, tx: &PoolTransaction) -> bool {
+    let uses_excluded_contract = tx.inputs().iter().any(|input| {
+        matches!(
+            input,
+            Input::Contract(contract)
+                if constraints.excluded_contracts.contains(&contract.contract_id)
+        )
+    });
+
+    !uses_excluded_contract && tx.max_gas_price() >= constraints.minimal_gas_price
+}
+
+/// The space left in the block for the selected transactions.
+#[derive(Clone, Copy, Debug)]
+struct BlockSpace {
+    gas: u64,
+    bytes: usize,
+    txs: u16,
+}
+
+impl BlockSpace {
+    fn new(constraints: &Constraints) -> Self {
+        Self {
+            gas: constraints.max_gas,
+            bytes: constraints.maximum_block_size as usize,
+            txs: constraints.maximum_txs,
+        }
+    }
+
+    fn is_exhausted(&self) -> bool {
+        self.gas == 0 || self.bytes == 0 || self.txs == 0
+    }
+
+    fn fits(&self, tx: &PoolTransaction) -> bool {
+        tx.max_gas() <= self.gas && tx.metered_bytes_size() <= self.bytes && self.txs > 0
+    }
+
+    fn consume(&mut self, tx: &PoolTransaction) {
+        self.gas = self.gas.saturating_sub(tx.max_gas());
+        self.bytes = self.bytes.saturating_sub(tx.metered_bytes_size());
+        self.txs = self.txs.saturating_sub(1);
+    }
+}
+
+/// The result of the attempt to select an executable transaction.
+enum UnitSelection<I> {
+    /// The transactions selected together in the order of the bundle.
+    Selected(Vec<I>),
+    /// Some of the transactions can't be included into the block.
+    NotSelectable,
+    /// The transactions don't fit into the space left in the block.
+    DoesNotFit,
+}
+
+/// Tries to select the executable transaction into the block and consumes the `space` on success.
+///
+/// Only the first transaction of a bundle is executable. The bundle is selected
+/// all-or-nothing, so all its transactions are selected together in the order of the bundle.
+fn select_unit<S>(
+    storage: &S,
+    index: &S::StorageIndex,
+    constraints: &Constraints,
+    space: &mut BlockSpace,
+) -> UnitSelection<S::StorageIndex>
+where
+    S: SelectionAlgorithmStorage,
+{
+    let unit = match storage.get_bundle(index) {
+        Some(members) => members.to_vec(),
+        None => vec![*index],
+    };
+
+    let mut space_left = *space;
+    for member in &unit {
+        let Some(stored_transaction) = storage.get(member) else {
+            debug_assert!(
+                false,
+                "The transaction of the bundle is not in the storage."
+            );
+            tracing::warn!("The transaction of the bundle is not in the storage.");
+            return UnitSelection::NotSelectable
+        };
+        let transaction = &stored_transaction.transaction;
+        if !is_selectable(constraints, transaction) {
+            return UnitSelection::NotSelectable
+        }
+        if !space_left.fits(transaction) {
+            return UnitSelection::DoesNotFit
+        }
+        space_left.consume(transaction);
+    }
+
+    *space = space_left;
+    UnitSelection::Selected(unit)
+}
+
+/// Removes the selected transactions from the storage and adds them to the `result`.
+/// Returns the dependents of the removed transactions that became executable.
+fn remove_selected<S>(
+    storage: &mut S,
+    selected: &[S::StorageIndex],
+    result: &mut RemovedTransactions,
+) -> Vec<S::StorageIndex>
+where
+    S: SelectionAlgorithmStorage,
+{
+    let mut dependents = Vec::new();
+    for storage_id in selected {
+        dependents.extend(storage.get_dependents(storage_id));
+        debug_assert!(!storage.has_dependencies(storage_id));
+        let removed = storage.remove(storage_id).expect(
+            "We just get the transaction from the storage above, it should exist.",
+        );
+        result.push(removed);
+    }
+
+    let mut executable = Vec::new();
+    for dependent in dependents {
+        if !selected.contains(&dependent)
+            && !executable.contains(&dependent)
+            && !storage.has_dependencies(&dependent)
+        {
+            executable.push(dependent);
+        }
+    }
+    executable
+}
+
+/// The selection algorithm chosen by the [`SelectionPolicy`].
+pub enum Selection<S>
+where
+    S: SelectionAlgorithmStorage,
+{
+    RatioTipGas(RatioTipGasSelection<S>),
+    Fifo(FifoSelection<S>),
+    Lanes(LanesSelection<S>),
+}
+
+impl<S> Selection<S>
+where
+    S: SelectionAlgorithmStorage,
+{
+    pub fn new(
+        policy: &SelectionPolicy,
+        new_executable_txs_notifier: tokio::sync::watch::Sender<()>,
+    ) -> Self {
+        match policy {
+            SelectionPolicy::RatioTipGas => {
+                Self::RatioTipGas(RatioTipGasSelection::new(new_executable_txs_notifier))
+            }
+            SelectionPolicy::Fifo => {
+                Self::Fifo(FifoSelection::new(new_executable_txs_notifier))
+            }
+            SelectionPolicy::Lanes(config) => Self::Lanes(LanesSelection::new(
+                config.clone(),
+                new_executable_txs_notifier,
+            )),
+        }
+    }
+
+    #[cfg(test)]
+    pub fn is_empty(&self) -> bool {
+        self.number_of_executable_transactions() == 0
+    }
+
+    #[cfg(test)]
+    pub(crate) fn assert_integrity(&self, expected_txs: &[ArcPoolTx]) {
+        match self {
+            Self::RatioTipGas(selection) => selection.assert_integrity(expected_txs),
+            Self::Fifo(selection) => selection.assert_integrity(expected_txs),
+            Self::Lanes(selection) => selection.assert_integrity(expected_txs),
+        }
+    }
+}
+
+impl<S> SelectionAlgorithm for Selection<S>
+where
+    S: SelectionAlgorithmStorage,
+{
+    type Storage = S;
+    type StorageIndex = S::StorageIndex;
+
+    fn gather_best_txs(
+        &mut self,
+        constraints: Constraints,
+        storage: &mut S,
+    ) -> RemovedTransactions {
+        match self {
+            Self::RatioTipGas(selection) => {
+                selection.gather_best_txs(constraints, storage)
+            }
+            Self::Fifo(selection) => selection.gather_best_txs(constraints, storage),
+            Self::Lanes(selection) => selection.gather_best_txs(constraints, storage),
+        }
+    }
+
+    fn new_executable_transaction(
+        &mut self,
+        storage_id: Self::StorageIndex,
+        store_entry: &StorageData,
+    ) {
+        match self {
+            Self::RatioTipGas(selection) => {
+                selection.new_executable_transaction(storage_id, store_entry)
+            }
+            Self::Fifo(selection) => {
+                selection.new_executable_transaction(storage_id, store_entry)
+            }
+            Self::Lanes(selection) => {
+                selection.new_executable_transaction(storage_id, store_entry)
+            }
+        }
+    }
+
+    fn number_of_executable_transactions(&self) -> usize {
+        match self {
+            Self::RatioTipGas(selection) => selection.number_of_executable_transactions(),
+            Self::Fifo(selection) => selection.number_of_executable_transactions(),
+            Self::Lanes(selection) => selection.number_of_executable_transactions(),
+        }
+    }
+
+    fn get_less_worth_txs(&self) -> impl Iterator<Item = &Self::StorageIndex> {
+        let iter: Box<dyn Iterator<Item = &Self::StorageIndex>> = match self {
+            Self::RatioTipGas(selection) => Box::new(selection.get_less_worth_txs()),
+            Self::Fifo(selection) => Box::new(selection.get_less_worth_txs()),
+            Self::Lanes(selection) => Box::new(selection.get_less_worth_txs()),
+        };
+        iter
+    }
+
+    fn on_removed_transaction(&mut self, storage_entry: &StorageData) {
+        match self {
+            Self::RatioTipGas(selection) => {
+                selection.on_removed_transaction(storage_entry)
+            }
+            Self::Fifo(selection) => selection.on_removed_transaction(storage_entry),
+            Self::Lanes(selection) => selection.on_removed_transaction(storage_entry),
+        }
+    }
+}
@@ -17,8 +17,13 @@ use crate::storage::{
 };
 
 use super::{
+    BlockSpace,
     Constraints,
     SelectionAlgorithm,
+    SelectionAlgorithmStorage,
+    UnitSelection,
+    remove_selected,
+    select_unit,
 };
 
 #[cfg(test)]
@@ -27,21 +32,6 @@ use fuel_core_types::services::txpool::ArcPoolTx;
 #[cfg(test)]
 use std::collections::HashMap;
 
-pub trait RatioTipGasSelectionAlgorithmStorage {
-    type StorageIndex: Debug;
-
-    fn get(&self, index: &Self::StorageIndex) -> Option<&StorageData>;
-
-    fn get_dependents(
-        &self,
-        index: &Self::StorageIndex,
-    ) -> impl Iterator<Item = Self::StorageIndex>;
-
-    fn has_dependencies(&self, index: &Self::StorageIndex) -> bool;
-
-    fn remove(&mut self, index: &Self::StorageIndex) -> Option<StorageData>;
-}
-
 pub type RatioTipGas = Ratio<u64>;
 
 /// Key used to sort transactions by tip/gas ratio.
@@ -69,6 +59,25 @@ impl Ord for Key {
     }
 }
 
+impl Key {
+    pub(crate) fn new(store_entry: &StorageData) -> Self {
+        let transaction = &store_entry.transaction;
+        let tip_gas_ratio =
+            RatioTipGas::new(transaction.tip().saturating_add(1), transaction.max_gas());
+
+        Self {
+            ratio: tip_gas_ratio,
+            creation_instant: store_entry.creation_instant,
+            tx_id: transaction.id(),
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn tx_id(&self) -> &TxId {
+        &self.tx_id
+    }
+}
+
 impl PartialOrd for Key {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
@@ -78,7 +87,7 @@ impl PartialOrd for Key {
 /// The selection algorithm that selects transactions based on the tip/gas ratio.
 pub struct RatioTipGasSelection<S>
 where
-    S: RatioTipGasSelectionAlgorithmStorage,
+    S: SelectionAlgorithmStorage,
 {
     executable_transactions_sorted_tip_gas_ratio: BTreeMap<Reverse<Key>, S::StorageIndex>,
     new_executable_txs_notifier: tokio::sync::watch::Sender<()>,
@@ -86,7 +95,7 @@ where
 
 impl<S> RatioTipGasSelection<S>
 where
-    S: RatioTipGasSelectionAlgorithmStorage,
+    S: SelectionAlgorithmStorage,
 {
     pub fn new(new_executable_txs_notifier: tokio::sync::watch::Sender<()>) -> Self {
         Self {
@@ -95,21 +104,8 @@ where
         }
     }
 
-    #[cfg(test)]
-    pub fn is_empty(&self) -> bool {
-        self.executable_transactions_sorted_tip_gas_ratio.is_empty()
-    }
-
     fn key(store_entry: &StorageData) -> Key {
-        let transaction = &store_entry.transaction;
-        let tip_gas_ratio =
-            RatioTipGas::new(transaction.tip().saturating_add(1), transaction.max_gas());
-
-        Key {
-            ratio: tip_gas_ratio,
-            creation_instant: store_entry.creation_instant,
-            tx_id: transaction.id(),
-        }
+        Key::new(store_entry)
     }
 
     fn on_removed_transaction_inner(&mut self, key: Key) {
@@ -141,7 +137,7 @@ where
 
 impl<S> SelectionAlgorithm for RatioTipGasSelection<S>
 where
-    S: RatioTipGasSelectionAlgorithmStorage,
+    S: SelectionAlgorithmStorage,
 {
     type Storage = S;
     type StorageIndex = S::StorageIndex;
@@ -151,9 +147,7 @@ where
         constraints: Constraints,
         storage: &mut S,
     ) -> RemovedTransactions {
-        let mut gas_left = constraints.max_gas;
-        let mut space_left = constraints.maximum_block_size as usize;
-        let mut nb_left = constraints.maximum_txs;
+        let mut space = BlockSpace::new(&constraints);
         let mut result = Vec::new();
 
         // Take iterate over all transactions with the highest tip/gas ratio. If transaction
@@ -164,23 +158,19 @@ where
         // It is done in this way to minimize number of iteration of the list of executable
         // transactions.
         let mut add_new_executable = false;
-        while gas_left > 0
-            && nb_left > 0
-            && space_left > 0
+        while !space.is_exhausted()
             && !self.executable_transactions_sorted_tip_gas_ratio.is_empty()
         {
             let mut clean_up_list = Vec::new();
             let mut transactions_to_remove = Vec::new();
             let mut transactions_to_promote = Vec::new();
 
-            'outer: for (key, storage_id) in
-                &self.executable_transactions_sorted_tip_gas_ratio
-            {
-                if nb_left == 0 || gas_left == 0 || space_left == 0 {
+            for (key, storage_id) in &self.executable_transactions_sorted_tip_gas_ratio {
+                if space.is_exhausted() {
                     break;
                 }
 
-                let Some(stored_transaction) = storage.get(storage_id) else {
+                if storage.get(storage_id).is_none() {
                     debug_assert!(
                         false,
                         "Transaction not found in the storage during `gather_best_txs`."
@@ -190,52 +180,21 @@ where
                     );
                     transactions_to_remove.push(*key);
                     continue
-                };
-
-                for input in stored_transaction.transaction.inputs() {
-                    if let fuel_core_types::fuel_tx::Input::Contract(contract) = input
-                        && constraints
-                            .excluded_contracts
-                            .contains(&contract.contract_id)
-                    {
-                        continue 'outer;
-                    }
-                }
-
-                let less_price = stored_transaction.transaction.max_gas_price()
-                    < constraints.minimal_gas_price;
-
-                if less_price {
-                    continue
                 }
 
-                let not_enough_gas = stored_transaction.transaction.max_gas() > gas_left;
-                let too_big_tx =
-                    stored_transaction.transaction.metered_bytes_size() > space_left;
-
-                if not_enough_gas || too_big_tx {
+                // The whole bundle is selected along with its first transaction.
+                let UnitSelection::Selected(selected) =
+                    select_unit(storage, storage_id, &constraints, &mut space)
+                else {
                     continue
-                }
-
-                gas_left =
-                    gas_left.saturating_sub(stored_transaction.transaction.max_gas());
-                space_left = space_left
-                    .saturating_sub(stored_transaction.transaction.metered_bytes_size());
-                nb_left = nb_left.saturating_sub(1);
+                };
 
-                let dependents = storage.get_dependents(storage_id).collect::<Vec<_>>();
-                debug_assert!(!storage.has_dependencies(storage_id));
-                let removed = storage.remove(storage_id).expect(
-                    "We just get the transaction from the storage above, it should exist.",
-                );
                 clean_up_list.push(*key);
-                result.push(removed);
-
-                for dependent in dependents {
-                    if !storage.has_dependencies(&dependent) {
-                        transactions_to_promote.push(dependent);
-                    }
-                }
+                transactions_to_promote.extend(remove_selected(
+                    storage,
+                    &selected,
+                    &mut result,
+                ));
             }
 
             for remove in transactions_to_remove {
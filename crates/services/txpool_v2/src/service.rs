@@ -31,9 +31,13 @@ use fuel_core_services::{
 use fuel_core_txpool::{
     collision_manager::basic::BasicCollisionManager,
     config::Config,
-    error::Error,
+    error::{
+        BundleError,
+        Error,
+    },
     pool::Pool,
     ports::{
+        AdmissionFilter as AdmissionFilterTrait,
         AtomicView,
         BlockImporter as BlockImporterTrait,
         ChainStateInfoProvider,
@@ -44,8 +48,9 @@ use fuel_core_txpool::{
         TxStatusManager as TxStatusManagerTrait,
         WasmChecker as WasmCheckerTrait,
     },
-    selection_algorithms::ratio_tip_gas::RatioTipGasSelection,
+    selection_algorithms::Selection,
     service::{
+        journal::Journal,
         pruner::TransactionPruner,
         subscriptions::Subscriptions,
         verifications::Verification,
@@ -62,6 +67,7 @@ use fuel_core_txpool::{
 use fuel_core_types::{
     fuel_tx::{
         Transaction,
+        TxId,
         UniqueIdentifier,
     },
     fuel_types::{
@@ -93,6 +99,7 @@ use std::{
     },
     sync::Arc,
     time::{
+        Duration,
         SystemTime,
         SystemTimeError,
     },
@@ -106,6 +113,7 @@ use tokio::{
     time::MissedTickBehavior,
 };
 
+pub(crate) mod journal;
 mod pruner;
 mod subscriptions;
 pub(crate) mod verifications;
@@ -114,7 +122,7 @@ pub type TxPool<TxStatusManager> = Pool<
     GraphStorage,
     <GraphStorage as Storage>::StorageIndex,
     BasicCollisionManager<<GraphStorage as Storage>::StorageIndex>,
-    RatioTipGasSelection<GraphStorage>,
+    Selection<GraphStorage>,
     TxStatusManager,
 >;
 
@@ -163,6 +171,11 @@ pub enum WritePoolRequest {
     },
     InsertTx {
         transaction: Arc<Transaction>,
+        replaceable: bool,
+        response_channel: oneshot::Sender<Result<(), Error>>,
+    },
+    InsertBundle {
+        transactions: Vec<Arc<Transaction>>,
         response_channel: oneshot::Sender<Result<(), Error>>,
     },
 }
@@ -174,6 +187,8 @@ where
     chain_id: ChainId,
     utxo_validation: bool,
     allow_syscall: bool,
+    max_maturity_lookahead: u32,
+    max_bundle_size: usize,
     subscriptions: Subscriptions,
     verification: Arc<Verification<View>>,
     p2p: Arc<P2P>,
@@ -186,6 +201,7 @@ where
     current_height_reader: SeqLockReader<BlockHeight>,
     tx_sync_history: Shared<HashSet<PeerId>>,
     shared_state: SharedState,
+    journal: Option<Journal>,
     metrics: bool,
 }
 
@@ -213,6 +229,7 @@ where
         _: &StateWatcher,
         _: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
+        self.replay_journal().await;
         Ok(self)
     }
 }
@@ -243,6 +260,11 @@ where
                 self.try_prune_transactions()
             }
 
+            _ = journal_rotation_tick(&mut self.journal) => {
+                self.rotate_journal().await;
+                TaskNextAction::Continue
+            }
+
             pool_notification = self.pool_worker.notification_receiver.recv() => {
                 match pool_notification { Some(notification) => {
                     self.process_notification(notification);
@@ -283,11 +305,34 @@ where
         }
     }
 
-    async fn shutdown(self) -> anyhow::Result<()> {
+    async fn shutdown(mut self) -> anyhow::Result<()> {
+        if self.journal.is_some() {
+            // The pool worker handles requests one by one, so once it answers,
+            // the notifications about all previous insertions are already queued.
+            // They are processed to append the accepted transactions to the journal.
+            let _ = self.absent_txs(vec![]).await;
+            while let Ok(notification) = self.pool_worker.notification_receiver.try_recv()
+            {
+                self.process_notification(notification);
+            }
+        }
+        self.rotate_journal().await;
+        if let Some(journal) = self.journal {
+            journal.close().await;
+        }
         Ok(())
     }
 }
 
+async fn journal_rotation_tick(journal: &mut Option<Journal>) {
+    match journal {
+        Some(journal) => {
+            journal.rotation_timer.tick().await;
+        }
+        None => futures::future::pending().await,
+    }
+}
+
 impl<View, P2P, TxStatusManager> Task<View, P2P, TxStatusManager>
 where
     View: TxPoolPersistentStorage,
@@ -338,6 +383,7 @@ where
             }
             WritePoolRequest::InsertTx {
                 transaction,
+                replaceable,
                 response_channel,
             } => match self.transaction_verifier_process.reserve() {
                 Ok(reservation) => {
@@ -345,6 +391,7 @@ where
                         transaction,
                         None,
                         Some(response_channel),
+                        replaceable,
                     );
 
                     self.transaction_verifier_process
@@ -355,17 +402,48 @@ where
                     let _ = response_channel.send(Err(Error::ServiceQueueFull));
                 }
             },
+            WritePoolRequest::InsertBundle {
+                transactions,
+                response_channel,
+            } if transactions.len() > self.max_bundle_size => {
+                // Rejects the oversized bundle before the verification of its transactions.
+                let error = Error::Bundle(BundleError::TooManyTransactions(
+                    transactions.len(),
+                    self.max_bundle_size,
+                ));
+                let _ = response_channel.send(Err(error));
+            }
+            WritePoolRequest::InsertBundle {
+                transactions,
+                response_channel,
+            } => match self.transaction_verifier_process.reserve() {
+                Ok(reservation) => {
+                    let op = self.insert_bundle(transactions, response_channel);
+
+                    self.transaction_verifier_process
+                        .spawn_reserved(reservation, op);
+                }
+                _ => {
+                    tracing::error!("Failed to insert bundle: Out of capacity");
+                    let _ = response_channel.send(Err(Error::ServiceQueueFull));
+                }
+            },
         }
     }
 
     fn process_notification(&mut self, notification: PoolNotification) {
         match notification {
             PoolNotification::Inserted {
-                tx_id,
+                tx,
                 time,
                 expiration,
                 source,
             } => {
+                let tx_id = tx.id();
+                let time = match &mut self.journal {
+                    Some(journal) => journal.record(&tx, time),
+                    None => time,
+                };
                 match source {
                     ExtendedInsertionSource::P2P { from_peer_info } => {
                         let _ = self.p2p.notify_gossip_transaction_validity(
@@ -388,16 +466,7 @@ where
                     }
                 }
 
-                self.pruner.time_txs_submitted.push_front((time, tx_id));
-
-                if expiration < u32::MAX.into() {
-                    let block_height_expiration = self
-                        .pruner
-                        .height_expiration_txs
-                        .entry(expiration)
-                        .or_default();
-                    block_height_expiration.push(tx_id);
-                }
+                self.track_for_pruning(tx_id, time, expiration);
             }
             PoolNotification::ErrorInsertion {
                 tx_id,
@@ -413,7 +482,9 @@ where
                             GossipsubMessageAcceptance::Ignore,
                         );
                     }
-                    InsertionSource::RPC { response_channel } => {
+                    InsertionSource::RPC {
+                        response_channel, ..
+                    } => {
                         if let Some(channel) = response_channel {
                             let _ = channel.send(Err(error));
                         }
@@ -429,6 +500,57 @@ where
                     self.tx_status_manager.status_update(tx_id, tx_status);
                 }
             }
+            PoolNotification::InsertedNotExecutable { tx, time } => {
+                if let Some(journal) = &mut self.journal {
+                    journal.record(&tx, time);
+                }
+            }
+            PoolNotification::BundleInserted {
+                txs,
+                time,
+                response_channel,
+            } => {
+                if response_channel.send(Ok(())).is_err() {
+                    tracing::error!("Failed to send the response to the RPC");
+                }
+
+                // Bundles are neither gossiped nor journaled: peers and the replay
+                // after the restart would receive them as independent transactions
+                // and could include them without the all-or-nothing guarantee.
+                for (tx_id, expiration) in txs {
+                    self.track_for_pruning(tx_id, time, expiration);
+                }
+            }
+            PoolNotification::BundleErrorInsertion {
+                tx_ids,
+                error,
+                response_channel,
+            } => {
+                for tx_id in tx_ids {
+                    let tx_status =
+                        TransactionStatus::squeezed_out(error.to_string(), tx_id);
+                    self.tx_status_manager.status_update(tx_id, tx_status);
+                }
+                let _ = response_channel.send(Err(error));
+            }
+        }
+    }
+
+    fn track_for_pruning(
+        &mut self,
+        tx_id: TxId,
+        time: SystemTime,
+        expiration: BlockHeight,
+    ) {
+        self.pruner.time_txs_submitted.push_front((time, tx_id));
+
+        if expiration < u32::MAX.into() {
+            let block_height_expiration = self
+                .pruner
+                .height_expiration_txs
+                .entry(expiration)
+                .or_default();
+            block_height_expiration.push(tx_id);
         }
     }
 
@@ -438,7 +560,7 @@ where
                 tracing::error!("Failed to insert transactions: Out of capacity");
                 continue
             };
-            let op = self.insert_transaction(transaction, None, None);
+            let op = self.insert_transaction(transaction, None, None, true);
 
             self.transaction_verifier_process
                 .spawn_reserved(reservation, op);
@@ -450,6 +572,7 @@ where
         transaction: Arc<Transaction>,
         from_peer_info: Option<GossipsubMessageInfo>,
         response_channel: Option<oneshot::Sender<Result<(), Error>>>,
+        replaceable: bool,
     ) -> impl FnOnce() + Send + 'static + use<View, P2P, TxStatusManager> {
         let metrics = self.metrics;
         if metrics {
@@ -465,6 +588,7 @@ where
         let tx_id = transaction.id(&self.chain_id);
         let utxo_validation = self.utxo_validation;
         let allow_syscall = self.allow_syscall;
+        let max_maturity_lookahead = self.max_maturity_lookahead;
         let tx_status_manager = self.tx_status_manager.clone();
 
         let insert_transaction_thread_pool_op = move || {
@@ -481,6 +605,7 @@ where
             let result = verification.perform_all_verifications(
                 transaction,
                 next_block_height,
+                max_maturity_lookahead,
                 utxo_validation,
                 allow_syscall,
             );
@@ -526,7 +651,10 @@ where
             let source = if let Some(from_peer_info) = from_peer_info {
                 InsertionSource::P2P { from_peer_info }
             } else {
-                InsertionSource::RPC { response_channel }
+                InsertionSource::RPC {
+                    response_channel,
+                    replaceable,
+                }
             };
             let tx = Arc::new(checked_tx);
 
@@ -551,6 +679,71 @@ where
         }
     }
 
+    fn insert_bundle(
+        &self,
+        transactions: Vec<Arc<Transaction>>,
+        response_channel: oneshot::Sender<Result<(), Error>>,
+    ) -> impl FnOnce() + Send + 'static + use<View, P2P, TxStatusManager> {
+        let verification = self.verification.clone();
+        let pool_insert_request_sender = self.pool_worker.request_insert_sender.clone();
+        let current_height_reader = self.current_height_reader.clone();
+        let chain_id = self.chain_id;
+        let utxo_validation = self.utxo_validation;
+        let allow_syscall = self.allow_syscall;
+        let tx_status_manager = self.tx_status_manager.clone();
+
+        move || {
+            let current_height = current_height_reader.read();
+            // Transactions will be executed in the next block, so we validate
+            // against the next block height.
+            let next_block_height = current_height.succ().unwrap_or(current_height);
+            let tx_ids = transactions
+                .iter()
+                .map(|tx| tx.id(&chain_id))
+                .collect::<Vec<_>>();
+
+            let mut txs = Vec::with_capacity(transactions.len());
+            for (index, transaction) in transactions.into_iter().enumerate() {
+                // Transactions of the bundle are executable right away,
+                // so they can't wait for their maturity in the pool.
+                let result = verification.perform_all_verifications(
+                    Arc::unwrap_or_clone(transaction),
+                    next_block_height,
+                    0,
+                    utxo_validation,
+                    allow_syscall,
+                );
+
+                match result {
+                    Ok(checked_tx) => txs.push(Arc::new(checked_tx)),
+                    Err(error) => {
+                        let error = Error::Bundle(BundleError::InvalidTransaction {
+                            index,
+                            error: Box::new(error),
+                        });
+                        for tx_id in tx_ids {
+                            tx_status_manager.status_update(
+                                tx_id,
+                                TransactionStatus::squeezed_out(error.to_string(), tx_id),
+                            );
+                        }
+                        let _ = response_channel.send(Err(error));
+                        return
+                    }
+                }
+            }
+
+            if let Err(e) =
+                pool_insert_request_sender.try_send(PoolInsertRequest::InsertBundle {
+                    txs,
+                    response_channel,
+                })
+            {
+                tracing::error!("Failed to send the insert bundle request: {}", e);
+            }
+        }
+    }
+
     fn manage_tx_from_p2p(
         &mut self,
         tx: Transaction,
@@ -566,7 +759,7 @@ where
             message_id,
             peer_id,
         });
-        let op = self.insert_transaction(Arc::new(tx), info, None);
+        let op = self.insert_transaction(Arc::new(tx), info, None, true);
         self.transaction_verifier_process
             .spawn_reserved(reservation, op);
     }
@@ -659,6 +852,89 @@ where
         });
     }
 
+    /// Inserts the transactions from the journal through the usual verification path.
+    /// Transactions that outlived the TTL of the pool are dropped, while the
+    /// others keep their original submission time, so the restart doesn't extend their TTL.
+    async fn replay_journal(&mut self) {
+        let Some(journal) = &mut self.journal else {
+            return
+        };
+        let mut entries = match journal.load().await {
+            Ok(entries) => entries,
+            Err(err) => {
+                tracing::error!("Failed to load the transaction pool journal: {err}");
+                return
+            }
+        };
+
+        let now = SystemTime::now();
+        let number_of_entries = entries.len();
+        entries.retain(|entry| {
+            now.duration_since(entry.submitted_at)
+                .map(|age| age < self.pruner.txs_ttl)
+                .unwrap_or(true)
+        });
+        // Dependent transactions are submitted after the transactions they depend on.
+        entries.sort_by_key(|entry| entry.submitted_at);
+        tracing::info!(
+            "Replaying {} transactions from the transaction pool journal, {} expired",
+            entries.len(),
+            number_of_entries.saturating_sub(entries.len())
+        );
+
+        for entry in &entries {
+            journal.replay(entry);
+        }
+
+        for entry in entries {
+            // Unlike other sources of transactions, we wait for the space in the
+            // verification queue instead of dropping the transaction.
+            let reservation = loop {
+                match self.transaction_verifier_process.reserve() {
+                    Ok(reservation) => break reservation,
+                    Err(_) => tokio::time::sleep(Duration::from_millis(10)).await,
+                }
+            };
+            let op = self.insert_transaction(Arc::new(entry.tx), None, None, true);
+            self.transaction_verifier_process
+                .spawn_reserved(reservation, op);
+        }
+    }
+
+    /// Drops the transactions that left all pools from the journal.
+    async fn rotate_journal(&mut self) {
+        let Some(journal) = &self.journal else { return };
+        let tx_ids = journal.recorded_tx_ids();
+        let removed = match self.absent_txs(tx_ids).await {
+            Ok(removed) => removed,
+            Err(err) => {
+                tracing::error!(
+                    "Failed to collect transactions for the transaction pool journal: {err}"
+                );
+                return
+            }
+        };
+
+        if let Some(journal) = &mut self.journal {
+            journal.rotate(&removed, self.pruner.txs_ttl);
+        }
+    }
+
+    async fn absent_txs(&self, tx_ids: Vec<TxId>) -> Result<Vec<TxId>, Error> {
+        let (response_channel, result_receiver) = oneshot::channel();
+        self.pool_worker
+            .request_read_sender
+            .send(PoolReadRequest::AbsentTxs {
+                tx_ids,
+                response_channel,
+            })
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)?;
+        result_receiver
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)
+    }
+
     fn try_prune_transactions(&mut self) -> TaskNextAction {
         let mut txs_to_remove = vec![];
         {
@@ -707,6 +983,7 @@ pub fn new_service<
     GasPriceProvider,
     WasmChecker,
     TxStatusManager,
+    AdmissionFilter,
 >(
     chain_id: ChainId,
     config: Config,
@@ -719,6 +996,7 @@ pub fn new_service<
     wasm_checker: WasmChecker,
     new_txs_notifier: watch::Sender<()>,
     tx_status_manager: TxStatusManager,
+    admission_filter: AdmissionFilter,
 ) -> Service<PSView, P2P, TxStatusManager>
 where
     P2P: P2PSubscriptions<GossipedTransaction = TransactionGossipData>,
@@ -730,6 +1008,7 @@ where
     WasmChecker: WasmCheckerTrait,
     BlockImporter: BlockImporterTrait,
     TxStatusManager: TxStatusManagerTrait,
+    AdmissionFilter: AdmissionFilterTrait,
 {
     let mut ttl_timer = tokio::time::interval(config.ttl_check_interval);
     ttl_timer.set_missed_tick_behavior(MissedTickBehavior::Skip);
@@ -785,21 +1064,25 @@ where
     .unwrap();
 
     let metrics = config.metrics;
+    let journal = config.journal.clone().map(Journal::new);
 
     let service_channel_limits = config.service_channel_limits;
     let utxo_validation = config.utxo_validation;
     let allow_syscall = config.allow_syscall;
+    let max_maturity_lookahead = config.max_maturity_lookahead;
+    let max_bundle_size = config.max_bundle_size;
     let tx_status_manager = Arc::new(tx_status_manager);
     let txpool = Pool::new(
         GraphStorage::new(GraphConfig {
             max_txs_chain_count: config.max_txs_chain_count,
         }),
         BasicCollisionManager::new(),
-        RatioTipGasSelection::new(new_txs_notifier.clone()),
+        Selection::new(&config.selection_policy, new_txs_notifier.clone()),
         config,
         pool_stats_sender,
         new_txs_notifier.clone(),
         tx_status_manager.clone(),
+        Arc::new(admission_filter),
     );
 
     // BlockHeight is < 64 bytes, so we can use SeqLock
@@ -827,6 +1110,8 @@ where
         chain_id,
         utxo_validation,
         allow_syscall,
+        max_maturity_lookahead,
+        max_bundle_size,
         subscriptions,
         verification: Arc::new(verification),
         transaction_verifier_process,
@@ -837,6 +1122,7 @@ where
         current_height_reader,
         pool_worker,
         shared_state,
+        journal,
         metrics,
         tx_sync_history: Default::default(),
         tx_status_manager,
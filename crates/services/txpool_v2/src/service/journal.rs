@@ -0,0 +1,350 @@
+use crate::config::JournalConfig;
+use fuel_core_types::{
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
+    fuel_types::canonical::{
+        Deserialize,
+        Serialize,
+    },
+    services::txpool::ArcPoolTx,
+};
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+        hash_map::Entry,
+    },
+    fs,
+    io::{
+        self,
+        Write,
+    },
+    ops::Deref,
+    path::{
+        Path,
+        PathBuf,
+    },
+    time::{
+        Duration,
+        SystemTime,
+    },
+};
+use tokio::{
+    sync::mpsc,
+    task::JoinHandle,
+    time::{
+        Instant,
+        Interval,
+        MissedTickBehavior,
+    },
+};
+
+/// The transaction from the journal along with the time of its submission to the pool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct JournalEntry {
+    pub submitted_at: SystemTime,
+    pub tx_id: TxId,
+    pub tx: Transaction,
+}
+
+enum Command {
+    Append(Box<JournalEntry>),
+    /// Rewrites the journal, keeping only the records of the `live` transactions.
+    Rotate {
+        live: HashSet<TxId>,
+    },
+}
+
+/// The on-disk journal of the transactions of the pool.
+///
+/// The journal is a sequence of records. Each record contains the submission time
+/// in milliseconds since the Unix epoch (8 bytes), the id of the transaction (32 bytes),
+/// the length of the transaction (4 bytes) and the canonically encoded transaction.
+/// Transactions are appended to the journal when the pool accepts them, and the periodic
+/// rotation drops the records of transactions that left the pool. The file is only
+/// accessed from the blocking thread pool.
+pub(crate) struct Journal {
+    path: PathBuf,
+    commands: mpsc::UnboundedSender<Command>,
+    writer: JoinHandle<()>,
+    pub rotation_timer: Interval,
+    /// Submission times of the transactions appended to the journal.
+    recorded: HashMap<TxId, SystemTime>,
+    /// Original submission times of the transactions replayed from the journal.
+    replayed: HashMap<TxId, SystemTime>,
+}
+
+impl Journal {
+    pub fn new(config: JournalConfig) -> Self {
+        // The journal was just replayed, so there is nothing to drop during the first tick.
+        let start = Instant::now()
+            .checked_add(config.rotation_interval)
+            .unwrap_or_else(Instant::now);
+        let mut rotation_timer =
+            tokio::time::interval_at(start, config.rotation_interval);
+        rotation_timer.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        let (commands, receiver) = mpsc::unbounded_channel();
+        let writer = tokio::task::spawn_blocking({
+            let path = config.path.clone();
+            move || run_writer(path, receiver)
+        });
+        Self {
+            path: config.path,
+            commands,
+            writer,
+            rotation_timer,
+            recorded: HashMap::new(),
+            replayed: HashMap::new(),
+        }
+    }
+
+    /// Reads the entries of the journal, returns nothing if the journal doesn't exist.
+    /// See [`decode`] for the handling of the broken records.
+    ///
+    /// The journal is rewritten with the decoded entries, so the records appended
+    /// after the load don't follow the incomplete record left by a crash.
+    pub async fn load(&self) -> io::Result<Vec<JournalEntry>> {
+        let path = self.path.clone();
+        tokio::task::spawn_blocking(move || {
+            let entries = read(&path)?;
+            if path.exists() {
+                write(&path, &entries)?;
+            }
+            Ok(entries)
+        })
+        .await
+        .map_err(io::Error::other)?
+    }
+
+    /// Remembers the original submission time of the transaction replayed from the
+    /// journal, so its TTL is not extended by the restart.
+    pub fn replay(&mut self, entry: &JournalEntry) {
+        self.replayed.insert(entry.tx_id, entry.submitted_at);
+    }
+
+    /// Appends the transaction accepted by the pool at the `time` to the journal,
+    /// unless it is already there. Returns the time of the submission of the transaction,
+    /// which is the original one for the replayed transactions.
+    pub fn record(&mut self, tx: &ArcPoolTx, time: SystemTime) -> SystemTime {
+        let tx_id = tx.id();
+        let submitted_at = self.replayed.get(&tx_id).copied().unwrap_or(time);
+        if let Entry::Vacant(entry) = self.recorded.entry(tx_id) {
+            entry.insert(submitted_at);
+            let entry = JournalEntry {
+                submitted_at,
+                tx_id,
+                tx: tx.deref().into(),
+            };
+            if self
+                .commands
+                .send(Command::Append(Box::new(entry)))
+                .is_err()
+            {
+                tracing::error!("The transaction pool journal writer is stopped");
+            }
+        }
+        submitted_at
+    }
+
+    /// Returns the ids of the transactions appended to the journal.
+    pub fn recorded_tx_ids(&self) -> Vec<TxId> {
+        self.recorded.keys().copied().collect()
+    }
+
+    /// Drops the records of the `removed` transactions from the journal, along with the
+    /// replayed transactions that outlived the `ttl`.
+    pub fn rotate(&mut self, removed: &[TxId], ttl: Duration) {
+        for tx_id in removed {
+            self.recorded.remove(tx_id);
+        }
+        let now = SystemTime::now();
+        self.replayed.retain(|_, submitted_at| {
+            now.duration_since(*submitted_at)
+                .map(|age| age < ttl)
+                .unwrap_or(true)
+        });
+
+        let live = self
+            .recorded
+            .keys()
+            .chain(self.replayed.keys())
+            .copied()
+            .collect();
+        if self.commands.send(Command::Rotate { live }).is_err() {
+            tracing::error!("The transaction pool journal writer is stopped");
+        }
+    }
+
+    /// Waits until the writer persists all appended records.
+    pub async fn close(self) {
+        drop(self.commands);
+        if let Err(err) = self.writer.await {
+            tracing::error!("The transaction pool journal writer failed: {err}");
+        }
+    }
+}
+
+fn run_writer(path: PathBuf, mut commands: mpsc::UnboundedReceiver<Command>) {
+    let mut file: Option<io::BufWriter<fs::File>> = None;
+    while let Some(command) = commands.blocking_recv() {
+        let result = match command {
+            Command::Append(entry) => append(&path, &mut file, &entry),
+            Command::Rotate { live } => {
+                // The rotation replaces the file, so the appended records are flushed first.
+                let result = match file.take() {
+                    Some(mut file) => file.flush(),
+                    None => Ok(()),
+                };
+                result.and_then(|_| read(&path)).and_then(|mut entries| {
+                    entries.retain(|entry| live.contains(&entry.tx_id));
+                    write(&path, &entries)
+                })
+            }
+        };
+        if let Err(err) = result {
+            tracing::error!("Failed to write the transaction pool journal: {err}");
+        }
+
+        // Appended records are flushed in batches, once there are no more commands.
+        if commands.is_empty()
+            && let Some(Err(err)) = file.as_mut().map(|file| file.flush())
+        {
+            tracing::error!("Failed to flush the transaction pool journal: {err}");
+        }
+    }
+
+    if let Some(Err(err)) = file
+        .map(|file| file.into_inner().map_err(|err| err.into_error()))
+        .map(|file| file.and_then(|file| file.sync_all()))
+    {
+        tracing::error!("Failed to sync the transaction pool journal: {err}");
+    }
+}
+
+fn append(
+    path: &Path,
+    file: &mut Option<io::BufWriter<fs::File>>,
+    entry: &JournalEntry,
+) -> io::Result<()> {
+    let writer = match file {
+        Some(writer) => writer,
+        None => {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let opened = fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)?;
+            file.insert(io::BufWriter::new(opened))
+        }
+    };
+    encode(entry, writer)
+}
+
+/// Reads the entries of the journal at the `path`, returns nothing if the journal
+/// doesn't exist. Only the first record of each transaction is returned.
+fn read(path: &Path) -> io::Result<Vec<JournalEntry>> {
+    let mut entries = match fs::read(path) {
+        Ok(bytes) => decode(&bytes),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => vec![],
+        Err(err) => return Err(err),
+    };
+    let mut seen = HashSet::new();
+    entries.retain(|entry| seen.insert(entry.tx_id));
+    Ok(entries)
+}
+
+/// Replaces the content of the journal at the `path` with the `entries`.
+/// The journal is written into a temporary file first and renamed after,
+/// so a crash in the middle of the write doesn't corrupt the previous journal.
+pub(crate) fn write(path: &Path, entries: &[JournalEntry]) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut tmp_path = path.to_path_buf().into_os_string();
+    tmp_path.push(".tmp");
+    let tmp_path = PathBuf::from(tmp_path);
+
+    let mut writer = io::BufWriter::new(fs::File::create(&tmp_path)?);
+    for entry in entries {
+        encode(entry, &mut writer)?;
+    }
+    let file = writer.into_inner().map_err(|err| err.into_error())?;
+    file.sync_all()?;
+    fs::rename(tmp_path, path)
+}
+
+fn encode<W: Write>(entry: &JournalEntry, writer: &mut W) -> io::Result<()> {
+    let submitted_at = entry
+        .submitted_at
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    let submitted_at = u64::try_from(submitted_at).unwrap_or(u64::MAX);
+    let tx = entry.tx.to_bytes();
+    let len = u32::try_from(tx.len()).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "The transaction is too large for the journal",
+        )
+    })?;
+
+    writer.write_all(&submitted_at.to_be_bytes())?;
+    writer.write_all(entry.tx_id.as_ref())?;
+    writer.write_all(&len.to_be_bytes())?;
+    writer.write_all(&tx)
+}
+
+/// Decodes the records of the journal.
+/// The decoding stops at the first incomplete record, while the records
+/// with transactions that can't be decoded are skipped.
+pub(crate) fn decode(mut bytes: &[u8]) -> Vec<JournalEntry> {
+    let mut entries = vec![];
+    while !bytes.is_empty() {
+        let record = bytes
+            .split_first_chunk::<8>()
+            .and_then(|(submitted_at, rest)| {
+                let (tx_id, rest) = rest.split_first_chunk::<32>()?;
+                let (len, rest) = rest.split_first_chunk::<4>()?;
+                let len = usize::try_from(u32::from_be_bytes(*len)).ok()?;
+                let (tx, rest) = rest.split_at_checked(len)?;
+                Some((
+                    u64::from_be_bytes(*submitted_at),
+                    TxId::new(*tx_id),
+                    tx,
+                    rest,
+                ))
+            });
+        let Some((submitted_at, tx_id, tx, rest)) = record else {
+            tracing::warn!(
+                "The last record of the transaction pool journal is incomplete"
+            );
+            break
+        };
+        bytes = rest;
+
+        let submitted_at =
+            SystemTime::UNIX_EPOCH.checked_add(Duration::from_millis(submitted_at));
+        match (submitted_at, Transaction::from_bytes(tx)) {
+            (Some(submitted_at), Ok(tx)) => {
+                entries.push(JournalEntry {
+                    submitted_at,
+                    tx_id,
+                    tx,
+                });
+            }
+            (_, Err(err)) => {
+                tracing::warn!(
+                    "Failed to decode the transaction from the journal: {err}"
+                );
+            }
+            (None, _) => {
+                tracing::warn!("The submission time of the journal record is invalid");
+            }
+        }
+    }
+    entries
+}
@@ -21,6 +21,7 @@ use fuel_core_types::{
         Transaction,
         UpgradePurpose,
         field::{
+            Maturity,
             MaxFeeLimit,
             UpgradePurpose as _,
         },
@@ -68,6 +69,7 @@ where
         &self,
         tx: Transaction,
         current_height: BlockHeight,
+        max_maturity_lookahead: u32,
         utxo_validation: bool,
         allow_syscall: bool,
     ) -> Result<PoolTransaction, Error> {
@@ -76,8 +78,11 @@ where
 
         let unverified = UnverifiedTx(tx);
 
-        let basically_verified_tx =
-            unverified.perform_basic_verifications(current_height, &consensus_params)?;
+        let basically_verified_tx = unverified.perform_basic_verifications(
+            current_height,
+            max_maturity_lookahead,
+            &consensus_params,
+        )?;
 
         let metadata =
             calculate_metadata(&basically_verified_tx.0, &consensus_params, version)?;
@@ -129,15 +134,28 @@ impl UnverifiedTx {
     pub fn perform_basic_verifications(
         self,
         current_height: BlockHeight,
+        max_maturity_lookahead: u32,
         consensus_params: &ConsensusParameters,
     ) -> Result<BasicVerifiedTx, Error> {
         if self.0.is_mint() {
             return Err(Error::MintIsDisallowed);
         }
 
+        // The transaction that matures within the lookahead is verified
+        // at its maturity height, and waits in the pool until the chain reaches it.
+        let maturity = maturity(&self.0);
+        let max_maturity =
+            BlockHeight::new(current_height.saturating_add(max_maturity_lookahead));
+        let verification_height = if maturity > current_height && maturity <= max_maturity
+        {
+            maturity
+        } else {
+            current_height
+        };
+
         let tx = self
             .0
-            .into_checked_basic(current_height, consensus_params)?;
+            .into_checked_basic(verification_height, consensus_params)?;
 
         Ok(BasicVerifiedTx(tx.into()))
     }
@@ -234,6 +252,17 @@ impl FullyVerifiedTx {
     }
 }
 
+fn maturity(tx: &Transaction) -> BlockHeight {
+    match tx {
+        Transaction::Script(tx) => tx.maturity(),
+        Transaction::Create(tx) => tx.maturity(),
+        Transaction::Mint(_) => BlockHeight::default(),
+        Transaction::Upgrade(tx) => tx.maturity(),
+        Transaction::Upload(tx) => tx.maturity(),
+        Transaction::Blob(tx) => tx.maturity(),
+    }
+}
+
 fn calculate_metadata(
     tx: &CheckedTransaction,
     consensus_params: &ConsensusParameters,
@@ -19,6 +19,10 @@ use tokio::sync::{
 use crate::{
     Constraints,
     error::Error,
+    inspection::{
+        InspectedTransaction,
+        InspectionFilter,
+    },
     pool::TxPoolStats,
     pool_worker::{
         self,
@@ -51,12 +55,51 @@ impl SharedState {
     }
 
     pub async fn insert(&self, transaction: Transaction) -> Result<(), Error> {
+        self.insert_opt(transaction, true).await
+    }
+
+    /// Inserts the transaction into the pool. If `replaceable` is `false`, transactions
+    /// colliding with it are rejected regardless of their tip while it is in the pool.
+    /// The flag is local to the node: it isn't gossiped to peers or kept in the journal.
+    pub async fn insert_opt(
+        &self,
+        transaction: Transaction,
+        replaceable: bool,
+    ) -> Result<(), Error> {
         let transaction = Arc::new(transaction);
         let (sender, receiver) = oneshot::channel();
 
         self.write_pool_requests_sender
             .send(WritePoolRequest::InsertTx {
                 transaction,
+                replaceable,
+                response_channel: sender,
+            })
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)?;
+
+        receiver
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)?
+    }
+
+    /// Inserts the ordered bundle of transactions. The bundle is included into the block
+    /// all-or-nothing and contiguously. If any transaction of the bundle is invalid,
+    /// the whole bundle is rejected.
+    ///
+    /// The all-or-nothing inclusion is guaranteed only by the selection of transactions
+    /// for the block; the executor still skips a member of the bundle that fails at
+    /// the execution without reverting the others.
+    pub async fn insert_bundle(
+        &self,
+        transactions: Vec<Transaction>,
+    ) -> Result<(), Error> {
+        let transactions = transactions.into_iter().map(Arc::new).collect();
+        let (sender, receiver) = oneshot::channel();
+
+        self.write_pool_requests_sender
+            .send(WritePoolRequest::InsertBundle {
+                transactions,
                 response_channel: sender,
             })
             .await
@@ -137,6 +180,28 @@ impl SharedState {
             .map_err(|_| Error::ServiceCommunicationFailed)
     }
 
+    /// Returns up to `filter.limit` transactions of the pool, including pending and
+    /// future transactions, that match the `filter`. Transactions are returned in
+    /// the inspection order, starting from the `filter.start` cursor.
+    pub async fn inspect(
+        &self,
+        filter: InspectionFilter,
+    ) -> Result<Vec<InspectedTransaction>, Error> {
+        let (response_channel, result_receiver) = oneshot::channel();
+
+        self.request_read_sender
+            .send(PoolReadRequest::Inspect {
+                filter,
+                response_channel,
+            })
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)?;
+
+        result_receiver
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)
+    }
+
     /// Get a notifier that is notified when new executable transactions are added to the pool.
     pub fn get_new_executable_txs_notifier(&self) -> watch::Receiver<()> {
         self.new_executable_txs_notifier.subscribe()
@@ -4,6 +4,7 @@ use std::{
         HashSet,
         VecDeque,
     },
+    sync::Arc,
     time::SystemTime,
 };
 
@@ -48,7 +49,7 @@ use crate::{
     extracted_outputs::ExtractedOutputs,
     pending_pool::MissingInput,
     ports::TxPoolPersistentStorage,
-    selection_algorithms::ratio_tip_gas::RatioTipGasSelectionAlgorithmStorage,
+    selection_algorithms::SelectionAlgorithmStorage,
     spent_inputs::SpentInputs,
     storage::checked_collision::CheckedTransaction,
 };
@@ -68,6 +69,8 @@ pub struct GraphStorage {
     coins_creators: HashMap<UtxoId, NodeIndex>,
     /// Contract -> Transaction that currently create the contract
     contracts_creators: HashMap<ContractId, NodeIndex>,
+    /// Transaction -> All transactions of its bundle in the bundle order
+    bundles: HashMap<NodeIndex, Arc<[NodeIndex]>>,
 }
 
 pub struct GraphConfig {
@@ -83,6 +86,7 @@ impl GraphStorage {
             graph: StableDiGraph::new(),
             coins_creators: HashMap::new(),
             contracts_creators: HashMap::new(),
+            bundles: HashMap::new(),
         }
     }
 
@@ -91,6 +95,7 @@ impl GraphStorage {
         self.graph.node_count() == 0
             && self.coins_creators.is_empty()
             && self.contracts_creators.is_empty()
+            && self.bundles.is_empty()
     }
 }
 
@@ -137,10 +142,25 @@ impl GraphStorage {
         &mut self,
         root_id: NodeIndex,
     ) -> Vec<StorageData> {
-        self.bfs(root_id)
+        let mut result = Vec::new();
+        let mut roots = vec![root_id];
+        // Removal of a bundle member adds other members of the bundle to the `roots`.
+        while let Some(root) = roots.pop() {
+            result.extend(self.bfs(root, &mut roots));
+        }
+        result
+    }
+
+    /// Dissolves the bundle of the transaction and returns its members.
+    fn remove_bundle(&mut self, index: NodeIndex) -> Option<Arc<[NodeIndex]>> {
+        let members = self.bundles.remove(&index)?;
+        for member in members.iter() {
+            self.bundles.remove(member);
+        }
+        Some(members)
     }
 
-    fn bfs(&mut self, root: NodeIndex) -> Vec<StorageData> {
+    fn bfs(&mut self, root: NodeIndex, roots: &mut Vec<NodeIndex>) -> Vec<StorageData> {
         // The algorithm heavily rely on the property of not having
         // diamond dependencies. The `DependentTransactionIsADiamondDeath` error
         // helps to achieve this property.
@@ -167,6 +187,9 @@ impl GraphStorage {
                     since we iterate over it using bfs",
             );
             self.clear_cache(&removed_storage_entry);
+            if let Some(members) = self.remove_bundle(remove) {
+                roots.extend(members.iter().filter(|member| **member != remove));
+            }
 
             for dependent in dependents {
                 queue.push_back(dependent);
@@ -394,7 +417,13 @@ impl GraphStorage {
                 .graph
                 .node_weight(node_id)
                 .expect("A node not expected exists in storage");
-            let has_dependencies = Storage::has_dependencies(self, &node_id);
+            // Transactions of a bundle except the first one are selected only
+            // together with the first one, so they are never executable.
+            let has_dependencies = Storage::has_dependencies(self, &node_id)
+                || self
+                    .bundles
+                    .get(&node_id)
+                    .is_some_and(|members| members.first() != Some(&node_id));
             let tx_id = node.transaction.id();
             let tx = txs_map
                 .remove(&tx_id)
@@ -460,6 +489,20 @@ impl GraphStorage {
             contracts_creators
         );
 
+        for (node_id, members) in &self.bundles {
+            assert!(
+                members.contains(node_id),
+                "The transaction is not a member of its bundle"
+            );
+            for member in members.iter() {
+                assert!(
+                    self.graph.contains_node(*member),
+                    "A member of the bundle is missing in storage"
+                );
+                assert_eq!(self.bundles.get(member), Some(members));
+            }
+        }
+
         txs_info
     }
 }
@@ -776,13 +819,26 @@ impl Storage for GraphStorage {
     }
 
     fn remove_transaction(&mut self, index: Self::StorageIndex) -> Option<StorageData> {
+        self.remove_bundle(index);
         self.graph.remove_node(index).inspect(|storage_entry| {
             self.clear_cache(storage_entry);
         })
     }
+
+    fn store_bundle(&mut self, members: &[Self::StorageIndex]) {
+        let bundle: Arc<[NodeIndex]> = members.into();
+        for member in members {
+            debug_assert!(self.graph.contains_node(*member));
+            self.bundles.insert(*member, bundle.clone());
+        }
+    }
+
+    fn get_bundle(&self, index: &Self::StorageIndex) -> Option<&[Self::StorageIndex]> {
+        self.bundles.get(index).map(|members| members.as_ref())
+    }
 }
 
-impl RatioTipGasSelectionAlgorithmStorage for GraphStorage {
+impl SelectionAlgorithmStorage for GraphStorage {
     type StorageIndex = NodeIndex;
 
     fn get(&self, index: &Self::StorageIndex) -> Option<&StorageData> {
@@ -800,10 +856,12 @@ impl RatioTipGasSelectionAlgorithmStorage for GraphStorage {
         self.get_direct_dependencies(*index).next().is_some()
     }
 
+    fn get_bundle(&self, index: &Self::StorageIndex) -> Option<&[Self::StorageIndex]> {
+        Storage::get_bundle(self, index)
+    }
+
     fn remove(&mut self, index: &Self::StorageIndex) -> Option<StorageData> {
-        self.graph.remove_node(*index).inspect(|storage_entry| {
-            self.clear_cache(storage_entry);
-        })
+        Storage::remove_transaction(self, *index)
     }
 }
 
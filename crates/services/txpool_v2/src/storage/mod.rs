@@ -100,11 +100,22 @@ pub trait Storage {
     ) -> Result<(), InputValidationErrorType>;
 
     /// Remove a transaction along with its dependents subtree.
+    /// If the transaction belongs to a bundle, all other transactions of the bundle
+    /// are removed along with their dependents subtrees too.
     fn remove_transaction_and_dependents_subtree(
         &mut self,
         index: Self::StorageIndex,
     ) -> RemovedTransactions;
 
     /// Remove a transaction from the storage.
+    /// If the transaction belongs to a bundle, the bundle is dissolved.
     fn remove_transaction(&mut self, index: Self::StorageIndex) -> Option<StorageData>;
+
+    /// Groups stored transactions into a bundle. The order of `members` is the order
+    /// of the bundle.
+    fn store_bundle(&mut self, members: &[Self::StorageIndex]);
+
+    /// Returns all transactions of the bundle the transaction belongs to
+    /// in the order of the bundle.
+    fn get_bundle(&self, index: &Self::StorageIndex) -> Option<&[Self::StorageIndex]>;
 }
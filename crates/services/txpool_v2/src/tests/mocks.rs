@@ -1,7 +1,9 @@
 use crate::{
     GasPrice,
+    TxPoolStats,
     ports::{
         self,
+        AdmissionFilter,
         AtomicView,
         BlockImporter as BlockImporterTrait,
         ChainStateInfoProvider,
@@ -64,6 +66,7 @@ use fuel_core_types::{
             TransactionStatus,
             statuses,
         },
+        txpool::PoolTransaction,
     },
 };
 use std::{
@@ -340,6 +343,40 @@ impl WasmChecker for MockWasmChecker {
     }
 }
 
+type AdmissionRule =
+    dyn Fn(&PoolTransaction, &TxPoolStats) -> Result<(), String> + Send + Sync;
+
+#[derive(Clone)]
+pub struct MockAdmissionFilter {
+    rule: Arc<AdmissionRule>,
+}
+
+impl MockAdmissionFilter {
+    pub fn new<F>(rule: F) -> Self
+    where
+        F: Fn(&PoolTransaction, &TxPoolStats) -> Result<(), String>
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self {
+            rule: Arc::new(rule),
+        }
+    }
+}
+
+impl Default for MockAdmissionFilter {
+    fn default() -> Self {
+        Self::new(|_, _| Ok(()))
+    }
+}
+
+impl AdmissionFilter for MockAdmissionFilter {
+    fn admit(&self, tx: &PoolTransaction, stats: &TxPoolStats) -> Result<(), String> {
+        (self.rule)(tx, stats)
+    }
+}
+
 mockall::mock! {
     pub ChainStateInfoProvider {}
 
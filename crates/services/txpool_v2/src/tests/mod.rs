@@ -2,10 +2,18 @@
 
 mod mocks;
 mod stability_test;
+mod tests_admission_filter;
+mod tests_bundle;
+mod tests_future_pool;
+mod tests_inspection;
+mod tests_journal;
+mod tests_owner_limits;
 mod tests_p2p;
 mod tests_pending_pool;
 mod tests_pool;
 mod tests_preconf_rollback;
+mod tests_replacement;
+mod tests_selection;
 mod tests_service;
 mod tx_status_manager_integration;
 mod universe;
@@ -204,6 +204,7 @@ fn stability_test_with_seed(seed: u64, limits: Limits, config: Config) {
         assert!(txpool.selection_algorithm.is_empty());
         assert!(txpool.storage.is_empty());
         assert!(txpool.collision_manager.is_empty());
+        assert!(txpool.owners_usage.is_empty());
     }
 }
 
@@ -0,0 +1,96 @@
+use std::sync::{
+    Arc,
+    atomic::{
+        AtomicBool,
+        Ordering,
+    },
+};
+
+use crate::{
+    error::Error,
+    tests::{
+        mocks::MockAdmissionFilter,
+        universe::TestPoolUniverse,
+    },
+};
+
+#[test]
+fn insert__tx_rejected_by_admission_filter_returns_error() {
+    let mut universe = TestPoolUniverse::default();
+    universe.admission_filter = MockAdmissionFilter::new(|tx, _| {
+        if tx.tip() < 10 {
+            Err("The tip is too low".to_string())
+        } else {
+            Ok(())
+        }
+    });
+    universe.build_pool();
+
+    // Given
+    let tx1 = universe.build_script_transaction(None, None, 10);
+    let tx2 = universe.build_script_transaction(None, None, 5);
+
+    // When
+    let result1 = universe.verify_and_insert(tx1);
+    let result2 = universe.verify_and_insert(tx2);
+
+    // Then
+    let tx1 = result1.unwrap();
+    let err = result2.unwrap_err();
+    assert!(matches!(err, Error::NotAdmitted(reason) if reason == "The tip is too low"));
+    universe.assert_pool_integrity(&[tx1]);
+}
+
+#[test]
+fn insert__admission_filter_receives_current_pool_stats() {
+    let mut universe = TestPoolUniverse::default();
+    universe.admission_filter = MockAdmissionFilter::new(|_, stats| {
+        if stats.tx_count >= 1 {
+            Err("The pool is busy".to_string())
+        } else {
+            Ok(())
+        }
+    });
+    universe.build_pool();
+
+    // Given
+    let tx1 = universe.build_script_transaction(None, None, 0);
+    let tx2 = universe.build_script_transaction(None, None, 0);
+    let tx1 = universe.verify_and_insert(tx1).unwrap();
+
+    // When
+    let result = universe.verify_and_insert(tx2);
+
+    // Then
+    let err = result.unwrap_err();
+    assert!(matches!(err, Error::NotAdmitted(reason) if reason == "The pool is busy"));
+    universe.assert_pool_integrity(&[tx1]);
+}
+
+#[test]
+fn insert__admission_filter_decision_can_change_at_runtime() {
+    let mut universe = TestPoolUniverse::default();
+    let reject_all = Arc::new(AtomicBool::new(true));
+    let rule = reject_all.clone();
+    universe.admission_filter = MockAdmissionFilter::new(move |_, _| {
+        if rule.load(Ordering::Relaxed) {
+            Err("Rejected by the current rules".to_string())
+        } else {
+            Ok(())
+        }
+    });
+    universe.build_pool();
+
+    // Given
+    let tx1 = universe.build_script_transaction(None, None, 0);
+    let tx2 = universe.build_script_transaction(None, None, 0);
+    universe.verify_and_insert(tx1).unwrap_err();
+
+    // When
+    reject_all.store(false, Ordering::Relaxed);
+    let result = universe.verify_and_insert(tx2);
+
+    // Then
+    let tx2 = result.unwrap();
+    universe.assert_pool_integrity(&[tx2]);
+}
@@ -0,0 +1,241 @@
+use std::iter;
+
+use crate::{
+    error::{
+        BundleError,
+        Error,
+        InputValidationError,
+    },
+    selection_algorithms::Constraints,
+    tests::universe::TestPoolUniverse,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        UniqueIdentifier,
+        UtxoId,
+    },
+    fuel_types::ChainId,
+};
+
+fn constraints(max_gas: u64) -> Constraints {
+    Constraints {
+        minimal_gas_price: 0,
+        max_gas,
+        maximum_txs: u16::MAX,
+        maximum_block_size: u32::MAX,
+        excluded_contracts: Default::default(),
+    }
+}
+
+#[test]
+fn insert_bundle__empty_bundle_is_rejected() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // When
+    let result = universe.verify_and_insert_bundle(vec![]);
+
+    // Then
+    let err = result.unwrap_err();
+    assert!(matches!(err, Error::Bundle(BundleError::Empty)));
+    universe.assert_pool_integrity(&[]);
+}
+
+#[test]
+fn insert_bundle__member_depending_on_previous_member_succeeds() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let (output, unset_input) = universe.create_output_and_input();
+    let tx1 = universe.build_script_transaction(None, Some(vec![output]), 0);
+    let input = unset_input.into_input(UtxoId::new(tx1.id(&ChainId::default()), 0));
+    let tx2 = universe.build_script_transaction(Some(vec![input]), None, 0);
+
+    // When
+    let result = universe.verify_and_insert_bundle(vec![tx1, tx2]);
+
+    // Then
+    let txs = result.unwrap();
+    universe.assert_pool_integrity(&txs);
+}
+
+#[test]
+fn insert_bundle__invalid_member_rejects_whole_bundle() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let (_, unset_input) = universe.create_output_and_input();
+    let utxo_id = UtxoId::new([1; 32].into(), 0);
+    let tx1 = universe.build_script_transaction(None, None, 0);
+    let tx2 = universe.build_script_transaction(
+        Some(vec![unset_input.into_input(utxo_id)]),
+        None,
+        0,
+    );
+
+    // When
+    let result = universe.verify_and_insert_bundle(vec![tx1, tx2]);
+
+    // Then
+    let err = result.unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Bundle(BundleError::InvalidTransaction { index: 1, error })
+            if matches!(*error, Error::InputValidation(InputValidationError::UtxoNotFound(id)) if id == utxo_id)
+    ));
+    universe.assert_pool_integrity(&[]);
+}
+
+#[test]
+fn insert_bundle__member_depending_on_pool_transaction_rejects_whole_bundle() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let (output, unset_input) = universe.create_output_and_input();
+    let pool_tx = universe.build_script_transaction(None, Some(vec![output]), 0);
+    let input = unset_input.into_input(UtxoId::new(pool_tx.id(&ChainId::default()), 0));
+    let pool_tx = universe.verify_and_insert(pool_tx).unwrap();
+    let tx1 = universe.build_script_transaction(None, None, 0);
+    let tx2 = universe.build_script_transaction(Some(vec![input]), None, 0);
+    let tx2_id = tx2.id(&ChainId::default());
+
+    // When
+    let result = universe.verify_and_insert_bundle(vec![tx1, tx2]);
+
+    // Then
+    let err = result.unwrap_err();
+    assert!(
+        matches!(err, Error::Bundle(BundleError::DependsOnNonMember(id)) if id == tx2_id)
+    );
+    universe.assert_pool_integrity(&[pool_tx]);
+}
+
+#[test]
+fn insert_bundle__member_colliding_with_pool_transaction_rejects_whole_bundle() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let (_, coin_input) = universe.setup_coin();
+    let pool_tx =
+        universe.build_script_transaction(Some(vec![coin_input.clone()]), None, 0);
+    let pool_tx = universe.verify_and_insert(pool_tx).unwrap();
+    let tx1 = universe.build_script_transaction(None, None, 0);
+    let tx2 = universe.build_script_transaction(Some(vec![coin_input]), None, 100);
+    let tx2_id = tx2.id(&ChainId::default());
+
+    // When
+    let result = universe.verify_and_insert_bundle(vec![tx1, tx2]);
+
+    // Then
+    let err = result.unwrap_err();
+    assert!(matches!(err, Error::Bundle(BundleError::Collision(id)) if id == tx2_id));
+    universe.assert_pool_integrity(&[pool_tx]);
+}
+
+#[test]
+fn insert__colliding_transaction_removes_whole_bundle() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let (_, coin_input) = universe.setup_coin();
+    let tx1 = universe.build_script_transaction(None, None, 0);
+    let tx2 = universe.build_script_transaction(Some(vec![coin_input.clone()]), None, 0);
+    universe.verify_and_insert_bundle(vec![tx1, tx2]).unwrap();
+    let tx3 = universe.build_script_transaction(Some(vec![coin_input]), None, 100);
+
+    // When
+    let result = universe.verify_and_insert(tx3);
+
+    // Then
+    let tx3 = result.unwrap();
+    universe.assert_pool_integrity(&[tx3]);
+}
+
+#[test]
+fn extract_transactions_for_block__bundle_is_selected_contiguously_and_in_order() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let high_tip_tx = universe.build_script_transaction(None, None, 100);
+    let low_tip_tx = universe.build_script_transaction(None, None, 50);
+    let (output, unset_input) = universe.create_output_and_input();
+    let tx1 = universe.build_script_transaction(None, Some(vec![output]), 1);
+    let input = unset_input.into_input(UtxoId::new(tx1.id(&ChainId::default()), 0));
+    let tx2 = universe.build_script_transaction(Some(vec![input]), None, 1000);
+    universe.verify_and_insert(high_tip_tx).unwrap();
+    universe.verify_and_insert(low_tip_tx).unwrap();
+    let bundle = universe.verify_and_insert_bundle(vec![tx1, tx2]).unwrap();
+
+    // When
+    let txs = universe
+        .get_pool()
+        .write()
+        .extract_transactions_for_block(constraints(u64::MAX));
+
+    // Then
+    assert_eq!(txs.len(), 4, "Should have 4 txs");
+    let position = txs
+        .iter()
+        .position(|tx| tx.id() == bundle[0].id())
+        .expect("The bundle should be selected");
+    assert_eq!(
+        txs[position.saturating_add(1)].id(),
+        bundle[1].id(),
+        "Members of the bundle should be selected one after another"
+    );
+    universe.assert_pool_integrity(&[]);
+}
+
+#[test]
+fn extract_transactions_for_block__bundle_that_does_not_fit_is_not_selected() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let tx = universe.build_script_transaction(None, None, 0);
+    let tx1 = universe.build_script_transaction(None, None, 100);
+    let tx2 = universe.build_script_transaction(None, None, 100);
+    let tx = universe.verify_and_insert(tx).unwrap();
+    let bundle = universe.verify_and_insert_bundle(vec![tx1, tx2]).unwrap();
+
+    // When
+    let max_gas = tx.max_gas();
+    let txs = universe
+        .get_pool()
+        .write()
+        .extract_transactions_for_block(constraints(max_gas));
+
+    // Then
+    assert_eq!(txs.len(), 1, "Should have 1 tx");
+    assert_eq!(txs[0].id(), tx.id(), "Should be the regular tx");
+    universe.assert_pool_integrity(&bundle);
+}
+
+#[test]
+fn process_committed_transactions__member_committed_separately_removes_bundle() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let tx1 = universe.build_script_transaction(None, None, 0);
+    let tx2 = universe.build_script_transaction(None, None, 0);
+    let tx3 = universe.build_script_transaction(None, None, 0);
+    let bundle = universe
+        .verify_and_insert_bundle(vec![tx1, tx2, tx3])
+        .unwrap();
+
+    // When
+    universe
+        .get_pool()
+        .write()
+        .process_committed_transactions(iter::once(bundle[1].id()));
+
+    // Then
+    universe.assert_pool_integrity(&[]);
+}
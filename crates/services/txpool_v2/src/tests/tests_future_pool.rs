@@ -0,0 +1,257 @@
+use fuel_core_services::Service as ServiceTrait;
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        consensus::Sealed,
+    },
+    fuel_asm::op,
+    fuel_tx::UniqueIdentifier,
+    fuel_types::BlockHeight,
+    services::block_importer::ImportResult,
+};
+use std::{
+    sync::Arc,
+    time::Duration,
+};
+
+use crate::{
+    error::Error,
+    tests::{
+        mocks::{
+            MockAdmissionFilter,
+            MockImporter,
+        },
+        universe::TestPoolUniverse,
+    },
+};
+
+fn block_at(height: BlockHeight) -> Sealed<Block> {
+    Sealed {
+        entity: {
+            let mut block = Block::default();
+            block.header_mut().set_block_height(height);
+            block
+        },
+        consensus: Default::default(),
+    }
+}
+
+#[tokio::test]
+async fn insert__future_tx_is_held_until_maturity() {
+    let mut universe = TestPoolUniverse::default();
+    let (sender, receiver) = tokio::sync::mpsc::channel(10);
+    let maturity = BlockHeight::new(5);
+    let tx = universe.build_script_transaction_with_maturity(None, None, 10, maturity);
+    let tx_id = tx.id(&Default::default());
+
+    let service =
+        universe.build_service(None, Some(MockImporter::with_block_provider(receiver)));
+    service.start_and_await().await.unwrap();
+
+    // Given
+    service.shared.insert(tx).await.unwrap();
+    universe
+        .await_expected_tx_statuses_submitted(vec![tx_id])
+        .await;
+    let found = service.shared.find(vec![tx_id]).await.unwrap();
+    assert!(found[0].is_none(), "Immature tx should not be executable");
+
+    // When
+    let height_before_maturity = BlockHeight::new(4);
+    sender
+        .send(Arc::new(
+            ImportResult::new_from_local(
+                block_at(height_before_maturity),
+                vec![],
+                vec![],
+            )
+            .wrap(),
+        ))
+        .await
+        .unwrap();
+    universe
+        .await_expected_tx_statuses_submitted(vec![tx_id])
+        .await;
+
+    // Then
+    let found = service.shared.find(vec![tx_id]).await.unwrap();
+    assert!(found[0].is_some(), "Matured tx should be in the pool");
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn insert__tx_with_maturity_beyond_lookahead_is_rejected() {
+    let mut universe = TestPoolUniverse::default();
+    universe.config.max_maturity_lookahead = 3;
+
+    // Given
+    let maturity = BlockHeight::new(5);
+    let tx = universe.build_script_transaction_with_maturity(None, None, 10, maturity);
+
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    // When
+    let result = service.shared.insert(tx).await;
+
+    // Then
+    let err = result.unwrap_err();
+    assert!(
+        matches!(err, Error::ConsensusValidity(_)),
+        "Expected maturity error, got {err:?}"
+    );
+    assert!(err.to_string().contains("TransactionMaturity"), "{err}");
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn insert__future_tx_is_rejected_when_future_pool_is_full() {
+    let mut universe = TestPoolUniverse::default();
+    universe.config.max_future_pool_size_percentage = 0;
+
+    // Given
+    let maturity = BlockHeight::new(5);
+    let tx = universe.build_script_transaction_with_maturity(None, None, 10, maturity);
+    let tx_id = tx.id(&Default::default());
+
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    // When
+    let result = service.shared.insert(tx).await;
+
+    // Then
+    assert!(matches!(result, Err(Error::NotInsertedLimitHit)));
+    universe
+        .await_expected_tx_statuses_squeeze_out(vec![tx_id])
+        .await;
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn insert__future_tx_spending_the_same_coin_as_other_future_tx_is_rejected() {
+    let mut universe = TestPoolUniverse::default();
+    let maturity = BlockHeight::new(5);
+    let (_, coin) = universe.setup_coin();
+    let tx1 = universe.build_script_transaction_with_maturity(
+        Some(vec![coin.clone()]),
+        None,
+        10,
+        maturity,
+    );
+    let tx2 = universe.build_script_transaction_with_maturity(
+        Some(vec![coin]),
+        None,
+        20,
+        maturity,
+    );
+
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    // Given
+    service.shared.insert(tx1).await.unwrap();
+
+    // When
+    let result = service.shared.insert(tx2).await;
+
+    // Then
+    let err = result.unwrap_err();
+    assert!(matches!(err, Error::Collided(_)), "{err:?}");
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn insert__future_tx_is_checked_by_admission_filter() {
+    let mut universe = TestPoolUniverse::default();
+    universe.admission_filter =
+        MockAdmissionFilter::new(|_, _| Err("The pool is busy".to_string()));
+
+    // Given
+    let maturity = BlockHeight::new(5);
+    let tx = universe.build_script_transaction_with_maturity(None, None, 10, maturity);
+
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    // When
+    let result = service.shared.insert(tx).await;
+
+    // Then
+    let err = result.unwrap_err();
+    assert!(
+        matches!(err, Error::NotAdmitted(ref reason) if reason == "The pool is busy"),
+        "{err:?}"
+    );
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn insert__future_txs_count_against_owner_limits() {
+    let mut universe = TestPoolUniverse::default();
+    universe.config.per_owner_limits.max_txs = 1;
+    let maturity = BlockHeight::new(5);
+    let code: Vec<u8> = vec![op::ret(1)].into_iter().collect();
+    let (_, coin1) = universe.setup_coin_with_predicate(code.clone());
+    let (_, coin2) = universe.setup_coin_with_predicate(code);
+    let tx1 = universe.build_script_transaction_with_maturity(
+        Some(vec![coin1]),
+        None,
+        10,
+        maturity,
+    );
+    let tx2 = universe.build_script_transaction_with_maturity(
+        Some(vec![coin2]),
+        None,
+        10,
+        maturity,
+    );
+
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    // Given
+    service.shared.insert(tx1).await.unwrap();
+
+    // When
+    let result = service.shared.insert(tx2).await;
+
+    // Then
+    let err = result.unwrap_err();
+    assert!(matches!(err, Error::NotInsertedOwnerLimitHit(_)), "{err:?}");
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn insert__future_tx_is_removed_after_ttl() {
+    let mut universe = TestPoolUniverse::default();
+    universe.config.max_txs_ttl = Duration::from_millis(100);
+    universe.config.pending_pool_tx_ttl = Duration::from_millis(50);
+
+    // Given
+    let maturity = BlockHeight::new(5);
+    let tx = universe.build_script_transaction_with_maturity(None, None, 10, maturity);
+    let tx_id = tx.id(&Default::default());
+
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    // When
+    service.shared.insert(tx).await.unwrap();
+
+    // Then
+    universe
+        .await_expected_tx_statuses_submitted(vec![tx_id])
+        .await;
+    universe
+        .await_expected_tx_statuses_squeeze_out(vec![tx_id])
+        .await;
+
+    service.stop_and_await().await.unwrap();
+}
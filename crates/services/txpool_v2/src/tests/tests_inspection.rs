@@ -0,0 +1,302 @@
+use fuel_core_services::Service;
+use fuel_core_storage::iter::IterDirection;
+use fuel_core_types::{
+    fuel_asm::op,
+    fuel_tx::{
+        Input,
+        UniqueIdentifier,
+        UtxoId,
+    },
+    fuel_types::BlockHeight,
+};
+use std::time::Duration;
+
+use crate::{
+    inspection::{
+        InspectionFilter,
+        NotExecutableReason,
+    },
+    tests::universe::TestPoolUniverse,
+};
+
+#[tokio::test]
+async fn inspect__returns_txs_sorted_by_tip_ratio() {
+    let mut universe = TestPoolUniverse::default();
+    let tx1 = universe.build_script_transaction(None, None, 10);
+    let tx2 = universe.build_script_transaction(None, None, 30);
+    let tx3 = universe.build_script_transaction(None, None, 20);
+    let ids = vec![
+        tx1.id(&Default::default()),
+        tx2.id(&Default::default()),
+        tx3.id(&Default::default()),
+    ];
+
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    // Given
+    service.shared.try_insert(vec![tx1, tx2, tx3]).unwrap();
+    universe
+        .await_expected_tx_statuses_submitted(ids.clone())
+        .await;
+
+    // When
+    let inspected = service
+        .shared
+        .inspect(InspectionFilter::new(2))
+        .await
+        .unwrap();
+
+    // Then
+    let inspected_ids: Vec<_> = inspected.iter().map(|tx| tx.tx.id()).collect();
+    assert_eq!(inspected_ids, vec![ids[1], ids[2]]);
+    assert!(inspected.iter().all(|tx| tx.is_executable()));
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn inspect__dependent_tx_waits_for_its_dependency() {
+    let mut universe = TestPoolUniverse::default();
+    let (output, unset_input) = universe.create_output_and_input();
+    let parent = universe.build_script_transaction(None, Some(vec![output]), 10);
+    let parent_id = parent.id(&Default::default());
+    let input = unset_input.into_input(UtxoId::new(parent_id, 0));
+    let child = universe.build_script_transaction(Some(vec![input]), None, 20);
+    let child_id = child.id(&Default::default());
+
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    // Given
+    service.shared.insert(parent).await.unwrap();
+    service.shared.insert(child).await.unwrap();
+
+    // When
+    let executable = service
+        .shared
+        .inspect(InspectionFilter {
+            executable: Some(true),
+            ..InspectionFilter::new(10)
+        })
+        .await
+        .unwrap();
+    let not_executable = service
+        .shared
+        .inspect(InspectionFilter {
+            executable: Some(false),
+            ..InspectionFilter::new(10)
+        })
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(executable.len(), 1);
+    assert_eq!(executable[0].tx.id(), parent_id);
+    assert_eq!(executable[0].dependents, 1);
+    assert_eq!(not_executable.len(), 1);
+    assert_eq!(not_executable[0].tx.id(), child_id);
+    assert_eq!(not_executable[0].dependents, 0);
+    assert_eq!(
+        not_executable[0].not_executable_reason,
+        Some(NotExecutableReason::WaitsForDependencies)
+    );
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn inspect__returns_pending_and_future_txs_with_reasons() {
+    let mut universe = TestPoolUniverse::default();
+    universe.config.utxo_validation = true;
+    let (_, unset_input) = universe.create_output_and_input();
+    let missing_utxo_id = UtxoId::new([1; 32].into(), 0);
+    let input = unset_input.into_input(missing_utxo_id);
+    let pending = universe.build_script_transaction(Some(vec![input]), None, 10);
+    let pending_id = pending.id(&Default::default());
+    let maturity = BlockHeight::new(5);
+    let future =
+        universe.build_script_transaction_with_maturity(None, None, 20, maturity);
+    let future_id = future.id(&Default::default());
+
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    // Given
+    service.shared.insert(future).await.unwrap();
+    service.shared.try_insert(vec![pending]).unwrap();
+
+    // When
+    // The pending transaction doesn't have a status, so we wait until it is in the pool.
+    let inspected = tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            let inspected = service
+                .shared
+                .inspect(InspectionFilter::new(10))
+                .await
+                .unwrap();
+            if inspected.len() == 2 {
+                break inspected
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .unwrap();
+
+    // Then
+    assert_eq!(inspected.len(), 2);
+    assert_eq!(inspected[0].tx.id(), future_id);
+    assert_eq!(
+        inspected[0].not_executable_reason,
+        Some(NotExecutableReason::Immature(maturity))
+    );
+    assert_eq!(inspected[1].tx.id(), pending_id);
+    assert_eq!(
+        inspected[1].not_executable_reason,
+        Some(NotExecutableReason::MissingInputs {
+            utxos: vec![missing_utxo_id],
+            contracts: vec![],
+        })
+    );
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn inspect__filters_txs_by_owner_and_contract() {
+    let mut universe = TestPoolUniverse::default();
+    let code: Vec<u8> = vec![op::ret(1)].into_iter().collect();
+    let owner = Input::predicate_owner(&code);
+    let (_, owner_coin) = universe.setup_coin_with_predicate(code);
+    let owner_tx = universe.build_script_transaction(Some(vec![owner_coin]), None, 10);
+    let owner_tx_id = owner_tx.id(&Default::default());
+    let (create_tx, contract_id) =
+        universe.build_create_contract_transaction(vec![1, 2, 3]);
+    let create_tx_id = create_tx.id(&Default::default());
+    let other_tx = universe.build_script_transaction(None, None, 10);
+    let ids = vec![owner_tx_id, create_tx_id, other_tx.id(&Default::default())];
+
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    // Given
+    service
+        .shared
+        .try_insert(vec![owner_tx, create_tx, other_tx])
+        .unwrap();
+    universe.await_expected_tx_statuses_submitted(ids).await;
+
+    // When
+    let by_owner = service
+        .shared
+        .inspect(InspectionFilter {
+            owner: Some(owner),
+            ..InspectionFilter::new(10)
+        })
+        .await
+        .unwrap();
+    let by_contract = service
+        .shared
+        .inspect(InspectionFilter {
+            contract: Some(contract_id),
+            ..InspectionFilter::new(10)
+        })
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(by_owner.len(), 1);
+    assert_eq!(by_owner[0].tx.id(), owner_tx_id);
+    assert_eq!(by_contract.len(), 1);
+    assert_eq!(by_contract[0].tx.id(), create_tx_id);
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn inspect__paginates_from_the_cursor_in_both_directions() {
+    let mut universe = TestPoolUniverse::default();
+    let txs: Vec<_> = [40, 30, 20, 10]
+        .into_iter()
+        .map(|tip| universe.build_script_transaction(None, None, tip))
+        .collect();
+    let ids: Vec<_> = txs.iter().map(|tx| tx.id(&Default::default())).collect();
+
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+    service.shared.try_insert(txs).unwrap();
+    universe
+        .await_expected_tx_statuses_submitted(ids.clone())
+        .await;
+
+    // Given
+    let first_page = service
+        .shared
+        .inspect(InspectionFilter::new(2))
+        .await
+        .unwrap();
+    let cursor = first_page.last().unwrap().cursor();
+
+    // When
+    let next_page = service
+        .shared
+        .inspect(InspectionFilter {
+            start: Some(cursor),
+            ..InspectionFilter::new(2)
+        })
+        .await
+        .unwrap();
+    let previous_page = service
+        .shared
+        .inspect(InspectionFilter {
+            start: Some(cursor),
+            direction: IterDirection::Reverse,
+            ..InspectionFilter::new(2)
+        })
+        .await
+        .unwrap();
+
+    // Then
+    let next_ids: Vec<_> = next_page.iter().map(|tx| tx.tx.id()).collect();
+    assert_eq!(next_ids, vec![ids[1], ids[2]]);
+    let previous_ids: Vec<_> = previous_page.iter().map(|tx| tx.tx.id()).collect();
+    assert_eq!(previous_ids, vec![ids[1], ids[0]]);
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn inspect__reports_bundle_members_through_the_head() {
+    let mut universe = TestPoolUniverse::default();
+    let (_, coin_input) = universe.setup_coin();
+    let head = universe.build_script_transaction(None, None, 10);
+    let member = universe.build_script_transaction(Some(vec![coin_input]), None, 20);
+    let head_id = head.id(&Default::default());
+    let member_id = member.id(&Default::default());
+
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    // Given
+    service
+        .shared
+        .insert_bundle(vec![head, member])
+        .await
+        .unwrap();
+
+    // When
+    let inspected = service
+        .shared
+        .inspect(InspectionFilter::new(10))
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(inspected.len(), 1);
+    assert_eq!(inspected[0].tx.id(), head_id);
+    let bundle: Vec<_> = inspected[0].bundle.iter().map(|tx| tx.id()).collect();
+    assert_eq!(bundle, vec![member_id]);
+
+    service.stop_and_await().await.unwrap();
+}
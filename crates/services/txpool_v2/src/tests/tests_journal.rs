@@ -0,0 +1,309 @@
+use fuel_core_services::Service as ServiceTrait;
+use fuel_core_types::{
+    fuel_tx::{
+        Transaction,
+        UniqueIdentifier,
+        UtxoId,
+    },
+    fuel_types::BlockHeight,
+    services::transaction_status::TransactionStatus,
+};
+use std::time::{
+    Duration,
+    SystemTime,
+};
+
+use crate::{
+    config::{
+        Config,
+        JournalConfig,
+    },
+    service::journal::{
+        JournalEntry,
+        decode,
+        write,
+    },
+    tests::universe::TestPoolUniverse,
+};
+
+fn journal_config(dir: &tempfile::TempDir) -> JournalConfig {
+    JournalConfig {
+        path: dir.path().join("txpool.journal"),
+        rotation_interval: Duration::from_secs(60),
+    }
+}
+
+fn journal_entry(tx: &Transaction, submitted_at: SystemTime) -> JournalEntry {
+    JournalEntry {
+        submitted_at,
+        tx_id: tx.id(&Default::default()),
+        tx: tx.clone(),
+    }
+}
+
+fn universe_with_journal(dir: &tempfile::TempDir) -> TestPoolUniverse {
+    TestPoolUniverse::default().config(Config {
+        journal: Some(journal_config(dir)),
+        ..Default::default()
+    })
+}
+
+#[tokio::test]
+async fn journal__transactions_are_replayed_after_restart() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut universe = universe_with_journal(&dir);
+    let tx1 = universe.build_script_transaction(None, None, 10);
+    let tx2 = universe.build_script_transaction(None, None, 20);
+    let ids = vec![tx1.id(&Default::default()), tx2.id(&Default::default())];
+
+    // Given
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+    service.shared.try_insert(vec![tx1, tx2]).unwrap();
+    universe
+        .await_expected_tx_statuses_submitted(ids.clone())
+        .await;
+    service.stop_and_await().await.unwrap();
+
+    // When
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+    universe
+        .await_expected_tx_statuses_submitted(ids.clone())
+        .await;
+
+    // Then
+    let out = service.shared.find(ids).await.unwrap();
+    assert!(out.iter().all(Option::is_some), "{out:?}");
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn journal__expired_and_invalid_transactions_are_not_replayed() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut universe =
+        universe_with_journal(&dir).with_block_height(BlockHeight::new(10));
+    let valid_tx = universe.build_script_transaction(None, None, 10);
+    let expired_tx = universe.build_script_transaction(None, None, 20);
+    // The transaction expires at the height lower than the current height of the pool.
+    let invalid_tx = universe.build_script_transaction_with_expiration(
+        None,
+        None,
+        30,
+        BlockHeight::new(5),
+    );
+
+    // Given
+    let now = SystemTime::now();
+    let expired_at = now
+        .checked_sub(universe.config.max_txs_ttl + Duration::from_secs(1))
+        .unwrap();
+    let entries = [
+        journal_entry(&valid_tx, now),
+        journal_entry(&expired_tx, expired_at),
+        journal_entry(&invalid_tx, now),
+    ];
+    write(&journal_config(&dir).path, &entries).unwrap();
+
+    // When
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+    let valid_tx_id = valid_tx.id(&Default::default());
+    let invalid_tx_id = invalid_tx.id(&Default::default());
+    universe
+        .await_expected_tx_statuses(vec![valid_tx_id, invalid_tx_id], |id, status| {
+            match status {
+                TransactionStatus::Submitted { .. } => id == valid_tx_id,
+                TransactionStatus::SqueezedOut { .. } => id == invalid_tx_id,
+                _ => false,
+            }
+        })
+        .await
+        .unwrap();
+
+    // Then
+    let out = service
+        .shared
+        .find(vec![
+            valid_tx_id,
+            expired_tx.id(&Default::default()),
+            invalid_tx_id,
+        ])
+        .await
+        .unwrap();
+    assert!(out[0].is_some(), "Valid tx should be replayed: {out:?}");
+    assert!(out[1].is_none(), "Expired tx should be dropped: {out:?}");
+    assert!(out[2].is_none(), "Invalid tx should be dropped: {out:?}");
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn journal__accepted_transactions_are_appended_including_pending_ones() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut universe = TestPoolUniverse::default().config(Config {
+        journal: Some(journal_config(&dir)),
+        utxo_validation: true,
+        ..Default::default()
+    });
+    let executable_tx = universe.build_script_transaction(None, None, 10);
+    let (_, unset_input) = universe.create_output_and_input();
+    let unknown_input = unset_input.into_input(UtxoId::new([123; 32].into(), 0));
+    let pending_tx =
+        universe.build_script_transaction(Some(vec![unknown_input]), None, 20);
+    let ids = vec![
+        executable_tx.id(&Default::default()),
+        pending_tx.id(&Default::default()),
+    ];
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    // When
+    service
+        .shared
+        .try_insert(vec![executable_tx, pending_tx])
+        .unwrap();
+
+    // Then
+    let path = journal_config(&dir).path;
+    let journaled_ids = tokio::time::timeout(Duration::from_secs(3), async {
+        loop {
+            let bytes = std::fs::read(&path).unwrap_or_default();
+            let mut journaled_ids = decode(&bytes)
+                .into_iter()
+                .map(|entry| entry.tx_id)
+                .collect::<Vec<_>>();
+            if journaled_ids.len() == ids.len() {
+                journaled_ids.sort();
+                break journaled_ids
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("Transactions should be appended without waiting for the rotation");
+    let mut expected_ids = ids;
+    expected_ids.sort();
+    assert_eq!(journaled_ids, expected_ids);
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn journal__bundles_are_not_journaled() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut universe = universe_with_journal(&dir);
+    let tx1 = universe.build_script_transaction(None, None, 10);
+    let tx2 = universe.build_script_transaction(None, None, 20);
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    // When
+    service.shared.insert_bundle(vec![tx1, tx2]).await.unwrap();
+    service.stop_and_await().await.unwrap();
+
+    // Then
+    let bytes = std::fs::read(journal_config(&dir).path).unwrap_or_default();
+    assert_eq!(decode(&bytes), vec![]);
+}
+
+#[tokio::test]
+async fn journal__replayed_transactions_keep_original_submission_time() {
+    const TTL: Duration = Duration::from_secs(10);
+    let dir = tempfile::tempdir().unwrap();
+    let mut universe = TestPoolUniverse::default().config(Config {
+        journal: Some(journal_config(&dir)),
+        max_txs_ttl: TTL,
+        ttl_check_interval: Duration::from_millis(100),
+        ..Default::default()
+    });
+    let tx = universe.build_script_transaction(None, None, 10);
+    let tx_id = tx.id(&Default::default());
+
+    // Given
+    let submitted_at = SystemTime::now()
+        .checked_sub(TTL - Duration::from_secs(1))
+        .unwrap();
+    write(
+        &journal_config(&dir).path,
+        &[journal_entry(&tx, submitted_at)],
+    )
+    .unwrap();
+
+    // When
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+    universe
+        .await_expected_tx_statuses_submitted(vec![tx_id])
+        .await;
+
+    // Then
+    universe
+        .await_expected_tx_statuses_squeeze_out(vec![tx_id])
+        .await;
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn journal__transactions_appended_after_incomplete_record_are_replayed() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut universe = universe_with_journal(&dir);
+    let replayed_tx = universe.build_script_transaction(None, None, 10);
+    let appended_tx = universe.build_script_transaction(None, None, 20);
+    let ids = vec![
+        replayed_tx.id(&Default::default()),
+        appended_tx.id(&Default::default()),
+    ];
+
+    // Given
+    let path = journal_config(&dir).path;
+    write(&path, &[journal_entry(&replayed_tx, SystemTime::now())]).unwrap();
+    let mut bytes = std::fs::read(&path).unwrap();
+    bytes.extend_from_slice(&[1; 10]);
+    std::fs::write(&path, bytes).unwrap();
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+    universe
+        .await_expected_tx_statuses_submitted(vec![ids[0]])
+        .await;
+    service.shared.try_insert(vec![appended_tx]).unwrap();
+    universe
+        .await_expected_tx_statuses_submitted(vec![ids[1]])
+        .await;
+    service.stop_and_await().await.unwrap();
+
+    // When
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+    universe
+        .await_expected_tx_statuses_submitted(ids.clone())
+        .await;
+
+    // Then
+    let out = service.shared.find(ids).await.unwrap();
+    assert!(out.iter().all(Option::is_some), "{out:?}");
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn journal__incomplete_record_is_ignored() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut universe = TestPoolUniverse::default();
+    let tx = universe.build_script_transaction(None, None, 10);
+    let config = journal_config(&dir);
+    let entry = journal_entry(&tx, SystemTime::UNIX_EPOCH);
+    write(&config.path, &[entry.clone(), entry.clone()]).unwrap();
+
+    // Given
+    let mut bytes = std::fs::read(&config.path).unwrap();
+    bytes.truncate(bytes.len().saturating_sub(1));
+
+    // When
+    let entries = decode(&bytes);
+
+    // Then
+    assert_eq!(entries, vec![entry]);
+}
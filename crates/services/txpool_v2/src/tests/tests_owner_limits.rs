@@ -0,0 +1,122 @@
+use crate::{
+    config::{
+        Config,
+        PoolLimits,
+    },
+    error::Error,
+    tests::universe::TestPoolUniverse,
+};
+use fuel_core_types::{
+    fuel_asm::op,
+    fuel_tx::{
+        Address,
+        Input,
+        Transaction,
+    },
+};
+
+fn per_owner_max_txs(max_txs: usize) -> Config {
+    let default = Config::default();
+    Config {
+        per_owner_limits: PoolLimits {
+            max_txs,
+            ..default.per_owner_limits
+        },
+        ..default
+    }
+}
+
+fn owner_predicate() -> (Vec<u8>, Address) {
+    let code: Vec<u8> = vec![op::ret(1)].into_iter().collect();
+    let owner = Input::predicate_owner(&code);
+    (code, owner)
+}
+
+fn owner_transaction(
+    universe: &mut TestPoolUniverse,
+    code: &[u8],
+    tip: u64,
+) -> Transaction {
+    let (_, coin) = universe.setup_coin_with_predicate(code.to_vec());
+    universe.build_script_transaction(Some(vec![coin]), None, tip)
+}
+
+#[test]
+fn insert__owner_within_limits_succeeds() {
+    let mut universe = TestPoolUniverse::default().config(per_owner_max_txs(2));
+    universe.build_pool();
+
+    // Given
+    let (code, _) = owner_predicate();
+    let tx1 = owner_transaction(&mut universe, &code, 10);
+    let tx2 = owner_transaction(&mut universe, &code, 10);
+
+    // When
+    let tx1 = universe.verify_and_insert(tx1).unwrap();
+    let tx2 = universe.verify_and_insert(tx2).unwrap();
+
+    // Then
+    universe.assert_pool_integrity(&[tx1, tx2]);
+}
+
+#[test]
+fn insert__owner_over_limit_evicts_less_worth_transaction_of_the_owner() {
+    let mut universe = TestPoolUniverse::default().config(per_owner_max_txs(2));
+    universe.build_pool();
+
+    // Given
+    let (code, _) = owner_predicate();
+    let other_tx = universe.build_script_transaction(None, None, 1);
+    let tx1 = owner_transaction(&mut universe, &code, 10);
+    let tx2 = owner_transaction(&mut universe, &code, 20);
+    let tx3 = owner_transaction(&mut universe, &code, 30);
+    let other_tx = universe.verify_and_insert(other_tx).unwrap();
+    universe.verify_and_insert(tx1).unwrap();
+    let tx2 = universe.verify_and_insert(tx2).unwrap();
+
+    // When
+    let tx3 = universe.verify_and_insert(tx3).unwrap();
+
+    // Then
+    universe.assert_pool_integrity(&[other_tx, tx2, tx3]);
+}
+
+#[test]
+fn insert__owner_over_limit_with_more_worth_transactions_returns_error() {
+    let mut universe = TestPoolUniverse::default().config(per_owner_max_txs(2));
+    universe.build_pool();
+
+    // Given
+    let (code, owner) = owner_predicate();
+    let tx1 = owner_transaction(&mut universe, &code, 20);
+    let tx2 = owner_transaction(&mut universe, &code, 20);
+    let tx3 = owner_transaction(&mut universe, &code, 10);
+    let tx1 = universe.verify_and_insert(tx1).unwrap();
+    let tx2 = universe.verify_and_insert(tx2).unwrap();
+
+    // When
+    let result = universe.verify_and_insert(tx3);
+
+    // Then
+    let err = result.unwrap_err();
+    assert!(matches!(err, Error::NotInsertedOwnerLimitHit(o) if o == owner));
+    universe.assert_pool_integrity(&[tx1, tx2]);
+}
+
+#[test]
+fn insert__owner_over_limit_does_not_affect_other_owners() {
+    let mut universe = TestPoolUniverse::default().config(per_owner_max_txs(1));
+    universe.build_pool();
+
+    // Given
+    let (code, _) = owner_predicate();
+    let tx1 = owner_transaction(&mut universe, &code, 10);
+    let tx1 = universe.verify_and_insert(tx1).unwrap();
+    let other_tx = universe.build_script_transaction(None, None, 1);
+
+    // When
+    let other_tx = universe.verify_and_insert(other_tx).unwrap();
+
+    // Then
+    universe.assert_pool_integrity(&[tx1, other_tx]);
+}
@@ -0,0 +1,197 @@
+use crate::{
+    config::ReplaceableInput,
+    error::{
+        CollisionReason,
+        Error,
+    },
+    tests::universe::TestPoolUniverse,
+};
+use fuel_core_services::Service as ServiceTrait;
+use fuel_core_types::{
+    fuel_tx::{
+        UniqueIdentifier,
+        UtxoId,
+    },
+    fuel_types::ChainId,
+};
+
+#[test]
+fn insert__replacement_with_insufficient_tip_bump_returns_error() {
+    let mut universe = TestPoolUniverse::default();
+    universe.config.replacement_policy.min_tip_bump_percentage = 50;
+    universe.build_pool();
+
+    // Given
+    let (_, coin_input) = universe.setup_coin();
+    let tx1 = universe.build_script_transaction(Some(vec![coin_input.clone()]), None, 10);
+    let tx2 = universe.build_script_transaction(Some(vec![coin_input]), None, 14);
+    let tx1 = universe.verify_and_insert(tx1).unwrap();
+
+    // When
+    let result = universe.verify_and_insert(tx2);
+
+    // Then
+    let err = result.unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Collided(CollisionReason::InsufficientTipBump {
+            min_tip_bump_percentage: 50
+        })
+    ));
+    universe.assert_pool_integrity(&[tx1]);
+}
+
+#[test]
+fn insert__replacement_with_sufficient_tip_bump_succeeds() {
+    let mut universe = TestPoolUniverse::default();
+    universe.config.replacement_policy.min_tip_bump_percentage = 50;
+    universe.build_pool();
+
+    // Given
+    let (_, coin_input) = universe.setup_coin();
+    let tx1 = universe.build_script_transaction(Some(vec![coin_input.clone()]), None, 10);
+    let tx2 = universe.build_script_transaction(Some(vec![coin_input]), None, 15);
+    universe.verify_and_insert(tx1).unwrap();
+
+    // When
+    let result = universe.verify_and_insert(tx2);
+
+    // Then
+    let tx2 = result.unwrap();
+    universe.assert_pool_integrity(&[tx2]);
+}
+
+#[test]
+fn insert__replacement_evicting_too_many_transactions_returns_error() {
+    let mut universe = TestPoolUniverse::default();
+    universe.config.replacement_policy.max_evicted_transactions = 1;
+    universe.build_pool();
+
+    // Given
+    let (_, coin_input) = universe.setup_coin();
+    let (output, unset_input) = universe.create_output_and_input();
+    let tx1 = universe.build_script_transaction(
+        Some(vec![coin_input.clone()]),
+        Some(vec![output]),
+        0,
+    );
+    let input = unset_input.into_input(UtxoId::new(tx1.id(&ChainId::default()), 0));
+    let tx2 = universe.build_script_transaction(Some(vec![input]), None, 0);
+    let tx3 = universe.build_script_transaction(Some(vec![coin_input]), None, 100);
+    let tx1 = universe.verify_and_insert(tx1).unwrap();
+    let tx2 = universe.verify_and_insert(tx2).unwrap();
+
+    // When
+    let result = universe.verify_and_insert(tx3);
+
+    // Then
+    let err = result.unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Collided(CollisionReason::TooManyEvictions { evicted: 2, max: 1 })
+    ));
+    universe.assert_pool_integrity(&[tx1, tx2]);
+}
+
+#[test]
+fn insert__replacement_on_non_replaceable_input_returns_error() {
+    let mut universe = TestPoolUniverse::default();
+    universe
+        .config
+        .replacement_policy
+        .non_replaceable_inputs
+        .insert(ReplaceableInput::Coin);
+    universe.build_pool();
+
+    // Given
+    let (_, coin_input) = universe.setup_coin();
+    let tx1 = universe.build_script_transaction(Some(vec![coin_input.clone()]), None, 10);
+    let tx2 = universe.build_script_transaction(Some(vec![coin_input]), None, 1000);
+    let tx1 = universe.verify_and_insert(tx1).unwrap();
+
+    // When
+    let result = universe.verify_and_insert(tx2);
+
+    // Then
+    let err = result.unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Collided(CollisionReason::ReplacementDisabled(ReplaceableInput::Coin))
+    ));
+    universe.assert_pool_integrity(&[tx1]);
+}
+
+#[test]
+fn insert__replacement_counts_bundle_members_as_evicted() {
+    let mut universe = TestPoolUniverse::default();
+    universe.config.replacement_policy.max_evicted_transactions = 1;
+    universe.build_pool();
+
+    // Given
+    let (_, coin_input) = universe.setup_coin();
+    let tx1 = universe.build_script_transaction(None, None, 0);
+    let tx2 = universe.build_script_transaction(Some(vec![coin_input.clone()]), None, 0);
+    let bundle = universe.verify_and_insert_bundle(vec![tx1, tx2]).unwrap();
+    let tx3 = universe.build_script_transaction(Some(vec![coin_input]), None, 100);
+
+    // When
+    let result = universe.verify_and_insert(tx3);
+
+    // Then
+    let err = result.unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Collided(CollisionReason::TooManyEvictions { evicted: 2, max: 1 })
+    ));
+    universe.assert_pool_integrity(&bundle);
+}
+
+#[tokio::test]
+async fn insert__replacement_of_non_replaceable_transaction_returns_error() {
+    let mut universe = TestPoolUniverse::default();
+
+    // Given
+    let (_, coin_input) = universe.setup_coin();
+    let tx1 = universe.build_script_transaction(Some(vec![coin_input.clone()]), None, 10);
+    let tx2 = universe.build_script_transaction(Some(vec![coin_input]), None, 1000);
+    let tx1_id = tx1.id(&ChainId::default());
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+    service.shared.insert_opt(tx1, false).await.unwrap();
+
+    // When
+    let result = service.shared.insert(tx2).await;
+
+    // Then
+    let err = result.unwrap_err();
+    assert!(
+        matches!(
+            err,
+            Error::Collided(CollisionReason::NonReplaceable(id)) if id == tx1_id
+        ),
+        "{err:?}"
+    );
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn insert__replacement_of_replaceable_transaction_succeeds() {
+    let mut universe = TestPoolUniverse::default();
+
+    // Given
+    let (_, coin_input) = universe.setup_coin();
+    let tx1 = universe.build_script_transaction(Some(vec![coin_input.clone()]), None, 10);
+    let tx2 = universe.build_script_transaction(Some(vec![coin_input]), None, 1000);
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+    service.shared.insert_opt(tx1, true).await.unwrap();
+
+    // When
+    let result = service.shared.insert(tx2).await;
+
+    // Then
+    assert!(result.is_ok(), "{result:?}");
+
+    service.stop_and_await().await.unwrap();
+}
@@ -0,0 +1,264 @@
+use std::collections::HashSet;
+
+use crate::{
+    config::{
+        Config,
+        LanesConfig,
+        SelectionPolicy,
+    },
+    selection_algorithms::Constraints,
+    tests::universe::TestPoolUniverse,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        Address,
+        Transaction,
+        TransactionBuilder,
+        TxId,
+        field::Inputs,
+    },
+    services::txpool::ArcPoolTx,
+};
+use proptest::prelude::*;
+
+fn constraints(max_gas: u64) -> Constraints {
+    Constraints {
+        minimal_gas_price: 0,
+        max_gas,
+        maximum_txs: u16::MAX,
+        maximum_block_size: u32::MAX,
+        excluded_contracts: Default::default(),
+    }
+}
+
+fn script_transaction(
+    universe: &mut TestPoolUniverse,
+    tip: u64,
+    gas_limit: u64,
+) -> Transaction {
+    let (_, gas_coin) = universe.setup_coin();
+    TransactionBuilder::script(vec![], vec![])
+        .tip(tip)
+        .max_fee_limit(tip)
+        .script_gas_limit(gas_limit)
+        .add_input(gas_coin)
+        .finalize_as_transaction()
+}
+
+fn owner_of(tx: &Transaction) -> Address {
+    *tx.as_script()
+        .expect("Only scripts are used in the tests")
+        .inputs()[0]
+        .input_owner()
+        .expect("The gas coin has an owner")
+}
+
+fn remaining_txs(inserted: &[ArcPoolTx], extracted: &[ArcPoolTx]) -> Vec<ArcPoolTx> {
+    let extracted: HashSet<TxId> = extracted.iter().map(|tx| tx.id()).collect();
+    inserted
+        .iter()
+        .filter(|tx| !extracted.contains(&tx.id()))
+        .cloned()
+        .collect()
+}
+
+fn fifo_config() -> Config {
+    Config {
+        selection_policy: SelectionPolicy::Fifo,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn fifo__older_transaction_with_lower_tip_is_selected_first() {
+    let mut universe = TestPoolUniverse::default().config(fifo_config());
+    universe.build_pool();
+
+    // Given
+    let tx1 = script_transaction(&mut universe, 1, 1000);
+    let tx2 = script_transaction(&mut universe, 1000, 1000);
+    let tx1 = universe.verify_and_insert(tx1).unwrap();
+    let tx2 = universe.verify_and_insert(tx2).unwrap();
+
+    // When
+    let txs = universe
+        .get_pool()
+        .write()
+        .extract_transactions_for_block(constraints(u64::MAX));
+
+    // Then
+    assert_eq!(txs.len(), 2, "Should have 2 txs");
+    assert_eq!(txs[0].id(), tx1.id(), "First should be tx1");
+    assert_eq!(txs[1].id(), tx2.id(), "Second should be tx2");
+    universe.assert_pool_integrity(&[]);
+}
+
+#[test]
+fn lanes__priority_transaction_with_lower_tip_is_selected_first() {
+    let mut universe = TestPoolUniverse::default();
+
+    // Given
+    let regular_tx = script_transaction(&mut universe, 1000, 1000);
+    let priority_tx = script_transaction(&mut universe, 1, 1000);
+    let mut universe = universe.config(Config {
+        selection_policy: SelectionPolicy::Lanes(LanesConfig {
+            priority_addresses: [owner_of(&priority_tx)].into_iter().collect(),
+            reserved_gas_percentage: 100,
+        }),
+        ..Default::default()
+    });
+    universe.build_pool();
+    let regular_tx = universe.verify_and_insert(regular_tx).unwrap();
+    let priority_tx = universe.verify_and_insert(priority_tx).unwrap();
+
+    // When
+    let max_gas = priority_tx.max_gas();
+    let txs = universe
+        .get_pool()
+        .write()
+        .extract_transactions_for_block(constraints(max_gas));
+
+    // Then
+    assert_eq!(txs.len(), 1, "Should have 1 tx");
+    assert_eq!(txs[0].id(), priority_tx.id(), "Should be the priority tx");
+    universe.assert_pool_integrity(&[regular_tx]);
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(20))]
+
+    #[test]
+    fn fifo__transactions_are_selected_in_arrival_order(
+        tips in prop::collection::vec(0u64..1000, 1..20),
+    ) {
+        let mut universe = TestPoolUniverse::default().config(fifo_config());
+        universe.build_pool();
+
+        // Given
+        let mut inserted = vec![];
+        for tip in tips {
+            let tx = script_transaction(&mut universe, tip, 1000);
+            inserted.push(universe.verify_and_insert(tx).unwrap());
+        }
+
+        // When
+        let txs = universe
+            .get_pool()
+            .write()
+            .extract_transactions_for_block(constraints(u64::MAX));
+
+        // Then
+        let expected: Vec<_> = inserted.iter().map(|tx| tx.id()).collect();
+        let actual: Vec<_> = txs.iter().map(|tx| tx.id()).collect();
+        prop_assert_eq!(actual, expected);
+        universe.assert_pool_integrity(&[]);
+    }
+
+    #[test]
+    fn fifo__newer_transactions_never_overtake_older_ones(
+        txs in prop::collection::vec((0u64..1000, 1u64..100_000), 1..20),
+        max_gas in 0u64..1_000_000,
+    ) {
+        let mut universe = TestPoolUniverse::default().config(fifo_config());
+        universe.build_pool();
+
+        // Given
+        let mut inserted = vec![];
+        for (tip, gas_limit) in txs {
+            let tx = script_transaction(&mut universe, tip, gas_limit);
+            inserted.push(universe.verify_and_insert(tx).unwrap());
+        }
+
+        // When
+        let txs = universe
+            .get_pool()
+            .write()
+            .extract_transactions_for_block(constraints(max_gas));
+
+        // Then
+        let mut expected = vec![];
+        let mut gas_left = max_gas;
+        for tx in &inserted {
+            let Some(left) = gas_left.checked_sub(tx.max_gas()) else {
+                break
+            };
+            gas_left = left;
+            expected.push(tx.id());
+        }
+        let actual: Vec<_> = txs.iter().map(|tx| tx.id()).collect();
+        prop_assert_eq!(actual, expected);
+        universe.assert_pool_integrity(&remaining_txs(&inserted, &txs));
+    }
+
+    #[test]
+    fn lanes__priority_transactions_get_reserved_gas(
+        regular_tips in prop::collection::vec(0u64..1000, 0..10),
+        priority_tips in prop::collection::vec(0u64..1000, 0..10),
+        reserved_gas_percentage in 0u8..=100,
+        max_gas_in_txs in 0u64..20,
+    ) {
+        let mut universe = TestPoolUniverse::default();
+
+        // Given
+        let regular_txs: Vec<_> = regular_tips
+            .into_iter()
+            .map(|tip| script_transaction(&mut universe, tip, 1000))
+            .collect();
+        let priority_txs: Vec<_> = priority_tips
+            .into_iter()
+            .map(|tip| script_transaction(&mut universe, tip, 1000))
+            .collect();
+        let priority_addresses = priority_txs.iter().map(owner_of).collect();
+        let mut universe = universe.config(Config {
+            selection_policy: SelectionPolicy::Lanes(LanesConfig {
+                priority_addresses,
+                reserved_gas_percentage,
+            }),
+            ..Default::default()
+        });
+        universe.build_pool();
+        let mut inserted = vec![];
+        for tx in regular_txs {
+            inserted.push(universe.verify_and_insert(tx).unwrap());
+        }
+        let mut priority_ids = HashSet::new();
+        for tx in priority_txs {
+            let tx = universe.verify_and_insert(tx).unwrap();
+            priority_ids.insert(tx.id());
+            inserted.push(tx);
+        }
+        let tx_gas = inserted.iter().map(|tx| tx.max_gas()).max().unwrap_or(1);
+        let max_gas = tx_gas.saturating_mul(max_gas_in_txs);
+
+        // When
+        let txs = universe
+            .get_pool()
+            .write()
+            .extract_transactions_for_block(constraints(max_gas));
+
+        // Then
+        let reserved_gas = u128::from(max_gas)
+            .saturating_mul(u128::from(reserved_gas_percentage))
+            .checked_div(100)
+            .unwrap();
+        let reserved_txs = usize::try_from(
+            reserved_gas.checked_div(u128::from(tx_gas)).unwrap(),
+        )
+        .unwrap();
+        let selected_priority = txs
+            .iter()
+            .filter(|tx| priority_ids.contains(&tx.id()))
+            .count();
+        prop_assert!(selected_priority >= reserved_txs.min(priority_ids.len()));
+
+        let fitting_txs =
+            usize::try_from(max_gas.checked_div(tx_gas).unwrap()).unwrap();
+        prop_assert!(txs.len() >= fitting_txs.min(inserted.len()));
+
+        let used_gas = txs
+            .iter()
+            .fold(0u64, |used, tx| used.saturating_add(tx.max_gas()));
+        prop_assert!(used_gas <= max_gas);
+        universe.assert_pool_integrity(&remaining_txs(&inserted, &txs));
+    }
+}
@@ -717,3 +717,32 @@ mod expiration_tests {
         }
     }
 }
+
+#[tokio::test]
+async fn insert_bundle__oversized_bundle_is_rejected_before_verification() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        max_bundle_size: 1,
+        ..Default::default()
+    });
+    let tx1 = universe.build_script_transaction(None, None, 10);
+    let tx2 = universe.build_script_transaction(None, None, 20);
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    // When
+    let result = service.shared.insert_bundle(vec![tx1, tx2]).await;
+
+    // Then
+    let err = result.expect_err("The bundle should be rejected");
+    assert!(
+        matches!(
+            err,
+            crate::error::Error::Bundle(crate::error::BundleError::TooManyTransactions(
+                2, 1
+            ))
+        ),
+        "{err:?}"
+    );
+
+    service.stop_and_await().await.unwrap();
+}
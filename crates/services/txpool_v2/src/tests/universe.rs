@@ -25,7 +25,7 @@ use crate::{
         Pool,
         TxPoolStats,
     },
-    selection_algorithms::ratio_tip_gas::RatioTipGasSelection,
+    selection_algorithms::Selection,
     service::{
         Shared,
         TxPool,
@@ -99,6 +99,7 @@ use std::time::Duration;
 use tokio::sync::mpsc;
 
 use super::mocks::{
+    MockAdmissionFilter,
     MockChainStateInfoProvider,
     MockImporter,
     MockP2P,
@@ -128,6 +129,7 @@ pub struct TestPoolUniverse {
     mock_db: MockDb,
     rng: StdRng,
     pub config: Config,
+    pub admission_filter: MockAdmissionFilter,
     pool: Option<Shared<TxPool<MockTxStatusManager>>>,
     mock_tx_status_manager: MockTxStatusManager,
     tx_status_manager_receiver: mpsc::Receiver<(TxId, TransactionStatus)>,
@@ -144,6 +146,7 @@ impl Default for TestPoolUniverse {
             mock_db: MockDb::default(),
             rng: StdRng::seed_from_u64(0),
             config: Default::default(),
+            admission_filter: Default::default(),
             pool: None,
             stats_receiver: None,
             mock_tx_status_manager: MockTxStatusManager::new(tx_all_status_sender, tx),
@@ -194,7 +197,7 @@ impl TestPoolUniverse {
                 max_txs_chain_count: self.config.max_txs_chain_count,
             }),
             BasicCollisionManager::new(),
-            RatioTipGasSelection::new(tx_new_executable_txs.clone()),
+            Selection::new(&self.config.selection_policy, tx_new_executable_txs.clone()),
             self.config.clone(),
             tx,
             tx_new_executable_txs,
@@ -202,6 +205,7 @@ impl TestPoolUniverse {
                 all_service_txs_sender,
                 status_sender,
             )),
+            Arc::new(self.admission_filter.clone()),
         )));
         self.stats_receiver = Some(rx);
         self.tx_status_manager_receiver = status_receiver;
@@ -244,6 +248,7 @@ impl TestPoolUniverse {
             MockWasmChecker { result: Ok(()) },
             tx,
             self.mock_tx_status_manager.clone(),
+            self.admission_filter.clone(),
         )
     }
 
@@ -296,6 +301,32 @@ impl TestPoolUniverse {
         tx_builder.finalize().into()
     }
 
+    pub fn build_script_transaction_with_maturity(
+        &mut self,
+        inputs: Option<Vec<Input>>,
+        outputs: Option<Vec<Output>>,
+        tip: u64,
+        maturity: BlockHeight,
+    ) -> Transaction {
+        let mut inputs = inputs.unwrap_or_default();
+        let (_, gas_coin) = self.setup_coin();
+        inputs.push(gas_coin);
+        let outputs = outputs.unwrap_or_default();
+        let mut tx_builder = TransactionBuilder::script(vec![], vec![]);
+        tx_builder.script_gas_limit(GAS_LIMIT);
+        for input in inputs {
+            tx_builder.add_input(input);
+        }
+        for output in outputs {
+            tx_builder.add_output(output);
+        }
+        tx_builder.tip(tip);
+        tx_builder.max_fee_limit(10000);
+        tx_builder.maturity(maturity);
+        tx_builder.expiration(DEFAULT_EXPIRATION_HEIGHT);
+        tx_builder.finalize().into()
+    }
+
     pub fn build_create_contract_transaction(
         &mut self,
         code: Vec<u8>,
@@ -351,6 +382,7 @@ impl TestPoolUniverse {
                 let tx = verification.perform_all_verifications(
                     tx,
                     Default::default(),
+                    0,
                     true,
                     false,
                 )?;
@@ -370,6 +402,52 @@ impl TestPoolUniverse {
         }
     }
 
+    // Returns the added transactions of the bundle
+    pub fn verify_and_insert_bundle(
+        &mut self,
+        txs: Vec<Transaction>,
+    ) -> Result<Vec<ArcPoolTx>, Error> {
+        match &self.pool {
+            Some(pool) => {
+                let mut mock_chain_state_info_provider =
+                    MockChainStateInfoProvider::default();
+                mock_chain_state_info_provider
+                    .expect_latest_consensus_parameters()
+                    .returning(|| (0, Arc::new(ConsensusParameters::standard())));
+                let verification = Verification {
+                    persistent_storage_provider: Arc::new(MockDBProvider(
+                        self.mock_db.clone(),
+                    )),
+                    gas_price_provider: Arc::new(MockTxPoolGasPrice::new(0)),
+                    chain_state_info_provider: Arc::new(mock_chain_state_info_provider),
+                    wasm_checker: Arc::new(MockWasmChecker::new(Ok(()))),
+                    memory_pool: MemoryPool::new(),
+                    blacklist: BlackList::default(),
+                };
+                let txs = txs
+                    .into_iter()
+                    .map(|tx| {
+                        verification
+                            .perform_all_verifications(
+                                tx,
+                                Default::default(),
+                                0,
+                                true,
+                                false,
+                            )
+                            .map(Arc::new)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                pool.write().insert_bundle(txs.clone(), &self.mock_db)?;
+
+                Ok(txs)
+            }
+            _ => {
+                panic!("Pool needs to be built first");
+            }
+        }
+    }
+
     pub fn verify_and_insert_with_gas_price(
         &mut self,
         tx: Transaction,
@@ -394,6 +472,7 @@ impl TestPoolUniverse {
                 let tx = verification.perform_all_verifications(
                     tx,
                     Default::default(),
+                    0,
                     true,
                     false,
                 )?;
@@ -436,6 +515,7 @@ impl TestPoolUniverse {
                 let tx = verification.perform_all_verifications(
                     tx,
                     Default::default(),
+                    0,
                     true,
                     false,
                 )?;
@@ -498,7 +578,19 @@ impl TestPoolUniverse {
 
     pub fn setup_coin(&mut self) -> (Coin, Input) {
         let input = self.random_predicate(AssetId::BASE, TEST_COIN_AMOUNT, None);
+        self.store_coin(input)
+    }
+
+    /// Creates a coin owned by the predicate with the `code`. Coins created with
+    /// the same `code` have the same owner.
+    pub fn setup_coin_with_predicate(&mut self, code: Vec<u8>) -> (Coin, Input) {
+        let input = self
+            .custom_predicate(AssetId::BASE, TEST_COIN_AMOUNT, code, None)
+            .into_default_estimated();
+        self.store_coin(input)
+    }
 
+    fn store_coin(&mut self, input: Input) -> (Coin, Input) {
         // add coin to the state
         let mut coin = CompressedCoin::default();
         coin.set_owner(*input.input_owner().unwrap());
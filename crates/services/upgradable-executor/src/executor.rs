@@ -2,6 +2,7 @@
 use crate::error::UpgradableError;
 use crate::{
     config::Config,
+    state_overrides::apply_state_overrides,
     storage_access_recorder::StorageAccessRecorder,
 };
 use fuel_core_executor::{
@@ -37,7 +38,10 @@ use fuel_core_types::{
             StateTransitionBytecodeVersion,
         },
     },
-    fuel_tx::Transaction,
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
     fuel_types::BlockHeight,
     services::{
         Uncommitted,
@@ -47,7 +51,9 @@ use fuel_core_types::{
             Error as ExecutorError,
             ExecutionResult,
             Result as ExecutorResult,
+            StateOverrides,
             StorageReadReplayEvent,
+            TransactionTrace,
             ValidationResult,
         },
     },
@@ -72,7 +78,10 @@ use fuel_core_types::services::executor::UncommittedResult;
 
 #[cfg(feature = "wasm-executor")]
 use fuel_core_executor::executor::convert_tx_execution_result_to_preconfirmation;
-use fuel_core_types::services::executor::memory::MemoryPool;
+use fuel_core_types::services::executor::memory::{
+    MemoryPool,
+    RecyclableMemory,
+};
 #[cfg(feature = "wasm-executor")]
 use fuel_core_types::{
     fuel_types::Bytes32,
@@ -111,22 +120,13 @@ enum ProduceBlockMode {
     DryRun {
         height: BlockHeightSelection,
         record_storage_reads: bool,
+        state_overrides: StateOverrides,
     },
 }
 impl ProduceBlockMode {
     fn is_dry_run(&self) -> bool {
         matches!(self, Self::DryRun { .. })
     }
-
-    fn record_storage_reads(&self) -> bool {
-        match self {
-            Self::Produce => false,
-            Self::DryRun {
-                record_storage_reads,
-                ..
-            } => *record_storage_reads,
-        }
-    }
 }
 
 /// Result of various `produce_*` functions
@@ -426,6 +426,7 @@ where
             ProduceBlockMode::DryRun {
                 height: BlockHeightSelection::Latest,
                 record_storage_reads: false,
+                state_overrides: Default::default(),
             },
         )
         .map(|r| r.map_result(|produced| produced.result))
@@ -477,13 +478,15 @@ where
     }
 
     /// Executes the block and returns the result of the execution without committing
-    /// the changes in the dry run mode.
+    /// the changes in the dry run mode. The `state_overrides` are applied on top of
+    /// the state before the execution.
     pub fn dry_run(
         &self,
         component: Components<Vec<Transaction>>,
         forbid_fake_coins: Option<bool>,
         at_height: Option<BlockHeight>,
         record_storage_reads: bool,
+        state_overrides: Option<StateOverrides>,
     ) -> ExecutorResult<DryRunResult> {
         if at_height.is_some() && !self.config.allow_historical_execution {
             return Err(ExecutorError::Other(
@@ -528,6 +531,7 @@ where
                         None => BlockHeightSelection::Latest,
                     },
                     record_storage_reads,
+                    state_overrides: state_overrides.unwrap_or_default(),
                 },
             )?
             .into_result();
@@ -634,6 +638,38 @@ where
         Ok(core::mem::take(&mut g))
     }
 
+    /// Re-executes an old block, building the call tree of the transaction with `tx_id`.
+    /// The WASM executor doesn't expose the call tree, so only blocks of the native
+    /// executor version are supported.
+    pub fn trace_transaction(
+        &self,
+        block: &Block,
+        tx_id: &TxId,
+    ) -> ExecutorResult<TransactionTrace> {
+        let block_version = block.header().state_transition_bytecode_version();
+        let native_executor_version = self.native_executor_version();
+        if block_version != native_executor_version {
+            return Err(ExecutorError::Other(format!(
+                "Tracing is not supported for version `{block_version}`. Expected version is `{}`",
+                Self::VERSION
+            )))
+        }
+
+        let relayer = self.relayer_view_provider.latest_view()?;
+        let memory = self.dry_run_pool.take_raw();
+        let options = self.config.as_ref().into();
+
+        if let Some(previous_block_height) = block.header().height().pred() {
+            let database = self.storage_view_provider.view_at(&previous_block_height)?;
+            ExecutionInstance::new(relayer, database, options, memory)
+                .trace_without_commit(block, tx_id)
+        } else {
+            let database = self.storage_view_provider.latest_view()?;
+            ExecutionInstance::new(relayer, database, options, memory)
+                .trace_without_commit(block, tx_id)
+        }
+    }
+
     #[cfg(feature = "wasm-executor")]
     fn wasm_storage_read_replay(
         &self,
@@ -892,25 +928,14 @@ where
         let instance_without_input =
             crate::instance::Instance::new(&self.engine).add_source(source)?;
 
-        let mut storage_rec = Default::default();
-        let instance_without_input = if let Some(previous_block_height) = db_height {
+        let (instance_without_input, storage_rec) = if let Some(previous_block_height) =
+            db_height
+        {
             let storage = self.storage_view_provider.view_at(&previous_block_height)?;
-            if mode.record_storage_reads() {
-                let storage = StorageAccessRecorder::new(storage);
-                storage_rec = storage.record.clone();
-                instance_without_input.add_storage(storage)?
-            } else {
-                instance_without_input.add_storage(storage)?
-            }
+            Self::wasm_add_storage(instance_without_input, storage, &mode)?
         } else {
             let storage = self.storage_view_provider.latest_view()?;
-            if mode.record_storage_reads() {
-                let storage = StorageAccessRecorder::new(storage);
-                storage_rec = storage.record.clone();
-                instance_without_input.add_storage(storage)?
-            } else {
-                instance_without_input.add_storage(storage)?
-            }
+            Self::wasm_add_storage(instance_without_input, storage, &mode)?
         };
 
         let relayer = self.relayer_view_provider.latest_view()?;
@@ -981,6 +1006,42 @@ where
         }))
     }
 
+    /// Adds the `storage` to the WASM `instance`. In the dry run mode, the state
+    /// overrides are applied to the `storage` and the storage reads are recorded if requested.
+    #[cfg(feature = "wasm-executor")]
+    fn wasm_add_storage<D>(
+        instance: crate::instance::Instance<crate::instance::Source>,
+        storage: D,
+        mode: &ProduceBlockMode,
+    ) -> ExecutorResult<(
+        crate::instance::Instance<crate::instance::Storage>,
+        crate::storage_access_recorder::StorageReadsRecord,
+    )>
+    where
+        D: KeyValueInspect<Column = Column> + Send + Sync + 'static,
+    {
+        let mut storage_rec = Default::default();
+        let instance = match mode {
+            ProduceBlockMode::Produce => instance.add_storage(storage)?,
+            ProduceBlockMode::DryRun {
+                record_storage_reads,
+                state_overrides,
+                ..
+            } => {
+                let storage = apply_state_overrides(storage, state_overrides)?;
+                if *record_storage_reads {
+                    let storage = StorageAccessRecorder::new(storage);
+                    storage_rec = storage.record.clone();
+                    instance.add_storage(storage)?
+                } else {
+                    instance.add_storage(storage)?
+                }
+            }
+        };
+
+        Ok((instance, storage_rec))
+    }
+
     #[cfg(feature = "wasm-executor")]
     fn wasm_validate_inner(
         &self,
@@ -1043,59 +1104,98 @@ where
             },
         };
 
-        let mut storage_rec = Default::default();
         let memory = if mode.is_dry_run() {
             self.dry_run_pool.take_raw()
         } else {
             self.produce_block_pool.take_raw()
         };
 
-        let result = if let Some(previous_block_height) = db_height {
+        if let Some(previous_block_height) = db_height {
             let database = self.storage_view_provider.view_at(&previous_block_height)?;
-            if mode.record_storage_reads() {
-                let database = StorageAccessRecorder::new(database);
-                storage_rec = database.record.clone();
-                ExecutionInstance::new(relayer, database, options, memory)
-                    .produce_without_commit(
-                        block,
-                        mode.is_dry_run(),
-                        new_tx_waiter,
-                        preconfirmation_sender,
-                    )
-                    .await
-            } else {
-                ExecutionInstance::new(relayer, database, options, memory)
-                    .produce_without_commit(
-                        block,
-                        mode.is_dry_run(),
-                        new_tx_waiter,
-                        preconfirmation_sender,
-                    )
-                    .await
-            }
+            Self::native_produce_with_storage(
+                relayer,
+                database,
+                block,
+                options,
+                mode,
+                memory,
+                new_tx_waiter,
+                preconfirmation_sender,
+            )
+            .await
         } else {
             let database = self.storage_view_provider.latest_view()?;
-            if mode.record_storage_reads() {
-                let database = StorageAccessRecorder::new(database);
-                storage_rec = database.record.clone();
-                ExecutionInstance::new(relayer, database, options, memory)
-                    .produce_without_commit(
-                        block,
-                        mode.is_dry_run(),
-                        new_tx_waiter,
-                        preconfirmation_sender,
-                    )
-                    .await
-            } else {
+            Self::native_produce_with_storage(
+                relayer,
+                database,
+                block,
+                options,
+                mode,
+                memory,
+                new_tx_waiter,
+                preconfirmation_sender,
+            )
+            .await
+        }
+    }
+
+    /// Produces the block on top of the `database`. In the dry run mode, the state
+    /// overrides are applied to the `database` and the storage reads are recorded if requested.
+    #[allow(clippy::too_many_arguments)]
+    async fn native_produce_with_storage<D, TxSource>(
+        relayer: R::LatestView,
+        database: D,
+        block: Components<TxSource>,
+        options: ExecutionOptions,
+        mode: ProduceBlockMode,
+        memory: RecyclableMemory,
+        new_tx_waiter: impl NewTxWaiterPort,
+        preconfirmation_sender: impl PreconfirmationSenderPort,
+    ) -> ExecutorResult<Uncommitted<ProducedBlock, Changes>>
+    where
+        D: KeyValueInspect<Column = Column> + Send + Sync + 'static,
+        TxSource: TransactionsSource + Send + Sync + 'static,
+    {
+        let mut storage_rec = Default::default();
+        let result = match mode {
+            ProduceBlockMode::Produce => {
                 ExecutionInstance::new(relayer, database, options, memory)
                     .produce_without_commit(
                         block,
-                        mode.is_dry_run(),
+                        false,
                         new_tx_waiter,
                         preconfirmation_sender,
                     )
                     .await
             }
+            ProduceBlockMode::DryRun {
+                record_storage_reads,
+                state_overrides,
+                ..
+            } => {
+                let database = apply_state_overrides(database, &state_overrides)?;
+                if record_storage_reads {
+                    let database = StorageAccessRecorder::new(database);
+                    storage_rec = database.record.clone();
+                    ExecutionInstance::new(relayer, database, options, memory)
+                        .produce_without_commit(
+                            block,
+                            true,
+                            new_tx_waiter,
+                            preconfirmation_sender,
+                        )
+                        .await
+                } else {
+                    ExecutionInstance::new(relayer, database, options, memory)
+                        .produce_without_commit(
+                            block,
+                            true,
+                            new_tx_waiter,
+                            preconfirmation_sender,
+                        )
+                        .await
+                }
+            }
         };
 
         let mut g = storage_rec.lock();
@@ -7,6 +7,7 @@ pub mod config;
 pub mod error;
 pub mod executor;
 
+mod state_overrides;
 mod storage_access_recorder;
 pub use fuel_core_executor as native_executor;
 
@@ -0,0 +1,212 @@
+use fuel_core_storage::{
+    ContractsAssetKey,
+    ContractsStateKey,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+    column::Column,
+    kv_store::KeyValueInspect,
+    tables::{
+        Coins,
+        ContractsAssets,
+        ContractsLatestUtxo,
+        ContractsRawCode,
+        ContractsState,
+        Messages,
+    },
+    transactional::{
+        IntoTransaction,
+        StorageTransaction,
+    },
+};
+use fuel_core_types::{
+    entities::contract::ContractUtxoInfo,
+    services::executor::StateOverrides,
+};
+
+/// Applies the `overrides` on top of the `storage`.
+/// The changes live only in the memory of the returned transaction and are never committed.
+pub fn apply_state_overrides<S>(
+    storage: S,
+    overrides: &StateOverrides,
+) -> StorageResult<StorageTransaction<S>>
+where
+    S: KeyValueInspect<Column = Column>,
+{
+    let mut storage = storage.into_transaction();
+
+    for contract in &overrides.contracts {
+        let contract_id = &contract.contract_id;
+
+        if let Some(bytecode) = &contract.bytecode {
+            storage
+                .storage_as_mut::<ContractsRawCode>()
+                .insert(contract_id, bytecode)?;
+
+            // The executor only accepts contract inputs with the known latest UTXO.
+            if !storage
+                .storage_as_ref::<ContractsLatestUtxo>()
+                .contains_key(contract_id)?
+            {
+                storage
+                    .storage_as_mut::<ContractsLatestUtxo>()
+                    .insert(contract_id, &ContractUtxoInfo::default())?;
+            }
+        }
+
+        for (key, value) in &contract.storage_slots {
+            storage
+                .storage_as_mut::<ContractsState>()
+                .insert(&ContractsStateKey::new(contract_id, key), value)?;
+        }
+
+        for (asset_id, amount) in &contract.balances {
+            storage
+                .storage_as_mut::<ContractsAssets>()
+                .insert(&ContractsAssetKey::new(contract_id, asset_id), amount)?;
+        }
+    }
+
+    for coin in &overrides.coins {
+        storage
+            .storage_as_mut::<Coins>()
+            .insert(&coin.utxo_id, &coin.compress())?;
+    }
+
+    for message in &overrides.messages {
+        storage
+            .storage_as_mut::<Messages>()
+            .insert(message.nonce(), message)?;
+    }
+
+    Ok(storage)
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::structured_storage::test::InMemoryStorage;
+    use fuel_core_types::{
+        entities::{
+            coins::coin::Coin,
+            relayer::message::{
+                Message,
+                MessageV1,
+            },
+        },
+        fuel_tx::{
+            AssetId,
+            Bytes32,
+            ContractId,
+            UtxoId,
+        },
+        services::executor::ContractOverride,
+    };
+
+    #[test]
+    fn apply_state_overrides__inserts_the_overridden_state() {
+        // Given
+        let storage = InMemoryStorage::<Column>::default();
+        let contract_id = ContractId::from([1; 32]);
+        let slot = Bytes32::from([2; 32]);
+        let asset_id = AssetId::from([3; 32]);
+        let coin = Coin {
+            utxo_id: UtxoId::new([4; 32].into(), 0),
+            owner: [5; 32].into(),
+            amount: 100,
+            asset_id,
+            tx_pointer: Default::default(),
+        };
+        let message: Message = MessageV1 {
+            nonce: [6; 32].into(),
+            amount: 200,
+            ..Default::default()
+        }
+        .into();
+        let overrides = StateOverrides {
+            contracts: vec![ContractOverride {
+                contract_id,
+                bytecode: Some(vec![7; 8]),
+                storage_slots: vec![(slot, vec![8; 32])],
+                balances: vec![(asset_id, 300)],
+            }],
+            coins: vec![coin],
+            messages: vec![message.clone()],
+        };
+
+        // When
+        let storage = apply_state_overrides(&storage, &overrides).unwrap();
+
+        // Then
+        let code = storage
+            .storage_as_ref::<ContractsRawCode>()
+            .get(&contract_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(code.as_ref().as_ref(), &[7; 8]);
+        assert!(
+            storage
+                .storage_as_ref::<ContractsLatestUtxo>()
+                .contains_key(&contract_id)
+                .unwrap()
+        );
+        let value = storage
+            .storage_as_ref::<ContractsState>()
+            .get(&ContractsStateKey::new(&contract_id, &slot))
+            .unwrap()
+            .unwrap();
+        assert_eq!(value.as_ref().as_ref(), &[8; 32]);
+        let balance = storage
+            .storage_as_ref::<ContractsAssets>()
+            .get(&ContractsAssetKey::new(&contract_id, &asset_id))
+            .unwrap()
+            .unwrap();
+        assert_eq!(*balance, 300);
+        let stored_coin = storage
+            .storage_as_ref::<Coins>()
+            .get(&coin.utxo_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored_coin.into_owned(), coin.compress());
+        let stored_message = storage
+            .storage_as_ref::<Messages>()
+            .get(message.nonce())
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored_message.into_owned(), message);
+    }
+
+    #[test]
+    fn apply_state_overrides__keeps_the_latest_utxo_of_existing_contract() {
+        // Given
+        let mut storage = InMemoryStorage::<Column>::default().into_transaction();
+        let contract_id = ContractId::from([1; 32]);
+        let existing_utxo = ContractUtxoInfo::V1(
+            (UtxoId::new([2; 32].into(), 1), Default::default()).into(),
+        );
+        storage
+            .storage_as_mut::<ContractsLatestUtxo>()
+            .insert(&contract_id, &existing_utxo)
+            .unwrap();
+        let overrides = StateOverrides {
+            contracts: vec![ContractOverride {
+                contract_id,
+                bytecode: Some(vec![1; 8]),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        // When
+        let storage = apply_state_overrides(storage, &overrides).unwrap();
+
+        // Then
+        let utxo = storage
+            .storage_as_ref::<ContractsLatestUtxo>()
+            .get(&contract_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(utxo.into_owned(), existing_utxo);
+    }
+}
@@ -10,12 +10,15 @@ use fuel_core_types::services::executor::StorageReadReplayEvent;
 use parking_lot::Mutex;
 use std::sync::Arc;
 
+/// The storage reads recorded by the [`StorageAccessRecorder`].
+pub type StorageReadsRecord = Arc<Mutex<Vec<StorageReadReplayEvent>>>;
+
 pub struct StorageAccessRecorder<S>
 where
     S: KeyValueInspect,
 {
     pub storage: S,
-    pub record: Arc<Mutex<Vec<StorageReadReplayEvent>>>,
+    pub record: StorageReadsRecord,
 }
 
 impl<S> StorageAccessRecorder<S>
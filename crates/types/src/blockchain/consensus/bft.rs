@@ -0,0 +1,219 @@
+//! Byzantine fault tolerant consensus
+
+use crate::{
+    blockchain::{
+        block::Block,
+        primitives::BlockId,
+    },
+    fuel_crypto::{
+        Hasher,
+        Message,
+        Signature,
+    },
+    fuel_types::{
+        BlockHeight,
+        ChainId,
+    },
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// The domain separator of the messages signed by the validators.
+const BFT_DOMAIN: &[u8] = b"FUEL_BFT";
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The consensus related data that doesn't live on the
+/// header.
+pub struct BftConsensus {
+    /// The round of the height in which the block was committed.
+    pub round: u32,
+    /// The precommit signatures of the validators for the block.
+    /// Each signature signs the [`Vote::signing_message`] of the precommit vote.
+    pub signatures: Vec<Signature>,
+}
+
+impl BftConsensus {
+    /// Create a new block consensus.
+    pub fn new(round: u32, signatures: Vec<Signature>) -> Self {
+        Self { round, signatures }
+    }
+
+    /// The message signed by the validators that committed the block.
+    pub fn commit_message(
+        &self,
+        chain_id: &ChainId,
+        height: BlockHeight,
+        block_id: BlockId,
+    ) -> Message {
+        Vote::precommit(height, self.round, Some(block_id)).signing_message(chain_id)
+    }
+}
+
+/// The type of the vote.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VoteType {
+    /// The first voting step of the round.
+    Prevote,
+    /// The second voting step of the round.
+    Precommit,
+}
+
+/// The vote of the validator for the block in the round of the height.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vote {
+    /// The height of the block.
+    pub height: BlockHeight,
+    /// The round of the height.
+    pub round: u32,
+    /// The type of the vote.
+    pub vote_type: VoteType,
+    /// The id of the block, or `None` for the vote against all blocks of the round.
+    pub block_id: Option<BlockId>,
+}
+
+impl Vote {
+    /// Create a new prevote.
+    pub fn prevote(height: BlockHeight, round: u32, block_id: Option<BlockId>) -> Self {
+        Self {
+            height,
+            round,
+            vote_type: VoteType::Prevote,
+            block_id,
+        }
+    }
+
+    /// Create a new precommit.
+    pub fn precommit(height: BlockHeight, round: u32, block_id: Option<BlockId>) -> Self {
+        Self {
+            height,
+            round,
+            vote_type: VoteType::Precommit,
+            block_id,
+        }
+    }
+
+    /// The message signed by the validator. It commits to the chain id,
+    /// so the vote can't be replayed on another chain with the same validators.
+    pub fn signing_message(&self, chain_id: &ChainId) -> Message {
+        let vote_type: u8 = match self.vote_type {
+            VoteType::Prevote => 0,
+            VoteType::Precommit => 1,
+        };
+        let mut hasher = Hasher::default();
+        hasher.input(BFT_DOMAIN);
+        hasher.input(chain_id.to_be_bytes());
+        hasher.input([vote_type]);
+        hasher.input(self.height.to_be_bytes());
+        hasher.input(self.round.to_be_bytes());
+        match &self.block_id {
+            Some(block_id) => {
+                hasher.input([1]);
+                hasher.input(block_id.as_slice());
+            }
+            None => hasher.input([0]),
+        }
+        Message::from_bytes(*hasher.digest())
+    }
+}
+
+/// The block proposed by the proposer of the round.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Proposal {
+    /// The height of the block.
+    pub height: BlockHeight,
+    /// The round of the height.
+    pub round: u32,
+    /// The round in which the proposer saw a quorum of prevotes for the block,
+    /// `None` if the block is new.
+    pub valid_round: Option<u32>,
+    /// The proposed block.
+    pub block: Block,
+}
+
+impl Proposal {
+    /// The message signed by the proposer. It commits to the chain id,
+    /// so the proposal can't be replayed on another chain with the same validators.
+    pub fn signing_message(&self, chain_id: &ChainId) -> Message {
+        let mut hasher = Hasher::default();
+        hasher.input(BFT_DOMAIN);
+        hasher.input(chain_id.to_be_bytes());
+        hasher.input([2]);
+        hasher.input(self.height.to_be_bytes());
+        hasher.input(self.round.to_be_bytes());
+        match self.valid_round {
+            Some(valid_round) => {
+                hasher.input([1]);
+                hasher.input(valid_round.to_be_bytes());
+            }
+            None => hasher.input([0]),
+        }
+        hasher.input(self.block.id().as_slice());
+        Message::from_bytes(*hasher.digest())
+    }
+}
+
+/// The value signed by the validator.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Signed<T> {
+    /// The signed value.
+    pub entity: T,
+    /// The signature of the validator.
+    pub signature: Signature,
+}
+
+/// The vote signed by the validator.
+pub type SignedVote = Signed<Vote>;
+
+/// The proposal signed by the proposer.
+pub type SignedProposal = Signed<Proposal>;
+
+/// The message exchanged by the validators during the consensus.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BftMessage {
+    /// The proposal of the block.
+    Proposal(SignedProposal),
+    /// The vote for the block.
+    Vote(SignedVote),
+}
+
+impl BftMessage {
+    /// The height of the message.
+    pub fn height(&self) -> BlockHeight {
+        match self {
+            BftMessage::Proposal(proposal) => proposal.entity.height,
+            BftMessage::Vote(vote) => vote.entity.height,
+        }
+    }
+
+    /// The round of the message.
+    pub fn round(&self) -> u32 {
+        match self {
+            BftMessage::Proposal(proposal) => proposal.entity.round,
+            BftMessage::Vote(vote) => vote.entity.round,
+        }
+    }
+
+    /// The message signed by the sender.
+    pub fn signing_message(&self, chain_id: &ChainId) -> Message {
+        match self {
+            BftMessage::Proposal(proposal) => proposal.entity.signing_message(chain_id),
+            BftMessage::Vote(vote) => vote.entity.signing_message(chain_id),
+        }
+    }
+
+    /// The signature of the sender.
+    pub fn signature(&self) -> &Signature {
+        match self {
+            BftMessage::Proposal(proposal) => &proposal.signature,
+            BftMessage::Vote(vote) => &vote.signature,
+        }
+    }
+}
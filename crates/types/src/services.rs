@@ -5,6 +5,8 @@ pub mod block_producer;
 pub mod executor;
 pub mod graphql_api;
 #[cfg(feature = "std")]
+pub mod leader_lease;
+#[cfg(feature = "std")]
 pub mod p2p;
 pub mod preconfirmation;
 pub mod relayer;
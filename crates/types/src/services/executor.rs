@@ -15,6 +15,7 @@ use crate::{
             transaction::RelayedTransactionId,
         },
     },
+    fuel_asm::Word,
     fuel_tx::{
         Receipt,
         TxId,
@@ -22,6 +23,7 @@ use crate::{
         ValidityError,
     },
     fuel_types::{
+        AssetId,
         BlockHeight,
         Bytes32,
         ContractId,
@@ -278,6 +280,49 @@ pub struct StorageReadReplayEvent {
     pub value: Option<Vec<u8>>,
 }
 
+/// The call tree of the executed transaction.
+/// The call frames are flattened in the order they were entered.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TransactionTrace {
+    /// The gas used by the script, including the gas used by all call frames.
+    pub gas_used: Word,
+    /// Receipts emitted by the script outside of any call frame.
+    pub receipts: Vec<Receipt>,
+    /// All call frames of the transaction.
+    pub frames: Vec<CallFrameTrace>,
+    /// The `Revert` or `Panic` receipt, if the script itself reverted.
+    pub revert: Option<Receipt>,
+}
+
+/// The contract call frame opened by the `CALL` instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CallFrameTrace {
+    /// The index of the parent frame in the [`TransactionTrace::frames`].
+    /// `None` if the frame was opened by the script.
+    pub parent: Option<u32>,
+    /// The called contract.
+    pub contract_id: ContractId,
+    /// The first parameter of the call, used as the function selector.
+    pub function_selector: Word,
+    /// The second parameter of the call, usually the arguments.
+    pub param2: Word,
+    /// The asset forwarded to the contract.
+    pub asset_id: AssetId,
+    /// The amount of the forwarded asset.
+    pub amount: Word,
+    /// The gas forwarded to the frame.
+    pub gas_forwarded: Word,
+    /// The gas used inside of the frame, including the gas used by nested frames.
+    pub gas_used: Word,
+    /// Receipts emitted by the contract inside of the frame,
+    /// including the `Call` receipts of nested frames.
+    pub receipts: Vec<Receipt>,
+    /// The `Revert` or `Panic` receipt, if the execution reverted inside of the frame.
+    pub revert: Option<Receipt>,
+}
+
 /// The result of a dry run.
 #[derive(Debug, Clone)]
 pub struct DryRunResult {
@@ -287,6 +332,41 @@ pub struct DryRunResult {
     pub storage_reads: Vec<StorageReadReplayEvent>,
 }
 
+/// The changes applied on top of the forked state before the dry run.
+/// They are never committed and are visible only to the transactions of the dry run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StateOverrides {
+    /// Overrides of the contracts' bytecode, storage slots, and balances.
+    pub contracts: Vec<ContractOverride>,
+    /// Fake coins inserted into the state.
+    pub coins: Vec<Coin>,
+    /// Fake messages inserted into the state.
+    pub messages: Vec<Message>,
+}
+
+impl StateOverrides {
+    /// Returns `true` if there is nothing to override.
+    pub fn is_empty(&self) -> bool {
+        self.contracts.is_empty() && self.coins.is_empty() && self.messages.is_empty()
+    }
+}
+
+/// The override of the contract's state.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContractOverride {
+    /// The contract to override.
+    pub contract_id: ContractId,
+    /// Replaces the bytecode of the contract.
+    /// If the contract doesn't exist, it is created with this bytecode.
+    pub bytecode: Option<Vec<u8>>,
+    /// Storage slots that are set to the specified values.
+    pub storage_slots: Vec<(Bytes32, Vec<u8>)>,
+    /// Balances that are set to the specified amounts.
+    pub balances: Vec<(AssetId, Word)>,
+}
+
 #[allow(missing_docs)]
 #[derive(Debug, Clone, PartialEq, derive_more::Display, derive_more::From)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
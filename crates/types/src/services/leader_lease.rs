@@ -0,0 +1,116 @@
+//! Contains types related to the leader lease of the block producer
+
+use crate::fuel_types::BlockHeight;
+use std::time::SystemTime;
+
+/// The backend of the leader lease.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeaderLeaseBackend {
+    /// The lease held in the quorum of Redis nodes.
+    Redis,
+    /// The lease replicated by the Raft log between the block producers.
+    Raft,
+}
+
+/// The state of the leader lease of the block producer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeaderLeaseStatus {
+    /// The backend of the lease.
+    pub backend: LeaderLeaseBackend,
+    /// The key of the lease in Redis. `None` for the Raft lease.
+    pub lease_key: Option<String>,
+    /// The token that identifies this node as the lease holder.
+    /// The id of the replica for the Raft lease.
+    pub lease_owner_token: String,
+    /// `true` if this node held the lease after the last lease round.
+    pub is_leader: bool,
+    /// The epoch token of this node, used to fence the writes to the block stream.
+    pub epoch_token: Option<u64>,
+    /// The token of the lease holder agreed by the quorum of nodes, if any.
+    pub holder: Option<String>,
+    /// The number of nodes required for the quorum.
+    pub quorum: u32,
+    /// The state of each Redis node, or of each peer of the Raft replica.
+    pub nodes: Vec<LeaseNodeStatus>,
+    /// The last round of lease requests sent to all Redis nodes.
+    /// `None` for the Raft lease.
+    pub last_quorum_round: Option<QuorumRound>,
+    /// The height of the latest block committed by this node.
+    pub local_height: Option<BlockHeight>,
+    /// The height of the latest block in the replicated block stream.
+    pub stream_height: Option<BlockHeight>,
+    /// The number of blocks in the block stream not yet committed by this node.
+    pub stream_lag: Option<u32>,
+    /// The recent lease transitions of this node, from the oldest to the newest.
+    pub transitions: Vec<LeaseTransition>,
+}
+
+/// The state of a single node of the leader lease.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeaseNodeStatus {
+    /// The address of the node without credentials.
+    pub address: String,
+    /// `true` if the node answered the status request. For the Raft peer,
+    /// `true` if it answered the latest request of this replica.
+    pub reachable: bool,
+    /// The token of the lease holder seen by the node.
+    pub holder: Option<String>,
+    /// The latest epoch token issued by the node.
+    pub epoch: Option<u64>,
+    /// The height of the latest block in the block stream of the node.
+    pub stream_height: Option<BlockHeight>,
+    /// The reason why the node is unreachable.
+    pub error: Option<String>,
+}
+
+/// The kind of the request sent to all Redis nodes in a lease round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuorumRoundKind {
+    /// Checks that this node still holds the lease.
+    OwnershipCheck,
+    /// Tries to acquire the free lease.
+    Promotion,
+    /// Releases the lease held by this node.
+    Release,
+}
+
+/// The outcome of a round of lease requests sent to all Redis nodes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuorumRound {
+    /// The kind of the requests.
+    pub kind: QuorumRoundKind,
+    /// The number of nodes in the round.
+    pub nodes: u32,
+    /// The number of nodes that answered the request.
+    pub responded: u32,
+    /// The number of nodes that granted the request.
+    pub granted: u32,
+    /// `true` if the quorum of nodes granted the request.
+    pub quorum_reached: bool,
+    /// The time when the round finished.
+    pub time: SystemTime,
+}
+
+/// The kind of the change of the lease held by this node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeaseTransitionKind {
+    /// This node became the leader.
+    Acquired,
+    /// This node adopted a higher epoch token while being the leader.
+    EpochChanged,
+    /// This node lost the lease to another node or because of unreachable nodes.
+    Lost,
+    /// This node released the lease.
+    Released,
+}
+
+/// The change of the lease held by this node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeaseTransition {
+    /// The kind of the change.
+    pub kind: LeaseTransitionKind,
+    /// The epoch token of this node after the change.
+    pub epoch: Option<u64>,
+    /// The time of the change.
+    pub time: SystemTime,
+}
@@ -29,7 +29,10 @@ use std::{
         Formatter,
     },
     str::FromStr,
-    time::SystemTime,
+    time::{
+        Duration,
+        SystemTime,
+    },
 };
 
 pub use tai64::Tai64;
@@ -281,6 +284,30 @@ pub struct HeartbeatData {
     pub last_heartbeat: SystemTime,
 }
 
+/// Contains the reputation of a connected peer
+pub struct PeerReputation {
+    /// The libp2p peer id
+    pub id: PeerId,
+    /// `true` if the peer is one of the reserved peers
+    pub reserved: bool,
+    /// the current application reputation score of the peer
+    pub app_score: f64,
+    /// the current gossipsub score of the peer, if it is known
+    pub gossipsub_score: Option<f64>,
+    /// recent heartbeat from the peer
+    pub heartbeat_data: HeartbeatData,
+    /// the average time between the recent heartbeats of the peer
+    pub average_heartbeat_interval: Duration,
+}
+
+/// Contains the information about a peer banned by the node operator
+pub struct BannedPeer {
+    /// The libp2p peer id
+    pub id: PeerId,
+    /// the time left until the ban expires
+    pub remaining_ban_duration: Duration,
+}
+
 /// Type that represents the networkable transaction pool
 /// It serializes from an Arc pool transaction and deserializes to a transaction
 #[allow(clippy::large_enum_variant)]
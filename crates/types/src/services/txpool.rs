@@ -18,6 +18,7 @@ use crate::{
         UtxoId,
         field::{
             Inputs,
+            Maturity,
             Outputs,
             ScriptGasLimit,
             Tip,
@@ -146,6 +147,17 @@ impl PoolTransaction {
         }
     }
 
+    /// Returns the maturity block for a transaction.
+    pub fn maturity(&self) -> BlockHeight {
+        match self {
+            PoolTransaction::Script(tx, _) => tx.transaction().maturity(),
+            PoolTransaction::Create(tx, _) => tx.transaction().maturity(),
+            PoolTransaction::Upgrade(tx, _) => tx.transaction().maturity(),
+            PoolTransaction::Upload(tx, _) => tx.transaction().maturity(),
+            PoolTransaction::Blob(tx, _) => tx.transaction().maturity(),
+        }
+    }
+
     #[cfg(feature = "test-helpers")]
     fn id_inner(&self) -> TxId {
         match self {
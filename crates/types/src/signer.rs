@@ -1,4 +1,7 @@
-//! Block and generic data signing using a secret key or AWS KMS
+//! Block and generic data signing using a secret key, AWS KMS or a remote signer
+
+#[cfg(feature = "remote-signer")]
+pub mod remote;
 
 use crate::{
     blockchain::primitives::SecretKeyWrapper,
@@ -44,6 +47,9 @@ pub enum SignMode {
         /// The cached public key bytes.
         cached_public_key_bytes: Vec<u8>,
     },
+    /// Sign using a remote signing service
+    #[cfg(feature = "remote-signer")]
+    Remote(remote::RemoteSigner),
 }
 
 impl SignMode {
@@ -66,6 +72,8 @@ impl SignMode {
                 client,
                 cached_public_key_bytes,
             } => sign_with_kms(client, key_id, cached_public_key_bytes, message).await?,
+            #[cfg(feature = "remote-signer")]
+            SignMode::Remote(signer) => signer.sign_prehashed(message).await?,
         };
         Ok(signature)
     }
@@ -94,6 +102,9 @@ impl SignMode {
                     k256::PublicKey::from_public_key_der(cached_public_key_bytes)?;
                 Ok(Some(PublicKey::from(k256_public_key)))
             }
+
+            #[cfg(feature = "remote-signer")]
+            SignMode::Remote(signer) => Ok(Some(PublicKey::from(*signer.public_key()))),
         }
     }
 
@@ -119,6 +130,9 @@ impl SignMode {
                     k256::PublicKey::from_public_key_der(cached_public_key_bytes)?;
                 Ok(Some(k256_public_key.into()))
             }
+
+            #[cfg(feature = "remote-signer")]
+            SignMode::Remote(signer) => Ok(Some((*signer.public_key()).into())),
         }
     }
 
@@ -136,13 +150,7 @@ async fn sign_with_kms(
     public_key_bytes: &[u8],
     message: Message,
 ) -> anyhow::Result<Signature> {
-    use k256::{
-        ecdsa::{
-            RecoveryId,
-            VerifyingKey,
-        },
-        pkcs8::DecodePublicKey,
-    };
+    use k256::pkcs8::DecodePublicKey;
 
     let reply = client
         .sign()
@@ -160,23 +168,41 @@ async fn sign_with_kms(
     // https://stackoverflow.com/a/71475108
     let sig = k256::ecdsa::Signature::from_der(&signature_der)
         .map_err(|_| anyhow!("invalid DER signature from AWS KMS"))?;
+    let correct_public_key = k256::PublicKey::from_public_key_der(public_key_bytes)
+        .map_err(|_| anyhow!("invalid DER public key from AWS KMS"))?;
+
+    recoverable_signature(sig, &message, &correct_public_key)
+}
+
+/// Converts the ECDSA signature produced by an external signer into
+/// the recoverable [`Signature`] of the `public_key`.
+#[cfg(any(feature = "aws-kms", feature = "remote-signer"))]
+fn recoverable_signature(
+    sig: k256::ecdsa::Signature,
+    message: &Message,
+    public_key: &k256::PublicKey,
+) -> anyhow::Result<Signature> {
+    use k256::ecdsa::{
+        RecoveryId,
+        VerifyingKey,
+    };
+
     let sig = sig.normalize_s().unwrap_or(sig);
 
     // This is a hack to get the recovery id. The signature should be normalized
-    // before computing the recovery id, but aws kms doesn't support this, and
-    // instead always computes the recovery id from non-normalized signature.
+    // before computing the recovery id, but external signers like aws kms don't
+    // support this, and instead always compute the recovery id from non-normalized
+    // signature, if they return it at all.
     // So instead the recovery id is determined by checking which variant matches
     // the original public key.
 
     let recid1 = RecoveryId::new(false, false);
     let recid2 = RecoveryId::new(true, false);
 
-    let rec1 = VerifyingKey::recover_from_prehash(&*message, &sig, recid1);
-    let rec2 = VerifyingKey::recover_from_prehash(&*message, &sig, recid2);
+    let rec1 = VerifyingKey::recover_from_prehash(&**message, &sig, recid1);
+    let rec2 = VerifyingKey::recover_from_prehash(&**message, &sig, recid2);
 
-    let correct_public_key = k256::PublicKey::from_public_key_der(public_key_bytes)
-        .map_err(|_| anyhow!("invalid DER public key from AWS KMS"))?
-        .into();
+    let correct_public_key = VerifyingKey::from(public_key);
 
     let recovery_id = if rec1.map(|r| r == correct_public_key).unwrap_or(false) {
         recid1
@@ -211,6 +237,8 @@ mod tests {
 
     #[cfg(not(feature = "aws-kms"))]
     use aws_config as _;
+    #[cfg(not(feature = "remote-signer-mock"))]
+    use rcgen as _;
 
     #[tokio::test]
     async fn sign_mode_is_available() {
@@ -0,0 +1,532 @@
+//! Signing using a remote signing service over HTTP(S).
+//!
+//! The signing service keeps the secret key, the node only sends the prehashed
+//! messages to sign. The service exposes the following endpoints for each key:
+//!
+//! - `GET {url}/v1/keys/{key_id}` returns the public key of the key as
+//!   `{"public_key": "<hex>"}`. The public key is either the 64 bytes of the
+//!   uncompressed point without the prefix, or the SEC1 encoded point.
+//! - `POST {url}/v1/keys/{key_id}/sign` with `{"digest": "<hex>"}` signs the
+//!   32 bytes digest with ECDSA over secp256k1 and returns
+//!   `{"signature": "<hex>"}`. The signature is either the 64 bytes `r || s`
+//!   or DER encoded. The recovery id is derived locally from the public key.
+//!
+//! Signing the same digest again is harmless, so failed requests are retried.
+
+#[cfg(feature = "remote-signer-mock")]
+pub mod mock;
+
+use crate::{
+    fuel_crypto::Message,
+    fuel_vm::Signature,
+};
+use anyhow::{
+    Context,
+    anyhow,
+};
+use reqwest::{
+    Certificate,
+    Identity,
+    RequestBuilder,
+    StatusCode,
+    Url,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+    de::DeserializeOwned,
+};
+use std::{
+    future::Future,
+    time::Duration,
+};
+
+/// The default timeout of a single request to the signing service.
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+/// The default timeout of establishing the connection to the signing service.
+pub const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(2);
+/// The default number of retries of a failed request.
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+/// The default delay before the first retry, doubled after each retry.
+pub const DEFAULT_RETRY_BACKOFF: Duration = Duration::from_millis(100);
+
+/// The configuration of the [`RemoteSigner`].
+#[derive(Clone, Debug)]
+pub struct RemoteSignerConfig {
+    /// The base URL of the signing service, e.g. `https://signer.internal:8443`.
+    pub url: String,
+    /// The identifier of the key in the signing service.
+    pub key_id: String,
+    /// PEM encoded CA certificates used to verify the signing service.
+    /// If set, only these certificates are trusted instead of the system roots.
+    pub ca_cert_pem: Option<Vec<u8>>,
+    /// PEM encoded client certificate chain and its private key,
+    /// used to authenticate the node to the signing service.
+    pub client_identity_pem: Option<Vec<u8>>,
+    /// The timeout of a single request.
+    pub request_timeout: Duration,
+    /// The timeout of establishing the connection.
+    pub connect_timeout: Duration,
+    /// The number of retries of a request that failed because of the transport,
+    /// a timeout or a server error.
+    pub max_retries: u32,
+    /// The delay before the first retry, doubled after each retry.
+    pub retry_backoff: Duration,
+}
+
+impl RemoteSignerConfig {
+    /// Creates the configuration of the `key_id` in the signing service at `url`
+    /// with the default timeouts and retries.
+    pub fn new(url: impl Into<String>, key_id: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            key_id: key_id.into(),
+            ca_cert_pem: None,
+            client_identity_pem: None,
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+            max_retries: DEFAULT_MAX_RETRIES,
+            retry_backoff: DEFAULT_RETRY_BACKOFF,
+        }
+    }
+}
+
+/// The client of the remote signing service.
+/// The public key is discovered once on [`RemoteSigner::connect`] and cached.
+#[derive(Clone, Debug)]
+pub struct RemoteSigner {
+    client: reqwest::Client,
+    sign_url: Url,
+    max_retries: u32,
+    retry_backoff: Duration,
+    public_key: k256::PublicKey,
+}
+
+#[derive(Deserialize)]
+struct PublicKeyResponse {
+    public_key: String,
+}
+
+#[derive(Serialize)]
+struct SignRequest {
+    digest: String,
+}
+
+#[derive(Deserialize)]
+struct SignResponse {
+    signature: String,
+}
+
+enum RequestError {
+    /// The request may succeed if repeated.
+    Retryable(anyhow::Error),
+    /// The request is rejected by the signing service.
+    Fatal(anyhow::Error),
+}
+
+impl RemoteSigner {
+    /// Creates the client of the signing service and requests the public key.
+    pub async fn connect(config: RemoteSignerConfig) -> anyhow::Result<Self> {
+        let mut builder = reqwest::Client::builder()
+            .timeout(config.request_timeout)
+            .connect_timeout(config.connect_timeout);
+        if let Some(ca_cert_pem) = &config.ca_cert_pem {
+            let certs = Certificate::from_pem_bundle(ca_cert_pem)
+                .context("Invalid CA certificate of the remote signer")?;
+            if certs.is_empty() {
+                anyhow::bail!("No CA certificate found for the remote signer");
+            }
+            builder = builder.tls_certs_only(certs);
+        }
+        if let Some(client_identity_pem) = &config.client_identity_pem {
+            let identity = Identity::from_pem(client_identity_pem)
+                .context("Invalid client certificate or key for the remote signer")?;
+            builder = builder.identity(identity);
+        }
+        let client = builder
+            .build()
+            .context("Failed to create the remote signer client")?;
+
+        let mut key_url = Url::parse(&config.url)
+            .with_context(|| format!("Invalid remote signer URL {}", config.url))?;
+        key_url
+            .path_segments_mut()
+            .map_err(|_| anyhow!("Invalid remote signer URL {}", config.url))?
+            .pop_if_empty()
+            .extend(["v1", "keys", config.key_id.as_str()]);
+        let mut sign_url = key_url.clone();
+        sign_url
+            .path_segments_mut()
+            .map_err(|_| anyhow!("Invalid remote signer URL {}", config.url))?
+            .push("sign");
+
+        let response: PublicKeyResponse =
+            with_retries(config.max_retries, config.retry_backoff, || {
+                send(client.get(key_url.clone()))
+            })
+            .await
+            .context("Failed to get the public key from the remote signer")?;
+        let public_key = parse_public_key(&response.public_key)?;
+
+        Ok(Self {
+            client,
+            sign_url,
+            max_retries: config.max_retries,
+            retry_backoff: config.retry_backoff,
+            public_key,
+        })
+    }
+
+    /// The public key of the signing key.
+    pub fn public_key(&self) -> &k256::PublicKey {
+        &self.public_key
+    }
+
+    /// Signs the prehashed `message` and verifies that the signature
+    /// belongs to the public key of the signing key.
+    pub async fn sign_prehashed(&self, message: Message) -> anyhow::Result<Signature> {
+        let body = SignRequest {
+            digest: hex::encode(*message),
+        };
+        let response: SignResponse =
+            with_retries(self.max_retries, self.retry_backoff, || {
+                send(self.client.post(self.sign_url.clone()).json(&body))
+            })
+            .await
+            .context("Failed to sign with the remote signer")?;
+        let signature = parse_signature(&response.signature)?;
+        super::recoverable_signature(signature, &message, &self.public_key)
+    }
+}
+
+async fn with_retries<T, F, Fut>(
+    max_retries: u32,
+    retry_backoff: Duration,
+    request: F,
+) -> anyhow::Result<T>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<T, RequestError>>,
+{
+    let mut attempt: u32 = 0;
+    loop {
+        match request().await {
+            Ok(response) => return Ok(response),
+            Err(RequestError::Retryable(_)) if attempt < max_retries => {
+                let backoff = retry_backoff.saturating_mul(2u32.saturating_pow(attempt));
+                tokio::time::sleep(backoff).await;
+                attempt = attempt.saturating_add(1);
+            }
+            Err(RequestError::Retryable(err)) => {
+                return Err(
+                    err.context(format!("Request failed after {max_retries} retries"))
+                )
+            }
+            Err(RequestError::Fatal(err)) => return Err(err),
+        }
+    }
+}
+
+async fn send<T: DeserializeOwned>(request: RequestBuilder) -> Result<T, RequestError> {
+    let response = request
+        .send()
+        .await
+        .map_err(|err| RequestError::Retryable(err.into()))?;
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        let err = anyhow!("The remote signer responded with {status}: {body}");
+        return if status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS {
+            Err(RequestError::Retryable(err))
+        } else {
+            Err(RequestError::Fatal(err))
+        }
+    }
+    response.json::<T>().await.map_err(|err| {
+        if err.is_timeout() {
+            RequestError::Retryable(err.into())
+        } else {
+            RequestError::Fatal(anyhow!("Invalid response of the remote signer: {err}"))
+        }
+    })
+}
+
+fn decode_hex(value: &str) -> anyhow::Result<Vec<u8>> {
+    let value = value.strip_prefix("0x").unwrap_or(value);
+    hex::decode(value).map_err(|err| anyhow!("Invalid hex encoding: {err}"))
+}
+
+fn parse_public_key(value: &str) -> anyhow::Result<k256::PublicKey> {
+    let mut bytes = decode_hex(value)?;
+    if bytes.len() == 64 {
+        // The uncompressed point without the SEC1 prefix
+        bytes.insert(0, 0x04);
+    }
+    k256::PublicKey::from_sec1_bytes(&bytes)
+        .map_err(|_| anyhow!("Invalid public key from the remote signer"))
+}
+
+fn parse_signature(value: &str) -> anyhow::Result<k256::ecdsa::Signature> {
+    let bytes = decode_hex(value)?;
+    let signature = if bytes.len() == 64 {
+        k256::ecdsa::Signature::from_slice(&bytes)
+    } else {
+        k256::ecdsa::Signature::from_der(&bytes)
+    };
+    signature.map_err(|_| anyhow!("Invalid signature from the remote signer"))
+}
+
+#[allow(non_snake_case)]
+#[cfg(all(test, feature = "remote-signer-mock"))]
+mod tests {
+    use super::{
+        mock::{
+            MockRemoteSigner,
+            MockRemoteSignerTls,
+        },
+        *,
+    };
+    use crate::{
+        fuel_crypto::{
+            PublicKey,
+            SecretKey,
+        },
+        signer::SignMode,
+    };
+    use rand::{
+        SeedableRng,
+        rngs::StdRng,
+    };
+
+    const KEY_ID: &str = "block-production";
+
+    fn secret_key() -> SecretKey {
+        let mut rng = StdRng::seed_from_u64(2322);
+        SecretKey::random(&mut rng)
+    }
+
+    fn config(mock: &MockRemoteSigner) -> RemoteSignerConfig {
+        let mut config = RemoteSignerConfig::new(mock.url(), KEY_ID);
+        config.retry_backoff = Duration::from_millis(10);
+        config
+    }
+
+    struct TestCertificates {
+        ca_pem: Vec<u8>,
+        server: MockRemoteSignerTls,
+        client_identity_pem: Vec<u8>,
+    }
+
+    fn certificates() -> TestCertificates {
+        use rcgen::{
+            BasicConstraints,
+            Certificate,
+            CertificateParams,
+            ExtendedKeyUsagePurpose,
+            IsCa,
+        };
+
+        let mut ca_params = CertificateParams::new(Vec::<String>::new());
+        ca_params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+        let ca = Certificate::from_params(ca_params).unwrap();
+
+        let mut server_params = CertificateParams::new(vec!["localhost".to_string()]);
+        server_params.extended_key_usages = vec![ExtendedKeyUsagePurpose::ServerAuth];
+        let server = Certificate::from_params(server_params).unwrap();
+
+        let mut client_params = CertificateParams::new(vec!["producer".to_string()]);
+        client_params.extended_key_usages = vec![ExtendedKeyUsagePurpose::ClientAuth];
+        let client = Certificate::from_params(client_params).unwrap();
+
+        let ca_pem = ca.serialize_pem().unwrap().into_bytes();
+        let client_identity_pem = format!(
+            "{}{}",
+            client.serialize_pem_with_signer(&ca).unwrap(),
+            client.serialize_private_key_pem()
+        )
+        .into_bytes();
+        TestCertificates {
+            server: MockRemoteSignerTls {
+                cert_chain_pem: server
+                    .serialize_pem_with_signer(&ca)
+                    .unwrap()
+                    .into_bytes(),
+                key_pem: server.serialize_private_key_pem().into_bytes(),
+                client_ca_pem: Some(ca_pem.clone()),
+            },
+            ca_pem,
+            client_identity_pem,
+        }
+    }
+
+    #[tokio::test]
+    async fn sign_message__when_remote_signer_then_signature_recovers_public_key() {
+        // given
+        let secret_key = secret_key();
+        let mock = MockRemoteSigner::spawn(KEY_ID, secret_key).await.unwrap();
+        let signer =
+            SignMode::Remote(RemoteSigner::connect(config(&mock)).await.unwrap());
+        let message = Message::new(b"block id");
+
+        // when
+        let signature = signer.sign_message(message).await.unwrap();
+
+        // then
+        assert_eq!(signer.public_key().unwrap(), Some(secret_key.public_key()));
+        assert_eq!(
+            signature.recover(&message).unwrap(),
+            secret_key.public_key()
+        );
+        assert!(signer.verifying_key().unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn connect__when_key_is_unknown_then_fails() {
+        // given
+        let mock = MockRemoteSigner::spawn(KEY_ID, secret_key()).await.unwrap();
+        let mut config = config(&mock);
+        config.key_id = "unknown".to_string();
+
+        // when
+        let result = RemoteSigner::connect(config).await;
+
+        // then
+        let err = result.expect_err("Unknown key should be rejected");
+        assert!(format!("{err:#}").contains("404"), "{err:#}");
+    }
+
+    #[tokio::test]
+    async fn sign_message__when_service_fails_transiently_then_retries() {
+        // given
+        let mock = MockRemoteSigner::spawn(KEY_ID, secret_key()).await.unwrap();
+        let signer = RemoteSigner::connect(config(&mock)).await.unwrap();
+        mock.fail_next_requests(usize::try_from(DEFAULT_MAX_RETRIES).unwrap());
+
+        // when
+        let result = signer.sign_prehashed(Message::new(b"block id")).await;
+
+        // then
+        assert!(result.is_ok(), "{result:?}");
+        assert_eq!(
+            mock.sign_requests(),
+            usize::try_from(DEFAULT_MAX_RETRIES).unwrap() + 1
+        );
+    }
+
+    #[tokio::test]
+    async fn sign_message__when_service_fails_longer_than_retries_then_fails() {
+        // given
+        let mock = MockRemoteSigner::spawn(KEY_ID, secret_key()).await.unwrap();
+        let mut config = config(&mock);
+        config.max_retries = 1;
+        let signer = RemoteSigner::connect(config).await.unwrap();
+        mock.fail_next_requests(2);
+
+        // when
+        let result = signer.sign_prehashed(Message::new(b"block id")).await;
+
+        // then
+        assert!(result.is_err());
+        assert_eq!(mock.sign_requests(), 2);
+    }
+
+    #[tokio::test]
+    async fn sign_message__when_service_is_slower_than_timeout_then_fails() {
+        // given
+        let mock = MockRemoteSigner::spawn(KEY_ID, secret_key()).await.unwrap();
+        let mut config = config(&mock);
+        config.request_timeout = Duration::from_millis(100);
+        config.max_retries = 0;
+        let signer = RemoteSigner::connect(config).await.unwrap();
+        mock.set_response_delay(Duration::from_secs(1));
+
+        // when
+        let result = signer.sign_prehashed(Message::new(b"block id")).await;
+
+        // then
+        let err = result.expect_err("Slow response should time out");
+        assert!(format!("{err:#}").contains("timed out"), "{err:#}");
+    }
+
+    #[tokio::test]
+    async fn connect__when_client_certificate_is_required_then_authenticates_with_identity()
+     {
+        // given
+        let secret_key = secret_key();
+        let certificates = certificates();
+        let mock = MockRemoteSigner::spawn_with_tls(
+            KEY_ID,
+            secret_key,
+            certificates.server.clone(),
+        )
+        .await
+        .unwrap();
+        let mut config = config(&mock);
+        config.ca_cert_pem = Some(certificates.ca_pem.clone());
+        config.client_identity_pem = Some(certificates.client_identity_pem.clone());
+
+        // when
+        let signer = RemoteSigner::connect(config).await.unwrap();
+
+        // then
+        let message = Message::new(b"block id");
+        let signature = signer.sign_prehashed(message).await.unwrap();
+        assert_eq!(
+            signature.recover(&message).unwrap(),
+            secret_key.public_key()
+        );
+    }
+
+    #[tokio::test]
+    async fn connect__when_client_certificate_is_missing_then_fails() {
+        // given
+        let certificates = certificates();
+        let mock =
+            MockRemoteSigner::spawn_with_tls(KEY_ID, secret_key(), certificates.server)
+                .await
+                .unwrap();
+        let mut config = config(&mock);
+        config.ca_cert_pem = Some(certificates.ca_pem);
+        config.max_retries = 0;
+
+        // when
+        let result = RemoteSigner::connect(config).await;
+
+        // then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse_public_key__accepts_raw_and_sec1_encodings() {
+        // given
+        let public_key = secret_key().public_key();
+        let k256_public_key =
+            parse_public_key(&hex::encode(public_key.as_ref())).unwrap();
+
+        // when
+        let compressed = k256_public_key.to_sec1_bytes();
+        let parsed = parse_public_key(&format!("0x{}", hex::encode(compressed))).unwrap();
+
+        // then
+        assert_eq!(PublicKey::from(k256_public_key), public_key);
+        assert_eq!(parsed, k256_public_key);
+    }
+
+    #[test]
+    fn parse_signature__accepts_raw_and_der_encodings() {
+        // given
+        let signing_key =
+            k256::ecdsa::SigningKey::from(k256::SecretKey::from(&secret_key()));
+        let (signature, _) = signing_key
+            .sign_prehash_recoverable(&*Message::new(b"block id"))
+            .unwrap();
+
+        // when
+        let raw = parse_signature(&hex::encode(signature.to_bytes())).unwrap();
+        let der = parse_signature(&hex::encode(signature.to_der().as_bytes())).unwrap();
+
+        // then
+        assert_eq!(raw, signature);
+        assert_eq!(der, signature);
+    }
+}
@@ -0,0 +1,342 @@
+//! A local signing service implementing the API of the [`super::RemoteSigner`],
+//! used in tests. Signs with a local secret key and can inject failures and delays.
+
+use crate::fuel_crypto::SecretKey;
+use anyhow::{
+    Context,
+    anyhow,
+};
+use k256::ecdsa::{
+    SigningKey,
+    signature::hazmat::PrehashSigner,
+};
+use std::{
+    net::{
+        Ipv4Addr,
+        SocketAddr,
+    },
+    sync::{
+        Arc,
+        atomic::{
+            AtomicU64,
+            AtomicUsize,
+            Ordering,
+        },
+    },
+    time::Duration,
+};
+use tokio::{
+    io::{
+        AsyncRead,
+        AsyncReadExt,
+        AsyncWrite,
+        AsyncWriteExt,
+    },
+    net::TcpListener,
+    task::JoinHandle,
+};
+use tokio_rustls::{
+    TlsAcceptor,
+    rustls::{
+        self,
+        RootCertStore,
+        ServerConfig,
+        pki_types::{
+            CertificateDer,
+            PrivateKeyDer,
+            pem::PemObject,
+        },
+        server::WebPkiClientVerifier,
+    },
+};
+
+/// The maximum size of the head of the request accepted by the mock.
+const MAX_REQUEST_HEAD_SIZE: usize = 16 * 1024;
+
+/// The TLS configuration of the [`MockRemoteSigner`].
+#[derive(Clone, Debug)]
+pub struct MockRemoteSignerTls {
+    /// PEM encoded certificate chain of the server, issued for `localhost`.
+    pub cert_chain_pem: Vec<u8>,
+    /// PEM encoded private key of the server.
+    pub key_pem: Vec<u8>,
+    /// PEM encoded CA certificates of the clients.
+    /// If set, the clients must authenticate with a certificate issued by them.
+    pub client_ca_pem: Option<Vec<u8>>,
+}
+
+#[derive(Default)]
+struct MockState {
+    failures: AtomicUsize,
+    delay_ms: AtomicU64,
+    sign_requests: AtomicUsize,
+}
+
+struct MockService {
+    key_id: String,
+    signing_key: SigningKey,
+    state: Arc<MockState>,
+}
+
+struct Request {
+    method: String,
+    path: String,
+    body: Vec<u8>,
+}
+
+/// The mock of the remote signing service listening on localhost.
+/// The server stops when the mock is dropped.
+pub struct MockRemoteSigner {
+    url: String,
+    state: Arc<MockState>,
+    server: JoinHandle<()>,
+}
+
+impl MockRemoteSigner {
+    /// Starts the mock serving plain HTTP, which signs with `secret_key`
+    /// under the `key_id`.
+    pub async fn spawn(
+        key_id: impl Into<String>,
+        secret_key: SecretKey,
+    ) -> anyhow::Result<Self> {
+        Self::start(key_id.into(), secret_key, None).await
+    }
+
+    /// Starts the mock serving HTTPS, which signs with `secret_key`
+    /// under the `key_id`.
+    pub async fn spawn_with_tls(
+        key_id: impl Into<String>,
+        secret_key: SecretKey,
+        tls: MockRemoteSignerTls,
+    ) -> anyhow::Result<Self> {
+        let acceptor = TlsAcceptor::from(Arc::new(server_config(&tls)?));
+        Self::start(key_id.into(), secret_key, Some(acceptor)).await
+    }
+
+    async fn start(
+        key_id: String,
+        secret_key: SecretKey,
+        acceptor: Option<TlsAcceptor>,
+    ) -> anyhow::Result<Self> {
+        let listener =
+            TcpListener::bind(SocketAddr::from((Ipv4Addr::LOCALHOST, 0))).await?;
+        let port = listener.local_addr()?.port();
+        let url = if acceptor.is_some() {
+            format!("https://localhost:{port}")
+        } else {
+            format!("http://127.0.0.1:{port}")
+        };
+        let state = Arc::new(MockState::default());
+        let service = Arc::new(MockService {
+            key_id,
+            signing_key: SigningKey::from(k256::SecretKey::from(&secret_key)),
+            state: state.clone(),
+        });
+
+        let server = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let service = service.clone();
+                let acceptor = acceptor.clone();
+                tokio::spawn(async move {
+                    // The client is responsible for handling broken connections
+                    let _ = match acceptor {
+                        Some(acceptor) => match acceptor.accept(stream).await {
+                            Ok(stream) => service.serve_connection(stream).await,
+                            Err(err) => Err(err),
+                        },
+                        None => service.serve_connection(stream).await,
+                    };
+                });
+            }
+        });
+
+        Ok(Self { url, state, server })
+    }
+
+    /// The base URL of the mock.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Responds to the next `count` requests with `503 Service Unavailable`.
+    pub fn fail_next_requests(&self, count: usize) {
+        self.state.failures.store(count, Ordering::SeqCst);
+    }
+
+    /// Delays all following responses by `delay`.
+    pub fn set_response_delay(&self, delay: Duration) {
+        let delay_ms = u64::try_from(delay.as_millis()).unwrap_or(u64::MAX);
+        self.state.delay_ms.store(delay_ms, Ordering::SeqCst);
+    }
+
+    /// The number of received signing requests, including the failed ones.
+    pub fn sign_requests(&self) -> usize {
+        self.state.sign_requests.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for MockRemoteSigner {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
+
+fn server_config(tls: &MockRemoteSignerTls) -> anyhow::Result<ServerConfig> {
+    let provider = Arc::new(rustls::crypto::aws_lc_rs::default_provider());
+    let certs = CertificateDer::pem_slice_iter(&tls.cert_chain_pem)
+        .collect::<Result<Vec<_>, _>>()
+        .context("Invalid server certificate")?;
+    let key =
+        PrivateKeyDer::from_pem_slice(&tls.key_pem).context("Invalid server key")?;
+    let builder = ServerConfig::builder_with_provider(provider.clone())
+        .with_safe_default_protocol_versions()?;
+    let builder = match &tls.client_ca_pem {
+        Some(client_ca_pem) => {
+            let mut roots = RootCertStore::empty();
+            for cert in CertificateDer::pem_slice_iter(client_ca_pem) {
+                roots.add(cert.context("Invalid client CA certificate")?)?;
+            }
+            let verifier =
+                WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider)
+                    .build()?;
+            builder.with_client_cert_verifier(verifier)
+        }
+        None => builder.with_no_client_auth(),
+    };
+    Ok(builder.with_single_cert(certs, key)?)
+}
+
+impl MockService {
+    async fn serve_connection<S>(&self, mut stream: S) -> std::io::Result<()>
+    where
+        S: AsyncRead + AsyncWrite + Unpin,
+    {
+        let (status, body) = match read_request(&mut stream).await {
+            Ok(request) => self.handle(request).await,
+            Err(err) => (400, error_body(err)),
+        };
+        let reason = match status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            _ => "Service Unavailable",
+        };
+        let response = format!(
+            "HTTP/1.1 {status} {reason}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        stream.write_all(response.as_bytes()).await?;
+        stream.shutdown().await
+    }
+
+    async fn handle(&self, request: Request) -> (u16, String) {
+        let is_sign_request = request.method == "POST";
+        if is_sign_request {
+            self.state.sign_requests.fetch_add(1, Ordering::SeqCst);
+        }
+        let delay_ms = self.state.delay_ms.load(Ordering::SeqCst);
+        if delay_ms > 0 {
+            tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+        }
+        let failed = self
+            .state
+            .failures
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |failures| {
+                failures.checked_sub(1)
+            })
+            .is_ok();
+        if failed {
+            return (503, error_body(anyhow!("Injected failure")))
+        }
+
+        let key_path = format!("/v1/keys/{}", self.key_id);
+        let sign_path = format!("{key_path}/sign");
+        match (request.method.as_str(), request.path.as_str()) {
+            ("GET", path) if path == key_path => {
+                let public_key = self.signing_key.verifying_key().to_encoded_point(false);
+                let body = serde_json::json!({
+                    "public_key": hex::encode(public_key.as_bytes()),
+                });
+                (200, body.to_string())
+            }
+            ("POST", path) if path == sign_path => match self.sign(&request.body) {
+                Ok(signature) => {
+                    let body = serde_json::json!({ "signature": signature });
+                    (200, body.to_string())
+                }
+                Err(err) => (400, error_body(err)),
+            },
+            _ => (404, error_body(anyhow!("Unknown key or endpoint"))),
+        }
+    }
+
+    fn sign(&self, body: &[u8]) -> anyhow::Result<String> {
+        let request: serde_json::Value = serde_json::from_slice(body)?;
+        let digest = request
+            .get("digest")
+            .and_then(|digest| digest.as_str())
+            .ok_or_else(|| anyhow!("Missing digest"))?;
+        let digest = hex::decode(digest.strip_prefix("0x").unwrap_or(digest))?;
+        if digest.len() != 32 {
+            anyhow::bail!("The digest must be 32 bytes");
+        }
+        let signature: k256::ecdsa::Signature = self.signing_key.sign_prehash(&digest)?;
+        Ok(hex::encode(signature.to_der().as_bytes()))
+    }
+}
+
+fn error_body(err: impl core::fmt::Display) -> String {
+    serde_json::json!({ "error": err.to_string() }).to_string()
+}
+
+async fn read_request<S>(stream: &mut S) -> anyhow::Result<Request>
+where
+    S: AsyncRead + Unpin,
+{
+    let mut buffer = Vec::new();
+    let head_end = loop {
+        if let Some(position) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+            break position;
+        }
+        if buffer.len() > MAX_REQUEST_HEAD_SIZE {
+            anyhow::bail!("The request head is too large");
+        }
+        let mut chunk = [0u8; 1024];
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            anyhow::bail!("The connection is closed before the end of the request");
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+    };
+
+    let head = std::str::from_utf8(&buffer[..head_end])?;
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines
+        .next()
+        .ok_or_else(|| anyhow!("Missing request line"))?
+        .split(' ');
+    let method = request_line.next().unwrap_or_default().to_string();
+    let path = request_line.next().unwrap_or_default().to_string();
+    let content_length = lines
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("content-length"))
+        .map(|(_, value)| value.trim().parse::<usize>())
+        .transpose()?
+        .unwrap_or(0);
+
+    let mut body = buffer.split_off(head_end.saturating_add(4));
+    if content_length > MAX_REQUEST_HEAD_SIZE {
+        anyhow::bail!("The request body is too large");
+    }
+    while body.len() < content_length {
+        let mut chunk = [0u8; 1024];
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            anyhow::bail!("The connection is closed before the end of the body");
+        }
+        body.extend_from_slice(&chunk[..read]);
+    }
+    body.truncate(content_length);
+
+    Ok(Request { method, path, body })
+}
@@ -0,0 +1,209 @@
+use fuel_core::{
+    fuel_core_graphql_api::authorization::API_KEYS_RELOAD_INTERVAL,
+    service::{
+        Config,
+        FuelService,
+    },
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_types::{
+    fuel_asm::{
+        RegId,
+        op,
+    },
+    fuel_tx::{
+        Transaction,
+        TransactionBuilder,
+    },
+    services::executor::StateOverrides,
+};
+use tempfile::NamedTempFile;
+
+const INTERNAL_KEY: &str = "internal";
+const PUBLIC_KEY: &str = "public";
+
+fn write_api_keys(file: &NamedTempFile, content: &str) {
+    std::fs::write(file.path(), content).unwrap();
+}
+
+async fn node_with_api_keys(file: &NamedTempFile) -> FuelService {
+    let mut config = Config::local_node();
+    // Only API keys can use privileged operations.
+    config.debug = false;
+    config.graphql_config.api_keys_path = Some(file.path().to_path_buf());
+    FuelService::new_node(config).await.unwrap()
+}
+
+fn client_with_api_key(srv: &FuelService, api_key: &str) -> FuelClient {
+    let mut client = FuelClient::from(srv.bound_address);
+    client.with_api_key(api_key);
+    client
+}
+
+#[tokio::test]
+async fn produce_blocks__requires_api_key_with_block_production_role() {
+    // Given
+    let file = NamedTempFile::new().unwrap();
+    write_api_keys(
+        &file,
+        r#"{ "internal": ["block_production"], "public": [] }"#,
+    );
+    let srv = node_with_api_keys(&file).await;
+    let anonymous = FuelClient::from(srv.bound_address);
+    let public = client_with_api_key(&srv, PUBLIC_KEY);
+    let internal = client_with_api_key(&srv, INTERNAL_KEY);
+
+    // When
+    let anonymous_result = anonymous.produce_blocks(1, None).await;
+    let public_result = public.produce_blocks(1, None).await;
+    let internal_result = internal.produce_blocks(1, None).await;
+
+    // Then
+    let err = anonymous_result.expect_err("Anonymous client can't produce blocks");
+    assert!(err.to_string().contains("block_production"), "{err}");
+    public_result.expect_err("The key without the role can't produce blocks");
+    assert_eq!(*internal_result.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn start_session__requires_api_key_with_debugger_role() {
+    // Given
+    let file = NamedTempFile::new().unwrap();
+    write_api_keys(&file, r#"{ "internal": ["debugger"] }"#);
+    let srv = node_with_api_keys(&file).await;
+    let anonymous = FuelClient::from(srv.bound_address);
+    let internal = client_with_api_key(&srv, INTERNAL_KEY);
+
+    // When
+    let anonymous_result = anonymous.start_session().await;
+    let internal_result = internal.start_session().await;
+
+    // Then
+    anonymous_result.expect_err("Anonymous client can't use the debugger");
+    let session = internal_result.unwrap();
+    assert!(internal.end_session(&session).await.unwrap());
+}
+
+fn script_tx() -> Transaction {
+    TransactionBuilder::script(vec![op::ret(RegId::ONE)].into_iter().collect(), vec![])
+        .script_gas_limit(1_000_000)
+        .add_fee_input()
+        .finalize_as_transaction()
+}
+
+#[tokio::test]
+async fn dry_run__without_utxo_validation_requires_api_key_with_role() {
+    // Given
+    let file = NamedTempFile::new().unwrap();
+    write_api_keys(&file, r#"{ "internal": ["unchecked_dry_run"] }"#);
+    let srv = node_with_api_keys(&file).await;
+    let anonymous = FuelClient::from(srv.bound_address);
+    let internal = client_with_api_key(&srv, INTERNAL_KEY);
+    let tx = script_tx();
+    let txs = std::slice::from_ref(&tx);
+
+    // When
+    let anonymous_result = anonymous
+        .dry_run_opt(txs, Some(false), None, None, None)
+        .await;
+    let internal_result = internal
+        .dry_run_opt(txs, Some(false), None, None, None)
+        .await;
+
+    // Then
+    let err = anonymous_result.expect_err("Anonymous client can't disable validation");
+    assert!(err.to_string().contains("unchecked_dry_run"), "{err}");
+    assert_eq!(internal_result.unwrap().len(), 1);
+    let checked_result = anonymous.dry_run(txs).await;
+    let err = checked_result.expect_err("The fee input doesn't exist");
+    assert!(!err.to_string().contains("unchecked_dry_run"), "{err}");
+}
+
+#[tokio::test]
+async fn dry_run__with_state_overrides_requires_api_key_with_role() {
+    // Given
+    let file = NamedTempFile::new().unwrap();
+    write_api_keys(&file, r#"{ "internal": ["unchecked_dry_run"] }"#);
+    let srv = node_with_api_keys(&file).await;
+    let anonymous = FuelClient::from(srv.bound_address);
+    let internal = client_with_api_key(&srv, INTERNAL_KEY);
+    let tx = script_tx();
+    let txs = std::slice::from_ref(&tx);
+    let overrides = || Some(StateOverrides::default());
+
+    // When
+    let anonymous_result = anonymous
+        .dry_run_opt(txs, None, None, None, overrides())
+        .await;
+    let internal_result = internal
+        .dry_run_opt(txs, Some(false), None, None, overrides())
+        .await;
+
+    // Then
+    let err = anonymous_result.expect_err("Anonymous client can't override the state");
+    assert!(err.to_string().contains("Overriding the state"), "{err}");
+    assert!(err.to_string().contains("unchecked_dry_run"), "{err}");
+    assert_eq!(internal_result.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn api_keys__are_reloaded_when_file_changes() {
+    // Given
+    let file = NamedTempFile::new().unwrap();
+    write_api_keys(&file, r#"{ "internal": [] }"#);
+    let srv = node_with_api_keys(&file).await;
+    let internal = client_with_api_key(&srv, INTERNAL_KEY);
+    internal
+        .produce_blocks(1, None)
+        .await
+        .expect_err("The key doesn't have the role yet");
+
+    // When
+    write_api_keys(&file, r#"{ "internal": ["block_production"] }"#);
+    tokio::time::sleep(API_KEYS_RELOAD_INTERVAL * 2).await;
+    let result = internal.produce_blocks(1, None).await;
+
+    // Then
+    assert_eq!(*result.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn peer_reputations__requires_api_key_with_peer_admin_role() {
+    // Given
+    let file = NamedTempFile::new().unwrap();
+    write_api_keys(&file, r#"{ "internal": ["peer_admin"], "public": [] }"#);
+    let srv = node_with_api_keys(&file).await;
+    let public = client_with_api_key(&srv, PUBLIC_KEY);
+    let internal = client_with_api_key(&srv, INTERNAL_KEY);
+
+    // When
+    let public_result = public.peer_reputations().await;
+    let internal_result = internal.peer_reputations().await;
+
+    // Then
+    let Err(err) = public_result else {
+        panic!("The key without the role can't inspect peers");
+    };
+    assert!(err.to_string().contains("peer_admin"), "{err}");
+    assert!(internal_result.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn ban_peer__requires_peer_admin_api_without_api_keys() {
+    // Given
+    let config = Config::local_node();
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let peer_id = "16Uiu2HAmBUEBJCHoBvGZzyQc5GC3ekfK6Dgk4HtDwjMq8GxRALhv"
+        .parse()
+        .unwrap();
+
+    // When
+    let result = client
+        .ban_peer(&peer_id, std::time::Duration::from_secs(60))
+        .await;
+
+    // Then
+    let err = result.expect_err("The peer admin API is disabled");
+    assert!(err.to_string().contains("--peer-admin-api"), "{err}");
+}
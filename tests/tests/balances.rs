@@ -17,9 +17,12 @@ use fuel_core_client::client::{
         PageDirection,
         PaginationRequest,
     },
-    types::primitives::{
-        Address,
-        AssetId,
+    types::{
+        TransactionStatus,
+        primitives::{
+            Address,
+            AssetId,
+        },
     },
 };
 use fuel_core_poa::Trigger;
@@ -596,3 +599,117 @@ async fn contract_balances_in_the_past() {
         contract_id.asset_id(&sub_asset_id)
     );
 }
+
+#[tokio::test]
+async fn balances_and_coins_in_the_past() {
+    let mut config = Config::local_node();
+    config.combined_db_config.state_rewind_policy = StateRewindPolicy::RewindFullRange;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let wallet = default_signing_wallet();
+    let owner = wallet.owner();
+    let recipient = Address::new([1u8; 32]);
+    let amount = 1234;
+    let request = || PaginationRequest {
+        cursor: None,
+        results: 100,
+        direction: PageDirection::Forward,
+    };
+
+    // Given
+    let height_before = client.produce_blocks(1, None).await.unwrap();
+    let owner_balance_before = client.balance(&owner, None).await.unwrap();
+    let owner_coins_before = client.coins(&owner, None, request()).await.unwrap();
+    let TransactionStatus::Success {
+        block_height: height_after,
+        ..
+    } = client
+        .run_transfer(wallet, vec![(recipient, AssetId::BASE, amount)])
+        .await
+        .unwrap()
+    else {
+        panic!("Failed to send tx");
+    };
+
+    // When
+    let owner_balance_at_before = client
+        .balance_at_height(&owner, &AssetId::BASE, height_before)
+        .await
+        .unwrap();
+    let recipient_balance_at_before = client
+        .balance_at_height(&recipient, &AssetId::BASE, height_before)
+        .await
+        .unwrap();
+    let recipient_balance_at_after = client
+        .balance_at_height(&recipient, &AssetId::BASE, height_after)
+        .await
+        .unwrap();
+    let recipient_balances_at_before = client
+        .balances_at_height(&recipient, height_before, request())
+        .await
+        .unwrap();
+    let recipient_balances_at_after = client
+        .balances_at_height(&recipient, height_after, request())
+        .await
+        .unwrap();
+    let owner_coins_at_before = client
+        .coins_at_height(&owner, None, height_before, request())
+        .await
+        .unwrap();
+    let recipient_coins_at_after = client
+        .coins_at_height(&recipient, Some(&AssetId::BASE), height_after, request())
+        .await
+        .unwrap();
+
+    // Then
+    assert_ne!(
+        client.balance(&owner, None).await.unwrap(),
+        owner_balance_before
+    );
+    assert_eq!(owner_balance_at_before, owner_balance_before);
+    assert_eq!(recipient_balance_at_before, 0);
+    assert_eq!(recipient_balance_at_after, amount as u128);
+    assert!(recipient_balances_at_before.results.is_empty());
+    assert_eq!(recipient_balances_at_after.results.len(), 1);
+    assert_eq!(
+        recipient_balances_at_after.results[0].amount,
+        amount as u128
+    );
+    assert_eq!(
+        owner_coins_at_before
+            .results
+            .iter()
+            .map(|coin| coin.utxo_id)
+            .collect::<Vec<_>>(),
+        owner_coins_before
+            .results
+            .iter()
+            .map(|coin| coin.utxo_id)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(recipient_coins_at_after.results.len(), 1);
+    assert_eq!(recipient_coins_at_after.results[0].amount, amount);
+}
+
+#[tokio::test]
+async fn balance_in_the_past_fails_without_historical_execution() {
+    let mut config = Config::local_node();
+    config.historical_execution = false;
+    config.combined_db_config.state_rewind_policy = StateRewindPolicy::RewindFullRange;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let height = client.produce_blocks(1, None).await.unwrap();
+
+    // When
+    let result = client
+        .balance_at_height(&default_signing_wallet().owner(), &AssetId::BASE, height)
+        .await;
+
+    // Then
+    let err = result.expect_err("Should fail without the historical execution");
+    assert!(
+        err.to_string()
+            .contains("`--historical-execution` is required for this operation"),
+        "{err}"
+    );
+}
@@ -0,0 +1,153 @@
+use fuel_core::{
+    p2p_test_helpers::{
+        BftBehaviour,
+        BftNodes,
+        BftValidatorSetup,
+        Node,
+        make_bft_nodes,
+    },
+    service::Config,
+};
+use fuel_core_poa::ports::BlockImporter;
+use fuel_core_storage::transactional::AtomicView;
+use fuel_core_types::{
+    blockchain::{
+        consensus::Consensus,
+        primitives::BlockId,
+    },
+    fuel_crypto::SecretKey,
+    fuel_types::BlockHeight,
+};
+use futures::StreamExt;
+use rand::{
+    SeedableRng,
+    rngs::StdRng,
+};
+use std::time::Duration;
+
+const NUMBER_OF_BLOCKS: u32 = 5;
+
+fn config() -> Config {
+    let mut config = Config::local_node();
+    config.bft_timeouts.propose = Duration::from_secs(2);
+    config.bft_timeouts.prevote = Duration::from_millis(500);
+    config.bft_timeouts.precommit = Duration::from_millis(500);
+    config.bft_timeouts.commit = Duration::from_millis(200);
+    config
+}
+
+fn validators(behaviours: &[BftBehaviour]) -> Vec<BftValidatorSetup> {
+    let mut rng = StdRng::seed_from_u64(2024);
+    behaviours
+        .iter()
+        .map(|behaviour| BftValidatorSetup::new(SecretKey::random(&mut rng), *behaviour))
+        .collect()
+}
+
+async fn wait_for_height(node: &Node, height: u32) {
+    let mut blocks = node.node.shared.block_importer.block_stream();
+    while node.db.latest_view().unwrap().latest_height().unwrap() < height.into() {
+        tokio::time::timeout(Duration::from_secs(60), blocks.next())
+            .await
+            .unwrap_or_else(|_| {
+                panic!("{} didn't reach the height {height}", node.config.name)
+            });
+    }
+}
+
+fn committed_block(node: &Node, height: u32) -> (BlockId, u32, usize) {
+    let height = BlockHeight::from(height);
+    let block = node
+        .db
+        .latest_view()
+        .unwrap()
+        .get_sealed_block_by_height(&height)
+        .unwrap()
+        .expect("The block should be committed");
+
+    match block.consensus {
+        Consensus::Bft(bft) => (block.entity.id(), bft.round, bft.signatures.len()),
+        consensus => panic!("Unexpected consensus {consensus:?}"),
+    }
+}
+
+async fn assert_honest_validators_agree(nodes: &BftNodes, quorum: usize) {
+    for node in &nodes.honest {
+        wait_for_height(node, NUMBER_OF_BLOCKS).await;
+    }
+
+    for height in 1..=NUMBER_OF_BLOCKS {
+        let (expected_id, _, signatures) = committed_block(&nodes.honest[0], height);
+        assert!(signatures >= quorum);
+
+        for node in &nodes.honest[1..] {
+            let (id, _, signatures) = committed_block(node, height);
+            assert_eq!(id, expected_id, "The validators forked at {height}");
+            assert!(signatures >= quorum);
+        }
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bft__honest_validators_commit_the_same_blocks() {
+    use BftBehaviour::*;
+
+    // given
+    let setups = validators(&[Honest, Honest, Honest, Honest]);
+
+    // when
+    let nodes = make_bft_nodes(setups, Some(config())).await;
+
+    // then
+    assert_honest_validators_agree(&nodes, 3).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bft__crashed_minority__does_not_stop_the_network() {
+    use BftBehaviour::*;
+
+    // given
+    let setups = validators(&[Honest, Crashed, Honest, Honest]);
+
+    // when
+    let nodes = make_bft_nodes(setups, Some(config())).await;
+
+    // then
+    assert_honest_validators_agree(&nodes, 3).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bft__equivocating_minority__does_not_break_safety() {
+    use BftBehaviour::*;
+
+    // given
+    let setups = validators(&[Honest, Equivocating, Honest, Honest]);
+
+    // when
+    let nodes = make_bft_nodes(setups, Some(config())).await;
+
+    // then
+    assert_honest_validators_agree(&nodes, 3).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bft__equivocating_minority_of_seven_validators__does_not_break_safety() {
+    use BftBehaviour::*;
+
+    // given
+    let setups = validators(&[
+        Honest,
+        Equivocating,
+        Honest,
+        Honest,
+        Equivocating,
+        Honest,
+        Honest,
+    ]);
+
+    // when
+    let nodes = make_bft_nodes(setups, Some(config())).await;
+
+    // then
+    assert_honest_validators_agree(&nodes, 5).await;
+}
@@ -24,7 +24,10 @@ use fuel_core_storage::tables::Coins;
 use fuel_core_types::{
     fuel_asm::*,
     fuel_tx::*,
-    fuel_types::canonical::Serialize,
+    fuel_types::{
+        ChainId,
+        canonical::Serialize,
+    },
     fuel_vm::{
         checked_transaction::IntoChecked,
         *,
@@ -140,6 +143,121 @@ async fn calling_the_contract_with_enabled_utxo_validation_is_successful() {
     assert!(matches!(tx_status, TransactionStatus::Success { .. }));
 }
 
+#[tokio::test]
+async fn transactions_by_contract_returns_creation_and_calls() {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(SEED);
+    let secret = SecretKey::random(&mut rng);
+    let amount = 10000;
+    let owner = Input::owner(&secret.public_key());
+    let utxo_ids = [
+        UtxoId::new([1; 32].into(), 0),
+        UtxoId::new([1; 32].into(), 1),
+        UtxoId::new([1; 32].into(), 2),
+    ];
+
+    let state_config = StateConfig {
+        coins: utxo_ids
+            .iter()
+            .map(|utxo_id| CoinConfig {
+                tx_id: *utxo_id.tx_id(),
+                output_index: utxo_id.output_index(),
+                owner: owner.into(),
+                amount,
+                asset_id: AssetId::BASE,
+                ..Default::default()
+            })
+            .collect(),
+        ..Default::default()
+    };
+    let config = Config {
+        utxo_validation: true,
+        ..Config::local_node_with_state_config(state_config)
+    };
+    let node = FuelService::from_database(Database::<OnChain>::in_memory(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(node.bound_address);
+
+    // Given
+    let bytecode: Witness = vec![].into();
+    let salt = Salt::zeroed();
+    let contract = Contract::from(bytecode.as_ref());
+    let state_root = Contract::default_state_root();
+    let contract_id = Contract::id(&salt, &contract.root(), &state_root);
+    let create_tx = TransactionBuilder::create(bytecode, salt, vec![])
+        .add_unsigned_coin_input(
+            secret,
+            utxo_ids[0],
+            amount,
+            Default::default(),
+            Default::default(),
+        )
+        .add_output(Output::contract_created(contract_id, state_root))
+        .finalize_as_transaction();
+    client.submit_and_await_commit(&create_tx).await.unwrap();
+
+    let call_tx = TransactionBuilder::script(vec![], vec![])
+        .add_input(Input::contract(
+            UtxoId::new(create_tx.id(&ChainId::default()), 1),
+            Contract::default_state_root(),
+            state_root,
+            Default::default(),
+            contract_id,
+        ))
+        .add_unsigned_coin_input(
+            secret,
+            utxo_ids[1],
+            amount,
+            Default::default(),
+            Default::default(),
+        )
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .finalize_as_transaction();
+    client.submit_and_await_commit(&call_tx).await.unwrap();
+
+    let unrelated_tx = TransactionBuilder::script(vec![], vec![])
+        .add_unsigned_coin_input(
+            secret,
+            utxo_ids[2],
+            amount,
+            Default::default(),
+            Default::default(),
+        )
+        .finalize_as_transaction();
+    client.submit_and_await_commit(&unrelated_tx).await.unwrap();
+
+    // When
+    let response = client
+        .transactions_by_contract(
+            &contract_id,
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+
+    // Then
+    let transactions = response
+        .results
+        .into_iter()
+        .map(|tx| {
+            assert!(matches!(tx.status, TransactionStatus::Success { .. }));
+            let tx: Transaction = tx.transaction.try_into().unwrap();
+            tx.id(&ChainId::default())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        transactions,
+        vec![
+            create_tx.id(&ChainId::default()),
+            call_tx.id(&ChainId::default())
+        ]
+    );
+}
+
 #[rstest]
 #[tokio::test]
 async fn test_contract_balance(
@@ -730,3 +730,88 @@ async fn balances_complexity_handles_amount_queried_by_sdk(
     let result = send_graph_ql_query(&url, &query).await;
     assert!(result.contains(result_substring), "{:?}", result);
 }
+
+const NODE_INFO_QUERY: &str = r#"
+    query {
+      nodeInfo {
+        nodeVersion
+      }
+    }
+"#;
+
+/// Sends the `NODE_INFO_QUERY` with the optional API key and returns the response as json.
+async fn send_node_info_query(url: &str, api_key: Option<&str>) -> serde_json::Value {
+    let client = reqwest::Client::new();
+    let mut request = client.post(url).json(&serde_json::json!({
+        "query": NODE_INFO_QUERY
+    }));
+    if let Some(api_key) = api_key {
+        request = request.header("x-api-key", api_key);
+    }
+    request.send().await.unwrap().json().await.unwrap()
+}
+
+fn rate_limit_code(response: &serde_json::Value) -> Option<&str> {
+    response["errors"][0]["extensions"]["code"].as_str()
+}
+
+#[tokio::test]
+async fn rate_limit__rejects_client_that_exhausted_its_budget() {
+    // Given
+    let mut config = Config::local_node();
+    config.graphql_config.rate_limit.ip_complexity_per_window = Some(10_000);
+    config.graphql_config.rate_limit.window = Duration::from_secs(3600);
+    let node = FuelService::new_node(config).await.unwrap();
+    let url = format!("http://{}/v1/graphql", node.bound_address);
+
+    // When
+    let mut rejected = None;
+    for _ in 0..10_000 {
+        let response = send_node_info_query(&url, None).await;
+        if rate_limit_code(&response).is_some() {
+            rejected = Some(response);
+            break
+        }
+    }
+
+    // Then
+    let rejected = rejected.expect("The client should be rate limited");
+    assert_eq!(rate_limit_code(&rejected), Some("RATE_LIMITED"));
+    assert!(
+        rejected["extensions"]["rate_limit_exceeded"]
+            .as_u64()
+            .is_some()
+    );
+    assert!(rejected["data"].is_null());
+}
+
+#[tokio::test]
+async fn rate_limit__tracks_api_keys_separately_from_ip() {
+    // Given
+    let api_keys = tempfile::NamedTempFile::new().unwrap();
+    std::fs::write(api_keys.path(), r#"{ "key": [] }"#).unwrap();
+    let mut config = Config::local_node();
+    config.graphql_config.api_keys_path = Some(api_keys.path().to_path_buf());
+    config.graphql_config.rate_limit.ip_complexity_per_window = Some(1);
+    config
+        .graphql_config
+        .rate_limit
+        .api_key_complexity_per_window = Some(usize::MAX);
+    let node = FuelService::new_node(config).await.unwrap();
+    let url = format!("http://{}/v1/graphql", node.bound_address);
+    let response = send_node_info_query(&url, None).await;
+    assert_eq!(rate_limit_code(&response), Some("RATE_LIMITED"));
+
+    // When
+    let known_key = send_node_info_query(&url, Some("key")).await;
+    let unknown_key = send_node_info_query(&url, Some("unknown")).await;
+
+    // Then
+    assert_eq!(rate_limit_code(&known_key), None, "{known_key}");
+    assert!(known_key["data"]["nodeInfo"]["nodeVersion"].is_string());
+    assert_eq!(
+        rate_limit_code(&unknown_key),
+        Some("RATE_LIMITED"),
+        "The unknown key should share the IP budget: {unknown_key}"
+    );
+}
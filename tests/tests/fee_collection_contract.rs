@@ -277,26 +277,27 @@ async fn missing_variable_output() {
         op::ret(RegId::ONE),
     ];
     let tx = TransactionBuilder::script(
-            script.into_iter().collect(),
-            asset_id.to_bytes().into_iter()
-                .chain(output_index.to_bytes().into_iter())
-                .chain(ctx.contract_id
-                    .to_bytes().into_iter())
-                .chain(0u64.to_bytes().into_iter())
-                .chain(0u64.to_bytes().into_iter())
-                .collect(),
-        )
-            .add_fee_input() // No coinbase fee for this block
-            .script_gas_limit(1_000_000)
-            .add_input(Input::contract(
-                Default::default(),
-                Default::default(),
-                Default::default(),
-                Default::default(),
-                ctx.contract_id,
-            ))
-            .add_output(Output::contract(1, Default::default(), Default::default()))
-            .finalize_as_transaction();
+        script.into_iter().collect(),
+        asset_id
+            .to_bytes()
+            .into_iter()
+            .chain(output_index.to_bytes().into_iter())
+            .chain(ctx.contract_id.to_bytes().into_iter())
+            .chain(0u64.to_bytes().into_iter())
+            .chain(0u64.to_bytes().into_iter())
+            .collect(),
+    )
+    .add_fee_input() // No coinbase fee for this block
+    .script_gas_limit(1_000_000)
+    .add_input(Input::contract(
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        ctx.contract_id,
+    ))
+    .add_output(Output::contract(1, Default::default(), Default::default()))
+    .finalize_as_transaction();
 
     let tx_status = ctx.client.submit_and_await_commit(&tx).await.unwrap();
     let TransactionStatus::Failure { reason, .. } = tx_status else {
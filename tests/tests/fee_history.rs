@@ -0,0 +1,121 @@
+#![allow(non_snake_case)]
+
+use fuel_core::service::{
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::{
+    FuelClient,
+    types::TransactionStatus,
+};
+use fuel_core_types::{
+    fuel_asm::{
+        RegId,
+        op,
+    },
+    fuel_tx::TransactionBuilder,
+    fuel_types::BlockHeight,
+};
+use test_helpers::{
+    assemble_tx::AssembleAndRunTx,
+    default_signing_wallet,
+};
+
+async fn run_script_with_tip(client: &FuelClient, tip: u64) {
+    let tx =
+        TransactionBuilder::script([op::ret(RegId::ONE)].into_iter().collect(), vec![])
+            .tip(tip)
+            .finalize_as_transaction();
+
+    let status = client
+        .assemble_and_run_tx(&tx, default_signing_wallet())
+        .await
+        .unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+}
+
+#[tokio::test]
+async fn fee_history__returns_tips_of_requested_blocks() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let tips = [10, 20, 30];
+    for tip in tips {
+        run_script_with_tip(&client, tip).await;
+    }
+
+    // When
+    let history = client.fee_history(2, None, &[0.0, 100.0]).await.unwrap();
+
+    // Then
+    assert_eq!(history.oldest_block, BlockHeight::new(2));
+    assert_eq!(history.blocks.len(), 2);
+    for (block, tip) in history.blocks.iter().zip(&tips[1..]) {
+        assert_eq!(block.reward, vec![*tip, *tip]);
+        assert!(block.gas_used_ratio > 0.0);
+        assert!(block.gas_used_ratio < 1.0);
+    }
+    assert_eq!(history.blocks[0].height, BlockHeight::new(2));
+    assert_eq!(history.blocks[1].height, BlockHeight::new(3));
+}
+
+#[tokio::test]
+async fn fee_history__history_is_clamped_to_the_first_block() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    run_script_with_tip(&client, 1).await;
+    run_script_with_tip(&client, 2).await;
+
+    // When
+    let history = client.fee_history(100, Some(1), &[50.0]).await.unwrap();
+
+    // Then
+    assert_eq!(history.oldest_block, BlockHeight::new(1));
+    assert_eq!(history.blocks.len(), 1);
+    assert_eq!(history.blocks[0].reward, vec![1]);
+}
+
+#[tokio::test]
+async fn fee_history__rejects_invalid_arguments() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    run_script_with_tip(&client, 1).await;
+
+    // When
+    let zero_blocks = client.fee_history(0, None, &[]).await;
+    let too_many_blocks = client.fee_history(1025, None, &[]).await;
+    let unsorted_percentiles = client.fee_history(1, None, &[50.0, 10.0]).await;
+    let out_of_range_percentiles = client.fee_history(1, None, &[101.0]).await;
+    let future_block = client.fee_history(1, Some(100), &[]).await;
+
+    // Then
+    assert!(zero_blocks.is_err());
+    assert!(too_many_blocks.is_err());
+    assert!(unsorted_percentiles.is_err());
+    assert!(out_of_range_percentiles.is_err());
+    assert!(future_block.is_err());
+}
+
+#[tokio::test]
+async fn fee_history__requires_fee_history_indexation() {
+    // Given
+    let mut config = Config::local_node();
+    config.fee_history_indexation = false;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let result = client.fee_history(1, None, &[]).await;
+
+    // Then
+    let error = result.expect_err("Should fail without the indexation");
+    assert!(
+        error
+            .to_string()
+            .contains("`--fee-history-indexation` is required"),
+        "{error}"
+    );
+}
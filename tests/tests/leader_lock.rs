@@ -13,6 +13,7 @@ use fuel_core::{
         config::RedisLeaderLockConfig,
     },
 };
+use fuel_core_client::client::FuelClient;
 use fuel_core_poa::{
     Trigger,
     ports::BlockImporter,
@@ -22,6 +23,10 @@ use fuel_core_types::{
     fuel_tx::Input,
     fuel_types::Address,
     secrecy::Secret,
+    services::leader_lease::{
+        LeaderLeaseBackend,
+        LeaseTransitionKind,
+    },
     signer::SignMode,
 };
 use futures::{
@@ -94,6 +99,59 @@ async fn leader_lock__four_producers__only_first_leader_produces_blocks() {
     .await;
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn leader_lock__two_producers__consensus_status_reports_the_same_holder() {
+    const BLOCK_TIME: Duration = Duration::from_millis(200);
+    const LEADER_ELECTION_TIMEOUT: Duration = Duration::from_secs(5);
+
+    // given
+    let (_redis, _bootstrap, make_node_config) = make_leader_lock_test_config_builder(
+        4242,
+        BLOCK_TIME,
+        "poa:leader:consensus-status",
+    )
+    .await;
+    let first_producer = make_node(make_node_config("First Producer"), vec![]).await;
+    let second_producer = make_node(make_node_config("Second Producer"), vec![]).await;
+    let (leader, followers) = find_leader_and_followers(
+        vec![first_producer, second_producer],
+        LEADER_ELECTION_TIMEOUT,
+    )
+    .await;
+    let follower = &followers[0];
+    wait_for_non_local_block_and_fail_on_local(follower).await;
+
+    // when
+    let leader_status = FuelClient::from(leader.node.bound_address)
+        .consensus_status()
+        .await
+        .expect("Should query the leader status")
+        .expect("Leader lock should be enabled");
+    let follower_status = FuelClient::from(follower.node.bound_address)
+        .consensus_status()
+        .await
+        .expect("Should query the follower status")
+        .expect("Leader lock should be enabled");
+
+    // then
+    assert_eq!(leader_status.backend, LeaderLeaseBackend::Redis);
+    assert!(leader_status.is_leader);
+    assert!(!follower_status.is_leader);
+    assert_eq!(
+        leader_status.holder.as_deref(),
+        Some(leader_status.lease_owner_token.as_str())
+    );
+    assert_eq!(follower_status.holder, leader_status.holder);
+    assert!(leader_status.nodes.iter().all(|node| node.reachable));
+    assert!(
+        leader_status
+            .transitions
+            .iter()
+            .any(|transition| transition.kind == LeaseTransitionKind::Acquired)
+    );
+    assert!(follower_status.stream_height.is_some());
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn leader_lock__three_producers__leadership_handoffs_are_exclusive() {
     const BLOCK_TIME: Duration = Duration::from_millis(200);
@@ -869,6 +927,9 @@ fn update_signing_key(config: &mut Config, key: Address) {
         ConsensusConfig::PoAV2(poa) => {
             poa.set_genesis_signing_key(key);
         }
+        ConsensusConfig::Bft(_) => {
+            // The validators of the network are defined by the chain config.
+        }
     }
     config.snapshot_reader = snapshot_reader.clone().with_chain_config(chain_config)
 }
@@ -2,6 +2,8 @@
 #![deny(unused_must_use)]
 #![deny(warnings)]
 
+#[cfg(not(feature = "only-p2p"))]
+mod api_keys;
 #[cfg(not(feature = "only-p2p"))]
 mod assemble_tx;
 #[cfg(not(feature = "only-p2p"))]
@@ -31,6 +33,8 @@ mod dos;
 #[cfg(not(feature = "only-p2p"))]
 mod fee_collection_contract;
 #[cfg(not(feature = "only-p2p"))]
+mod fee_history;
+#[cfg(not(feature = "only-p2p"))]
 mod fuel_client;
 #[cfg(not(feature = "only-p2p"))]
 mod gas_price;
@@ -47,6 +51,8 @@ mod leader_lock;
 #[cfg(not(feature = "only-p2p"))]
 mod local_node;
 #[cfg(not(feature = "only-p2p"))]
+mod logs;
+#[cfg(not(feature = "only-p2p"))]
 mod messages;
 #[cfg(not(feature = "only-p2p"))]
 mod metrics;
@@ -57,6 +63,8 @@ mod poa;
 #[cfg(not(feature = "only-p2p"))]
 mod preconfirmations;
 #[cfg(not(feature = "only-p2p"))]
+mod raft_leader_lock;
+#[cfg(not(feature = "only-p2p"))]
 mod recovery;
 #[cfg(not(feature = "only-p2p"))]
 mod regenesis;
@@ -82,12 +90,18 @@ mod storage;
 #[cfg(not(feature = "only-p2p"))]
 mod storage_read_replay;
 #[cfg(not(feature = "only-p2p"))]
+mod transaction_trace;
+#[cfg(not(feature = "only-p2p"))]
 mod trigger_integration;
 #[cfg(not(feature = "only-p2p"))]
 mod tx;
 #[cfg(not(feature = "only-p2p"))]
 mod vm_storage;
+#[cfg(not(feature = "only-p2p"))]
+mod websocket;
 
+#[cfg(feature = "only-p2p")]
+mod bft;
 #[cfg(feature = "only-p2p")]
 mod preconfirmations_gossip;
 #[cfg(feature = "only-p2p")]
@@ -103,4 +117,7 @@ mod peering;
 #[cfg(feature = "aws-kms")]
 mod aws_kms;
 
+#[cfg(feature = "remote-signer")]
+mod remote_signer;
+
 fuel_core_trace::enable_tracing!();
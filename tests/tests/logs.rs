@@ -0,0 +1,312 @@
+use fuel_core::{
+    chain_config::{
+        ContractConfig,
+        StateConfig,
+    },
+    service::{
+        Config,
+        FuelService,
+    },
+};
+use fuel_core_client::client::{
+    FuelClient,
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    types::{
+        LogFilter,
+        TransactionStatus,
+    },
+};
+use fuel_core_types::{
+    fuel_asm::{
+        GTFArgs,
+        RegId,
+        op,
+    },
+    fuel_tx::{
+        Bytes32,
+        ContractId,
+        Receipt,
+        Word,
+    },
+    fuel_types::canonical::Serialize,
+};
+use futures::StreamExt;
+use std::time::Duration;
+use test_helpers::{
+    assemble_tx::AssembleAndRunTx,
+    default_signing_wallet,
+};
+
+const LOG_ID: Word = 7;
+
+fn config_with_logging_contract(contract_id: ContractId) -> Config {
+    let mut state_config = StateConfig::local_testnet();
+    state_config.contracts.push(ContractConfig {
+        contract_id,
+        code: vec![
+            op::movi(0x10, LOG_ID.try_into().unwrap()),
+            op::log(RegId::ZERO, 0x10, RegId::ZERO, RegId::ZERO),
+            op::ret(RegId::ONE),
+        ]
+        .into_iter()
+        .collect(),
+        tx_id: Bytes32::new([1; 32]),
+        output_index: Default::default(),
+        tx_pointer_block_height: Default::default(),
+        tx_pointer_tx_idx: Default::default(),
+        states: Default::default(),
+        balances: Default::default(),
+    });
+
+    let mut config = Config::local_node_with_state_config(state_config);
+    config.utxo_validation = true;
+    config.txpool.utxo_validation = true;
+    config.gas_price_config.min_exec_gas_price = 1000;
+    config
+}
+
+async fn call_contract(client: &FuelClient, contract_id: ContractId) {
+    let script = vec![
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let script_data = contract_id
+        .to_bytes()
+        .into_iter()
+        .chain(Word::MIN.to_be_bytes())
+        .chain(Word::MIN.to_be_bytes())
+        .collect();
+
+    let status = client
+        .run_script(script, script_data, default_signing_wallet())
+        .await
+        .unwrap();
+    assert!(
+        matches!(status, TransactionStatus::Success { .. }),
+        "{status:?}"
+    );
+}
+
+fn filter(contract_id: ContractId) -> LogFilter {
+    LogFilter {
+        contract_id,
+        rb: LOG_ID,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn logs__returns_paginated_logs_of_the_contract() {
+    let contract_id = ContractId::new([1; 32]);
+    let config = config_with_logging_contract(contract_id);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    for _ in 0..3 {
+        call_contract(&client, contract_id).await;
+    }
+
+    // When
+    let first_page = client
+        .logs(
+            filter(contract_id),
+            PaginationRequest {
+                cursor: None,
+                results: 2,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+    let second_page = client
+        .logs(
+            filter(contract_id),
+            PaginationRequest {
+                cursor: first_page.cursor.clone(),
+                results: 2,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(first_page.results.len(), 2);
+    assert!(first_page.has_next_page);
+    assert_eq!(second_page.results.len(), 1);
+    let heights = first_page
+        .results
+        .iter()
+        .chain(second_page.results.iter())
+        .map(|log| log.block_height)
+        .collect::<Vec<_>>();
+    assert_eq!(heights, vec![1u32.into(), 2u32.into(), 3u32.into()]);
+    for log in first_page.results.iter().chain(second_page.results.iter()) {
+        assert_eq!(log.contract_id, contract_id);
+        assert_eq!(log.rb, LOG_ID);
+        assert!(matches!(
+            log.receipt,
+            Receipt::Log { id, rb, .. } if id == contract_id && rb == LOG_ID
+        ));
+    }
+}
+
+#[tokio::test]
+async fn logs__respects_the_height_range_and_log_id() {
+    let contract_id = ContractId::new([1; 32]);
+    let config = config_with_logging_contract(contract_id);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    for _ in 0..3 {
+        call_contract(&client, contract_id).await;
+    }
+    let request = PaginationRequest {
+        cursor: None,
+        results: 10,
+        direction: PageDirection::Backward,
+    };
+
+    // When
+    let in_range = client
+        .logs(
+            LogFilter {
+                from_height: Some(2u32.into()),
+                to_height: Some(2u32.into()),
+                ..filter(contract_id)
+            },
+            request.clone(),
+        )
+        .await
+        .unwrap();
+    let other_log_id = client
+        .logs(
+            LogFilter {
+                rb: LOG_ID + 1,
+                ..filter(contract_id)
+            },
+            request,
+        )
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(in_range.results.len(), 1);
+    assert_eq!(in_range.results[0].block_height, 2u32.into());
+    assert!(other_log_id.results.is_empty());
+}
+
+#[tokio::test]
+async fn logs__fails_when_indexation_is_disabled() {
+    let contract_id = ContractId::new([1; 32]);
+    let mut config = config_with_logging_contract(contract_id);
+    config.logs_indexation = false;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let result = client
+        .logs(
+            filter(contract_id),
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await;
+
+    // Then
+    let err = result.expect_err("Should fail without the indexation");
+    assert!(
+        err.to_string()
+            .contains("`--logs-indexation` is required for this operation"),
+        "{err}"
+    );
+}
+
+#[cfg(feature = "default")]
+#[tokio::test]
+async fn logs__fails_when_indexation_was_disabled_before_restart() {
+    use fuel_core::{
+        combined_database::CombinedDatabase,
+        state::rocks_db::DatabaseConfig,
+    };
+
+    let tmp_dir = tempfile::TempDir::new().unwrap();
+    let contract_id = ContractId::new([1; 32]);
+    let open_database = || {
+        CombinedDatabase::open(
+            tmp_dir.path(),
+            Default::default(),
+            DatabaseConfig::config_for_tests(),
+        )
+        .unwrap()
+    };
+
+    // Given
+    {
+        let mut config = config_with_logging_contract(contract_id);
+        config.logs_indexation = false;
+        let srv = FuelService::from_combined_database(open_database(), config)
+            .await
+            .unwrap();
+        let client = FuelClient::from(srv.bound_address);
+        call_contract(&client, contract_id).await;
+        srv.send_stop_signal_and_await_shutdown().await.unwrap();
+    }
+    let srv = FuelService::from_combined_database(
+        open_database(),
+        config_with_logging_contract(contract_id),
+    )
+    .await
+    .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let result = client
+        .logs(
+            filter(contract_id),
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await;
+
+    // Then
+    let err = result.expect_err("Should fail without the complete indexation");
+    assert!(
+        err.to_string()
+            .contains("Logs indexation is not available for this database"),
+        "{err}"
+    );
+}
+
+#[tokio::test]
+async fn logs_subscription__streams_logs_of_new_blocks() {
+    let contract_id = ContractId::new([1; 32]);
+    let config = config_with_logging_contract(contract_id);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let mut logs = client.logs_subscription(filter(contract_id)).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(1000)).await;
+
+    // When
+    call_contract(&client, contract_id).await;
+
+    // Then
+    let log = logs.next().await.unwrap().unwrap();
+    assert_eq!(log.contract_id, contract_id);
+    assert_eq!(log.rb, LOG_ID);
+    assert_eq!(log.block_height, 1u32.into());
+}
@@ -369,10 +369,12 @@ async fn can_get_message_proof() {
             .chain((0 as Word).to_be_bytes().iter().copied())
             .chain(args.iter().flat_map(|arg| {
                 // Recipient address
-                arg.recipient_address.into_iter()
+                arg.recipient_address
+                    .into_iter()
                     // The message data
                     .chain(arg.message_data.clone().into_iter())
-            })).collect();
+            }))
+            .collect();
         let script_data = base_asset_id
             .into_iter()
             .chain(smo_data.into_iter())
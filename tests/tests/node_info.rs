@@ -85,6 +85,8 @@ async fn test_peer_info() {
     // Create a producer and a validator that share the same key pair.
     let secret = SecretKey::random(&mut rng);
     let pub_key = Input::owner(&secret.public_key());
+    let mut config = Config::local_node();
+    config.peer_admin_api = true;
     let Nodes {
         mut producers,
         mut validators,
@@ -95,7 +97,7 @@ async fn test_peer_info() {
             ProducerSetup::new(secret).with_txs(1).with_name("Alice"),
         )],
         [Some(ValidatorSetup::new(pub_key).with_name("Bob"))],
-        None,
+        Some(config),
     )
     .await;
 
@@ -147,3 +149,97 @@ async fn test_peer_info() {
         .unwrap();
     assert!(time_since_heartbeat < Duration::from_secs(10));
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn peer_admin__ban_peer_disconnects_it_until_unbanned() {
+    use fuel_core::p2p_test_helpers::{
+        BootstrapSetup,
+        Nodes,
+        ProducerSetup,
+        ValidatorSetup,
+        make_nodes,
+    };
+    use fuel_core_types::{
+        fuel_tx::Input,
+        fuel_vm::SecretKey,
+        services::p2p::PeerId,
+    };
+    use rand::{
+        SeedableRng,
+        rngs::StdRng,
+    };
+    use std::time::Duration;
+
+    let mut rng = StdRng::seed_from_u64(line!() as u64);
+
+    let secret = SecretKey::random(&mut rng);
+    let pub_key = Input::owner(&secret.public_key());
+    let Nodes {
+        mut producers,
+        mut validators,
+        bootstrap_nodes: _dont_drop,
+    } = make_nodes(
+        [Some(BootstrapSetup::new(pub_key))],
+        [Some(
+            ProducerSetup::new(secret).with_txs(1).with_name("Alice"),
+        )],
+        [Some(ValidatorSetup::new(pub_key).with_name("Bob"))],
+        None,
+    )
+    .await;
+
+    let producer = producers.pop().unwrap();
+    let mut validator = validators.pop().unwrap();
+    let expected = producer.insert_txs().await;
+    validator.consistency_10s(&expected).await;
+
+    let validator_peer_id: PeerId = validator
+        .node
+        .shared
+        .config
+        .p2p
+        .as_ref()
+        .unwrap()
+        .keypair
+        .public()
+        .to_peer_id()
+        .to_base58()
+        .parse()
+        .unwrap();
+    let client = FuelClient::from(producer.node.bound_address);
+
+    // Given
+    let reputations = client.peer_reputations().await.unwrap();
+    let reputation = reputations
+        .iter()
+        .find(|reputation| reputation.id == validator_peer_id)
+        .expect("Should be connected to validator");
+    assert!(!reputation.reserved);
+
+    // When
+    client
+        .ban_peer(&validator_peer_id, Duration::from_secs(3600))
+        .await
+        .unwrap();
+
+    // Then
+    let banned_peers = client.banned_peers().await.unwrap();
+    assert_eq!(banned_peers.len(), 1);
+    assert_eq!(banned_peers[0].id, validator_peer_id);
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while client
+            .connected_peers_info()
+            .await
+            .unwrap()
+            .iter()
+            .any(|info| info.id == validator_peer_id)
+        {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    })
+    .await
+    .expect("The banned validator should be disconnected");
+
+    client.unban_peer(&validator_peer_id).await.unwrap();
+    assert!(client.banned_peers().await.unwrap().is_empty());
+}
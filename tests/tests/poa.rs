@@ -318,6 +318,9 @@ mod p2p {
             ConsensusConfig::PoAV2(poa) => {
                 poa.set_genesis_signing_key(key);
             }
+            ConsensusConfig::Bft(_) => {
+                // The validators of the network are defined by the chain config.
+            }
         }
         config.snapshot_reader = snapshot_reader.clone().with_chain_config(chain_config)
     }
@@ -585,7 +585,8 @@ async fn preconfirmation__sentry_allows_usage_of_dynamic_outputs() {
     let funds_secret_key: SecretKey = TESTNET_WALLET_SECRETS[1].parse().unwrap();
     let funded_account = SigningAccount::Wallet(funds_secret_key);
 
-    let script_with_transfers = TransactionBuilder::script(script.into_iter().collect(), script_data)
+    let script_with_transfers =
+        TransactionBuilder::script(script.into_iter().collect(), script_data)
             // We want to shift all dynamic outputs like `Change` and `Variable`
             // to the right for 1 output, so we add known output as a first one to achieve it.
             .add_output(Output::coin(receiver, known_amount, base_asset_id))
@@ -0,0 +1,351 @@
+use fuel_core::{
+    chain_config::ConsensusConfig,
+    p2p_test_helpers::{
+        Bootstrap,
+        CustomizeConfig,
+        Node,
+        make_config,
+        make_node,
+    },
+    service::{
+        Config,
+        config::{
+            RaftLeaderLockConfig,
+            RaftPeer,
+        },
+    },
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_poa::{
+    Trigger,
+    ports::BlockImporter,
+};
+use fuel_core_types::{
+    fuel_crypto::SecretKey,
+    fuel_tx::Input,
+    fuel_types::Address,
+    secrecy::Secret,
+    services::leader_lease::{
+        LeaderLeaseBackend,
+        LeaseTransitionKind,
+    },
+    signer::SignMode,
+};
+use futures::{
+    StreamExt,
+    stream::FuturesUnordered,
+};
+use rand::{
+    SeedableRng,
+    rngs::StdRng,
+};
+use std::{
+    net::{
+        SocketAddr,
+        SocketAddrV4,
+        TcpListener,
+    },
+    time::Duration,
+};
+
+const BLOCK_TIME: Duration = Duration::from_millis(200);
+const LEADER_ELECTION_TIMEOUT: Duration = Duration::from_secs(10);
+const BLOCK_IMPORT_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[tokio::test(flavor = "multi_thread")]
+async fn raft_leader_lock__three_producers__only_elected_leader_produces_blocks() {
+    const BLOCKS_TO_CHECK: usize = 20;
+
+    // given
+    let (_bootstrap, make_node_config) = make_raft_test_config_builder(4444, 3).await;
+    let mut nodes = Vec::new();
+    for (index, name) in ["First Producer", "Second Producer", "Third Producer"]
+        .into_iter()
+        .enumerate()
+    {
+        nodes.push(make_node(make_node_config(index, name), vec![]).await);
+    }
+
+    // when
+    let (leader, followers) = find_leader_and_followers(nodes).await;
+
+    // then
+    only_leader_produces_blocks(&leader, &followers, BLOCKS_TO_CHECK).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn raft_leader_lock__three_producers__when_leader_stops_then_follower_continues_the_chain()
+ {
+    const BLOCKS_BEFORE_FAILOVER: usize = 3;
+    const STOP_TIMEOUT: Duration = Duration::from_secs(1);
+
+    // given
+    let (_bootstrap, make_node_config) = make_raft_test_config_builder(5555, 3).await;
+    let mut nodes = Vec::new();
+    for (index, name) in ["First Producer", "Second Producer", "Third Producer"]
+        .into_iter()
+        .enumerate()
+    {
+        nodes.push(make_node(make_node_config(index, name), vec![]).await);
+    }
+    let (mut leader, followers) = find_leader_and_followers(nodes).await;
+    only_leader_produces_blocks(&leader, &followers, BLOCKS_BEFORE_FAILOVER).await;
+    let last_leader_height = u32::from(
+        leader
+            .db
+            .latest_view()
+            .unwrap()
+            .latest_height()
+            .expect("Leader should have a latest height"),
+    );
+
+    // when
+    tokio::time::timeout(STOP_TIMEOUT, leader.shutdown())
+        .await
+        .expect("Should stop leader before timeout");
+
+    // then
+    let (new_leader, remaining) = find_leader_and_followers(followers).await;
+    let new_leader_height = tokio::time::timeout(
+        LEADER_ELECTION_TIMEOUT,
+        wait_for_local_block_height(&new_leader),
+    )
+    .await
+    .expect("New leader should produce a local block");
+    assert!(
+        new_leader_height > last_leader_height,
+        "New leader should build on top of height {last_leader_height}, got {new_leader_height}"
+    );
+    only_leader_produces_blocks(&new_leader, &remaining, BLOCKS_BEFORE_FAILOVER).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn raft_leader_lock__three_producers__consensus_status_reports_the_same_holder() {
+    // given
+    let (_bootstrap, make_node_config) = make_raft_test_config_builder(6666, 3).await;
+    let mut nodes = Vec::new();
+    for (index, name) in ["First Producer", "Second Producer", "Third Producer"]
+        .into_iter()
+        .enumerate()
+    {
+        nodes.push(make_node(make_node_config(index, name), vec![]).await);
+    }
+    let (leader, followers) = find_leader_and_followers(nodes).await;
+    only_leader_produces_blocks(&leader, &followers, 1).await;
+
+    // when
+    let leader_status = FuelClient::from(leader.node.bound_address)
+        .consensus_status()
+        .await
+        .expect("Should query the leader status")
+        .expect("Raft leader lock should be enabled");
+    let follower_status = FuelClient::from(followers[0].node.bound_address)
+        .consensus_status()
+        .await
+        .expect("Should query the follower status")
+        .expect("Raft leader lock should be enabled");
+
+    // then
+    assert_eq!(leader_status.backend, LeaderLeaseBackend::Raft);
+    assert_eq!(leader_status.lease_key, None);
+    assert!(leader_status.is_leader);
+    assert!(!follower_status.is_leader);
+    assert_eq!(
+        leader_status.holder.as_deref(),
+        Some(leader_status.lease_owner_token.as_str())
+    );
+    assert_eq!(follower_status.holder, leader_status.holder);
+    assert_eq!(leader_status.quorum, 2);
+    assert_eq!(leader_status.nodes.len(), 2);
+    assert!(leader_status.nodes.iter().all(|node| node.reachable));
+    assert!(
+        leader_status
+            .transitions
+            .iter()
+            .any(|transition| transition.kind == LeaseTransitionKind::Acquired)
+    );
+    assert!(follower_status.stream_height.is_some());
+}
+
+async fn make_raft_test_config_builder(
+    seed: u64,
+    replicas: usize,
+) -> (Bootstrap, impl Fn(usize, &str) -> Config) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::random(&mut rng);
+    let pub_key = Input::owner(&secret.public_key());
+    let mut base_config = Config::local_node();
+    update_signing_key(&mut base_config, pub_key);
+
+    let bootstrap_config = make_config(
+        "Bootstrap".to_string(),
+        base_config.clone(),
+        CustomizeConfig::no_overrides(),
+    );
+    let bootstrap = Bootstrap::new(&bootstrap_config).await.unwrap();
+    let bootstrap_listeners = bootstrap.listeners();
+
+    let cluster = (0..replicas)
+        .map(|index| RaftPeer {
+            id: raft_node_id(index),
+            addr: SocketAddr::V4(SocketAddrV4::new(
+                std::net::Ipv4Addr::LOCALHOST,
+                bind_unused_port(),
+            )),
+        })
+        .collect::<Vec<_>>();
+
+    let data_dirs = (0..replicas)
+        .map(|_| tempfile::TempDir::new().unwrap())
+        .collect::<Vec<_>>();
+
+    let make_node_config = move |index: usize, name: &str| {
+        let mut node_config = make_config(
+            name.to_string(),
+            base_config.clone(),
+            CustomizeConfig::no_overrides(),
+        );
+        let this = &cluster[index];
+        node_config.debug = true;
+        node_config.block_production = Trigger::Interval {
+            block_time: BLOCK_TIME,
+        };
+        node_config.raft_leader_lock = Some(RaftLeaderLockConfig {
+            node_id: this.id,
+            listen_addr: this.addr,
+            peers: cluster
+                .iter()
+                .filter(|peer| peer.id != this.id)
+                .cloned()
+                .collect(),
+            data_dir: data_dirs[index].path().join("raft"),
+            shared_secret: Secret::new("raft-leader-lock-test".to_string()),
+            election_timeout: Duration::from_secs(1),
+            heartbeat_interval: Duration::from_millis(200),
+            rpc_timeout: Duration::from_millis(300),
+            stream_max_len: 1000,
+        });
+        node_config.consensus_signer = SignMode::Key(Secret::new(secret.into()));
+        node_config.p2p.as_mut().unwrap().bootstrap_nodes = bootstrap_listeners.clone();
+        node_config.p2p.as_mut().unwrap().reserved_nodes = bootstrap_listeners.clone();
+        node_config.p2p.as_mut().unwrap().info_interval =
+            Some(Duration::from_millis(100));
+        node_config.min_connected_reserved_peers = 0;
+        node_config.time_until_synced = BLOCK_TIME;
+        node_config
+    };
+
+    (bootstrap, make_node_config)
+}
+
+fn raft_node_id(index: usize) -> u64 {
+    u64::try_from(index)
+        .expect("Replica index should fit into u64")
+        .saturating_add(1)
+}
+
+async fn find_leader_and_followers(nodes: Vec<Node>) -> (Node, Vec<Node>) {
+    let leader_index = {
+        let mut waiters = nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| async move {
+                wait_for_local_block(node).await;
+                index
+            })
+            .collect::<FuturesUnordered<_>>();
+        tokio::time::timeout(LEADER_ELECTION_TIMEOUT, waiters.next())
+            .await
+            .expect("No producer emitted a local block before the election timeout")
+            .expect("Block stream ended unexpectedly before leader election")
+    };
+
+    let mut leader = None;
+    let mut followers = Vec::with_capacity(nodes.len().saturating_sub(1));
+    for (index, node) in nodes.into_iter().enumerate() {
+        if index == leader_index {
+            leader = Some(node);
+        } else {
+            followers.push(node);
+        }
+    }
+
+    (leader.expect("Leader index should exist"), followers)
+}
+
+async fn wait_for_local_block(node: &Node) {
+    wait_for_local_block_height(node).await;
+}
+
+async fn wait_for_local_block_height(node: &Node) -> u32 {
+    let mut stream = node.node.shared.block_importer.block_stream();
+    while let Some(block) = stream.next().await {
+        if block.is_locally_produced() {
+            return u32::from(*block.block_header.height());
+        }
+    }
+    panic!("block stream ended unexpectedly");
+}
+
+async fn wait_for_non_local_block_and_fail_on_local(node: &Node) {
+    let mut stream = node.node.shared.block_importer.block_stream();
+    if let Some(block) = stream.next().await {
+        if block.is_locally_produced() {
+            let height = *block.block_header.height();
+            panic!(
+                "Expected only non-local blocks while leader is alive; got local block at height {height}"
+            );
+        }
+        return;
+    }
+    panic!("block stream ended unexpectedly");
+}
+
+async fn only_leader_produces_blocks(
+    leader: &Node,
+    followers: &[Node],
+    non_local_blocks_to_check: usize,
+) {
+    for _ in 0..non_local_blocks_to_check {
+        tokio::time::timeout(BLOCK_IMPORT_TIMEOUT, wait_for_local_block(leader))
+            .await
+            .expect("Leader should import a local block");
+        let mut follower_checks = followers
+            .iter()
+            .map(|node| {
+                tokio::time::timeout(
+                    BLOCK_IMPORT_TIMEOUT,
+                    wait_for_non_local_block_and_fail_on_local(node),
+                )
+            })
+            .collect::<FuturesUnordered<_>>();
+        while let Some(result) = follower_checks.next().await {
+            result.expect("Non-leader should import a non-local block");
+        }
+    }
+}
+
+fn bind_unused_port() -> u16 {
+    let socket = TcpListener::bind(SocketAddrV4::new(std::net::Ipv4Addr::LOCALHOST, 0))
+        .expect("Should bind an ephemeral port");
+    let port = socket.local_addr().expect("Should get local addr").port();
+    drop(socket);
+    port
+}
+
+fn update_signing_key(config: &mut Config, key: Address) {
+    let snapshot_reader = &config.snapshot_reader;
+    let mut chain_config = snapshot_reader.chain_config().clone();
+    match &mut chain_config.consensus {
+        ConsensusConfig::PoA { signing_key } => {
+            *signing_key = key;
+        }
+        ConsensusConfig::PoAV2(poa) => {
+            poa.set_genesis_signing_key(key);
+        }
+        ConsensusConfig::Bft(_) => {
+            // The validators of the network are defined by the chain config.
+        }
+    }
+    config.snapshot_reader = snapshot_reader.clone().with_chain_config(chain_config)
+}
@@ -0,0 +1,71 @@
+use fuel_core::{
+    combined_database::CombinedDatabase,
+    state::rocks_db::DatabaseConfig,
+};
+use fuel_core_storage::transactional::AtomicView;
+use fuel_core_types::{
+    blockchain::consensus::Consensus,
+    fuel_crypto::SecretKey,
+    signer::remote::mock::MockRemoteSigner,
+};
+use rand::{
+    SeedableRng,
+    rngs::StdRng,
+};
+use test_helpers::fuel_core_driver::FuelCoreDriver;
+
+#[tokio::test]
+async fn can_get_sealed_block_from_poa_produced_block_when_signing_with_remote_signer() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret_key = SecretKey::random(&mut rng);
+    let poa_public = secret_key.public_key();
+    let signer = MockRemoteSigner::spawn("block-production", secret_key)
+        .await
+        .unwrap();
+
+    // start node with the remote signer enabled and produce some blocks
+    let num_blocks = 10;
+    let args = vec![
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--consensus-remote-signer-url",
+        signer.url(),
+        "--consensus-remote-signer-key-id",
+        "block-production",
+    ];
+    let driver = FuelCoreDriver::spawn(&args).await.unwrap();
+    let _ = driver
+        .client
+        .produce_blocks(num_blocks, None)
+        .await
+        .unwrap();
+
+    // stop the node and just grab the database
+    let db_path = driver.kill().await;
+    let db = CombinedDatabase::open(
+        db_path.path(),
+        Default::default(),
+        DatabaseConfig::config_for_tests(),
+    )
+    .unwrap();
+
+    let view = db.on_chain().latest_view().unwrap();
+
+    // verify that all blocks are signed by the key of the remote signer
+    for height in 1..=num_blocks {
+        let sealed_block = view
+            .get_sealed_block_by_height(&height.into())
+            .unwrap()
+            .expect("expected sealed block to be available");
+        let block_id = sealed_block.entity.id();
+        let signature = match sealed_block.consensus {
+            Consensus::PoA(ref poa) => poa.signature,
+            _ => panic!("Not expected consensus"),
+        };
+        signature
+            .verify(&poa_public, &block_id.into_message())
+            .expect("failed to verify signature");
+    }
+    assert!(signer.sign_requests() >= usize::try_from(num_blocks).unwrap());
+}
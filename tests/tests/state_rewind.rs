@@ -474,7 +474,7 @@ async fn dry_run__correct_utxoid_state_in_past_blocks() -> anyhow::Result<()> {
 
     // At the first block, the coin doesn't exist, so it should fail
     let err = client
-        .dry_run_opt(&[second_tx.clone()], None, None, Some(bh_first))
+        .dry_run_opt(&[second_tx.clone()], None, None, Some(bh_first), None)
         .await
         .expect_err("should fail")
         .to_string();
@@ -482,7 +482,7 @@ async fn dry_run__correct_utxoid_state_in_past_blocks() -> anyhow::Result<()> {
 
     // Just before the second transfer, the coin should exist and dry-run should succeed
     let status = client
-        .dry_run_opt(&[second_tx.clone()], None, None, Some(bh_second))
+        .dry_run_opt(&[second_tx.clone()], None, None, Some(bh_second), None)
         .await
         .expect("should succeed")[0]
         .clone();
@@ -492,7 +492,7 @@ async fn dry_run__correct_utxoid_state_in_past_blocks() -> anyhow::Result<()> {
 
     // At latest height, attempting to dry-run the same transaction again should fail
     let err = client
-        .dry_run_opt(&[second_tx.clone()], None, None, None)
+        .dry_run_opt(&[second_tx.clone()], None, None, None, None)
         .await
         .expect_err("should fail")
         .to_string();
@@ -500,7 +500,7 @@ async fn dry_run__correct_utxoid_state_in_past_blocks() -> anyhow::Result<()> {
 
     // At latest height, a similar transaction with a different TxId should still fail
     let err = client
-        .dry_run_opt(&[third_tx.clone()], None, None, None)
+        .dry_run_opt(&[third_tx.clone()], None, None, None, None)
         .await
         .expect_err("should fail")
         .to_string();
@@ -553,6 +553,7 @@ async fn dry_run__correct_contract_state_in_past_blocks() -> anyhow::Result<()>
                 None,
                 None,
                 block_height,
+                None,
             )
             .await?[0]
             .result
@@ -576,6 +577,7 @@ async fn dry_run__correct_contract_state_in_past_blocks() -> anyhow::Result<()>
             None,
             None,
             Some(deployed_height),
+            None,
         )
         .await
         .expect_err("Should fail to dry-run at this height");
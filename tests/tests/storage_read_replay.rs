@@ -102,7 +102,7 @@ async fn dry_run__storage_read_replay__returns_counter_state() {
         // when
         let tx = counter_contract::increment_tx(&mut rng, contract_id);
         let (statuses, storage_reads) = client
-            .dry_run_opt_record_storage_reads(&[tx], None, None, height)
+            .dry_run_opt_record_storage_reads(&[tx], None, None, height, None)
             .await
             .unwrap();
 
@@ -163,7 +163,7 @@ async fn dry_run__storage_read_replay__multiple_dry_runs_keep_original_reads() {
     let tx2 = counter_contract::increment_tx(&mut rng, contract_id);
     let tx3 = counter_contract::increment_tx(&mut rng, contract_id);
     let (statuses, storage_reads) = client
-        .dry_run_opt_record_storage_reads(&[tx1, tx2, tx3], None, None, None)
+        .dry_run_opt_record_storage_reads(&[tx1, tx2, tx3], None, None, None, None)
         .await
         .unwrap();
 
@@ -0,0 +1,221 @@
+use fuel_core::{
+    chain_config::{
+        ContractConfig,
+        StateConfig,
+    },
+    service::{
+        Config,
+        FuelService,
+    },
+};
+use fuel_core_client::client::{
+    FuelClient,
+    types::TransactionStatus,
+};
+use fuel_core_types::{
+    fuel_asm::{
+        GTFArgs,
+        RegId,
+        op,
+    },
+    fuel_tx::{
+        Bytes32,
+        ContractId,
+        Receipt,
+        TxId,
+        UniqueIdentifier,
+        Word,
+    },
+    fuel_types::{
+        ChainId,
+        canonical::Serialize,
+    },
+};
+use test_helpers::{
+    assemble_tx::AssembleAndRunTx,
+    default_signing_wallet,
+};
+
+const LOG_ID: Word = 7;
+const REVERT_CODE: Word = 13;
+/// The contract and two call parameters.
+const CALL_DATA_LEN: u16 = 48;
+
+const OUTER: ContractId = ContractId::new([1; 32]);
+const LOGGING: ContractId = ContractId::new([2; 32]);
+const REVERTING: ContractId = ContractId::new([3; 32]);
+
+fn contract(contract_id: ContractId, code: Vec<u8>) -> ContractConfig {
+    ContractConfig {
+        contract_id,
+        code,
+        tx_id: Bytes32::new(*contract_id),
+        output_index: Default::default(),
+        tx_pointer_block_height: Default::default(),
+        tx_pointer_tx_idx: Default::default(),
+        states: Default::default(),
+        balances: Default::default(),
+    }
+}
+
+fn config_with_contracts() -> Config {
+    let mut state_config = StateConfig::local_testnet();
+    // Calls the contract from the second call data in the script data.
+    state_config.contracts.push(contract(
+        OUTER,
+        vec![
+            op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+            op::addi(0x10, 0x10, CALL_DATA_LEN),
+            op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ]
+        .into_iter()
+        .collect(),
+    ));
+    state_config.contracts.push(contract(
+        LOGGING,
+        vec![
+            op::movi(0x10, LOG_ID.try_into().unwrap()),
+            op::log(RegId::ZERO, 0x10, RegId::ZERO, RegId::ZERO),
+            op::ret(RegId::ONE),
+        ]
+        .into_iter()
+        .collect(),
+    ));
+    state_config.contracts.push(contract(
+        REVERTING,
+        vec![
+            op::movi(0x10, REVERT_CODE.try_into().unwrap()),
+            op::rvrt(0x10),
+        ]
+        .into_iter()
+        .collect(),
+    ));
+
+    let mut config = Config::local_node_with_state_config(state_config);
+    config.utxo_validation = true;
+    config.txpool.utxo_validation = true;
+    config.gas_price_config.min_exec_gas_price = 1000;
+    config
+}
+
+/// Calls the `OUTER` contract, that calls the `inner` contract.
+async fn call_nested(client: &FuelClient, inner: ContractId) -> TxId {
+    let script = vec![
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let script_data = [OUTER, inner]
+        .into_iter()
+        .flat_map(|contract_id| {
+            contract_id
+                .to_bytes()
+                .into_iter()
+                .chain(Word::MIN.to_be_bytes())
+                .chain(Word::MIN.to_be_bytes())
+        })
+        .collect();
+
+    let tx = client
+        .assemble_script(script, script_data, default_signing_wallet())
+        .await
+        .unwrap();
+    let status = client.submit_and_await_commit(&tx).await.unwrap();
+    assert!(
+        matches!(
+            status,
+            TransactionStatus::Success { .. } | TransactionStatus::Failure { .. }
+        ),
+        "{status:?}"
+    );
+
+    tx.id(&ChainId::default())
+}
+
+#[tokio::test]
+async fn transaction_trace__returns_nested_call_frames() {
+    let srv = FuelService::new_node(config_with_contracts())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let tx_id = call_nested(&client, LOGGING).await;
+
+    // When
+    let trace = client.transaction_trace(&tx_id).await.unwrap();
+
+    // Then
+    assert_eq!(trace.revert, None);
+    assert_eq!(trace.frames.len(), 2);
+    let outer = &trace.frames[0];
+    let inner = &trace.frames[1];
+    assert_eq!(outer.parent, None);
+    assert_eq!(outer.contract_id, OUTER);
+    assert_eq!(inner.parent, Some(0));
+    assert_eq!(inner.contract_id, LOGGING);
+    assert!(inner.gas_used > 0);
+    assert!(outer.gas_used > inner.gas_used);
+    assert!(trace.gas_used > outer.gas_used);
+    assert!(inner.receipts.iter().any(|receipt| matches!(
+        receipt,
+        Receipt::Log { id, rb, .. } if *id == LOGGING && *rb == LOG_ID
+    )));
+    assert!(matches!(
+        outer.receipts.as_slice(),
+        [Receipt::Call { to, .. }, Receipt::Return { id, .. }] if *to == LOGGING && *id == OUTER
+    ));
+    assert!(matches!(
+        trace.receipts.as_slice(),
+        [
+            Receipt::Call { to, .. },
+            Receipt::Return { .. },
+            Receipt::ScriptResult { .. }
+        ] if *to == OUTER
+    ));
+}
+
+#[tokio::test]
+async fn transaction_trace__returns_revert_point_of_failed_transaction() {
+    let srv = FuelService::new_node(config_with_contracts())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let tx_id = call_nested(&client, REVERTING).await;
+
+    // When
+    let trace = client.transaction_trace(&tx_id).await.unwrap();
+
+    // Then
+    assert_eq!(trace.revert, None);
+    assert_eq!(trace.frames[0].revert, None);
+    let reverting = &trace.frames[1];
+    assert_eq!(reverting.contract_id, REVERTING);
+    assert!(matches!(
+        reverting.revert,
+        Some(Receipt::Revert { id, ra, .. }) if id == REVERTING && ra == REVERT_CODE
+    ));
+}
+
+#[tokio::test]
+async fn transaction_trace__fails_without_historical_execution() {
+    let mut config = config_with_contracts();
+    config.historical_execution = false;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let tx_id = call_nested(&client, LOGGING).await;
+
+    // When
+    let result = client.transaction_trace(&tx_id).await;
+
+    // Then
+    let err = result.expect_err("Should fail without the historical execution");
+    assert!(
+        err.to_string()
+            .contains("`--historical-execution` is required for this operation"),
+        "{err}"
+    );
+}
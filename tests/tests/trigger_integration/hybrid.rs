@@ -0,0 +1,115 @@
+use fuel_core::service::{
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::{
+    FuelClient,
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    types::TransactionStatus,
+};
+use fuel_core_poa::Trigger;
+use fuel_core_types::{
+    fuel_asm::*,
+    fuel_crypto::SecretKey,
+    fuel_tx::TransactionBuilder,
+    secrecy::Secret,
+    signer::SignMode,
+};
+use rand::{
+    Rng,
+    SeedableRng,
+    rngs::StdRng,
+};
+use std::time::Duration;
+
+async fn blocks_count(client: &FuelClient) -> usize {
+    client
+        .blocks(PaginationRequest {
+            cursor: None,
+            results: 20,
+            direction: PageDirection::Forward,
+        })
+        .await
+        .expect("blocks request failed")
+        .results
+        .len()
+}
+
+#[tokio::test]
+async fn poa_hybrid_produces_block_before_period_when_threshold_is_reached() {
+    let mut rng = StdRng::seed_from_u64(10);
+
+    let mut config = Config::local_node();
+    config.consensus_signer =
+        SignMode::Key(Secret::new(SecretKey::random(&mut rng).into()));
+    config.block_production = Trigger::Hybrid {
+        block_time: Duration::from_secs(3600),
+        fill_threshold_percent: 10,
+        max_idle_time: None,
+    };
+    let max_gas_per_tx = config
+        .snapshot_reader
+        .chain_config()
+        .consensus_parameters
+        .tx_params()
+        .max_gas_per_tx();
+
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // The transaction uses half of the block gas limit.
+    let tx =
+        TransactionBuilder::script([op::ret(RegId::ONE)].into_iter().collect(), vec![])
+            .script_gas_limit(max_gas_per_tx / 2)
+            .add_unsigned_coin_input(
+                SecretKey::random(&mut rng),
+                rng.r#gen(),
+                rng.r#gen(),
+                rng.r#gen(),
+                Default::default(),
+            )
+            .finalize_as_transaction();
+
+    let status = tokio::time::timeout(
+        Duration::from_secs(10),
+        client.submit_and_await_commit(&tx),
+    )
+    .await
+    .expect("the block should be produced before the end of the period")
+    .unwrap();
+
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+    assert_eq!(blocks_count(&client).await, 2 /* genesis block + 1 */);
+}
+
+#[tokio::test(start_paused = true)]
+async fn poa_hybrid_skips_empty_blocks_until_max_idle_time() {
+    let mut rng = StdRng::seed_from_u64(10);
+
+    let mut config = Config::local_node();
+    config.consensus_signer =
+        SignMode::Key(Secret::new(SecretKey::random(&mut rng).into()));
+    config.block_production = Trigger::Hybrid {
+        block_time: Duration::from_secs(2),
+        fill_threshold_percent: 50,
+        max_idle_time: Some(Duration::from_secs(20)),
+    };
+
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let time_start = tokio::time::Instant::now();
+
+    tokio::time::sleep(Duration::from_secs(10)).await;
+    assert_eq!(blocks_count(&client).await, 1 /* genesis block */);
+
+    while blocks_count(&client).await < 2 {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+
+    // The empty block is produced only after the max idle time
+    assert!(time_start.elapsed() >= Duration::from_secs(20));
+    assert_eq!(blocks_count(&client).await, 2 /* genesis block + 1 */);
+}
@@ -1,3 +1,4 @@
+mod hybrid;
 mod instant;
 mod interval;
 mod never;
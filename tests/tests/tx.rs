@@ -58,6 +58,7 @@ use test_helpers::{
     default_signing_wallet,
 };
 
+mod dry_run_state_overrides;
 mod log_syscall;
 mod predicates;
 mod tx_pointer;
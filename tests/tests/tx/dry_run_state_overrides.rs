@@ -0,0 +1,175 @@
+use fuel_core::service::{
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_types::{
+    entities::{
+        coins::coin::Coin,
+        relayer::message::MessageV1,
+    },
+    fuel_asm::{
+        GTFArgs,
+        RegId,
+        op,
+    },
+    fuel_crypto::SecretKey,
+    fuel_tx::*,
+    fuel_types::canonical::Serialize,
+    services::executor::{
+        ContractOverride,
+        StateOverrides,
+        TransactionExecutionResult,
+    },
+};
+use rand::{
+    Rng,
+    SeedableRng,
+    rngs::StdRng,
+};
+
+fn contract_call_tx(contract_id: ContractId) -> Transaction {
+    let script = vec![
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let script_data = contract_id
+        .to_bytes()
+        .into_iter()
+        .chain(Word::MIN.to_be_bytes())
+        .chain(Word::MIN.to_be_bytes())
+        .collect();
+
+    TransactionBuilder::script(script.into_iter().collect(), script_data)
+        .script_gas_limit(1_000_000)
+        .add_fee_input()
+        .add_input(Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_output(Output::contract(1, Default::default(), Default::default()))
+        .finalize_as_transaction()
+}
+
+#[tokio::test]
+async fn dry_run__state_overrides_replace_contract_bytecode_storage_and_balances() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let contract_id = ContractId::new([1; 32]);
+    let tx = contract_call_tx(contract_id);
+    // Logs the storage slot and the balance of the contract under the zero key and asset.
+    let bytecode = vec![
+        op::movi(0x10, Bytes32::LEN.try_into().unwrap()),
+        op::aloc(0x10),
+        op::srw(0x11, 0x12, RegId::HP, 0),
+        op::bal(0x13, RegId::HP, RegId::FP),
+        op::log(0x11, 0x13, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let mut slot_value = vec![0; Bytes32::LEN];
+    slot_value[..8].copy_from_slice(&42u64.to_be_bytes());
+    let overrides = StateOverrides {
+        contracts: vec![ContractOverride {
+            contract_id,
+            bytecode: Some(bytecode.into_iter().collect()),
+            storage_slots: vec![(Bytes32::zeroed(), slot_value)],
+            balances: vec![(AssetId::zeroed(), 100)],
+        }],
+        ..Default::default()
+    };
+    let without_overrides = client
+        .dry_run_opt(std::slice::from_ref(&tx), Some(false), None, None, None)
+        .await;
+
+    // When
+    let with_overrides = client
+        .dry_run_opt(&[tx], Some(false), None, None, Some(overrides))
+        .await
+        .unwrap();
+
+    // Then
+    assert!(without_overrides.is_err());
+    let status = with_overrides.last().expect("Nonempty response");
+    assert!(
+        matches!(status.result, TransactionExecutionResult::Success { .. }),
+        "{:?}",
+        status.result
+    );
+    assert!(status.result.receipts().iter().any(|receipt| matches!(
+        receipt,
+        Receipt::Log { id, ra, rb, .. } if *id == contract_id && *ra == 42 && *rb == 100
+    )));
+}
+
+#[tokio::test]
+async fn dry_run__state_overrides_inject_fake_coins_and_messages() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let secret = SecretKey::random(&mut rng);
+    let owner = Input::owner(&secret.public_key());
+    let coin = Coin {
+        utxo_id: rng.r#gen(),
+        owner,
+        amount: 1_000,
+        asset_id: AssetId::BASE,
+        tx_pointer: Default::default(),
+    };
+    let message = MessageV1 {
+        sender: rng.r#gen(),
+        recipient: owner,
+        nonce: rng.r#gen(),
+        amount: 2_000,
+        data: vec![],
+        da_height: Default::default(),
+    };
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .script_gas_limit(10_000)
+        .add_unsigned_coin_input(
+            secret,
+            coin.utxo_id,
+            coin.amount,
+            coin.asset_id,
+            coin.tx_pointer,
+        )
+        .add_unsigned_message_input(
+            secret,
+            message.sender,
+            message.nonce,
+            message.amount,
+            vec![],
+        )
+        .add_output(Output::change(owner, 0, AssetId::BASE))
+        .finalize_as_transaction();
+    let overrides = StateOverrides {
+        coins: vec![coin],
+        messages: vec![message.into()],
+        ..Default::default()
+    };
+    let without_overrides = client
+        .dry_run_opt(std::slice::from_ref(&tx), Some(true), Some(0), None, None)
+        .await;
+
+    // When
+    let with_overrides = client
+        .dry_run_opt(&[tx], Some(true), Some(0), None, Some(overrides))
+        .await
+        .unwrap();
+
+    // Then
+    assert!(without_overrides.is_err());
+    let status = with_overrides.last().expect("Nonempty response");
+    assert!(
+        matches!(status.result, TransactionExecutionResult::Success { .. }),
+        "{:?}",
+        status.result
+    );
+}
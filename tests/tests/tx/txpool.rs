@@ -4,17 +4,33 @@ use crate::helpers::{
     TestContext,
     TestSetupBuilder,
 };
-use fuel_core::service::FuelService;
+use fuel_core::service::{
+    Config,
+    FuelService,
+    adapters::TxPoolAdmissionFilter,
+};
 use fuel_core_client::client::{
     FuelClient,
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
     types::TransactionStatus,
 };
 use fuel_core_poa::Trigger;
+use fuel_core_txpool::{
+    TxPoolStats,
+    ports::AdmissionFilter,
+};
 use fuel_core_types::{
     fuel_asm::*,
     fuel_crypto::*,
     fuel_tx,
-    fuel_tx::*,
+    fuel_tx::{
+        field::Inputs,
+        *,
+    },
+    services::txpool::PoolTransaction,
 };
 use futures::StreamExt;
 use itertools::Itertools;
@@ -23,7 +39,10 @@ use rand::{
     SeedableRng,
     rngs::StdRng,
 };
-use std::time::Duration;
+use std::{
+    sync::Arc,
+    time::Duration,
+};
 use test_helpers::{
     assemble_tx::AssembleAndRunTx,
     config_with_fee,
@@ -185,3 +204,256 @@ async fn informs_immediately_if_the_input_was_spent_during_previous_blocks_witho
     let err = status.expect_err("Should receive error that transaction squeezed out");
     assert!(err.to_string().contains("was already spent"))
 }
+
+fn transfer_transactions(rng: &mut StdRng, count: u64) -> Vec<Script> {
+    (1..=count)
+        .map(|i| {
+            TransactionBuilder::script(
+                op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+                vec![],
+            )
+            .script_gas_limit(10_000)
+            .add_unsigned_coin_input(
+                SecretKey::random(rng),
+                rng.r#gen(),
+                1000 + i,
+                Default::default(),
+                Default::default(),
+            )
+            .add_output(Output::Change {
+                amount: 0,
+                asset_id: Default::default(),
+                to: rng.r#gen(),
+            })
+            .finalize()
+        })
+        .collect_vec()
+}
+
+#[tokio::test]
+async fn submit_bundle__transactions_are_included_one_after_another() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut test_builder = TestSetupBuilder::new(2322);
+    let transactions = transfer_transactions(&mut rng, 4);
+    test_builder.config_coin_inputs_from_transactions(&transactions.iter().collect_vec());
+    test_builder.trigger = Trigger::Never;
+    let TestContext {
+        srv: _dont_drop,
+        client,
+        ..
+    } = test_builder.finalize().await;
+
+    // Given
+    let mut transactions = transactions
+        .into_iter()
+        .map(fuel_tx::Transaction::from)
+        .collect::<Vec<_>>();
+    let bundle = transactions.split_off(2);
+    for tx in &transactions {
+        client.submit(tx).await.unwrap();
+    }
+
+    // When
+    let bundle_ids = client.submit_bundle(&bundle).await.unwrap();
+    client.produce_blocks(1, None).await.unwrap();
+
+    // Then
+    let block = client.block_by_height(1.into()).await.unwrap().unwrap();
+    assert_eq!(block.transactions.len(), 5);
+    let position = block
+        .transactions
+        .iter()
+        .position(|id| id == &bundle_ids[0])
+        .expect("The bundle should be included");
+    assert_eq!(block.transactions[position + 1], bundle_ids[1]);
+}
+
+#[tokio::test]
+async fn submit_bundle__invalid_transaction_rejects_whole_bundle() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut test_builder = TestSetupBuilder::new(2322);
+    let transactions = transfer_transactions(&mut rng, 2);
+    // Only the first transaction has its coin in the genesis
+    test_builder.config_coin_inputs_from_transactions(&[&transactions[0]]);
+    test_builder.trigger = Trigger::Never;
+    let TestContext {
+        srv: _dont_drop,
+        client,
+        ..
+    } = test_builder.finalize().await;
+
+    // Given
+    let bundle = transactions
+        .into_iter()
+        .map(fuel_tx::Transaction::from)
+        .collect::<Vec<_>>();
+
+    // When
+    let result = client.submit_bundle(&bundle).await;
+
+    // Then
+    let err = result.expect_err("The bundle should be rejected");
+    assert!(err.to_string().contains("Bundle error"), "{err}");
+    let chain_id = client
+        .chain_info()
+        .await
+        .unwrap()
+        .consensus_parameters
+        .chain_id();
+    let first = client.transaction(&bundle[0].id(&chain_id)).await.unwrap();
+    assert!(first.is_none());
+}
+
+#[tokio::test]
+async fn submit_bundle__rejected_by_node_that_does_not_produce_blocks() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    config.debug = false;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let bundle = vec![fuel_tx::Transaction::default_test_tx()];
+
+    // When
+    let result = client.submit_bundle(&bundle).await;
+
+    // Then
+    let err = result.expect_err("The bundle should be rejected");
+    assert!(
+        err.to_string()
+            .contains("only the block producer accepts them"),
+        "{err}"
+    );
+}
+
+struct RejectAllFilter;
+
+impl AdmissionFilter for RejectAllFilter {
+    fn admit(&self, _: &PoolTransaction, _: &TxPoolStats) -> Result<(), String> {
+        Err("Rejected by the compliance rules".to_string())
+    }
+}
+
+#[tokio::test]
+async fn submit__transaction_rejected_by_installed_admission_filter() {
+    let mut config = Config::local_node();
+    config.txpool_admission_filter =
+        TxPoolAdmissionFilter::new(Arc::new(RejectAllFilter));
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let tx = fuel_tx::Transaction::default_test_tx();
+
+    // When
+    let result = client.submit(&tx).await;
+
+    // Then
+    let err = result.expect_err("The transaction should be rejected");
+    assert!(
+        err.to_string().contains("Rejected by the compliance rules"),
+        "{err}"
+    );
+}
+
+#[tokio::test]
+async fn tx_pool_transactions__returns_pool_content_sorted_by_tip() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut test_builder = TestSetupBuilder::new(2322);
+    let transactions = (1..=3)
+        .map(|i| {
+            TransactionBuilder::script(
+                op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+                vec![],
+            )
+            .script_gas_limit(10_000)
+            .tip(i * 10)
+            .max_fee_limit(100)
+            .add_unsigned_coin_input(
+                SecretKey::random(&mut rng),
+                rng.r#gen(),
+                1000,
+                Default::default(),
+                Default::default(),
+            )
+            .finalize()
+        })
+        .collect_vec();
+    test_builder.config_coin_inputs_from_transactions(&transactions.iter().collect_vec());
+    test_builder.trigger = Trigger::Never;
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    // Given
+    for tx in &transactions {
+        client.submit(&tx.clone().into()).await.unwrap();
+    }
+    let owner = *transactions[0].inputs()[0].input_owner().unwrap();
+
+    // When
+    let first_page = client
+        .tx_pool_transactions(
+            None,
+            None,
+            None,
+            PaginationRequest {
+                cursor: None,
+                results: 2,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+    let second_page = client
+        .tx_pool_transactions(
+            None,
+            None,
+            None,
+            PaginationRequest {
+                cursor: first_page.cursor.clone(),
+                results: 2,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+    let by_owner = client
+        .tx_pool_transactions(
+            Some(&owner),
+            None,
+            Some(true),
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+
+    // Then
+    assert!(first_page.has_next_page);
+    assert!(!second_page.has_next_page);
+    let tips = first_page
+        .results
+        .iter()
+        .chain(second_page.results.iter())
+        .map(|tx| tx.tip)
+        .collect_vec();
+    assert_eq!(tips, vec![30, 20, 10]);
+    assert!(
+        first_page
+            .results
+            .iter()
+            .all(|tx| tx.is_executable() && tx.dependents_count == 0)
+    );
+    assert_eq!(by_owner.results.len(), 1);
+    assert_eq!(
+        by_owner.results[0].transaction,
+        transactions[0].clone().into()
+    );
+}
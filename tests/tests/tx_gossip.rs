@@ -1,17 +1,25 @@
 #![allow(unexpected_cfgs)] // for cfg(coverage)
 
 use fuel_core::{
-    chain_config::StateConfig,
+    chain_config::{
+        StateConfig,
+        TESTNET_WALLET_SECRETS,
+    },
+    p2p::config::TxGossipMode,
     p2p_test_helpers::{
         BootstrapSetup,
         BootstrapType,
+        CustomizeConfig,
         Nodes,
         ProducerSetup,
         ValidatorSetup,
         make_nodes,
     },
 };
-use fuel_core_client::client::FuelClient;
+use fuel_core_client::client::{
+    FuelClient,
+    types::TransactionStatus,
+};
 use fuel_core_types::{
     fuel_tx::{
         input::{
@@ -34,8 +42,13 @@ use std::{
         Hash,
         Hasher,
     },
+    str::FromStr,
     time::Duration,
 };
+use test_helpers::assemble_tx::{
+    AssembleAndRunTx,
+    SigningAccount,
+};
 
 #[tokio::test(flavor = "multi_thread")]
 async fn test_tx_gossiping() {
@@ -117,6 +130,82 @@ async fn test_tx_gossiping() {
     assert!(response.is_some());
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_tx_gossiping__announce_mode_fetches_announced_tx() {
+    let mut rng = StdRng::seed_from_u64(1234);
+
+    let producer_secret = SecretKey::random(&mut rng);
+    let producer_pub_key = Input::owner(&producer_secret.public_key());
+    let validator_secret = SecretKey::random(&mut rng);
+    let validator_pub_key = Input::owner(&validator_secret.public_key());
+
+    // Given
+    let overrides =
+        CustomizeConfig::no_overrides().tx_gossip_mode(TxGossipMode::Announce);
+    let Nodes {
+        producers,
+        validators,
+        bootstrap_nodes: _dont_drop,
+    } = make_nodes(
+        vec![
+            Some(BootstrapSetup::new(producer_pub_key)),
+            Some(BootstrapSetup::new(validator_pub_key)),
+        ],
+        vec![Some(
+            ProducerSetup::new_with_overrides(producer_secret, overrides.clone())
+                .with_name(format!("{}:producer", producer_pub_key)),
+        )],
+        vec![Some(
+            ValidatorSetup::new_with_overrides(producer_pub_key, overrides)
+                .with_name(format!("{}:validator", validator_pub_key)),
+        )],
+        None,
+    )
+    .await;
+
+    let producer = FuelClient::from(producers[0].node.bound_address);
+    let validator = FuelClient::from(validators[0].node.bound_address);
+
+    let consensus_parameters = producer
+        .chain_info()
+        .await
+        .expect("Could not get chain info")
+        .consensus_parameters;
+    let chain_id = consensus_parameters.chain_id();
+    let base_asset_id = *consensus_parameters.base_asset_id();
+
+    let wallet =
+        SigningAccount::Wallet(SecretKey::from_str(TESTNET_WALLET_SECRETS[0]).unwrap());
+    let tx = producer
+        .assemble_transfer(wallet, vec![(Address::new([1u8; 32]), base_asset_id, 1234)])
+        .await
+        .unwrap();
+    let tx_id = tx.id(&chain_id);
+
+    // When
+    validator
+        .submit(&tx)
+        .await
+        .expect("Transaction submission to validator failed");
+
+    // Then
+    // The producer only receives the id of the transaction, so it has to fetch
+    // the transaction from the validator before including it into the block.
+    let status = tokio::time::timeout(Duration::from_secs(10), async {
+        loop {
+            if let Ok(status @ TransactionStatus::Success { .. }) =
+                producer.transaction_status(&tx_id).await
+            {
+                return status;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    })
+    .await
+    .expect("The announced transaction should be included by the producer");
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+}
+
 const NUMBER_OF_INVALID_TXS: usize = 100;
 
 async fn test_tx_gossiping_invalid_txs(
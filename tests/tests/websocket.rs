@@ -0,0 +1,146 @@
+use fuel_core::service::{
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::{
+    FuelClient,
+    types::TransactionStatus,
+};
+use fuel_core_types::fuel_tx::{
+    Transaction,
+    field::Inputs,
+};
+use futures::StreamExt;
+use soketto::{
+    Incoming,
+    handshake::{
+        Client,
+        ServerResponse,
+    },
+};
+use std::time::Duration;
+use tokio_util::compat::TokioAsyncReadCompatExt;
+
+async fn websocket_client(srv: &FuelService) -> FuelClient {
+    let mut client = FuelClient::from(srv.bound_address);
+    client.use_websocket_subscriptions();
+    client
+}
+
+#[tokio::test]
+async fn websocket__multiplexes_subscriptions_over_one_connection() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = websocket_client(&srv).await;
+
+    // Given
+    let mut first_blocks = client.new_blocks_subscription().await.unwrap();
+    let mut second_blocks = client.new_blocks_subscription().await.unwrap();
+    tokio::time::sleep(Duration::from_millis(1000)).await;
+
+    // When
+    let tx = Transaction::default_test_tx();
+    let mut statuses = client.submit_and_await_status(&tx).await.unwrap();
+
+    // Then
+    let submitted = statuses.next().await.unwrap().unwrap();
+    assert!(matches!(submitted, TransactionStatus::Submitted { .. }));
+    let success = statuses.next().await.unwrap().unwrap();
+    assert!(matches!(success, TransactionStatus::Success { .. }));
+    let first = first_blocks.next().await.unwrap().unwrap();
+    let second = second_blocks.next().await.unwrap().unwrap();
+    assert_eq!(*first.sealed_block.entity.header().height(), 1u32.into());
+    assert_eq!(*second.sealed_block.entity.header().height(), 1u32.into());
+}
+
+#[tokio::test]
+async fn websocket__dropped_subscription_does_not_affect_others() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = websocket_client(&srv).await;
+    let dropped_blocks = client.new_blocks_subscription().await.unwrap();
+    let mut blocks = client.new_blocks_subscription().await.unwrap();
+    tokio::time::sleep(Duration::from_millis(1000)).await;
+
+    // Given
+    drop(dropped_blocks);
+
+    // When
+    client.produce_blocks(1, None).await.unwrap();
+
+    // Then
+    let block = blocks.next().await.unwrap().unwrap();
+    assert_eq!(*block.sealed_block.entity.header().height(), 1u32.into());
+}
+
+#[tokio::test]
+async fn websocket__subscription_errors_are_returned_to_the_client() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = websocket_client(&srv).await;
+
+    // Given
+    let mut tx = Transaction::default_test_tx();
+    if let Transaction::Script(script) = &mut tx {
+        // The transaction without inputs is invalid.
+        script.inputs_mut().clear();
+    }
+
+    // When
+    let result = client.submit_and_await_status(&tx).await;
+
+    // Then
+    match result {
+        Ok(mut statuses) => {
+            statuses
+                .next()
+                .await
+                .expect("Should return the error")
+                .expect_err("Should fail");
+        }
+        Err(error) => panic!("The subscription should be started: {error}"),
+    }
+}
+
+#[tokio::test]
+async fn websocket__endpoint_rejects_requests_without_upgrade() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let url = format!("http://{}/v1/graphql-ws", srv.bound_address);
+
+    // When
+    let response = reqwest::get(url).await.unwrap();
+
+    // Then
+    assert_eq!(response.status(), reqwest::StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn websocket__protocol_errors_close_the_connection_with_the_code() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let socket = tokio::net::TcpStream::connect(srv.bound_address)
+        .await
+        .unwrap();
+    let host = srv.bound_address.to_string();
+    let mut client = Client::new(socket.compat(), &host, "/v1/graphql-ws");
+    client.add_protocol("graphql-transport-ws");
+    let response = client.handshake().await.unwrap();
+    assert!(matches!(response, ServerResponse::Accepted { .. }));
+    let (mut sender, mut receiver) = client.into_builder().finish();
+    let init = r#"{"type":"connection_init"}"#;
+    sender.send_text(init).await.unwrap();
+    sender.flush().await.unwrap();
+    let mut ack = Vec::new();
+    receiver.receive_data(&mut ack).await.unwrap();
+
+    // Given
+    let duplicated_init = init;
+
+    // When
+    sender.send_text(duplicated_init).await.unwrap();
+    sender.flush().await.unwrap();
+
+    // Then
+    let mut message = Vec::new();
+    let incoming = receiver.receive(&mut message).await.unwrap();
+    let Incoming::Closed(reason) = incoming else {
+        panic!("The connection should be closed")
+    };
+    assert_eq!(reason.code, 4429);
+}